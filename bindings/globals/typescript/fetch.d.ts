@@ -150,11 +150,100 @@ declare class Response {
 
 	get headers(): Headers;
 
+	get body(): ReadableStream | null;
+
 	get bodyUsed(): boolean;
 
 	arrayBuffer(): Promise<ArrayBuffer>;
 
+	json(): Promise<any>;
+
 	text(): Promise<string>;
 }
 
-declare function fetch(input: RequestInfo, init?: RequestInit): Promise<Response>;
+declare interface FetchProgress {
+	loaded: number;
+	total: number | null;
+}
+
+declare interface FetchInit extends RequestInit {
+	onUploadProgress?: (progress: FetchProgress) => void;
+	onDownloadProgress?: (progress: FetchProgress) => void;
+}
+
+declare function fetch(input: RequestInfo, init?: FetchInit): Promise<Response>;
+
+declare interface CacheQueryOptions {
+	ignoreSearch?: boolean;
+	ignoreMethod?: boolean;
+}
+
+declare interface MultiCacheQueryOptions extends CacheQueryOptions {
+	cacheName?: string;
+}
+
+declare class Cache {
+	match(request: RequestInfo, options?: CacheQueryOptions): Promise<Response | undefined>;
+
+	matchAll(request?: RequestInfo, options?: CacheQueryOptions): Promise<Response[]>;
+
+	put(request: RequestInfo, response: Response): Promise<void>;
+
+	delete(request: RequestInfo, options?: CacheQueryOptions): Promise<boolean>;
+
+	keys(request?: RequestInfo, options?: CacheQueryOptions): Promise<Request[]>;
+}
+
+declare class CacheStorage {
+	match(request: RequestInfo, options?: MultiCacheQueryOptions): Promise<Response | undefined>;
+
+	has(cacheName: string): Promise<boolean>;
+
+	open(cacheName: string): Promise<Cache>;
+
+	delete(cacheName: string): Promise<boolean>;
+
+	keys(): Promise<string[]>;
+}
+
+declare const caches: CacheStorage;
+
+declare interface EventSourceInit {
+	withCredentials?: boolean;
+}
+
+declare interface EventSourceEvent {
+	type: string;
+	target: EventSource;
+}
+
+declare interface EventSourceMessageEvent extends EventSourceEvent {
+	data: string;
+	origin: string;
+	lastEventId: string;
+}
+
+declare class EventSource {
+	static readonly CONNECTING: 0;
+	static readonly OPEN: 1;
+	static readonly CLOSED: 2;
+
+	constructor(url: string, init?: EventSourceInit);
+
+	get url(): string;
+
+	get withCredentials(): boolean;
+
+	get readyState(): 0 | 1 | 2;
+
+	onopen: ((event: EventSourceEvent) => void) | null;
+	onmessage: ((event: EventSourceMessageEvent) => void) | null;
+	onerror: ((event: EventSourceEvent) => void) | null;
+
+	addEventListener(type: "open" | "error", listener: (event: EventSourceEvent) => void): void;
+	addEventListener(type: string, listener: (event: EventSourceMessageEvent) => void): void;
+
+	removeEventListener(type: string, listener: Function): void;
+
+	close(): void;
+}
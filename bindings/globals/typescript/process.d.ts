@@ -0,0 +1,25 @@
+interface ProcessEnv {
+	get(key: string): string | undefined;
+
+	set(key: string, value: string): void;
+
+	delete(key: string): void;
+
+	toObject(): Record<string, string>;
+}
+
+interface Process {
+	readonly args: readonly string[];
+
+	readonly env: ProcessEnv;
+
+	exitCode: number;
+
+	cwd(): string;
+
+	chdir(path: string): void;
+
+	exit(code?: number): never;
+}
+
+declare const process: Process;
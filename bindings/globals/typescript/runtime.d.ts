@@ -0,0 +1,22 @@
+interface MemoryUsage {
+	heapUsed: number;
+	heapLimit: number;
+	gcCount: number;
+	majorGCCount: number;
+	minorGCCount: number;
+	realms: RealmMemoryUsage[];
+}
+
+interface RealmMemoryUsage {
+	heapUsed: number;
+}
+
+interface Runtime {
+	memoryUsage(): MemoryUsage;
+
+	heapDump(path: string): void;
+
+	heapSnapshot(path: string): void;
+}
+
+declare const runtime: Runtime;
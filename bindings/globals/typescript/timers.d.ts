@@ -1,9 +1,19 @@
-declare function setTimeout<T extends any[]>(callback: (...arguments: [...T]) => void, duration?: number, ...arguments: [...T]): number;
+declare class Timeout {
+	private constructor();
 
-declare function setInterval<T extends any[]>(callback: (...arguments: [...T]) => void, duration?: number, ...arguments: [...T]): number;
+	ref(): this;
+	unref(): this;
+	hasRef(): boolean;
+	refresh(): this;
+	[Symbol.toPrimitive](): number;
+}
 
-declare function clearTimeout(id: number): void;
+declare function setTimeout<T extends any[]>(callback: (...arguments: [...T]) => void, duration?: number, ...arguments: [...T]): Timeout;
 
-declare function clearInterval(id: number): void;
+declare function setInterval<T extends any[]>(callback: (...arguments: [...T]) => void, duration?: number, ...arguments: [...T]): Timeout;
+
+declare function clearTimeout(id?: Timeout | number): void;
+
+declare function clearInterval(id?: Timeout | number): void;
 
 declare function queueMacrotask(callback: () => void): void;
@@ -1,9 +1,23 @@
 declare module "assert" {
+	export type ErrorMatcher = (new (...args: any[]) => any) | RegExp | Record<PropertyKey, unknown>;
+
 	export function ok(assertion?: boolean, message?: string): void;
 
 	export function equals(actual: any, expected: any, message?: string): void;
 
+	export function deepStrictEqual(actual: any, expected: any, message?: string): void;
+
+	export function notDeepStrictEqual(actual: any, expected: any, message?: string): void;
+
 	export function throws(func: () => void, message?: string): void;
+	export function throws(func: () => void, expected?: ErrorMatcher, message?: string): void;
+
+	export function rejects(promise: Promise<any> | (() => Promise<any>), message?: string): Promise<void>;
+	export function rejects(promise: Promise<any> | (() => Promise<any>), expected?: ErrorMatcher, message?: string): Promise<void>;
+
+	export function match(string: string, regexp: RegExp, message?: string): void;
+
+	export function matchSnapshot(actual: any, name: string, message?: string): void;
 
 	export function fail(message?: string): void;
 
@@ -11,7 +25,12 @@ declare module "assert" {
 		export {
 			ok,
 			equals,
+			deepStrictEqual,
+			notDeepStrictEqual,
 			throws,
+			rejects,
+			match,
+			matchSnapshot,
 			fail,
 		};
 	}
@@ -0,0 +1,72 @@
+declare module "buffer" {
+	export type Encoding = "utf8" | "utf-8" | "utf16le" | "utf-16le" | "ucs2" | "ucs-2" | "latin1" | "binary" | "ascii" | "base64" | "base64url" | "hex";
+
+	export class Buffer extends Uint8Array {
+		static from(string: string, encoding?: Encoding): Buffer;
+		static from(buffer: ArrayBuffer | SharedArrayBuffer, byteOffset?: number, length?: number): Buffer;
+		static from(data: ArrayLike<number> | Iterable<number> | ArrayBufferView): Buffer;
+		static alloc(size: number, fill?: string | number | Uint8Array, encoding?: Encoding): Buffer;
+		static allocUnsafe(size: number): Buffer;
+		static concat(list: readonly Uint8Array[], totalLength?: number): Buffer;
+		static isBuffer(value: any): value is Buffer;
+		static isEncoding(encoding: string): encoding is Encoding;
+		static byteLength(value: string | ArrayBufferView | ArrayBuffer, encoding?: Encoding): number;
+		static compare(a: Uint8Array, b: Uint8Array): -1 | 0 | 1;
+
+		toString(encoding?: Encoding, start?: number, end?: number): string;
+		toJSON(): { type: "Buffer", data: number[] };
+		equals(other: Uint8Array): boolean;
+		compare(target: Uint8Array, targetStart?: number, targetEnd?: number, sourceStart?: number, sourceEnd?: number): -1 | 0 | 1;
+		indexOf(value: string | number | Uint8Array, byteOffset?: number, encoding?: Encoding): number;
+		includes(value: string | number | Uint8Array, byteOffset?: number, encoding?: Encoding): boolean;
+		fill(value: string | number | Uint8Array, offset?: number, end?: number, encoding?: Encoding): this;
+		write(string: string, offset?: number, length?: number, encoding?: Encoding): number;
+		slice(start?: number, end?: number): Buffer;
+		subarray(start?: number, end?: number): Buffer;
+
+		readInt8(offset?: number): number;
+		readUInt8(offset?: number): number;
+		readInt16LE(offset?: number): number;
+		readInt16BE(offset?: number): number;
+		readUInt16LE(offset?: number): number;
+		readUInt16BE(offset?: number): number;
+		readInt32LE(offset?: number): number;
+		readInt32BE(offset?: number): number;
+		readUInt32LE(offset?: number): number;
+		readUInt32BE(offset?: number): number;
+		readFloatLE(offset?: number): number;
+		readFloatBE(offset?: number): number;
+		readDoubleLE(offset?: number): number;
+		readDoubleBE(offset?: number): number;
+		readBigInt64LE(offset?: number): bigint;
+		readBigInt64BE(offset?: number): bigint;
+		readBigUInt64LE(offset?: number): bigint;
+		readBigUInt64BE(offset?: number): bigint;
+		writeInt8(value: number, offset?: number): number;
+		writeUInt8(value: number, offset?: number): number;
+		writeInt16LE(value: number, offset?: number): number;
+		writeInt16BE(value: number, offset?: number): number;
+		writeUInt16LE(value: number, offset?: number): number;
+		writeUInt16BE(value: number, offset?: number): number;
+		writeInt32LE(value: number, offset?: number): number;
+		writeInt32BE(value: number, offset?: number): number;
+		writeUInt32LE(value: number, offset?: number): number;
+		writeUInt32BE(value: number, offset?: number): number;
+		writeFloatLE(value: number, offset?: number): number;
+		writeFloatBE(value: number, offset?: number): number;
+		writeDoubleLE(value: number, offset?: number): number;
+		writeDoubleBE(value: number, offset?: number): number;
+		writeBigInt64LE(value: bigint, offset?: number): number;
+		writeBigInt64BE(value: bigint, offset?: number): number;
+		writeBigUInt64LE(value: bigint, offset?: number): number;
+		writeBigUInt64BE(value: bigint, offset?: number): number;
+	}
+
+	namespace BufferModule {
+		export {
+			Buffer,
+		};
+	}
+
+	export default BufferModule;
+}
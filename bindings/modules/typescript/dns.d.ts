@@ -0,0 +1,43 @@
+declare module "dns" {
+	export interface ResolveOptions {
+		nameserver?: string;
+	}
+
+	export interface LookupOptions extends ResolveOptions {
+		family?: 4 | 6;
+	}
+
+	export interface MxRecord {
+		exchange: string;
+		priority: number;
+	}
+
+	export interface SrvRecord {
+		name: string;
+		port: number;
+		priority: number;
+		weight: number;
+	}
+
+	export function lookup(hostname: string, options?: LookupOptions): Promise<string[]>;
+	export function resolve4(hostname: string, options?: ResolveOptions): Promise<string[]>;
+	export function resolve6(hostname: string, options?: ResolveOptions): Promise<string[]>;
+	export function resolveMx(hostname: string, options?: ResolveOptions): Promise<MxRecord[]>;
+	export function resolveTxt(hostname: string, options?: ResolveOptions): Promise<string[][]>;
+	export function resolveSrv(hostname: string, options?: ResolveOptions): Promise<SrvRecord[]>;
+	export function reverse(ip: string, options?: ResolveOptions): Promise<string[]>;
+
+	namespace Dns {
+		export {
+			lookup,
+			resolve4,
+			resolve6,
+			resolveMx,
+			resolveTxt,
+			resolveSrv,
+			reverse,
+		};
+	}
+
+	export default Dns;
+}
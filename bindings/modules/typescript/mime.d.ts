@@ -0,0 +1,33 @@
+declare module "mime" {
+	export interface MediaType {
+		type: string;
+		subtype: string;
+		essence: string;
+		parameters: Record<string, string>;
+	}
+
+	export function lookup(path: string): string | null;
+
+	export function extension(type: string): string | null;
+
+	export function parse(type: string): MediaType | null;
+
+	export function format(type: string): string | null;
+
+	export function essence(type: string): string | null;
+
+	export function isSameEssence(first: string, second: string): boolean;
+
+	namespace Mime {
+		export {
+			lookup,
+			extension,
+			parse,
+			format,
+			essence,
+			isSameEssence,
+		};
+	}
+
+	export default Mime;
+}
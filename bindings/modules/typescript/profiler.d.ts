@@ -0,0 +1,18 @@
+declare module "profiler" {
+	export function start(interval?: number, allocations?: boolean): boolean;
+	export type Weight = "time" | "heap-growth" | "allocations";
+
+	export function stop(path?: undefined, weight?: Weight): string;
+	export function stop(path: string, weight?: Weight): void;
+	export function isProfiling(): boolean;
+
+	namespace Profiler {
+		export {
+			start,
+			stop,
+			isProfiling,
+		};
+	}
+
+	export default Profiler;
+}
@@ -0,0 +1,36 @@
+declare module "signals" {
+	export type Signal = "SIGHUP" | "SIGINT" | "SIGQUIT" | "SIGTERM" | "SIGUSR1" | "SIGUSR2" | "SIGALRM" | "SIGCHLD" | "SIGPIPE" | "SIGWINCH";
+
+	export class SignalListener implements AsyncIterable<Signal> {
+		private constructor();
+
+		get signal(): Signal;
+		get closed(): boolean;
+
+		recv(): Promise<boolean>;
+		close(): void;
+
+		[Symbol.asyncIterator](): AsyncGenerator<Signal, void, undefined>;
+	}
+
+	/** Only exported on Unix. */
+	export const Signal: undefined | Readonly<Record<"HUP" | "INT" | "QUIT" | "TERM" | "USR1" | "USR2" | "ALRM" | "CHLD" | "PIPE" | "WINCH", number>> & {
+		readonly [signal: number]: string;
+	};
+
+	export function listen(signal: Signal): SignalListener;
+	export function addSignalListener(signal: Signal, callback: (signal: Signal) => void): void;
+	export function removeSignalListener(signal: Signal, callback: (signal: Signal) => void): void;
+
+	namespace Signals {
+		export {
+			listen,
+			addSignalListener,
+			removeSignalListener,
+			SignalListener,
+			Signal,
+		};
+	}
+
+	export default Signals;
+}
@@ -0,0 +1,53 @@
+declare module "sqlite" {
+	export type SqlValue = null | number | bigint | string | Uint8Array;
+	export type Params = SqlValue[] | Record<string, SqlValue | boolean | undefined>;
+	export type Row = Record<string, SqlValue>;
+
+	export interface OpenOptions {
+		readonly?: boolean;
+		create?: boolean;
+		wal?: boolean;
+		timeout?: number;
+	}
+
+	export interface RunResult {
+		changes: number;
+		lastInsertRowid: number | bigint;
+	}
+
+	export class Database {
+		constructor(path: string, options?: OpenOptions);
+
+		get path(): string;
+
+		exec(sql: string): Promise<void>;
+		run(sql: string, params?: Params): Promise<RunResult>;
+		all(sql: string, params?: Params): Promise<Row[]>;
+		get(sql: string, params?: Params): Promise<Row | null>;
+		prepare(sql: string): Statement;
+		transaction<T>(callback: (database: Database) => T | Promise<T>): Promise<T>;
+		close(): Promise<void>;
+	}
+
+	export class Statement {
+		private constructor();
+
+		get source(): string;
+
+		run(params?: Params): Promise<RunResult>;
+		all(params?: Params): Promise<Row[]>;
+		get(params?: Params): Promise<Row | null>;
+	}
+
+	export function open(path: string, options?: OpenOptions): Database;
+
+	namespace Sqlite {
+		export {
+			open,
+			Database,
+			Statement,
+		};
+	}
+
+	export default Sqlite;
+}
@@ -0,0 +1,172 @@
+declare module "stream" {
+	import {Buffer, Encoding} from "buffer";
+
+	export interface StreamOptions {
+		destroy?(this: Stream, error: Error | null, callback: (error?: Error | null) => void): void;
+		autoDestroy?: boolean;
+		objectMode?: boolean;
+		highWaterMark?: number;
+	}
+
+	export interface ReadableOptions extends StreamOptions {
+		read?(this: Readable, size: number): void;
+		encoding?: string;
+		readableObjectMode?: boolean;
+		readableHighWaterMark?: number;
+	}
+
+	export interface WritableOptions extends StreamOptions {
+		write?(this: Writable, chunk: any, encoding: Encoding | "buffer", callback: (error?: Error | null) => void): void;
+		final?(this: Writable, callback: (error?: Error | null) => void): void;
+		decodeStrings?: boolean;
+		defaultEncoding?: Encoding;
+		writableObjectMode?: boolean;
+		writableHighWaterMark?: number;
+	}
+
+	export interface DuplexOptions extends ReadableOptions, WritableOptions {
+		allowHalfOpen?: boolean;
+	}
+
+	export interface TransformOptions extends DuplexOptions {
+		transform?(this: Transform, chunk: any, encoding: Encoding | "buffer", callback: TransformCallback): void;
+		flush?(this: Transform, callback: TransformCallback): void;
+	}
+
+	export type TransformCallback = (error?: Error | null, data?: any) => void;
+
+	export class Stream {
+		constructor(options?: StreamOptions);
+
+		get destroyed(): boolean;
+
+		on(event: string | symbol, listener: (...args: any[]) => void): this;
+		addListener(event: string | symbol, listener: (...args: any[]) => void): this;
+		once(event: string | symbol, listener: (...args: any[]) => void): this;
+		off(event: string | symbol, listener: (...args: any[]) => void): this;
+		removeListener(event: string | symbol, listener: (...args: any[]) => void): this;
+		removeAllListeners(event?: string | symbol): this;
+		emit(event: string | symbol, ...args: any[]): boolean;
+		listenerCount(event: string | symbol): number;
+
+		destroy(error?: Error, callback?: (error?: Error | null) => void): this;
+		_destroy(error: Error | null, callback: (error?: Error | null) => void): void;
+	}
+
+	export class Readable extends Stream implements AsyncIterable<any> {
+		constructor(options?: ReadableOptions);
+
+		get readable(): boolean;
+		get readableEnded(): boolean;
+		get readableFlowing(): boolean | null;
+		get readableLength(): number;
+		get readableHighWaterMark(): number;
+		get readableObjectMode(): boolean;
+
+		_read(size: number): void;
+		push(chunk: any, encoding?: Encoding): boolean;
+		unshift(chunk: any, encoding?: Encoding): void;
+		read(): any;
+		setEncoding(encoding?: string): this;
+		pause(): this;
+		resume(): this;
+		isPaused(): boolean;
+		pipe<T extends Writable>(destination: T, options?: { end?: boolean }): T;
+		unpipe(destination?: Writable): this;
+
+		[Symbol.asyncIterator](): AsyncIterator<any>;
+
+		static from(iterable: Iterable<any> | AsyncIterable<any>, options?: ReadableOptions): Readable;
+		static fromWeb(readableStream: ReadableStream, options?: ReadableOptions): Readable;
+		static toWeb(readable: Readable): ReadableStream<Uint8Array>;
+	}
+
+	export class Writable extends Stream {
+		constructor(options?: WritableOptions);
+
+		get writable(): boolean;
+		get writableEnded(): boolean;
+		get writableFinished(): boolean;
+		get writableLength(): number;
+		get writableHighWaterMark(): number;
+		get writableObjectMode(): boolean;
+		get writableNeedDrain(): boolean;
+		get writableCorked(): number;
+
+		_write(chunk: any, encoding: Encoding | "buffer", callback: (error?: Error | null) => void): void;
+		_final(callback: (error?: Error | null) => void): void;
+		write(chunk: any, callback?: (error?: Error | null) => void): boolean;
+		write(chunk: any, encoding?: Encoding, callback?: (error?: Error | null) => void): boolean;
+		end(callback?: () => void): this;
+		end(chunk: any, callback?: () => void): this;
+		end(chunk: any, encoding?: Encoding, callback?: () => void): this;
+		cork(): void;
+		uncork(): void;
+		setDefaultEncoding(encoding: Encoding): this;
+
+		static fromWeb(writableStream: any, options?: WritableOptions): Writable;
+		static toWeb(writable: Writable): any;
+	}
+
+	export class Duplex extends Readable implements Writable {
+		constructor(options?: DuplexOptions);
+
+		get writable(): boolean;
+		get writableEnded(): boolean;
+		get writableFinished(): boolean;
+		get writableLength(): number;
+		get writableHighWaterMark(): number;
+		get writableObjectMode(): boolean;
+		get writableNeedDrain(): boolean;
+		get writableCorked(): number;
+
+		_write(chunk: any, encoding: Encoding | "buffer", callback: (error?: Error | null) => void): void;
+		_final(callback: (error?: Error | null) => void): void;
+		write(chunk: any, callback?: (error?: Error | null) => void): boolean;
+		write(chunk: any, encoding?: Encoding, callback?: (error?: Error | null) => void): boolean;
+		end(callback?: () => void): this;
+		end(chunk: any, callback?: () => void): this;
+		end(chunk: any, encoding?: Encoding, callback?: () => void): this;
+		cork(): void;
+		uncork(): void;
+		setDefaultEncoding(encoding: Encoding): this;
+
+		static from(value: Duplex | { readable: ReadableStream, writable: any }): Duplex;
+		static fromWeb(pair: { readable: ReadableStream, writable: any }, options?: DuplexOptions): Duplex;
+		static toWeb(duplex: Duplex): { readable: ReadableStream<Uint8Array>, writable: any };
+	}
+
+	export class Transform extends Duplex {
+		constructor(options?: TransformOptions);
+
+		_transform(chunk: any, encoding: Encoding | "buffer", callback: TransformCallback): void;
+		_flush?(callback: TransformCallback): void;
+	}
+
+	export class PassThrough extends Transform {}
+
+	export function finished(stream: Stream, callback: (error?: Error) => void): () => void;
+	export function pipeline<T extends Stream>(...streams: [Readable, ...Stream[], T]): T;
+	export function pipeline<T extends Stream>(...streams: [Readable, ...Stream[], T, (error?: Error) => void]): T;
+
+	export const promises: {
+		finished(stream: Stream): Promise<void>;
+		pipeline(...streams: Stream[]): Promise<void>;
+	};
+
+	namespace StreamModule {
+		export {
+			Stream,
+			Readable,
+			Writable,
+			Duplex,
+			Transform,
+			PassThrough,
+			finished,
+			pipeline,
+			promises,
+		};
+	}
+
+	export default StreamModule;
+}
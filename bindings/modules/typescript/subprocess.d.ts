@@ -0,0 +1,54 @@
+declare module "subprocess" {
+	export type Stdio = "inherit" | "piped" | "null";
+
+	export interface SpawnOptions {
+		args?: string[];
+		cwd?: string;
+		env?: Record<string, string>;
+		clearEnv?: boolean;
+		stdin?: Stdio;
+		stdout?: Stdio;
+		stderr?: Stdio;
+	}
+
+	export interface ProcessStatus {
+		success: boolean;
+		code: number | null;
+		signal: number | null;
+	}
+
+	export interface ProcessOutput {
+		status: ProcessStatus;
+		stdout: Uint8Array;
+		stderr: Uint8Array;
+	}
+
+	export class Subprocess {
+		private constructor();
+
+		get pid(): number | null;
+		get stdout(): ReadableStream<Uint8Array>;
+		get stderr(): ReadableStream<Uint8Array>;
+
+		status(): Promise<ProcessStatus>;
+		kill(signal?: string): void;
+
+		readStdout(): Promise<Uint8Array | null>;
+		readStderr(): Promise<Uint8Array | null>;
+		writeStdin(data: BufferSource): Promise<void>;
+		closeStdin(): Promise<void>;
+	}
+
+	export function spawn(program: string, options?: SpawnOptions): Subprocess;
+
+	export function output(program: string, options?: SpawnOptions): Promise<ProcessOutput>;
+
+	namespace Subprocess {
+		export {
+			spawn,
+			output,
+		};
+	}
+
+	export default Subprocess;
+}
@@ -0,0 +1,42 @@
+declare module "tty" {
+	export interface KeyEvent {
+		key: string;
+		ctrl: boolean;
+		alt: boolean;
+		shift: boolean;
+		meta: boolean;
+	}
+
+	export interface TerminalSize {
+		columns: number;
+		rows: number;
+	}
+
+	export function prompt(message?: string, defaultValue?: string): Promise<string | null>;
+	export function confirm(message?: string): Promise<boolean>;
+	export function password(message?: string): Promise<string>;
+	export function readKey(): Promise<KeyEvent>;
+	export function setRawMode(enabled: boolean): void;
+	export function isRawMode(): boolean;
+	export function keys(): AsyncGenerator<KeyEvent, void, undefined>;
+	export function size(): TerminalSize;
+	export function isatty(fd: 0 | 1 | 2): boolean;
+	export function supportsColor(): boolean;
+
+	namespace Tty {
+		export {
+			prompt,
+			confirm,
+			password,
+			readKey,
+			setRawMode,
+			isRawMode,
+			keys,
+			size,
+			isatty,
+			supportsColor,
+		};
+	}
+
+	export default Tty;
+}
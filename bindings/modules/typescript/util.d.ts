@@ -0,0 +1,52 @@
+declare module "util" {
+	export interface InspectOptions {
+		depth?: number;
+		indentation?: number;
+		colors?: boolean;
+		multiline?: boolean;
+	}
+
+	export function inspect(value: any, options?: InspectOptions): string;
+	export function format(...values: any[]): string;
+
+	export function promisify<T>(original: (...args: [...any[], (error: any, value: T) => void]) => void): (...args: any[]) => Promise<T>;
+	export function callbackify<T>(original: (...args: any[]) => Promise<T>): (...args: [...any[], (error: any, value?: T) => void]) => void;
+
+	export namespace types {
+		function isPromise(value: any): value is Promise<any>;
+		function isMap(value: any): value is Map<any, any>;
+		function isSet(value: any): value is Set<any>;
+		function isMapIterator(value: any): boolean;
+		function isSetIterator(value: any): boolean;
+		function isDate(value: any): value is Date;
+		function isRegExp(value: any): value is RegExp;
+		function isNativeError(value: any): value is Error;
+		function isArgumentsObject(value: any): value is IArguments;
+		function isArrayBuffer(value: any): value is ArrayBuffer;
+		function isSharedArrayBuffer(value: any): value is SharedArrayBuffer;
+		function isAnyArrayBuffer(value: any): value is ArrayBuffer | SharedArrayBuffer;
+		function isArrayBufferView(value: any): value is ArrayBufferView;
+		function isBoxedPrimitive(value: any): boolean;
+		function isProxy(value: any): boolean;
+		function isAsyncFunction(value: any): boolean;
+		function isGeneratorFunction(value: any): boolean;
+		function isGeneratorObject(value: any): boolean;
+		function isWeakMap(value: any): value is WeakMap<any, any>;
+		function isWeakSet(value: any): value is WeakSet<any>;
+		function isTypedArray(value: any): boolean;
+		function isUint8Array(value: any): value is Uint8Array;
+		function isDataView(value: any): value is DataView;
+	}
+
+	namespace Util {
+		export {
+			inspect,
+			format,
+			promisify,
+			callbackify,
+			types,
+		};
+	}
+
+	export default Util;
+}
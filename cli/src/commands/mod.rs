@@ -5,7 +5,7 @@
  */
 
 use runtime::cache::Cache;
-use runtime::config::{Config, LogLevel, CONFIG};
+use runtime::config::{Config, LogFormat, LogLevel, CONFIG};
 
 use crate::{Cli, Command};
 
@@ -27,11 +27,31 @@ pub(crate) async fn handle_command(cli: Cli) {
 		}
 
 		Some(Command::Eval { source }) => {
-			CONFIG.set(Config::default().log_level(LogLevel::Debug).script(true)).unwrap();
+			CONFIG
+				.set(Config::default().log_level(LogLevel::Debug).script(true).allow_env(true))
+				.unwrap();
 			eval::eval_source(&source).await;
 		}
 
-		Some(Command::Run { path, log_level, debug, script }) => {
+		Some(Command::Run {
+			path,
+			log_level,
+			debug,
+			log_format,
+			script,
+			allow_env,
+			unstable,
+			inspect_depth,
+			inspect_array_length,
+			locale,
+			microtask_budget,
+			off_thread_compile,
+			env_file,
+			env_override,
+			prof,
+			heap_prof,
+			args,
+		}) => {
 			let log_level = if debug {
 				LogLevel::Debug
 			} else {
@@ -44,13 +64,46 @@ pub(crate) async fn handle_command(cli: Cli) {
 					_ => panic!("Invalid Logging Level"),
 				}
 			};
+			let log_format = match log_format.to_uppercase().as_str() {
+				"TEXT" => LogFormat::Text,
+				"JSON" => LogFormat::Json,
+				_ => panic!("Invalid Logging Format"),
+			};
+
+			let config = Config::default()
+				.log_level(log_level)
+				.log_format(log_format)
+				.script(script)
+				.allow_env(allow_env)
+				.unstable(unstable)
+				.inspect_depth(inspect_depth)
+				.inspect_array_length(inspect_array_length)
+				.locale(locale)
+				.microtask_budget(microtask_budget)
+				.off_thread_compile(off_thread_compile)
+				.env_files(env_file)
+				.env_override(env_override)
+				.profile(prof)
+				.heap_profile(heap_prof)
+				.args(args);
+			CONFIG.set(config).unwrap();
 
-			CONFIG.set(Config::default().log_level(log_level).script(script)).unwrap();
-			run::run(&path).await;
+			let config = Config::global();
+			if let Err(err) = runtime::env::load_env_files(&config.env_files, config.env_override) {
+				eprintln!("{}", err);
+				std::process::exit(1);
+			}
+
+			let code = run::run(&path).await;
+			if code != 0 {
+				std::process::exit(code);
+			}
 		}
 
 		Some(Command::Repl) | None => {
-			CONFIG.set(Config::default().log_level(LogLevel::Debug).script(true)).unwrap();
+			CONFIG
+				.set(Config::default().log_level(LogLevel::Debug).script(true).allow_env(true))
+				.unwrap();
 			repl::start_repl().await;
 		}
 	}
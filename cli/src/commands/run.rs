@@ -10,10 +10,10 @@ use runtime::config::Config;
 
 use crate::evaluate::{eval_module, eval_script};
 
-pub(crate) async fn run(path: &str) {
+pub(crate) async fn run(path: &str) -> i32 {
 	if Config::global().script {
-		eval_script(Path::new(path)).await;
+		eval_script(Path::new(path)).await
 	} else {
-		eval_module(Path::new(path)).await;
+		eval_module(Path::new(path)).await
 	}
 }
@@ -7,32 +7,185 @@
 use std::ffi::OsStr;
 use std::fs::read_to_string;
 use std::io::ErrorKind;
+use std::iter::Peekable;
 use std::path::Path;
+use std::str::CharIndices;
 
+use humansize::{SizeFormatter, BINARY};
+use ion::conversions::FromValue;
 use ion::format::{format_value, Config as FormatConfig};
 use ion::module::Module;
-use ion::script::Script;
-use ion::Context;
+use ion::profiler::{self, Weight};
+use ion::script::{off_thread_statistics, CompileOptions, Script};
+use ion::{Context, ErrorReport, Exception, Promise, Value};
 use modules::Modules;
 use mozjs::rust::{JSEngine, Runtime as RustRuntime};
 use runtime::cache::locate_in_cache;
 use runtime::cache::map::{save_sourcemap, transform_error_report_with_sourcemaps};
-use runtime::config::Config;
+use runtime::config::{Config, LogLevel};
 use runtime::module::Loader;
 use runtime::{Runtime, RuntimeBuilder};
 use sourcemap::SourceMap;
 
 pub(crate) async fn eval_inline(rt: &Runtime<'_>, source: &str) {
-	let result = Script::compile_and_evaluate(rt.cx(), Path::new("inline.js"), source);
+	let cx = rt.cx();
+	let path = Path::new("inline.js");
 
-	match result {
-		Ok(v) => println!("{}", format_value(rt.cx(), FormatConfig::default().quoted(true), &v)),
-		Err(report) => eprintln!("{}", report.format(rt.cx())),
+	let mut script = Script::compile(cx, path, source);
+	let mut is_async = false;
+	if script.is_err() && source.contains("await") {
+		if let Some(wrapped) = wrap_await(source).iter().find_map(|source| Script::compile(cx, path, source).ok()) {
+			script = Ok(wrapped);
+			is_async = true;
+		}
+	}
+
+	match script.and_then(|script| script.evaluate(cx)) {
+		Ok(value) if is_async => {
+			let promise = Promise::from_value(cx, &value, true, ()).unwrap();
+			promise.catch(cx, |cx, _| Ok(Value::undefined(cx)));
+			run_event_loop(rt).await;
+			if promise.is_fulfilled() {
+				let result = promise.result(cx);
+				println!("{}", format_value(cx, FormatConfig::default().quoted(true), &result));
+			} else if promise.is_rejected() {
+				eprintln!("{}", format_rejection(cx, &promise));
+			}
+			return;
+		}
+		Ok(v) => println!("{}", format_value(cx, FormatConfig::default().quoted(true), &v)),
+		Err(report) => eprintln!("{}", report.format(cx)),
 	}
 	run_event_loop(rt).await;
 }
 
-pub(crate) async fn eval_script(path: &Path) {
+/// Wraps an input of the REPL which uses `await` in async functions, as it cannot be compiled as a script otherwise.
+///
+/// The first candidate returns the value of an expression, and the second runs statements. Declarations at the top
+/// level of the input are turned into assignments, so their variables are assigned to the global object instead, and
+/// remain accessible from later inputs. Variables of destructuring declarations are assigned as in sloppy mode.
+/// The last candidate runs the unmodified statements, in case the rewritten input does not compile.
+fn wrap_await(source: &str) -> [String; 3] {
+	let statements: Vec<_> = split_statements(source.trim_end_matches(';'))
+		.into_iter()
+		.map(|statement| declaration_to_assignment(&statement).unwrap_or(statement))
+		.collect();
+	let rewritten = statements.join(";");
+	[
+		format!("(async () => (\n{rewritten}\n))()"),
+		format!("(async () => {{\n{rewritten}\n}})()"),
+		format!("(async () => {{\n{source}\n}})()"),
+	]
+}
+
+/// Splits the source into top-level statements, at each `;` and at each line which starts a declaration.
+fn split_statements(source: &str) -> Vec<String> {
+	let mut statements = Vec::new();
+	for statement in split_top_level(source, ';') {
+		let mut current: Option<String> = None;
+		for line in split_top_level(statement, '\n') {
+			match &mut current {
+				Some(statement) if declarators(line).is_none() => {
+					statement.push('\n');
+					statement.push_str(line);
+				}
+				_ => statements.extend(current.replace(String::from(line))),
+			}
+		}
+		statements.extend(current);
+	}
+	statements
+}
+
+/// Returns the declarators of a `const`, `let` or `var` declaration, or [None] if the statement is not a declaration.
+fn declarators(statement: &str) -> Option<&str> {
+	let statement = statement.trim_start();
+	["const", "let", "var"].iter().find_map(|keyword| {
+		let rest = statement.strip_prefix(keyword)?;
+		rest.starts_with(|c: char| c.is_whitespace() || c == '{' || c == '[').then_some(rest)
+	})
+}
+
+/// Converts a `const`, `let` or `var` declaration into assignments of each of its declarators.
+/// Returns [None] if the statement is not a declaration.
+fn declaration_to_assignment(statement: &str) -> Option<String> {
+	let declarators = declarators(statement)?;
+
+	let assignments: Option<Vec<_>> = split_top_level(declarators, ',')
+		.into_iter()
+		.map(|declarator| {
+			let target = split_top_level(declarator, '=')[0];
+			let value = declarator.get(target.len() + 1..);
+			let target = target.trim();
+			let is_identifier = !target.is_empty()
+				&& !target.starts_with(|c: char| c.is_ascii_digit())
+				&& target.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+			match value {
+				_ if is_identifier => Some(format!("globalThis.{target} ={}", value.unwrap_or(" undefined"))),
+				Some(value) if target.starts_with(['{', '[']) => Some(format!("({target} ={value})")),
+				_ => None,
+			}
+		})
+		.collect();
+	Some(assignments?.join(", "))
+}
+
+/// Splits the source at each `separator` which is not nested within brackets, strings, templates or comments.
+fn split_top_level(source: &str, separator: char) -> Vec<&str> {
+	let mut parts = Vec::new();
+	let mut start = 0;
+	let mut depth = 0usize;
+	let mut chars = source.char_indices().peekable();
+	while let Some((index, char)) = chars.next() {
+		match char {
+			'(' | '[' | '{' => depth += 1,
+			')' | ']' | '}' => depth = depth.saturating_sub(1),
+			'"' | '\'' | '`' => skip_string(&mut chars, char),
+			'/' if chars.next_if(|(_, c)| *c == '/').is_some() => while chars.next_if(|(_, c)| *c != '\n').is_some() {},
+			'/' if chars.next_if(|(_, c)| *c == '*').is_some() => {
+				while let Some((_, char)) = chars.next() {
+					if char == '*' && chars.next_if(|(_, c)| *c == '/').is_some() {
+						break;
+					}
+				}
+			}
+			char if char == separator && depth == 0 => {
+				parts.push(&source[start..index]);
+				start = index + char.len_utf8();
+			}
+			_ => {}
+		}
+	}
+	parts.push(&source[start..]);
+	parts
+}
+
+/// Skips the rest of a string or template literal, including any substitutions of the template.
+fn skip_string(chars: &mut Peekable<CharIndices>, quote: char) {
+	while let Some((_, char)) = chars.next() {
+		match char {
+			'\\' => {
+				chars.next();
+			}
+			'$' if quote == '`' && chars.next_if(|(_, c)| *c == '{').is_some() => {
+				let mut depth = 1;
+				while let Some((_, char)) = chars.next() {
+					match char {
+						'{' => depth += 1,
+						'}' if depth == 1 => break,
+						'}' => depth -= 1,
+						'"' | '\'' | '`' => skip_string(chars, char),
+						_ => {}
+					}
+				}
+			}
+			char if char == quote => return,
+			_ => {}
+		}
+	}
+}
+
+pub(crate) async fn eval_script(path: &Path) -> i32 {
 	let engine = JSEngine::init().unwrap();
 	let rt = RustRuntime::new(engine.handle());
 
@@ -42,15 +195,26 @@ pub(crate) async fn eval_script(path: &Path) {
 		.macrotask_queue()
 		.standard_modules(Modules)
 		.build(cx);
+	start_profiler(&rt);
 
 	if let Some((script, _)) = read_script(path) {
 		let (script, sourcemap) = cache(path, script);
-		if let Some(sourcemap) = sourcemap {
-			save_sourcemap(path, sourcemap);
-		}
-		let result = Script::compile_and_evaluate(rt.cx(), path, &script);
+		let result = if Config::global().off_thread_compile {
+			let options = CompileOptions::new(path.to_str().unwrap());
+			let script = Script::compile_off_thread(rt.cx(), script, options);
+			if let Some(sourcemap) = sourcemap {
+				save_sourcemap(path, sourcemap);
+			}
+			script.await.and_then(|script| script.evaluate(rt.cx()))
+		} else {
+			if let Some(sourcemap) = sourcemap {
+				save_sourcemap(path, sourcemap);
+			}
+			Script::compile_and_evaluate(rt.cx(), path, &script)
+		};
 
 		match result {
+			_ if rt.is_exiting() => {}
 			Ok(v) => println!("{}", format_value(rt.cx(), FormatConfig::default().quoted(true), &v)),
 			Err(mut report) => {
 				transform_error_report_with_sourcemaps(&mut report);
@@ -58,10 +222,15 @@ pub(crate) async fn eval_script(path: &Path) {
 			}
 		}
 		run_event_loop(&rt).await;
+		write_profile(&rt);
+		print_off_thread_statistics(&rt);
+		rt.exit_code()
+	} else {
+		1
 	}
 }
 
-pub(crate) async fn eval_module(path: &Path) {
+pub(crate) async fn eval_module(path: &Path) -> i32 {
 	let engine = JSEngine::init().unwrap();
 	let rt = RustRuntime::new(engine.handle());
 
@@ -72,19 +241,79 @@ pub(crate) async fn eval_module(path: &Path) {
 		.modules(Loader::default())
 		.standard_modules(Modules)
 		.build(cx);
+	start_profiler(&rt);
 
 	if let Some((script, filename)) = read_script(path) {
 		let (script, sourcemap) = cache(path, script);
-		if let Some(sourcemap) = sourcemap {
-			save_sourcemap(path, sourcemap);
-		}
-		let result = Module::compile_and_evaluate(rt.cx(), &filename, Some(path), &script);
+		let result = if Config::global().off_thread_compile {
+			let module = Module::compile_and_evaluate_off_thread(rt.cx(), &filename, Some(path), script);
+			if let Some(sourcemap) = sourcemap {
+				save_sourcemap(path, sourcemap);
+			}
+			module.await
+		} else {
+			if let Some(sourcemap) = sourcemap {
+				save_sourcemap(path, sourcemap);
+			}
+			Module::compile_and_evaluate(rt.cx(), &filename, Some(path), &script)
+		};
 
-		if let Err(mut error) = result {
-			transform_error_report_with_sourcemaps(&mut error.report);
-			eprintln!("{}", error.format(rt.cx()));
+		let promise = match result {
+			Ok((_, promise)) => promise,
+			Err(mut error) => {
+				if !rt.is_exiting() {
+					transform_error_report_with_sourcemaps(&mut error.report);
+					eprintln!("{}", error.format(rt.cx()));
+				}
+				None
+			}
+		};
+		// Rejections of the evaluation promise are reported once the event loop completes.
+		if let Some(promise) = &promise {
+			promise.catch(rt.cx(), |cx, _| Ok(Value::undefined(cx)));
 		}
 		run_event_loop(&rt).await;
+		write_profile(&rt);
+		print_off_thread_statistics(&rt);
+		match promise {
+			Some(promise) if !rt.is_exiting() => module_exit_code(&rt, &promise),
+			_ => rt.exit_code(),
+		}
+	} else {
+		1
+	}
+}
+
+/// Returns the exit code of the entry module from its evaluation promise, once the event loop has completed.
+///
+/// If the module threw, the error is reported and the exit code is 1, unless `process.exitCode` was set.
+/// If its top-level await never settled, nothing remains that could settle it, so the exit code is 13.
+fn module_exit_code(rt: &Runtime<'_>, promise: &Promise) -> i32 {
+	let cx = rt.cx();
+	if promise.is_rejected() {
+		eprintln!("{}", format_rejection(cx, promise));
+		match rt.exit_code() {
+			0 => 1,
+			code => code,
+		}
+	} else if promise.is_pending() {
+		eprintln!("Top-level await did not settle before the event loop completed");
+		13
+	} else {
+		rt.exit_code()
+	}
+}
+
+/// Formats the reason of a rejected [Promise] as an uncaught error, transformed with sourcemaps if it is an error.
+fn format_rejection(cx: &Context, promise: &Promise) -> String {
+	let result = promise.result(cx);
+	match Exception::from_value(cx, &result) {
+		Ok(exception) => {
+			let mut report = ErrorReport::from_exception_with_error_stack(cx, exception);
+			transform_error_report_with_sourcemaps(&mut report);
+			report.format(cx)
+		}
+		Err(_) => format!("Uncaught {}", format_value(cx, FormatConfig::default(), &result)),
 	}
 }
 
@@ -116,6 +345,64 @@ async fn run_event_loop(rt: &Runtime<'_>) {
 	}
 }
 
+fn start_profiler(rt: &Runtime<'_>) {
+	let config = Config::global();
+	if config.heap_profile.is_some() {
+		if !profiler::start_heap_profiling(rt.cx(), profiler::DEFAULT_INTERVAL) {
+			eprintln!("Failed to start tracking allocations");
+		}
+	} else if config.profile.is_some() {
+		profiler::start_profiling(rt.cx(), profiler::DEFAULT_INTERVAL);
+	}
+}
+
+fn write_profile(rt: &Runtime<'_>) {
+	let config = Config::global();
+	let Some(profile) = profiler::stop_profiling(rt.cx()) else {
+		return;
+	};
+
+	if let Some(path) = &config.profile {
+		match profile.write(path, Weight::Time) {
+			Ok(()) => eprintln!(
+				"Wrote CPU profile with {} samples to {}",
+				profile.samples.len(),
+				path.display()
+			),
+			Err(err) => eprintln!("Failed to write CPU profile to {}: {}", path.display(), err),
+		}
+	}
+	if let Some(path) = &config.heap_profile {
+		match profile.write(path, Weight::Allocations) {
+			Ok(()) => {
+				let allocated: u64 = profile.allocations.iter().map(|allocation| allocation.bytes).sum();
+				let peak = profile.object_counts.iter().map(|count| count.objects).max().unwrap_or_default();
+				eprintln!(
+					"Wrote heap profile with {} allocations of {} to {}, with a peak of {} live objects",
+					profile.allocations.len(),
+					SizeFormatter::new(allocated, BINARY),
+					path.display(),
+					peak
+				);
+			}
+			Err(err) => eprintln!("Failed to write heap profile to {}: {}", path.display(), err),
+		}
+	}
+}
+
+fn print_off_thread_statistics(rt: &Runtime<'_>) {
+	let config = Config::global();
+	if config.off_thread_compile && config.log_level == LogLevel::Debug {
+		let statistics = off_thread_statistics(rt.cx());
+		eprintln!(
+			"Compiled {} source(s) off-thread in {:.3}ms, {:.3}ms overlapped with other work",
+			statistics.compilations,
+			statistics.compile_time.as_secs_f64() * 1000.0,
+			statistics.saved_time().as_secs_f64() * 1000.0,
+		);
+	}
+}
+
 fn cache(path: &Path, script: String) -> (String, Option<SourceMap>) {
 	let is_typescript = Config::global().typescript && path.extension() == Some(OsStr::new("ts"));
 	is_typescript
@@ -124,3 +411,66 @@ fn cache(path: &Path, script: String) -> (String, Option<SourceMap>) {
 		.map(|(s, sm)| (s, Some(sm)))
 		.unwrap_or_else(|| (script, None))
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::evaluate::{split_top_level, wrap_await};
+
+	#[test]
+	fn declarations() {
+		let cases = [
+			("const a = await b;", "globalThis.a = await b"),
+			(
+				"let a = await b, c, d = 1",
+				"globalThis.a = await b, globalThis.c = undefined, globalThis.d = 1",
+			),
+			("const { a, b: [c] = [] } = await d", "({ a, b: [c] = [] } = await d)"),
+			(
+				"var [a, , ...b] = await c, d = 1",
+				"([a, , ...b] = await c), globalThis.d = 1",
+			),
+			(
+				"const f = async () => await g(1, 2)",
+				"globalThis.f = async () => await g(1, 2)",
+			),
+			("let a = await b; a + 1", "globalThis.a = await b; a + 1"),
+			("letter = await a", "letter = await a"),
+			("if (a) { let b = await c; }", "if (a) { let b = await c; }"),
+			("const r = await f(/;/)", "globalThis.r = await f(/;/)"),
+			(
+				"const a = await b\nconst c = a +\n1\nlet d",
+				"globalThis.a = await b;globalThis.c = a +\n1;globalThis.d = undefined",
+			),
+			(
+				"const a = await b,\n\tc = `\nlet d`",
+				"globalThis.a = await b, globalThis.c = `\nlet d`",
+			),
+		];
+		for (source, expected) in cases {
+			let [expression, statements, unmodified] = wrap_await(source);
+			assert_eq!(expression, format!("(async () => (\n{expected}\n))()"));
+			assert_eq!(statements, format!("(async () => {{\n{expected}\n}})()"));
+			assert_eq!(unmodified, format!("(async () => {{\n{source}\n}})()"));
+		}
+	}
+
+	#[test]
+	fn nesting() {
+		assert_eq!(
+			split_top_level("a, (b, c), [d, e], { f, g }", ','),
+			["a", " (b, c)", " [d, e]", " { f, g }"]
+		);
+		assert_eq!(
+			split_top_level(r#"'a,b', "c\",d", e"#, ','),
+			[r#"'a,b'"#, r#" "c\",d""#, " e"]
+		);
+		assert_eq!(
+			split_top_level("`a,${[b, `c,${d}`]},e`, f", ','),
+			["`a,${[b, `c,${d}`]},e`", " f"]
+		);
+		assert_eq!(
+			split_top_level("a /* b, c */, d // e, f", ','),
+			["a /* b, c */", " d // e, f"]
+		);
+	}
+}
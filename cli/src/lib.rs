@@ -4,6 +4,8 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
+use std::path::PathBuf;
+
 use clap::{Parser, Subcommand};
 use commands::handle_command;
 use tokio::task::LocalSet;
@@ -57,8 +59,82 @@ pub(crate) enum Command {
 		#[arg(help = "Sets logging level to DEBUG", short, long)]
 		debug: bool,
 
+		#[arg(
+			help = "Sets the format of console output, either TEXT or JSON, Default: TEXT",
+			long,
+			required(false),
+			default_value = "TEXT"
+		)]
+		log_format: String,
+
 		#[arg(help = "Disables ES Modules Features", short, long)]
 		script: bool,
+
+		#[arg(help = "Allows access to environment variables", long)]
+		allow_env: bool,
+
+		#[arg(help = "Enables unstable APIs", long)]
+		unstable: bool,
+
+		#[arg(
+			help = "Sets the depth of nested objects printed by the console",
+			long,
+			default_value_t = 4
+		)]
+		inspect_depth: u16,
+
+		#[arg(
+			help = "Sets the number of array elements printed by the console",
+			long,
+			default_value_t = 100
+		)]
+		inspect_array_length: u32,
+
+		#[arg(help = "Sets the default locale used by Intl, such as 'en-GB'", long)]
+		locale: Option<String>,
+
+		#[arg(
+			help = "Sets the maximum number of microtasks run in each microtask checkpoint, 0 for no limit",
+			long,
+			default_value_t = 100_000
+		)]
+		microtask_budget: usize,
+
+		#[arg(
+			help = "Compiles the entry script or module on a separate thread, imports are compiled synchronously",
+			long
+		)]
+		off_thread_compile: bool,
+
+		#[arg(
+			help = "Loads environment variables from a dotenv file, without overriding existing ones",
+			long
+		)]
+		env_file: Vec<PathBuf>,
+
+		#[arg(help = "Overrides existing environment variables with those from '--env-file'", long)]
+		env_override: bool,
+
+		#[arg(
+			help = "Writes a CPU profile of the script as speedscope JSON, or as folded stacks to '.folded' files",
+			long,
+			value_name = "PATH",
+			num_args = 0..=1,
+			default_missing_value = "profile.json"
+		)]
+		prof: Option<PathBuf>,
+
+		#[arg(
+			help = "Writes a profile of the allocations of the script by stack, in the same formats as '--prof'",
+			long,
+			value_name = "PATH",
+			num_args = 0..=1,
+			default_missing_value = "heap-profile.json"
+		)]
+		heap_prof: Option<PathBuf>,
+
+		#[arg(help = "Arguments passed to the script, after '--'", last = true)]
+		args: Vec<String>,
 	},
 }
 
@@ -5,21 +5,79 @@
  */
 
 use syn::meta::ParseNestedMeta;
-use syn::{LitStr, Result};
+use syn::parse::{Parse, ParseStream};
+use syn::{LitStr, Result, Type};
 
-use crate::attribute::name::Name;
+use crate::attribute::name::{Name, RenameRule};
 use crate::attribute::{ArgumentError, ParseArgument, ParseArgumentWith, ParseAttribute};
 use crate::class::method::MethodKind;
+use crate::function::wrapper::IteratorKind;
 
 // TODO: Add `inspectable` to provide `toString` and `toJSON`
 #[derive(Default)]
 pub(crate) struct ClassAttribute {
 	pub(crate) name: Option<LitStr>,
+	pub(crate) extends: Option<Type>,
+	pub(crate) instances: Vec<ClassInstance>,
+	pub(crate) unstable: bool,
 }
 
 impl ParseAttribute for ClassAttribute {
 	fn parse(&mut self, meta: &ParseNestedMeta) -> Result<()> {
 		self.name.parse_argument(meta, "name", "Class")?;
+		self.extends.parse_argument(meta, "extends", "Class")?;
+		self.instances.parse_argument(meta, "instances", "Class")?;
+		self.unstable.parse_argument(meta, "unstable", "Class")?;
+
+		if self.name.is_some() && !self.instances.is_empty() {
+			return Err(meta.error("Class with `instances` attribute cannot have `name` attributes."));
+		}
+
+		Ok(())
+	}
+}
+
+/// Concrete instantiation of a generic class, written as `"Name" = Type<Arguments>`.
+pub(crate) struct ClassInstance {
+	pub(crate) name: LitStr,
+	pub(crate) ty: Type,
+}
+
+impl Parse for ClassInstance {
+	fn parse(input: ParseStream) -> Result<ClassInstance> {
+		let name = input.parse()?;
+		let _: Token![=] = input.parse()?;
+		let ty = input.parse()?;
+		Ok(ClassInstance { name, ty })
+	}
+}
+
+#[derive(Default)]
+pub(crate) struct ClassFieldAttribute {
+	pub(crate) name: Option<LitStr>,
+	pub(crate) accessor: bool,
+	pub(crate) readonly: bool,
+}
+
+impl ParseAttribute for ClassFieldAttribute {
+	fn parse(&mut self, meta: &ParseNestedMeta) -> Result<()> {
+		self.name.parse_argument(meta, "name", "Field")?;
+		self.accessor.parse_argument(meta, "accessor", "Field")?;
+		self.readonly.parse_argument(meta, "readonly", "Field")?;
+		Ok(())
+	}
+}
+
+#[derive(Default)]
+pub(crate) struct ClassImplAttribute {
+	pub(crate) rename_all: Option<RenameRule>,
+	pub(crate) instances: Vec<Type>,
+}
+
+impl ParseAttribute for ClassImplAttribute {
+	fn parse(&mut self, meta: &ParseNestedMeta) -> Result<()> {
+		self.rename_all.parse_argument(meta, "rename_all", "Class")?;
+		self.instances.parse_argument(meta, "instances", "Class")?;
 		Ok(())
 	}
 }
@@ -30,12 +88,18 @@ pub(crate) struct MethodAttribute {
 	pub(crate) alias: Vec<LitStr>,
 	pub(crate) kind: Option<MethodKind>,
 	pub(crate) skip: bool,
+	pub(crate) r#static: bool,
+	pub(crate) iterator: Option<IteratorKind>,
+	pub(crate) deprecated: Option<LitStr>,
+	pub(crate) unstable: bool,
 }
 
 impl ParseAttribute for MethodAttribute {
 	fn parse(&mut self, meta: &ParseNestedMeta) -> Result<()> {
 		const METHOD_KIND_ERROR: ArgumentError =
 			ArgumentError::Full("Method cannot have multiple `constructor`, `get`, or `set` attributes.");
+		const ITERATOR_ERROR: ArgumentError =
+			ArgumentError::Full("Method cannot have multiple `iterable`, or `async_iterable` attributes.");
 
 		self.name.parse_argument(meta, "name", "Method")?;
 		self.alias.parse_argument(meta, "alias", None)?;
@@ -44,6 +108,24 @@ impl ParseAttribute for MethodAttribute {
 		self.kind.parse_argument_with(meta, MethodKind::Getter, "get", METHOD_KIND_ERROR)?;
 		self.kind.parse_argument_with(meta, MethodKind::Setter, "set", METHOD_KIND_ERROR)?;
 		self.skip.parse_argument(meta, "skip", "Method")?;
+		self.r#static.parse_argument(meta, "static", "Method")?;
+		self.iterator.parse_argument_with(meta, IteratorKind::Sync, "iterable", ITERATOR_ERROR)?;
+		self.iterator
+			.parse_argument_with(meta, IteratorKind::Async, "async_iterable", ITERATOR_ERROR)?;
+		self.deprecated.parse_argument(meta, "deprecated", "Method")?;
+		self.unstable.parse_argument(meta, "unstable", "Method")?;
+
+		if self.r#static && self.kind == Some(MethodKind::Constructor) {
+			return Err(meta.error("Constructor cannot have `static` attribute."));
+		}
+		if self.unstable && self.kind == Some(MethodKind::Constructor) {
+			return Err(meta.error("Constructor cannot have `unstable` attribute."));
+		}
+		if self.iterator.is_some() && (self.kind.is_some() || self.name.is_some() || self.r#static) {
+			return Err(
+				meta.error("Iterable methods cannot have `constructor`, `get`, `set`, `name`, or `static` attributes.")
+			);
+		}
 
 		Ok(())
 	}
@@ -5,24 +5,52 @@
  */
 
 use syn::meta::ParseNestedMeta;
-use syn::{Expr, Result};
+use syn::{Expr, LitStr, Result};
 
-use crate::attribute::{ParseArgument, ParseAttribute};
+use crate::attribute::value::DefaultValue;
+use crate::attribute::{Optional, ParseArgument, ParseAttribute};
+
+#[derive(Default)]
+pub(crate) struct FunctionAttribute {
+	pub(crate) deprecated: Option<LitStr>,
+	pub(crate) unstable: bool,
+}
+
+impl ParseAttribute for FunctionAttribute {
+	fn parse(&mut self, meta: &ParseNestedMeta) -> Result<()> {
+		self.deprecated.parse_argument(meta, "deprecated", "Function")?;
+		self.unstable.parse_argument(meta, "unstable", "Function")?;
+		Ok(())
+	}
+}
 
 #[derive(Default)]
 pub(crate) struct ParameterAttribute {
 	pub(crate) this: bool,
 	pub(crate) convert: Option<Box<Expr>>,
+	pub(crate) default: Optional<DefaultValue>,
+	pub(crate) varargs: bool,
 }
 
 impl ParseAttribute for ParameterAttribute {
 	fn parse(&mut self, meta: &ParseNestedMeta) -> Result<()> {
 		self.this.parse_argument(meta, "this", "Parameter")?;
 		self.convert.parse_argument(meta, "convert", "Parameter")?;
+		self.default.parse_argument(meta, "default", "Parameter")?;
+		self.varargs.parse_argument(meta, "varargs", "Parameter")?;
 
 		if self.this && self.convert.is_some() {
 			return Err(meta.error("Parameter with `this` attribute cannot have `convert` attributes."));
 		}
+		if self.this && self.default.0.is_some() {
+			return Err(meta.error("Parameter with `this` attribute cannot have `default` attributes."));
+		}
+		if self.this && self.varargs {
+			return Err(meta.error("Parameter with `this` attribute cannot have `varargs` attributes."));
+		}
+		if self.varargs && self.default.0.is_some() {
+			return Err(meta.error("Parameter with `varargs` attribute cannot have `default` attributes."));
+		}
 
 		Ok(())
 	}
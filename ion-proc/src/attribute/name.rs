@@ -29,19 +29,13 @@ impl Name {
 
 	pub(crate) fn to_property_spec(&self, ion: &TokenStream, function: &mut Ident) -> (Box<Expr>, Box<Expr>) {
 		match self {
-			Name::String(literal) => {
-				let mut name = literal.value();
-				if name.is_case(Case::ScreamingSnake) {
-					name = name.to_case(Case::Camel)
-				}
-				(
-					Box::new(Expr::Lit(ExprLit {
-						attrs: Vec::new(),
-						lit: Lit::Str(LitStr::new(&name, literal.span())),
-					})),
-					parse_quote!(#ion::flags::PropertyFlags::CONSTANT_ENUMERATED),
-				)
-			}
+			Name::String(literal) => (
+				Box::new(Expr::Lit(ExprLit {
+					attrs: Vec::new(),
+					lit: Lit::Str(literal.clone()),
+				})),
+				parse_quote!(#ion::flags::PropertyFlags::CONSTANT_ENUMERATED),
+			),
 			Name::Symbol(symbol) => {
 				*function = format_ident!("{}_symbol", function);
 				(
@@ -72,3 +66,33 @@ impl Parse for Name {
 		}
 	}
 }
+
+/// Casing applied to Rust identifiers to produce their JavaScript names, as with `#[ion(rename_all = "...")]`.
+#[derive(Copy, Clone)]
+pub(crate) struct RenameRule(Case);
+
+impl RenameRule {
+	pub(crate) const KEBAB: RenameRule = RenameRule(Case::Kebab);
+
+	pub(crate) fn apply(&self, name: &str) -> String {
+		name.to_case(self.0)
+	}
+}
+
+impl Parse for RenameRule {
+	fn parse(input: ParseStream) -> syn::Result<RenameRule> {
+		let literal: LitStr = input.parse()?;
+		let case = match literal.value().as_str() {
+			"lowercase" => Case::Flat,
+			"UPPERCASE" => Case::UpperFlat,
+			"PascalCase" => Case::Pascal,
+			"camelCase" => Case::Camel,
+			"snake_case" => Case::Snake,
+			"SCREAMING_SNAKE_CASE" => Case::ScreamingSnake,
+			"kebab-case" => Case::Kebab,
+			"SCREAMING-KEBAB-CASE" => Case::Cobol,
+			_ => return Err(Error::new(literal.span(), "Unknown casing for `rename_all`")),
+		};
+		Ok(RenameRule(case))
+	}
+}
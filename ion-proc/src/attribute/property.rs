@@ -8,7 +8,7 @@ use syn::meta::ParseNestedMeta;
 use syn::{LitStr, Result};
 
 use crate::attribute::name::Name;
-use crate::attribute::{ParseArgument, ParseAttribute};
+use crate::attribute::{ArgumentError, ParseArgument, ParseAttribute};
 
 #[derive(Default)]
 pub(crate) struct PropertyAttribute {
@@ -16,6 +16,7 @@ pub(crate) struct PropertyAttribute {
 	pub(crate) alias: Vec<LitStr>,
 	pub(crate) skip: bool,
 	pub(crate) r#static: bool,
+	pub(crate) constant: bool,
 }
 
 impl ParseAttribute for PropertyAttribute {
@@ -23,7 +24,10 @@ impl ParseAttribute for PropertyAttribute {
 		self.name.parse_argument(meta, "name", "Property")?;
 		self.alias.parse_argument(meta, "alias", None)?;
 		self.skip.parse_argument(meta, "skip", "Property")?;
-		self.r#static.parse_argument(meta, "static", "Property")?;
+		const LOCATION_ERROR: &str = "Property cannot have multiple `static`, or `constant` attributes.";
+
+		self.r#static.parse_argument(meta, "static", ArgumentError::Full(LOCATION_ERROR))?;
+		self.constant.parse_argument(meta, "constant", ArgumentError::Full(LOCATION_ERROR))?;
 
 		Ok(())
 	}
@@ -7,7 +7,7 @@
 use syn::meta::ParseNestedMeta;
 use syn::parse::Result;
 
-use crate::attribute::{ParseArgument, ParseAttribute};
+use crate::attribute::{ArgumentError, ParseArgument, ParseAttribute};
 
 #[derive(Default)]
 pub(crate) struct TraceAttribute {
@@ -16,7 +16,10 @@ pub(crate) struct TraceAttribute {
 
 impl ParseAttribute for TraceAttribute {
 	fn parse(&mut self, meta: &ParseNestedMeta) -> Result<()> {
-		self.no_trace.parse_argument(meta, "no_trace", "Field")?;
+		const SKIP_ERROR: &str = "Field cannot have multiple `no_trace`, or `skip` attributes.";
+
+		self.no_trace.parse_argument(meta, "no_trace", ArgumentError::Full(SKIP_ERROR))?;
+		self.no_trace.parse_argument(meta, "skip", ArgumentError::Full(SKIP_ERROR))?;
 		Ok(())
 	}
 }
@@ -8,6 +8,7 @@ use syn::meta::ParseNestedMeta;
 use syn::parse::{Parse, ParseStream};
 use syn::{Expr, ExprClosure, Lit, LitStr, Result};
 
+use crate::attribute::name::RenameRule;
 use crate::attribute::{ArgumentError, Optional, ParseArgument, ParseArgumentWith, ParseAttribute};
 
 #[derive(Clone, Default)]
@@ -49,6 +50,7 @@ impl Parse for DefaultValue {
 pub(crate) struct DataAttribute {
 	pub(crate) tag: Optional<Tag>,
 	pub(crate) inherit: bool,
+	pub(crate) rename_all: Option<RenameRule>,
 }
 
 impl ParseAttribute for DataAttribute {
@@ -59,6 +61,7 @@ impl ParseAttribute for DataAttribute {
 			.parse_argument_with(meta, Tag::Untagged, "untagged", ArgumentError::Full(TAG_ERROR))?;
 		self.tag.parse_argument(meta, "tag", ArgumentError::Full(TAG_ERROR))?;
 		self.inherit.parse_argument(meta, "inherit", "Data")?;
+		self.rename_all.parse_argument(meta, "rename_all", "Data")?;
 
 		Ok(())
 	}
@@ -66,6 +69,7 @@ impl ParseAttribute for DataAttribute {
 
 #[derive(Default)]
 pub(crate) struct VariantAttribute {
+	pub(crate) name: Option<LitStr>,
 	pub(crate) tag: Optional<Tag>,
 	pub(crate) inherit: bool,
 	pub(crate) skip: bool,
@@ -74,7 +78,10 @@ pub(crate) struct VariantAttribute {
 impl ParseAttribute for VariantAttribute {
 	fn parse(&mut self, meta: &ParseNestedMeta) -> Result<()> {
 		const TAG_ERROR: &str = "Variant cannot have multiple `untagged`, or `tag` attributes.";
+		const NAME_ERROR: &str = "Variant cannot have multiple `name`, or `rename` attributes.";
 
+		self.name.parse_argument(meta, "name", ArgumentError::Full(NAME_ERROR))?;
+		self.name.parse_argument(meta, "rename", ArgumentError::Full(NAME_ERROR))?;
 		self.tag
 			.parse_argument_with(meta, Tag::Untagged, "untagged", ArgumentError::Full(TAG_ERROR))?;
 		self.tag.parse_argument(meta, "tag", ArgumentError::Full(TAG_ERROR))?;
@@ -98,7 +105,10 @@ pub(crate) struct FieldAttribute {
 
 impl ParseAttribute for FieldAttribute {
 	fn parse(&mut self, meta: &ParseNestedMeta) -> Result<()> {
-		self.name.parse_argument(meta, "name", "Field")?;
+		const NAME_ERROR: &str = "Field cannot have multiple `name`, or `rename` attributes.";
+
+		self.name.parse_argument(meta, "name", ArgumentError::Full(NAME_ERROR))?;
+		self.name.parse_argument(meta, "rename", ArgumentError::Full(NAME_ERROR))?;
 		self.inherit.parse_argument(meta, "inherit", "Field")?;
 		self.skip.parse_argument(meta, "skip", "Field")?;
 		self.default.parse_argument(meta, "default", "Field")?;
@@ -109,3 +119,72 @@ impl ParseAttribute for FieldAttribute {
 		Ok(())
 	}
 }
+
+#[derive(Default)]
+pub(crate) struct StringEnumAttribute {
+	pub(crate) rename_all: Option<RenameRule>,
+}
+
+impl ParseAttribute for StringEnumAttribute {
+	fn parse(&mut self, meta: &ParseNestedMeta) -> Result<()> {
+		self.rename_all.parse_argument(meta, "rename_all", "Enum")?;
+		Ok(())
+	}
+}
+
+#[derive(Default)]
+pub(crate) struct StringVariantAttribute {
+	pub(crate) name: Option<LitStr>,
+	pub(crate) skip: bool,
+}
+
+impl ParseAttribute for StringVariantAttribute {
+	fn parse(&mut self, meta: &ParseNestedMeta) -> Result<()> {
+		self.name.parse_argument(meta, "name", "Variant")?;
+		self.skip.parse_argument(meta, "skip", "Variant")?;
+		Ok(())
+	}
+}
+
+#[derive(Default)]
+pub(crate) struct ConstEnumAttribute {
+	pub(crate) name: Option<LitStr>,
+	pub(crate) rename_all: Option<RenameRule>,
+}
+
+impl ParseAttribute for ConstEnumAttribute {
+	fn parse(&mut self, meta: &ParseNestedMeta) -> Result<()> {
+		self.name.parse_argument(meta, "name", "Enum")?;
+		self.rename_all.parse_argument(meta, "rename_all", "Enum")?;
+		Ok(())
+	}
+}
+
+#[derive(Default)]
+pub(crate) struct ToDataAttribute {
+	pub(crate) rename_all: Option<RenameRule>,
+}
+
+impl ParseAttribute for ToDataAttribute {
+	fn parse(&mut self, meta: &ParseNestedMeta) -> Result<()> {
+		self.rename_all.parse_argument(meta, "rename_all", "Data")?;
+		Ok(())
+	}
+}
+
+#[derive(Default)]
+pub(crate) struct ToFieldAttribute {
+	pub(crate) name: Option<LitStr>,
+	pub(crate) skip: bool,
+}
+
+impl ParseAttribute for ToFieldAttribute {
+	fn parse(&mut self, meta: &ParseNestedMeta) -> Result<()> {
+		const NAME_ERROR: &str = "Field cannot have multiple `name`, or `rename` attributes.";
+
+		self.name.parse_argument(meta, "name", ArgumentError::Full(NAME_ERROR))?;
+		self.name.parse_argument(meta, "rename", ArgumentError::Full(NAME_ERROR))?;
+		self.skip.parse_argument(meta, "skip", "Field")?;
+		Ok(())
+	}
+}
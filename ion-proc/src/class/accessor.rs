@@ -8,17 +8,18 @@ use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 
 use convert_case::{Case, Casing};
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::TokenStream;
 use syn::spanned::Spanned;
 use syn::{Error, ItemFn, Result, Type};
 
+use crate::attribute::name::RenameRule;
 use crate::class::method::{impl_method, Method};
 use crate::function::parameter::Parameters;
 
-pub(super) struct Accessor(pub(super) Option<Method>, Option<Method>);
+pub(super) struct Accessor(pub(super) Option<Method>, pub(super) Option<Method>);
 
 impl Accessor {
-	pub(super) fn to_specs(&self, ion: &TokenStream, class: &Ident) -> Vec<TokenStream> {
+	pub(super) fn to_specs(&self, ion: &TokenStream, class: &Type) -> Vec<TokenStream> {
 		let names = self.0.as_ref().or(self.1.as_ref()).map(|method| &*method.names).unwrap_or_default();
 		names
 			.iter()
@@ -33,18 +34,18 @@ impl Accessor {
 						let setter = setter.method.sig.ident.clone();
 
 						function_ident = format_ident!("{}_getter_setter", function_ident);
-						quote!(#ion::#function_ident!(#class::#getter, #class::#setter, #key, #flags))
+						quote!(#ion::#function_ident!(<#class>::#getter, <#class>::#setter, #key, #flags))
 					}
 					Accessor(Some(getter), None) => {
 						let getter = getter.method.sig.ident.clone();
 
 						function_ident = format_ident!("{}_getter", function_ident);
-						quote!(#ion::#function_ident!(#class::#getter, #key, #flags))
+						quote!(#ion::#function_ident!(<#class>::#getter, #key, #flags))
 					}
 					Accessor(None, Some(setter)) => {
 						let setter = setter.method.sig.ident.clone();
 						function_ident = format_ident!("{}_getter", function_ident);
-						quote!(#ion::#function_ident!(#class::#setter, #key, #flags))
+						quote!(#ion::#function_ident!(<#class>::#setter, #key, #flags))
 					}
 					Accessor(None, None) => {
 						function_ident = format_ident!("create_{}_accessor", function_ident);
@@ -63,21 +64,24 @@ impl Accessor {
 	}
 }
 
-pub(super) fn get_accessor_name(mut name: String, is_setter: bool) -> String {
+pub(super) fn get_accessor_name(mut name: String, is_setter: bool, rename_all: Option<RenameRule>) -> String {
 	let pat_snake = if is_setter { "set_" } else { "get_" };
 	let pat_camel = if is_setter { "set" } else { "get" };
 	if name.starts_with(pat_snake) {
 		name.drain(0..4);
-		if name.is_case(Case::Snake) {
+		if rename_all.is_none() && name.is_case(Case::Snake) {
 			name = name.to_case(Case::Camel);
 		}
 	} else if name.starts_with(pat_camel) {
 		name.drain(0..3);
-		if name.is_case(Case::Pascal) {
+		if rename_all.is_none() && name.is_case(Case::Pascal) {
 			name = name.to_case(Case::Camel);
 		}
 	}
-	name
+	match rename_all {
+		Some(rename_all) => rename_all.apply(&name),
+		None => name,
+	}
 }
 
 pub(super) fn impl_accessor(
@@ -96,7 +100,7 @@ pub(super) fn impl_accessor(
 	} else {
 		format_ident!("__ion_bindings_getter_{}", method.sig.ident)
 	};
-	let (mut accessor, parameters) = impl_method(ion, method, ty, |sig| {
+	let (mut accessor, parameters) = impl_method(ion, method, ty, None, |sig| {
 		let parameters = Parameters::parse(&sig.inputs, Some(ty))?;
 		let nargs: i32 = parameters
 			.parameters
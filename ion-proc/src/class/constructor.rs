@@ -12,7 +12,7 @@ use crate::function::wrapper::impl_wrapper_fn;
 use crate::function::{check_abi, set_signature};
 
 pub(super) fn impl_constructor(ion: &TokenStream, mut constructor: ItemFn, ty: &Type) -> Result<Method> {
-	let (wrapper, parameters) = impl_wrapper_fn(ion, constructor.clone(), Some(ty), true)?;
+	let (wrapper, parameters) = impl_wrapper_fn(ion, constructor.clone(), Some(ty), true, None)?;
 
 	check_abi(&mut constructor)?;
 	set_signature(&mut constructor)?;
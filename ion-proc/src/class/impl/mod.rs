@@ -4,73 +4,122 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Span, TokenStream};
 use quote::ToTokens;
 use syn::spanned::Spanned;
-use syn::{parse2, Error, FnArg, ImplItem, ImplItemFn, ItemFn, ItemImpl, Result, Type, Visibility};
+use syn::visit_mut::VisitMut;
+use syn::{parse2, Error, FnArg, Generics, ImplItem, ImplItemFn, ItemFn, ItemImpl, Result, Type, Visibility};
 
-use crate::attribute::class::MethodAttribute;
+use crate::attribute::class::{ClassImplAttribute, MethodAttribute};
 use crate::attribute::krate::crate_from_attributes;
-use crate::attribute::name::Name;
+use crate::attribute::name::{Name, RenameRule};
 use crate::attribute::ParseAttribute;
 use crate::class::accessor::{get_accessor_name, impl_accessor, insert_accessor};
 use crate::class::constructor::impl_constructor;
-use crate::class::method::{impl_method, Method, MethodKind, MethodReceiver};
-use crate::class::property::{Property, PropertyType};
+use crate::class::generic_replacer;
+use crate::class::method::{get_method_name, impl_method, Method, MethodKind, MethodReceiver};
+use crate::class::overload::{impl_overloads, merge_overloads};
+use crate::class::property::{Property, PropertyLocation, PropertyType};
 use crate::class::r#impl::spec::PrototypeSpecs;
+use crate::function::impl_deprecation;
+use crate::function::parameter::Parameters;
+use crate::function::wrapper::IteratorKind;
 
 mod spec;
 
-pub(super) fn impl_js_class_impl(r#impl: &mut ItemImpl) -> Result<[ItemImpl; 2]> {
+pub(super) fn impl_js_class_impl(r#impl: &mut ItemImpl) -> Result<Vec<ItemImpl>> {
 	let ion = &crate_from_attributes(&mut r#impl.attrs);
+	let ClassImplAttribute { rename_all, instances } =
+		ClassImplAttribute::from_attributes_mut("ion", &mut r#impl.attrs)?;
 
-	if !r#impl.generics.params.is_empty() {
+	if let Some(r#trait) = &r#impl.trait_ {
 		return Err(Error::new(
-			r#impl.generics.span(),
-			"Native Class Impls cannot have generics.",
+			r#trait.1.span(),
+			"Native Class Impls cannot be for a trait.",
 		));
 	}
 
-	if let Some(r#trait) = &r#impl.trait_ {
+	if r#impl.generics.params.is_empty() {
+		if !instances.is_empty() {
+			return Err(Error::new(
+				r#impl.self_ty.span(),
+				"Native Class Impls without generics cannot have instances.",
+			));
+		}
+		return impl_class_instance(ion, r#impl, rename_all).map(Vec::from);
+	}
+
+	if instances.is_empty() {
 		return Err(Error::new(
-			r#trait.1.span(),
-			"Native Class Impls cannot be for a trait.",
+			r#impl.generics.span(),
+			"Native Class Impls with generics must have `instances`.",
 		));
 	}
 
+	let mut impls = Vec::new();
+	for instance in &instances {
+		let mut replacer = generic_replacer(&r#impl.generics, instance)?;
+		let mut instance = r#impl.clone();
+		instance.generics = Generics::default();
+		replacer.visit_item_impl_mut(&mut instance);
+		impls.extend(impl_class_instance(ion, &mut instance, rename_all)?);
+	}
+
+	strip_attributes(r#impl)?;
+	Ok(impls)
+}
+
+fn impl_class_instance(
+	ion: &TokenStream, r#impl: &mut ItemImpl, rename_all: Option<RenameRule>,
+) -> Result<[ItemImpl; 2]> {
 	let r#type = *r#impl.self_ty.clone();
-	let mut constructor: Option<Method> = None;
+	let mut constructors = Vec::new();
 	let mut specs = PrototypeSpecs::default();
+	let mut unstable_specs = PrototypeSpecs::default();
 
 	for item in &mut r#impl.items {
 		match item {
 			ImplItem::Const(r#const) => {
-				if let Some((property, r#static)) = Property::from_const(r#const)? {
-					if r#static {
-						specs.properties.1.push(property);
-					} else {
-						specs.properties.0.push(property);
+				if let Some((property, location)) = Property::from_const(r#const, rename_all)? {
+					match location {
+						PropertyLocation::Prototype => specs.properties.0.push(property),
+						PropertyLocation::Constructor => specs.properties.1.push(property),
+						PropertyLocation::Both => {
+							specs.properties.0.push(property.clone());
+							specs.properties.1.push(property);
+						}
 					}
 				}
 			}
 			ImplItem::Fn(r#fn) => {
-				if let Some(parsed_constructor) = parse_class_method(ion, r#fn, &mut specs, &r#type)? {
-					if let Some(constructor) = constructor.as_ref() {
-						return Err(Error::new(
-							r#fn.span(),
-							format!(
-								"Received multiple constructor implementations: {} and {}.",
-								constructor.method.sig.ident, parsed_constructor.method.sig.ident
-							),
-						));
-					} else {
-						constructor = Some(parsed_constructor);
-					}
+				if let Some(constructor) =
+					parse_class_method(ion, r#fn, &mut specs, &mut unstable_specs, &r#type, rename_all)?
+				{
+					constructors.push(constructor);
 				}
 			}
 			_ => (),
 		}
 	}
+
+	let constructor = if constructors.is_empty() {
+		None
+	} else {
+		if constructors.len() > 1 {
+			for (index, constructor) in constructors.iter_mut().enumerate() {
+				constructor.method.sig.ident = format_ident!("__ion_bindings_constructor_{}", index);
+			}
+		}
+		Some(impl_overloads(
+			format_ident!("__ion_bindings_constructor"),
+			constructors,
+		)?)
+	};
+	specs.methods.0 = merge_overloads(specs.methods.0)?;
+	specs.methods.1 = merge_overloads(specs.methods.1)?;
+	unstable_specs.methods.0 = merge_overloads(unstable_specs.methods.0)?;
+	unstable_specs.methods.1 = merge_overloads(unstable_specs.methods.1)?;
+
 	specs.properties.0.push(Property {
 		ty: PropertyType::String,
 		ident: parse_quote!(__ION_TO_STRING_TAG),
@@ -79,12 +128,36 @@ pub(super) fn impl_js_class_impl(r#impl: &mut ItemImpl) -> Result<[ItemImpl; 2]>
 		)],
 	});
 
-	let ident: Ident = parse2(quote_spanned!(r#type.span() => #r#type))?;
-	class_definition(ion, r#impl.span(), &r#type, &ident, constructor, specs)
+	class_definition(ion, r#impl.span(), &r#type, constructor, specs, unstable_specs)
+}
+
+fn strip_attributes(r#impl: &mut ItemImpl) -> Result<()> {
+	for item in &mut r#impl.items {
+		match item {
+			ImplItem::Const(r#const) => r#const.attrs.clear(),
+			ImplItem::Fn(r#fn) if matches!(r#fn.vis, Visibility::Public(_)) => {
+				MethodAttribute::from_attributes_mut("ion", &mut r#fn.attrs)?;
+				clear_input_attributes(r#fn);
+			}
+			_ => (),
+		}
+	}
+	Ok(())
+}
+
+fn clear_input_attributes(r#fn: &mut ImplItemFn) {
+	for input in &mut r#fn.sig.inputs {
+		let attrs = match input {
+			FnArg::Receiver(arg) => &mut arg.attrs,
+			FnArg::Typed(arg) => &mut arg.attrs,
+		};
+		attrs.clear();
+	}
 }
 
 fn parse_class_method(
-	ion: &TokenStream, r#fn: &mut ImplItemFn, specs: &mut PrototypeSpecs, r#type: &Type,
+	ion: &TokenStream, r#fn: &mut ImplItemFn, specs: &mut PrototypeSpecs, unstable_specs: &mut PrototypeSpecs,
+	r#type: &Type, rename_all: Option<RenameRule>,
 ) -> Result<Option<Method>> {
 	match &r#fn.vis {
 		Visibility::Public(_) => (),
@@ -94,7 +167,16 @@ fn parse_class_method(
 	let mut names = vec![];
 
 	let attribute = MethodAttribute::from_attributes_mut("ion", &mut r#fn.attrs)?;
-	let MethodAttribute { name, alias, kind, skip } = attribute;
+	let MethodAttribute {
+		name,
+		alias,
+		kind,
+		skip,
+		r#static,
+		iterator,
+		deprecated,
+		unstable,
+	} = attribute;
 	for alias in alias {
 		names.push(Name::String(alias));
 	}
@@ -102,38 +184,57 @@ fn parse_class_method(
 		return Ok(None);
 	}
 
+	let name = match iterator {
+		Some(IteratorKind::Sync) => Some(Name::Symbol(parse_quote!(#ion::symbol::WellKnownSymbolCode::Iterator))),
+		Some(IteratorKind::Async) => Some(Name::Symbol(
+			parse_quote!(#ion::symbol::WellKnownSymbolCode::AsyncIterator),
+		)),
+		None => name,
+	};
 	let name = name.unwrap_or_else(|| {
-		if kind == Some(MethodKind::Getter) || kind == Some(MethodKind::Setter) {
-			Name::from_string(
-				get_accessor_name(r#fn.sig.ident.to_string(), kind == Some(MethodKind::Setter)),
-				r#fn.sig.ident.span(),
-			)
+		let ident = r#fn.sig.ident.to_string();
+		let name = if kind == Some(MethodKind::Getter) || kind == Some(MethodKind::Setter) {
+			get_accessor_name(ident, kind == Some(MethodKind::Setter), rename_all)
 		} else {
-			Name::from_string(r#fn.sig.ident.to_string(), r#fn.sig.ident.span())
-		}
+			get_method_name(ident, rename_all)
+		};
+		Name::from_string(name, r#fn.sig.ident.span())
 	});
 	names.insert(0, name.clone());
 
+	let specs = if unstable { unstable_specs } else { specs };
+	let deprecate = |mut method: Method| {
+		if let Some(message) = &deprecated {
+			let name = name.as_string();
+			impl_deprecation(ion, &mut method.method, Some(r#type), &quote!(#name), message)?;
+		}
+		Ok::<_, Error>(method)
+	};
+
 	let method: ItemFn = parse2(r#fn.to_token_stream())?;
+	clear_input_attributes(r#fn);
 
-	for input in &mut r#fn.sig.inputs {
-		let attrs = match input {
-			FnArg::Receiver(arg) => &mut arg.attrs,
-			FnArg::Typed(arg) => &mut arg.attrs,
-		};
-		attrs.clear();
-	}
+	let check_static = |parameters: &Parameters| match &parameters.this {
+		Some((this, _, _)) if r#static && !this.is_object() => Err(Error::new(
+			r#fn.sig.span(),
+			"Static methods can only have `this` parameters of type `&Object`.",
+		)),
+		_ => Ok(()),
+	};
 
 	match kind {
 		Some(MethodKind::Constructor) => {
-			let constructor = impl_constructor(ion, method, r#type)?;
+			let constructor = deprecate(impl_constructor(ion, method, r#type)?)?;
 			return Ok(Some(Method { names, ..constructor }));
 		}
 		Some(MethodKind::Getter) => {
 			let (getter, parameters) = impl_accessor(ion, method, r#type, false)?;
-			let getter = Method { names, ..getter };
+			check_static(&parameters)?;
+			let getter = Method { names, ..deprecate(getter)? };
+
+			let is_static = parameters.this.is_none() || r#static;
 
-			if parameters.this.is_some() {
+			if !is_static {
 				insert_accessor(&mut specs.accessors.0, name.as_string(), Some(getter), None);
 			} else {
 				insert_accessor(&mut specs.accessors.1, name.as_string(), Some(getter), None);
@@ -141,19 +242,29 @@ fn parse_class_method(
 		}
 		Some(MethodKind::Setter) => {
 			let (setter, parameters) = impl_accessor(ion, method, r#type, true)?;
-			let setter = Method { names, ..setter };
+			check_static(&parameters)?;
+			let setter = Method { names, ..deprecate(setter)? };
 
-			if parameters.this.is_some() {
+			let is_static = parameters.this.is_none() || r#static;
+
+			if !is_static {
 				insert_accessor(&mut specs.accessors.0, name.as_string(), None, Some(setter));
 			} else {
 				insert_accessor(&mut specs.accessors.1, name.as_string(), None, Some(setter));
 			}
 		}
 		None => {
-			let (method, _) = impl_method(ion, method, r#type, |_| Ok(()))?;
-			let method = Method { names, ..method };
+			let (method, parameters) = impl_method(ion, method, r#type, iterator, |_| Ok(()))?;
+			if iterator.is_some() && parameters.this.is_none() {
+				return Err(Error::new(
+					r#fn.sig.span(),
+					"Iterable methods must have a `self` or `this` parameter.",
+				));
+			}
+			check_static(&parameters)?;
+			let method = Method { names, ..deprecate(method)? };
 
-			if method.receiver == MethodReceiver::Dynamic {
+			if method.receiver == MethodReceiver::Dynamic && !r#static {
 				specs.methods.0.push(method);
 			} else {
 				specs.methods.1.push(method);
@@ -165,11 +276,20 @@ fn parse_class_method(
 }
 
 fn class_definition(
-	ion: &TokenStream, span: Span, r#type: &Type, ident: &Ident, constructor: Option<Method>, specs: PrototypeSpecs,
+	ion: &TokenStream, span: Span, r#type: &Type, constructor: Option<Method>, specs: PrototypeSpecs,
+	unstable_specs: PrototypeSpecs,
 ) -> Result<[ItemImpl; 2]> {
-	let (spec_fns, def_fns) = specs.to_impl_fns(ion, span, ident)?;
+	let (mut spec_fns, mut def_fns) = specs.to_impl_fns(ion, span, r#type, false)?;
+	let (unstable_spec_fns, unstable_def_fns) = unstable_specs.to_impl_fns(ion, span, r#type, true)?;
+	spec_fns.extend(unstable_spec_fns);
+	def_fns.extend(unstable_def_fns);
+
 	let constructor_function = constructor.as_ref().map(|c| &c.method);
-	let functions = specs.into_functions().into_iter().map(|method| method.method);
+	let functions = specs
+		.into_functions()
+		.into_iter()
+		.chain(unstable_specs.into_functions())
+		.map(|method| method.method);
 
 	let mut spec_impls: ItemImpl = parse2(quote_spanned!(span => impl #r#type {
 		#constructor_function
@@ -186,18 +306,26 @@ fn class_definition(
 	});
 	let class_definition = parse2(quote_spanned!(span => impl #ion::ClassDefinition for #r#type {
 		fn class() -> &'static #ion::class::NativeClass {
-			static __ION_NATIVE_CLASS: &#ion::class::NativeClass = #r#type::__ion_native_class();
+			static __ION_NATIVE_CLASS: &#ion::class::NativeClass = <#r#type>::__ion_native_class();
 			__ION_NATIVE_CLASS
 		}
 
 		fn parent_prototype(cx: &#ion::Context) -> ::std::option::Option<#ion::Local<*mut ::mozjs::jsapi::JSObject>> {
-			#r#type::__ion_parent_prototype(cx)
+			<#r#type>::__ion_parent_prototype(cx)
 		}
 
 		fn constructor() -> (::std::option::Option<#ion::function::NativeFunction>, ::core::primitive::u32) {
 			(#constructor_function, #constructor_nargs)
 		}
 
+		fn field_properties() -> ::std::option::Option<&'static [::mozjs::jsapi::JSPropertySpec]> {
+			<#r#type>::__ion_field_properties()
+		}
+
+		fn unstable() -> bool {
+			<#r#type>::__ION_UNSTABLE
+		}
+
 		#(#def_fns)*
 	}))?;
 
@@ -22,35 +22,37 @@ pub(super) struct PrototypeSpecs {
 
 impl PrototypeSpecs {
 	pub(super) fn to_impl_fns(
-		&self, ion: &TokenStream, span: Span, ident: &Ident,
+		&self, ion: &TokenStream, span: Span, class: &Type, unstable: bool,
 	) -> Result<(Vec<ImplItemFn>, Vec<ImplItemFn>)> {
 		let mut impl_fns = Vec::with_capacity(4);
 
 		if !self.methods.0.is_empty() {
-			impl_fns.push(methods_to_impl_fn(ion, span, ident, &self.methods.0, false)?);
+			impl_fns.push(methods_to_impl_fn(ion, span, class, &self.methods.0, false, unstable)?);
 		}
 		if !self.methods.1.is_empty() {
-			impl_fns.push(methods_to_impl_fn(ion, span, ident, &self.methods.1, true)?);
+			impl_fns.push(methods_to_impl_fn(ion, span, class, &self.methods.1, true, unstable)?);
 		}
 
 		if !self.properties.0.is_empty() || !self.accessors.0.is_empty() {
 			impl_fns.push(properties_to_spec_function(
 				ion,
 				span,
-				ident,
+				class,
 				&self.properties.0,
 				&self.accessors.0,
 				false,
+				unstable,
 			)?);
 		}
 		if !self.properties.1.is_empty() || !self.accessors.1.is_empty() {
 			impl_fns.push(properties_to_spec_function(
 				ion,
 				span,
-				ident,
+				class,
 				&self.properties.1,
 				&self.accessors.1,
 				true,
+				unstable,
 			)?);
 		}
 
@@ -71,12 +73,15 @@ impl PrototypeSpecs {
 }
 
 fn methods_to_impl_fn(
-	ion: &TokenStream, span: Span, class: &Ident, methods: &[Method], r#static: bool,
+	ion: &TokenStream, span: Span, class: &Type, methods: &[Method], r#static: bool, unstable: bool,
 ) -> Result<(ImplItemFn, ImplItemFn)> {
 	let mut ident = parse_quote!(functions);
 	if r#static {
 		ident = format_ident!("static_{}", ident);
 	}
+	if unstable {
+		ident = format_ident!("unstable_{}", ident);
+	}
 	let function_ident = format_ident!("__ion_{}_specs", ident);
 
 	let specs: Vec<_> = methods.iter().flat_map(|method| method.to_specs(ion, class)).collect();
@@ -89,13 +94,16 @@ fn methods_to_impl_fn(
 }
 
 fn properties_to_spec_function(
-	ion: &TokenStream, span: Span, class: &Ident, properties: &[Property], accessors: &HashMap<String, Accessor>,
-	r#static: bool,
+	ion: &TokenStream, span: Span, class: &Type, properties: &[Property], accessors: &HashMap<String, Accessor>,
+	r#static: bool, unstable: bool,
 ) -> Result<(ImplItemFn, ImplItemFn)> {
 	let mut ident = parse_quote!(properties);
 	if r#static {
 		ident = format_ident!("static_{}", ident);
 	}
+	if unstable {
+		ident = format_ident!("unstable_{}", ident);
+	}
 	let function_ident = format_ident!("__ion_{}_specs", ident);
 
 	let mut specs: Vec<_> = properties.iter().flat_map(|property| property.to_specs(ion, class)).collect();
@@ -5,12 +5,12 @@
  */
 
 use convert_case::{Case, Casing};
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::TokenStream;
 use syn::{ItemFn, Result, Signature, Type};
 
-use crate::attribute::name::Name;
+use crate::attribute::name::{Name, RenameRule};
 use crate::function::parameter::Parameters;
-use crate::function::wrapper::impl_wrapper_fn;
+use crate::function::wrapper::{impl_wrapper_fn, IteratorKind};
 use crate::function::{check_abi, impl_fn_body, set_signature};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -35,7 +35,7 @@ pub(super) struct Method {
 }
 
 impl Method {
-	pub(super) fn to_specs(&self, ion: &TokenStream, class: &Ident) -> Vec<TokenStream> {
+	pub(super) fn to_specs(&self, ion: &TokenStream, class: &Type) -> Vec<TokenStream> {
 		let ident = &self.method.sig.ident;
 		let nargs = self.nargs;
 
@@ -43,27 +43,31 @@ impl Method {
 			.iter()
 			.map(|name| match name {
 				Name::String(literal) => {
-					let mut name = literal.value();
-					if name.is_case(Case::Snake) {
-						name = name.to_case(Case::Camel)
-					}
-					quote!(#ion::function_spec!(#class::#ident, #name, #nargs, #ion::flags::PropertyFlags::CONSTANT_ENUMERATED))
+					quote!(#ion::function_spec!(<#class>::#ident, #literal, #nargs, #ion::flags::PropertyFlags::CONSTANT_ENUMERATED))
 				}
 				Name::Symbol(symbol) => {
-					quote!(#ion::function_spec_symbol!(#class::#ident, #symbol, #nargs, #ion::flags::PropertyFlags::CONSTANT))
+					quote!(#ion::function_spec_symbol!(<#class>::#ident, #symbol, #nargs, #ion::flags::PropertyFlags::CONSTANT))
 				}
 			})
 			.collect()
 	}
 }
 
+pub(super) fn get_method_name(name: String, rename_all: Option<RenameRule>) -> String {
+	match rename_all {
+		Some(rename_all) => rename_all.apply(&name),
+		None if name.is_case(Case::Snake) => name.to_case(Case::Camel),
+		None => name,
+	}
+}
+
 pub(super) fn impl_method<F>(
-	ion: &TokenStream, mut method: ItemFn, ty: &Type, predicate: F,
+	ion: &TokenStream, mut method: ItemFn, ty: &Type, iterator: Option<IteratorKind>, predicate: F,
 ) -> Result<(Method, Parameters)>
 where
 	F: FnOnce(&Signature) -> Result<()>,
 {
-	let (wrapper, parameters) = impl_wrapper_fn(ion, method.clone(), Some(ty), false)?;
+	let (wrapper, parameters) = impl_wrapper_fn(ion, method.clone(), Some(ty), false, iterator)?;
 
 	predicate(&method.sig).and_then(|_| {
 		check_abi(&mut method)?;
@@ -6,15 +6,17 @@
 
 use proc_macro2::TokenStream;
 use syn::spanned::Spanned;
-use syn::{Error, Item, Result};
+use syn::{Error, GenericArgument, GenericParam, Generics, Item, PathArguments, Result, Type};
 
 use crate::class::r#impl::impl_js_class_impl;
 use crate::class::r#struct::impl_js_class_struct;
+use crate::visitors::GenericReplacer;
 
 mod accessor;
 pub(crate) mod constructor;
 mod r#impl;
 pub(crate) mod method;
+mod overload;
 pub(crate) mod property;
 mod r#struct;
 
@@ -37,3 +39,35 @@ pub(super) fn impl_js_class(item: Item) -> Result<TokenStream> {
 		_ => Err(Error::new(item.span(), "Expected Struct or Impl Block")),
 	}
 }
+
+/// Maps the type parameters of a generic class to the generic arguments of one of its instances.
+pub(super) fn generic_replacer(generics: &Generics, instance: &Type) -> Result<GenericReplacer> {
+	let arguments = match instance {
+		Type::Path(ty) if ty.qself.is_none() => ty.path.segments.last().and_then(|segment| match &segment.arguments {
+			PathArguments::AngleBracketed(arguments) => Some(&arguments.args),
+			_ => None,
+		}),
+		_ => None,
+	};
+	let arguments =
+		arguments.ok_or_else(|| Error::new(instance.span(), "Class Instance must have generic arguments."))?;
+
+	if arguments.len() != generics.params.len() {
+		return Err(Error::new(
+			instance.span(),
+			format!("Class Instance must have {} generic arguments.", generics.params.len()),
+		));
+	}
+
+	let replacements = generics
+		.params
+		.iter()
+		.zip(arguments)
+		.map(|(param, argument)| match (param, argument) {
+			(GenericParam::Type(param), GenericArgument::Type(ty)) => Ok((param.ident.clone(), ty.clone())),
+			(GenericParam::Type(_), _) => Err(Error::new(argument.span(), "Expected Type Argument")),
+			_ => Err(Error::new(param.span(), "Native Classes can only have type generics.")),
+		})
+		.collect::<Result<_>>()?;
+	Ok(GenericReplacer { replacements })
+}
@@ -0,0 +1,76 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::Ident;
+use syn::spanned::Spanned;
+use syn::{parse2, Error, ItemFn, Result};
+
+use crate::class::method::Method;
+
+/// Merges methods with the same name into a single method, which dispatches to an overload by the number of arguments.
+pub(super) fn merge_overloads(methods: Vec<Method>) -> Result<Vec<Method>> {
+	let mut merged: Vec<Vec<Method>> = Vec::with_capacity(methods.len());
+	for method in methods {
+		let name = method.names[0].as_string();
+		match merged.iter_mut().find(|overloads| overloads[0].names[0].as_string() == name) {
+			Some(overloads) => overloads.push(method),
+			None => merged.push(vec![method]),
+		}
+	}
+
+	merged
+		.into_iter()
+		.map(|overloads| {
+			let ident = format_ident!("{}_overloads", overloads[0].method.sig.ident);
+			impl_overloads(ident, overloads)
+		})
+		.collect()
+}
+
+/// Creates a method which calls the overload with the most required arguments that are all present.
+/// Falls back to the overload with the fewest required arguments, which reports the missing arguments.
+pub(super) fn impl_overloads(ident: Ident, mut overloads: Vec<Method>) -> Result<Method> {
+	if overloads.len() == 1 {
+		return Ok(overloads.remove(0));
+	}
+
+	overloads.sort_by_key(|overload| overload.nargs);
+	for pair in overloads.windows(2) {
+		if pair[0].nargs == pair[1].nargs {
+			return Err(Error::new(
+				pair[1].method.sig.span(),
+				format!(
+					"Overloads of `{}` must have different numbers of required arguments.",
+					pair[0].names[0].as_string()
+				),
+			));
+		}
+	}
+
+	let fallback = overloads.remove(0);
+	let functions = overloads.iter().chain([&fallback]).map(|overload| &overload.method);
+	let branches = overloads.iter().rev().map(|overload| {
+		let ident = &overload.method.sig.ident;
+		let nargs = u32::from(overload.nargs);
+		quote!(if argc >= #nargs {
+			return unsafe { #ident(cx, argc, vp) };
+		})
+	});
+	let fallback_ident = &fallback.method.sig.ident;
+
+	let method: ItemFn = parse2(quote_spanned!(fallback.method.span() =>
+		unsafe extern "C" fn #ident(
+			cx: *mut ::mozjs::jsapi::JSContext, argc: ::core::primitive::u32, vp: *mut ::mozjs::jsval::JSVal
+		) -> ::core::primitive::bool {
+			#(#functions)*
+
+			#(#branches)*
+			unsafe { #fallback_ident(cx, argc, vp) }
+		}
+	))?;
+
+	Ok(Method { method, ..fallback })
+}
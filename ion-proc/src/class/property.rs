@@ -4,10 +4,11 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
+use convert_case::{Case, Casing};
 use proc_macro2::{Ident, TokenStream};
-use syn::{ImplItemConst, Result, Type};
+use syn::{Error, ImplItemConst, Result, Type};
 
-use crate::attribute::name::Name;
+use crate::attribute::name::{Name, RenameRule};
 use crate::attribute::property::PropertyAttribute;
 use crate::attribute::ParseAttribute;
 use crate::utils::path_ends_with;
@@ -19,6 +20,15 @@ pub(super) enum PropertyType {
 	String,
 }
 
+/// Object on which a class property is defined.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(super) enum PropertyLocation {
+	Prototype,
+	Constructor,
+	/// Constants are defined on both the constructor and the prototype, as with WebIDL constants.
+	Both,
+}
+
 #[derive(Clone)]
 pub(super) struct Property {
 	pub(super) ty: PropertyType,
@@ -27,12 +37,14 @@ pub(super) struct Property {
 }
 
 impl Property {
-	pub(super) fn from_const(r#const: &mut ImplItemConst) -> Result<Option<(Property, bool)>> {
+	pub(super) fn from_const(
+		r#const: &mut ImplItemConst, rename_all: Option<RenameRule>,
+	) -> Result<Option<(Property, PropertyLocation)>> {
 		let mut names = Vec::new();
 
 		let attribute = PropertyAttribute::from_attributes_mut("ion", &mut r#const.attrs)?;
 
-		let PropertyAttribute { name, alias, skip, r#static } = attribute;
+		let PropertyAttribute { name, alias, skip, r#static, constant } = attribute;
 		for alias in alias {
 			names.push(Name::String(alias));
 		}
@@ -43,18 +55,38 @@ impl Property {
 		}
 
 		let ident = r#const.ident.clone();
-
-		match name {
-			Some(name) => names.insert(0, name),
-			None => names.insert(0, Name::from_string(ident.to_string(), ident.span())),
+		if r#static && constant {
+			return Err(Error::new(
+				ident.span(),
+				"Property cannot have both `static` and `constant` attributes.",
+			));
 		}
+		let location = if constant {
+			PropertyLocation::Both
+		} else if r#static {
+			PropertyLocation::Constructor
+		} else {
+			PropertyLocation::Prototype
+		};
+
+		let name = name.unwrap_or_else(|| {
+			let name = ident.to_string();
+			let name = match rename_all {
+				_ if constant => name,
+				Some(rename_all) => rename_all.apply(&name),
+				None if name.is_case(Case::ScreamingSnake) => name.to_case(Case::Camel),
+				None => name,
+			};
+			Name::from_string(name, ident.span())
+		});
+		names.insert(0, name);
 
 		match &r#const.ty {
 			Type::Path(ty) => {
 				if path_ends_with(&ty.path, "i32") {
-					Ok(Some((Property { ty: PropertyType::Int32, ident, names }, r#static)))
+					Ok(Some((Property { ty: PropertyType::Int32, ident, names }, location)))
 				} else if path_ends_with(&ty.path, "f64") {
-					Ok(Some((Property { ty: PropertyType::Double, ident, names }, r#static)))
+					Ok(Some((Property { ty: PropertyType::Double, ident, names }, location)))
 				} else {
 					Ok(None)
 				}
@@ -62,7 +94,7 @@ impl Property {
 			Type::Reference(re) => {
 				if let Type::Path(ty) = &*re.elem {
 					if path_ends_with(&ty.path, "str") {
-						return Ok(Some((Property { ty: PropertyType::String, ident, names }, r#static)));
+						return Ok(Some((Property { ty: PropertyType::String, ident, names }, location)));
 					}
 				}
 				Ok(None)
@@ -71,7 +103,7 @@ impl Property {
 		}
 	}
 
-	pub(super) fn to_specs(&self, ion: &TokenStream, class: &Ident) -> Vec<TokenStream> {
+	pub(super) fn to_specs(&self, ion: &TokenStream, class: &Type) -> Vec<TokenStream> {
 		let ident = &self.ident;
 
 		self.names
@@ -87,7 +119,7 @@ impl Property {
 					PropertyType::String => format_ident!("{}_string", function_ident),
 				};
 
-				quote!(#ion::spec::#function_ident(#key, #class::#ident, #flags))
+				quote!(#ion::spec::#function_ident(#key, <#class>::#ident, #flags))
 			})
 			.collect()
 	}
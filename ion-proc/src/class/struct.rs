@@ -4,17 +4,25 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
+use convert_case::{Case, Casing};
 use proc_macro2::{Ident, Span, TokenStream};
+use quote::ToTokens;
+use syn::ext::IdentExt;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{parse2, Error, ItemImpl, ItemStruct, Member, Path, Result, Type};
+use syn::visit_mut::VisitMut;
+use syn::{parse2, Error, Generics, ItemFn, ItemImpl, ItemStruct, LitStr, Member, Path, Result, Type};
 
-use crate::attribute::class::ClassAttribute;
+use crate::attribute::class::{ClassAttribute, ClassFieldAttribute, ClassInstance};
 use crate::attribute::krate::crate_from_attributes;
+use crate::attribute::name::Name;
 use crate::attribute::ParseAttribute;
+use crate::class::accessor::{impl_accessor, Accessor};
+use crate::class::generic_replacer;
+use crate::class::method::Method;
 use crate::utils::{new_token, path_ends_with};
 
-pub(super) fn impl_js_class_struct(r#struct: &mut ItemStruct) -> Result<[ItemImpl; 7]> {
+pub(super) fn impl_js_class_struct(r#struct: &mut ItemStruct) -> Result<Vec<ItemImpl>> {
 	let ion = &crate_from_attributes(&mut r#struct.attrs);
 
 	let repr_c = r#struct.attrs.iter().fold(Ok(false), |acc, attr| {
@@ -42,25 +50,60 @@ pub(super) fn impl_js_class_struct(r#struct: &mut ItemStruct) -> Result<[ItemImp
 		r#struct.attrs.push(parse_quote!(#[derive(#ion::Traceable)]));
 	}
 
-	let attribute = ClassAttribute::from_attributes_mut("ion", &mut r#struct.attrs)?;
+	let ClassAttribute { name, extends, instances, unstable } =
+		ClassAttribute::from_attributes_mut("ion", &mut r#struct.attrs)?;
 
-	if !r#struct.generics.params.is_empty() {
+	if r#struct.generics.params.is_empty() {
+		if !instances.is_empty() {
+			return Err(Error::new(
+				r#struct.ident.span(),
+				"Native Class Structs without generics cannot have instances.",
+			));
+		}
+
+		let name = name.map(|name| name.value()).unwrap_or_else(|| r#struct.ident.to_string());
+		let ident = &r#struct.ident;
+		let r#type: Type = parse2(quote_spanned!(ident.span() => #ident))?;
+		return impl_class_instance(ion, r#struct, &name, &r#type, extends.as_ref(), unstable);
+	}
+
+	if instances.is_empty() {
 		return Err(Error::new(
 			r#struct.generics.span(),
-			"Native Class Structs cannot have generics.",
+			"Native Class Structs with generics must have `instances`.",
 		));
 	}
 
-	let name = if let Some(name) = attribute.name {
-		name.value()
-	} else {
-		r#struct.ident.to_string()
-	};
+	let mut impls = Vec::new();
+	for ClassInstance { name, ty } in instances {
+		let mut replacer = generic_replacer(&r#struct.generics, &ty)?;
+		let mut instance = r#struct.clone();
+		instance.generics = Generics::default();
+		for field in &mut instance.fields {
+			replacer.visit_type_mut(&mut field.ty);
+		}
+		impls.extend(impl_class_instance(
+			ion,
+			&mut instance,
+			&name.value(),
+			&ty,
+			extends.as_ref(),
+			unstable,
+		)?);
+	}
+
+	for field in &mut r#struct.fields {
+		ClassFieldAttribute::from_attributes_mut("ion", &mut field.attrs)?;
+	}
+	Ok(impls)
+}
 
-	let ident = &r#struct.ident;
-	let r#type: Type = parse2(quote_spanned!(ident.span() => #ident))?;
+fn impl_class_instance(
+	ion: &TokenStream, r#struct: &mut ItemStruct, name: &str, r#type: &Type, extends: Option<&Type>, unstable: bool,
+) -> Result<Vec<ItemImpl>> {
+	let field_accessors = impl_field_accessors(ion, r#struct, r#type)?;
 
-	let (super_field, super_type) = if let Some(field) = r#struct.fields.iter().next() {
+	let (super_field, field_type) = if let Some(field) = r#struct.fields.iter().next() {
 		(Member::Named(field.ident.as_ref().unwrap().clone()), field.ty.clone())
 	} else {
 		return Err(Error::new(
@@ -69,19 +112,123 @@ pub(super) fn impl_js_class_struct(r#struct: &mut ItemStruct) -> Result<[ItemImp
 		));
 	};
 
-	if let Type::Path(ty) = &super_type {
+	let super_path = if let Type::Path(ty) = &field_type {
 		if ty.path.segments.iter().any(|segment| !segment.arguments.is_empty()) {
-			return Err(Error::new(super_type.span(), "Superclass Type must not have generics."));
+			return Err(Error::new(field_type.span(), "Superclass Type must not have generics."));
 		}
+		&ty.path
 	} else {
-		return Err(Error::new(super_type.span(), "Superclass Type must be a path."));
+		return Err(Error::new(field_type.span(), "Superclass Type must be a path."));
+	};
+
+	if let Some(extends) = extends {
+		// The types may be written with different paths, such as `Event` and `crate::Event`.
+		let matches = match extends {
+			Type::Path(extends) => extends
+				.path
+				.segments
+				.last()
+				.is_some_and(|segment| path_ends_with(super_path, &segment.ident)),
+			_ => false,
+		};
+		if !matches {
+			return Err(Error::new(
+				field_type.span(),
+				format!(
+					"First field of a class extending {} must be of that type.",
+					extends.to_token_stream()
+				),
+			));
+		}
+	}
+
+	// The prototype chain is built from the superclass given by `extends`, and `__ion_superclass` checks that the
+	// first field is of that type.
+	let super_type = extends.unwrap_or(&field_type);
+	let mut impls = class_impls(ion, r#struct.span(), name, r#type, &super_field, super_type, unstable)?.to_vec();
+	impls.push(field_accessors);
+	Ok(impls)
+}
+
+fn impl_field_accessors(ion: &TokenStream, r#struct: &mut ItemStruct, r#type: &Type) -> Result<ItemImpl> {
+	let mut functions = Vec::new();
+	let mut specs = Vec::new();
+
+	for (index, field) in r#struct.fields.iter_mut().enumerate() {
+		let attribute = ClassFieldAttribute::from_attributes_mut("ion", &mut field.attrs)?;
+		let ClassFieldAttribute { name, accessor, readonly } = attribute;
+		if !accessor {
+			if name.is_some() || readonly {
+				return Err(Error::new(
+					field.span(),
+					"Field must have `accessor` to have `name` or `readonly`.",
+				));
+			}
+			continue;
+		}
+		if index == 0 {
+			return Err(Error::new(field.span(), "Superclass Field cannot have an accessor."));
+		}
+
+		let ident = field.ident.as_ref().unwrap();
+		let ty = &field.ty;
+		let name = name.unwrap_or_else(|| {
+			let name = ident.unraw().to_string().to_case(Case::Camel);
+			LitStr::new(&name, ident.span())
+		});
+		let names = vec![Name::String(name)];
+
+		let getter_ident = format_ident!("__ion_field_get_{}", ident.unraw());
+		let getter: ItemFn = parse2(quote_spanned!(field.span() => fn #getter_ident(&self) -> &#ty {
+			&self.#ident
+		}))?;
+		let (getter_method, _) = impl_accessor(ion, getter.clone(), r#type, false)?;
+		let getter_method = Method { names: names.clone(), ..getter_method };
+
+		let setter_method = if readonly {
+			None
+		} else {
+			let setter_ident = format_ident!("__ion_field_set_{}", ident.unraw());
+			let setter: ItemFn = parse2(quote_spanned!(field.span() => fn #setter_ident(&mut self, value: #ty) {
+				self.#ident = value;
+			}))?;
+			let (setter_method, _) = impl_accessor(ion, setter.clone(), r#type, true)?;
+			functions.push(setter);
+			Some(Method { names, ..setter_method })
+		};
+		functions.push(getter);
+
+		let accessor = Accessor(Some(getter_method), setter_method);
+		specs.extend(accessor.to_specs(ion, r#type));
+		let Accessor(getter_method, setter_method) = accessor;
+		functions.extend(getter_method.into_iter().chain(setter_method).map(|method| method.method));
 	}
 
-	class_impls(ion, r#struct.span(), &name, &r#type, &super_field, &super_type)
+	let properties = if specs.is_empty() {
+		quote!(::std::option::Option::None)
+	} else {
+		quote!(
+			static SPECS: &[::mozjs::jsapi::JSPropertySpec] = &[
+				#(#specs,)*
+				::mozjs::jsapi::JSPropertySpec::ZERO,
+			];
+			::std::option::Option::Some(SPECS)
+		)
+	};
+
+	let mut field_impl: ItemImpl = parse2(quote_spanned!(r#struct.span() => impl #r#type {
+		#(#functions)*
+
+		pub fn __ion_field_properties() -> ::std::option::Option<&'static [::mozjs::jsapi::JSPropertySpec]> {
+			#properties
+		}
+	}))?;
+	field_impl.attrs.push(parse_quote!(#[doc(hidden)]));
+	Ok(field_impl)
 }
 
 fn class_impls(
-	ion: &TokenStream, span: Span, name: &str, r#type: &Type, super_field: &Member, super_type: &Type,
+	ion: &TokenStream, span: Span, name: &str, r#type: &Type, super_field: &Member, super_type: &Type, unstable: bool,
 ) -> Result<[ItemImpl; 7]> {
 	let from_value = impl_from_value(ion, span, r#type, false)?;
 	let from_value_mut = impl_from_value(ion, span, r#type, true)?;
@@ -102,7 +249,7 @@ fn class_impls(
 	let mut class_impl: ItemImpl = parse2(quote_spanned!(span => impl #r#type {
 		pub const fn __ion_native_prototype_chain() -> #ion::class::PrototypeChain {
 			const ION_TYPE_ID: #ion::class::TypeIdWrapper<#r#type> = #ion::class::TypeIdWrapper::new();
-			#super_type::__ion_native_prototype_chain().push(&ION_TYPE_ID)
+			<#super_type>::__ion_native_prototype_chain().push(&ION_TYPE_ID)
 		}
 
 		pub const fn __ion_native_class() -> &'static #ion::class::NativeClass {
@@ -128,13 +275,19 @@ fn class_impls(
 					ext: ::std::ptr::null_mut(),
 					oOps: ::std::ptr::null_mut(),
 				},
-				prototype_chain: #r#type::__ion_native_prototype_chain(),
+				prototype_chain: <#r#type>::__ion_native_prototype_chain(),
 			};
 
 			&ION_NATIVE_CLASS
 		}
 
+		pub const fn __ion_superclass(&self) -> &#super_type {
+			&self.#super_field
+		}
+
 		pub const __ION_TO_STRING_TAG: &'static str = #name;
+
+		pub const __ION_UNSTABLE: bool = #unstable;
 	}))?;
 	class_impl.attrs.push(parse_quote!(#[doc(hidden)]));
 
@@ -6,9 +6,11 @@
 
 use proc_macro2::TokenStream;
 use syn::punctuated::Punctuated;
-use syn::{parse2, Abi, Block, Error, FnArg, Generics, ItemFn, Result};
+use syn::{parse2, Abi, Block, Error, FnArg, Generics, ItemFn, LitStr, Result, Stmt, Type};
 
+use crate::attribute::function::FunctionAttribute;
 use crate::attribute::krate::crate_from_attributes;
+use crate::attribute::ParseAttribute;
 use crate::function::wrapper::impl_wrapper_fn;
 use crate::utils::new_token;
 
@@ -19,7 +21,9 @@ pub(crate) mod wrapper;
 // TODO: Partially Remove Error Handling in Infallible Functions
 pub(crate) fn impl_js_fn(mut function: ItemFn) -> Result<ItemFn> {
 	let ion = &crate_from_attributes(&mut function.attrs);
-	let (wrapper, _) = impl_wrapper_fn(ion, function.clone(), None, false)?;
+	let FunctionAttribute { deprecated, unstable } =
+		FunctionAttribute::from_attributes_mut("ion", &mut function.attrs)?;
+	let (wrapper, _) = impl_wrapper_fn(ion, function.clone(), None, false, None)?;
 
 	check_abi(&mut function)?;
 	set_signature(&mut function)?;
@@ -27,6 +31,15 @@ pub(crate) fn impl_js_fn(mut function: ItemFn) -> Result<ItemFn> {
 	function.attrs.clear();
 	function.block = impl_fn_body(ion, &wrapper)?;
 
+	// Native functions are named when they are defined, so the name is taken from the callee.
+	let name = quote!(&#ion::function::__callee_name(cx, argc, vp));
+	if let Some(message) = deprecated {
+		impl_deprecation(ion, &mut function, None, &name, &message)?;
+	}
+	if unstable {
+		impl_unstable_check(ion, &mut function, &name)?;
+	}
+
 	Ok(function)
 }
 
@@ -63,3 +76,36 @@ pub(crate) fn impl_fn_body(ion: &TokenStream, wrapper: &ItemFn) -> Result<Box<Bl
 		#ion::function::__handle_native_function_result(cx, result)
 	}))
 }
+
+/// Reports the first call of the native function as deprecated, with the given name and message.
+pub(crate) fn impl_deprecation(
+	ion: &TokenStream, function: &mut ItemFn, class: Option<&Type>, name: &TokenStream, message: &LitStr,
+) -> Result<()> {
+	let class = match class {
+		Some(class) => quote!(::std::option::Option::Some(<#class as #ion::ClassDefinition>::class())),
+		None => quote!(::std::option::Option::None),
+	};
+	let report: Stmt = parse2(quote!({
+		static __ION_DEPRECATION_REPORTED: ::std::sync::atomic::AtomicBool = ::std::sync::atomic::AtomicBool::new(false);
+		if !__ION_DEPRECATION_REPORTED.swap(true, ::std::sync::atomic::Ordering::Relaxed) {
+			#ion::function::__report_deprecation(cx, #class, #name, #message);
+		}
+	}))?;
+
+	// The first statement of the native function body creates `cx`.
+	function.block.stmts.insert(1, report);
+	Ok(())
+}
+
+/// Throws an error from the native function if unstable bindings are not enabled, before any arguments are converted.
+/// This only guards specs that were defined with `Object::define_methods`, instead of `Object::define_unstable_methods`.
+fn impl_unstable_check(ion: &TokenStream, function: &mut ItemFn, name: &TokenStream) -> Result<()> {
+	let check: Stmt = parse2(quote!({
+		if !(*cx.get_inner_data().as_ptr()).unstable {
+			return #ion::function::__throw_unstable(cx, #name);
+		}
+	}))?;
+
+	function.block.stmts.insert(1, check);
+	Ok(())
+}
@@ -8,9 +8,10 @@ use proc_macro2::{Span, TokenStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::visit_mut::visit_type_mut;
-use syn::{parse2, Error, Expr, FnArg, Ident, Pat, PatType, Receiver, Result, Stmt, Type};
+use syn::{parse2, Error, Expr, FnArg, Ident, Pat, PatType, PathArguments, Receiver, Result, Stmt, Type};
 
 use crate::attribute::function::ParameterAttribute;
+use crate::attribute::value::DefaultValue;
 use crate::attribute::ParseAttribute;
 use crate::utils::{pat_is_ident, path_ends_with};
 use crate::visitors::{LifetimeRemover, SelfRenamer};
@@ -18,6 +19,8 @@ use crate::visitors::{LifetimeRemover, SelfRenamer};
 pub(crate) struct Parameter {
 	pub(crate) pat_ty: PatType,
 	convert: Option<Box<Expr>>,
+	default: Option<DefaultValue>,
+	varargs: bool,
 }
 
 #[derive(Clone)]
@@ -44,12 +47,27 @@ impl Parameter {
 			FnArg::Typed(pat_ty) => {
 				let mut pat_ty = pat_ty.clone();
 				let attribute = ParameterAttribute::from_attributes_mut("ion", &mut pat_ty.attrs)?;
-				Ok(Parameter { pat_ty, convert: attribute.convert })
+				if attribute.varargs && !is_vec(&pat_ty.ty) {
+					return Err(Error::new(
+						pat_ty.ty.span(),
+						"Parameter with `varargs` attribute must be of type `Vec<T>`.",
+					));
+				}
+				Ok(Parameter {
+					pat_ty,
+					convert: attribute.convert,
+					default: attribute.default.0,
+					varargs: attribute.varargs,
+				})
 			}
 			FnArg::Receiver(_) => Err(Error::new(arg.span(), "Expected Typed Function Argument")),
 		}
 	}
 
+	pub(crate) fn is_variadic(&self) -> bool {
+		self.varargs || matches!(&*self.pat_ty.ty, Type::Path(ty) if path_ends_with(&ty.path, "Rest"))
+	}
+
 	pub(crate) fn get_type_without_lifetimes(&self) -> Box<Type> {
 		let mut ty = self.pat_ty.ty.clone();
 		visit_type_mut(&mut LifetimeRemover, &mut ty);
@@ -71,13 +89,40 @@ impl Parameter {
 			}
 		};
 
+		if self.varargs {
+			return parse2(quote_spanned!(span =>
+				let #pat_ty = (0..__accessor.len())
+					.map(|_| #ion::conversions::FromValue::from_value(__cx, &__accessor.value(), false, #convert))
+					.collect::<#ion::Result<_>>()?;
+			));
+		}
+
+		let default = match &self.default {
+			Some(DefaultValue::Default) => quote_spanned!(span => ::std::default::Default::default()),
+			Some(DefaultValue::Literal(lit)) => quote_spanned!(span => #lit),
+			Some(DefaultValue::Closure(closure)) => quote_spanned!(span => (#closure)()),
+			Some(DefaultValue::Expr(expr)) => quote_spanned!(span => #expr),
+			None => {
+				return parse2(quote_spanned!(span =>
+					let #pat_ty = #ion::function::FromArgument::from_argument(&mut __accessor, #convert)?;
+				));
+			}
+		};
+
 		parse2(quote_spanned!(span =>
-			let #pat_ty = #ion::function::FromArgument::from_argument(&mut __accessor, #convert)?;
+			let #pat_ty = match __accessor.optional_value() {
+				::std::option::Option::Some(__value) => #ion::conversions::FromValue::from_value(__cx, &__value, false, #convert)?,
+				::std::option::Option::None => #default,
+			};
 		))
 	}
 }
 
 impl ThisParameter {
+	pub(crate) fn is_object(&self) -> bool {
+		matches!(self.kind, ThisKind::Object)
+	}
+
 	pub(crate) fn from_arg(arg: &FnArg, class_ty: Option<&Type>) -> Result<Option<ThisParameter>> {
 		match arg {
 			FnArg::Typed(pat_ty) => {
@@ -182,7 +227,7 @@ impl Parameters {
 					Err(e) => return Some(Err(e)),
 				};
 				if let Type::Path(ty) = &*param.pat_ty.ty {
-					if !path_ends_with(&ty.path, "Opt") && !path_ends_with(&ty.path, "Rest") {
+					if param.default.is_none() && !path_ends_with(&ty.path, "Opt") && !param.is_variadic() {
 						nargs = match nargs.checked_add(1) {
 							Some(nargs) => nargs,
 							None => return Some(Err(Error::new(arg.span(), "Function has too many arguments"))),
@@ -191,7 +236,16 @@ impl Parameters {
 				}
 				Some(Ok(param))
 			})
-			.collect::<Result<_>>()?;
+			.collect::<Result<Vec<_>>>()?;
+
+		if let Some((_, rest)) = parameters.split_last() {
+			if let Some(parameter) = rest.iter().find(|parameter| parameter.is_variadic()) {
+				return Err(Error::new(
+					parameter.pat_ty.span(),
+					"Variadic parameter must be the last parameter.",
+				));
+			}
+		}
 
 		Ok(Parameters { parameters, this, nargs })
 	}
@@ -214,6 +268,13 @@ impl Parameters {
 		Ok((statements, idents))
 	}
 
+	/// Checks if the `this` parameter is a `&self` receiver.
+	pub(crate) fn is_shared_self(&self) -> bool {
+		self.this.as_ref().is_some_and(|(this, ident, _)| {
+			ident == "self" && matches!(&*this.pat_ty.ty, Type::Reference(reference) if reference.mutability.is_none())
+		})
+	}
+
 	pub(crate) fn get_this_ident(&self) -> Option<Ident> {
 		self.this.as_ref().map(|x| x.1.clone())
 	}
@@ -240,6 +301,16 @@ impl Parameters {
 	}
 }
 
+fn is_vec(ty: &Type) -> bool {
+	match ty {
+		Type::Path(ty) => ty.path.segments.last().is_some_and(|segment| {
+			segment.ident == "Vec"
+				&& matches!(&segment.arguments, PathArguments::AngleBracketed(arguments) if arguments.args.len() == 1)
+		}),
+		_ => false,
+	}
+}
+
 pub(crate) fn parse_this(pat_ty: PatType, is_class: bool, span: Span) -> Result<ThisParameter> {
 	match &*pat_ty.ty {
 		Type::Reference(reference) => {
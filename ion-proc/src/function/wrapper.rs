@@ -14,17 +14,39 @@ use crate::function::inner::impl_inner_fn;
 use crate::function::parameter::Parameters;
 use crate::utils::{new_token, path_ends_with};
 
+/// Kind of iterator returned to JS by an iterable method.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum IteratorKind {
+	Sync,
+	Async,
+}
+
 pub(crate) fn impl_wrapper_fn(
 	ion: &TokenStream, mut function: ItemFn, class_ty: Option<&Type>, is_constructor: bool,
+	iterator: Option<IteratorKind>,
 ) -> Result<(ItemFn, Parameters)> {
-	if function.sig.asyncness.is_some() {
+	let is_async = function.sig.asyncness.is_some();
+	if is_async && iterator.is_some() {
+		return Err(Error::new(
+			function.sig.asyncness.span(),
+			"Iterable methods cannot be async.",
+		));
+	}
+	if is_async && is_constructor {
 		return Err(Error::new(
 			function.sig.asyncness.span(),
-			"Async functions cannot be used as methods. Use `Promise::block_on_future` or `future_to_promise` instead.",
+			"Constructors cannot be async.",
 		));
 	}
 
 	let parameters = Parameters::parse(&function.sig.inputs, class_ty)?;
+	let is_async_method = is_async && class_ty.is_some() && parameters.is_shared_self();
+	if is_async && parameters.this.is_some() && !is_async_method {
+		return Err(Error::new(
+			function.sig.asyncness.span(),
+			"Async functions can only have a `&self` parameter, as their futures must be 'static.",
+		));
+	}
 	let (statements, idents) = parameters.to_statements(ion)?;
 
 	let inner = impl_inner_fn(function.clone(), &parameters, class_ty.is_none());
@@ -54,15 +76,27 @@ pub(crate) fn impl_wrapper_fn(
 		ReturnType::Type(_, ty) => *ty.clone(),
 	};
 
-	let result = if let Type::Path(ty) = &output {
-		if path_ends_with(&ty.path, "Result") || path_ends_with(&ty.path, "ResultExc") {
-			quote!(__result.map_err(::std::convert::Into::into))
-		} else {
-			quote!(#ion::ResultExc::<#ty>::Ok(__result))
-		}
+	let is_result = if let Type::Path(ty) = &output {
+		path_ends_with(&ty.path, "Result") || path_ends_with(&ty.path, "ResultExc")
+	} else {
+		false
+	};
+	let output = if is_async {
+		parse_quote!(#ion::Result<#ion::Promise>)
+	} else {
+		output
+	};
+
+	let result = if is_async || is_result {
+		quote!(__result.map_err(::std::convert::Into::into))
 	} else {
 		quote!(#ion::ResultExc::<#output>::Ok(__result))
 	};
+	let result = match iterator {
+		Some(IteratorKind::Sync) => quote!(#result.map(|__result| #ion::Iterator::new(__result, &__args.this()))),
+		Some(IteratorKind::Async) => quote!(#result.map(|__result| #ion::AsyncIterator::new(__result, &__args.this()))),
+		None => result,
+	};
 	let result = quote!(#result.map(Box::new));
 	let result = if !is_constructor {
 		quote!(#result.map(|__result| #ion::conversions::IntoValue::into_value(__result, __cx, &mut __args.rval())))
@@ -74,17 +108,44 @@ pub(crate) fn impl_wrapper_fn(
 
 	let ident = &function.sig.ident;
 	let call = if let Some(class) = class_ty {
-		quote!(#class::#ident)
+		quote!(<#class>::#ident)
 	} else {
 		quote!(inner)
 	};
 
 	let this_ident = parameters.get_this_ident();
-	let inner_call = if this_ident.is_some() && this_ident.unwrap() == "self" {
+	let mut inner_call = if this_ident.is_some() && this_ident.unwrap() == "self" {
 		quote!(#call(self_, #(#idents,)*))
 	} else {
 		quote!(#call(#(#idents,)*))
 	};
+	if is_async {
+		let output = if is_result {
+			quote!(__future.await)
+		} else {
+			quote!(::std::result::Result::<_, ()>::Ok(__future.await))
+		};
+		inner_call = if is_async_method {
+			// The object is rooted until the future completes, so its private data outlives the future.
+			quote!({
+				let __root = #ion::TracedHeap::new(__this.handle().get());
+				let self_ = unsafe { &*::std::ptr::from_ref(self_) };
+				let __future = #inner_call;
+				#ion::Promise::spawn_future(__cx, async move {
+					let __root = __root;
+					let __future = __future;
+					#output
+				})
+				.ok_or_else(|| #ion::Error::new("Failed to spawn future", #ion::ErrorKind::Normal))
+			})
+		} else {
+			quote!({
+				let __future = #inner_call;
+				#ion::Promise::spawn_future(__cx, async move { #output })
+					.ok_or_else(|| #ion::Error::new("Failed to spawn future", #ion::ErrorKind::Normal))
+			})
+		};
+	}
 
 	let body = parse2(quote_spanned!(function.span() => {
 		__args.check_args(__cx, #nargs)?;
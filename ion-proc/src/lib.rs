@@ -15,7 +15,7 @@ use quote::ToTokens;
 use crate::class::impl_js_class;
 use crate::function::impl_js_fn;
 use crate::trace::impl_trace;
-use crate::value::impl_from_value;
+use crate::value::{impl_const_enum, impl_from_value, impl_string_enum, impl_to_value};
 
 pub(crate) mod attribute;
 pub(crate) mod class;
@@ -56,3 +56,27 @@ pub fn from_value(input: TokenStream) -> TokenStream {
 		Err(error) => error.to_compile_error().into(),
 	}
 }
+
+#[proc_macro_derive(ToValue, attributes(ion))]
+pub fn to_value(input: TokenStream) -> TokenStream {
+	match impl_to_value(parse_macro_input!(input)) {
+		Ok(to_value) => to_value.into_token_stream().into(),
+		Err(error) => error.to_compile_error().into(),
+	}
+}
+
+#[proc_macro_derive(ConstEnum, attributes(ion))]
+pub fn const_enum(input: TokenStream) -> TokenStream {
+	match impl_const_enum(parse_macro_input!(input)) {
+		Ok(const_enum) => const_enum.into(),
+		Err(error) => error.to_compile_error().into(),
+	}
+}
+
+#[proc_macro_derive(StringEnum, attributes(ion))]
+pub fn string_enum(input: TokenStream) -> TokenStream {
+	match impl_string_enum(parse_macro_input!(input)) {
+		Ok(string_enum) => string_enum.into(),
+		Err(error) => error.to_compile_error().into(),
+	}
+}
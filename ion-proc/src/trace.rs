@@ -55,7 +55,7 @@ fn impl_body(span: Span, data: &Data) -> Result<Box<Block>> {
 						.iter()
 						.enumerate()
 						.filter_map(|(index, ident)| (!skip.contains(&index)).then_some(ident));
-					match &variant.fields {
+					let mut arm: Arm = match &variant.fields {
 						Fields::Named(_) => parse2(quote_spanned!(variant.span() => Self::#ident { #(#idents,)* } => {
 							#(::mozjs::gc::Traceable::trace(#traced, __ion_tracer));*
 						})),
@@ -63,7 +63,9 @@ fn impl_body(span: Span, data: &Data) -> Result<Box<Block>> {
 							#(::mozjs::gc::Traceable::trace(#traced, __ion_tracer));*
 						})),
 						Fields::Unit => parse2(quote_spanned!(variant.span() => Self::#ident => {})),
-					}
+					}?;
+					arm.attrs = variant.attrs.iter().filter(|attr| attr.path().is_ident("cfg")).cloned().collect();
+					Ok(arm)
 				})
 				.collect::<Result<_>>()?;
 			parse2(quote_spanned!(span => {
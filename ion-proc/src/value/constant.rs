@@ -0,0 +1,95 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::TokenStream;
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Error, Fields, LitStr, Result};
+
+use crate::attribute::krate::crate_from_attributes;
+use crate::attribute::value::{ConstEnumAttribute, StringVariantAttribute};
+use crate::attribute::ParseAttribute;
+
+pub(crate) fn impl_const_enum(mut input: DeriveInput) -> Result<TokenStream> {
+	let ion = &crate_from_attributes(&mut input.attrs);
+
+	let Data::Enum(data) = &input.data else {
+		return Err(Error::new(input.span(), "ConstEnum can only be derived for enums"));
+	};
+	if !input.generics.params.is_empty() {
+		return Err(Error::new(
+			input.generics.span(),
+			"ConstEnum cannot be derived for generic enums",
+		));
+	}
+
+	let ConstEnumAttribute { name: js_name, rename_all } = ConstEnumAttribute::from_attributes("ion", &input.attrs)?;
+	let name = &input.ident;
+	let js_name = js_name.unwrap_or_else(|| LitStr::new(&name.to_string(), name.span()));
+
+	let mut idents = Vec::with_capacity(data.variants.len());
+	let mut constants = Vec::with_capacity(data.variants.len());
+	for variant in &data.variants {
+		if !matches!(variant.fields, Fields::Unit) {
+			return Err(Error::new(variant.span(), "ConstEnum variants cannot have fields"));
+		}
+
+		let StringVariantAttribute { name, skip } = StringVariantAttribute::from_attributes("ion", &variant.attrs)?;
+		let name = name.unwrap_or_else(|| {
+			let ident = variant.ident.to_string();
+			let ident = rename_all.map(|rule| rule.apply(&ident)).unwrap_or(ident);
+			LitStr::new(&ident, variant.ident.span())
+		});
+
+		if !skip {
+			constants.push((&variant.ident, name));
+		}
+		idents.push(&variant.ident);
+	}
+
+	let (constant_idents, constant_names): (Vec<_>, Vec<_>) = constants.into_iter().unzip();
+
+	let error = LitStr::new(&format!("Invalid value for Enumeration {name}"), name.span());
+
+	Ok(quote_spanned!(input.span() =>
+		#[automatically_derived]
+		impl #ion::ConstEnum for #name {
+			const NAME: &'static str = #js_name;
+			const CONSTANTS: &'static [(&'static str, i32)] = &[
+				#((#constant_names, Self::#constant_idents as i32),)*
+			];
+		}
+
+		#[automatically_derived]
+		impl<'cx> #ion::conversions::FromValue<'cx> for #name {
+			type Config = ();
+
+			fn from_value(cx: &'cx #ion::Context, value: &#ion::Value, strict: bool, _: ()) -> #ion::Result<Self> {
+				let value = <i32 as #ion::conversions::FromValue>::from_value(
+					cx,
+					value,
+					strict,
+					#ion::conversions::ConversionBehavior::EnforceRange,
+				)?;
+				#(
+					if value == Self::#constant_idents as i32 {
+						return ::std::result::Result::Ok(Self::#constant_idents);
+					}
+				)*
+				::std::result::Result::Err(#ion::Error::new(#error, #ion::ErrorKind::Type))
+			}
+		}
+
+		#[automatically_derived]
+		impl<'cx> #ion::conversions::ToValue<'cx> for #name {
+			fn to_value(&self, cx: &'cx #ion::Context, value: &mut #ion::Value) {
+				let constant = match self {
+					#(Self::#idents => Self::#idents as i32,)*
+				};
+				#ion::conversions::ToValue::to_value(&constant, cx, value);
+			}
+		}
+	))
+}
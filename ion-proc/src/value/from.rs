@@ -13,6 +13,7 @@ use syn::{
 };
 
 use crate::attribute::krate::crate_from_attributes;
+use crate::attribute::name::RenameRule;
 use crate::attribute::value::{DataAttribute, DefaultValue, FieldAttribute, Tag, VariantAttribute};
 use crate::attribute::{Optional, ParseAttribute};
 use crate::utils::{add_trait_bounds, format_type, path_ends_with};
@@ -36,7 +37,6 @@ pub(crate) fn impl_from_value(mut input: DeriveInput) -> Result<ItemImpl> {
 	}
 
 	let attribute = DataAttribute::from_attributes("ion", &input.attrs)?;
-	let DataAttribute { tag, inherit } = attribute;
 
 	let mut repr = None;
 	for attr in &input.attrs {
@@ -70,7 +70,7 @@ pub(crate) fn impl_from_value(mut input: DeriveInput) -> Result<ItemImpl> {
 
 	let name = &input.ident;
 
-	let (body, requires_object) = impl_body(ion, input.span(), &input.data, name, tag, inherit, repr)?;
+	let (body, requires_object) = impl_body(ion, input.span(), &input.data, name, attribute, repr)?;
 
 	let object = if requires_object {
 		Some(quote_spanned!(input.span() =>
@@ -94,12 +94,14 @@ pub(crate) fn impl_from_value(mut input: DeriveInput) -> Result<ItemImpl> {
 }
 
 fn impl_body(
-	ion: &TokenStream, span: Span, data: &Data, ident: &Ident, tag: Optional<Tag>, inherit: bool, repr: Option<Ident>,
+	ion: &TokenStream, span: Span, data: &Data, ident: &Ident, attribute: DataAttribute, repr: Option<Ident>,
 ) -> Result<(Box<Block>, bool)> {
+	let DataAttribute { tag, inherit, rename_all } = attribute;
+	let rename_all = rename_all.as_ref();
 	match data {
 		Data::Struct(data) => match &data.fields {
 			Fields::Named(fields) => {
-				let mapped = map_fields(ion, &fields.named, None, tag, inherit)?;
+				let mapped = map_fields(ion, &fields.named, None, tag, inherit, rename_all)?;
 				let (requirement, idents, declarations, requires_object) = mapped;
 				parse2(quote_spanned!(span => {
 					#requirement
@@ -109,7 +111,7 @@ fn impl_body(
 				.map(|b| (b, requires_object))
 			}
 			Fields::Unnamed(fields) => {
-				let mapped = map_fields(ion, &fields.unnamed, None, tag, inherit)?;
+				let mapped = map_fields(ion, &fields.unnamed, None, tag, inherit, rename_all)?;
 				let (requirement, idents, declarations, requires_object) = mapped;
 				parse2(quote_spanned!(span => {
 					#requirement
@@ -139,22 +141,28 @@ fn impl_body(
 						Ok(attribute) => attribute,
 						Err(e) => return Some(Err(e)),
 					};
-					let VariantAttribute { tag, inherit, skip } = attribute;
+					let VariantAttribute { name, tag, inherit, skip } = attribute;
 					let tag = Optional(old_tag.0.or(tag.0));
-					let inherit = old_inherit || inherit;
 					if skip {
 						return None;
 					}
+					let variant_string = name.map(|name| name.value()).unwrap_or(variant_string);
+
+					// Newtype variants of untagged enums are parsed from the whole value, as with WebIDL unions.
+					let newtype = matches!(&variant.fields, Fields::Unnamed(fields) if fields.unnamed.len() == 1);
+					let untagged = matches!(tag.0, None | Some(Tag::Untagged));
+					let inherit = old_inherit || inherit || (newtype && untagged);
 
 					let handle_result = quote!(if let ::std::result::Result::Ok(success) = variant {
 						return ::std::result::Result::Ok(success);
 					});
 					match &variant.fields {
 						Fields::Named(fields) => {
-							let mapped = match map_fields(ion, &fields.named, Some(variant_string), tag, inherit) {
-								Ok(mapped) => mapped,
-								Err(e) => return Some(Err(e)),
-							};
+							let mapped =
+								match map_fields(ion, &fields.named, Some(variant_string), tag, inherit, rename_all) {
+									Ok(mapped) => mapped,
+									Err(e) => return Some(Err(e)),
+								};
 							let (requirement, idents, declarations, requires_object) = mapped;
 
 							Some(
@@ -170,7 +178,14 @@ fn impl_body(
 							)
 						}
 						Fields::Unnamed(fields) => {
-							let mapped = match map_fields(ion, &fields.unnamed, Some(variant_string), tag, inherit) {
+							let mapped = match map_fields(
+								ion,
+								&fields.unnamed,
+								Some(variant_string),
+								tag,
+								inherit,
+								rename_all,
+							) {
 								Ok(mapped) => mapped,
 								Err(e) => return Some(Err(e)),
 							};
@@ -243,7 +258,7 @@ fn impl_body(
 
 fn map_fields(
 	ion: &TokenStream, fields: &Punctuated<Field, Token![,]>, variant: Option<String>, tag: Optional<Tag>,
-	inherit: bool,
+	inherit: bool, rename_all: Option<&RenameRule>,
 ) -> Result<(TokenStream, Vec<Ident>, Vec<TokenStream>, bool)> {
 	let mut requires_object = matches!(tag.0, Some(Tag::External | Tag::Internal(_)));
 
@@ -253,7 +268,7 @@ fn map_fields(
 				let error = format!("Expected Object at External Tag {variant}");
 				quote!(
 					let __object: #ion::Object = __object.get_as(cx, #variant, true, ())?
-						.ok_or_else(|_| #ion::Error::new(#error, #ion::ErrorKind::Type))?;
+						.ok_or_else(|| #ion::Error::new(#error, #ion::ErrorKind::Type))?;
 				)
 			} else {
 				return Err(Error::new(Span::call_site(), "Cannot have Tag for Struct"));
@@ -282,7 +297,12 @@ fn map_fields(
 		.enumerate()
 		.filter_map(|(index, field)| {
 			let (ident, mut key) = if let Some(ident) = &field.ident {
-				(ident.clone(), ident.to_string().to_case(Case::Camel))
+				let name = ident.to_string();
+				let key = match rename_all {
+					Some(rename_all) => rename_all.apply(&name),
+					None => name.to_case(Case::Camel),
+				};
+				(ident.clone(), key)
 			} else {
 				let ident = format_ident!("field{}", index);
 				(ident, index.to_string())
@@ -4,6 +4,12 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
+pub(crate) use constant::*;
 pub(crate) use from::*;
+pub(crate) use string::*;
+pub(crate) use to::*;
 
+pub(crate) mod constant;
 pub(crate) mod from;
+pub(crate) mod string;
+pub(crate) mod to;
@@ -0,0 +1,101 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::TokenStream;
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Error, Fields, LitStr, Result};
+
+use crate::attribute::krate::crate_from_attributes;
+use crate::attribute::name::RenameRule;
+use crate::attribute::value::{StringEnumAttribute, StringVariantAttribute};
+use crate::attribute::ParseAttribute;
+
+pub(crate) fn impl_string_enum(mut input: DeriveInput) -> Result<TokenStream> {
+	let ion = &crate_from_attributes(&mut input.attrs);
+
+	let Data::Enum(data) = &input.data else {
+		return Err(Error::new(input.span(), "StringEnum can only be derived for enums"));
+	};
+	if !input.generics.params.is_empty() {
+		return Err(Error::new(
+			input.generics.span(),
+			"StringEnum cannot be derived for generic enums",
+		));
+	}
+
+	let StringEnumAttribute { rename_all } = StringEnumAttribute::from_attributes("ion", &input.attrs)?;
+	let rename_all = rename_all.unwrap_or(RenameRule::KEBAB);
+
+	let mut idents = Vec::with_capacity(data.variants.len());
+	let mut strings = Vec::with_capacity(data.variants.len());
+	let mut parsed = Vec::with_capacity(data.variants.len());
+	for variant in &data.variants {
+		if !matches!(variant.fields, Fields::Unit) {
+			return Err(Error::new(variant.span(), "StringEnum variants cannot have fields"));
+		}
+
+		let StringVariantAttribute { name, skip } = StringVariantAttribute::from_attributes("ion", &variant.attrs)?;
+		let name = name.unwrap_or_else(|| {
+			let ident = variant.ident.to_string();
+			LitStr::new(&rename_all.apply(&ident), variant.ident.span())
+		});
+
+		if !skip {
+			parsed.push((&variant.ident, name.clone()));
+		}
+		idents.push(&variant.ident);
+		strings.push(name);
+	}
+
+	let (parsed_idents, parsed_strings): (Vec<_>, Vec<_>) = parsed.into_iter().unzip();
+
+	let name = &input.ident;
+	let error = LitStr::new(&format!("Invalid value for Enumeration {name}"), name.span());
+
+	Ok(quote_spanned!(input.span() =>
+		#[automatically_derived]
+		impl ::std::str::FromStr for #name {
+			type Err = #ion::Error;
+
+			fn from_str(string: &str) -> #ion::Result<Self> {
+				match string {
+					#(#parsed_strings => ::std::result::Result::Ok(Self::#parsed_idents),)*
+					_ => ::std::result::Result::Err(#ion::Error::new(#error, #ion::ErrorKind::Type)),
+				}
+			}
+		}
+
+		#[automatically_derived]
+		impl ::std::fmt::Display for #name {
+			fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+				let string = match self {
+					#(Self::#idents => #strings,)*
+				};
+				f.write_str(string)
+			}
+		}
+
+		#[automatically_derived]
+		impl<'cx> #ion::conversions::FromValue<'cx> for #name {
+			type Config = ();
+
+			fn from_value(cx: &'cx #ion::Context, value: &#ion::Value, strict: bool, _: ()) -> #ion::Result<Self> {
+				let string = <::std::string::String as #ion::conversions::FromValue>::from_value(cx, value, strict, ())?;
+				<Self as ::std::str::FromStr>::from_str(&string)
+			}
+		}
+
+		#[automatically_derived]
+		impl<'cx> #ion::conversions::ToValue<'cx> for #name {
+			fn to_value(&self, cx: &'cx #ion::Context, value: &mut #ion::Value) {
+				let string = match self {
+					#(Self::#idents => #strings,)*
+				};
+				#ion::conversions::ToValue::to_value(string, cx, value);
+			}
+		}
+	))
+}
@@ -0,0 +1,92 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use convert_case::{Case, Casing};
+use syn::spanned::Spanned;
+use syn::{parse2, Data, DeriveInput, Error, Fields, GenericParam, Generics, ItemImpl, Result, Type};
+
+use crate::attribute::krate::crate_from_attributes;
+use crate::attribute::value::{ToDataAttribute, ToFieldAttribute};
+use crate::attribute::ParseAttribute;
+use crate::utils::{add_trait_bounds, path_ends_with};
+
+pub(crate) fn impl_to_value(mut input: DeriveInput) -> Result<ItemImpl> {
+	let ion = &crate_from_attributes(&mut input.attrs);
+
+	add_trait_bounds(&mut input.generics, &parse_quote!(#ion::conversions::ToValue<'cx>));
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+	let mut impl_generics: Generics = parse2(quote_spanned!(impl_generics.span() => #impl_generics))?;
+
+	let has_cx = impl_generics.params.iter().any(|param| {
+		if let GenericParam::Lifetime(lt) = param {
+			lt.lifetime == parse_quote!('cx)
+		} else {
+			false
+		}
+	});
+	if !has_cx {
+		impl_generics.params.insert(0, parse2(quote!('cx))?);
+	}
+
+	let fields = match &input.data {
+		Data::Struct(data) => match &data.fields {
+			Fields::Named(fields) => &fields.named,
+			_ => {
+				return Err(Error::new(
+					data.fields.span(),
+					"#[derive(ToValue)] is only implemented for structs with named fields",
+				))
+			}
+		},
+		_ => {
+			return Err(Error::new(
+				input.span(),
+				"#[derive(ToValue)] is only implemented for structs",
+			))
+		}
+	};
+
+	let ToDataAttribute { rename_all } = ToDataAttribute::from_attributes("ion", &input.attrs)?;
+
+	let mut statements = Vec::with_capacity(fields.len());
+	for field in fields {
+		let ToFieldAttribute { name, skip } = ToFieldAttribute::from_attributes("ion", &field.attrs)?;
+		if skip {
+			continue;
+		}
+
+		let ident = field.ident.as_ref().unwrap();
+		let key = match (name, &rename_all) {
+			(Some(name), _) => name.value(),
+			(None, Some(rename_all)) => rename_all.apply(&ident.to_string()),
+			(None, None) => ident.to_string().to_case(Case::Camel),
+		};
+
+		let optional = matches!(&field.ty, Type::Path(ty) if path_ends_with(&ty.path, "Option"));
+		let statement = if optional {
+			quote_spanned!(field.span() =>
+				if let ::std::option::Option::Some(#ident) = &self.#ident {
+					__object.set_as(cx, #key, #ident);
+				}
+			)
+		} else {
+			quote_spanned!(field.span() => __object.set_as(cx, #key, &self.#ident);)
+		};
+		statements.push(statement);
+	}
+
+	let name = &input.ident;
+	parse2(quote_spanned!(input.span() =>
+		#[automatically_derived]
+		impl #impl_generics #ion::conversions::ToValue<'cx> for #name #ty_generics #where_clause {
+			fn to_value(&self, cx: &'cx #ion::Context, value: &mut #ion::Value) {
+				let __object = #ion::Object::new(cx);
+				#(#statements)*
+				#ion::conversions::ToValue::to_value(&__object, cx, value);
+			}
+		}
+	))
+}
@@ -4,6 +4,9 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
+use std::collections::HashMap;
+
+use proc_macro2::Ident;
 use syn::punctuated::Punctuated;
 use syn::visit_mut::{visit_type_mut, visit_type_path_mut, visit_type_reference_mut, VisitMut};
 use syn::{GenericArgument, PathArguments, Type, TypePath, TypeReference};
@@ -48,3 +51,23 @@ impl VisitMut for SelfRenamer<'_> {
 		visit_type_mut(self, ty);
 	}
 }
+
+pub(crate) struct GenericReplacer {
+	pub(crate) replacements: HashMap<Ident, Type>,
+}
+
+impl VisitMut for GenericReplacer {
+	fn visit_type_mut(&mut self, ty: &mut Type) {
+		if let Type::Path(typ) = ty {
+			if let Some(ident) = typ.path.get_ident() {
+				if typ.qself.is_none() {
+					if let Some(replacement) = self.replacements.get(ident) {
+						*ty = replacement.clone();
+						return;
+					}
+				}
+			}
+		}
+		visit_type_mut(self, ty);
+	}
+}
@@ -0,0 +1,21 @@
+use ion::ConstEnum;
+
+#[derive(ConstEnum)]
+pub enum Direction {
+	Up,
+	Down,
+	Left,
+	Right,
+}
+
+#[derive(ConstEnum)]
+#[ion(name = "Level", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LogLevel {
+	Debug = 10,
+	Info = 20,
+	Warning = 30,
+	#[ion(name = "ERR")]
+	Error = 40,
+	#[ion(skip)]
+	Internal = 50,
+}
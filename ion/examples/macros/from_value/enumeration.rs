@@ -7,3 +7,19 @@ enum Representation {
 	One = 1,
 	Ten = 10,
 }
+
+#[derive(FromValue)]
+pub enum Union {
+	Number(f64),
+	String(String),
+	Sequence(Vec<String>),
+}
+
+#[derive(FromValue)]
+#[ion(tag = "type")]
+pub enum Shape {
+	#[ion(rename = "circle")]
+	Circle { radius: f64 },
+	#[ion(rename = "rectangle")]
+	Rectangle { width: f64, height: f64 },
+}
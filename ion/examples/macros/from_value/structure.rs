@@ -26,3 +26,13 @@ pub struct Complex<'cx> {
 fn parse_as_atomic_arc(cx: &Context, value: Value) -> Result<Arc<AtomicU64>> {
 	u64::from_value(cx, &value, true, ConversionBehavior::Default).map(|num| Arc::new(AtomicU64::new(num)))
 }
+
+#[derive(FromValue)]
+#[ion(rename_all = "snake_case")]
+pub struct Renamed {
+	pub byte_length: u32,
+	#[ion(rename = "type")]
+	pub kind: String,
+	#[ion(default = 8)]
+	pub high_water_mark: u32,
+}
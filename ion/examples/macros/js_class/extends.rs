@@ -0,0 +1,44 @@
+use ion::class::Reflector;
+use ion::js_class;
+
+#[js_class]
+pub struct Shape {
+	reflector: Reflector,
+	sides: u32,
+}
+
+#[js_class]
+impl Shape {
+	#[ion(constant)]
+	pub const MAX_SIDES: i32 = 12;
+
+	#[ion(constructor)]
+	pub fn constructor(sides: u32) -> Shape {
+		Shape { reflector: Reflector::default(), sides }
+	}
+
+	#[ion(get)]
+	pub fn get_sides(&self) -> u32 {
+		self.sides
+	}
+}
+
+#[js_class]
+#[ion(extends = Shape)]
+pub struct Square {
+	shape: Shape,
+	#[ion(accessor)]
+	length: f64,
+}
+
+#[js_class]
+impl Square {
+	#[ion(constructor)]
+	pub fn constructor(length: f64) -> Square {
+		Square { shape: Shape::constructor(4), length }
+	}
+
+	pub fn area(&self) -> f64 {
+		self.length * self.length
+	}
+}
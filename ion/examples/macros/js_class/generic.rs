@@ -0,0 +1,42 @@
+use ion::class::Reflector;
+use ion::js_class;
+
+#[js_class]
+#[ion(instances = ["NumberStack" = Stack<f64>, "StringStack" = Stack<String>])]
+pub struct Stack<T> {
+	reflector: Reflector,
+	#[ion(accessor, readonly)]
+	limit: i32,
+	items: Vec<T>,
+}
+
+#[js_class]
+#[ion(instances = [Stack<f64>, Stack<String>])]
+impl<T> Stack<T> {
+	#[ion(constructor)]
+	pub fn constructor(limit: i32) -> Stack<T> {
+		Stack {
+			reflector: Reflector::default(),
+			limit,
+			items: Vec::new(),
+		}
+	}
+
+	pub fn push(&mut self, item: T) -> bool {
+		if self.get_length() < self.limit {
+			self.items.push(item);
+			true
+		} else {
+			false
+		}
+	}
+
+	pub fn pop(&mut self) -> Option<T> {
+		self.items.pop()
+	}
+
+	#[ion(get)]
+	pub fn get_length(&self) -> i32 {
+		i32::try_from(self.items.len()).unwrap_or(i32::MAX)
+	}
+}
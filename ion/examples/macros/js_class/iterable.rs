@@ -0,0 +1,37 @@
+use std::ops::Range;
+
+use ion::class::Reflector;
+use ion::{js_class, Context, JSAsyncIterator, Promise, Value};
+
+#[js_class]
+pub struct Countdown {
+	reflector: Reflector,
+	from: u32,
+}
+
+#[js_class]
+impl Countdown {
+	#[ion(constructor)]
+	pub fn constructor(from: u32) -> Countdown {
+		Countdown { reflector: Reflector::default(), from }
+	}
+
+	#[ion(iterable)]
+	pub fn iterator(&self) -> std::iter::Rev<Range<u32>> {
+		(0..self.from).rev()
+	}
+
+	#[ion(async_iterable)]
+	pub fn async_iterator(&self) -> AsyncCountdown {
+		AsyncCountdown(self.from)
+	}
+}
+
+pub struct AsyncCountdown(u32);
+
+impl JSAsyncIterator for AsyncCountdown {
+	fn next_promise<'cx>(&mut self, cx: &'cx Context, _: &Value<'cx>) -> Option<Promise<'cx>> {
+		self.0 = self.0.checked_sub(1)?;
+		Some(Promise::resolved(cx, &Value::u32(cx, self.0)))
+	}
+}
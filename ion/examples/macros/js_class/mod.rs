@@ -1 +1,8 @@
+pub mod extends;
+pub mod generic;
+pub mod iterable;
+pub mod overload;
+pub mod rename;
+pub mod stability;
 pub mod toggle;
+pub mod traced;
@@ -0,0 +1,32 @@
+use ion::class::Reflector;
+use ion::js_class;
+
+#[js_class]
+pub struct Point {
+	reflector: Reflector,
+	x: f64,
+	y: f64,
+}
+
+#[js_class]
+impl Point {
+	#[ion(constructor)]
+	pub fn origin() -> Point {
+		Point::at(0.0, 0.0)
+	}
+
+	#[ion(constructor)]
+	pub fn at(x: f64, y: f64) -> Point {
+		Point { reflector: Reflector::default(), x, y }
+	}
+
+	#[ion(name = "translate")]
+	pub fn translate_both(&mut self, offset: f64) {
+		self.translate(offset, offset);
+	}
+
+	pub fn translate(&mut self, x: f64, y: f64) {
+		self.x += x;
+		self.y += y;
+	}
+}
@@ -0,0 +1,38 @@
+use ion::class::Reflector;
+use ion::js_class;
+
+#[js_class]
+#[ion(name = "Renamed")]
+pub struct Rename {
+	reflector: Reflector,
+	value: i32,
+}
+
+#[js_class]
+#[ion(rename_all = "PascalCase")]
+impl Rename {
+	pub const MAX_VALUE: i32 = 100;
+
+	#[ion(constructor)]
+	pub fn constructor() -> Rename {
+		Rename {
+			reflector: Reflector::default(),
+			value: 0,
+		}
+	}
+
+	#[ion(name = "typeof")]
+	pub fn kind(&self) -> String {
+		String::from("rename")
+	}
+
+	pub fn increment_value(&mut self) -> i32 {
+		self.value += 1;
+		self.value
+	}
+
+	#[ion(get)]
+	pub fn get_current_value(&self) -> i32 {
+		self.value
+	}
+}
@@ -0,0 +1,58 @@
+use ion::class::Reflector;
+use ion::js_class;
+
+#[js_class]
+pub struct Counter {
+	reflector: Reflector,
+	count: u32,
+}
+
+#[js_class]
+impl Counter {
+	#[ion(constructor)]
+	pub fn constructor() -> Counter {
+		Counter {
+			reflector: Reflector::default(),
+			count: 0,
+		}
+	}
+
+	pub fn increment(&mut self) -> u32 {
+		self.count += 1;
+		self.count
+	}
+
+	#[ion(deprecated = "Use `increment` instead.")]
+	pub fn inc(&mut self) -> u32 {
+		self.increment()
+	}
+
+	#[ion(unstable)]
+	pub fn reset(&mut self) {
+		self.count = 0;
+	}
+
+	#[ion(get, deprecated = "Use `increment()` to read the count.")]
+	pub fn get_value(&self) -> u32 {
+		self.count
+	}
+
+	#[ion(get, unstable)]
+	pub fn get_doubled(&self) -> u32 {
+		self.count * 2
+	}
+}
+
+#[js_class]
+#[ion(unstable)]
+pub struct Gauge {
+	reflector: Reflector,
+}
+
+#[js_class]
+impl Gauge {
+	#[ion(constructor)]
+	pub fn constructor() -> Gauge {
+		Gauge { reflector: Reflector::default() }
+	}
+}
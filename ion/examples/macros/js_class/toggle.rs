@@ -25,15 +25,15 @@ impl Toggle {
 		true
 	}
 
-	#[ion(name = "callback", alias = ["if_toggled", "if_switched"])]
+	#[ion(name = "callback", alias = ["ifToggled", "ifSwitched"])]
 	pub fn if_toggled(&self, cx: &Context, function: Function) -> Result<String> {
 		let value = function.call(cx, &Object::null(cx), &[Value::i32(cx, self.toggled)]).unwrap();
 		String::from_value(cx, &value, false, ())
 	}
 
 	#[ion(get, alias = ["switch"])]
-	pub fn get_toggle(&self) -> i32 {
-		self.toggled * i32::from(self.toggle)
+	pub fn get_toggle(&self) -> bool {
+		self.toggle
 	}
 
 	#[ion(set)]
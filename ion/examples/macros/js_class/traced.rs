@@ -0,0 +1,36 @@
+use std::time::Instant;
+
+use ion::class::Reflector;
+use ion::{js_class, Context, Value};
+use mozjs::jsapi::Heap;
+use mozjs::jsval::JSVal;
+
+#[js_class]
+pub struct Memo {
+	reflector: Reflector,
+	value: Box<Heap<JSVal>>,
+	#[trace(skip)]
+	created: Instant,
+}
+
+#[js_class]
+impl Memo {
+	#[ion(constructor)]
+	pub fn constructor(value: Value) -> Memo {
+		Memo {
+			reflector: Reflector::default(),
+			value: Heap::boxed(value.get()),
+			created: Instant::now(),
+		}
+	}
+
+	#[ion(get)]
+	pub fn get_value<'cx>(&self, cx: &'cx Context) -> Value<'cx> {
+		Value::from(cx.root(self.value.get()))
+	}
+
+	#[ion(get)]
+	pub fn get_age(&self) -> f64 {
+		self.created.elapsed().as_secs_f64()
+	}
+}
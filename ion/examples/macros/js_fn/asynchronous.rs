@@ -0,0 +1,15 @@
+use ion::{js_fn, Error, ErrorKind, Result};
+
+#[js_fn]
+pub async fn asynchronous(integer: i32) -> i32 {
+	integer
+}
+
+#[js_fn]
+pub async fn asynchronous_result(string: String) -> Result<String> {
+	if string.is_empty() {
+		Err(Error::new("Expected Non-Empty String", ErrorKind::Type))
+	} else {
+		Ok(string)
+	}
+}
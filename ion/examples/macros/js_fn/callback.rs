@@ -0,0 +1,23 @@
+use ion::function::Callback;
+use ion::{js_fn, Context, Error, Object, Promise, Result};
+
+#[js_fn]
+pub fn map_number(cx: &Context, callback: Callback<(f64,), f64>, number: f64) -> Result<f64> {
+	callback
+		.call(cx, &Object::null(cx), (number,))
+		.map_err(|_| Error::new("Callback Failed", None))
+}
+
+#[js_fn]
+pub fn map_string<'cx>(
+	cx: &'cx Context, callback: Callback<(String,), String>, string: String,
+) -> Option<Promise<'cx>> {
+	let cx2 = unsafe { Context::new_unchecked(cx.as_ptr()) };
+	Promise::spawn_future(cx, async move {
+		let this = Object::null(&cx2);
+		callback
+			.call_async(&cx2, &this, (string,))
+			.await
+			.map_err(|_| Error::new("Callback Failed", None))
+	})
+}
@@ -0,0 +1,15 @@
+use ion::conversions::ConversionBehavior;
+use ion::js_fn;
+
+#[js_fn]
+pub fn default_literal(#[ion(default = 10)] _integer: i32) {}
+
+#[js_fn]
+pub fn default_trait(#[ion(default)] _string: String, #[ion(default)] _boolean: bool) {}
+
+#[js_fn]
+pub fn default_expression(
+	#[ion(convert = ConversionBehavior::Clamp, default = u8::MAX)] _integer: u8,
+	#[ion(default = || vec![1.0, 2.0])] _numbers: Vec<f64>,
+) {
+}
@@ -0,0 +1,13 @@
+use ion::js_fn;
+
+#[js_fn]
+#[ion(deprecated = "Use `sum` instead.")]
+pub fn add(a: f64, b: f64) -> f64 {
+	a + b
+}
+
+#[js_fn]
+#[ion(unstable)]
+pub fn subtract(a: f64, b: f64) -> f64 {
+	a - b
+}
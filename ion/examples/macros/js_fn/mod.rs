@@ -1,5 +1,9 @@
+pub mod asynchronous;
+pub mod callback;
 pub mod complex;
 pub mod context;
+pub mod default;
+pub mod deprecated;
 pub mod integer;
 pub mod object;
 pub mod output;
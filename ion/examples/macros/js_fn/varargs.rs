@@ -1,3 +1,4 @@
+use ion::conversions::ConversionBehavior;
 use ion::function::{Enforce, Rest};
 use ion::{js_fn, Object};
 
@@ -9,3 +10,12 @@ pub fn varargs_integer(Rest(_integers): Rest<Enforce<i64>>) {}
 
 #[js_fn]
 pub fn varargs_object(Rest(_objects): Rest<Object>) {}
+
+#[js_fn]
+pub fn varargs_vec(#[ion(varargs)] _strings: Vec<String>) {}
+
+#[js_fn]
+pub fn varargs_vec_integer(
+	_first: String, #[ion(varargs, convert = ConversionBehavior::EnforceRange)] _integers: Vec<i64>,
+) {
+}
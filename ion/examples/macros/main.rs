@@ -1,3 +1,6 @@
+pub mod const_enum;
 pub mod from_value;
 pub mod js_class;
 pub mod js_fn;
+pub mod string_enum;
+pub mod to_value;
@@ -0,0 +1,18 @@
+use ion::StringEnum;
+
+#[derive(StringEnum)]
+pub enum Kebab {
+	Same,
+	CrossOrigin,
+	#[ion(name = "")]
+	Empty,
+}
+
+#[derive(StringEnum)]
+#[ion(rename_all = "camelCase")]
+pub enum Camel {
+	FirstValue,
+	SecondValue,
+	#[ion(skip)]
+	Internal,
+}
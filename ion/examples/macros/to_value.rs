@@ -0,0 +1,11 @@
+use ion::ToValue;
+
+#[derive(ToValue)]
+pub struct Dictionary {
+	pub byte_length: u64,
+	#[ion(rename = "type")]
+	pub kind: String,
+	pub description: Option<String>,
+	#[ion(skip)]
+	pub internal: bool,
+}
@@ -6,6 +6,7 @@
 
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
+use std::str;
 
 use mozjs::jsapi::mozilla::{Range, RangedPtr};
 use mozjs::jsapi::{
@@ -35,6 +36,45 @@ impl<'b> BigInt<'b> {
 		BigInt::from(cx.root(unsafe { BigIntFromUint64(cx.as_ptr(), number) }))
 	}
 
+	/// Creates a [BigInt] from a 128-bit signed integer.
+	pub fn from_i128(cx: &Context, number: i128) -> BigInt {
+		match i64::try_from(number) {
+			Ok(number) => BigInt::from_i64(cx, number),
+			Err(_) => BigInt::from_string(cx, &number.to_string()).unwrap(),
+		}
+	}
+
+	/// Creates a [BigInt] from a 128-bit unsigned integer.
+	pub fn from_u128(cx: &Context, number: u128) -> BigInt {
+		match u64::try_from(number) {
+			Ok(number) => BigInt::from_u64(cx, number),
+			Err(_) => BigInt::from_string(cx, &number.to_string()).unwrap(),
+		}
+	}
+
+	/// Creates a [BigInt] from its sign and the big-endian bytes of its magnitude.
+	pub fn from_digits(cx: &Context, negative: bool, digits: &[u8]) -> BigInt {
+		let mut digits = digits.to_vec();
+		let mut decimal = Vec::new();
+		while digits.iter().any(|digit| *digit != 0) {
+			let mut remainder = 0;
+			for digit in &mut digits {
+				let current = (remainder << 8) | *digit as u16;
+				*digit = (current / 10) as u8;
+				remainder = current % 10;
+			}
+			decimal.push(b'0' + remainder as u8);
+		}
+
+		if decimal.is_empty() {
+			decimal.push(b'0');
+		} else if negative {
+			decimal.push(b'-');
+		}
+		decimal.reverse();
+		BigInt::from_string(cx, str::from_utf8(&decimal).unwrap()).unwrap()
+	}
+
 	/// Creates a [BigInt] from a double.
 	/// Returns an error if `number` is `NaN`, `Infinity`, `-Infinity` or contains a fractional component.
 	pub fn from_f64(cx: &Context, number: f64) -> Result<BigInt, Exception> {
@@ -89,6 +129,37 @@ impl<'b> BigInt<'b> {
 		unsafe { BigIntIsUint64(self.get(), &mut result).then_some(result) }
 	}
 
+	/// Converts a [BigInt] to a 128-bit signed integer if possible.
+	pub fn to_i128(&self, cx: &Context) -> Option<i128> {
+		match self.to_i64() {
+			Some(number) => Some(number as i128),
+			None => self.to_string(cx, 10)?.to_owned(cx).ok()?.parse().ok(),
+		}
+	}
+
+	/// Converts a [BigInt] to a 128-bit unsigned integer if possible.
+	pub fn to_u128(&self, cx: &Context) -> Option<u128> {
+		match self.to_u64() {
+			Some(number) => Some(number as u128),
+			None => self.to_string(cx, 10)?.to_owned(cx).ok()?.parse().ok(),
+		}
+	}
+
+	/// Returns the sign and the big-endian bytes of the magnitude of the [BigInt].
+	/// The magnitude contains no leading zero bytes, and is empty for zero.
+	pub fn to_digits(&self, cx: &Context) -> (bool, Vec<u8>) {
+		let string = self.to_string(cx, 16).unwrap().to_owned(cx).unwrap();
+		let hex = string.trim_start_matches('-').trim_start_matches('0').as_bytes();
+
+		let mut digits = Vec::with_capacity(hex.len().div_ceil(2));
+		let (head, tail) = hex.split_at(hex.len() % 2);
+		if let Some(nibble) = head.first() {
+			digits.push(hex_value(*nibble));
+		}
+		digits.extend(tail.chunks_exact(2).map(|pair| (hex_value(pair[0]) << 4) | hex_value(pair[1])));
+		(self.is_negative(), digits)
+	}
+
 	/// Converts a [BigInt] to a double.
 	/// Returns `Infinity` or `-Infinity` if it does not fit in a double.
 	pub fn to_f64(&self) -> f64 {
@@ -118,6 +189,14 @@ impl<'b> BigInt<'b> {
 	}
 }
 
+fn hex_value(nibble: u8) -> u8 {
+	match nibble {
+		b'0'..=b'9' => nibble - b'0',
+		b'a'..=b'f' => nibble - b'a' + 10,
+		_ => unreachable!(),
+	}
+}
+
 impl<'b> From<Local<'b, *mut JSBigInt>> for BigInt<'b> {
 	fn from(bi: Local<'b, *mut JSBigInt>) -> BigInt<'b> {
 		BigInt { bi }
@@ -23,6 +23,7 @@ pub use crate::class::native::{NativeClass, PrototypeChain, TypeIdWrapper, MAX_P
 pub use crate::class::reflect::{Castable, DerivedFrom, NativeObject, Reflector};
 use crate::conversions::{IntoValue, ToValue};
 use crate::function::NativeFunction;
+use crate::object::init_current_lazy_globals;
 use crate::{class_num_reserved_slots, Context, Error, ErrorKind, Function, Local, Object, Result, Value};
 
 mod native;
@@ -73,6 +74,37 @@ pub trait ClassDefinition: NativeObject {
 		None
 	}
 
+	/// Returns the accessors generated for the fields of the native struct, which are defined on the prototype.
+	fn field_properties() -> Option<&'static [JSPropertySpec]> {
+		None
+	}
+
+	/// Returns whether the class is unstable, in which case its constructor is only defined on the global when unstable
+	/// bindings are enabled.
+	fn unstable() -> bool {
+		false
+	}
+
+	/// Returns the methods marked as unstable, which are only defined when unstable bindings are enabled.
+	fn unstable_functions() -> Option<&'static [JSFunctionSpec]> {
+		None
+	}
+
+	/// Returns the accessors marked as unstable, which are only defined when unstable bindings are enabled.
+	fn unstable_properties() -> Option<&'static [JSPropertySpec]> {
+		None
+	}
+
+	/// Returns the static methods marked as unstable, which are only defined when unstable bindings are enabled.
+	fn unstable_static_functions() -> Option<&'static [JSFunctionSpec]> {
+		None
+	}
+
+	/// Returns the static accessors marked as unstable, which are only defined when unstable bindings are enabled.
+	fn unstable_static_properties() -> Option<&'static [JSPropertySpec]> {
+		None
+	}
+
 	fn init_class<'cx>(cx: &'cx Context, object: &Object) -> (bool, &'cx ClassInfo) {
 		let infos = unsafe { &mut (*cx.get_inner_data().as_ptr()).class_infos };
 
@@ -111,8 +143,30 @@ pub trait ClassDefinition: NativeObject {
 				};
 				let prototype = cx.root(class);
 
+				if let Some(field_properties) = Self::field_properties() {
+					assert!(has_zero_spec(Some(field_properties)));
+					let prototype = Object::from(cx.root(prototype.get()));
+					assert!(unsafe { prototype.define_properties(cx, field_properties) });
+				}
+
 				let constructor = unsafe { JS_GetConstructor(cx.as_ptr(), prototype.handle().into()) };
 				let constructor = Object::from(cx.root(constructor));
+
+				if unsafe { (*cx.get_inner_data().as_ptr()).unstable } {
+					let prototype = Object::from(cx.root(prototype.get()));
+					define_unstable_specs(cx, &prototype, Self::unstable_functions(), Self::unstable_properties());
+					define_unstable_specs(
+						cx,
+						&constructor,
+						Self::unstable_static_functions(),
+						Self::unstable_static_properties(),
+					);
+				} else if Self::unstable() {
+					// The class is still initialised, so instances can be created natively.
+					let name = unsafe { CStr::from_ptr(Self::class().base.name) };
+					object.delete(cx, name.to_str().unwrap());
+				}
+
 				let constructor = Function::from_object(cx, &constructor).unwrap();
 
 				let class_info = ClassInfo {
@@ -127,8 +181,7 @@ pub trait ClassDefinition: NativeObject {
 	}
 
 	fn new_raw_object(cx: &Context) -> *mut JSObject {
-		let infos = unsafe { &mut (*cx.get_inner_data().as_ptr()).class_infos };
-		let info = infos.get(&TypeId::of::<Self>()).expect("Uninitialised Class");
+		let info = class_info::<Self>(cx);
 		unsafe {
 			JS_NewObjectWithGivenProto(
 				cx.as_ptr(),
@@ -191,10 +244,7 @@ pub trait ClassDefinition: NativeObject {
 	}
 
 	fn has_instance(cx: &Context, object: &Object) -> Result<bool> {
-		let infos = unsafe { &mut (*cx.get_inner_data().as_ptr()).class_infos };
-		let constructor =
-			Function::from(cx.root(infos.get(&TypeId::of::<Self>()).expect("Uninitialised Class").constructor.get()))
-				.to_object(cx);
+		let constructor = Function::from(cx.root(class_info::<Self>(cx).constructor.get())).to_object(cx);
 		let object = object.as_value(cx);
 		let mut has_instance = false;
 		let result = unsafe {
@@ -213,6 +263,17 @@ pub trait ClassDefinition: NativeObject {
 	}
 }
 
+/// Returns the [ClassInfo] of the class.
+/// If the class has not been initialised, lazily defined globals are initialised first, as they may define it.
+fn class_info<T: 'static>(cx: &Context) -> &ClassInfo {
+	let infos = unsafe { &(*cx.get_inner_data().as_ptr()).class_infos };
+	if !infos.contains_key(&TypeId::of::<T>()) {
+		init_current_lazy_globals(cx);
+	}
+	let infos = unsafe { &(*cx.get_inner_data().as_ptr()).class_infos };
+	infos.get(&TypeId::of::<T>()).expect("Uninitialised Class")
+}
+
 pub struct ClassObjectWrapper<T: ClassDefinition>(pub Box<T>);
 
 impl<T: ClassDefinition> IntoValue<'_> for ClassObjectWrapper<T> {
@@ -245,6 +306,19 @@ fn unwrap_specs<T>(specs: Option<&[T]>) -> *const T {
 	specs.map_or_else(ptr::null, |specs| specs.as_ptr())
 }
 
+fn define_unstable_specs(
+	cx: &Context, object: &Object, functions: Option<&[JSFunctionSpec]>, properties: Option<&[JSPropertySpec]>,
+) {
+	if let Some(functions) = functions {
+		assert!(has_zero_spec(Some(functions)));
+		assert!(unsafe { object.define_methods(cx, functions) });
+	}
+	if let Some(properties) = properties {
+		assert!(has_zero_spec(Some(properties)));
+		assert!(unsafe { object.define_properties(cx, properties) });
+	}
+}
+
 fn check_private<T: ClassDefinition>(cx: &Context, object: &Object) -> Result<()> {
 	if unsafe { class_num_reserved_slots(get_object_class(object.handle().get())) >= 1 }
 		&& (T::instance_of(cx, object) || T::has_instance(cx, object)?)
@@ -21,6 +21,18 @@ use mozjs::jsapi::{
 use crate::conversions::ToValue;
 use crate::{Context, Exception, Object, ResultExc, Value};
 
+/// Callbacks which only support the types built into the engine.
+static BUILTIN_CALLBACKS: JSStructuredCloneCallbacks = JSStructuredCloneCallbacks {
+	read: None,
+	write: None,
+	reportError: None,
+	readTransfer: None,
+	writeTransfer: None,
+	freeTransfer: None,
+	canTransfer: None,
+	sabCloned: None,
+};
+
 pub struct StructuredCloneBuffer {
 	buf: *mut JSAutoStructuredCloneBuffer,
 	scope: StructuredCloneScope,
@@ -129,6 +141,30 @@ impl Drop for StructuredCloneBuffer {
 	}
 }
 
+/// Serializes a [Value] into bytes with the structured clone algorithm.
+///
+/// Transferred objects, such as `ArrayBuffer`s, are copied into the bytes and detached.
+/// The bytes do not contain pointers, so they can be [read](read) by another [Context].
+pub fn write(cx: &Context, value: &Value, transfer: Option<Vec<Object>>) -> ResultExc<Vec<u8>> {
+	let mut buffer = StructuredCloneBuffer::new(StructuredCloneScope::DifferentProcess, &BUILTIN_CALLBACKS, None);
+	buffer.write(cx, value, transfer, &different_process_policy())?;
+	Ok(unsafe { buffer.to_vec() })
+}
+
+/// Deserializes a [Value] from bytes created by [write].
+pub fn read<'cx>(cx: &'cx Context, bytes: &[u8]) -> ResultExc<Value<'cx>> {
+	let buffer = StructuredCloneBuffer::new(StructuredCloneScope::DifferentProcess, &BUILTIN_CALLBACKS, None);
+	unsafe { buffer.write_from_bytes(bytes) };
+	buffer.read(cx, &different_process_policy())
+}
+
+fn different_process_policy() -> CloneDataPolicy {
+	CloneDataPolicy {
+		allowIntraClusterClonableSharedObjects_: false,
+		allowSharedMemoryObjects_: false,
+	}
+}
+
 pub unsafe fn read_uint64(r: *mut JSStructuredCloneReader) -> Option<u64> {
 	let mut high = 0;
 	let mut low = 0;
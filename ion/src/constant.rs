@@ -0,0 +1,41 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use crate::flags::PropertyFlags;
+use crate::{Context, Object};
+
+/// Represents an enumeration of integer constants, which can be exposed as a frozen namespace object.
+///
+/// The namespace object maps each name to its value, and each value back to its name, so `Signal.INT` is `2`
+/// and `Signal[2]` is `"INT"`.
+///
+/// Can be derived with `#[derive(ConstEnum)]` on enums without fields.
+pub trait ConstEnum {
+	/// Name of the namespace object.
+	const NAME: &'static str;
+	/// Names and values of the constants.
+	const CONSTANTS: &'static [(&'static str, i32)];
+
+	/// Creates the frozen namespace object containing the constants.
+	fn namespace(cx: &Context) -> Option<Object> {
+		let namespace = Object::new(cx);
+		for (name, value) in Self::CONSTANTS {
+			if !namespace.define_as(cx, *name, value, PropertyFlags::CONSTANT_ENUMERATED) {
+				return None;
+			}
+			if !namespace.has_own(cx, *value) && !namespace.define_as(cx, *value, *name, PropertyFlags::CONSTANT) {
+				return None;
+			}
+		}
+		namespace.freeze(cx).then_some(namespace)
+	}
+
+	/// Defines the namespace object on the given object, with the key [ConstEnum::NAME].
+	fn define_namespace(cx: &Context, object: &Object) -> bool {
+		Self::namespace(cx)
+			.is_some_and(|namespace| object.define_as(cx, Self::NAME, &namespace, PropertyFlags::CONSTANT_ENUMERATED))
+	}
+}
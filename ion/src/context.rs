@@ -5,6 +5,7 @@
  */
 
 use std::any::{Any, TypeId};
+use std::borrow::Cow;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::ffi::c_void;
@@ -20,7 +21,13 @@ use mozjs::rust::Runtime;
 use private::RootedArena;
 
 use crate::class::ClassInfo;
+use crate::error::ErrorClassInfo;
+use crate::future::FutureSpawner;
+use crate::gc::GCCallbacks;
 use crate::module::ModuleLoader;
+use crate::object::LazyGlobals;
+use crate::profiler::Profiler;
+use crate::script::OffThreadStatistics;
 use crate::Local;
 
 /// Represents Types that can be Rooted in SpiderMonkey
@@ -56,7 +63,15 @@ impl<T: Traceable + Any> TraceablePrivate for T {
 #[derive(Default)]
 pub struct ContextInner {
 	pub class_infos: HashMap<TypeId, ClassInfo>,
+	pub error_classes: HashMap<Cow<'static, str>, ErrorClassInfo>,
+	pub gc_callbacks: GCCallbacks,
+	pub lazy_globals: LazyGlobals,
+	pub off_thread_statistics: OffThreadStatistics,
 	pub module_loader: Option<Box<dyn ModuleLoader>>,
+	pub future_spawner: Option<Box<dyn FutureSpawner>>,
+	pub profiler: Profiler,
+	/// Whether bindings marked with `#[ion(unstable)]` are installed when classes are initialised.
+	pub unstable: bool,
 	private: Option<Box<dyn TraceablePrivate>>,
 }
 
@@ -79,6 +94,9 @@ impl ContextInner {
 			for info in inner.class_infos.values() {
 				info.trace(trc);
 			}
+			for info in inner.error_classes.values() {
+				info.trace(trc);
+			}
 			inner.private.trace(trc);
 		}
 	}
@@ -22,10 +22,20 @@ pub trait ToPropertyKey<'cx> {
 }
 
 macro_rules! impl_to_key_for_integer {
+	(index $ty:ty) => {
+		impl<'cx> ToPropertyKey<'cx> for $ty {
+			fn to_key(&self, cx: &'cx Context) -> Option<PropertyKey<'cx>> {
+				Some(PropertyKey::with_index(cx, u32::from(*self)))
+			}
+		}
+	};
 	($ty:ty) => {
 		impl<'cx> ToPropertyKey<'cx> for $ty {
 			fn to_key(&self, cx: &'cx Context) -> Option<PropertyKey<'cx>> {
-				Some(PropertyKey::with_int(cx, *self as i32))
+				match u32::try_from(*self) {
+					Ok(index) => Some(PropertyKey::with_index(cx, index)),
+					Err(_) => PropertyKey::with_string(cx, &self.to_string()),
+				}
 			}
 		}
 	};
@@ -34,10 +44,14 @@ macro_rules! impl_to_key_for_integer {
 impl_to_key_for_integer!(i8);
 impl_to_key_for_integer!(i16);
 impl_to_key_for_integer!(i32);
-
-impl_to_key_for_integer!(u8);
-impl_to_key_for_integer!(u16);
-impl_to_key_for_integer!(u32);
+impl_to_key_for_integer!(i64);
+impl_to_key_for_integer!(isize);
+
+impl_to_key_for_integer!(index u8);
+impl_to_key_for_integer!(index u16);
+impl_to_key_for_integer!(index u32);
+impl_to_key_for_integer!(u64);
+impl_to_key_for_integer!(usize);
 
 impl<'cx> ToPropertyKey<'cx> for *mut JSString {
 	fn to_key(&self, cx: &'cx Context) -> Option<PropertyKey<'cx>> {
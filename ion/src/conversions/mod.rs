@@ -8,4 +8,6 @@ pub use key::*;
 pub use value::*;
 
 mod key;
+#[cfg(feature = "serde")]
+pub mod serde;
 mod value;
@@ -0,0 +1,269 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::vec;
+
+use serde::de::value::StringDeserializer;
+use serde::de::{
+	self, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess, VariantAccess, Visitor,
+};
+use serde::{forward_to_deserialize_any, Deserialize};
+
+use crate::conversions::serde::SerdeError;
+use crate::conversions::FromValue;
+use crate::typedarray::Uint8ArrayWrapper;
+use crate::{Array, BigInt, Context, Error, ErrorKind, Object, OwnedKey, PropertyKey, Value};
+
+type Result<T> = std::result::Result<T, SerdeError>;
+
+const MAX_SAFE_INTEGER: f64 = ((1u64 << 53) - 1) as f64;
+
+/// Converts a [Value] into a [DeserializeOwned] type.
+///
+/// Objects are deserialized from their own enumerable string and integer keys.
+pub fn from_value<T: DeserializeOwned>(cx: &Context, value: &Value) -> crate::Result<T> {
+	T::deserialize(Deserializer::new(cx, value)).map_err(SerdeError::into_inner)
+}
+
+/// [Deserializer](de::Deserializer) which reads from a [Value].
+pub struct Deserializer<'cx> {
+	cx: &'cx Context,
+	value: Value<'cx>,
+}
+
+impl<'cx> Deserializer<'cx> {
+	pub fn new(cx: &'cx Context, value: &Value) -> Deserializer<'cx> {
+		Deserializer {
+			cx,
+			value: Value::from(cx.root(value.get())),
+		}
+	}
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer<'_> {
+	type Error = SerdeError;
+
+	fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+		let cx = self.cx;
+		let value = self.value.handle();
+
+		if value.is_undefined() || value.is_null() {
+			visitor.visit_unit()
+		} else if value.is_boolean() {
+			visitor.visit_bool(value.to_boolean())
+		} else if value.is_int32() {
+			visitor.visit_i32(value.to_int32())
+		} else if value.is_double() {
+			let number = value.to_double();
+			if number.fract() == 0.0 && number.abs() <= MAX_SAFE_INTEGER {
+				visitor.visit_i64(number as i64)
+			} else {
+				visitor.visit_f64(number)
+			}
+		} else if value.is_string() {
+			visitor.visit_string(String::from_value(cx, &self.value, true, ()).map_err(SerdeError)?)
+		} else if value.is_bigint() {
+			let bigint = BigInt::from(cx.root(value.to_bigint()));
+			if let Some(number) = bigint.to_i64() {
+				visitor.visit_i64(number)
+			} else if let Some(number) = bigint.to_u64() {
+				visitor.visit_u64(number)
+			} else {
+				Err(type_error("BigInt is too large to be deserialized"))
+			}
+		} else if value.is_object() {
+			let object = self.value.to_object(cx);
+			if Array::is_array(cx, &object) {
+				let array = Array::from(cx, object.into_local()).unwrap();
+				visitor.visit_seq(ArrayAccess::new(cx, array))
+			} else {
+				visitor.visit_map(ObjectAccess::new(cx, object))
+			}
+		} else {
+			Err(type_error("Symbols cannot be deserialized"))
+		}
+	}
+
+	fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+		let value = self.value.handle();
+		if value.is_undefined() || value.is_null() {
+			visitor.visit_none()
+		} else {
+			visitor.visit_some(self)
+		}
+	}
+
+	fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+		de::Deserializer::deserialize_byte_buf(self, visitor)
+	}
+
+	fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+		match Uint8ArrayWrapper::from_value(self.cx, &self.value, true, ()) {
+			Ok(bytes) => visitor.visit_byte_buf(bytes.to_vec()),
+			Err(_) => de::Deserializer::deserialize_any(self, visitor),
+		}
+	}
+
+	fn deserialize_newtype_struct<V: Visitor<'de>>(self, _: &'static str, visitor: V) -> Result<V::Value> {
+		visitor.visit_newtype_struct(self)
+	}
+
+	fn deserialize_enum<V: Visitor<'de>>(
+		self, _: &'static str, _: &'static [&'static str], visitor: V,
+	) -> Result<V::Value> {
+		let cx = self.cx;
+		let value = self.value.handle();
+
+		if value.is_string() {
+			let variant = String::from_value(cx, &self.value, true, ()).map_err(SerdeError)?;
+			let variant: StringDeserializer<SerdeError> = variant.into_deserializer();
+			visitor.visit_enum(variant)
+		} else if value.is_object() {
+			let object = self.value.to_object(cx);
+			let mut keys = object.keys(cx, None);
+			if keys.len() == 1 {
+				let key = keys.next().unwrap();
+				if let OwnedKey::String(variant) = key.to_owned_key(cx).map_err(SerdeError)? {
+					let value = object.get(cx, &key).map_err(SerdeError)?.unwrap_or_else(|| Value::undefined(cx));
+					return visitor.visit_enum(VariantDeserializer { cx, variant, value });
+				}
+			}
+			Err(type_error("Expected Object with a Single Key for Enum"))
+		} else {
+			Err(type_error("Expected String or Object for Enum"))
+		}
+	}
+
+	forward_to_deserialize_any! {
+		bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+		unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+	}
+}
+
+impl<'de> VariantAccess<'de> for Deserializer<'_> {
+	type Error = SerdeError;
+
+	fn unit_variant(self) -> Result<()> {
+		<()>::deserialize(self)
+	}
+
+	fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value> {
+		seed.deserialize(self)
+	}
+
+	fn tuple_variant<V: Visitor<'de>>(self, _: usize, visitor: V) -> Result<V::Value> {
+		de::Deserializer::deserialize_seq(self, visitor)
+	}
+
+	fn struct_variant<V: Visitor<'de>>(self, _: &'static [&'static str], visitor: V) -> Result<V::Value> {
+		de::Deserializer::deserialize_map(self, visitor)
+	}
+}
+
+struct ArrayAccess<'cx> {
+	cx: &'cx Context,
+	array: Array<'cx>,
+	index: u32,
+	len: u32,
+}
+
+impl<'cx> ArrayAccess<'cx> {
+	fn new(cx: &'cx Context, array: Array<'cx>) -> ArrayAccess<'cx> {
+		let len = array.len(cx);
+		ArrayAccess { cx, array, index: 0, len }
+	}
+}
+
+impl<'de> SeqAccess<'de> for ArrayAccess<'_> {
+	type Error = SerdeError;
+
+	fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+		if self.index >= self.len {
+			return Ok(None);
+		}
+
+		let value = self.array.get(self.cx, self.index).map_err(SerdeError)?;
+		let value = value.unwrap_or_else(|| Value::undefined(self.cx));
+		self.index += 1;
+		seed.deserialize(Deserializer { cx: self.cx, value }).map(Some)
+	}
+
+	fn size_hint(&self) -> Option<usize> {
+		Some((self.len - self.index) as usize)
+	}
+}
+
+struct ObjectAccess<'cx> {
+	cx: &'cx Context,
+	object: Object<'cx>,
+	keys: vec::IntoIter<PropertyKey<'cx>>,
+	value: Option<Value<'cx>>,
+}
+
+impl<'cx> ObjectAccess<'cx> {
+	fn new(cx: &'cx Context, object: Object<'cx>) -> ObjectAccess<'cx> {
+		let keys: Vec<_> = object.keys(cx, None).collect();
+		ObjectAccess {
+			cx,
+			object,
+			keys: keys.into_iter(),
+			value: None,
+		}
+	}
+}
+
+impl<'de> MapAccess<'de> for ObjectAccess<'_> {
+	type Error = SerdeError;
+
+	fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+		for key in self.keys.by_ref() {
+			let name = match key.to_owned_key(self.cx).map_err(SerdeError)? {
+				OwnedKey::Int(int) => int.to_string(),
+				OwnedKey::String(string) => string,
+				_ => continue,
+			};
+
+			let value = self.object.get(self.cx, &key).map_err(SerdeError)?;
+			self.value = Some(value.unwrap_or_else(|| Value::undefined(self.cx)));
+
+			let name: StringDeserializer<SerdeError> = name.into_deserializer();
+			return seed.deserialize(name).map(Some);
+		}
+		Ok(None)
+	}
+
+	fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+		match self.value.take() {
+			Some(value) => seed.deserialize(Deserializer { cx: self.cx, value }),
+			None => Err(type_error("Value requested before key")),
+		}
+	}
+
+	fn size_hint(&self) -> Option<usize> {
+		Some(self.keys.len())
+	}
+}
+
+struct VariantDeserializer<'cx> {
+	cx: &'cx Context,
+	variant: String,
+	value: Value<'cx>,
+}
+
+impl<'de, 'cx> EnumAccess<'de> for VariantDeserializer<'cx> {
+	type Error = SerdeError;
+	type Variant = Deserializer<'cx>;
+
+	fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Deserializer<'cx>)> {
+		let variant: StringDeserializer<SerdeError> = self.variant.into_deserializer();
+		let variant = seed.deserialize(variant)?;
+		Ok((variant, Deserializer { cx: self.cx, value: self.value }))
+	}
+}
+
+fn type_error(message: &'static str) -> SerdeError {
+	SerdeError(Error::new(message, ErrorKind::Type))
+}
@@ -0,0 +1,48 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::error;
+use std::fmt::{self, Display, Formatter};
+
+pub use de::{from_value, Deserializer};
+pub use ser::{to_value, Serializer};
+
+use crate::{Error, ErrorKind};
+
+mod de;
+mod ser;
+
+/// Error produced while serializing or deserializing a [Value](crate::Value).
+///
+/// This wraps an [Error], since [Error] cannot implement [std::error::Error] itself.
+#[derive(Clone, Debug)]
+pub struct SerdeError(pub Error);
+
+impl SerdeError {
+	pub fn into_inner(self) -> Error {
+		self.0
+	}
+}
+
+impl Display for SerdeError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.0.message)
+	}
+}
+
+impl error::Error for SerdeError {}
+
+impl ::serde::ser::Error for SerdeError {
+	fn custom<T: Display>(msg: T) -> SerdeError {
+		SerdeError(Error::new(msg.to_string(), ErrorKind::Type))
+	}
+}
+
+impl ::serde::de::Error for SerdeError {
+	fn custom<T: Display>(msg: T) -> SerdeError {
+		SerdeError(Error::new(msg.to_string(), ErrorKind::Type))
+	}
+}
@@ -0,0 +1,335 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use serde::ser::{
+	self, Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+	SerializeTupleStruct, SerializeTupleVariant,
+};
+
+use crate::conversions::serde::SerdeError;
+use crate::conversions::ToValue;
+use crate::typedarray::Uint8Array;
+use crate::{Array, BigInt, Context, Error, ErrorKind, Object, Value};
+
+type Result<T> = std::result::Result<T, SerdeError>;
+
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+/// Converts a [Serialize] type into a [Value].
+///
+/// 64-bit integers outside of the safe integer range are converted to [BigInt]s.
+pub fn to_value<'cx, T: Serialize + ?Sized>(cx: &'cx Context, value: &T) -> crate::Result<Value<'cx>> {
+	value.serialize(Serializer::new(cx)).map_err(SerdeError::into_inner)
+}
+
+/// [Serializer](ser::Serializer) which produces a [Value].
+pub struct Serializer<'cx> {
+	cx: &'cx Context,
+}
+
+impl<'cx> Serializer<'cx> {
+	pub fn new(cx: &'cx Context) -> Serializer<'cx> {
+		Serializer { cx }
+	}
+}
+
+impl<'cx> ser::Serializer for Serializer<'cx> {
+	type Ok = Value<'cx>;
+	type Error = SerdeError;
+	type SerializeSeq = ArraySerializer<'cx>;
+	type SerializeTuple = ArraySerializer<'cx>;
+	type SerializeTupleStruct = ArraySerializer<'cx>;
+	type SerializeTupleVariant = VariantSerializer<'cx, ArraySerializer<'cx>>;
+	type SerializeMap = ObjectSerializer<'cx>;
+	type SerializeStruct = ObjectSerializer<'cx>;
+	type SerializeStructVariant = VariantSerializer<'cx, ObjectSerializer<'cx>>;
+
+	fn serialize_bool(self, v: bool) -> Result<Value<'cx>> {
+		Ok(Value::bool(self.cx, v))
+	}
+
+	fn serialize_i8(self, v: i8) -> Result<Value<'cx>> {
+		Ok(Value::i32(self.cx, v as i32))
+	}
+
+	fn serialize_i16(self, v: i16) -> Result<Value<'cx>> {
+		Ok(Value::i32(self.cx, v as i32))
+	}
+
+	fn serialize_i32(self, v: i32) -> Result<Value<'cx>> {
+		Ok(Value::i32(self.cx, v))
+	}
+
+	fn serialize_i64(self, v: i64) -> Result<Value<'cx>> {
+		if v.unsigned_abs() <= MAX_SAFE_INTEGER {
+			Ok(Value::f64(self.cx, v as f64))
+		} else {
+			Ok(Value::bigint(self.cx, &BigInt::from_i64(self.cx, v)))
+		}
+	}
+
+	fn serialize_u8(self, v: u8) -> Result<Value<'cx>> {
+		Ok(Value::u32(self.cx, v as u32))
+	}
+
+	fn serialize_u16(self, v: u16) -> Result<Value<'cx>> {
+		Ok(Value::u32(self.cx, v as u32))
+	}
+
+	fn serialize_u32(self, v: u32) -> Result<Value<'cx>> {
+		Ok(Value::u32(self.cx, v))
+	}
+
+	fn serialize_u64(self, v: u64) -> Result<Value<'cx>> {
+		if v <= MAX_SAFE_INTEGER {
+			Ok(Value::f64(self.cx, v as f64))
+		} else {
+			Ok(Value::bigint(self.cx, &BigInt::from_u64(self.cx, v)))
+		}
+	}
+
+	fn serialize_f32(self, v: f32) -> Result<Value<'cx>> {
+		Ok(Value::f64(self.cx, v as f64))
+	}
+
+	fn serialize_f64(self, v: f64) -> Result<Value<'cx>> {
+		Ok(Value::f64(self.cx, v))
+	}
+
+	fn serialize_char(self, v: char) -> Result<Value<'cx>> {
+		Ok(Value::string(self.cx, v.encode_utf8(&mut [0; 4])))
+	}
+
+	fn serialize_str(self, v: &str) -> Result<Value<'cx>> {
+		Ok(Value::string(self.cx, v))
+	}
+
+	fn serialize_bytes(self, v: &[u8]) -> Result<Value<'cx>> {
+		match Uint8Array::copy_from_bytes(self.cx, v) {
+			Some(array) => Ok(array.as_value(self.cx)),
+			None => Err(SerdeError(Error::new("Failed to create Uint8Array", ErrorKind::Normal))),
+		}
+	}
+
+	fn serialize_none(self) -> Result<Value<'cx>> {
+		Ok(Value::null(self.cx))
+	}
+
+	fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Value<'cx>> {
+		value.serialize(self)
+	}
+
+	fn serialize_unit(self) -> Result<Value<'cx>> {
+		Ok(Value::null(self.cx))
+	}
+
+	fn serialize_unit_struct(self, _: &'static str) -> Result<Value<'cx>> {
+		Ok(Value::null(self.cx))
+	}
+
+	fn serialize_unit_variant(self, _: &'static str, _: u32, variant: &'static str) -> Result<Value<'cx>> {
+		Ok(Value::string(self.cx, variant))
+	}
+
+	fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _: &'static str, value: &T) -> Result<Value<'cx>> {
+		value.serialize(self)
+	}
+
+	fn serialize_newtype_variant<T: Serialize + ?Sized>(
+		self, _: &'static str, _: u32, variant: &'static str, value: &T,
+	) -> Result<Value<'cx>> {
+		let value = value.serialize(Serializer::new(self.cx))?;
+		Ok(wrap_variant(self.cx, variant, &value))
+	}
+
+	fn serialize_seq(self, _: Option<usize>) -> Result<ArraySerializer<'cx>> {
+		Ok(ArraySerializer::new(self.cx))
+	}
+
+	fn serialize_tuple(self, _: usize) -> Result<ArraySerializer<'cx>> {
+		Ok(ArraySerializer::new(self.cx))
+	}
+
+	fn serialize_tuple_struct(self, _: &'static str, _: usize) -> Result<ArraySerializer<'cx>> {
+		Ok(ArraySerializer::new(self.cx))
+	}
+
+	fn serialize_tuple_variant(
+		self, _: &'static str, _: u32, variant: &'static str, _: usize,
+	) -> Result<VariantSerializer<'cx, ArraySerializer<'cx>>> {
+		Ok(VariantSerializer::new(self.cx, variant, ArraySerializer::new(self.cx)))
+	}
+
+	fn serialize_map(self, _: Option<usize>) -> Result<ObjectSerializer<'cx>> {
+		Ok(ObjectSerializer::new(self.cx))
+	}
+
+	fn serialize_struct(self, _: &'static str, _: usize) -> Result<ObjectSerializer<'cx>> {
+		Ok(ObjectSerializer::new(self.cx))
+	}
+
+	fn serialize_struct_variant(
+		self, _: &'static str, _: u32, variant: &'static str, _: usize,
+	) -> Result<VariantSerializer<'cx, ObjectSerializer<'cx>>> {
+		Ok(VariantSerializer::new(self.cx, variant, ObjectSerializer::new(self.cx)))
+	}
+}
+
+pub struct ArraySerializer<'cx> {
+	cx: &'cx Context,
+	array: Array<'cx>,
+	index: u32,
+}
+
+impl<'cx> ArraySerializer<'cx> {
+	fn new(cx: &'cx Context) -> ArraySerializer<'cx> {
+		ArraySerializer { cx, array: Array::new(cx), index: 0 }
+	}
+}
+
+impl<'cx> SerializeSeq for ArraySerializer<'cx> {
+	type Ok = Value<'cx>;
+	type Error = SerdeError;
+
+	fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+		let value = value.serialize(Serializer::new(self.cx))?;
+		self.array.set(self.cx, self.index, &value);
+		self.index += 1;
+		Ok(())
+	}
+
+	fn end(self) -> Result<Value<'cx>> {
+		Ok(self.array.as_value(self.cx))
+	}
+}
+
+impl<'cx> SerializeTuple for ArraySerializer<'cx> {
+	type Ok = Value<'cx>;
+	type Error = SerdeError;
+
+	fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+		SerializeSeq::serialize_element(self, value)
+	}
+
+	fn end(self) -> Result<Value<'cx>> {
+		SerializeSeq::end(self)
+	}
+}
+
+impl<'cx> SerializeTupleStruct for ArraySerializer<'cx> {
+	type Ok = Value<'cx>;
+	type Error = SerdeError;
+
+	fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+		SerializeSeq::serialize_element(self, value)
+	}
+
+	fn end(self) -> Result<Value<'cx>> {
+		SerializeSeq::end(self)
+	}
+}
+
+pub struct ObjectSerializer<'cx> {
+	cx: &'cx Context,
+	object: Object<'cx>,
+	key: Option<Value<'cx>>,
+}
+
+impl<'cx> ObjectSerializer<'cx> {
+	fn new(cx: &'cx Context) -> ObjectSerializer<'cx> {
+		ObjectSerializer { cx, object: Object::new(cx), key: None }
+	}
+}
+
+impl<'cx> SerializeMap for ObjectSerializer<'cx> {
+	type Ok = Value<'cx>;
+	type Error = SerdeError;
+
+	fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+		let key = key.serialize(Serializer::new(self.cx))?;
+		if !key.handle().is_string() && !key.handle().is_number() {
+			return Err(SerdeError(Error::new(
+				"Map keys must be strings or numbers",
+				ErrorKind::Type,
+			)));
+		}
+		self.key = Some(key);
+		Ok(())
+	}
+
+	fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+		let key = self.key.take().expect("serialize_value called before serialize_key");
+		let value = value.serialize(Serializer::new(self.cx))?;
+		self.object.set(self.cx, &key, &value);
+		Ok(())
+	}
+
+	fn end(self) -> Result<Value<'cx>> {
+		Ok(self.object.as_value(self.cx))
+	}
+}
+
+impl<'cx> SerializeStruct for ObjectSerializer<'cx> {
+	type Ok = Value<'cx>;
+	type Error = SerdeError;
+
+	fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<()> {
+		let value = value.serialize(Serializer::new(self.cx))?;
+		self.object.set(self.cx, key, &value);
+		Ok(())
+	}
+
+	fn end(self) -> Result<Value<'cx>> {
+		SerializeMap::end(self)
+	}
+}
+
+/// Serializes enum variants with data as an object with a single key, `{ variant: data }`.
+fn wrap_variant<'cx>(cx: &'cx Context, variant: &'static str, value: &Value) -> Value<'cx> {
+	let object = Object::new(cx);
+	object.set(cx, variant, value);
+	object.as_value(cx)
+}
+
+pub struct VariantSerializer<'cx, S> {
+	cx: &'cx Context,
+	variant: &'static str,
+	inner: S,
+}
+
+impl<'cx, S> VariantSerializer<'cx, S> {
+	fn new(cx: &'cx Context, variant: &'static str, inner: S) -> VariantSerializer<'cx, S> {
+		VariantSerializer { cx, variant, inner }
+	}
+}
+
+impl<'cx> SerializeTupleVariant for VariantSerializer<'cx, ArraySerializer<'cx>> {
+	type Ok = Value<'cx>;
+	type Error = SerdeError;
+
+	fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+		SerializeSeq::serialize_element(&mut self.inner, value)
+	}
+
+	fn end(self) -> Result<Value<'cx>> {
+		let value = SerializeSeq::end(self.inner)?;
+		Ok(wrap_variant(self.cx, self.variant, &value))
+	}
+}
+
+impl<'cx> SerializeStructVariant for VariantSerializer<'cx, ObjectSerializer<'cx>> {
+	type Ok = Value<'cx>;
+	type Error = SerdeError;
+
+	fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<()> {
+		SerializeStruct::serialize_field(&mut self.inner, key, value)
+	}
+
+	fn end(self) -> Result<Value<'cx>> {
+		let value = SerializeMap::end(self.inner)?;
+		Ok(wrap_variant(self.cx, self.variant, &value))
+	}
+}
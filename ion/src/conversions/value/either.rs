@@ -0,0 +1,53 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use crate::conversions::{FromValue, ToValue};
+use crate::{Context, Result, Value};
+
+/// Represents a union of two types, such as a parameter which accepts either a string or a number.
+///
+/// Conversion from a [Value] is attempted as the left type first, then as the right type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Either<L, R> {
+	Left(L),
+	Right(R),
+}
+
+impl<L, R> Either<L, R> {
+	pub fn left(self) -> Option<L> {
+		match self {
+			Either::Left(left) => Some(left),
+			Either::Right(_) => None,
+		}
+	}
+
+	pub fn right(self) -> Option<R> {
+		match self {
+			Either::Left(_) => None,
+			Either::Right(right) => Some(right),
+		}
+	}
+}
+
+impl<'cx, L: FromValue<'cx>, R: FromValue<'cx>> FromValue<'cx> for Either<L, R> {
+	type Config = (L::Config, R::Config);
+
+	fn from_value(cx: &'cx Context, value: &Value, strict: bool, (left, right): Self::Config) -> Result<Either<L, R>> {
+		match L::from_value(cx, value, strict, left) {
+			Ok(left) => Ok(Either::Left(left)),
+			Err(_) => R::from_value(cx, value, strict, right).map(Either::Right),
+		}
+	}
+}
+
+impl<'cx, L: ToValue<'cx>, R: ToValue<'cx>> ToValue<'cx> for Either<L, R> {
+	fn to_value(&self, cx: &'cx Context, value: &mut Value) {
+		match self {
+			Either::Left(left) => left.to_value(cx, value),
+			Either::Right(right) => right.to_value(cx, value),
+		}
+	}
+}
@@ -4,6 +4,11 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::hash::{BuildHasher, Hash};
+
+use chrono::{DateTime, Utc};
 pub use mozjs::conversions::ConversionBehavior;
 use mozjs::conversions::{ConversionResult, FromJSValConvertible};
 use mozjs::jsapi::{
@@ -15,11 +20,13 @@ use mozjs::rust::{ToBoolean, ToNumber, ToString};
 use mozjs::typedarray as jsta;
 use mozjs::typedarray::JSObjectStorage;
 
+use crate::conversions::ToValue;
 use crate::object::RegExp;
 use crate::string::byte::{BytePredicate, ByteString};
 use crate::typedarray::{ArrayBuffer, TypedArray, TypedArrayElement};
 use crate::{
-	Array, Context, Date, Error, ErrorKind, Exception, Function, Object, Promise, Result, StringRef, Symbol, Value,
+	Array, BigInt, Context, Date, Error, ErrorKind, Exception, Function, Map, Object, Promise, Result, Set, StringRef,
+	Symbol, Value,
 };
 
 /// Represents types that can be converted to from [JavaScript Values](Value).
@@ -245,6 +252,55 @@ impl<'cx> FromValue<'cx> for Date<'cx> {
 	}
 }
 
+impl<'cx> FromValue<'cx> for DateTime<Utc> {
+	type Config = ();
+
+	fn from_value(cx: &'cx Context, value: &Value, strict: bool, _: ()) -> Result<DateTime<Utc>> {
+		let date = Date::from_value(cx, value, strict, ())?;
+		date.to_date(cx).ok_or_else(|| Error::new("Invalid Date", ErrorKind::Range))
+	}
+}
+
+impl<'cx> FromValue<'cx> for Map<'cx> {
+	type Config = ();
+
+	fn from_value(cx: &'cx Context, value: &Value, _: bool, _: ()) -> Result<Map<'cx>> {
+		if !value.handle().is_object() {
+			return Err(Error::new("Expected Map", ErrorKind::Type));
+		}
+
+		let object = value.to_object(cx).into_local();
+		if let Some(map) = Map::from(cx, object) {
+			unsafe {
+				AssertSameCompartment(cx.as_ptr(), map.get());
+			}
+			Ok(map)
+		} else {
+			Err(Error::new("Expected Map", ErrorKind::Type))
+		}
+	}
+}
+
+impl<'cx> FromValue<'cx> for Set<'cx> {
+	type Config = ();
+
+	fn from_value(cx: &'cx Context, value: &Value, _: bool, _: ()) -> Result<Set<'cx>> {
+		if !value.handle().is_object() {
+			return Err(Error::new("Expected Set", ErrorKind::Type));
+		}
+
+		let object = value.to_object(cx).into_local();
+		if let Some(set) = Set::from(cx, object) {
+			unsafe {
+				AssertSameCompartment(cx.as_ptr(), set.get());
+			}
+			Ok(set)
+		} else {
+			Err(Error::new("Expected Set", ErrorKind::Type))
+		}
+	}
+}
+
 impl<'cx> FromValue<'cx> for Promise<'cx> {
 	type Config = ();
 
@@ -325,7 +381,7 @@ impl<'cx, T: TypedArrayElement> FromValue<'cx> for TypedArray<'cx, T> {
 
 	fn from_value(cx: &'cx Context, value: &Value, _: bool, _: ()) -> Result<TypedArray<'cx, T>> {
 		if !value.handle().is_object() {
-			return Err(Error::new("Expected ArrayBuffer", ErrorKind::Type));
+			return Err(Error::new(format!("Expected {}", T::NAME), ErrorKind::Type));
 		}
 
 		let object = value.to_object(cx).into_local();
@@ -335,7 +391,7 @@ impl<'cx, T: TypedArrayElement> FromValue<'cx> for TypedArray<'cx, T> {
 			}
 			Ok(array)
 		} else {
-			Err(Error::new("Expected ArrayBuffer", ErrorKind::Type))
+			Err(Error::new(format!("Expected {}", T::NAME), ErrorKind::Type))
 		}
 	}
 }
@@ -389,6 +445,54 @@ impl<'cx> FromValue<'cx> for Symbol<'cx> {
 	}
 }
 
+impl<'cx> FromValue<'cx> for BigInt<'cx> {
+	type Config = ();
+
+	fn from_value(cx: &'cx Context, value: &Value, strict: bool, _: ()) -> Result<BigInt<'cx>> {
+		let value = value.handle();
+		if value.is_bigint() {
+			return Ok(BigInt::from(cx.root(value.to_bigint())));
+		} else if strict {
+			return Err(Error::new("Expected BigInt in Strict Conversion", ErrorKind::Type));
+		}
+
+		if value.is_boolean() {
+			Ok(BigInt::from_bool(cx, value.to_boolean()))
+		} else if value.is_number() {
+			BigInt::from_f64(cx, value.to_number()).map_err(|e| e.to_error())
+		} else if value.is_string() {
+			let string = crate::String::from(cx.root(value.to_string())).to_owned(cx)?;
+			BigInt::from_string(cx, &string).map_err(|e| match e {
+				Some(e) => e.to_error(),
+				None => Error::new("Failed to Convert String to BigInt", ErrorKind::Syntax),
+			})
+		} else {
+			Err(Error::new("Expected BigInt", ErrorKind::Type))
+		}
+	}
+}
+
+macro_rules! impl_from_value_for_bigint_integer {
+	($ty:ty, $to:ident) => {
+		impl<'cx> FromValue<'cx> for $ty {
+			type Config = ();
+
+			fn from_value(cx: &'cx Context, value: &Value, strict: bool, _: ()) -> Result<$ty> {
+				let bigint = BigInt::from_value(cx, value, strict, ())?;
+				bigint.$to(cx).ok_or_else(|| {
+					Error::new(
+						concat!("BigInt is out of range for ", stringify!($ty)),
+						ErrorKind::Range,
+					)
+				})
+			}
+		}
+	};
+}
+
+impl_from_value_for_bigint_integer!(i128, to_i128);
+impl_from_value_for_bigint_integer!(u128, to_u128);
+
 impl<'cx> FromValue<'cx> for JSVal {
 	type Config = ();
 
@@ -425,6 +529,140 @@ impl<'cx, T: FromValue<'cx>> FromValue<'cx> for Option<T> {
 	}
 }
 
+impl<'cx, K, V, S> FromValue<'cx> for HashMap<K, V, S>
+where
+	K: FromValue<'cx> + Hash + Eq,
+	V: FromValue<'cx>,
+	K::Config: Clone,
+	V::Config: Clone,
+	S: BuildHasher + Default,
+{
+	type Config = (K::Config, V::Config);
+
+	fn from_value(
+		cx: &'cx Context, value: &Value, strict: bool, (key_config, value_config): Self::Config,
+	) -> Result<HashMap<K, V, S>> {
+		map_entries(cx, value, strict)?
+			.into_iter()
+			.map(|(k, v)| {
+				Ok((
+					K::from_value(cx, &k, strict, key_config.clone())?,
+					V::from_value(cx, &v, strict, value_config.clone())?,
+				))
+			})
+			.collect()
+	}
+}
+
+impl<'cx, K, V> FromValue<'cx> for BTreeMap<K, V>
+where
+	K: FromValue<'cx> + Ord,
+	V: FromValue<'cx>,
+	K::Config: Clone,
+	V::Config: Clone,
+{
+	type Config = (K::Config, V::Config);
+
+	fn from_value(
+		cx: &'cx Context, value: &Value, strict: bool, (key_config, value_config): Self::Config,
+	) -> Result<BTreeMap<K, V>> {
+		map_entries(cx, value, strict)?
+			.into_iter()
+			.map(|(k, v)| {
+				Ok((
+					K::from_value(cx, &k, strict, key_config.clone())?,
+					V::from_value(cx, &v, strict, value_config.clone())?,
+				))
+			})
+			.collect()
+	}
+}
+
+/// Returns the entries of a [Map], or the own enumerable string-keyed properties of an [Object] if `strict` is false.
+fn map_entries<'cx>(cx: &'cx Context, value: &Value, strict: bool) -> Result<Vec<(Value<'cx>, Value<'cx>)>> {
+	if !strict && value.handle().is_object() {
+		let object = value.to_object(cx);
+		if !Map::is_map(cx, &object) {
+			return object
+				.iter(cx, None)
+				.filter(|(key, _)| !key.handle().is_symbol())
+				.map(|(key, value)| Ok((key.as_value(cx), value?)))
+				.collect();
+		}
+	}
+	Map::from_value(cx, value, strict, ())?.to_vec(cx)
+}
+
+impl<'cx, T, S> FromValue<'cx> for HashSet<T, S>
+where
+	T: FromValue<'cx> + Hash + Eq,
+	T::Config: Clone,
+	S: BuildHasher + Default,
+{
+	type Config = T::Config;
+
+	fn from_value(cx: &'cx Context, value: &Value, strict: bool, config: T::Config) -> Result<HashSet<T, S>> {
+		let set = Set::from_value(cx, value, strict, ())?;
+		set.to_vec(cx)?.iter().map(|t| T::from_value(cx, t, strict, config.clone())).collect()
+	}
+}
+
+impl<'cx, T> FromValue<'cx> for BTreeSet<T>
+where
+	T: FromValue<'cx> + Ord,
+	T::Config: Clone,
+{
+	type Config = T::Config;
+
+	fn from_value(cx: &'cx Context, value: &Value, strict: bool, config: T::Config) -> Result<BTreeSet<T>> {
+		let set = Set::from_value(cx, value, strict, ())?;
+		set.to_vec(cx)?.iter().map(|t| T::from_value(cx, t, strict, config.clone())).collect()
+	}
+}
+
+impl<'cx> FromValue<'cx> for Cow<'_, str> {
+	type Config = ();
+
+	fn from_value(cx: &'cx Context, value: &Value, strict: bool, _: ()) -> Result<Cow<'static, str>> {
+		String::from_value(cx, value, strict, ()).map(Cow::Owned)
+	}
+}
+
+macro_rules! impl_from_value_for_tuple {
+	($len:literal, $($ty:ident $config:ident $index:tt),+) => {
+		impl<'cx, $($ty: FromValue<'cx>),+> FromValue<'cx> for ($($ty,)+) {
+			type Config = ($($ty::Config,)+);
+
+			fn from_value(
+				cx: &'cx Context, value: &Value, strict: bool, ($($config,)+): Self::Config,
+			) -> Result<($($ty,)+)> {
+				let array = Array::from_value(cx, value, strict, ())?;
+				if strict && array.len(cx) != $len {
+					return Err(Error::new(concat!("Expected Array of Length ", $len), ErrorKind::Type));
+				}
+
+				Ok(($({
+					let element = array.get(cx, $index)?.unwrap_or_else(|| Value::undefined(cx));
+					$ty::from_value(cx, &element, strict, $config)?
+				},)+))
+			}
+		}
+	};
+}
+
+impl_from_value_for_tuple!(1, A a 0);
+impl_from_value_for_tuple!(2, A a 0, B b 1);
+impl_from_value_for_tuple!(3, A a 0, B b 1, C c 2);
+impl_from_value_for_tuple!(4, A a 0, B b 1, C c 2, D d 3);
+impl_from_value_for_tuple!(5, A a 0, B b 1, C c 2, D d 3, E e 4);
+impl_from_value_for_tuple!(6, A a 0, B b 1, C c 2, D d 3, E e 4, F f 5);
+impl_from_value_for_tuple!(7, A a 0, B b 1, C c 2, D d 3, E e 4, F f 5, G g 6);
+impl_from_value_for_tuple!(8, A a 0, B b 1, C c 2, D d 3, E e 4, F f 5, G g 6, H h 7);
+impl_from_value_for_tuple!(9, A a 0, B b 1, C c 2, D d 3, E e 4, F f 5, G g 6, H h 7, I i 8);
+impl_from_value_for_tuple!(10, A a 0, B b 1, C c 2, D d 3, E e 4, F f 5, G g 6, H h 7, I i 8, J j 9);
+impl_from_value_for_tuple!(11, A a 0, B b 1, C c 2, D d 3, E e 4, F f 5, G g 6, H h 7, I i 8, J j 9, K k 10);
+impl_from_value_for_tuple!(12, A a 0, B b 1, C c 2, D d 3, E e 4, F f 5, G g 6, H h 7, I i 8, J j 9, K k 10, L l 11);
+
 // Copied from [rust-mozjs](https://github.com/servo/rust-mozjs/blob/master/src/conversions.rs#L619-L642)
 struct ForOfIteratorGuard<'a> {
 	root: &'a mut ForOfIterator,
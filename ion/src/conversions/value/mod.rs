@@ -4,10 +4,14 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
+pub use either::Either;
 pub use from::*;
 pub use into::*;
+pub use settled::Settled;
 pub use to::*;
 
+mod either;
 mod from;
 mod into;
+mod settled;
 mod to;
@@ -0,0 +1,53 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use mozjs::jsapi::PromiseState;
+
+use crate::conversions::{FromValue, ToValue};
+use crate::{Context, Error, ErrorKind, Promise, Result, Value};
+
+/// Represents the outcome of a settled [Promise], which is fulfilled with [Ok] or rejected with [Err].
+///
+/// Conversion to a [Value] creates a settled [Promise], and conversion from a [Value] requires a settled [Promise], so
+/// it fails if the promise is still pending.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Settled<T, E>(pub std::result::Result<T, E>);
+
+impl<T, E> Settled<T, E> {
+	pub fn into_result(self) -> std::result::Result<T, E> {
+		self.0
+	}
+}
+
+impl<T, E> From<std::result::Result<T, E>> for Settled<T, E> {
+	fn from(result: std::result::Result<T, E>) -> Settled<T, E> {
+		Settled(result)
+	}
+}
+
+impl<'cx, T: FromValue<'cx>, E: FromValue<'cx>> FromValue<'cx> for Settled<T, E> {
+	type Config = (T::Config, E::Config);
+
+	fn from_value(cx: &'cx Context, value: &Value, strict: bool, (ok, err): Self::Config) -> Result<Settled<T, E>> {
+		let promise = Promise::from_value(cx, value, true, ())?;
+		let result = promise.result(cx);
+		match promise.state() {
+			PromiseState::Fulfilled => Ok(Settled(Ok(T::from_value(cx, &result, strict, ok)?))),
+			PromiseState::Rejected => Ok(Settled(Err(E::from_value(cx, &result, strict, err)?))),
+			PromiseState::Pending => Err(Error::new("Expected Settled Promise", ErrorKind::Type)),
+		}
+	}
+}
+
+impl<'cx, T: ToValue<'cx>, E: ToValue<'cx>> ToValue<'cx> for Settled<T, E> {
+	fn to_value(&self, cx: &'cx Context, value: &mut Value) {
+		let promise = match &self.0 {
+			Ok(result) => Promise::resolved(cx, &result.as_value(cx)),
+			Err(error) => Promise::rejected(cx, &error.as_value(cx)),
+		};
+		promise.to_value(cx, value);
+	}
+}
@@ -5,16 +5,18 @@
  */
 
 use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::ptr::NonNull;
 use std::rc::Rc;
 
+use chrono::{DateTime, TimeZone};
 use mozjs::jsapi::{
 	JSFunction, JSObject, JSString, JS_GetFunctionObject, JS_IdToValue, JS_NewStringCopyN, JS_WrapValue,
 	PropertyKey as JSPropertyKey, Symbol as JSSymbol,
 };
 use mozjs::jsval::{
-	BooleanValue, DoubleValue, Int32Value, JSVal, NullValue, ObjectOrNullValue, ObjectValue, StringValue, SymbolValue,
-	UInt32Value, UndefinedValue,
+	BigIntValue, BooleanValue, DoubleValue, Int32Value, JSVal, NullValue, ObjectOrNullValue, ObjectValue, StringValue,
+	SymbolValue, UInt32Value, UndefinedValue,
 };
 use mozjs::rust::{maybe_wrap_object_or_null_value, maybe_wrap_object_value, maybe_wrap_value};
 use mozjs::typedarray as jsta;
@@ -22,7 +24,7 @@ use mozjs::typedarray as jsta;
 use crate::object::RegExp;
 use crate::string::byte::{BytePredicate, ByteStr, ByteString};
 use crate::typedarray::{ArrayBuffer, TypedArray, TypedArrayElement};
-use crate::{Array, Context, Date, Function, Object, Promise, PropertyKey, Symbol, Value};
+use crate::{Array, BigInt, Context, Date, Function, Map, Object, Promise, PropertyKey, Set, Symbol, Value};
 
 /// Represents types that can be converted to JavaScript [Values](Value).
 pub trait ToValue<'cx> {
@@ -175,6 +177,24 @@ impl<'cx> ToValue<'cx> for Date<'cx> {
 	}
 }
 
+impl<'cx, Tz: TimeZone> ToValue<'cx> for DateTime<Tz> {
+	fn to_value(&self, cx: &'cx Context, value: &mut Value) {
+		Date::from_date(cx, self.clone()).to_value(cx, value);
+	}
+}
+
+impl<'cx> ToValue<'cx> for Map<'cx> {
+	fn to_value(&self, cx: &'cx Context, value: &mut Value) {
+		self.handle().to_value(cx, value);
+	}
+}
+
+impl<'cx> ToValue<'cx> for Set<'cx> {
+	fn to_value(&self, cx: &'cx Context, value: &mut Value) {
+		self.handle().to_value(cx, value);
+	}
+}
+
 impl<'cx> ToValue<'cx> for Promise<'cx> {
 	fn to_value(&self, cx: &'cx Context, value: &mut Value) {
 		self.handle().to_value(cx, value);
@@ -229,6 +249,24 @@ impl<'cx> ToValue<'cx> for Symbol<'cx> {
 	}
 }
 
+impl<'cx> ToValue<'cx> for BigInt<'cx> {
+	fn to_value(&self, _: &'cx Context, value: &mut Value) {
+		value.handle_mut().set(BigIntValue(unsafe { &*self.get() }));
+	}
+}
+
+impl ToValue<'_> for i128 {
+	fn to_value(&self, cx: &Context, value: &mut Value) {
+		BigInt::from_i128(cx, *self).to_value(cx, value);
+	}
+}
+
+impl ToValue<'_> for u128 {
+	fn to_value(&self, cx: &Context, value: &mut Value) {
+		BigInt::from_u128(cx, *self).to_value(cx, value);
+	}
+}
+
 impl ToValue<'_> for JSVal {
 	fn to_value(&self, cx: &Context, value: &mut Value) {
 		value.handle_mut().set(*self);
@@ -308,3 +346,80 @@ impl<'cx, T: ToValue<'cx>> ToValue<'cx> for Vec<T> {
 		(**self).to_value(cx, value);
 	}
 }
+
+impl<'cx, K: ToValue<'cx>, V: ToValue<'cx>, S> ToValue<'cx> for HashMap<K, V, S> {
+	fn to_value(&self, cx: &'cx Context, value: &mut Value) {
+		let map = Map::new(cx);
+
+		for (k, v) in self {
+			assert!(map.set(cx, &k.as_value(cx), &v.as_value(cx)));
+		}
+
+		map.to_value(cx, value);
+	}
+}
+
+impl<'cx, T: ToValue<'cx>, S> ToValue<'cx> for HashSet<T, S> {
+	fn to_value(&self, cx: &'cx Context, value: &mut Value) {
+		let set = Set::new(cx);
+
+		for t in self {
+			assert!(set.add(cx, &t.as_value(cx)));
+		}
+
+		set.to_value(cx, value);
+	}
+}
+
+impl<'cx, K: ToValue<'cx>, V: ToValue<'cx>> ToValue<'cx> for BTreeMap<K, V> {
+	fn to_value(&self, cx: &'cx Context, value: &mut Value) {
+		let map = Map::new(cx);
+
+		for (k, v) in self {
+			assert!(map.set(cx, &k.as_value(cx), &v.as_value(cx)));
+		}
+
+		map.to_value(cx, value);
+	}
+}
+
+impl<'cx, T: ToValue<'cx>> ToValue<'cx> for BTreeSet<T> {
+	fn to_value(&self, cx: &'cx Context, value: &mut Value) {
+		let set = Set::new(cx);
+
+		for t in self {
+			assert!(set.add(cx, &t.as_value(cx)));
+		}
+
+		set.to_value(cx, value);
+	}
+}
+
+macro_rules! impl_to_value_for_tuple {
+	($($ty:ident $index:tt),+) => {
+		impl<'cx, $($ty: ToValue<'cx>),+> ToValue<'cx> for ($($ty,)+) {
+			fn to_value(&self, cx: &'cx Context, value: &mut Value) {
+				let array = Array::new_with_length(cx, [$($index),+].len());
+
+				$(
+					assert!(array.set(cx, $index, &self.$index.as_value(cx)));
+				)+
+
+				array.to_value(cx, value);
+			}
+		}
+	};
+}
+
+impl_to_value_for_tuple!(A 0);
+impl_to_value_for_tuple!(A 0, B 1);
+impl_to_value_for_tuple!(A 0, B 1, C 2);
+impl_to_value_for_tuple!(A 0, B 1, C 2, D 3);
+impl_to_value_for_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_to_value_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_to_value_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_to_value_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_to_value_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_to_value_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_to_value_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_to_value_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
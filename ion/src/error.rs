@@ -5,16 +5,23 @@
  */
 
 use std::borrow::Cow;
+use std::ffi::CString;
 use std::fmt::{Display, Formatter};
 use std::{error, fmt, ptr};
 
 use mozjs::error::{throw_internal_error, throw_range_error, throw_type_error};
-use mozjs::jsapi::{CreateError, JSExnType, JSObject, JSProtoKey, JS_ReportErrorUTF8, UndefinedHandleValue};
+use mozjs::gc::Traceable;
+use mozjs::jsapi::{
+	CreateError, ExceptionStackBehavior, Heap, JSExnType, JSObject, JSProtoKey, JSTracer, JS_GetClassObject,
+	JS_GetPrototype, JS_NewObjectWithGivenProto, JS_ReportErrorUTF8, JS_SetPendingException, JS_SetPrototype,
+	UndefinedHandleValue,
+};
 
-use crate::conversions::ToValue;
+use crate::conversions::{FromValue, ToValue};
 use crate::exception::ThrowException;
+use crate::flags::PropertyFlags;
 use crate::stack::Location;
-use crate::{Context, ErrorReport, Exception, Object, Stack, Value};
+use crate::{Context, ErrorReport, Exception, Function, Object, Stack, Value};
 
 /// Represents the types of errors that can be thrown and are recognised in the JS Runtime.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -30,6 +37,8 @@ pub enum ErrorKind {
 	WasmCompile,
 	WasmLink,
 	WasmRuntime,
+	/// Error class defined with [Error::define_class], identified by its name.
+	Custom(Cow<'static, str>),
 	None,
 }
 
@@ -59,9 +68,34 @@ impl ErrorKind {
 		}
 	}
 
+	/// Converts an [ErrorKind] into the prototype key of its built-in constructor.
+	/// Returns [None] for [ErrorKind::Custom] and [ErrorKind::None].
+	pub fn to_proto_key(&self) -> Option<JSProtoKey> {
+		use ErrorKind as EK;
+		use JSProtoKey::{
+			JSProto_AggregateError, JSProto_CompileError, JSProto_Error, JSProto_EvalError, JSProto_InternalError,
+			JSProto_LinkError, JSProto_RangeError, JSProto_ReferenceError, JSProto_RuntimeError, JSProto_SyntaxError,
+			JSProto_TypeError,
+		};
+		match self {
+			EK::Normal => Some(JSProto_Error),
+			EK::Internal => Some(JSProto_InternalError),
+			EK::Aggregate => Some(JSProto_AggregateError),
+			EK::Eval => Some(JSProto_EvalError),
+			EK::Range => Some(JSProto_RangeError),
+			EK::Reference => Some(JSProto_ReferenceError),
+			EK::Syntax => Some(JSProto_SyntaxError),
+			EK::Type => Some(JSProto_TypeError),
+			EK::WasmCompile => Some(JSProto_CompileError),
+			EK::WasmLink => Some(JSProto_LinkError),
+			EK::WasmRuntime => Some(JSProto_RuntimeError),
+			EK::Custom(_) | EK::None => None,
+		}
+	}
+
 	/// Converts an [ErrorKind] into a an exception type.
 	///
-	/// Note that [`ErrorKind::None`] is converted to [`JSEXN_ERR`](JSExnType::JSEXN_ERR).
+	/// Note that [`ErrorKind::Custom`] and [`ErrorKind::None`] are converted to [`JSEXN_ERR`](JSExnType::JSEXN_ERR).
 	pub fn to_exception_type(&self) -> JSExnType {
 		use {ErrorKind as EK, JSExnType as JSET};
 		match self {
@@ -76,7 +110,7 @@ impl ErrorKind {
 			EK::WasmCompile => JSET::JSEXN_WASMCOMPILEERROR,
 			EK::WasmLink => JSET::JSEXN_WASMLINKERROR,
 			EK::WasmRuntime => JSET::JSEXN_WASMRUNTIMEERROR,
-			EK::None => JSET::JSEXN_ERR,
+			EK::Custom(_) | EK::None => JSET::JSEXN_ERR,
 		}
 	}
 }
@@ -96,12 +130,30 @@ impl Display for ErrorKind {
 			EK::WasmCompile => "CompileError",
 			EK::WasmLink => "LinkError",
 			EK::WasmRuntime => "CompileError",
+			EK::Custom(name) => &**name,
 			EK::None => "Not an Error",
 		};
 		f.write_str(str)
 	}
 }
 
+/// Stores information about an error class created with [Error::define_class].
+#[derive(Debug)]
+pub struct ErrorClassInfo {
+	exception_type: JSExnType,
+	constructor: Box<Heap<*mut JSObject>>,
+	prototype: Box<Heap<*mut JSObject>>,
+}
+
+unsafe impl Traceable for ErrorClassInfo {
+	unsafe fn trace(&self, trc: *mut JSTracer) {
+		unsafe {
+			self.constructor.trace(trc);
+			self.prototype.trace(trc);
+		}
+	}
+}
+
 /// Represents errors in the JS Runtime
 /// Contains information about the type of error, the error message and the error location.
 ///
@@ -133,13 +185,146 @@ impl Error {
 		}
 	}
 
+	/// Defines a subclass of the error class of `parent` as a property of the object, with the given name.
+	/// The parent can also be a previously defined [ErrorKind::Custom].
+	///
+	/// Instances have the built-in error of the parent at their root, so they are recognised as errors by
+	/// `instanceof`, [Exception::from_object] and the formatter. Errors of [ErrorKind::Custom] with this name are
+	/// created as instances of the class.
+	///
+	/// Returns [None] if the parent is [ErrorKind::None] or an undefined [ErrorKind::Custom].
+	pub fn define_class<'cx>(
+		cx: &'cx Context, object: &Object, name: &'static str, parent: ErrorKind,
+	) -> Option<Function<'cx>> {
+		let (exception_type, parent_constructor) = match &parent {
+			ErrorKind::Custom(parent) => {
+				let info = error_class(cx, parent)?;
+				(info.exception_type, Object::from(cx.root(info.constructor.get())))
+			}
+			ErrorKind::None => return None,
+			kind => {
+				let mut constructor = Object::null(cx);
+				let key = kind.to_proto_key()?;
+				unsafe {
+					if !JS_GetClassObject(cx.as_ptr(), key, constructor.handle_mut().into()) {
+						return None;
+					}
+				}
+				(kind.to_exception_type(), constructor)
+			}
+		};
+		let parent_prototype: Object = parent_constructor.get_as(cx, "prototype", true, ()).ok()??;
+
+		let prototype = unsafe {
+			Object::from(cx.root(JS_NewObjectWithGivenProto(
+				cx.as_ptr(),
+				ptr::null(),
+				parent_prototype.handle().into(),
+			)))
+		};
+		if prototype.handle().is_null()
+			|| !prototype.define_as(cx, "name", name, PropertyFlags::empty())
+			|| !prototype.define_as(cx, "message", "", PropertyFlags::empty())
+		{
+			return None;
+		}
+
+		let kind = ErrorKind::Custom(Cow::Borrowed(name));
+		let constructor = Function::from_closure(
+			cx,
+			&CString::new(name).ok()?,
+			Box::new(move |args| {
+				let cx = args.cx();
+				let message = match args.value(0) {
+					Some(message) if !message.handle().is_undefined() => String::from_value(cx, &message, false, ())?,
+					_ => String::new(),
+				};
+				let error = Error::new(message, kind.clone()).to_object(cx).ok_or_else(Error::none)?;
+
+				if args.is_constructing() {
+					let new_target = Value::from(cx.root(args.call_args().new_target().get())).to_object(cx);
+					if let Some(prototype) = new_target.get_as::<_, Object>(cx, "prototype", true, ())? {
+						unsafe {
+							JS_SetPrototype(cx.as_ptr(), error.handle().into(), prototype.handle().into());
+						}
+					}
+				}
+				Ok(error.as_value(cx))
+			}),
+			1,
+			PropertyFlags::CONSTRUCTOR,
+		);
+		let constructor_object = constructor.to_object(cx);
+
+		unsafe {
+			if !JS_SetPrototype(
+				cx.as_ptr(),
+				constructor_object.handle().into(),
+				parent_constructor.handle().into(),
+			) {
+				return None;
+			}
+		}
+		if !constructor_object.define_as(cx, "prototype", &prototype, PropertyFlags::CONSTANT)
+			|| !prototype.define_as(cx, "constructor", &constructor_object, PropertyFlags::empty())
+			|| !object.define_as(cx, name, &constructor_object, PropertyFlags::empty())
+		{
+			return None;
+		}
+
+		let classes = unsafe { &mut (*cx.get_inner_data().as_ptr()).error_classes };
+		classes.insert(
+			Cow::Borrowed(name),
+			ErrorClassInfo {
+				exception_type,
+				constructor: Heap::boxed(constructor_object.handle().get()),
+				prototype: Heap::boxed(prototype.handle().get()),
+			},
+		);
+
+		Some(constructor)
+	}
+
+	/// Returns the [ErrorKind::Custom] of an error object, if it inherits from a class defined with
+	/// [Error::define_class].
+	pub fn custom_kind(cx: &Context, object: &Object) -> Option<ErrorKind> {
+		let classes = unsafe { &(*cx.get_inner_data().as_ptr()).error_classes };
+		if classes.is_empty() {
+			return None;
+		}
+
+		let mut prototype = Object::null(cx);
+		let mut current = Object::from(cx.root(object.handle().get()));
+		loop {
+			unsafe {
+				if !JS_GetPrototype(cx.as_ptr(), current.handle().into(), prototype.handle_mut().into()) {
+					return None;
+				}
+			}
+			if prototype.handle().is_null() {
+				return None;
+			}
+			let proto = prototype.handle().get();
+			if let Some((name, _)) = classes.iter().find(|(_, info)| info.prototype.get() == proto) {
+				return Some(ErrorKind::Custom(name.clone()));
+			}
+			current = Object::from(cx.root(proto));
+		}
+	}
+
 	pub fn to_object<'cx>(&self, cx: &'cx Context) -> Option<Object<'cx>> {
 		if let Some(object) = self.object {
 			return Some(cx.root(object).into());
 		}
 		if self.kind != ErrorKind::None {
 			unsafe {
-				let exception_type = self.kind.to_exception_type();
+				let (exception_type, prototype) = match &self.kind {
+					ErrorKind::Custom(name) => {
+						let info = error_class(cx, name)?;
+						(info.exception_type, Some(info.prototype.get()))
+					}
+					kind => (kind.to_exception_type(), None),
+				};
 
 				let stack = Stack::from_capture(cx).unwrap();
 				let (file, lineno, column) = stack
@@ -175,7 +360,12 @@ impl Error {
 					UndefinedHandleValue,
 					error.handle_mut().into(),
 				) {
-					return Some(error.to_object(cx));
+					let error = error.to_object(cx);
+					if let Some(prototype) = prototype {
+						let prototype = Object::from(cx.root(prototype));
+						JS_SetPrototype(cx.as_ptr(), error.handle().into(), prototype.handle().into());
+					}
+					return Some(error);
 				}
 			}
 		}
@@ -234,6 +424,15 @@ impl ThrowException for Error {
 				EK::Internal => throw_internal_error(cx.as_ptr(), &self.message),
 				EK::Range => throw_range_error(cx.as_ptr(), &self.message),
 				EK::Type => throw_type_error(cx.as_ptr(), &self.message),
+				EK::Custom(_) => {
+					if let Some(object) = self.to_object(cx) {
+						let exception = object.as_value(cx);
+						JS_SetPendingException(cx.as_ptr(), exception.handle().into(), ExceptionStackBehavior::Capture);
+					} else {
+						// The class has not been defined, so a plain error is thrown instead.
+						JS_ReportErrorUTF8(cx.as_ptr(), format!("{}\0", self.message).as_ptr().cast());
+					}
+				}
 				EK::None => (),
 				_ => unimplemented!("Throwing Exception for this is not implemented"),
 			}
@@ -246,3 +445,7 @@ impl<'cx> ToValue<'cx> for Error {
 		self.to_object(cx).to_value(cx, value)
 	}
 }
+
+fn error_class<'c>(cx: &'c Context, name: &str) -> Option<&'c ErrorClassInfo> {
+	unsafe { (*cx.get_inner_data().as_ptr()).error_classes.get(name) }
+}
@@ -73,7 +73,8 @@ impl Exception {
 				let column: u32 = exception.get_as(cx, "columnNumber", true, ConversionBehavior::Clamp)?.unwrap();
 
 				let location = Location { file, lineno, column };
-				let kind = ErrorKind::from_proto_key(IdentifyStandardInstance(handle.get()));
+				let kind = Error::custom_kind(cx, exception)
+					.unwrap_or_else(|| ErrorKind::from_proto_key(IdentifyStandardInstance(handle.get())));
 				let error = Error {
 					kind,
 					message: message.into(),
@@ -216,6 +217,12 @@ impl ErrorReport {
 		}
 	}
 
+	/// Creates an [ErrorReport] for execution which was terminated without a pending exception,
+	/// such as by a native function which returns `false` to stop the script.
+	pub fn terminated() -> ErrorReport {
+		Error::new("Execution was terminated", None).into()
+	}
+
 	/// Creates an [ErrorReport] from an existing [Exception] and optionally a [Stack].
 	pub fn from<S: Into<Option<Stack>>>(exception: Exception, stack: S) -> ErrorReport {
 		ErrorReport { exception, stack: stack.into() }
@@ -244,9 +251,13 @@ impl ErrorReport {
 	}
 
 	/// Formats the [ErrorReport] as a string for printing.
+	///
+	/// [Internal](crate::stack::StackRecord::is_internal) records of the [Stack] are omitted.
 	pub fn format(&self, cx: &Context) -> String {
 		let mut string = self.exception.format(cx);
 		if let Some(stack) = &self.stack {
+			let mut stack = stack.clone();
+			stack.filter_internal();
 			if !stack.is_empty() {
 				string.push_str(NEWLINE);
 				string.push_str(&stack.format());
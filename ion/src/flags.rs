@@ -5,17 +5,20 @@
  */
 
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 use std::{fmt, str};
 
 use arrayvec::ArrayVec;
 use bitflags::{bitflags, Flags};
 use mozjs::jsapi::{
 	RegExpFlag_DotAll, RegExpFlag_Global, RegExpFlag_HasIndices, RegExpFlag_IgnoreCase, RegExpFlag_Multiline,
-	RegExpFlag_Sticky, RegExpFlag_Unicode, RegExpFlags as REFlags, JSITER_FORAWAITOF, JSITER_HIDDEN, JSITER_OWNONLY,
-	JSITER_PRIVATE, JSITER_SYMBOLS, JSITER_SYMBOLSONLY, JSPROP_ENUMERATE, JSPROP_PERMANENT, JSPROP_READONLY,
-	JSPROP_RESOLVING,
+	RegExpFlag_Sticky, RegExpFlag_Unicode, RegExpFlags as REFlags, JSFUN_CONSTRUCTOR, JSITER_FORAWAITOF, JSITER_HIDDEN,
+	JSITER_OWNONLY, JSITER_PRIVATE, JSITER_SYMBOLS, JSITER_SYMBOLSONLY, JSPROP_ENUMERATE, JSPROP_PERMANENT,
+	JSPROP_READONLY, JSPROP_RESOLVING,
 };
 
+use crate::{Error, ErrorKind};
+
 bitflags! {
 	/// Represents the flags of properties on an [Object](crate::Object)
 	#[derive(Clone, Copy, Debug)]
@@ -28,6 +31,9 @@ bitflags! {
 		/// Prevents deletion and attribute modification of the property.
 		const PERMANENT = JSPROP_PERMANENT as u16;
 		const RESOLVING = JSPROP_RESOLVING as u16;
+		/// Allows a [Function](crate::Function) to be called as a constructor.
+		/// Only applies when creating functions.
+		const CONSTRUCTOR = JSFUN_CONSTRUCTOR as u16;
 
 		const CONSTANT = PropertyFlags::READ_ONLY.bits() | PropertyFlags::PERMANENT.bits();
 		const CONSTANT_ENUMERATED = PropertyFlags::CONSTANT.bits() | PropertyFlags::ENUMERATE.bits();
@@ -104,6 +110,31 @@ impl Display for RegExpFlags {
 	}
 }
 
+impl FromStr for RegExpFlags {
+	type Err = Error;
+
+	fn from_str(flags: &str) -> Result<RegExpFlags, Error> {
+		let mut result = RegExpFlags::empty();
+		for flag in flags.chars() {
+			let flag = match flag {
+				'd' => RegExpFlags::HAS_INDICES,
+				'g' => RegExpFlags::GLOBAL,
+				'i' => RegExpFlags::IGNORE_CASE,
+				'm' => RegExpFlags::MULTILINE,
+				's' => RegExpFlags::DOT_ALL,
+				'u' => RegExpFlags::UNICODE,
+				'y' => RegExpFlags::STICKY,
+				_ => return Err(Error::new(format!("Invalid RegExp Flag: {flag}"), ErrorKind::Syntax)),
+			};
+			if result.contains(flag) {
+				return Err(Error::new(format!("Duplicate RegExp Flag: {flag}"), ErrorKind::Syntax));
+			}
+			result |= flag;
+		}
+		Ok(result)
+	}
+}
+
 impl From<RegExpFlags> for REFlags {
 	fn from(flags: RegExpFlags) -> Self {
 		REFlags { flags_: flags.bits() }
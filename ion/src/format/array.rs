@@ -7,12 +7,11 @@
 use std::fmt;
 use std::fmt::{Display, Formatter, Write};
 
-use colored::Colorize;
 use mozjs::jsapi::JSProtoKey;
 
 use crate::format::descriptor::format_descriptor;
 use crate::format::object::{write_prefix, write_remaining};
-use crate::format::{indent_str, Config, NEWLINE};
+use crate::format::{indent_str, Colorize, Config, NEWLINE};
 use crate::{Array, Context};
 
 /// Formats an [JavaScript Array](Array) using the given [configuration](Config).
@@ -40,7 +39,8 @@ impl Display for ArrayDisplay<'_> {
 			JSProtoKey::JSProto_Array,
 		)?;
 
-		if self.cfg.depth < 5 {
+		// Arrays are abbreviated one level deeper than objects.
+		if self.cfg.depth <= self.cfg.max_depth {
 			let length = self.array.len(self.cx);
 
 			if length == 0 {
@@ -50,7 +50,7 @@ impl Display for ArrayDisplay<'_> {
 
 				let (remaining, inner) = if self.cfg.multiline {
 					f.write_str(NEWLINE)?;
-					let len = length.clamp(0, 100);
+					let len = length.clamp(0, self.cfg.max_array_length);
 
 					let inner = indent_str((self.cfg.indentation + self.cfg.depth + 1) as usize);
 
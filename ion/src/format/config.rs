@@ -9,39 +9,41 @@ use colored::Color;
 use crate::flags::IteratorFlags;
 
 /// Configuration for the colours used when formatting values as specific types.
+///
+/// Values of types without a colour are formatted without colouring.
 #[derive(Clone, Copy, Debug)]
 pub struct ColourConfig {
-	pub boolean: Color,
-	pub number: Color,
-	pub string: Color,
-	pub bigint: Color,
-	pub symbol: Color,
-	pub null: Color,
-	pub undefined: Color,
-	pub array: Color,
-	pub object: Color,
-	pub function: Color,
-	pub date: Color,
-	pub promise: Color,
-	pub regexp: Color,
+	pub boolean: Option<Color>,
+	pub number: Option<Color>,
+	pub string: Option<Color>,
+	pub bigint: Option<Color>,
+	pub symbol: Option<Color>,
+	pub null: Option<Color>,
+	pub undefined: Option<Color>,
+	pub array: Option<Color>,
+	pub object: Option<Color>,
+	pub function: Option<Color>,
+	pub date: Option<Color>,
+	pub promise: Option<Color>,
+	pub regexp: Option<Color>,
 }
 
 impl Default for ColourConfig {
 	fn default() -> Self {
 		ColourConfig {
-			boolean: Color::Cyan,
-			number: Color::Blue,
-			string: Color::Green,
-			bigint: Color::Blue,
-			symbol: Color::Magenta,
-			null: Color::TrueColor { r: 118, g: 118, b: 118 },
-			undefined: Color::TrueColor { r: 118, g: 118, b: 118 },
-			array: Color::White,
-			object: Color::White,
-			function: Color::White,
-			date: Color::White,
-			promise: Color::Yellow,
-			regexp: Color::Green,
+			boolean: Some(Color::Cyan),
+			number: Some(Color::Blue),
+			string: Some(Color::Green),
+			bigint: Some(Color::Blue),
+			symbol: Some(Color::Magenta),
+			null: Some(Color::TrueColor { r: 118, g: 118, b: 118 }),
+			undefined: Some(Color::TrueColor { r: 118, g: 118, b: 118 }),
+			array: Some(Color::White),
+			object: Some(Color::White),
+			function: Some(Color::White),
+			date: Some(Color::White),
+			promise: Some(Color::Yellow),
+			regexp: Some(Color::Green),
 		}
 	}
 }
@@ -50,19 +52,38 @@ impl ColourConfig {
 	/// Returns [ColourConfig] where all formatted strings are white.
 	pub fn white() -> ColourConfig {
 		ColourConfig {
-			boolean: Color::White,
-			number: Color::White,
-			string: Color::White,
-			bigint: Color::White,
-			symbol: Color::White,
-			null: Color::White,
-			undefined: Color::White,
-			array: Color::White,
-			object: Color::White,
-			function: Color::White,
-			date: Color::White,
-			promise: Color::White,
-			regexp: Color::White,
+			boolean: Some(Color::White),
+			number: Some(Color::White),
+			string: Some(Color::White),
+			bigint: Some(Color::White),
+			symbol: Some(Color::White),
+			null: Some(Color::White),
+			undefined: Some(Color::White),
+			array: Some(Color::White),
+			object: Some(Color::White),
+			function: Some(Color::White),
+			date: Some(Color::White),
+			promise: Some(Color::White),
+			regexp: Some(Color::White),
+		}
+	}
+
+	/// Returns [ColourConfig] where no formatted strings are coloured.
+	pub fn none() -> ColourConfig {
+		ColourConfig {
+			boolean: None,
+			number: None,
+			string: None,
+			bigint: None,
+			symbol: None,
+			null: None,
+			undefined: None,
+			array: None,
+			object: None,
+			function: None,
+			date: None,
+			promise: None,
+			regexp: None,
 		}
 	}
 }
@@ -74,6 +95,10 @@ pub struct Config {
 	pub colours: ColourConfig,
 	pub iteration: IteratorFlags,
 	pub depth: u16,
+	/// Depth at which nested objects are abbreviated, such as `[Object]`. Arrays are abbreviated one level deeper.
+	pub max_depth: u16,
+	/// Maximum number of elements of an array that are formatted, when formatting over multiple lines.
+	pub max_array_length: u32,
 	pub indentation: u16,
 	pub multiline: bool,
 	pub quoted: bool,
@@ -93,6 +118,14 @@ impl Config {
 		Config { depth, ..self }
 	}
 
+	pub fn max_depth(self, max_depth: u16) -> Config {
+		Config { max_depth, ..self }
+	}
+
+	pub fn max_array_length(self, max_array_length: u32) -> Config {
+		Config { max_array_length, ..self }
+	}
+
 	pub fn indentation(self, indentation: u16) -> Config {
 		Config { indentation, ..self }
 	}
@@ -112,6 +145,8 @@ impl Default for Config {
 			colours: ColourConfig::default(),
 			iteration: IteratorFlags::default(),
 			depth: 0,
+			max_depth: 4,
+			max_array_length: 100,
 			indentation: 0,
 			multiline: true,
 			quoted: false,
@@ -7,9 +7,7 @@
 use std::fmt;
 use std::fmt::{Display, Formatter};
 
-use colored::Colorize;
-
-use crate::format::Config;
+use crate::format::{Colorize, Config};
 use crate::{Context, Date};
 
 /// Formats a [JavaScript Date](Date) using the given [configuration](Config).
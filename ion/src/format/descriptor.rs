@@ -7,11 +7,9 @@
 use std::fmt;
 use std::fmt::{Display, Formatter, Write};
 
-use colored::Colorize;
-
 use crate::format::object::format_object;
 use crate::format::primitive::format_primitive;
-use crate::format::{format_value, Config};
+use crate::format::{format_value, Colorize, Config};
 use crate::{Context, Function, Object, PropertyDescriptor};
 
 /// Formats a [descriptor](PropertyDescriptor) with the given [configuration](Config).
@@ -7,10 +7,9 @@
 use std::fmt;
 use std::fmt::{Display, Formatter};
 
-use colored::Colorize;
 use indent::indent_by;
 
-use crate::format::Config;
+use crate::format::{Colorize, Config};
 use crate::{Context, Function};
 
 /// Formats a [function](Function), using the given [configuration](Config).
@@ -7,11 +7,10 @@
 use std::fmt;
 use std::fmt::{Display, Formatter};
 
-use colored::Colorize;
 use itoa::Buffer;
 
 use crate::format::symbol::format_symbol;
-use crate::format::Config;
+use crate::format::{Colorize, Config};
 use crate::{Context, OwnedKey};
 
 /// Formats the [key of an object](OwnedKey) with the given [configuration](Config).
@@ -8,7 +8,8 @@ use std::borrow::Cow;
 use std::fmt::{Display, Formatter};
 use std::{fmt, str};
 
-pub use config::Config;
+use colored::{Color, ColoredString};
+pub use config::{ColourConfig, Config};
 
 use crate::format::object::format_object;
 use crate::format::primitive::format_primitive;
@@ -47,6 +48,29 @@ pub fn indent_str(indentation: usize) -> Cow<'static, str> {
 	}
 }
 
+/// Colours strings with the colour from a [ColourConfig], leaving them uncoloured if there is no colour.
+pub(crate) trait Colorize {
+	fn color(self, colour: Option<Color>) -> ColoredString;
+}
+
+impl Colorize for &str {
+	fn color(self, colour: Option<Color>) -> ColoredString {
+		match colour {
+			Some(colour) => colored::Colorize::color(self, colour),
+			None => ColoredString::from(self),
+		}
+	}
+}
+
+impl Colorize for ColoredString {
+	fn color(self, colour: Option<Color>) -> ColoredString {
+		match colour {
+			Some(colour) => colored::Colorize::color(self, colour),
+			None => self,
+		}
+	}
+}
+
 /// Formats a [JavaScript Value](Value) with the given [configuration](Config).
 pub fn format_value<'cx>(cx: &'cx Context, cfg: Config, value: &'cx Value<'cx>) -> ValueDisplay<'cx> {
 	ValueDisplay { cx, value, cfg }
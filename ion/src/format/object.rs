@@ -8,7 +8,7 @@ use std::cmp::Ordering;
 use std::fmt;
 use std::fmt::{Display, Formatter, Write};
 
-use colored::{Color, Colorize};
+use colored::Color;
 use itoa::Buffer;
 use mozjs::jsapi::{
 	ESClass, IdentifyStandardPrototype, JSProtoKey, JS_GetConstructor, JS_GetPrototype, JS_HasInstance, Type,
@@ -26,7 +26,7 @@ use crate::format::promise::format_promise;
 use crate::format::regexp::format_regexp;
 use crate::format::string::format_string;
 use crate::format::typedarray::{format_array_buffer, format_typed_array};
-use crate::format::{indent_str, Config, NEWLINE};
+use crate::format::{indent_str, Colorize, Config, NEWLINE};
 use crate::symbol::WellKnownSymbolCode;
 use crate::typedarray::{ArrayBuffer, ArrayBufferView, TypedArray, TypedArrayElement};
 use crate::{
@@ -123,7 +123,7 @@ impl Display for RawObjectDisplay<'_> {
 
 		write_prefix(f, self.cx, self.cfg, self.object, "Object", JSProtoKey::JSProto_Object)?;
 
-		if self.cfg.depth < 4 {
+		if self.cfg.depth < self.cfg.max_depth {
 			let keys = self.object.keys(self.cx, Some(self.cfg.iteration));
 			let length = keys.len();
 
@@ -210,7 +210,7 @@ pub(crate) fn write_prefix(
 		Ok(None)
 	}
 
-	fn write_tag(f: &mut Formatter, colour: Color, tag: Option<&str>, fallback: &str) -> fmt::Result {
+	fn write_tag(f: &mut Formatter, colour: Option<Color>, tag: Option<&str>, fallback: &str) -> fmt::Result {
 		if let Some(tag) = tag {
 			if tag != fallback {
 				"[".color(colour).fmt(f)?;
@@ -253,7 +253,9 @@ fn write_key_descriptor(
 	format_descriptor(cx, cfg, desc, object).fmt(f)
 }
 
-pub(crate) fn write_remaining(f: &mut Formatter, remaining: usize, inner: Option<&str>, colour: Color) -> fmt::Result {
+pub(crate) fn write_remaining(
+	f: &mut Formatter, remaining: usize, inner: Option<&str>, colour: Option<Color>,
+) -> fmt::Result {
 	if remaining > 0 {
 		if let Some(inner) = inner {
 			f.write_str(inner)?;
@@ -7,14 +7,13 @@
 use std::fmt;
 use std::fmt::{Display, Formatter};
 
-use colored::Colorize;
 use itoa::Buffer;
 
 use crate::bigint::BigInt;
 use crate::conversions::FromValue;
 use crate::format::string::format_string;
 use crate::format::symbol::format_symbol;
-use crate::format::Config;
+use crate::format::{Colorize, Config};
 use crate::{Context, Symbol, Value};
 
 /// Formats a primitive value using the given [configuration](Config).
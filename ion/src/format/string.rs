@@ -7,11 +7,10 @@
 use std::fmt;
 use std::fmt::{Display, Formatter};
 
-use colored::Colorize;
 use encoding_rs::UTF_8;
 use mozjs::jsval::StringValue;
 
-use crate::format::Config;
+use crate::format::{Colorize, Config};
 use crate::{Context, Local, Value};
 
 pub fn format_string<'cx>(cx: &'cx Context, cfg: Config, string: &'cx crate::String<'cx>) -> StringDisplay<'cx> {
@@ -7,10 +7,9 @@
 use std::fmt;
 use std::fmt::{Display, Formatter, Write};
 
-use colored::Colorize;
 use itoa::Buffer;
 
-use crate::format::{indent_str, Config, NEWLINE};
+use crate::format::{indent_str, Colorize, Config, NEWLINE};
 use crate::typedarray::{ArrayBuffer, TypedArray, TypedArrayElement};
 
 pub fn format_array_buffer<'cx>(cfg: Config, buffer: &'cx ArrayBuffer<'cx>) -> ArrayBufferDisplay<'cx> {
@@ -156,6 +156,15 @@ impl<'cx> Accessor<'_, 'cx> {
 		arg
 	}
 
+	/// Returns the argument at the current index, or [None] if there are no arguments remaining or it is `undefined`.
+	pub fn optional_value(&mut self) -> Option<Value<'cx>> {
+		if self.is_empty() {
+			return None;
+		}
+		let arg = self.value();
+		(!arg.handle().is_undefined()).then_some(arg)
+	}
+
 	/// Returns `true` if the function was called with `new`.
 	pub fn is_constructing(&self) -> bool {
 		self.args.is_constructing()
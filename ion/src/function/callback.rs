@@ -0,0 +1,142 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+
+use mozjs::gc::Traceable;
+use mozjs::jsapi::{Heap, JSObject, JSTracer};
+
+use crate::conversions::{FromValue, ToValue};
+use crate::{Context, ErrorReport, Exception, Function, Local, Object, Promise, Value};
+
+/// Arguments that can be passed to a [Callback].
+/// Implemented for tuples of up to 12 elements that implement [ToValue].
+pub trait CallbackArgs<'cx> {
+	/// Converts the arguments into [values](Value).
+	fn into_values(self, cx: &'cx Context) -> Vec<Value<'cx>>;
+}
+
+impl<'cx> CallbackArgs<'cx> for () {
+	fn into_values(self, _: &'cx Context) -> Vec<Value<'cx>> {
+		Vec::new()
+	}
+}
+
+macro_rules! impl_callback_args_for_tuple {
+	($($ty:ident $index:tt),+) => {
+		impl<'cx, $($ty: ToValue<'cx>),+> CallbackArgs<'cx> for ($($ty,)+) {
+			fn into_values(self, cx: &'cx Context) -> Vec<Value<'cx>> {
+				vec![$(self.$index.as_value(cx)),+]
+			}
+		}
+	};
+}
+
+impl_callback_args_for_tuple!(A 0);
+impl_callback_args_for_tuple!(A 0, B 1);
+impl_callback_args_for_tuple!(A 0, B 1, C 2);
+impl_callback_args_for_tuple!(A 0, B 1, C 2, D 3);
+impl_callback_args_for_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_callback_args_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_callback_args_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_callback_args_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_callback_args_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_callback_args_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_callback_args_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_callback_args_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+/// Represents a JavaScript [Function] with typed arguments and return value.
+///
+/// The function is traced rather than rooted, so a [Callback] can be stored in a traced value, such as a field of a
+/// [class](crate::ClassDefinition), and called after the native function that received it has returned. As with other
+/// traced values, a [Callback] stored on an object which the function references can still be collected.
+/// A [Callback] which is not traced must not outlive the [Function] it was created from.
+///
+/// `Args` is a tuple of the argument types, and `Ret` is the type the return value is converted to.
+pub struct Callback<Args, Ret = ()> {
+	function: Box<Heap<*mut JSObject>>,
+	_marker: PhantomData<fn(Args) -> Ret>,
+}
+
+impl<Args, Ret> Callback<Args, Ret> {
+	/// Creates a [Callback] from a [Function].
+	pub fn new(cx: &Context, function: &Function) -> Callback<Args, Ret> {
+		Callback {
+			function: Heap::boxed(function.to_object(cx).handle().get()),
+			_marker: PhantomData,
+		}
+	}
+
+	/// Returns the underlying [Function] of the [Callback].
+	pub fn function<'cx>(&self, cx: &'cx Context) -> Function<'cx> {
+		Function::from_object(cx, &unsafe { Local::from_heap(&self.function) }).unwrap()
+	}
+
+	/// Calls the [Callback] with the given `this` [Object] and arguments.
+	/// Returns [Err] if the call fails, an exception occurs, or the return value cannot be converted.
+	pub fn call<'cx>(&self, cx: &'cx Context, this: &Object, args: Args) -> Result<Ret, Option<ErrorReport>>
+	where
+		Args: CallbackArgs<'cx>,
+		Ret: FromValue<'cx, Config = ()>,
+	{
+		let args = args.into_values(cx);
+		let value = self.function(cx).call(cx, this, &args)?;
+		Ret::from_value(cx, &value, false, ()).map_err(|error| Some(ErrorReport::from(Exception::Error(error), None)))
+	}
+
+	/// Calls the [Callback] with the given `this` [Object] and arguments.
+	/// If the [Callback] returns a [Promise], it is awaited and its fulfilled value is converted instead.
+	/// Returns [Err] if the call fails, an exception occurs, the promise is rejected,
+	/// or the value cannot be converted.
+	pub async fn call_async<'cx>(
+		&self, cx: &'cx Context, this: &Object<'_>, args: Args,
+	) -> Result<Ret, Option<ErrorReport>>
+	where
+		Args: CallbackArgs<'cx>,
+		Ret: FromValue<'cx, Config = ()>,
+	{
+		let args = args.into_values(cx);
+		let mut value = self.function(cx).call(cx, this, &args)?;
+
+		if value.handle().is_object() {
+			if let Some(promise) = Promise::from(value.to_object(cx).into_local()) {
+				let future = promise.to_future(cx);
+				value = match future.await {
+					Ok(result) => Value::from(cx.root(result.get())),
+					Err(error) => {
+						let error = Value::from(cx.root(error.get()));
+						let exception = Exception::from_value(cx, &error).unwrap_or_else(Exception::Error);
+						return Err(Some(ErrorReport::from(exception, None)));
+					}
+				};
+			}
+		}
+
+		Ret::from_value(cx, &value, false, ()).map_err(|error| Some(ErrorReport::from(Exception::Error(error), None)))
+	}
+}
+
+impl<Args, Ret> Debug for Callback<Args, Ret> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.debug_struct("Callback").field("function", &self.function.get()).finish()
+	}
+}
+
+impl<'cx, Args, Ret> FromValue<'cx> for Callback<Args, Ret> {
+	type Config = ();
+
+	fn from_value(cx: &'cx Context, value: &Value, strict: bool, _: ()) -> crate::Result<Callback<Args, Ret>> {
+		Function::from_value(cx, value, strict, ()).map(|function| Callback::new(cx, &function))
+	}
+}
+
+unsafe impl<Args, Ret> Traceable for Callback<Args, Ret> {
+	unsafe fn trace(&self, trc: *mut JSTracer) {
+		unsafe { self.function.trace(trc) }
+	}
+}
@@ -9,18 +9,20 @@ use std::ops::Deref;
 
 use mozjs::conversions::jsstr_to_string;
 use mozjs::jsapi::{
-	HandleValueArray, JSContext, JSFunction, JSFunctionSpec, JSObject, JS_CallFunction, JS_DecompileFunction,
-	JS_GetFunctionArity, JS_GetFunctionDisplayId, JS_GetFunctionId, JS_GetFunctionLength, JS_GetFunctionObject,
-	JS_GetObjectFunction, JS_IsBuiltinEvalFunction, JS_IsBuiltinFunctionConstructor, JS_IsConstructor, JS_NewFunction,
-	JS_ObjectIsFunction, NewFunctionFromSpec1, NewFunctionWithReserved, SetFunctionNativeReserved,
+	Call, Construct, Construct1, HandleValueArray, JSContext, JSFunction, JSFunctionSpec, JSObject, JS_CallFunction,
+	JS_DecompileFunction, JS_GetFunctionArity, JS_GetFunctionDisplayId, JS_GetFunctionId, JS_GetFunctionLength,
+	JS_GetFunctionObject, JS_GetObjectFunction, JS_IsBuiltinEvalFunction, JS_IsBuiltinFunctionConstructor,
+	JS_IsConstructor, JS_NewFunction, JS_ObjectIsFunction, NewFunctionFromSpec1, NewFunctionWithReserved,
+	SetFunctionNativeReserved,
 };
 use mozjs::jsval::{JSVal, ObjectValue};
 
+use crate::conversions::ToValue;
 use crate::flags::PropertyFlags;
 use crate::function::closure::{
 	call_closure, call_closure_once, create_closure_object, create_closure_once_object, Closure, ClosureOnce,
 };
-use crate::{Context, Error, ErrorReport, Local, Object, Value};
+use crate::{Context, Error, ErrorReport, Exception, Local, Object, Value};
 
 /// Native Function that can be used from JavaScript.
 pub type NativeFunction = unsafe extern "C" fn(*mut JSContext, u32, *mut JSVal) -> bool;
@@ -179,10 +181,85 @@ impl<'f> Function<'f> {
 		} {
 			Ok(rval)
 		} else {
-			Err(ErrorReport::new_with_exception_stack(cx).unwrap())
+			Err(pending_report(cx))
 		}
 	}
 
+	/// Calls the [Function] with the given `this` [Value] and arguments.
+	/// Unlike [Function::call], `this` can be any value, including primitives, `null` and `undefined`.
+	/// Returns [Err] if the function call fails or an exception occurs.
+	pub fn apply<'cx>(
+		&self, cx: &'cx Context, this: &Value, args: &[Value],
+	) -> Result<Value<'cx>, Option<ErrorReport>> {
+		let function = self.get().as_value(cx);
+		let args: Vec<_> = args.iter().map(|a| a.get()).collect();
+		let mut rval = Value::undefined(cx);
+		if unsafe {
+			Call(
+				cx.as_ptr(),
+				this.handle().into(),
+				function.handle().into(),
+				&HandleValueArray::from_rooted_slice(args.as_slice()),
+				rval.handle_mut().into(),
+			)
+		} {
+			Ok(rval)
+		} else {
+			Err(pending_report(cx))
+		}
+	}
+
+	/// Constructs an [Object] with the [Function] as a constructor, as with the `new` operator.
+	/// If `new_target` is given, it is used as `new.target` instead of the [Function] itself.
+	/// Returns [Err] if the function is not a constructor or an exception occurs.
+	pub fn construct<'cx>(
+		&self, cx: &'cx Context, args: &[Value], new_target: Option<&Object>,
+	) -> Result<Object<'cx>, Option<ErrorReport>> {
+		let function = self.get().as_value(cx);
+		let args: Vec<_> = args.iter().map(|a| a.get()).collect();
+		let args = unsafe { HandleValueArray::from_rooted_slice(args.as_slice()) };
+		let mut object = Object::null(cx);
+		let success = match new_target {
+			Some(new_target) => unsafe {
+				Construct(
+					cx.as_ptr(),
+					function.handle().into(),
+					new_target.handle().into(),
+					&args,
+					object.handle_mut().into(),
+				)
+			},
+			None => unsafe { Construct1(cx.as_ptr(), function.handle().into(), &args, object.handle_mut().into()) },
+		};
+		if success {
+			Ok(object)
+		} else {
+			Err(pending_report(cx))
+		}
+	}
+
+	/// Creates a bound [Function] with the given `this` [Value] and leading arguments, as with
+	/// `Function.prototype.bind`.
+	pub fn bind<'cx>(
+		&self, cx: &'cx Context, this: &Value, args: &[Value],
+	) -> Result<Function<'cx>, Option<ErrorReport>> {
+		let object = Object::from(cx.root(unsafe { JS_GetFunctionObject(self.get()) }));
+		let bind = object
+			.get(cx, "bind")
+			.ok()
+			.flatten()
+			.filter(|bind| bind.handle().is_object())
+			.and_then(|bind| Function::from_object(cx, &bind.to_object(cx)))
+			.ok_or(None)?;
+
+		let mut bind_args = Vec::with_capacity(args.len() + 1);
+		bind_args.push(Value::from(cx.root(this.get())));
+		bind_args.extend(args.iter().map(|arg| Value::from(cx.root(arg.get()))));
+
+		let bound = bind.call(cx, &object, &bind_args)?;
+		Ok(Function::from_object(cx, &bound.to_object(cx)).unwrap())
+	}
+
 	/// Checks if the [Function] is the built-in eval function.
 	pub fn is_eval(&self) -> bool {
 		unsafe { JS_IsBuiltinEvalFunction(self.get()) }
@@ -217,3 +294,10 @@ impl<'f> Deref for Function<'f> {
 		&self.function
 	}
 }
+
+/// Creates an [ErrorReport] from the pending exception, or from the error which occurred while converting it.
+/// Returns [None] if there is no pending exception.
+fn pending_report(cx: &Context) -> Option<ErrorReport> {
+	ErrorReport::new_with_exception_stack(cx)
+		.unwrap_or_else(|error| Some(ErrorReport::from(Exception::Error(error), None)))
+}
@@ -5,18 +5,23 @@
  */
 
 use std::any::Any;
+use std::ffi::CStr;
 use std::mem::forget;
 use std::thread::Result;
 
 pub use arguments::{Accessor, Arguments, FromArgument};
+pub use callback::{Callback, CallbackArgs};
 pub use closure::{Closure, ClosureOnce};
 pub use function::{Function, NativeFunction};
 use mozjs::conversions::ConversionBehavior;
+use mozjs::jsval::JSVal;
 
+use crate::class::NativeClass;
 use crate::conversions::{FromValue, ToValue};
-use crate::{Context, Error, Object, ResultExc, ThrowException, Value};
+use crate::{Context, Error, ErrorKind, Object, ResultExc, ThrowException, Value};
 
 mod arguments;
+mod callback;
 mod closure;
 mod function;
 
@@ -49,6 +54,48 @@ pub fn __handle_native_constructor_result(
 	}
 }
 
+/// Reports the use of a deprecated function or accessor with `console.warn`.
+/// Falls back to printing to stderr if the global object has no console.
+#[doc(hidden)]
+pub fn __report_deprecation(cx: &Context, class: Option<&NativeClass>, name: &str, message: &str) {
+	let name = match class {
+		Some(class) => {
+			let class = unsafe { CStr::from_ptr(class.base.name) };
+			format!("{}.{name}", class.to_string_lossy())
+		}
+		None => String::from(name),
+	};
+	let warning = format!("{name} is deprecated: {message}");
+
+	let global = Object::global(cx);
+	let console = global.get_as::<_, Object>(cx, "console", true, ()).ok().flatten();
+	let warn = console.as_ref().and_then(|console| {
+		let warn = console.get_as::<_, Function>(cx, "warn", true, ()).ok().flatten()?;
+		Some((console, warn))
+	});
+	match warn {
+		Some((console, warn)) if warn.call(cx, console, &[warning.as_value(cx)]).is_ok() => {}
+		_ => eprintln!("Warning: {warning}"),
+	}
+}
+
+/// Returns the name of the native function being called, which is the name it was defined with.
+#[doc(hidden)]
+pub unsafe fn __callee_name(cx: &Context, argc: u32, vp: *mut JSVal) -> String {
+	let args = unsafe { Arguments::new(cx, argc, vp) };
+	Function::from_object(cx, &args.callee())
+		.and_then(|function| function.name(cx).ok())
+		.unwrap_or_default()
+}
+
+/// Throws an error for a call to an unstable function when unstable bindings are not enabled.
+#[doc(hidden)]
+pub fn __throw_unstable(cx: &Context, name: &str) -> bool {
+	let message = format!("{name} is unstable and requires unstable bindings to be enabled.");
+	Error::new(message, ErrorKind::Type).throw(cx);
+	false
+}
+
 fn handle_unwind_error(cx: &Context, unwind_error: Box<dyn Any + Send>) -> bool {
 	match unwind_error.downcast::<String>() {
 		Ok(unwind) => Error::new(*unwind, None).throw(cx),
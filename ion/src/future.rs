@@ -14,9 +14,32 @@ use futures::channel::mpsc::Receiver;
 use futures::Stream;
 use mozjs::jsval::JSVal;
 
-use crate::{Context, Promise, Value};
+use crate::conversions::BoxedIntoValue;
+use crate::{Context, Promise, TracedHeap, Value};
 
-pub struct PromiseFuture(Receiver<Result<JSVal, JSVal>>);
+/// [Future] spawned by a [FutureSpawner], whose output settles a [Promise].
+pub type SpawnedFuture = Pin<Box<dyn Future<Output = Result<BoxedIntoValue, BoxedIntoValue>>>>;
+
+/// Represents the ability of a runtime to spawn futures onto its event loop.
+///
+/// Used by [Promise::spawn_future] and by `async` functions and methods of `#[js_fn]` and `#[js_class]`.
+pub trait FutureSpawner {
+	/// Spawns the future, settling the promise with its output once it completes.
+	/// Returns `false` if the future could not be spawned.
+	fn spawn(&self, cx: &Context, promise: &Promise, future: SpawnedFuture) -> bool;
+}
+
+/// Initialises a future spawner in the current runtime.
+pub fn init_future_spawner<FS: FutureSpawner + 'static>(cx: &Context, spawner: FS) {
+	unsafe {
+		(*cx.get_inner_data().as_ptr()).future_spawner = Some(Box::new(spawner));
+	}
+}
+
+/// [Future] that completes once a [Promise] is settled.
+///
+/// The settled value is kept rooted, so it remains valid until the output is dropped.
+pub struct PromiseFuture(Receiver<Result<TracedHeap<JSVal>, TracedHeap<JSVal>>>);
 
 impl PromiseFuture {
 	pub fn new(cx: &Context, promise: &Promise) -> PromiseFuture {
@@ -28,11 +51,11 @@ impl PromiseFuture {
 		promise.add_reactions(
 			cx,
 			move |_, value| {
-				let _ = rx1.try_send(Ok(value.get()));
+				let _ = rx1.try_send(Ok(TracedHeap::new(value.get())));
 				Ok(Value::undefined_handle())
 			},
 			move |_, value| {
-				let _ = rx2.try_send(Err(value.get()));
+				let _ = rx2.try_send(Err(TracedHeap::new(value.get())));
 				Ok(Value::undefined_handle())
 			},
 		);
@@ -42,9 +65,9 @@ impl PromiseFuture {
 }
 
 impl Future for PromiseFuture {
-	type Output = Result<JSVal, JSVal>;
+	type Output = Result<TracedHeap<JSVal>, TracedHeap<JSVal>>;
 
-	fn poll(mut self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Result<JSVal, JSVal>> {
+	fn poll(mut self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Self::Output> {
 		let result = Pin::new(&mut self.0);
 		if let Poll::Ready(Some(val)) = result.poll_next(cx) {
 			Poll::Ready(val)
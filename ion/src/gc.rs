@@ -0,0 +1,412 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+use std::ffi::{c_void, CString};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::{io, ptr};
+
+use mozjs::jsapi::js::GetGCHeapUsageForObjectZone;
+use mozjs::jsapi::{
+	DumpHeap, DumpHeapNurseryBehaviour, GCDescription, GCProgress as JSGCProgress, GCReason, JSContext, JSGCParamKey,
+	JSGCStatus, JS_GetContextPrivate, JS_GetGCParameter, JS_SetGCCallback, JS_SetGCParameter, SetGCSliceCallback,
+	JS_GC,
+};
+
+use crate::utils::json_string;
+use crate::{Context, ContextInner, Object};
+
+/// Represents the status of a garbage collection passed to the callback set with [set_gc_callback].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum GCStatus {
+	Begin,
+	End,
+}
+
+/// Represents the progress of an incremental garbage collection passed to the callback set with
+/// [set_slice_callback].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum GCProgress {
+	CycleBegin,
+	SliceBegin,
+	SliceEnd,
+	CycleEnd,
+}
+
+/// Callback called when a garbage collection begins or ends.
+///
+/// The callback is called during garbage collection, so it must not allocate GC things or run scripts.
+pub type GCCallback = dyn FnMut(GCStatus);
+
+/// Callback called at the boundaries of each slice of an incremental garbage collection.
+///
+/// The callback is called during garbage collection, so it must not allocate GC things or run scripts.
+pub type GCSliceCallback = dyn FnMut(GCProgress);
+
+/// Stores the garbage collection callbacks of a [Context].
+#[derive(Default)]
+pub struct GCCallbacks {
+	gc: Option<Box<GCCallback>>,
+	slice: Option<Box<GCSliceCallback>>,
+}
+
+/// Statistics about the garbage collected heap of the runtime.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct HeapStatistics {
+	/// Number of bytes allocated by the garbage collector.
+	pub bytes: u64,
+	/// Maximum number of bytes the garbage collector can allocate.
+	pub max_bytes: u64,
+	/// Number of garbage collections, both major and minor.
+	pub gc_count: u64,
+	/// Number of major garbage collections, which collect the whole heap.
+	pub major_gc_count: u64,
+	/// Number of minor garbage collections, which only collect the nursery.
+	pub minor_gc_count: u64,
+}
+
+/// Sets the callback called when a garbage collection begins or ends, replacing the previous callback.
+/// Passing [None] removes the callback.
+pub fn set_gc_callback(cx: &Context, callback: Option<Box<GCCallback>>) {
+	let enabled = callback.is_some();
+	unsafe {
+		(*cx.get_inner_data().as_ptr()).gc_callbacks.gc = callback;
+		JS_SetGCCallback(cx.as_ptr(), enabled.then_some(call_gc_callback as _), ptr::null_mut());
+	}
+}
+
+/// Sets the callback called at the boundaries of incremental garbage collection slices, replacing the previous
+/// callback.
+/// Passing [None] removes the callback.
+pub fn set_slice_callback(cx: &Context, callback: Option<Box<GCSliceCallback>>) {
+	let enabled = callback.is_some();
+	unsafe {
+		(*cx.get_inner_data().as_ptr()).gc_callbacks.slice = callback;
+		SetGCSliceCallback(cx.as_ptr(), enabled.then_some(call_slice_callback as _));
+	}
+}
+
+/// Sets a parameter of the garbage collector of the runtime, such as [JSGCParamKey::JSGC_MAX_BYTES].
+/// Boolean parameters are enabled with `1` and disabled with `0`.
+pub fn set_parameter(cx: &Context, key: JSGCParamKey, value: u32) {
+	unsafe { JS_SetGCParameter(cx.as_ptr(), key, value) }
+}
+
+/// Returns the current value of a parameter of the garbage collector of the runtime.
+pub fn parameter(cx: &Context, key: JSGCParamKey) -> u32 {
+	unsafe { JS_GetGCParameter(cx.as_ptr(), key) }
+}
+
+/// Returns the current [HeapStatistics] of the runtime.
+pub fn heap_statistics(cx: &Context) -> HeapStatistics {
+	HeapStatistics {
+		bytes: u64::from(parameter(cx, JSGCParamKey::JSGC_BYTES)),
+		max_bytes: u64::from(parameter(cx, JSGCParamKey::JSGC_MAX_BYTES)),
+		gc_count: u64::from(parameter(cx, JSGCParamKey::JSGC_NUMBER)),
+		major_gc_count: u64::from(parameter(cx, JSGCParamKey::JSGC_MAJOR_GC_NUMBER)),
+		minor_gc_count: u64::from(parameter(cx, JSGCParamKey::JSGC_MINOR_GC_NUMBER)),
+	}
+}
+
+/// Returns the number of bytes allocated by the garbage collector in the zone of the object.
+///
+/// Globals created with the default realm options are in their own zone, so the usage of the zone of such a global is
+/// the usage of its realm.
+pub fn zone_heap_bytes(object: &Object) -> u64 {
+	unsafe { GetGCHeapUsageForObjectZone(object.handle().get()) }
+}
+
+/// Performs a full, non-incremental garbage collection.
+pub fn collect(cx: &Context) {
+	unsafe { JS_GC(cx.as_ptr(), GCReason::API) }
+}
+
+/// Writes a text dump of the garbage collected heap to the file at the given path, replacing it if it exists.
+///
+/// The dump is in the plain text format of SpiderMonkey's `DumpHeap`, which lists every live GC thing with its address,
+/// kind and outgoing edges. Use [write_heap_snapshot] for a snapshot which can be opened by DevTools.
+/// The nursery is collected beforehand, so that every object appears in the dump.
+/// Retaining paths can be found by following edges from the roots listed at the start of the dump.
+pub fn write_heap_dump(cx: &Context, path: &Path) -> io::Result<()> {
+	let path = path
+		.to_str()
+		.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Path is not valid UTF-8"))?;
+	let path = CString::new(path)?;
+	let file = unsafe { libc::fopen(path.as_ptr(), c"w".as_ptr()) };
+	if file.is_null() {
+		return Err(io::Error::last_os_error());
+	}
+
+	unsafe {
+		DumpHeap(cx.as_ptr(), file.cast(), DumpHeapNurseryBehaviour::CollectNursery, None);
+	}
+
+	if unsafe { libc::fclose(file) } == 0 {
+		Ok(())
+	} else {
+		Err(io::Error::last_os_error())
+	}
+}
+
+/// Writes a snapshot of the garbage collected heap to the file at the given path, replacing it if it exists.
+///
+/// The snapshot is in the JSON format of `.heapsnapshot` files, which can be loaded in the Memory panel of Chromium
+/// DevTools. It is built from the same graph as [write_heap_dump], so every GC thing is a node sized by its cell, and
+/// each root of the heap is an edge from the synthetic `(GC roots)` node.
+pub fn write_heap_snapshot(cx: &Context, path: &Path) -> io::Result<()> {
+	let dump = read_heap_dump(cx)?;
+	let snapshot = HeapSnapshot::parse(&dump);
+	let mut file = BufWriter::new(File::create(path)?);
+	snapshot.write(&mut file)?;
+	file.flush()
+}
+
+/// Dumps the heap into a temporary file and reads it back.
+fn read_heap_dump(cx: &Context) -> io::Result<String> {
+	let file = unsafe { libc::tmpfile() };
+	if file.is_null() {
+		return Err(io::Error::last_os_error());
+	}
+
+	let mut dump = Vec::new();
+	let mut buffer = [0; 8192];
+	let failed = unsafe {
+		DumpHeap(cx.as_ptr(), file.cast(), DumpHeapNurseryBehaviour::CollectNursery, None);
+		libc::rewind(file);
+		loop {
+			let read = libc::fread(buffer.as_mut_ptr().cast(), 1, buffer.len(), file);
+			if read == 0 {
+				break;
+			}
+			dump.extend_from_slice(&buffer[..read]);
+		}
+		let failed = libc::ferror(file) != 0;
+		libc::fclose(file);
+		failed
+	};
+
+	if failed {
+		Err(io::Error::other("Failed to read heap dump"))
+	} else {
+		Ok(String::from_utf8_lossy(&dump).into_owned())
+	}
+}
+
+const NODE_FIELDS: usize = 7;
+
+#[derive(Clone, Copy)]
+#[repr(u8)]
+enum NodeType {
+	Hidden = 0,
+	Array = 1,
+	String = 2,
+	Object = 3,
+	Code = 4,
+	Closure = 5,
+	RegExp = 6,
+	Synthetic = 9,
+	Symbol = 12,
+	BigInt = 13,
+	ObjectShape = 14,
+}
+
+struct Node<'d> {
+	kind: NodeType,
+	name: usize,
+	size: u64,
+	edges: Vec<(usize, &'d str)>,
+}
+
+/// Graph of a heap dump, in the layout of a `.heapsnapshot` file.
+#[derive(Default)]
+struct HeapSnapshot<'d> {
+	nodes: Vec<Node<'d>>,
+	indices: HashMap<&'d str, usize>,
+	strings: Vec<&'d str>,
+	string_indices: HashMap<&'d str, usize>,
+}
+
+impl<'d> HeapSnapshot<'d> {
+	fn parse(dump: &'d str) -> HeapSnapshot<'d> {
+		let mut snapshot = HeapSnapshot::default();
+		let name = snapshot.string("(GC roots)");
+		snapshot.nodes.push(Node {
+			kind: NodeType::Synthetic,
+			name,
+			size: 0,
+			edges: Vec::new(),
+		});
+
+		// Roots are listed first, followed by weak map entries, and the cells of the heap after the separator.
+		let mut in_heap = false;
+		let mut in_roots = true;
+		let mut size = 0;
+		for line in dump.lines() {
+			if let Some(comment) = line.strip_prefix("# ") {
+				if comment == "Weak maps." {
+					in_roots = false;
+				} else if let Some(arena) = comment.strip_prefix("arena ") {
+					let field = arena.split(' ').find_map(|field| field.strip_prefix("size="));
+					size = field.and_then(|size| size.parse().ok()).unwrap_or(0);
+				}
+			} else if line.starts_with("==========") {
+				in_heap = true;
+			} else if in_heap {
+				if let Some(edge) = line.strip_prefix("> ") {
+					if let Some((address, name)) = split_thing(edge) {
+						let name = snapshot.string(name);
+						snapshot.nodes.last_mut().unwrap().edges.push((name, address));
+					}
+				} else if let Some((address, description)) = split_thing(line) {
+					let (kind, name) = describe(description);
+					let name = snapshot.string(name);
+					snapshot.indices.insert(address, snapshot.nodes.len());
+					snapshot.nodes.push(Node { kind, name, size, edges: Vec::new() });
+				}
+			} else if in_roots {
+				if let Some((address, name)) = split_thing(line) {
+					let name = snapshot.string(name);
+					snapshot.nodes[0].edges.push((name, address));
+				}
+			}
+		}
+		snapshot
+	}
+
+	fn string(&mut self, string: &'d str) -> usize {
+		let strings = &mut self.strings;
+		*self.string_indices.entry(string).or_insert_with(|| {
+			strings.push(string);
+			strings.len() - 1
+		})
+	}
+
+	/// Returns the names and node indices of the edges of a node.
+	/// Edges to GC things which are not in the heap, such as those in the nursery, are dropped.
+	fn edges<'s>(&'s self, node: &'s Node<'d>) -> impl Iterator<Item = (usize, usize)> + 's {
+		node.edges
+			.iter()
+			.filter_map(|(name, address)| self.indices.get(address).map(|index| (*name, *index)))
+	}
+
+	fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+		let edge_count: usize = self.nodes.iter().map(|node| self.edges(node).count()).sum();
+
+		write!(
+			writer,
+			concat!(
+				r#"{{"snapshot":{{"meta":{{"node_fields":["type","name","id","self_size","edge_count","trace_node_id","#,
+				r#""detachedness"],"node_types":[["hidden","array","string","object","code","closure","regexp","number","#,
+				r#""native","synthetic","concatenated string","sliced string","symbol","bigint","object shape"],"string","#,
+				r#""number","number","number","number","number"],"edge_fields":["type","name_or_index","to_node"],"#,
+				r#""edge_types":[["context","element","property","internal","hidden","shortcut","weak"],"#,
+				r#""string_or_number","node"]}},"node_count":{},"edge_count":{},"trace_function_count":0}},"#
+			),
+			self.nodes.len(),
+			edge_count
+		)?;
+
+		writer.write_all(b"\n\"nodes\":[")?;
+		for (index, node) in self.nodes.iter().enumerate() {
+			let separator = if index == 0 { "" } else { ",\n" };
+			write!(
+				writer,
+				"{separator}{},{},{},{},{},0,0",
+				node.kind as u8,
+				node.name,
+				index * 2 + 1,
+				node.size,
+				self.edges(node).count()
+			)?;
+		}
+
+		// Every edge is a property edge, named by the tracer.
+		writer.write_all(b"],\n\"edges\":[")?;
+		let mut first = true;
+		for node in &self.nodes {
+			for (name, index) in self.edges(node) {
+				let separator = if first { "" } else { ",\n" };
+				first = false;
+				write!(writer, "{separator}2,{name},{}", index * NODE_FIELDS)?;
+			}
+		}
+
+		writer.write_all(
+			b"],\n\"trace_function_infos\":[],\"trace_tree\":[],\"samples\":[],\"locations\":[],\n\"strings\":[",
+		)?;
+		for (index, string) in self.strings.iter().enumerate() {
+			if index > 0 {
+				writer.write_all(b",\n")?;
+			}
+			writer.write_all(json_string(string).as_bytes())?;
+		}
+		writer.write_all(b"]}\n")
+	}
+}
+
+/// Splits a line of a heap dump into the address of the GC thing and its description, skipping its mark colour.
+fn split_thing(line: &str) -> Option<(&str, &str)> {
+	let (address, rest) = line.split_once(' ')?;
+	let mut chars = rest.chars();
+	chars.next()?;
+	match chars.next() {
+		Some(' ') => Some((address, chars.as_str())),
+		None => Some((address, "")),
+		_ => None,
+	}
+}
+
+/// Returns the node type and name of a GC thing from its description in a heap dump.
+fn describe(description: &str) -> (NodeType, &str) {
+	let (kind, details) = description.split_once(' ').unwrap_or((description, ""));
+	match kind {
+		"Function" => (
+			NodeType::Closure,
+			if details.is_empty() { "(anonymous)" } else { details },
+		),
+		"string" | "atom" => {
+			let contents = details.split_once("> ").filter(|_| details.starts_with('<'));
+			(NodeType::String, contents.map_or(details, |(_, contents)| contents))
+		}
+		"symbol" => (NodeType::Symbol, description),
+		"BigInt" => (NodeType::BigInt, description),
+		"Array" => (NodeType::Array, kind),
+		"RegExp" => (NodeType::RegExp, description),
+		"script" | "jitcode" => (NodeType::Code, description),
+		"shape" | "base_shape" | "prop_map" => (NodeType::ObjectShape, kind),
+		"scope" | "getter_setter" | "reg_exp_shared" => (NodeType::Hidden, description),
+		_ => (NodeType::Object, kind),
+	}
+}
+
+unsafe fn callbacks<'a>(cx: *mut JSContext) -> Option<&'a mut GCCallbacks> {
+	let inner = unsafe { JS_GetContextPrivate(cx).cast::<ContextInner>() };
+	unsafe { inner.as_mut().map(|inner| &mut inner.gc_callbacks) }
+}
+
+unsafe extern "C" fn call_gc_callback(cx: *mut JSContext, status: JSGCStatus, _: GCReason, _: *mut c_void) {
+	if let Some(callback) = unsafe { callbacks(cx) }.and_then(|callbacks| callbacks.gc.as_mut()) {
+		let status = match status {
+			JSGCStatus::JSGC_BEGIN => GCStatus::Begin,
+			JSGCStatus::JSGC_END => GCStatus::End,
+		};
+		callback(status);
+	}
+}
+
+unsafe extern "C" fn call_slice_callback(cx: *mut JSContext, progress: JSGCProgress, _: *const GCDescription) {
+	if let Some(callback) = unsafe { callbacks(cx) }.and_then(|callbacks| callbacks.slice.as_mut()) {
+		let progress = match progress {
+			JSGCProgress::GC_CYCLE_BEGIN => GCProgress::CycleBegin,
+			JSGCProgress::GC_SLICE_BEGIN => GCProgress::SliceBegin,
+			JSGCProgress::GC_SLICE_END => GCProgress::SliceEnd,
+			JSGCProgress::GC_CYCLE_END => GCProgress::CycleEnd,
+		};
+		callback(progress);
+	}
+}
@@ -0,0 +1,346 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::ffi::c_void;
+use std::io::{self, Write};
+use std::{ptr, slice};
+
+use mozjs::jsapi::{ESClass, JS_ParseJSON, JS_ParseJSONWithReviver, ToJSON};
+
+use crate::conversions::{FromValue, ToValue};
+use crate::{
+	Array, Context, Error, ErrorKind, ErrorReport, Exception, Function, Object, PropertyKey, ResultExc, Value,
+};
+
+/// Parses a JSON string into a [Value], as with `JSON.parse`.
+pub fn parse<'cx>(cx: &'cx Context, text: &str) -> ResultExc<Value<'cx>> {
+	let text: Vec<u16> = text.encode_utf16().collect();
+	let mut value = Value::undefined(cx);
+	let success = unsafe { JS_ParseJSON(cx.as_ptr(), text.as_ptr(), text.len() as u32, value.handle_mut().into()) };
+	if success {
+		Ok(value)
+	} else {
+		Err(pending_exception(cx))
+	}
+}
+
+/// Parses a JSON string into a [Value], transforming each parsed value with the reviver.
+pub fn parse_with_reviver<'cx>(cx: &'cx Context, text: &str, reviver: &Function) -> ResultExc<Value<'cx>> {
+	let text: Vec<u16> = text.encode_utf16().collect();
+	let reviver = reviver.as_value(cx);
+	let mut value = Value::undefined(cx);
+	let success = unsafe {
+		JS_ParseJSONWithReviver(
+			cx.as_ptr(),
+			text.as_ptr(),
+			text.len() as u32,
+			reviver.handle().into(),
+			value.handle_mut().into(),
+		)
+	};
+	if success {
+		Ok(value)
+	} else {
+		Err(pending_exception(cx))
+	}
+}
+
+/// Converts a [Value] into a JSON string, as with `JSON.stringify`.
+///
+/// The replacer can be a function or an array of keys to include. The indent is used for each level of nesting.
+/// Returns [None] if the value cannot be represented in JSON, such as `undefined` or functions.
+pub fn stringify(
+	cx: &Context, value: &Value, replacer: Option<&Object>, indent: Option<&str>,
+) -> ResultExc<Option<String>> {
+	let replacer = replacer.map_or_else(
+		|| Object::null(cx),
+		|replacer| Object::from(cx.root(replacer.handle().get())),
+	);
+	let indent = indent.map_or_else(|| Value::undefined(cx), |indent| indent.as_value(cx));
+
+	let mut json = Vec::new();
+	let mut output = JsonWriter {
+		writer: &mut json,
+		written: false,
+		error: None,
+	};
+	let written = to_json(cx, value, &replacer, &indent, &mut output)?;
+	Ok(written.then(|| String::from_utf8(json).unwrap()))
+}
+
+/// Converts a [Value] into JSON, as with [stringify], and writes it to the writer as it is produced.
+///
+/// Arrays and objects are written one element at a time, so the full JSON string is never held in memory.
+/// Returns `false` if the value cannot be represented in JSON, in which case nothing is written.
+pub fn stringify_into<W: Write>(
+	cx: &Context, value: &Value, replacer: Option<&Object>, indent: Option<&str>, writer: &mut W,
+) -> ResultExc<bool> {
+	let replacer = match replacer {
+		Some(replacer) if unsafe { Function::is_function_raw(replacer.handle().get()) } => {
+			Replacer::Function(Function::from_object(cx, replacer).unwrap())
+		}
+		Some(replacer) if Array::is_array(cx, replacer) => Replacer::Keys(property_list(cx, replacer)?),
+		_ => Replacer::None,
+	};
+	let gap: String = indent.unwrap_or_default().chars().take(10).collect();
+
+	let holder = Object::new(cx);
+	holder.set(cx, "", value);
+	let key = PropertyKey::with_string(cx, "").unwrap();
+	let mut stringifier = Stringifier {
+		cx,
+		output: JsonWriter { writer, written: false, error: None },
+		replacer,
+		gap,
+		indent: String::new(),
+		stack: Vec::new(),
+	};
+	match stringifier.resolve(&holder, &key)? {
+		Some(value) => {
+			stringifier.write_value(&value)?;
+			Ok(true)
+		}
+		None => Ok(false),
+	}
+}
+
+/// Returns the keys to include for a replacer array, without duplicates.
+fn property_list<'cx>(cx: &'cx Context, replacer: &Object) -> ResultExc<Vec<PropertyKey<'cx>>> {
+	let replacer = Array::from(cx, cx.root(replacer.handle().get())).unwrap();
+	let mut names = Vec::new();
+	let mut keys = Vec::new();
+	for index in 0..replacer.len(cx) {
+		let Some(item) = replacer.get(cx, index)? else {
+			continue;
+		};
+		let is_key = if item.handle().is_object() {
+			matches!(
+				item.to_object(cx).get_builtin_class(cx),
+				ESClass::String | ESClass::Number
+			)
+		} else {
+			item.handle().is_string() || item.handle().is_number()
+		};
+		if !is_key {
+			continue;
+		}
+
+		let name = crate::String::from_value(cx, &item, false, ())?;
+		let string = name.to_owned(cx)?;
+		if !names.contains(&string) {
+			names.push(string);
+			keys.push(PropertyKey::from_value(cx, &name.as_value(cx)).unwrap());
+		}
+	}
+	Ok(keys)
+}
+
+enum Replacer<'cx> {
+	None,
+	Function(Function<'cx>),
+	Keys(Vec<PropertyKey<'cx>>),
+}
+
+struct Stringifier<'cx, 'w> {
+	cx: &'cx Context,
+	output: JsonWriter<'w>,
+	replacer: Replacer<'cx>,
+	gap: String,
+	indent: String,
+	stack: Vec<Object<'cx>>,
+}
+
+impl<'cx> Stringifier<'cx, '_> {
+	/// Returns the value of the property to serialise, after calling `toJSON` and the replacer function.
+	/// Returns [None] if the property is omitted, as with `undefined`, functions and symbols.
+	fn resolve(&self, holder: &Object, key: &PropertyKey) -> ResultExc<Option<Value<'cx>>> {
+		let cx = self.cx;
+		let mut value = holder.get(cx, key)?.unwrap_or_else(|| Value::undefined(cx));
+
+		if value.handle().is_object() {
+			let object = value.to_object(cx);
+			if let Some(to_json) = object.get(cx, "toJSON")?.filter(Value::is_callable) {
+				let to_json = Function::from_object(cx, &to_json.to_object(cx)).unwrap();
+				value = to_json
+					.call(cx, &object, &[self.key_value(key)?])
+					.map_err(|report| self.exception(report))?;
+			}
+		}
+		if let Replacer::Function(replacer) = &self.replacer {
+			value = replacer
+				.call(cx, holder, &[self.key_value(key)?, value])
+				.map_err(|report| self.exception(report))?;
+		}
+
+		let handle = value.handle();
+		if handle.is_undefined() || handle.is_symbol() || value.is_callable() {
+			Ok(None)
+		} else {
+			Ok(Some(value))
+		}
+	}
+
+	fn write_value(&mut self, value: &Value) -> ResultExc<()> {
+		let cx = self.cx;
+		if !value.handle().is_object() {
+			return self.write_primitive(value);
+		}
+
+		let object = value.to_object(cx);
+		if let Some(primitive) = object.unbox_primitive(cx) {
+			return self.write_primitive(&primitive);
+		}
+		if self.stack.iter().any(|parent| parent.handle().get() == object.handle().get()) {
+			return Err(Error::new("Cyclic object value", ErrorKind::Type).into());
+		}
+
+		let outer = self.indent.clone();
+		self.indent.push_str(&self.gap);
+		self.stack.push(Object::from(cx.root(object.handle().get())));
+
+		let result = if Array::is_array(cx, &object) {
+			self.write_array(&object)
+		} else {
+			self.write_object(&object)
+		};
+
+		self.stack.pop();
+		self.indent = outer;
+		result
+	}
+
+	fn write_array(&mut self, array: &Object) -> ResultExc<()> {
+		let cx = self.cx;
+		let length = Array::from(cx, cx.root(array.handle().get())).unwrap().len(cx);
+		self.write("[")?;
+		for index in 0..length {
+			self.write_separator(index == 0)?;
+			let key = PropertyKey::with_index(cx, index);
+			match self.resolve(array, &key)? {
+				Some(value) => self.write_value(&value)?,
+				None => self.write("null")?,
+			}
+		}
+		self.write_end(length == 0, "]")
+	}
+
+	fn write_object(&mut self, object: &Object) -> ResultExc<()> {
+		let cx = self.cx;
+		let keys: Vec<_> = match &self.replacer {
+			Replacer::Keys(keys) => keys.iter().map(|key| PropertyKey::from(cx.root(key.handle().get()))).collect(),
+			_ => object.keys(cx, None).collect(),
+		};
+
+		self.write("{")?;
+		let mut empty = true;
+		for key in keys {
+			let Some(value) = self.resolve(object, &key)? else {
+				continue;
+			};
+			self.write_separator(empty)?;
+			empty = false;
+
+			self.write_primitive(&self.key_value(&key)?)?;
+			self.write(if self.gap.is_empty() { ":" } else { ": " })?;
+			self.write_value(&value)?;
+		}
+		self.write_end(empty, "}")
+	}
+
+	/// Writes a value which is not an object, with the same representation as `JSON.stringify`.
+	fn write_primitive(&mut self, value: &Value) -> ResultExc<()> {
+		let null = Object::null(self.cx);
+		let indent = Value::undefined(self.cx);
+		to_json(self.cx, value, &null, &indent, &mut self.output).map(|_| ())
+	}
+
+	fn write_separator(&mut self, first: bool) -> ResultExc<()> {
+		if !first {
+			self.write(",")?;
+		}
+		if !self.gap.is_empty() {
+			self.write("\n")?;
+			let indent = self.indent.clone();
+			self.write(&indent)?;
+		}
+		Ok(())
+	}
+
+	fn write_end(&mut self, empty: bool, end: &str) -> ResultExc<()> {
+		if !empty && !self.gap.is_empty() {
+			self.write("\n")?;
+			let indent = self.indent[..self.indent.len() - self.gap.len()].to_string();
+			self.write(&indent)?;
+		}
+		self.write(end)
+	}
+
+	fn write(&mut self, json: &str) -> ResultExc<()> {
+		self.output.writer.write_all(json.as_bytes()).map_err(|error| Error::from(error).into())
+	}
+
+	/// Returns the key as a string, as index keys are integers.
+	fn key_value(&self, key: &PropertyKey) -> ResultExc<Value<'cx>> {
+		let key = crate::String::from_value(self.cx, &key.as_value(self.cx), false, ())?;
+		Ok(key.as_value(self.cx))
+	}
+
+	fn exception(&self, report: Option<ErrorReport>) -> Exception {
+		report.map_or_else(|| pending_exception(self.cx), |report| report.exception)
+	}
+}
+
+/// Converts a [Value] into JSON with `JS::ToJSON`, which produces the full JSON string before writing it.
+fn to_json(cx: &Context, value: &Value, replacer: &Object, indent: &Value, output: &mut JsonWriter) -> ResultExc<bool> {
+	output.written = false;
+	let success = unsafe {
+		ToJSON(
+			cx.as_ptr(),
+			value.handle().into(),
+			replacer.handle().into(),
+			indent.handle().into(),
+			Some(write_json),
+			ptr::from_mut(output).cast::<c_void>(),
+		)
+	};
+
+	if let Some(error) = output.error.take() {
+		Err(Error::from(error).into())
+	} else if success {
+		Ok(output.written)
+	} else {
+		Err(pending_exception(cx))
+	}
+}
+
+struct JsonWriter<'w> {
+	writer: &'w mut dyn Write,
+	written: bool,
+	error: Option<io::Error>,
+}
+
+unsafe extern "C" fn write_json(buf: *const u16, len: u32, data: *mut c_void) -> bool {
+	let output = unsafe { &mut *data.cast::<JsonWriter>() };
+	let json = String::from_utf16_lossy(unsafe { slice::from_raw_parts(buf, len as usize) });
+	match output.writer.write_all(json.as_bytes()) {
+		Ok(()) => {
+			output.written = true;
+			true
+		}
+		Err(error) => {
+			output.error = Some(error);
+			false
+		}
+	}
+}
+
+fn pending_exception(cx: &Context) -> Exception {
+	match Exception::new(cx) {
+		Ok(Some(exception)) => exception,
+		Ok(None) => Error::new("JSON operation failed without an exception", None).into(),
+		Err(error) => error.into(),
+	}
+}
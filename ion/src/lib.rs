@@ -13,23 +13,25 @@ use std::result;
 
 pub use bigint::BigInt;
 pub use class::ClassDefinition;
+pub use constant::ConstEnum;
 pub use context::{Context, ContextInner};
 pub use error::{Error, ErrorKind};
 pub use exception::{ErrorReport, Exception, ThrowException};
 pub use function::{Arguments, Function};
-pub use future::PromiseFuture;
+pub use future::{init_future_spawner, FutureSpawner, PromiseFuture, SpawnedFuture};
 #[cfg(feature = "macros")]
 pub use ion_proc::*;
 pub use object::*;
-pub use root::{Local, TracedHeap};
+pub use root::{Local, Persistent, TracedHeap};
 pub use stack::{Stack, StackRecord};
 pub use string::{String, StringRef};
 pub use symbol::Symbol;
-pub use value::Value;
+pub use value::{Value, ValueType};
 
 mod bigint;
 pub mod class;
 pub mod clone;
+mod constant;
 mod context;
 pub mod conversions;
 mod error;
@@ -38,8 +40,11 @@ pub mod flags;
 pub mod format;
 pub mod function;
 mod future;
+pub mod gc;
+pub mod json;
 pub mod module;
 pub mod object;
+pub mod profiler;
 mod root;
 pub mod script;
 pub mod spec;
@@ -4,17 +4,24 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
-use std::path::Path;
+use std::future::Future;
+use std::path::{Path, PathBuf};
 use std::ptr;
 
+use futures::channel::oneshot;
+use futures::executor::block_on;
+
 use mozjs::jsapi::{
-	CompileModule, CreateModuleRequest, GetModuleRequestSpecifier, Handle, JSContext, JSObject, JS_GetRuntime,
-	ModuleEvaluate, ModuleLink, SetModuleMetadataHook, SetModulePrivate, SetModuleResolveHook,
+	CompileModule, CreateModuleRequest, GetModuleNamespace, GetModulePrivate, GetModuleRequestSpecifier,
+	GetRequestedModuleSpecifier, GetRequestedModulesCount, Handle, InstantiateModuleStencil, InstantiateOptions,
+	JSContext, JSObject, JS_GetRuntime, ModuleEvaluate, ModuleLink, SetModuleMetadataHook, SetModulePrivate,
+	SetModuleResolveHook,
 };
 use mozjs::jsval::JSVal;
-use mozjs::rust::{transform_u16_to_source_text, CompileOptionsWrapper};
+use mozjs::rust::transform_u16_to_source_text;
 
 use crate::conversions::{FromValue, ToValue};
+use crate::script::{compile_stencil_off_thread, receive_stencil, CompileOptions, OffThreadStencil};
 use crate::{Context, Error, ErrorReport, Local, Object, Promise, ThrowException, Value};
 
 /// Represents private module data
@@ -106,23 +113,12 @@ impl<'cx> Module<'cx> {
 		let script: Vec<u16> = script.encode_utf16().collect();
 		let mut source = transform_u16_to_source_text(script.as_slice());
 		let filename = path.and_then(Path::to_str).unwrap_or(filename);
-		let options = unsafe { CompileOptionsWrapper::new(cx.as_ptr(), filename, 1) };
+		let options = CompileOptions::new(filename).to_wrapper(cx);
 
 		let module = unsafe { CompileModule(cx.as_ptr(), options.ptr.cast_const(), &mut source) };
 
 		if !module.is_null() {
-			let module = Module(Object::from(cx.root(module)));
-
-			let data = ModuleData {
-				path: path.and_then(Path::to_str).map(String::from),
-			};
-
-			unsafe {
-				let private = data.to_object(cx).as_value(cx);
-				SetModulePrivate(module.0.handle().get(), &*private.handle());
-			}
-
-			Ok(module)
+			Ok(Module::with_data(cx, module, path))
 		} else {
 			Err(ModuleError::new(
 				ErrorReport::new(cx).unwrap().unwrap(),
@@ -131,6 +127,60 @@ impl<'cx> Module<'cx> {
 		}
 	}
 
+	/// Compiles a [Module] on a separate thread, and returns a future which resolves to the compiled module.
+	/// Parsing then overlaps with work done on the thread of the [Context] before the future is awaited.
+	///
+	/// The future must be polled on the thread of the [Context].
+	/// Resolves to [Err] when module compilation fails.
+	pub fn compile_off_thread(
+		cx: &'cx Context, filename: &str, path: Option<&Path>, script: String,
+	) -> impl Future<Output = Result<Module<'cx>, ModuleError>> + 'cx {
+		let source = script.clone();
+		let pending = Module::load_off_thread(cx, filename, path, move || Some((source, ())));
+		let (filename, path) = (pending.filename.clone(), pending.path.clone());
+
+		async move {
+			match pending.receive(cx).await {
+				Some(result) => result.map(|(module, _)| module),
+				None => Module::compile(cx, &filename, path.as_deref(), &script),
+			}
+		}
+	}
+
+	/// Loads and compiles a [Module] on a separate thread.
+	/// `load` is called on that thread, and returns the source of the module, along with data which is returned when
+	/// the module is received.
+	///
+	/// Starting several modules at once compiles them in parallel.
+	pub fn load_off_thread<T, F>(cx: &Context, filename: &str, path: Option<&Path>, load: F) -> PendingModule<T>
+	where
+		T: Send + 'static,
+		F: FnOnce() -> Option<(String, T)> + Send + 'static,
+	{
+		let filename = String::from(path.and_then(Path::to_str).unwrap_or(filename));
+		let options = CompileOptions::new(filename.clone());
+		PendingModule {
+			receiver: compile_stencil_off_thread(cx, &options, true, load),
+			filename,
+			path: path.map(Path::to_path_buf),
+		}
+	}
+
+	fn with_data(cx: &'cx Context, module: *mut JSObject, path: Option<&Path>) -> Module<'cx> {
+		let module = Module(Object::from(cx.root(module)));
+
+		let data = ModuleData {
+			path: path.and_then(Path::to_str).map(String::from),
+		};
+
+		unsafe {
+			let private = data.to_object(cx).as_value(cx);
+			SetModulePrivate(module.0.handle().get(), &*private.handle());
+		}
+
+		module
+	}
+
 	/// Compiles and evaluates a [Module] with the given source and filename.
 	/// On success, returns the compiled module object and a promise. The promise resolves with the return value of the module.
 	/// The promise is a byproduct of enabling top-level await.
@@ -139,15 +189,44 @@ impl<'cx> Module<'cx> {
 		cx: &'cx Context, filename: &str, path: Option<&Path>, script: &str,
 	) -> Result<(Module<'cx>, Option<Promise<'cx>>), ModuleError> {
 		let module = Module::compile(cx, filename, path, script)?;
+		module.prefetch(cx);
+		module.link_and_evaluate(cx)
+	}
 
-		if let Err(error) = module.instantiate(cx) {
+	/// Compiles a [Module] on a separate thread with [Module::compile_off_thread], then evaluates it.
+	pub fn compile_and_evaluate_off_thread(
+		cx: &'cx Context, filename: &str, path: Option<&Path>, script: String,
+	) -> impl Future<Output = Result<(Module<'cx>, Option<Promise<'cx>>), ModuleError>> + 'cx {
+		let module = Module::compile_off_thread(cx, filename, path, script);
+		async move {
+			let module = module.await?;
+			module.prefetch(cx);
+			module.link_and_evaluate(cx)
+		}
+	}
+
+	/// Gives the [ModuleLoader] of the [Context] the chance to prefetch the modules requested by the [Module].
+	///
+	/// This borrows the loader from the [Context], so it must not be called from within a [ModuleLoader], which
+	/// should call [ModuleLoader::prefetch] on itself instead.
+	fn prefetch(&self, cx: &Context) {
+		let loader = unsafe { &mut (*cx.get_inner_data().as_ptr()).module_loader };
+		if let Some(loader) = loader {
+			loader.prefetch(cx, self);
+		}
+	}
+
+	/// Links and evaluates a compiled [Module], returning the same values as [Module::compile_and_evaluate].
+	#[expect(clippy::result_large_err)]
+	pub fn link_and_evaluate(self, cx: &'cx Context) -> Result<(Module<'cx>, Option<Promise<'cx>>), ModuleError> {
+		if let Err(error) = self.instantiate(cx) {
 			return Err(ModuleError::new(error, ModuleErrorKind::Instantiation));
 		}
 
-		match module.evaluate(cx) {
+		match self.evaluate(cx) {
 			Ok(val) => {
 				let promise = Promise::from_value(cx, &val, true, ()).ok();
-				Ok((module, promise))
+				Ok((self, promise))
 			}
 			Err(error) => Err(ModuleError::new(error, ModuleErrorKind::Evaluation)),
 		}
@@ -168,9 +247,86 @@ impl<'cx> Module<'cx> {
 		if unsafe { ModuleEvaluate(cx.as_ptr(), self.0.handle().into(), rval.handle_mut().into()) } {
 			Ok(rval)
 		} else {
-			Err(ErrorReport::new_with_exception_stack(cx)?.unwrap())
+			Err(ErrorReport::new_with_exception_stack(cx)?.unwrap_or_else(ErrorReport::terminated))
+		}
+	}
+
+	/// Returns the namespace object of the [Module], which contains its exports.
+	/// Returns [None] if the module has not been instantiated, or an exception occurs.
+	pub fn namespace(&self, cx: &'cx Context) -> Option<Object<'cx>> {
+		let namespace = unsafe { GetModuleNamespace(cx.as_ptr(), self.0.handle().into()) };
+		(!namespace.is_null()).then(|| Object::from(cx.root(namespace)))
+	}
+
+	/// Returns the specifiers of the modules requested by the [Module] with `import` declarations, in source order.
+	pub fn requested_modules(&self, cx: &Context) -> crate::Result<Vec<String>> {
+		let count = unsafe { GetRequestedModulesCount(cx.as_ptr(), self.0.handle().into()) };
+		(0..count)
+			.map(|index| {
+				let specifier = unsafe { GetRequestedModuleSpecifier(cx.as_ptr(), self.0.handle().into(), index) };
+				crate::String::from(cx.root(specifier)).to_owned(cx)
+			})
+			.collect()
+	}
+
+	/// Returns the private data of the [Module], set when it was compiled.
+	pub fn data(&self, cx: &Context) -> Option<ModuleData> {
+		let private = Value::from(cx.root(unsafe { GetModulePrivate(self.0.handle().get()) }));
+		ModuleData::from_private(cx, &private)
+	}
+}
+
+/// Represents a [Module] being loaded and compiled on a separate thread, started with [Module::load_off_thread].
+///
+/// Dropping a [PendingModule] cancels it, and the compiled stencil is released once the thread finishes.
+#[must_use]
+pub struct PendingModule<T> {
+	receiver: Option<oneshot::Receiver<Option<OffThreadStencil<T>>>>,
+	filename: String,
+	path: Option<PathBuf>,
+}
+
+impl<T> PendingModule<T> {
+	/// Waits for the module to be compiled, and returns it with the data returned when it was loaded.
+	/// Returns [None] if the thread could not be spawned, or loading the module failed.
+	pub async fn receive(self, cx: &Context) -> Option<Result<(Module, T), ModuleError>> {
+		let compiled = receive_stencil(cx, self.receiver).await?;
+		let path = self.path.as_deref();
+
+		// Compilation errors are reported on the main thread, where they can be converted into exceptions.
+		let Some(stencil) = compiled.stencil() else {
+			let module = Module::compile(cx, &self.filename, path, &compiled.source);
+			return Some(module.map(|module| (module, compiled.data)));
+		};
+
+		let instantiate = InstantiateOptions {
+			skipFilenameValidation: false,
+			hideScriptFromDebugger: false,
+			deferDebugMetadata: false,
+		};
+		let module = unsafe { InstantiateModuleStencil(cx.as_ptr(), &instantiate, stencil, ptr::null_mut()) };
+
+		if !module.is_null() {
+			Some(Ok((Module::with_data(cx, module, path), compiled.data)))
+		} else {
+			Some(Err(ModuleError::new(
+				ErrorReport::new(cx).unwrap().unwrap(),
+				ModuleErrorKind::Compilation,
+			)))
 		}
 	}
+
+	/// Blocks the current thread until the module is compiled, and returns it.
+	/// Refer to [PendingModule::receive] for more details.
+	pub fn finish(self, cx: &Context) -> Option<Result<(Module, T), ModuleError>> {
+		block_on(self.receive(cx))
+	}
+
+	/// Cancels loading the module without waiting for it to be compiled.
+	/// The compiled stencil is released on the compiling thread once it finishes.
+	pub fn cancel(self) {
+		drop(self.receiver);
+	}
 }
 
 /// Represents an ES module loader.
@@ -186,6 +342,11 @@ pub trait ModuleLoader {
 
 	/// Returns metadata of a module, used to populate `import.meta`.
 	fn metadata(&self, cx: &Context, private: &Value, meta: &Object) -> crate::Result<()>;
+
+	/// Called with a compiled module before it is linked, so the modules it requests can be loaded ahead of time.
+	/// This is called for modules compiled with [Module::compile_and_evaluate], and loaders should call it for the
+	/// modules they resolve before linking them.
+	fn prefetch(&mut self, _: &Context, _: &Module) {}
 }
 
 impl ModuleLoader for () {
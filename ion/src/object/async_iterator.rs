@@ -0,0 +1,191 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::ptr;
+
+use mozjs::gc::Traceable;
+use mozjs::jsapi::{
+	GetRealmAsyncIteratorPrototype, Heap, JSClass, JSClassOps, JSContext, JSFunctionSpec, JSNativeWrapper, JSObject,
+	JSTracer, JSCLASS_BACKGROUND_FINALIZE,
+};
+use mozjs::jsval::JSVal;
+
+use crate::class::{
+	finalise_native_object_operation, trace_native_object_operation, NativeClass, NativeObject, PrototypeChain,
+	Reflector, TypeIdWrapper,
+};
+use crate::conversions::{IntoValue, ToValue};
+use crate::flags::PropertyFlags;
+use crate::function::NativeFunction;
+use crate::object::class_reserved_slots;
+use crate::spec::{create_function_spec, create_function_spec_symbol};
+use crate::symbol::WellKnownSymbolCode;
+use crate::{Arguments, ClassDefinition, Context, Error, ErrorKind, Local, Object, Promise, ThrowException, Value};
+
+/// Represents an iterator whose values are produced asynchronously, for use with `for await`.
+pub trait JSAsyncIterator {
+	/// Returns a [Promise] which resolves to the next value, or [None] if the iterator is exhausted.
+	fn next_promise<'cx>(&mut self, cx: &'cx Context, private: &Value<'cx>) -> Option<Promise<'cx>>;
+}
+
+pub struct AsyncIterator {
+	reflector: Reflector,
+	iter: Box<dyn JSAsyncIterator>,
+	private: Box<Heap<JSVal>>,
+}
+
+impl AsyncIterator {
+	pub fn new<I: JSAsyncIterator + 'static>(iter: I, private: &Value) -> AsyncIterator {
+		AsyncIterator {
+			reflector: Reflector::default(),
+			iter: Box::new(iter),
+			private: Heap::boxed(private.get()),
+		}
+	}
+
+	/// Returns a [Promise] which resolves to the next iterator result.
+	pub fn next_promise<'cx>(&mut self, cx: &'cx Context) -> Option<Promise<'cx>> {
+		let private = Value::from(unsafe { Local::from_heap(&self.private) });
+		match self.iter.next_promise(cx, &private) {
+			Some(promise) => promise.map(cx, |cx, value| Ok(iterator_result(cx, value, false))),
+			None => Some(Promise::resolved(cx, &iterator_result(cx, &Value::undefined(cx), true))),
+		}
+	}
+}
+
+fn iterator_result<'cx>(cx: &'cx Context, value: &Value, done: bool) -> Value<'cx> {
+	let object = Object::new(cx);
+	object.set_as(cx, "value", value);
+	object.set_as(cx, "done", &done);
+	object.as_value(cx)
+}
+
+impl AsyncIterator {
+	unsafe extern "C" fn next_raw(cx: *mut JSContext, argc: u32, vp: *mut JSVal) -> bool {
+		let cx = &unsafe { Context::new_unchecked(cx) };
+		let args = &mut unsafe { Arguments::new(cx, argc, vp) };
+
+		let this = args.this().to_object(cx);
+		let iterator = match AsyncIterator::get_mut_private(cx, &this) {
+			Ok(iterator) => iterator,
+			Err(e) => {
+				e.throw(cx);
+				return false;
+			}
+		};
+
+		match iterator.next_promise(cx) {
+			Some(promise) => {
+				promise.to_value(cx, &mut args.rval());
+				true
+			}
+			None => {
+				Error::new("Failed to create Promise for Iterator Result", ErrorKind::Normal).throw(cx);
+				false
+			}
+		}
+	}
+
+	unsafe extern "C" fn iterable(cx: *mut JSContext, argc: u32, vp: *mut JSVal) -> bool {
+		let cx = &unsafe { Context::new_unchecked(cx) };
+		let args = &mut unsafe { Arguments::new(cx, argc, vp) };
+
+		let this = args.this().handle().get();
+		args.rval().handle_mut().set(this);
+
+		true
+	}
+}
+
+impl IntoValue<'_> for AsyncIterator {
+	fn into_value(self: Box<Self>, cx: &Context, value: &mut Value) {
+		let object = cx.root(AsyncIterator::new_object(cx, self));
+		object.handle().get().to_value(cx, value);
+	}
+}
+
+unsafe impl Traceable for AsyncIterator {
+	unsafe fn trace(&self, trc: *mut JSTracer) {
+		unsafe {
+			self.private.trace(trc);
+		}
+	}
+}
+
+static ASYNC_ITERATOR_CLASS_OPS: JSClassOps = JSClassOps {
+	addProperty: None,
+	delProperty: None,
+	enumerate: None,
+	newEnumerate: None,
+	resolve: None,
+	mayResolve: None,
+	finalize: Some(finalise_native_object_operation::<AsyncIterator>),
+	call: None,
+	construct: None,
+	trace: Some(trace_native_object_operation::<AsyncIterator>),
+};
+
+static ASYNC_ITERATOR_CLASS: NativeClass = NativeClass {
+	base: JSClass {
+		name: "NativeAsyncIterator\0".as_ptr().cast(),
+		flags: JSCLASS_BACKGROUND_FINALIZE | class_reserved_slots(1),
+		cOps: &ASYNC_ITERATOR_CLASS_OPS,
+		spec: ptr::null_mut(),
+		ext: ptr::null_mut(),
+		oOps: ptr::null_mut(),
+	},
+	prototype_chain: PrototypeChain::new().push(&TypeIdWrapper::<AsyncIterator>::new()),
+};
+
+static ASYNC_ITERATOR_METHODS: &[JSFunctionSpec] = &[
+	create_function_spec(
+		"next\0",
+		JSNativeWrapper {
+			op: Some(AsyncIterator::next_raw),
+			info: ptr::null_mut(),
+		},
+		0,
+		PropertyFlags::CONSTANT_ENUMERATED,
+	),
+	create_function_spec_symbol(
+		WellKnownSymbolCode::AsyncIterator,
+		JSNativeWrapper {
+			op: Some(AsyncIterator::iterable),
+			info: ptr::null_mut(),
+		},
+		0,
+		PropertyFlags::CONSTANT,
+	),
+	JSFunctionSpec::ZERO,
+];
+
+impl NativeObject for AsyncIterator {
+	fn reflector(&self) -> &Reflector {
+		&self.reflector
+	}
+}
+
+impl ClassDefinition for AsyncIterator {
+	fn class() -> &'static NativeClass {
+		&ASYNC_ITERATOR_CLASS
+	}
+
+	fn proto_class() -> Option<&'static NativeClass> {
+		None
+	}
+
+	fn parent_prototype(cx: &Context) -> Option<Local<*mut JSObject>> {
+		Some(cx.root(unsafe { GetRealmAsyncIteratorPrototype(cx.as_ptr()) }))
+	}
+
+	fn constructor() -> (Option<NativeFunction>, u32) {
+		(None, 0)
+	}
+
+	fn functions() -> Option<&'static [JSFunctionSpec]> {
+		Some(ASYNC_ITERATOR_METHODS)
+	}
+}
@@ -10,7 +10,25 @@ use chrono::offset::Utc;
 use chrono::{DateTime, TimeZone};
 use mozjs::jsapi::{ClippedTime, DateGetMsecSinceEpoch, DateIsValid, JSObject, NewDateObject, ObjectIsDate};
 
-use crate::{Context, Local};
+use crate::conversions::FromValue;
+use crate::{Context, Function, Local, Object, Value};
+
+macro_rules! date_component {
+	($(#[$attr:meta])* $getter:ident, $setter:ident, $get:literal, $set:literal) => {
+		$(#[$attr])*
+		///
+		/// Returns [None] if the [Date] is invalid.
+		pub fn $getter(&self, cx: &Context) -> Option<i32> {
+			self.call_method(cx, $get, &[]).map(|component| component as i32)
+		}
+
+		#[doc = concat!("Sets the component of the [Date] in local time, as with `Date.prototype.", $set, "`.")]
+		/// Returns `false` if the resulting [Date] is invalid.
+		pub fn $setter(&self, cx: &Context, value: i32) -> bool {
+			self.call_method(cx, $set, &[Value::i32(cx, value)]).is_some()
+		}
+	};
+}
 
 /// Represents a `Date` in the JavaScript Runtime.
 /// Refer to [MDN](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date) for more details.
@@ -26,8 +44,13 @@ impl<'d> Date<'d> {
 	}
 
 	/// Creates a new [Date] with the given time.
-	pub fn from_date(cx: &'d Context, time: DateTime<Utc>) -> Date<'d> {
-		let date = unsafe { NewDateObject(cx.as_ptr(), ClippedTime { t: time.timestamp_millis() as f64 }) };
+	pub fn from_date<Tz: TimeZone>(cx: &'d Context, time: DateTime<Tz>) -> Date<'d> {
+		Date::from_timestamp(cx, time.timestamp_millis() as f64)
+	}
+
+	/// Creates a new [Date] with the given number of milliseconds since the UNIX epoch.
+	pub fn from_timestamp(cx: &'d Context, milliseconds: f64) -> Date<'d> {
+		let date = unsafe { NewDateObject(cx.as_ptr(), ClippedTime { t: milliseconds }) };
 		Date { date: cx.root(date) }
 	}
 
@@ -56,15 +79,75 @@ impl<'d> Date<'d> {
 	}
 
 	/// Converts the [Date] to a [DateTime].
+	/// Returns [None] if the [Date] is invalid.
 	pub fn to_date(&self, cx: &Context) -> Option<DateTime<Utc>> {
-		let mut milliseconds: f64 = f64::MAX;
-		if !unsafe { DateGetMsecSinceEpoch(cx.as_ptr(), self.date.handle().into(), &mut milliseconds) }
-			|| milliseconds == f64::MAX
-		{
-			None
-		} else {
-			Utc.timestamp_millis_opt(milliseconds as i64).single()
-		}
+		self.timestamp(cx)
+			.and_then(|milliseconds| Utc.timestamp_millis_opt(milliseconds as i64).single())
+	}
+
+	/// Returns the number of milliseconds since the UNIX epoch.
+	/// Returns [None] if the [Date] is invalid.
+	pub fn timestamp(&self, cx: &Context) -> Option<f64> {
+		let mut milliseconds = f64::NAN;
+		unsafe { DateGetMsecSinceEpoch(cx.as_ptr(), self.date.handle().into(), &mut milliseconds) };
+		(!milliseconds.is_nan()).then_some(milliseconds)
+	}
+
+	/// Sets the number of milliseconds since the UNIX epoch.
+	/// Returns `false` if the resulting [Date] is invalid.
+	pub fn set_timestamp(&self, cx: &Context, milliseconds: f64) -> bool {
+		self.call_method(cx, "setTime", &[Value::f64(cx, milliseconds)]).is_some()
+	}
+
+	date_component! {
+		/// Returns the year of the [Date] in local time.
+		year, set_year, "getFullYear", "setFullYear"
+	}
+
+	date_component! {
+		/// Returns the month of the [Date] in local time, starting from `0` for January.
+		month, set_month, "getMonth", "setMonth"
+	}
+
+	date_component! {
+		/// Returns the day of the month of the [Date] in local time, starting from `1`.
+		day, set_day, "getDate", "setDate"
+	}
+
+	date_component! {
+		/// Returns the hours of the [Date] in local time.
+		hours, set_hours, "getHours", "setHours"
+	}
+
+	date_component! {
+		/// Returns the minutes of the [Date] in local time.
+		minutes, set_minutes, "getMinutes", "setMinutes"
+	}
+
+	date_component! {
+		/// Returns the seconds of the [Date] in local time.
+		seconds, set_seconds, "getSeconds", "setSeconds"
+	}
+
+	date_component! {
+		/// Returns the milliseconds of the [Date] in local time.
+		milliseconds, set_milliseconds, "getMilliseconds", "setMilliseconds"
+	}
+
+	/// Returns the difference in minutes between UTC and the local time of the [Date].
+	/// Returns [None] if the [Date] is invalid.
+	pub fn timezone_offset(&self, cx: &Context) -> Option<i32> {
+		self.call_method(cx, "getTimezoneOffset", &[]).map(|offset| offset as i32)
+	}
+
+	/// Calls a method of `Date.prototype` on the [Date], returning its numeric result.
+	/// Returns [None] if the method fails or returns `NaN`.
+	fn call_method(&self, cx: &Context, name: &str, args: &[Value]) -> Option<f64> {
+		let object = Object::from(cx.root(self.date.get()));
+		let method: Function = object.get_as(cx, name, true, ()).ok()??;
+		let result = method.call(cx, &object, args).ok()?;
+		let number = f64::from_value(cx, &result, true, ()).ok()?;
+		(!number.is_nan()).then_some(number)
 	}
 
 	/// Checks if a [raw object](*mut JSObject) is a date.
@@ -129,4 +212,35 @@ mod tests {
 			pre_epoch.to_date(cx)
 		);
 	}
+
+	#[test]
+	fn components() {
+		let rt = TestRuntime::new();
+		let cx = &rt.cx;
+
+		let date = Date::from_timestamp(cx, POST_EPOCH as f64);
+		assert_eq!(Some(POST_EPOCH as f64), date.timestamp(cx));
+
+		assert!(date.set_year(cx, 2024));
+		assert!(date.set_month(cx, 1));
+		assert!(date.set_day(cx, 29));
+		assert!(date.set_hours(cx, 12));
+		assert!(date.set_minutes(cx, 30));
+		assert!(date.set_seconds(cx, 15));
+		assert!(date.set_milliseconds(cx, 500));
+
+		assert_eq!(Some(2024), date.year(cx));
+		assert_eq!(Some(1), date.month(cx));
+		assert_eq!(Some(29), date.day(cx));
+		assert_eq!(Some(12), date.hours(cx));
+		assert_eq!(Some(30), date.minutes(cx));
+		assert_eq!(Some(15), date.seconds(cx));
+		assert_eq!(Some(500), date.milliseconds(cx));
+		assert!(date.timezone_offset(cx).is_some());
+
+		assert!(!date.set_timestamp(cx, f64::NAN));
+		assert!(!date.is_valid(cx));
+		assert_eq!(None, date.timestamp(cx));
+		assert_eq!(None, date.year(cx));
+	}
 }
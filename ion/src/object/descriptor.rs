@@ -30,11 +30,13 @@ impl<'pd> PropertyDescriptor<'pd> {
 		desc
 	}
 
+	/// Creates a [PropertyDescriptor] for an accessor property.
+	/// A missing getter or setter is left undefined.
 	pub fn new_accessor(
-		cx: &'pd Context, getter: &Function, setter: &Function, attrs: PropertyFlags,
+		cx: &'pd Context, getter: Option<&Function>, setter: Option<&Function>, attrs: PropertyFlags,
 	) -> PropertyDescriptor<'pd> {
-		let getter = getter.to_object(cx);
-		let setter = setter.to_object(cx);
+		let getter = getter.map(|getter| getter.to_object(cx)).unwrap_or_else(|| Object::null(cx));
+		let setter = setter.map(|setter| setter.to_object(cx)).unwrap_or_else(|| Object::null(cx));
 		let mut desc = PropertyDescriptor::empty(cx);
 		unsafe {
 			SetAccessorPropertyDescriptor(
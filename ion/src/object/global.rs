@@ -0,0 +1,138 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+use std::ptr;
+
+use mozjs::jsapi::{
+	CurrentGlobalOrNull, HandleId, HandleObject, JSClass, JSClassOps, JSContext, JS_AlreadyHasOwnPropertyById,
+	JS_GlobalObjectTraceHook, JS_NewEnumerateStandardClasses, JS_ResolveStandardClass, MutableHandleIdVector,
+	JSCLASS_GLOBAL_SLOT_COUNT, JSCLASS_IS_GLOBAL,
+};
+
+use crate::object::{class_reserved_slots, OwnedKey, PropertyKey};
+use crate::{Context, Object};
+
+/// Initialiser of lazily defined global properties.
+/// It is called with the global object when one of its properties is first accessed, and must define all of them.
+pub type LazyGlobal = fn(&Context, &Object) -> bool;
+
+/// Stores the lazily defined global properties of a [Context], which are not yet initialised.
+#[derive(Default)]
+pub struct LazyGlobals {
+	names: HashMap<String, usize>,
+	initialisers: Vec<Option<LazyGlobal>>,
+}
+
+impl LazyGlobals {
+	fn take(&mut self, name: &str) -> Option<LazyGlobal> {
+		let index = self.names.remove(name)?;
+		self.initialisers[index].take()
+	}
+
+	fn take_all(&mut self) -> Vec<LazyGlobal> {
+		self.names.clear();
+		self.initialisers.drain(..).flatten().collect()
+	}
+}
+
+/// Defines global properties with the given names, which are initialised by the [LazyGlobal] when one of them is
+/// first accessed, rather than when the global object is created.
+///
+/// The global object must be created with [GLOBAL_CLASS], which [default_new_global](super::default_new_global) uses.
+pub fn define_lazy_global(cx: &Context, names: &[&str], initialiser: LazyGlobal) {
+	let globals = unsafe { &mut (*cx.get_inner_data().as_ptr()).lazy_globals };
+	let index = globals.initialisers.len();
+	globals.initialisers.push(Some(initialiser));
+	for name in names {
+		globals.names.insert(String::from(*name), index);
+	}
+}
+
+/// Initialises all lazily defined global properties which have not been accessed yet.
+/// Returns `false` if any initialiser fails.
+pub fn init_lazy_globals(cx: &Context, global: &Object) -> bool {
+	let initialisers = unsafe { (*cx.get_inner_data().as_ptr()).lazy_globals.take_all() };
+	initialisers
+		.into_iter()
+		.fold(true, |result, initialiser| initialiser(cx, global) && result)
+}
+
+/// Initialises all lazily defined global properties of the current global object.
+pub(crate) fn init_current_lazy_globals(cx: &Context) -> bool {
+	let global = unsafe { CurrentGlobalOrNull(cx.as_ptr()) };
+	!global.is_null() && init_lazy_globals(cx, &Object::from(cx.root(global)))
+}
+
+unsafe extern "C" fn resolve_global(
+	cx: *mut JSContext, object: HandleObject, id: HandleId, resolved: *mut bool,
+) -> bool {
+	if !unsafe { JS_ResolveStandardClass(cx, object, id, resolved) } {
+		return false;
+	}
+	if unsafe { *resolved } {
+		return true;
+	}
+
+	let cx = &unsafe { Context::new_unchecked(cx) };
+	if unsafe { (*cx.get_inner_data().as_ptr()).lazy_globals.names.is_empty() } {
+		return true;
+	}
+
+	let key = PropertyKey::from(cx.root(unsafe { *id.ptr }));
+	let Ok(OwnedKey::String(name)) = key.to_owned_key(cx) else {
+		return true;
+	};
+
+	let initialiser = unsafe { (*cx.get_inner_data().as_ptr()).lazy_globals.take(&name) };
+	if let Some(initialiser) = initialiser {
+		let global = Object::from(cx.root(unsafe { *object.ptr }));
+		if !initialiser(cx, &global) {
+			return false;
+		}
+		unsafe {
+			return JS_AlreadyHasOwnPropertyById(cx.as_ptr(), object, id, resolved);
+		}
+	}
+	true
+}
+
+unsafe extern "C" fn enumerate_global(
+	cx: *mut JSContext, object: HandleObject, properties: MutableHandleIdVector, enumerable_only: bool,
+) -> bool {
+	{
+		let cx = &unsafe { Context::new_unchecked(cx) };
+		let global = Object::from(cx.root(unsafe { *object.ptr }));
+		if !init_lazy_globals(cx, &global) {
+			return false;
+		}
+	}
+	unsafe { JS_NewEnumerateStandardClasses(cx, object, properties, enumerable_only) }
+}
+
+static GLOBAL_CLASS_OPS: JSClassOps = JSClassOps {
+	addProperty: None,
+	delProperty: None,
+	enumerate: None,
+	newEnumerate: Some(enumerate_global),
+	resolve: Some(resolve_global),
+	mayResolve: None,
+	finalize: None,
+	call: None,
+	construct: None,
+	trace: Some(JS_GlobalObjectTraceHook),
+};
+
+/// Class of global objects, which resolves standard classes and properties defined with [define_lazy_global] when
+/// they are first accessed.
+pub static GLOBAL_CLASS: JSClass = JSClass {
+	name: "Global\0".as_ptr().cast(),
+	flags: JSCLASS_IS_GLOBAL | class_reserved_slots(JSCLASS_GLOBAL_SLOT_COUNT),
+	cOps: &GLOBAL_CLASS_OPS,
+	spec: ptr::null_mut(),
+	ext: ptr::null_mut(),
+	oOps: ptr::null_mut(),
+};
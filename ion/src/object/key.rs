@@ -8,7 +8,9 @@ use std::hash::{Hash, Hasher};
 use std::mem::discriminant;
 use std::ops::{Deref, DerefMut};
 
-use mozjs::jsapi::{JSProtoKey, JS_IdToProtoKey, JS_ValueToId, PropertyKey as JSPropertyKey, ProtoKeyToId};
+use mozjs::jsapi::{
+	JSProtoKey, JS_IdToProtoKey, JS_IndexToId, JS_ValueToId, PropertyKey as JSPropertyKey, ProtoKeyToId,
+};
 use mozjs::jsid::{IntId, VoidId};
 
 use crate::conversions::ToPropertyKey;
@@ -20,8 +22,23 @@ pub struct PropertyKey<'k> {
 
 impl<'k> PropertyKey<'k> {
 	/// Creates a [PropertyKey] from an integer.
+	/// Negative integers are not array indices, so they are converted to string keys.
 	pub fn with_int(cx: &'k Context, int: i32) -> PropertyKey<'k> {
-		PropertyKey::from(cx.root(IntId(int)))
+		match u32::try_from(int) {
+			Ok(index) => PropertyKey::with_index(cx, index),
+			Err(_) => PropertyKey::with_string(cx, &int.to_string()).unwrap(),
+		}
+	}
+
+	/// Creates a [PropertyKey] from an array index, without converting it to a string where possible.
+	pub fn with_index(cx: &'k Context, index: u32) -> PropertyKey<'k> {
+		if let Ok(int) = i32::try_from(index) {
+			PropertyKey::from(cx.root(IntId(int)))
+		} else {
+			let mut key = PropertyKey::from(cx.root(VoidId()));
+			assert!(unsafe { JS_IndexToId(cx.as_ptr(), index, key.handle_mut().into()) });
+			key
+		}
 	}
 
 	/// Creates a [PropertyKey] from a string.
@@ -11,8 +11,8 @@ use mozjs::jsapi::{
 	MapValues, NewMapObject,
 };
 
-use crate::conversions::ToValue;
-use crate::{Context, Function, Local, Object, Value};
+use crate::conversions::{FromValue, ToValue};
+use crate::{Array, Context, Function, Local, Object, Result, Value};
 
 pub struct Map<'m> {
 	map: Local<'m, *mut JSObject>,
@@ -124,6 +124,19 @@ impl<'m> Map<'m> {
 		entries.to_object(cx)
 	}
 
+	/// Returns the entries of the [Map] as key-value pairs, in insertion order.
+	pub fn to_vec<'cx>(&self, cx: &'cx Context) -> Result<Vec<(Value<'cx>, Value<'cx>)>> {
+		let entries = self.entries(cx).as_value(cx);
+		Vec::<Array>::from_value(cx, &entries, false, ())?
+			.into_iter()
+			.map(|entry| {
+				let key = entry.get(cx, 0)?.unwrap_or_else(|| Value::undefined(cx));
+				let value = entry.get(cx, 1)?.unwrap_or_else(|| Value::undefined(cx));
+				Ok((key, value))
+			})
+			.collect()
+	}
+
 	/// Runs the given callback for each entry in the [Map].
 	pub fn for_each(&self, cx: &Context, callback: &Function, this: &Object) -> bool {
 		unsafe {
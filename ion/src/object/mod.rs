@@ -7,34 +7,44 @@
 use std::ptr;
 
 pub use array::Array;
+pub use async_iterator::{AsyncIterator, JSAsyncIterator};
 pub use date::Date;
 pub use descriptor::PropertyDescriptor;
+pub(crate) use global::init_current_lazy_globals;
+pub use global::{define_lazy_global, init_lazy_globals, LazyGlobal, LazyGlobals, GLOBAL_CLASS};
 pub use iterator::{Iterator, JSIterator};
 pub use key::{OwnedKey, PropertyKey};
 pub use map::Map;
 use mozjs::jsapi::{
-	JSClass, JSPrincipals, JS_NewGlobalObject, OnNewGlobalHookOption, JSCLASS_RESERVED_SLOTS_MASK,
-	JSCLASS_RESERVED_SLOTS_SHIFT,
+	Construct1, HandleValueArray, JSClass, JSPrincipals, JSProtoKey, JS_GetClassObject, JS_NewGlobalObject,
+	OnNewGlobalHookOption, JSCLASS_RESERVED_SLOTS_MASK, JSCLASS_RESERVED_SLOTS_SHIFT,
 };
-use mozjs::rust::{RealmOptions, SIMPLE_GLOBAL_CLASS};
-pub use object::Object;
+use mozjs::rust::RealmOptions;
+pub use object::{Object, ObjectIter, ObjectKeysIter, ObjectOwnedKeysIter, ObjectValuesIter};
 pub use promise::Promise;
-pub use regexp::RegExp;
+pub use proxy::{Proxy, ProxyBuilder};
+pub use regexp::{RegExp, RegExpMatch};
 pub use set::Set;
+pub use weak::{FinalizationRegistry, Finalizer, WeakHandle, WeakMap, WeakRef, WeakSet};
 
-use crate::Context;
+use crate::conversions::ToValue;
+use crate::{Context, Value};
 
 mod array;
+mod async_iterator;
 mod date;
 mod descriptor;
+mod global;
 mod iterator;
 mod key;
 mod map;
 mod object;
 mod promise;
+mod proxy;
 mod regexp;
 mod set;
 pub mod typedarray;
+mod weak;
 
 /// Returns the bit-masked representation of reserved slots for a class.
 pub const fn class_reserved_slots(slots: u32) -> u32 {
@@ -62,6 +72,27 @@ pub fn new_global<'cx>(
 	Object::from(cx.root(global))
 }
 
+/// Constructs an instance of a built-in class with the given arguments.
+pub(crate) fn construct_builtin<'cx>(cx: &'cx Context, key: JSProtoKey, args: &[Value]) -> Option<Object<'cx>> {
+	let mut constructor = Object::null(cx);
+	if !unsafe { JS_GetClassObject(cx.as_ptr(), key, constructor.handle_mut().into()) } {
+		return None;
+	}
+
+	let constructor = constructor.as_value(cx);
+	let args: Vec<_> = args.iter().map(|a| a.get()).collect();
+	let mut object = Object::null(cx);
+	unsafe {
+		Construct1(
+			cx.as_ptr(),
+			constructor.handle().into(),
+			&HandleValueArray::from_rooted_slice(args.as_slice()),
+			object.handle_mut().into(),
+		)
+	}
+	.then_some(object)
+}
+
 pub fn default_new_global(cx: &Context) -> Object {
 	let mut options = RealmOptions::default();
 	options.creationOptions_.sharedMemoryAndAtomics_ = true;
@@ -69,7 +100,7 @@ pub fn default_new_global(cx: &Context) -> Object {
 
 	new_global(
 		cx,
-		&SIMPLE_GLOBAL_CLASS,
+		&GLOBAL_CLASS,
 		None,
 		OnNewGlobalHookOption::FireOnNewGlobalHook,
 		Some(options),
@@ -13,9 +13,9 @@ use std::slice;
 use mozjs::jsapi::{
 	CurrentGlobalOrNull, ESClass, GetBuiltinClass, GetPropertyKeys, JSFunctionSpec, JSFunctionSpecWithHelp, JSObject,
 	JSPropertySpec, JS_DefineFunctionById, JS_DefineFunctions, JS_DefineFunctionsWithHelp, JS_DefineProperties,
-	JS_DefinePropertyById2, JS_DeletePropertyById, JS_GetPropertyById, JS_GetPropertyDescriptorById,
-	JS_HasOwnPropertyById, JS_HasPropertyById, JS_NewPlainObject, JS_SetPropertyById, PropertyKey as JSPropertyKey,
-	Unbox,
+	JS_DefinePropertyById1, JS_DefinePropertyById2, JS_DeletePropertyById, JS_FreezeObject, JS_GetPropertyById,
+	JS_GetPropertyDescriptorById, JS_HasOwnPropertyById, JS_HasPropertyById, JS_NewPlainObject, JS_SetPropertyById,
+	PropertyKey as JSPropertyKey, Unbox,
 };
 use mozjs::jsval::NullValue;
 use mozjs::rust::IdVector;
@@ -195,6 +195,34 @@ impl<'o> Object<'o> {
 		self.define(cx, key, &value.as_value(cx), attrs)
 	}
 
+	/// Defines a property at the given key of the [Object] with the given [PropertyDescriptor].
+	///
+	/// Returns `false` if the property cannot be defined.
+	pub fn define_property<'cx, K: ToPropertyKey<'cx>>(
+		&self, cx: &'cx Context, key: K, descriptor: &PropertyDescriptor,
+	) -> bool {
+		let key = key.to_key(cx).unwrap();
+		unsafe {
+			JS_DefinePropertyById1(
+				cx.as_ptr(),
+				self.handle().into(),
+				key.handle().into(),
+				descriptor.handle().into(),
+			)
+		}
+	}
+
+	/// Defines an accessor property at the given key of the [Object] with the given getter, setter and attributes.
+	/// A missing setter makes the property read-only, and [PropertyFlags::READ_ONLY] is ignored.
+	///
+	/// Returns `false` if the property cannot be defined.
+	pub fn define_accessor<'cx, K: ToPropertyKey<'cx>>(
+		&self, cx: &'cx Context, key: K, getter: Option<&Function>, setter: Option<&Function>, attrs: PropertyFlags,
+	) -> bool {
+		let descriptor = PropertyDescriptor::new_accessor(cx, getter, setter, attrs);
+		self.define_property(cx, key, &descriptor)
+	}
+
 	/// Defines a method with the given name, and the given number of arguments and attributes on the [Object].
 	///
 	/// Parameters are similar to [create_function_spec](crate::spec::create_function_spec).
@@ -226,6 +254,20 @@ impl<'o> Object<'o> {
 		unsafe { JS_DefineFunctions(cx.as_ptr(), self.handle().into(), methods.as_ptr()) }
 	}
 
+	/// Defines unstable methods on the objects using the given [specs](JSFunctionSpec).
+	///
+	/// The methods are only defined if unstable bindings are enabled, so they can be feature-detected.
+	/// Functions marked with `#[ion(unstable)]` should be defined with this instead of [Object::define_methods].
+	///
+	/// The final element of the `methods` slice must be `JSFunctionSpec::ZERO`.
+	pub unsafe fn define_unstable_methods(&self, cx: &Context, methods: &[JSFunctionSpec]) -> bool {
+		if unsafe { (*cx.get_inner_data().as_ptr()).unstable } {
+			unsafe { self.define_methods(cx, methods) }
+		} else {
+			true
+		}
+	}
+
 	/// Defines methods on the objects using the given [specs](JSFunctionSpecWithHelp), with help.
 	///
 	/// The final element of the `methods` slice must be `JSFunctionSpecWithHelp::ZERO`.
@@ -256,6 +298,13 @@ impl<'o> Object<'o> {
 		}
 	}
 
+	/// Freezes the [Object], as with `Object.freeze`.
+	///
+	/// Returns `false` if the object cannot be frozen.
+	pub fn freeze(&self, cx: &Context) -> bool {
+		unsafe { JS_FreezeObject(cx.as_ptr(), self.handle().into()) }
+	}
+
 	/// Gets the builtin class of the object as described in the ECMAScript specification.
 	///
 	/// Returns [ESClass::Other] for other projects or proxies that cannot be unwrapped.
@@ -291,6 +340,9 @@ impl<'o> Object<'o> {
 
 	/// Returns an iterator of the keys of the [Object].
 	/// Each key can be a [String], [Symbol](crate::symbol) or integer.
+	///
+	/// By default, only own enumerable string and integer keys are included.
+	/// Symbol and non-enumerable keys can be included with [IteratorFlags::SYMBOLS] and [IteratorFlags::HIDDEN].
 	pub fn keys<'cx>(&self, cx: &'cx Context, flags: Option<IteratorFlags>) -> ObjectKeysIter<'cx> {
 		let flags = flags.unwrap_or(IteratorFlags::OWN_ONLY);
 		let mut ids = unsafe { IdVector::new(cx.as_ptr()) };
@@ -298,6 +350,7 @@ impl<'o> Object<'o> {
 		ObjectKeysIter::new(cx, ids)
 	}
 
+	/// Returns an iterator of the entries of the [Object], as pairs of keys and values.
 	pub fn iter<'cx, 's>(&'s self, cx: &'cx Context, flags: Option<IteratorFlags>) -> ObjectIter<'cx, 's>
 	where
 		'o: 'cx,
@@ -305,6 +358,23 @@ impl<'o> Object<'o> {
 		ObjectIter::new(self, self.keys(cx, flags))
 	}
 
+	/// Returns an iterator of the values of the [Object].
+	pub fn values<'cx, 's>(&'s self, cx: &'cx Context, flags: Option<IteratorFlags>) -> ObjectValuesIter<'cx, 's>
+	where
+		'o: 'cx,
+	{
+		ObjectValuesIter { iter: self.iter(cx, flags) }
+	}
+
+	/// Returns an iterator of the entries of the [Object].
+	/// Equivalent to [Object::iter].
+	pub fn entries<'cx, 's>(&'s self, cx: &'cx Context, flags: Option<IteratorFlags>) -> ObjectIter<'cx, 's>
+	where
+		'o: 'cx,
+	{
+		self.iter(cx, flags)
+	}
+
 	pub fn to_hashmap<'cx>(
 		&self, cx: &'cx Context, flags: Option<IteratorFlags>,
 	) -> Result<HashMap<OwnedKey<'cx>, Value<'cx>>>
@@ -477,13 +547,46 @@ impl ExactSizeIterator for ObjectIter<'_, '_> {
 
 impl FusedIterator for ObjectIter<'_, '_> {}
 
+pub struct ObjectValuesIter<'cx, 'o> {
+	iter: ObjectIter<'cx, 'o>,
+}
+
+impl<'cx> Iterator for ObjectValuesIter<'cx, '_> {
+	type Item = Result<Value<'cx>>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.iter.next().map(|(_, value)| value)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.iter.size_hint()
+	}
+}
+
+impl DoubleEndedIterator for ObjectValuesIter<'_, '_> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.iter.next_back().map(|(_, value)| value)
+	}
+}
+
+impl ExactSizeIterator for ObjectValuesIter<'_, '_> {
+	fn len(&self) -> usize {
+		self.iter.len()
+	}
+}
+
+impl FusedIterator for ObjectValuesIter<'_, '_> {}
+
 #[cfg(test)]
 mod tests {
+	use mozjs::jsapi::{CallArgs, JSContext};
+	use mozjs::jsval::{Int32Value, JSVal};
+
 	use crate::conversions::FromValue;
 	use crate::flags::{IteratorFlags, PropertyFlags};
 	use crate::symbol::WellKnownSymbolCode;
 	use crate::utils::test::TestRuntime;
-	use crate::{Context, Object, OwnedKey, Symbol, Value};
+	use crate::{Context, Function, Object, OwnedKey, PropertyDescriptor, Symbol, Value};
 
 	type Property = (&'static str, i32);
 
@@ -559,6 +662,58 @@ mod tests {
 		assert!(object.get(cx, DEFINE.0).unwrap().is_some());
 	}
 
+	#[test]
+	fn accessor() {
+		unsafe extern "C" fn get_answer(_: *mut JSContext, argc: u32, vp: *mut JSVal) -> bool {
+			let args = unsafe { CallArgs::from_vp(vp, argc) };
+			args.rval().set(Int32Value(42));
+			true
+		}
+
+		let rt = TestRuntime::new();
+		let cx = &rt.cx;
+
+		let object = Object::new(cx);
+		let getter = Function::new(cx, "answer", Some(get_answer), 0, PropertyFlags::empty());
+		let key = Symbol::new(cx, "answer");
+		assert!(object.define_accessor(cx, &key, Some(&getter), None, PropertyFlags::ENUMERATE));
+
+		let value = object.get(cx, &key).unwrap().unwrap();
+		assert_eq!(42, value.handle().to_int32());
+
+		let descriptor = object.get_descriptor(cx, &key).unwrap().unwrap();
+		assert!(descriptor.is_enumerable());
+		assert!(descriptor.is_configurable());
+		assert!(descriptor.getter(cx).is_some());
+		assert!(descriptor.setter(cx).is_none());
+		assert!(descriptor.value(cx).is_none());
+
+		let descriptor = PropertyDescriptor::new(cx, &Value::i32(cx, 7), PropertyFlags::READ_ONLY);
+		assert!(object.define_property(cx, "data", &descriptor));
+		assert!(!object.get_descriptor(cx, "data").unwrap().unwrap().is_writable());
+	}
+
+	#[test]
+	fn integer_keys() {
+		let rt = TestRuntime::new();
+		let cx = &rt.cx;
+
+		let object = Object::new(cx);
+		assert!(object.set(cx, -1, &Value::i32(cx, 1)));
+		assert!(object.set(cx, u32::MAX - 1, &Value::i32(cx, 2)));
+		assert!(object.set(cx, 1u64 << 40, &Value::i32(cx, 3)));
+
+		assert!(object.has_own(cx, "-1"));
+		assert!(object.has_own(cx, "4294967294"));
+		assert!(object.has_own(cx, "1099511627776"));
+		assert!(object.has_own(cx, 1099511627776usize));
+		assert!(!object.has_own(cx, 0));
+
+		assert!(object.set(cx, 5u8, &Value::i32(cx, 4)));
+		let key = object.keys(cx, None).next().unwrap();
+		assert_eq!(key.to_owned_key(cx).unwrap(), OwnedKey::Int(5));
+	}
+
 	#[test]
 	fn iterator() {
 		let rt = TestRuntime::new();
@@ -579,6 +734,10 @@ mod tests {
 				assert_eq!(OwnedKey::String(String::from(properties[i].0)), key.unwrap());
 			}
 
+			for (i, value) in object.values(cx, flags).enumerate() {
+				assert_eq!(properties[i].1, value.unwrap().handle().to_int32());
+			}
+
 			for (i, (key, value)) in object.iter(cx, flags).enumerate() {
 				assert_eq!(
 					OwnedKey::String(String::from(properties[i].0)),
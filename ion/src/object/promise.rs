@@ -11,13 +11,14 @@ use futures::executor::block_on;
 use mozjs::gc::HandleObject;
 use mozjs::glue::JS_GetPromiseResult;
 use mozjs::jsapi::{
-	AddPromiseReactions, CallOriginalPromiseReject, CallOriginalPromiseResolve, GetPromiseID, GetPromiseState,
-	IsPromiseObject, JSObject, NewPromiseObject, PromiseState, RejectPromise, ResolvePromise,
+	AddPromiseReactions, CallOriginalPromiseReject, CallOriginalPromiseResolve, CallOriginalPromiseThen, GetPromiseID,
+	GetPromiseIsHandled, GetPromiseState, IsPromiseObject, JSObject, NewPromiseObject, PromiseState, RejectPromise,
+	ResolvePromise, SetAnyPromiseIsHandled,
 };
 
-use crate::conversions::ToValue;
+use crate::conversions::{BoxedIntoValue, IntoValue, ToValue};
 use crate::flags::PropertyFlags;
-use crate::{Context, Error, Function, Local, Object, ResultExc, Value};
+use crate::{Context, Error, Function, Local, Object, PromiseFuture, ResultExc, Value};
 
 /// Represents a [Promise] in the JavaScript Runtime.
 /// Refer to [MDN](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise) for more details.
@@ -103,6 +104,29 @@ impl<'p> Promise<'p> {
 		})
 	}
 
+	/// Creates a new [Promise] with a [Future].
+	/// The future is spawned onto the event loop with the [FutureSpawner](crate::FutureSpawner) of the runtime.
+	///
+	/// The [Result] of the future determines if the promise is resolved or rejected.
+	/// Returns [None] if no [FutureSpawner](crate::FutureSpawner) has been initialised, or the future could not be spawned.
+	pub fn spawn_future<F, Output, Error>(cx: &'p Context, future: F) -> Option<Promise<'p>>
+	where
+		F: Future<Output = Result<Output, Error>> + 'static,
+		Output: for<'cx> IntoValue<'cx> + 'static,
+		Error: for<'cx> IntoValue<'cx> + 'static,
+	{
+		let spawner = unsafe { (*cx.get_inner_data().as_ptr()).future_spawner.as_deref()? };
+		let promise = Promise::new(cx);
+
+		let future = async move {
+			match future.await {
+				Ok(output) => Ok(Box::new(output) as BoxedIntoValue),
+				Err(error) => Err(Box::new(error) as BoxedIntoValue),
+			}
+		};
+		spawner.spawn(cx, &promise, Box::pin(future)).then_some(promise)
+	}
+
 	/// Creates a new [Promise], that is resolved to the given value.
 	/// Similar to `Promise.resolve`
 	pub fn resolved(cx: &'p Context, value: &Value) -> Promise<'p> {
@@ -148,6 +172,37 @@ impl<'p> Promise<'p> {
 		unsafe { GetPromiseState(self.handle().into()) }
 	}
 
+	/// Checks if the [Promise] is still pending.
+	pub fn is_pending(&self) -> bool {
+		matches!(self.state(), PromiseState::Pending)
+	}
+
+	/// Checks if the [Promise] has been fulfilled.
+	pub fn is_fulfilled(&self) -> bool {
+		matches!(self.state(), PromiseState::Fulfilled)
+	}
+
+	/// Checks if the [Promise] has been rejected.
+	pub fn is_rejected(&self) -> bool {
+		matches!(self.state(), PromiseState::Rejected)
+	}
+
+	/// Checks if a rejection handler has been attached to the [Promise].
+	pub fn is_handled(&self) -> bool {
+		unsafe { GetPromiseIsHandled(self.handle().into()) }
+	}
+
+	/// Marks the [Promise] as handled, so its rejection is not reported as unhandled.
+	pub fn set_handled(&self, cx: &Context) -> bool {
+		unsafe { SetAnyPromiseIsHandled(cx.as_ptr(), self.handle().into()) }
+	}
+
+	/// Returns a [PromiseFuture] that completes once the [Promise] is settled.
+	/// The reactions only run when the job queue is drained, so the future must be polled by the event loop.
+	pub fn to_future(&self, cx: &Context) -> PromiseFuture {
+		PromiseFuture::new(cx, self)
+	}
+
 	/// Returns the result of the [Promise].
 	pub fn result<'cx>(&self, cx: &'cx Context) -> Value<'cx> {
 		let mut value = Value::undefined(cx);
@@ -189,6 +244,25 @@ impl<'p> Promise<'p> {
 		}
 	}
 
+	/// Adds a reaction to the [Promise], returning a new [Promise] resolved with the result of the reaction.
+	/// Similar to `.then()` with a single callback, so rejections propagate to the returned [Promise].
+	pub fn map<'cx, F>(&self, cx: &'cx Context, on_resolved: F) -> Option<Promise<'cx>>
+	where
+		F: for<'cx2> FnOnce(&'cx2 Context, &Value<'cx2>) -> ResultExc<Value<'cx2>> + 'static,
+	{
+		let on_resolved = wrap_reaction(cx, on_resolved);
+
+		let promise = unsafe {
+			CallOriginalPromiseThen(
+				cx.as_ptr(),
+				self.handle().into(),
+				on_resolved.handle().into(),
+				HandleObject::null().into(),
+			)
+		};
+		(!promise.is_null()).then(|| Promise { promise: cx.root(promise) })
+	}
+
 	pub fn catch<'cx, F>(&self, cx: &'cx Context, on_rejected: F) -> bool
 	where
 		F: for<'cx2> FnOnce(&'cx2 Context, &Value<'cx2>) -> ResultExc<Value<'cx2>> + 'static,
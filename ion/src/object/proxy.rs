@@ -0,0 +1,197 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::ffi::CStr;
+use std::ops::{Deref, DerefMut};
+
+use mozjs::jsapi::{JSObject, JSProtoKey};
+
+use crate::conversions::{FromValue, ToPropertyKey, ToValue};
+use crate::flags::PropertyFlags;
+use crate::function::Closure;
+use crate::object::construct_builtin;
+use crate::{Arguments, Array, Context, Error, ErrorKind, Function, Local, Object, PropertyKey, ResultExc, Value};
+
+/// Represents a [Proxy] in the JavaScript Runtime.
+/// Refer to [MDN](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy) for more details.
+#[derive(Debug)]
+pub struct Proxy<'p> {
+	proxy: Local<'p, *mut JSObject>,
+}
+
+impl<'p> Proxy<'p> {
+	/// Creates a [ProxyBuilder] for a [Proxy] of the given target.
+	///
+	/// Traps which are not set are forwarded to the target.
+	pub fn builder(cx: &'p Context, target: &Object) -> ProxyBuilder<'p> {
+		ProxyBuilder {
+			cx,
+			target: Object::from(cx.root(target.handle().get())),
+			handler: Object::new(cx),
+		}
+	}
+
+	pub fn into_local(self) -> Local<'p, *mut JSObject> {
+		self.proxy
+	}
+}
+
+impl<'p> Deref for Proxy<'p> {
+	type Target = Local<'p, *mut JSObject>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.proxy
+	}
+}
+
+impl<'p> DerefMut for Proxy<'p> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.proxy
+	}
+}
+
+/// Builder for a [Proxy], where the traps of the handler are Rust closures.
+///
+/// The `apply` and `construct` traps are only called if the target is callable or a constructor respectively.
+pub struct ProxyBuilder<'cx> {
+	cx: &'cx Context,
+	target: Object<'cx>,
+	handler: Object<'cx>,
+}
+
+impl<'cx> ProxyBuilder<'cx> {
+	/// Sets the `get` trap, which is called with the target, key and receiver.
+	pub fn get<F>(self, mut trap: F) -> ProxyBuilder<'cx>
+	where
+		F: for<'c> FnMut(&'c Context, &Object<'c>, &PropertyKey<'c>, &Value<'c>) -> ResultExc<Value<'c>> + 'static,
+	{
+		self.trap(
+			c"get",
+			3,
+			Box::new(move |args| {
+				let cx = args.cx();
+				let target = argument(args, 0).to_object(cx);
+				let key = property_key(cx, &argument(args, 1))?;
+				trap(cx, &target, &key, &argument(args, 2))
+			}),
+		)
+	}
+
+	/// Sets the `set` trap, which is called with the target, key, value and receiver.
+	/// The trap returns whether the assignment succeeded.
+	pub fn set<F>(self, mut trap: F) -> ProxyBuilder<'cx>
+	where
+		F: for<'c> FnMut(&'c Context, &Object<'c>, &PropertyKey<'c>, &Value<'c>, &Value<'c>) -> ResultExc<bool>
+			+ 'static,
+	{
+		self.trap(
+			c"set",
+			4,
+			Box::new(move |args| {
+				let cx = args.cx();
+				let target = argument(args, 0).to_object(cx);
+				let key = property_key(cx, &argument(args, 1))?;
+				let success = trap(cx, &target, &key, &argument(args, 2), &argument(args, 3))?;
+				Ok(Value::bool(cx, success))
+			}),
+		)
+	}
+
+	/// Sets the `has` trap, which is called with the target and key.
+	pub fn has<F>(self, mut trap: F) -> ProxyBuilder<'cx>
+	where
+		F: for<'c> FnMut(&'c Context, &Object<'c>, &PropertyKey<'c>) -> ResultExc<bool> + 'static,
+	{
+		self.trap(
+			c"has",
+			2,
+			Box::new(move |args| {
+				let cx = args.cx();
+				let target = argument(args, 0).to_object(cx);
+				let key = property_key(cx, &argument(args, 1))?;
+				Ok(Value::bool(cx, trap(cx, &target, &key)?))
+			}),
+		)
+	}
+
+	/// Sets the `ownKeys` trap, which is called with the target.
+	///
+	/// `Object.keys` and similar functions also consult `getOwnPropertyDescriptor`,
+	/// which is forwarded to the target unless set with [ProxyBuilder::trap].
+	pub fn own_keys<F>(self, mut trap: F) -> ProxyBuilder<'cx>
+	where
+		F: for<'c> FnMut(&'c Context, &Object<'c>) -> ResultExc<Vec<PropertyKey<'c>>> + 'static,
+	{
+		self.trap(
+			c"ownKeys",
+			1,
+			Box::new(move |args| {
+				let cx = args.cx();
+				let target = argument(args, 0).to_object(cx);
+				Ok(trap(cx, &target)?.as_value(cx))
+			}),
+		)
+	}
+
+	/// Sets the `apply` trap, which is called with the target, `this` and arguments.
+	pub fn apply<F>(self, mut trap: F) -> ProxyBuilder<'cx>
+	where
+		F: for<'c> FnMut(&'c Context, &Object<'c>, &Value<'c>, Vec<Value<'c>>) -> ResultExc<Value<'c>> + 'static,
+	{
+		self.trap(
+			c"apply",
+			3,
+			Box::new(move |args| {
+				let cx = args.cx();
+				let target = argument(args, 0).to_object(cx);
+				let arguments = Array::from_value(cx, &argument(args, 2), true, ())?.to_vec(cx);
+				trap(cx, &target, &argument(args, 1), arguments)
+			}),
+		)
+	}
+
+	/// Sets the `construct` trap, which is called with the target, arguments and `new.target`.
+	pub fn construct<F>(self, mut trap: F) -> ProxyBuilder<'cx>
+	where
+		F: for<'c> FnMut(&'c Context, &Object<'c>, Vec<Value<'c>>, &Object<'c>) -> ResultExc<Object<'c>> + 'static,
+	{
+		self.trap(
+			c"construct",
+			3,
+			Box::new(move |args| {
+				let cx = args.cx();
+				let target = argument(args, 0).to_object(cx);
+				let arguments = Array::from_value(cx, &argument(args, 1), true, ())?.to_vec(cx);
+				let new_target = argument(args, 2).to_object(cx);
+				Ok(trap(cx, &target, arguments, &new_target)?.as_value(cx))
+			}),
+		)
+	}
+
+	/// Sets a trap with the given name on the handler.
+	/// The closure receives the arguments of the trap as specified for the [handler](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy/Proxy#handler_functions).
+	pub fn trap(self, name: &CStr, nargs: u32, closure: Box<Closure>) -> ProxyBuilder<'cx> {
+		let function = Function::from_closure(self.cx, name, closure, nargs, PropertyFlags::empty());
+		assert!(self.handler.set_as(self.cx, name.to_str().unwrap(), &function));
+		self
+	}
+
+	/// Creates the [Proxy].
+	/// Returns [None] if the proxy could not be created.
+	pub fn build(self) -> Option<Proxy<'cx>> {
+		let args = [self.target.as_value(self.cx), self.handler.as_value(self.cx)];
+		let proxy = construct_builtin(self.cx, JSProtoKey::JSProto_Proxy, &args)?;
+		Some(Proxy { proxy: proxy.into_local() })
+	}
+}
+
+fn argument<'cx>(args: &Arguments<'cx>, index: u16) -> Value<'cx> {
+	args.value(index).unwrap_or_else(|| Value::undefined(args.cx()))
+}
+
+fn property_key<'cx>(cx: &'cx Context, key: &Value<'cx>) -> ResultExc<PropertyKey<'cx>> {
+	key.to_key(cx).ok_or_else(|| Error::new("Invalid Property Key", ErrorKind::Type).into())
+}
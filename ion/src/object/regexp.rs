@@ -4,6 +4,7 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
+use std::collections::HashMap;
 use std::ops::{Deref, DerefMut};
 use std::ptr::NonNull;
 
@@ -13,8 +14,9 @@ use mozjs::jsapi::{
 	ObjectIsRegExp, RegExpFlags as REFlags,
 };
 
+use crate::conversions::{ConversionBehavior, FromValue};
 use crate::flags::RegExpFlags;
-use crate::{Context, Local, Object, Value};
+use crate::{Array, Context, Error, ErrorKind, Exception, Local, Object, OwnedKey, Value};
 
 #[derive(Debug)]
 pub struct RegExp<'r> {
@@ -85,6 +87,47 @@ impl<'r> RegExp<'r> {
 		self.execute(cx, string, index, false, &mut rval, false).then_some(rval)
 	}
 
+	/// Checks if the [RegExp] matches the string, without updating the legacy `RegExp` statics.
+	pub fn test(&self, cx: &Context, string: &str) -> bool {
+		let mut index = 0;
+		self.execute_test_no_static(cx, string, &mut index)
+	}
+
+	/// Executes the [RegExp] on the string, starting from `index`, and returns the match with its captured groups.
+	/// `index` is in UTF-16 code units, and is updated to the end of the match for global and sticky regexps.
+	pub fn exec(&self, cx: &Context, string: &str, index: &mut usize) -> crate::Result<Option<RegExpMatch>> {
+		let Some(result) = self.execute_match_no_static(cx, string, index) else {
+			return Err(match Exception::new(cx)? {
+				Some(exception) => exception.to_error(),
+				None => Error::new("Failed to Execute RegExp", ErrorKind::Normal),
+			});
+		};
+		if result.handle().is_null() {
+			return Ok(None);
+		}
+
+		let array = Array::from_value(cx, &result, true, ())?;
+		let captures = (0..array.len(cx))
+			.map(|i| Ok(array.get_as::<Option<String>>(cx, i, true, ())?.flatten()))
+			.collect::<crate::Result<_>>()?;
+
+		let object = array.as_object();
+		let index = object
+			.get_as::<_, u32>(cx, "index", true, ConversionBehavior::Default)?
+			.unwrap_or_default();
+
+		let mut groups = HashMap::new();
+		if let Some(named) = object.get_as::<_, Option<Object>>(cx, "groups", false, ())?.flatten() {
+			for (key, value) in named.iter(cx, None) {
+				if let OwnedKey::String(name) = key.to_owned_key(cx)? {
+					groups.insert(name, Option::<String>::from_value(cx, &value?, true, ())?);
+				}
+			}
+		}
+
+		Ok(Some(RegExpMatch { index: index as usize, captures, groups }))
+	}
+
 	fn execute<'cx>(
 		&self, cx: &'cx Context, string: &str, index: &mut usize, test: bool, rval: &mut Value<'cx>, with_static: bool,
 	) -> bool {
@@ -155,6 +198,35 @@ impl<'r> RegExp<'r> {
 	}
 }
 
+/// Represents a successful match of a [RegExp] against a string.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RegExpMatch {
+	/// Index of the start of the match, in UTF-16 code units.
+	pub index: usize,
+	/// Matched substring, followed by the captures of each group.
+	/// Groups which did not participate in the match are [None].
+	pub captures: Vec<Option<String>>,
+	/// Captures of the named groups.
+	pub groups: HashMap<String, Option<String>>,
+}
+
+impl RegExpMatch {
+	/// Returns the matched substring.
+	pub fn matched(&self) -> &str {
+		self.captures.first().and_then(Option::as_deref).unwrap_or_default()
+	}
+
+	/// Returns the capture of the group at the given index, where `0` is the whole match.
+	pub fn get(&self, index: usize) -> Option<&str> {
+		self.captures.get(index).and_then(Option::as_deref)
+	}
+
+	/// Returns the capture of the named group.
+	pub fn name(&self, name: &str) -> Option<&str> {
+		self.groups.get(name).and_then(Option::as_deref)
+	}
+}
+
 impl<'r> Deref for RegExp<'r> {
 	type Target = Local<'r, *mut JSObject>;
 
@@ -16,8 +16,8 @@ use mozjs::jsapi::{
 	IsSetObject, JSObject, NewSetObject, SetAdd, SetClear, SetDelete, SetEntries, SetForEach, SetHas, SetKeys, SetSize,
 };
 
-use crate::conversions::ToValue;
-use crate::{Context, Function, Local, Object, Value};
+use crate::conversions::{FromValue, ToValue};
+use crate::{Context, Function, Local, Object, Result, Value};
 
 pub struct Set<'s> {
 	set: Local<'s, *mut JSObject>,
@@ -96,6 +96,12 @@ impl<'s> Set<'s> {
 		entries.to_object(cx)
 	}
 
+	/// Returns the values of the [Set], in insertion order.
+	pub fn to_vec<'cx>(&self, cx: &'cx Context) -> Result<Vec<Value<'cx>>> {
+		let values = self.keys(cx).as_value(cx);
+		Vec::<Value>::from_value(cx, &values, false, ())
+	}
+
 	/// Runs the given callback for each entry in the [Set].
 	pub fn for_each(&self, cx: &Context, callback: &Function, this: &Object) -> bool {
 		unsafe {
@@ -8,6 +8,7 @@ use std::ffi::c_void;
 use std::ops::{Deref, DerefMut};
 use std::{ptr, slice};
 
+use bytes::Bytes;
 use mozjs::jsapi::{
 	ArrayBufferClone, ArrayBufferCopyData, DetachArrayBuffer, GetArrayBufferMaybeSharedLengthAndData,
 	IsArrayBufferObjectMaybeShared, IsDetachedArrayBufferObject, JSObject, NewArrayBufferWithContents,
@@ -41,8 +42,35 @@ impl<'ab> ArrayBuffer<'ab> {
 	}
 
 	/// Creates a new [ArrayBuffer] by transferring ownership of the bytes to the JS runtime.
+	/// The allocation is reused as is, without shrinking it to fit.
 	pub fn from_vec(cx: &Context, bytes: Vec<u8>) -> Option<ArrayBuffer> {
-		ArrayBuffer::from_boxed_slice(cx, bytes.into_boxed_slice())
+		unsafe extern "C" fn free_external_array_buffer(_: *mut c_void, data: *mut c_void) {
+			let _ = unsafe { Box::from_raw(data.cast::<Vec<u8>>()) };
+		}
+
+		let mut bytes = Box::new(bytes);
+		let (ptr, len) = (bytes.as_mut_ptr(), bytes.len());
+		let buffer = unsafe {
+			NewExternalArrayBuffer(
+				cx.as_ptr(),
+				len,
+				ptr.cast(),
+				Some(free_external_array_buffer),
+				Box::into_raw(bytes).cast(),
+			)
+		};
+
+		if buffer.is_null() {
+			None
+		} else {
+			Some(ArrayBuffer { buffer: cx.root(buffer) })
+		}
+	}
+
+	/// Creates a new [ArrayBuffer] from [Bytes].
+	/// The bytes are only copied if they are shared with other instances of [Bytes].
+	pub fn from_bytes(cx: &Context, bytes: Bytes) -> Option<ArrayBuffer> {
+		ArrayBuffer::from_vec(cx, Vec::from(bytes))
 	}
 
 	/// Creates a new [ArrayBuffer] by transferring ownership of the bytes to the JS runtime.
@@ -101,22 +129,51 @@ impl<'ab> ArrayBuffer<'ab> {
 	}
 
 	/// Returns a slice to the contents of the [ArrayBuffer].
+	/// Returns an empty slice if the [ArrayBuffer] is already detached.
 	///
 	/// The slice may be invalidated if the [ArrayBuffer] is detached.
 	pub unsafe fn as_slice(&self) -> &[u8] {
 		let (ptr, len, _) = self.data();
+		if ptr.is_null() {
+			return &[];
+		}
 		unsafe { slice::from_raw_parts(ptr, len) }
 	}
 
 	/// Returns a mutable slice to the contents of the [ArrayBuffer].
+	/// Returns an empty slice if the [ArrayBuffer] is already detached.
 	///
 	/// The slice may be invalidated if the [ArrayBuffer] is detached.
 	#[expect(clippy::mut_from_ref)]
 	pub unsafe fn as_mut_slice(&self) -> &mut [u8] {
 		let (ptr, len, _) = self.data();
+		if ptr.is_null() {
+			return &mut [];
+		}
 		unsafe { slice::from_raw_parts_mut(ptr, len) }
 	}
 
+	/// Returns a slice to the contents of the [ArrayBuffer], or an error if it is detached.
+	///
+	/// The slice may be invalidated if the [ArrayBuffer] is detached while it is held.
+	pub unsafe fn as_slice_checked(&self) -> Result<&[u8]> {
+		if self.is_detached() {
+			Err(Error::new("ArrayBuffer is detached", ErrorKind::Type))
+		} else {
+			Ok(unsafe { self.as_slice() })
+		}
+	}
+
+	/// Copies the contents of the [ArrayBuffer] into a [Vec].
+	pub fn to_vec(&self) -> Vec<u8> {
+		unsafe { self.as_slice().to_vec() }
+	}
+
+	/// Copies the contents of the [ArrayBuffer] into [Bytes], which can be sent across threads.
+	pub fn to_bytes(&self) -> Bytes {
+		Bytes::copy_from_slice(unsafe { self.as_slice() })
+	}
+
 	/// Clones an [ArrayBuffer].
 	pub fn clone<'cx>(&self, cx: &'cx Context, offset: usize, len: usize) -> Option<ArrayBuffer<'cx>> {
 		let buffer = unsafe { ArrayBufferClone(cx.as_ptr(), self.handle().into(), offset, len) };
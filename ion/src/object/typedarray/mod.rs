@@ -17,36 +17,46 @@ use mozjs::typedarray as jsta;
 use mozjs::typedarray::{ArrayBufferU8, ClampedU8, Float32, Float64, Int16, Int32, Int8, Uint16, Uint32, Uint8};
 pub use view::*;
 
-use crate::conversions::{IntoValue, ToValue};
-use crate::{Context, Value};
+use crate::conversions::{FromValue, IntoValue, ToValue};
+use crate::{Context, Result, Value};
 
 mod buffer;
 mod view;
 
+#[derive(Clone, Debug)]
 pub struct ArrayBufferWrapper {
-	buf: Box<[<ArrayBufferU8 as jsta::TypedArrayElement>::Element]>,
+	buf: Vec<<ArrayBufferU8 as jsta::TypedArrayElement>::Element>,
 }
 
 impl ArrayBufferWrapper {
 	pub fn into_array_buffer(self, cx: &Context) -> Option<ArrayBuffer> {
-		ArrayBuffer::from_boxed_slice(cx, self.buf)
+		ArrayBuffer::from_vec(cx, self.buf)
 	}
 }
 
-impl<B: Into<Box<[<ArrayBufferU8 as jsta::TypedArrayElement>::Element]>>> From<B> for ArrayBufferWrapper {
+impl<B: Into<Vec<<ArrayBufferU8 as jsta::TypedArrayElement>::Element>>> From<B> for ArrayBufferWrapper {
 	fn from(buffer: B) -> ArrayBufferWrapper {
 		ArrayBufferWrapper { buf: buffer.into() }
 	}
 }
 
 impl Deref for ArrayBufferWrapper {
-	type Target = Box<[<ArrayBufferU8 as jsta::TypedArrayElement>::Element]>;
+	type Target = Vec<<ArrayBufferU8 as jsta::TypedArrayElement>::Element>;
 
 	fn deref(&self) -> &Self::Target {
 		&self.buf
 	}
 }
 
+impl<'cx> FromValue<'cx> for ArrayBufferWrapper {
+	type Config = ();
+
+	fn from_value(cx: &'cx Context, value: &Value, strict: bool, _: ()) -> Result<ArrayBufferWrapper> {
+		let buffer = ArrayBuffer::from_value(cx, value, strict, ())?;
+		Ok(ArrayBufferWrapper::from(buffer.to_vec()))
+	}
+}
+
 impl<'cx> IntoValue<'cx> for ArrayBufferWrapper {
 	fn into_value(self: Box<Self>, cx: &'cx Context, value: &mut Value) {
 		if let Some(buffer) = self.into_array_buffer(cx) {
@@ -58,30 +68,40 @@ impl<'cx> IntoValue<'cx> for ArrayBufferWrapper {
 macro_rules! impl_typedarray_wrapper {
 	($(($typedarray:ident, $element:ty)$(,)?)*) => {
 		$(
+			#[derive(Clone, Debug)]
 			pub struct $typedarray {
-				buf: Box<[<$element as jsta::TypedArrayElement>::Element]>,
+				buf: Vec<<$element as jsta::TypedArrayElement>::Element>,
 			}
 
 			impl $typedarray {
 				pub fn into_typed_array(self, cx: &Context) -> Option<TypedArray<$element>> {
-					TypedArray::from_boxed_slice(cx, self.buf)
+					TypedArray::from_vec(cx, self.buf)
 				}
 			}
 
-			impl<B: Into<Box<[<$element as jsta::TypedArrayElement>::Element]>>> From<B> for $typedarray {
+			impl<B: Into<Vec<<$element as jsta::TypedArrayElement>::Element>>> From<B> for $typedarray {
 				fn from(buffer: B) -> $typedarray {
 					$typedarray { buf: buffer.into() }
 				}
 			}
 
 			impl Deref for $typedarray {
-				type Target = Box<[<$element as jsta::TypedArrayElement>::Element]>;
+				type Target = Vec<<$element as jsta::TypedArrayElement>::Element>;
 
 				fn deref(&self) -> &Self::Target {
 					&self.buf
 				}
 			}
 
+			impl<'cx> FromValue<'cx> for $typedarray {
+				type Config = ();
+
+				fn from_value(cx: &'cx Context, value: &Value, strict: bool, _: ()) -> Result<$typedarray> {
+					let array = TypedArray::<$element>::from_value(cx, value, strict, ())?;
+					Ok($typedarray::from(array.to_vec()))
+				}
+			}
+
 			impl<'cx> IntoValue<'cx> for $typedarray {
 				fn into_value(self: Box<Self>, cx: &'cx Context, value: &mut Value) {
 					if let Some(array) =  self.into_typed_array(cx) {
@@ -25,8 +25,9 @@ use mozjs::typedarray::{
 };
 
 use crate::typedarray::buffer::ArrayBuffer;
+use crate::typedarray::type_to_element_size;
 use crate::utils::BoxExt;
-use crate::{Context, Local, Object};
+use crate::{Context, Error, ErrorKind, Local, Object, Result};
 
 pub trait TypedArrayElement: jsta::TypedArrayElement {
 	const NAME: &'static str;
@@ -101,8 +102,32 @@ impl<'bv, T: TypedArrayElementCreator> TypedArray<'bv, T> {
 	}
 
 	/// Creates a new [TypedArray] by transferring ownership of the values to the JS runtime.
+	/// The allocation is reused as is, without shrinking it to fit.
 	pub fn from_vec(cx: &Context, bytes: Vec<T::Element>) -> Option<TypedArray<T>> {
-		TypedArray::from_boxed_slice(cx, bytes.into_boxed_slice())
+		unsafe extern "C" fn free_external_array_buffer<T: TypedArrayElementCreator>(
+			_: *mut c_void, data: *mut c_void,
+		) {
+			let _ = unsafe { Box::from_raw(data.cast::<Vec<T::Element>>()) };
+		}
+
+		let mut bytes = Box::new(bytes);
+		let (ptr, len) = (bytes.as_mut_ptr(), bytes.len());
+		let buffer = unsafe {
+			NewExternalArrayBuffer(
+				cx.as_ptr(),
+				len * size_of::<T::Element>(),
+				ptr.cast(),
+				Some(free_external_array_buffer::<T>),
+				Box::into_raw(bytes).cast(),
+			)
+		};
+
+		if buffer.is_null() {
+			return None;
+		}
+
+		let buffer = ArrayBuffer::from(cx.root(buffer)).unwrap();
+		TypedArray::with_array_buffer(cx, &buffer, 0, len)
 	}
 
 	/// Creates a new [TypedArray] by transferring ownership of the bytes to the JS runtime.
@@ -183,22 +208,46 @@ impl<'bv, T: TypedArrayElement> TypedArray<'bv, T> {
 	}
 
 	/// Returns a slice to the contents of the [TypedArray].
+	/// Returns an empty slice if the underlying [ArrayBuffer] is already detached.
 	///
 	/// The slice may be invalidated if the underlying [ArrayBuffer] is detached.
 	pub unsafe fn as_slice(&self) -> &[T::Element] {
 		let (ptr, len) = self.data();
+		if ptr.is_null() {
+			return &[];
+		}
 		unsafe { slice::from_raw_parts(ptr, len) }
 	}
 
 	/// Returns a mutable slice to the contents of the [TypedArray].
+	/// Returns an empty slice if the underlying [ArrayBuffer] is already detached.
 	///
 	/// The slice may be invalidated if the underlying [ArrayBuffer] is detached.
 	#[expect(clippy::mut_from_ref)]
 	pub unsafe fn as_mut_slice(&self) -> &mut [T::Element] {
 		let (ptr, len) = self.data();
+		if ptr.is_null() {
+			return &mut [];
+		}
 		unsafe { slice::from_raw_parts_mut(ptr, len) }
 	}
 
+	/// Returns a slice to the contents of the [TypedArray], or an error if the underlying [ArrayBuffer] is detached.
+	///
+	/// The slice may be invalidated if the underlying [ArrayBuffer] is detached while it is held.
+	pub unsafe fn as_slice_checked(&self, cx: &Context) -> Result<&[T::Element]> {
+		if self.is_detached(cx) {
+			Err(Error::new(format!("{} is detached", T::NAME), ErrorKind::Type))
+		} else {
+			Ok(unsafe { self.as_slice() })
+		}
+	}
+
+	/// Copies the contents of the [TypedArray] into a [Vec].
+	pub fn to_vec(&self) -> Vec<T::Element> {
+		unsafe { self.as_slice().to_vec() }
+	}
+
 	/// Returns the offset of the [TypedArray] with respect to the underlying [ArrayBuffer].
 	pub fn offset(&self) -> usize {
 		unsafe { JS_GetArrayBufferViewByteOffset(self.get()) }
@@ -228,10 +277,20 @@ impl<'bv, T: TypedArrayElement> TypedArray<'bv, T> {
 		.unwrap()
 	}
 
+	/// Checks if the underlying [ArrayBuffer] is detached.
+	pub fn is_detached(&self, cx: &Context) -> bool {
+		self.buffer(cx).is_detached()
+	}
+
 	pub fn into_local(self) -> Local<'bv, *mut JSObject> {
 		self.view
 	}
 
+	/// Converts the [TypedArray] into an [ArrayBufferView] over its bytes.
+	pub fn into_view(self) -> ArrayBufferView<'bv> {
+		TypedArray { view: self.view, _phantom: PhantomData }
+	}
+
 	/// Checks if an object is an array buffer view.
 	#[expect(clippy::not_unsafe_ptr_arg_deref)]
 	pub fn is_array_buffer_view(object: *mut JSObject) -> bool {
@@ -239,10 +298,27 @@ impl<'bv, T: TypedArrayElement> TypedArray<'bv, T> {
 	}
 }
 
+/// [ArrayBufferView] accepts any typed array or `DataView`, and exposes its contents as bytes.
 impl TypedArray<'_, ArrayBufferViewU8> {
+	/// Returns the element type of the view. `DataView`s have the type [Type::MaxTypedArrayViewType].
 	pub fn view_type(&self) -> Type {
 		unsafe { JS_GetArrayBufferViewType(self.get()) }
 	}
+
+	/// Checks if the view is a `DataView`.
+	pub fn is_data_view(&self) -> bool {
+		self.view_type() == Type::MaxTypedArrayViewType
+	}
+
+	/// Returns the size of each element of the view in bytes.
+	pub fn element_size(&self) -> usize {
+		type_to_element_size(self.view_type())
+	}
+
+	/// Returns the number of elements in the view.
+	pub fn element_len(&self) -> usize {
+		self.byte_length() / self.element_size()
+	}
 }
 
 impl<T: TypedArrayElement> Debug for TypedArray<'_, T> {
@@ -0,0 +1,119 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::ops::{Deref, DerefMut};
+
+use mozjs::jsapi::{JSObject, JSProtoKey};
+
+use crate::conversions::ToValue;
+use crate::flags::PropertyFlags;
+use crate::object::construct_builtin;
+use crate::object::weak::{call_method, is_class};
+use crate::{Context, Function, Local, Object, Value};
+
+/// Callback called with the held value of a target collected by a [FinalizationRegistry].
+///
+/// The callback is called from a job scheduled after garbage collection, so it can allocate GC things and run scripts.
+pub type Finalizer = dyn FnMut(&Context, &Value) + 'static;
+
+/// Represents a [FinalizationRegistry] in the JavaScript Runtime.
+/// It calls a cleanup callback with the held value of each registered target after the target is collected.
+///
+/// Cleanup callbacks only run if the embedder schedules them with `SetHostCleanupFinalizationRegistryCallback`.
+///
+/// Refer to [MDN](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry) for more details.
+pub struct FinalizationRegistry<'r> {
+	registry: Local<'r, *mut JSObject>,
+}
+
+impl<'r> FinalizationRegistry<'r> {
+	/// Creates a new [FinalizationRegistry] with the given cleanup callback.
+	pub fn new(cx: &'r Context, cleanup: &Function) -> Option<FinalizationRegistry<'r>> {
+		let cleanup = cleanup.as_value(cx);
+		let registry = construct_builtin(cx, JSProtoKey::JSProto_FinalizationRegistry, &[cleanup])?;
+		Some(FinalizationRegistry { registry: registry.into_local() })
+	}
+
+	/// Creates a new [FinalizationRegistry] which calls a native [Finalizer].
+	///
+	/// This allows native resources, such as file descriptors or buffers, to be released when the object owning
+	/// them is collected, by registering the object with a held value identifying the resource.
+	pub fn with_finalizer(cx: &'r Context, mut finalizer: Box<Finalizer>) -> Option<FinalizationRegistry<'r>> {
+		let cleanup = Function::from_closure(
+			cx,
+			c"",
+			Box::new(move |args| {
+				let cx = args.cx();
+				let held = args.value(0).unwrap_or_else(|| Value::undefined(cx));
+				finalizer(cx, &held);
+				Ok(Value::undefined(cx))
+			}),
+			1,
+			PropertyFlags::empty(),
+		);
+		FinalizationRegistry::new(cx, &cleanup)
+	}
+
+	/// Creates a [FinalizationRegistry] from an [Object].
+	///
+	/// Returns [None] if the object is not a finalization registry.
+	pub fn from(object: Local<'r, *mut JSObject>) -> Option<FinalizationRegistry<'r>> {
+		if FinalizationRegistry::is_finalization_registry(object.get()) {
+			Some(FinalizationRegistry { registry: object })
+		} else {
+			None
+		}
+	}
+
+	/// Creates a [FinalizationRegistry] from an [Object].
+	///
+	/// ### Safety
+	/// Object must be a finalization registry.
+	pub unsafe fn from_unchecked(object: Local<'r, *mut JSObject>) -> FinalizationRegistry<'r> {
+		FinalizationRegistry { registry: object }
+	}
+
+	/// Registers the target with the [FinalizationRegistry].
+	/// After the target is collected, the cleanup callback is called with the held value.
+	///
+	/// If an unregister token is given, it can be passed to [FinalizationRegistry::unregister] to remove the
+	/// registration.
+	pub fn register(&self, cx: &Context, target: &Object, held: &Value, token: Option<&Object>) -> bool {
+		let registry = Object::from(cx.root(self.get()));
+		let mut args = vec![target.as_value(cx), Value::from(cx.root(held.get()))];
+		if let Some(token) = token {
+			args.push(token.as_value(cx));
+		}
+		call_method(cx, &registry, "register", &args).is_some()
+	}
+
+	/// Unregisters all targets registered with the given unregister token.
+	/// Returns `true` if any targets were unregistered.
+	pub fn unregister(&self, cx: &Context, token: &Object) -> bool {
+		let registry = Object::from(cx.root(self.get()));
+		call_method(cx, &registry, "unregister", &[token.as_value(cx)])
+			.is_some_and(|unregistered| unregistered.handle().to_boolean())
+	}
+
+	/// Checks if the object is a finalization registry.
+	pub fn is_finalization_registry(object: *mut JSObject) -> bool {
+		is_class(object, c"FinalizationRegistry")
+	}
+}
+
+impl<'r> Deref for FinalizationRegistry<'r> {
+	type Target = Local<'r, *mut JSObject>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.registry
+	}
+}
+
+impl<'r> DerefMut for FinalizationRegistry<'r> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.registry
+	}
+}
@@ -0,0 +1,113 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::ops::{Deref, DerefMut};
+
+use mozjs::jsapi::{GetWeakMapEntry, IsWeakMapObject, JSObject, NewWeakMapObject, SetWeakMapEntry};
+
+use crate::conversions::ToValue;
+use crate::object::weak::call_method;
+use crate::{Context, Local, Object, Value};
+
+/// Represents a [WeakMap] in the JavaScript Runtime.
+/// Entries do not keep their keys alive, so a [WeakMap] can associate data with objects without leaking them.
+///
+/// Refer to [MDN](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap) for more details.
+pub struct WeakMap<'m> {
+	map: Local<'m, *mut JSObject>,
+}
+
+impl<'m> WeakMap<'m> {
+	/// Creates a new empty [WeakMap].
+	pub fn new(cx: &'m Context) -> WeakMap<'m> {
+		WeakMap {
+			map: cx.root(unsafe { NewWeakMapObject(cx.as_ptr()) }),
+		}
+	}
+
+	/// Creates a [WeakMap] from an [Object].
+	///
+	/// Returns [None] if the object is not a weak map.
+	pub fn from(object: Local<'m, *mut JSObject>) -> Option<WeakMap<'m>> {
+		if WeakMap::is_weak_map(object.get()) {
+			Some(WeakMap { map: object })
+		} else {
+			None
+		}
+	}
+
+	/// Creates a [WeakMap] from an [Object].
+	///
+	/// ### Safety
+	/// Object must be a weak map.
+	pub unsafe fn from_unchecked(object: Local<'m, *mut JSObject>) -> WeakMap<'m> {
+		WeakMap { map: object }
+	}
+
+	/// Checks if the [WeakMap] contains the given key.
+	pub fn has(&self, cx: &Context, key: &Object) -> bool {
+		let map = Object::from(cx.root(self.handle().get()));
+		call_method(cx, &map, "has", &[key.as_value(cx)]).is_some_and(|has| has.handle().to_boolean())
+	}
+
+	/// Returns the value of the [WeakMap] at the given key.
+	pub fn get<'cx>(&self, cx: &'cx Context, key: &Object) -> Option<Value<'cx>> {
+		if self.has(cx, key) {
+			let key = key.as_value(cx);
+			let mut value = Value::undefined(cx);
+			unsafe {
+				GetWeakMapEntry(
+					cx.as_ptr(),
+					self.handle().into(),
+					key.handle().into(),
+					value.handle_mut().into(),
+				)
+			}
+			.then_some(value)
+		} else {
+			None
+		}
+	}
+
+	/// Sets the value of the [WeakMap] at the given key.
+	pub fn set(&self, cx: &Context, key: &Object, value: &Value) -> bool {
+		let key = key.as_value(cx);
+		unsafe {
+			SetWeakMapEntry(
+				cx.as_ptr(),
+				self.handle().into(),
+				key.handle().into(),
+				value.handle().into(),
+			)
+		}
+	}
+
+	/// Deletes the value of the [WeakMap] at the given key.
+	pub fn delete(&self, cx: &Context, key: &Object) -> bool {
+		let map = Object::from(cx.root(self.handle().get()));
+		call_method(cx, &map, "delete", &[key.as_value(cx)]).is_some_and(|deleted| deleted.handle().to_boolean())
+	}
+
+	/// Checks if the object is a weak map.
+	#[expect(clippy::not_unsafe_ptr_arg_deref)]
+	pub fn is_weak_map(object: *mut JSObject) -> bool {
+		unsafe { IsWeakMapObject(object) }
+	}
+}
+
+impl<'m> Deref for WeakMap<'m> {
+	type Target = Local<'m, *mut JSObject>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.map
+	}
+}
+
+impl<'m> DerefMut for WeakMap<'m> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.map
+	}
+}
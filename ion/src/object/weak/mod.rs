@@ -0,0 +1,37 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::ffi::CStr;
+
+use mozjs::jsapi::JSObject;
+use mozjs::rust::get_object_class;
+
+pub use finalization::{FinalizationRegistry, Finalizer};
+pub use map::WeakMap;
+pub use reference::{WeakHandle, WeakRef};
+pub use set::WeakSet;
+
+use crate::{Context, Function, Object, Value};
+
+mod finalization;
+mod map;
+mod reference;
+mod set;
+
+/// Calls a method of the object, as there are no engine APIs for checking and deleting entries of weak collections.
+fn call_method<'cx>(cx: &'cx Context, object: &Object, name: &str, args: &[Value]) -> Option<Value<'cx>> {
+	let method = object.get(cx, name).ok()??;
+	if !method.handle().is_object() {
+		return None;
+	}
+	let method = Function::from_object(cx, &method.to_object(cx))?;
+	method.call(cx, object, args).ok()
+}
+
+fn is_class(object: *mut JSObject, name: &CStr) -> bool {
+	let class = unsafe { get_object_class(object) };
+	!class.is_null() && unsafe { CStr::from_ptr((*class).name) } == name
+}
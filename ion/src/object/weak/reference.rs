@@ -0,0 +1,106 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::ops::{Deref, DerefMut};
+
+use mozjs::gc::Traceable;
+use mozjs::jsapi::{Heap, JSObject, JSProtoKey, JSTracer};
+
+use crate::conversions::ToValue;
+use crate::object::construct_builtin;
+use crate::object::weak::{call_method, is_class};
+use crate::{Context, Local, Object};
+
+/// Represents a [WeakRef] in the JavaScript Runtime.
+/// It refers to its target without keeping it alive.
+///
+/// Refer to [MDN](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef) for more details.
+pub struct WeakRef<'r> {
+	weak_ref: Local<'r, *mut JSObject>,
+}
+
+impl<'r> WeakRef<'r> {
+	/// Creates a new [WeakRef] to the given target.
+	pub fn new(cx: &'r Context, target: &Object) -> Option<WeakRef<'r>> {
+		let weak_ref = construct_builtin(cx, JSProtoKey::JSProto_WeakRef, &[target.as_value(cx)])?;
+		Some(WeakRef { weak_ref: weak_ref.into_local() })
+	}
+
+	/// Creates a [WeakRef] from an [Object].
+	///
+	/// Returns [None] if the object is not a weak reference.
+	pub fn from(object: Local<'r, *mut JSObject>) -> Option<WeakRef<'r>> {
+		if WeakRef::is_weak_ref(object.get()) {
+			Some(WeakRef { weak_ref: object })
+		} else {
+			None
+		}
+	}
+
+	/// Creates a [WeakRef] from an [Object].
+	///
+	/// ### Safety
+	/// Object must be a weak reference.
+	pub unsafe fn from_unchecked(object: Local<'r, *mut JSObject>) -> WeakRef<'r> {
+		WeakRef { weak_ref: object }
+	}
+
+	/// Returns the target of the [WeakRef], or [None] if it has been collected.
+	///
+	/// The target is kept alive until the end of the current job, as with `WeakRef.prototype.deref`.
+	pub fn target<'cx>(&self, cx: &'cx Context) -> Option<Object<'cx>> {
+		let weak_ref = Object::from(cx.root(self.get()));
+		let target = call_method(cx, &weak_ref, "deref", &[])?;
+		target.handle().is_object().then(|| target.to_object(cx))
+	}
+
+	/// Checks if the object is a weak reference.
+	pub fn is_weak_ref(object: *mut JSObject) -> bool {
+		is_class(object, c"WeakRef")
+	}
+}
+
+impl<'r> Deref for WeakRef<'r> {
+	type Target = Local<'r, *mut JSObject>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.weak_ref
+	}
+}
+
+impl<'r> DerefMut for WeakRef<'r> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.weak_ref
+	}
+}
+
+/// Rust-side weak handle to an object, which can be stored in native objects without keeping the object alive.
+///
+/// The handle holds a [WeakRef] to the object, which is traced when the handle is traced.
+#[derive(Debug)]
+pub struct WeakHandle {
+	weak_ref: Box<Heap<*mut JSObject>>,
+}
+
+impl WeakHandle {
+	/// Creates a new [WeakHandle] to the given object.
+	pub fn new(cx: &Context, object: &Object) -> Option<WeakHandle> {
+		let weak_ref = WeakRef::new(cx, object)?;
+		Some(WeakHandle { weak_ref: Heap::boxed(weak_ref.get()) })
+	}
+
+	/// Returns the object, or [None] if it has been collected.
+	pub fn upgrade<'cx>(&self, cx: &'cx Context) -> Option<Object<'cx>> {
+		let weak_ref = unsafe { WeakRef::from_unchecked(cx.root(self.weak_ref.get())) };
+		weak_ref.target(cx)
+	}
+}
+
+unsafe impl Traceable for WeakHandle {
+	unsafe fn trace(&self, trc: *mut JSTracer) {
+		unsafe { self.weak_ref.trace(trc) }
+	}
+}
@@ -0,0 +1,86 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::ops::{Deref, DerefMut};
+
+use mozjs::jsapi::{JSObject, JSProtoKey};
+
+use crate::conversions::ToValue;
+use crate::object::construct_builtin;
+use crate::object::weak::{call_method, is_class};
+use crate::{Context, Local, Object};
+
+/// Represents a [WeakSet] in the JavaScript Runtime.
+/// Values are held weakly, so membership does not keep them alive.
+///
+/// Refer to [MDN](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet) for more details.
+pub struct WeakSet<'s> {
+	set: Local<'s, *mut JSObject>,
+}
+
+impl<'s> WeakSet<'s> {
+	/// Creates a new empty [WeakSet].
+	pub fn new(cx: &'s Context) -> Option<WeakSet<'s>> {
+		let set = construct_builtin(cx, JSProtoKey::JSProto_WeakSet, &[])?;
+		Some(WeakSet { set: set.into_local() })
+	}
+
+	/// Creates a [WeakSet] from an [Object].
+	///
+	/// Returns [None] if the object is not a weak set.
+	pub fn from(object: Local<'s, *mut JSObject>) -> Option<WeakSet<'s>> {
+		if WeakSet::is_weak_set(object.get()) {
+			Some(WeakSet { set: object })
+		} else {
+			None
+		}
+	}
+
+	/// Creates a [WeakSet] from an [Object].
+	///
+	/// ### Safety
+	/// Object must be a weak set.
+	pub unsafe fn from_unchecked(object: Local<'s, *mut JSObject>) -> WeakSet<'s> {
+		WeakSet { set: object }
+	}
+
+	/// Checks if the [WeakSet] contains the given object.
+	pub fn has(&self, cx: &Context, object: &Object) -> bool {
+		let set = Object::from(cx.root(self.get()));
+		call_method(cx, &set, "has", &[object.as_value(cx)]).is_some_and(|has| has.handle().to_boolean())
+	}
+
+	/// Adds the object to the [WeakSet].
+	pub fn add(&self, cx: &Context, object: &Object) -> bool {
+		let set = Object::from(cx.root(self.get()));
+		call_method(cx, &set, "add", &[object.as_value(cx)]).is_some()
+	}
+
+	/// Deletes the object from the [WeakSet].
+	pub fn delete(&self, cx: &Context, object: &Object) -> bool {
+		let set = Object::from(cx.root(self.get()));
+		call_method(cx, &set, "delete", &[object.as_value(cx)]).is_some_and(|deleted| deleted.handle().to_boolean())
+	}
+
+	/// Checks if the object is a weak set.
+	pub fn is_weak_set(object: *mut JSObject) -> bool {
+		is_class(object, c"WeakSet")
+	}
+}
+
+impl<'s> Deref for WeakSet<'s> {
+	type Target = Local<'s, *mut JSObject>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.set
+	}
+}
+
+impl<'s> DerefMut for WeakSet<'s> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.set
+	}
+}
@@ -0,0 +1,590 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::fmt::Write;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+use std::{fs, io, thread};
+
+use mozjs::jsapi::{
+	JSAutoRealm, JSContext, JSGCParamKey, JSObject, JS_AddInterruptCallback, JS_DefineDebuggerObject,
+	JS_GetContextPrivate, JS_RequestInterruptCallback, OnNewGlobalHookOption,
+};
+
+use crate::conversions::{ConversionBehavior, FromValue, ToValue};
+use crate::script::Script;
+use crate::utils::json_string;
+use crate::{gc, new_global, Context, ContextInner, Function, Object, Stack, TracedHeap, Value, GLOBAL_CLASS};
+
+/// Interval between samples used when none is given.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Minimum interval between the counts of live objects taken while allocations are tracked.
+pub const CENSUS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A frame of a sampled stack.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Frame {
+	/// Name of the function, or [None] for top-level code.
+	pub function: Option<String>,
+	pub file: String,
+	pub line: u32,
+	pub column: u32,
+}
+
+impl Frame {
+	/// Returns the name of the frame, as displayed in a flamegraph.
+	pub fn name(&self) -> String {
+		let function = self.function.as_deref().filter(|function| !function.is_empty());
+		format!(
+			"{} ({}:{}:{})",
+			function.unwrap_or("(anonymous)"),
+			self.file,
+			self.line,
+			self.column
+		)
+	}
+}
+
+/// A sampled stack, with the time since profiling started and the state of the heap.
+#[derive(Clone, Debug)]
+pub struct Sample {
+	/// Indices into [Profile::frames], from the outermost frame to the innermost frame.
+	pub stack: Vec<usize>,
+	pub time: Duration,
+	/// Number of bytes allocated by the garbage collector when the sample was taken.
+	pub heap_bytes: u64,
+	/// Number of garbage collections which had run when the sample was taken.
+	pub gc_count: u64,
+}
+
+/// An object allocated while allocations were tracked by [start_heap_profiling].
+#[derive(Clone, Debug)]
+pub struct Allocation {
+	/// Indices into [Profile::frames] of the stack which allocated the object, from the outermost frame to the innermost
+	/// frame.
+	pub stack: Vec<usize>,
+	/// Time since profiling started when the allocation was recorded, which is at most one sampling interval after the
+	/// object was allocated.
+	pub time: Duration,
+	/// Name of the class of the object, such as `Object` or `Array`.
+	pub class: String,
+	/// Size of the object in the garbage collected heap, in bytes.
+	pub bytes: u64,
+}
+
+/// Count of the live objects of the profiled realm, taken by a census of the heap.
+#[derive(Copy, Clone, Debug)]
+pub struct ObjectCount {
+	/// Time since profiling started when the census was taken.
+	pub time: Duration,
+	/// Number of live objects.
+	pub objects: u64,
+	/// Total size of the live objects in the garbage collected heap, in bytes.
+	pub bytes: u64,
+}
+
+/// Weighting of the samples of a [Profile] when it is formatted.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Weight {
+	/// Time spent running each stack, in milliseconds, where each sample is weighted by the sampling interval.
+	#[default]
+	Time,
+	/// Growth of the garbage collected heap, in bytes, where each sample is weighted by the growth of the heap since the
+	/// previous sample.
+	///
+	/// The growth is attributed to the stack running when the sample is taken, rather than to the stacks which
+	/// allocated. Garbage collections shrink the heap, so growth between a collection and the following sample is only
+	/// partly counted. Use [Weight::Allocations] to attribute memory to the stacks which allocated it.
+	HeapGrowth,
+	/// Size of the objects allocated by each stack, in bytes, where each [Allocation] is weighted by its size.
+	///
+	/// Allocations are only recorded by profiles started with [start_heap_profiling].
+	Allocations,
+}
+
+/// Profile recorded by sampling the JavaScript stack at a regular interval.
+///
+/// Samples are only taken while JavaScript is running, so time spent idle in the event loop is not included.
+#[derive(Clone, Debug, Default)]
+pub struct Profile {
+	pub frames: Vec<Frame>,
+	pub samples: Vec<Sample>,
+	pub interval: Duration,
+	/// Time between starting and stopping the profiler.
+	pub duration: Duration,
+	/// Number of bytes allocated by the garbage collector when the profiler was started.
+	pub initial_heap_bytes: u64,
+	/// Objects allocated by the profiled realm, in the order they were recorded.
+	pub allocations: Vec<Allocation>,
+	/// Counts of the live objects of the profiled realm, taken at most every [CENSUS_INTERVAL] and when the profiler
+	/// is stopped.
+	pub object_counts: Vec<ObjectCount>,
+}
+
+impl Profile {
+	/// Returns the weights of the samples of the [Profile], or of its allocations for [Weight::Allocations], in the same
+	/// order.
+	pub fn weights(&self, weight: Weight) -> Vec<f64> {
+		match weight {
+			Weight::Time => vec![self.interval.as_secs_f64() * 1000.0; self.samples.len()],
+			Weight::HeapGrowth => {
+				let mut previous = self.initial_heap_bytes;
+				self.samples
+					.iter()
+					.map(|sample| {
+						let growth = sample.heap_bytes.saturating_sub(previous);
+						previous = sample.heap_bytes;
+						growth as f64
+					})
+					.collect()
+			}
+			Weight::Allocations => self.allocations.iter().map(|allocation| allocation.bytes as f64).collect(),
+		}
+	}
+
+	/// Returns the stacks weighted by [Profile::weights], in the same order.
+	fn stacks(&self, weight: Weight) -> Vec<&[usize]> {
+		match weight {
+			Weight::Time | Weight::HeapGrowth => self.samples.iter().map(|sample| sample.stack.as_slice()).collect(),
+			Weight::Allocations => self.allocations.iter().map(|allocation| allocation.stack.as_slice()).collect(),
+		}
+	}
+
+	/// Formats the [Profile] in the file format of [speedscope](https://www.speedscope.app), as JSON.
+	pub fn to_speedscope(&self, name: &str, weight: Weight) -> String {
+		let mut json = String::from(r#"{"$schema":"https://www.speedscope.app/file-format-schema.json","#);
+		json.push_str(r#""shared":{"frames":["#);
+		for (i, frame) in self.frames.iter().enumerate() {
+			if i > 0 {
+				json.push(',');
+			}
+			let function = frame.function.as_deref().filter(|function| !function.is_empty());
+			let _ = write!(
+				json,
+				r#"{{"name":{},"file":{},"line":{},"col":{}}}"#,
+				json_string(function.unwrap_or("(anonymous)")),
+				json_string(&frame.file),
+				frame.line,
+				frame.column
+			);
+		}
+
+		let weights = self.weights(weight);
+		let (unit, end) = match weight {
+			Weight::Time => ("milliseconds", self.duration.as_secs_f64() * 1000.0),
+			Weight::HeapGrowth | Weight::Allocations => ("bytes", weights.iter().sum()),
+		};
+		let _ = write!(
+			json,
+			r#"]}},"profiles":[{{"type":"sampled","name":{},"unit":"{unit}","startValue":0,"endValue":{end},"#,
+			json_string(name),
+		);
+		json.push_str(r#""samples":["#);
+		for (i, stack) in self.stacks(weight).into_iter().enumerate() {
+			if i > 0 {
+				json.push(',');
+			}
+			let stack: Vec<_> = stack.iter().map(usize::to_string).collect();
+			let _ = write!(json, "[{}]", stack.join(","));
+		}
+		json.push_str(r#"],"weights":["#);
+		let weights: Vec<_> = weights.iter().map(f64::to_string).collect();
+		json.push_str(&weights.join(","));
+		json.push_str(r#"]}],"exporter":"spiderfire"}"#);
+		json
+	}
+
+	/// Formats the [Profile] as folded stacks, which are accepted by `flamegraph.pl` and `inferno`.
+	/// Each line contains the frames of a stack separated by `;`, followed by the total weight of that stack.
+	/// Time is counted in samples rather than milliseconds.
+	pub fn to_folded(&self, weight: Weight) -> String {
+		let weights = match weight {
+			Weight::Time => vec![1.0; self.samples.len()],
+			Weight::HeapGrowth | Weight::Allocations => self.weights(weight),
+		};
+		let mut totals: Vec<(&[usize], f64)> = Vec::new();
+		let mut indices = HashMap::new();
+		for (stack, weight) in self.stacks(weight).into_iter().zip(weights) {
+			let index = *indices.entry(stack).or_insert_with(|| {
+				totals.push((stack, 0.0));
+				totals.len() - 1
+			});
+			totals[index].1 += weight;
+		}
+
+		let mut folded = String::new();
+		for (stack, total) in totals {
+			if total > 0.0 {
+				let names: Vec<_> = stack.iter().map(|&frame| self.frames[frame].name().replace(';', ":")).collect();
+				let _ = writeln!(folded, "{} {total}", names.join(";"));
+			}
+		}
+		folded
+	}
+
+	/// Writes the [Profile] to the file at the given path, replacing it if it exists.
+	/// Files with the `folded` extension are written as folded stacks, and others in the format of speedscope.
+	pub fn write(&self, path: &Path, weight: Weight) -> io::Result<()> {
+		if path.extension() == Some(OsStr::new("folded")) {
+			fs::write(path, self.to_folded(weight))
+		} else {
+			let name = path.file_stem().map(OsStr::to_string_lossy).unwrap_or_default();
+			fs::write(path, self.to_speedscope(&name, weight))
+		}
+	}
+}
+
+/// Stores the state of the profiler of a [Context].
+#[derive(Default)]
+pub struct Profiler {
+	callback_added: bool,
+	recording: Option<Recording>,
+}
+
+struct Recording {
+	frames: HashMap<Frame, usize>,
+	profile: Profile,
+	start: Instant,
+	running: Arc<AtomicBool>,
+	requested: Arc<AtomicBool>,
+	sampler: Option<JoinHandle<()>>,
+	tracker: Option<AllocationTracker>,
+}
+
+impl Recording {
+	/// Returns the index of the frame in [Profile::frames], adding it if it has not been seen before.
+	fn intern(&mut self, frame: Frame) -> usize {
+		let frames = &mut self.profile.frames;
+		*self.frames.entry(frame).or_insert_with_key(|frame| {
+			frames.push(frame.clone());
+			frames.len() - 1
+		})
+	}
+
+	fn stop(&mut self) {
+		self.running.store(false, Ordering::SeqCst);
+		if let Some(sampler) = self.sampler.take() {
+			let _ = sampler.join();
+		}
+	}
+}
+
+impl Drop for Profiler {
+	fn drop(&mut self) {
+		if let Some(recording) = &mut self.recording {
+			recording.stop();
+		}
+	}
+}
+
+struct SendContext(*mut JSContext);
+
+unsafe impl Send for SendContext {}
+
+/// Starts sampling the JavaScript stack every `interval`, until [stop_profiling] is called.
+/// Returns `false` if the profiler is already running.
+///
+/// Samples are taken by a separate thread, which requests an interrupt of the [Context]. The stack is captured once
+/// running JavaScript handles the interrupt.
+pub fn start_profiling(cx: &Context, interval: Duration) -> bool {
+	start(cx, interval, None)
+}
+
+/// Starts sampling the JavaScript stack every `interval` as with [start_profiling], and also records every object
+/// allocated by the realm of the [Context] with the stack which allocated it, and counts its live objects.
+/// Returns `false` if the profiler is already running, or if allocations cannot be tracked.
+///
+/// Allocations are tracked with the Debugger API, which slows down allocation and disables JIT compilation of the
+/// profiled code, so the samples of the profile are not representative of the time spent running it.
+pub fn start_heap_profiling(cx: &Context, interval: Duration) -> bool {
+	if is_profiling(cx) {
+		return false;
+	}
+	match AllocationTracker::new(cx) {
+		Some(tracker) => start(cx, interval, Some(tracker)),
+		None => false,
+	}
+}
+
+fn start(cx: &Context, interval: Duration, tracker: Option<AllocationTracker>) -> bool {
+	let profiler = unsafe { &mut (*cx.get_inner_data().as_ptr()).profiler };
+	if profiler.recording.is_some() {
+		return false;
+	}
+	if !profiler.callback_added {
+		unsafe {
+			JS_AddInterruptCallback(cx.as_ptr(), Some(interrupt_callback));
+		}
+		profiler.callback_added = true;
+	}
+
+	let running = Arc::new(AtomicBool::new(true));
+	let requested = Arc::new(AtomicBool::new(false));
+	let sampler = {
+		let context = SendContext(cx.as_ptr());
+		let running = Arc::clone(&running);
+		let requested = Arc::clone(&requested);
+		thread::spawn(move || {
+			// Captures the whole wrapper, as the pointer itself cannot be sent.
+			let context = context;
+			while running.load(Ordering::SeqCst) {
+				thread::sleep(interval);
+				if running.load(Ordering::SeqCst) && !requested.swap(true, Ordering::SeqCst) {
+					unsafe { JS_RequestInterruptCallback(context.0) }
+				}
+			}
+		})
+	};
+
+	profiler.recording = Some(Recording {
+		frames: HashMap::new(),
+		profile: Profile {
+			interval,
+			initial_heap_bytes: u64::from(gc::parameter(cx, JSGCParamKey::JSGC_BYTES)),
+			..Profile::default()
+		},
+		start: Instant::now(),
+		running,
+		requested,
+		sampler: Some(sampler),
+		tracker,
+	});
+	true
+}
+
+/// Stops the profiler and returns the recorded [Profile].
+/// Returns [None] if the profiler is not running.
+pub fn stop_profiling(cx: &Context) -> Option<Profile> {
+	let profiler = unsafe { &mut (*cx.get_inner_data().as_ptr()).profiler };
+	let mut recording = profiler.recording.take()?;
+	recording.stop();
+	let time = recording.start.elapsed();
+	if let Some(mut tracker) = recording.tracker.take() {
+		let records = tracker.drain(cx, time, true);
+		recording.add(time, records);
+		tracker.stop(cx);
+	}
+	recording.profile.duration = time;
+	Some(recording.profile)
+}
+
+/// Checks if the profiler is running.
+pub fn is_profiling(cx: &Context) -> bool {
+	unsafe { (*cx.get_inner_data().as_ptr()).profiler.recording.is_some() }
+}
+
+unsafe extern "C" fn interrupt_callback(cx: *mut JSContext) -> bool {
+	let inner = unsafe { JS_GetContextPrivate(cx).cast::<ContextInner>() };
+	let Some(recording) = (unsafe { inner.as_mut() }).and_then(|inner| inner.profiler.recording.as_mut()) else {
+		return true;
+	};
+	if !recording.requested.swap(false, Ordering::SeqCst) {
+		return true;
+	}
+	let time = recording.start.elapsed();
+
+	let cx = &unsafe { Context::new_unchecked(cx) };
+	// The heap is measured before capturing the stack, which allocates.
+	let heap_bytes = u64::from(gc::parameter(cx, JSGCParamKey::JSGC_BYTES));
+	let gc_count = u64::from(gc::parameter(cx, JSGCParamKey::JSGC_NUMBER));
+	let Some(stack) = Stack::from_capture(cx) else {
+		return true;
+	};
+
+	let recording = unsafe { (*cx.get_inner_data().as_ptr()).profiler.recording.as_mut() };
+	if let Some(recording) = recording {
+		let stack = stack
+			.records
+			.into_iter()
+			.rev()
+			.map(|record| {
+				recording.intern(Frame {
+					function: record.function,
+					file: record.location.file,
+					line: record.location.lineno,
+					column: record.location.column,
+				})
+			})
+			.collect();
+		recording.profile.samples.push(Sample { stack, time, heap_bytes, gc_count });
+
+		// The tracker is taken while it runs, so that interrupts handled by the tracker do not drain it again.
+		if let Some(mut tracker) = recording.tracker.take() {
+			let records = tracker.drain(cx, time, false);
+			let recording = unsafe { (*cx.get_inner_data().as_ptr()).profiler.recording.as_mut() };
+			if let Some(recording) = recording {
+				recording.add(time, records);
+				recording.tracker = Some(tracker);
+			} else {
+				tracker.stop(cx);
+			}
+		}
+	}
+	true
+}
+
+/// Source of the allocation tracker, which is evaluated in a separate global, as a debugger cannot observe its own
+/// realm.
+/// The allocations log is drained with the frames of each stack from the innermost frame to the outermost frame.
+const TRACKER: &str = r#"(function (target) {
+	const debuggee = new Debugger(target);
+	const memory = debuggee.memory;
+	memory.trackingAllocationSites = true;
+	memory.allocationSamplingProbability = 1;
+	memory.maxAllocationsLogLength = 1000000;
+
+	function frames(frame) {
+		const frames = [];
+		for (; frame; frame = frame.parent) {
+			frames.push({
+				name: frame.functionDisplayName,
+				source: frame.source,
+				line: frame.line,
+				column: frame.column,
+			});
+		}
+		return frames;
+	}
+
+	return {
+		drain() {
+			return memory.drainAllocationsLog().map(entry => ({
+				class: entry.class,
+				size: entry.size,
+				frames: frames(entry.frame),
+			}));
+		},
+		census() {
+			const breakdown = { by: "coarseType", objects: { by: "count", count: true, bytes: true } };
+			return memory.takeCensus({ breakdown }).objects;
+		},
+		stop() {
+			memory.trackingAllocationSites = false;
+			debuggee.removeAllDebuggees();
+		},
+	};
+})"#;
+
+/// Tracks the allocations of the realm of a [Context] with a `Debugger` in a separate global.
+struct AllocationTracker {
+	tracker: TracedHeap<*mut JSObject>,
+	last_census: Option<Duration>,
+}
+
+impl AllocationTracker {
+	fn new(cx: &Context) -> Option<AllocationTracker> {
+		let target = Object::global(cx);
+		let global = new_global(
+			cx,
+			&GLOBAL_CLASS,
+			None,
+			OnNewGlobalHookOption::DontFireOnNewGlobalHook,
+			None,
+		);
+		let _realm = JSAutoRealm::new(cx.as_ptr(), global.handle().get());
+		if !unsafe { JS_DefineDebuggerObject(cx.as_ptr(), global.handle().into()) } {
+			return None;
+		}
+
+		let function = Script::compile_and_evaluate(cx, Path::new("allocation-tracker.js"), TRACKER).ok()?;
+		let function = Function::from_object(cx, &function.to_object(cx))?;
+		let tracker = function.call(cx, &global, &[target.as_value(cx)]).ok()?;
+		Some(AllocationTracker {
+			tracker: TracedHeap::new(tracker.to_object(cx).handle().get()),
+			last_census: None,
+		})
+	}
+
+	/// Calls a method of the tracker in the realm of the tracker.
+	fn call<'cx>(&self, cx: &'cx Context, name: &str) -> Option<Value<'cx>> {
+		let tracker = Object::from(self.tracker.to_local());
+		let result = {
+			let _realm = JSAutoRealm::new(cx.as_ptr(), tracker.handle().get());
+			let method: Function = tracker.get_as(cx, name, true, ()).ok()??;
+			method.call(cx, &tracker, &[]).ok()?
+		};
+		// Wraps the result for the realm of the caller.
+		Some(result.as_value(cx))
+	}
+
+	fn stop(&self, cx: &Context) {
+		self.call(cx, "stop");
+	}
+}
+
+/// Allocations and census logged by the [AllocationTracker], which are added to the [Recording] once no JavaScript runs.
+#[derive(Default)]
+struct Records {
+	allocations: Vec<(Vec<Frame>, String, u64)>,
+	object_count: Option<ObjectCount>,
+}
+
+impl AllocationTracker {
+	/// Drains the allocations logged since the last call, and takes a census if one is due, or if `census` is `true`.
+	fn drain(&mut self, cx: &Context, time: Duration, census: bool) -> Records {
+		let mut records = Records::default();
+		let entries: Vec<Object> = self
+			.call(cx, "drain")
+			.and_then(|entries| Vec::from_value(cx, &entries, true, ()).ok())
+			.unwrap_or_default();
+		for entry in entries {
+			let class: String = entry.get_as(cx, "class", true, ()).ok().flatten().unwrap_or_default();
+			let bytes: f64 = entry.get_as(cx, "size", true, ()).ok().flatten().unwrap_or_default();
+			let frames: Vec<Object> = entry.get_as(cx, "frames", true, ()).ok().flatten().unwrap_or_default();
+			let frames = frames
+				.iter()
+				.rev()
+				.map(|frame| Frame {
+					function: frame.get_as::<_, Option<String>>(cx, "name", true, ()).ok().flatten().flatten(),
+					file: frame.get_as(cx, "source", true, ()).ok().flatten().unwrap_or_default(),
+					line: frame
+						.get_as(cx, "line", true, ConversionBehavior::Default)
+						.ok()
+						.flatten()
+						.unwrap_or_default(),
+					column: frame
+						.get_as(cx, "column", true, ConversionBehavior::Default)
+						.ok()
+						.flatten()
+						.unwrap_or_default(),
+				})
+				.collect();
+			records.allocations.push((frames, class, bytes as u64));
+		}
+
+		let due = self.last_census.is_none_or(|last| time.saturating_sub(last) >= CENSUS_INTERVAL);
+		if census || due {
+			if let Some(count) = self.call(cx, "census").filter(|count| count.handle().is_object()) {
+				let count = count.to_object(cx);
+				let objects: f64 = count.get_as(cx, "count", true, ()).ok().flatten().unwrap_or_default();
+				let bytes: f64 = count.get_as(cx, "bytes", true, ()).ok().flatten().unwrap_or_default();
+				records.object_count = Some(ObjectCount {
+					time,
+					objects: objects as u64,
+					bytes: bytes as u64,
+				});
+				self.last_census = Some(time);
+			}
+		}
+		records
+	}
+}
+
+impl Recording {
+	fn add(&mut self, time: Duration, records: Records) {
+		for (frames, class, bytes) in records.allocations {
+			let stack = frames.into_iter().map(|frame| self.intern(frame)).collect();
+			self.profile.allocations.push(Allocation { stack, time, class, bytes });
+		}
+		self.profile.object_counts.extend(records.object_count);
+	}
+}
@@ -11,6 +11,11 @@ use mozjs::jsapi::Heap;
 
 use crate::Local;
 
+/// Represents a persistent reference managed by the Garbage Collector.
+///
+/// Unlike a [Local], it is not bound to the lifetime of a [Context](crate::Context), so it can be stored in Rust
+/// structs, such as event listeners or callbacks which outlive the current rooting scope.
+/// The value is rooted until the [TracedHeap] is dropped.
 #[derive(Debug)]
 pub struct TracedHeap<T: GCMethods + Copy + 'static>
 where
@@ -24,6 +29,7 @@ impl<T: GCMethods + Copy + 'static> TracedHeap<T>
 where
 	Heap<T>: Traceable + Default,
 {
+	/// Creates a new [TracedHeap] which roots the given value.
 	pub fn new(value: T) -> TracedHeap<T> {
 		let heap = Heap::boxed(value);
 		unsafe { RootedTraceableSet::add(&*heap) };
@@ -31,6 +37,16 @@ where
 	}
 }
 
+impl<T: GCMethods + RootKind + Copy + 'static> TracedHeap<T>
+where
+	Heap<T>: Traceable + Default,
+{
+	/// Creates a new [TracedHeap] from the value of a [Local].
+	pub fn from_local(local: &Local<T>) -> TracedHeap<T> {
+		TracedHeap::new(local.get())
+	}
+}
+
 impl<T: GCMethods + Copy + 'static> TracedHeap<T>
 where
 	Heap<T>: Traceable,
@@ -48,11 +64,21 @@ impl<T: GCMethods + RootKind + Copy + 'static> TracedHeap<T>
 where
 	Heap<T>: Traceable,
 {
+	/// Creates a [Local] which refers to the value of the [TracedHeap].
 	pub fn to_local(&self) -> Local<T> {
 		unsafe { Local::from_heap(&self.heap) }
 	}
 }
 
+impl<T: GCMethods + Copy + 'static> Clone for TracedHeap<T>
+where
+	Heap<T>: Traceable + Default,
+{
+	fn clone(&self) -> TracedHeap<T> {
+		TracedHeap::new(self.get())
+	}
+}
+
 impl<T: GCMethods + Copy + 'static> Drop for TracedHeap<T>
 where
 	Heap<T>: Traceable,
@@ -9,3 +9,6 @@ pub use local::Local;
 
 mod heap;
 mod local;
+
+/// Alias of [TracedHeap], for references which persist beyond the current rooting scope.
+pub type Persistent<T> = TracedHeap<T>;
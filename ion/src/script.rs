@@ -4,13 +4,193 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
+use std::future::Future;
 use std::path::Path;
+use std::time::{Duration, Instant};
+use std::{ptr, thread};
 
-use mozjs::jsapi::{Compile, JSScript, JS_ExecuteScript};
-use mozjs::rust::{transform_u16_to_source_text, CompileOptionsWrapper};
+use futures::channel::oneshot;
+use mozjs::jsapi::{
+	Compile, CompileGlobalScriptToStencil2, CompileModuleScriptToStencil2, DelazificationOption,
+	DestroyFrontendContext, InstantiateGlobalStencil, InstantiateOptions, JSScript, JS_ExecuteScript,
+	NewFrontendContext, SetNativeStackQuota, Stencil, StencilRelease,
+};
+use mozjs::rust::{transform_str_to_source_text, transform_u16_to_source_text, CompileOptionsWrapper};
 
 use crate::{Context, ErrorReport, Local, Value};
 
+const OFF_THREAD_STACK_SIZE: usize = 2 * 1024 * 1024;
+const OFF_THREAD_STACK_QUOTA: usize = 1024 * 1024;
+
+/// Represents the options used to compile a [Script].
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct CompileOptions {
+	pub filename: String,
+	pub line: u32,
+	pub lazy: bool,
+}
+
+impl CompileOptions {
+	/// Creates [CompileOptions] with the given filename, starting at the first line with lazy parsing.
+	pub fn new<S: Into<String>>(filename: S) -> CompileOptions {
+		CompileOptions {
+			filename: filename.into(),
+			line: 1,
+			lazy: true,
+		}
+	}
+
+	/// Sets the line number the source starts at, such as for scripts embedded within other files.
+	pub fn line(self, line: u32) -> CompileOptions {
+		CompileOptions { line, ..self }
+	}
+
+	/// Sets whether inner functions are parsed lazily when they are first called.
+	/// Disabling lazy parsing is faster for sources where most functions are called.
+	pub fn lazy(self, lazy: bool) -> CompileOptions {
+		CompileOptions { lazy, ..self }
+	}
+
+	pub(crate) fn to_wrapper(&self, cx: &Context) -> CompileOptionsWrapper {
+		let options = unsafe { CompileOptionsWrapper::new(cx.as_ptr(), &self.filename, self.line) };
+		if !self.lazy {
+			unsafe {
+				(*options.ptr)._base.eagerDelazificationStrategy_ = DelazificationOption::ParseEverythingEagerly;
+			}
+		}
+		options
+	}
+}
+
+impl Default for CompileOptions {
+	fn default() -> CompileOptions {
+		CompileOptions::new("inline.js")
+	}
+}
+
+/// Statistics about the scripts and modules compiled on separate threads for a [Context].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct OffThreadStatistics {
+	/// Number of sources compiled on separate threads.
+	pub compilations: u32,
+	/// Total time spent compiling on separate threads.
+	pub compile_time: Duration,
+	/// Total time spent waiting for compilations after they were awaited.
+	pub wait_time: Duration,
+}
+
+impl OffThreadStatistics {
+	/// Returns the compile time which overlapped with other work on the thread of the [Context].
+	pub fn saved_time(&self) -> Duration {
+		self.compile_time.saturating_sub(self.wait_time)
+	}
+}
+
+/// Returns the [OffThreadStatistics] of the [Context].
+pub fn off_thread_statistics(cx: &Context) -> OffThreadStatistics {
+	unsafe { (*cx.get_inner_data().as_ptr()).off_thread_statistics }
+}
+
+/// Reference to a compiled [Stencil], which is released when dropped.
+struct OwnedStencil(*mut Stencil);
+
+impl Drop for OwnedStencil {
+	fn drop(&mut self) {
+		if !self.0.is_null() {
+			unsafe { StencilRelease(self.0) };
+		}
+	}
+}
+
+/// Result of loading and compiling a source on a separate thread.
+///
+/// The stencil is released when this is dropped, including when it is never received, so that pending compilations
+/// can be cancelled by dropping their receiver.
+pub(crate) struct OffThreadStencil<T> {
+	/// Compiled stencil, or null if compilation failed.
+	stencil: OwnedStencil,
+	compile_time: Duration,
+	pub(crate) source: String,
+	pub(crate) data: T,
+}
+
+unsafe impl<T: Send> Send for OffThreadStencil<T> {}
+
+impl<T> OffThreadStencil<T> {
+	/// Returns the compiled stencil, or [None] if compilation failed.
+	/// The stencil is only valid while this is alive.
+	pub(crate) fn stencil(&self) -> Option<*mut Stencil> {
+		(!self.stencil.0.is_null()).then_some(self.stencil.0)
+	}
+}
+
+struct OffThreadOptions(CompileOptionsWrapper);
+
+unsafe impl Send for OffThreadOptions {}
+
+/// Starts loading and compiling a script or module to a stencil on a separate thread.
+/// The source is loaded by calling `load` on that thread, which also returns data to be passed back with the stencil.
+///
+/// Returns [None] if the thread could not be spawned.
+pub(crate) fn compile_stencil_off_thread<T, F>(
+	cx: &Context, options: &CompileOptions, module: bool, load: F,
+) -> Option<oneshot::Receiver<Option<OffThreadStencil<T>>>>
+where
+	T: Send + 'static,
+	F: FnOnce() -> Option<(String, T)> + Send + 'static,
+{
+	let (sender, receiver) = oneshot::channel();
+	let wrapper = OffThreadOptions(options.to_wrapper(cx));
+
+	let spawned = thread::Builder::new().stack_size(OFF_THREAD_STACK_SIZE).spawn(move || {
+		// Moves the whole wrapper into the thread, rather than only its pointer.
+		let wrapper = wrapper;
+		let Some((source, data)) = load() else {
+			let _ = sender.send(None);
+			return;
+		};
+
+		let start = Instant::now();
+		let mut text = transform_str_to_source_text(&source);
+		let stencil = unsafe {
+			let fc = NewFrontendContext();
+			SetNativeStackQuota(fc, OFF_THREAD_STACK_QUOTA);
+			let stencil = if module {
+				CompileModuleScriptToStencil2(fc, wrapper.0.ptr, &mut text)
+			} else {
+				CompileGlobalScriptToStencil2(fc, wrapper.0.ptr, &mut text)
+			};
+			DestroyFrontendContext(fc);
+			stencil
+		};
+
+		let _ = sender.send(Some(OffThreadStencil {
+			stencil: OwnedStencil(stencil.mRawPtr),
+			compile_time: start.elapsed(),
+			source,
+			data,
+		}));
+	});
+	spawned.ok().map(|_| receiver)
+}
+
+/// Waits for a stencil compiled on a separate thread, and records it in the [OffThreadStatistics] of the [Context].
+/// Returns [None] if the thread could not be spawned, or loading the source failed.
+pub(crate) async fn receive_stencil<T>(
+	cx: &Context, receiver: Option<oneshot::Receiver<Option<OffThreadStencil<T>>>>,
+) -> Option<OffThreadStencil<T>> {
+	let awaited = Instant::now();
+	let stencil = receiver?.await.ok()??;
+
+	let statistics = unsafe { &mut (*cx.get_inner_data().as_ptr()).off_thread_statistics };
+	statistics.compilations += 1;
+	statistics.compile_time += stencil.compile_time;
+	statistics.wait_time += awaited.elapsed();
+
+	Some(stencil)
+}
+
 #[derive(Debug)]
 pub struct Script<'cx> {
 	script: Local<'cx, *mut JSScript>,
@@ -20,16 +200,60 @@ impl<'s> Script<'s> {
 	/// Compiles a script with a given filename and returns the compiled script.
 	/// Returns [Err] when script compilation fails.
 	pub fn compile<'cx>(cx: &'cx Context, path: &Path, script: &str) -> Result<Script<'cx>, ErrorReport> {
+		Script::compile_with_options(cx, script, &CompileOptions::new(path.to_str().unwrap()))
+	}
+
+	/// Compiles a script with the given [CompileOptions] and returns the compiled script.
+	/// Returns [Err] when script compilation fails.
+	pub fn compile_with_options<'cx>(
+		cx: &'cx Context, script: &str, options: &CompileOptions,
+	) -> Result<Script<'cx>, ErrorReport> {
 		let script: Vec<u16> = script.encode_utf16().collect();
 		let mut source = transform_u16_to_source_text(script.as_slice());
-		let options = unsafe { CompileOptionsWrapper::new(cx.as_ptr(), path.to_str().unwrap(), 1) };
+		let options = options.to_wrapper(cx);
 
 		let script = unsafe { Compile(cx.as_ptr(), options.ptr, &mut source) };
 
 		if !script.is_null() {
 			Ok(Script { script: cx.root(script) })
 		} else {
-			Err(ErrorReport::new_with_exception_stack(cx)?.unwrap())
+			Err(ErrorReport::new_with_exception_stack(cx)?.unwrap_or_else(ErrorReport::terminated))
+		}
+	}
+
+	/// Compiles a script on a separate thread, and returns a future which resolves to the compiled script.
+	/// This avoids blocking the event loop while compiling large scripts.
+	/// Compile times are recorded in the [OffThreadStatistics] of the [Context].
+	///
+	/// The future must be polled on the thread of the [Context].
+	/// Resolves to [Err] when script compilation fails.
+	pub fn compile_off_thread<'cx>(
+		cx: &'cx Context, script: String, options: CompileOptions,
+	) -> impl Future<Output = Result<Script<'cx>, ErrorReport>> + 'cx {
+		let source = script.clone();
+		let receiver = compile_stencil_off_thread(cx, &options, false, move || Some((source, ())));
+
+		async move {
+			let compiled = receive_stencil(cx, receiver).await;
+
+			// Compilation errors are reported on the main thread, where they can be converted into exceptions.
+			let Some(stencil) = compiled.as_ref().and_then(OffThreadStencil::stencil) else {
+				return Script::compile_with_options(cx, &script, &options);
+			};
+
+			let instantiate = InstantiateOptions {
+				skipFilenameValidation: false,
+				hideScriptFromDebugger: false,
+				deferDebugMetadata: false,
+			};
+			let script = unsafe { InstantiateGlobalStencil(cx.as_ptr(), &instantiate, stencil, ptr::null_mut()) };
+			drop(compiled);
+
+			if !script.is_null() {
+				Ok(Script { script: cx.root(script) })
+			} else {
+				Err(ErrorReport::new_with_exception_stack(cx)?.unwrap_or_else(ErrorReport::terminated))
+			}
 		}
 	}
 
@@ -41,7 +265,7 @@ impl<'s> Script<'s> {
 		if unsafe { JS_ExecuteScript(cx.as_ptr(), self.script.handle().into(), rval.handle_mut().into()) } {
 			Ok(rval)
 		} else {
-			Err(ErrorReport::new_with_exception_stack(cx)?.unwrap())
+			Err(ErrorReport::new_with_exception_stack(cx)?.unwrap_or_else(ErrorReport::terminated))
 		}
 	}
 
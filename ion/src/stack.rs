@@ -6,19 +6,19 @@
 
 use std::fmt::{Display, Formatter, Write};
 use std::mem::MaybeUninit;
-use std::{fmt, ptr};
+use std::{fmt, ptr, slice};
 
 use mozjs::conversions::jsstr_to_string;
 use mozjs::jsapi::{
-	BuildStackString, CaptureCurrentStack, JSObject, JSString, JS_StackCapture_AllFrames, JS_StackCapture_MaxFrames,
-	StackFormat,
+	BuildStackString, CaptureCurrentStack, ExceptionStackOrNull, JSObject, JSString, JS_StackCapture_AllFrames,
+	JS_StackCapture_MaxFrames, StackFormat,
 };
 #[cfg(feature = "sourcemap")]
 use sourcemap::SourceMap;
 
 use crate::format::{INDENT, NEWLINE};
 use crate::utils::normalise_path;
-use crate::{Context, Object};
+use crate::{Context, Object, Value};
 
 /// Represents a location in a source file.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -65,6 +65,13 @@ impl StackRecord {
 	}
 }
 
+impl StackRecord {
+	/// Checks if the [StackRecord] belongs to code internal to the engine, such as self-hosted builtins.
+	pub fn is_internal(&self) -> bool {
+		self.location.file.is_empty() || self.location.file == "self-hosted"
+	}
+}
+
 impl Display for StackRecord {
 	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
 		f.write_str(self.function.as_deref().unwrap_or(""))?;
@@ -117,11 +124,46 @@ impl Stack {
 		capture_stack(cx, None).and_then(|stack| Stack::from_object(cx, stack))
 	}
 
+	/// Captures the [Stack] of the [Context], with at most `max_frames` [records](StackRecord).
+	pub fn from_capture_with_max_frames(cx: &Context, max_frames: u32) -> Option<Stack> {
+		capture_stack(cx, Some(max_frames)).and_then(|stack| Stack::from_object(cx, stack))
+	}
+
+	/// Creates a [Stack] from the stack recorded when an exception was created.
+	/// Returns [None] if the exception is not an error object.
+	pub fn from_exception(cx: &Context, exception: &Value) -> Option<Stack> {
+		if !exception.handle().is_object() {
+			return None;
+		}
+		let exception = exception.to_object(cx);
+		let stack = unsafe { ExceptionStackOrNull(exception.handle().into()) };
+		if stack.is_null() {
+			None
+		} else {
+			Stack::from_object(cx, stack)
+		}
+	}
+
 	/// Returns `true` if the stack contains no [records](StackRecord)
 	pub fn is_empty(&self) -> bool {
 		self.records.is_empty()
 	}
 
+	/// Returns an iterator over the [records](StackRecord) of the [Stack], from the innermost frame.
+	pub fn iter(&self) -> slice::Iter<'_, StackRecord> {
+		self.records.iter()
+	}
+
+	/// Retains only the [records](StackRecord) which satisfy the predicate.
+	pub fn filter<F: FnMut(&StackRecord) -> bool>(&mut self, predicate: F) {
+		self.records.retain(predicate);
+	}
+
+	/// Removes [internal](StackRecord::is_internal) records from the [Stack].
+	pub fn filter_internal(&mut self) {
+		self.filter(|record| !record.is_internal());
+	}
+
 	/// Transforms a [Stack] with the given [SourceMap], by applying it to each of its [records](StackRecord).
 	#[cfg(feature = "sourcemap")]
 	pub fn transform_with_sourcemap(&mut self, sourcemap: &SourceMap) {
@@ -143,6 +185,15 @@ impl Stack {
 	}
 }
 
+impl<'s> IntoIterator for &'s Stack {
+	type Item = &'s StackRecord;
+	type IntoIter = slice::Iter<'s, StackRecord>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
 impl Display for Stack {
 	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
 		f.write_str(&self.format())
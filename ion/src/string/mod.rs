@@ -12,8 +12,9 @@ use bytemuck::cast_slice;
 use byteorder::NativeEndian;
 use mozjs::jsapi::{
 	JSString, JS_CompareStrings, JS_ConcatStrings, JS_DeprecatedStringHasLatin1Chars, JS_GetEmptyString,
-	JS_GetLatin1StringCharsAndLength, JS_GetStringCharAt, JS_GetTwoByteStringCharsAndLength, JS_NewDependentString,
-	JS_NewExternalStringLatin1, JS_NewExternalUCString, JS_NewUCStringCopyN, JS_StringIsLinear,
+	JS_GetLatin1StringCharsAndLength, JS_GetStringCharAt, JS_GetStringLength, JS_GetTwoByteStringCharsAndLength,
+	JS_NewDependentString, JS_NewExternalStringLatin1, JS_NewExternalUCString, JS_NewStringCopyN, JS_NewUCStringCopyN,
+	JS_StringIsLinear,
 };
 use utf16string::{WStr, WString};
 
@@ -70,9 +71,22 @@ impl<'s> String<'s> {
 	}
 
 	/// Creates a new [String] with a given string, by copying it to the JS Runtime.
+	///
+	/// Strings of only Latin-1 characters are copied as Latin-1, without an intermediate UTF-16 buffer.
+	/// Short strings are encoded on the stack.
 	pub fn copy_from_str<'cx>(cx: &'cx Context, string: &str) -> Option<String<'cx>> {
-		let utf16: Vec<u16> = string.encode_utf16().collect();
-		let jsstr = unsafe { JS_NewUCStringCopyN(cx.as_ptr(), utf16.as_ptr(), utf16.len()) };
+		let jsstr = if string.is_ascii() {
+			unsafe { JS_NewStringCopyN(cx.as_ptr(), string.as_ptr().cast(), string.len()) }
+		} else if string.chars().all(|c| u32::from(c) <= 0xFF) {
+			let chars = string.chars().map(|c| c as u8);
+			with_encoded(chars, string.len(), |chars| unsafe {
+				JS_NewStringCopyN(cx.as_ptr(), chars.as_ptr().cast(), chars.len())
+			})
+		} else {
+			with_encoded(string.encode_utf16(), string.len(), |chars| unsafe {
+				JS_NewUCStringCopyN(cx.as_ptr(), chars.as_ptr(), chars.len())
+			})
+		};
 		if jsstr.is_null() {
 			None
 		} else {
@@ -137,6 +151,17 @@ impl<'s> String<'s> {
 		result
 	}
 
+	/// Returns the length of the [String] in UTF-16 code units.
+	/// This does not require the string to be linear.
+	pub fn len(&self) -> usize {
+		unsafe { JS_GetStringLength(self.get()) }
+	}
+
+	/// Checks if the [String] is empty.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
 	/// Checks if a string is linear (contiguous) in memory.
 	pub fn is_linear(&self) -> bool {
 		unsafe { JS_StringIsLinear(self.get()) }
@@ -204,12 +229,29 @@ impl<'s> String<'s> {
 		}
 	}
 
+	/// Converts the [String] into a [Vec] of UTF-16 code units.
+	/// Unlike [String::to_owned], this is lossless for strings with unpaired surrogates.
+	pub fn to_wtf16(&self, cx: &Context) -> Vec<u16> {
+		match self.as_latin1(cx) {
+			Some(chars) => chars.iter().map(|c| u16::from(*c)).collect(),
+			None => self.as_wtf16(cx).unwrap().to_vec(),
+		}
+	}
+
+	/// Converts a [String] to an owned [String](RustString), replacing unpaired surrogates with
+	/// [U+FFFD](char::REPLACEMENT_CHARACTER).
+	pub fn to_owned_lossy(&self, cx: &Context) -> RustString {
+		match self.as_latin1(cx) {
+			Some(chars) => latin1_to_string(chars),
+			None => RustString::from_utf16_lossy(self.as_wtf16(cx).unwrap()),
+		}
+	}
+
 	/// Converts a [String] to an owned [String](RustString).
+	/// Returns an [Error] if the string contains unpaired surrogates.
 	pub fn to_owned(&self, cx: &Context) -> crate::Result<RustString> {
 		if let Some(chars) = self.as_latin1(cx) {
-			let mut string = RustString::with_capacity(chars.len());
-			string.extend(chars.iter().map(|c| *c as char));
-			Ok(string)
+			Ok(latin1_to_string(chars))
 		} else {
 			let string = self.as_wstr(cx)?.unwrap();
 			Ok(string.to_utf8())
@@ -217,6 +259,42 @@ impl<'s> String<'s> {
 	}
 }
 
+/// Maximum length of strings which are encoded on the stack before being copied to the runtime.
+const INLINE_LENGTH: usize = 64;
+
+/// Encodes the code units into a buffer and calls the function with it.
+/// The buffer is on the stack if `max_len`, the maximum number of code units, is at most [INLINE_LENGTH].
+fn with_encoded<T, I, F>(units: I, max_len: usize, f: F) -> *mut JSString
+where
+	T: Copy + Default,
+	I: Iterator<Item = T>,
+	F: FnOnce(&[T]) -> *mut JSString,
+{
+	if max_len <= INLINE_LENGTH {
+		let mut buffer = [T::default(); INLINE_LENGTH];
+		let mut len = 0;
+		for unit in units {
+			buffer[len] = unit;
+			len += 1;
+		}
+		f(&buffer[..len])
+	} else {
+		let buffer: Vec<T> = units.collect();
+		f(&buffer)
+	}
+}
+
+fn latin1_to_string(chars: &[u8]) -> RustString {
+	if chars.is_ascii() {
+		// ASCII is valid UTF-8, so the characters can be copied directly.
+		unsafe { RustString::from_utf8_unchecked(chars.to_vec()) }
+	} else {
+		let mut string = RustString::with_capacity(chars.len() * 2);
+		string.extend(chars.iter().map(|c| *c as char));
+		string
+	}
+}
+
 impl<'s> From<Local<'s, *mut JSString>> for String<'s> {
 	fn from(str: Local<'s, *mut JSString>) -> String<'s> {
 		String { str }
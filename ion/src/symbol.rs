@@ -6,9 +6,10 @@
 
 use std::mem::transmute;
 use std::ops::{Deref, DerefMut};
+use std::ptr;
 
 use mozjs::jsapi::{
-	GetSymbolCode, GetSymbolDescription, GetSymbolFor, GetWellKnownSymbol, NewSymbol, Symbol as JSSymbol,
+	GetSymbolCode, GetSymbolDescription, GetSymbolFor, GetWellKnownSymbol, JSString, NewSymbol, Symbol as JSSymbol,
 	SymbolCode as JSSymbolCode,
 };
 
@@ -68,6 +69,28 @@ impl WellKnownSymbolCode {
 			WKSC::MatchAll => "matchAll",
 		}
 	}
+
+	/// Converts an identifier of a property on the `Symbol` global object into its [WellKnownSymbolCode].
+	pub fn from_identifier(identifier: &str) -> Option<WellKnownSymbolCode> {
+		use WellKnownSymbolCode as WKSC;
+		let code = match identifier {
+			"isConcatSpreadable" => WKSC::IsConcatSpreadable,
+			"iterator" => WKSC::Iterator,
+			"match" => WKSC::Match,
+			"replace" => WKSC::Replace,
+			"search" => WKSC::Search,
+			"species" => WKSC::Species,
+			"hasInstance" => WKSC::HasInstance,
+			"split" => WKSC::Split,
+			"toPrimitive" => WKSC::ToPrimitive,
+			"toStringTag" => WKSC::ToStringTag,
+			"unscopables" => WKSC::Unscopables,
+			"asyncIterator" => WKSC::AsyncIterator,
+			"matchAll" => WKSC::MatchAll,
+			_ => return None,
+		};
+		Some(code)
+	}
 }
 
 impl SymbolCode {
@@ -138,6 +161,13 @@ impl<'s> Symbol<'s> {
 		Symbol { sym: cx.root(symbol) }
 	}
 
+	/// Creates a new unique symbol without a description.
+	pub fn new_unique(cx: &Context) -> Symbol {
+		let description = cx.root(ptr::null_mut::<JSString>());
+		let symbol = unsafe { NewSymbol(cx.as_ptr(), description.handle().into()) };
+		Symbol { sym: cx.root(symbol) }
+	}
+
 	/// Gets a [Symbol] from the symbol registry with the given key.
 	pub fn for_key<'cx>(cx: &'cx Context, key: &str) -> Symbol<'cx> {
 		let key = key.as_value(cx);
@@ -158,6 +188,12 @@ impl<'s> Symbol<'s> {
 		unsafe { GetSymbolCode(self.sym.handle().into()).into() }
 	}
 
+	/// Returns the key of a [Symbol] in the symbol registry, as with `Symbol.keyFor`.
+	/// Returns [None] if the symbol is not in the registry.
+	pub fn key_for(&self, cx: &Context) -> Option<String> {
+		(self.code() == SymbolCode::InSymbolRegistry).then(|| self.description(cx)).flatten()
+	}
+
 	/// Returns the description of a [Symbol].
 	/// Returns [None] for well-known symbols.
 	pub fn description(&self, cx: &Context) -> Option<String> {
@@ -177,6 +213,14 @@ impl<'o> From<Local<'o, *mut JSSymbol>> for Symbol<'o> {
 	}
 }
 
+impl PartialEq for Symbol<'_> {
+	fn eq(&self, other: &Self) -> bool {
+		self.get() == other.get()
+	}
+}
+
+impl Eq for Symbol<'_> {}
+
 impl<'s> Deref for Symbol<'s> {
 	type Target = Local<'s, *mut JSSymbol>;
 
@@ -4,6 +4,7 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
+use std::fmt::Write;
 use std::mem::MaybeUninit;
 use std::path::{Component, Path, PathBuf};
 use std::slice;
@@ -32,6 +33,27 @@ pub fn normalise_path<P: AsRef<Path>>(path: P) -> PathBuf {
 	buf
 }
 
+/// Quotes and escapes a string as a JSON string literal.
+pub(crate) fn json_string(string: &str) -> String {
+	let mut json = String::with_capacity(string.len() + 2);
+	json.push('"');
+	for char in string.chars() {
+		match char {
+			'"' => json.push_str("\\\""),
+			'\\' => json.push_str("\\\\"),
+			'\n' => json.push_str("\\n"),
+			'\r' => json.push_str("\\r"),
+			'\t' => json.push_str("\\t"),
+			char if char < ' ' => {
+				let _ = write!(json, "\\u{:04x}", char as u32);
+			}
+			char => json.push(char),
+		}
+	}
+	json.push('"');
+	json
+}
+
 pub trait BoxExt<T> {
 	unsafe fn into_raw_parts(self) -> (*mut T, usize);
 
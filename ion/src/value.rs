@@ -4,9 +4,14 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
+use std::fmt;
+use std::fmt::{Display, Formatter};
 use std::ops::{Deref, DerefMut};
 
-use mozjs::jsapi::{JS_ValueToSource, NullHandleValue, SameValue, UndefinedHandleValue};
+use mozjs::jsapi::{
+	IsCallable, IsConstructor, JS_LooselyEqual, JS_StrictlyEqual, JS_ValueToSource, NullHandleValue, SameValue,
+	UndefinedHandleValue,
+};
 use mozjs::jsval::{
 	BigIntValue, BooleanValue, DoubleValue, Int32Value, JSVal, NullValue, ObjectValue, SymbolValue, UInt32Value,
 	UndefinedValue,
@@ -14,7 +19,44 @@ use mozjs::jsval::{
 
 use crate::bigint::BigInt;
 use crate::conversions::ToValue;
-use crate::{Array, Context, Local, Object, Symbol};
+use crate::{Array, Context, Error, Local, Object, Result, Symbol};
+
+/// Represents the type of a [Value].
+/// This matches the result of the `typeof` operator, except that `null` is distinct from objects.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ValueType {
+	Undefined,
+	Null,
+	Boolean,
+	Number,
+	String,
+	Symbol,
+	BigInt,
+	Object,
+	Function,
+}
+
+impl ValueType {
+	/// Returns the name of the type as returned by the `typeof` operator.
+	pub fn type_of(&self) -> &'static str {
+		match self {
+			ValueType::Undefined => "undefined",
+			ValueType::Null | ValueType::Object => "object",
+			ValueType::Boolean => "boolean",
+			ValueType::Number => "number",
+			ValueType::String => "string",
+			ValueType::Symbol => "symbol",
+			ValueType::BigInt => "bigint",
+			ValueType::Function => "function",
+		}
+	}
+}
+
+impl Display for ValueType {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str(self.type_of())
+	}
+}
 
 /// Represents a JavaScript Value in the runtime.
 /// It can represent either a primitive or an object.
@@ -95,13 +137,75 @@ impl<'v> Value<'v> {
 		cx.root(self.handle().to_object()).into()
 	}
 
+	/// Returns the [ValueType] of the [Value].
+	///
+	/// ### Panics
+	/// This panics if the [Value] is a magic value, which is internal to the engine.
+	pub fn value_type(&self) -> ValueType {
+		let value = self.handle();
+		if value.is_undefined() {
+			ValueType::Undefined
+		} else if value.is_null() {
+			ValueType::Null
+		} else if value.is_boolean() {
+			ValueType::Boolean
+		} else if value.is_number() {
+			ValueType::Number
+		} else if value.is_string() {
+			ValueType::String
+		} else if value.is_symbol() {
+			ValueType::Symbol
+		} else if value.is_bigint() {
+			ValueType::BigInt
+		} else if self.is_callable() {
+			ValueType::Function
+		} else if value.is_object() {
+			ValueType::Object
+		} else {
+			unreachable!("Unexpected Magic Value")
+		}
+	}
+
 	/// Compares two values for equality using the [SameValue algorithm](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-samevalue).
 	/// This is identical to strict equality (===), except that NaN's are equal and 0 !== -0.
-	pub fn is_same(&self, cx: &Context, other: &Value) -> bool {
+	pub fn same_value(&self, cx: &Context, other: &Value) -> bool {
 		let mut same = false;
 		unsafe { SameValue(cx.as_ptr(), self.handle().into(), other.handle().into(), &mut same) && same }
 	}
 
+	/// Compares two values for equality using the [SameValue algorithm](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-samevalue).
+	#[deprecated(note = "use `Value::same_value` instead")]
+	pub fn is_same(&self, cx: &Context, other: &Value) -> bool {
+		self.same_value(cx, other)
+	}
+
+	/// Compares two values for equality using the [IsStrictlyEqual algorithm](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-isstrictlyequal), as with `===`.
+	pub fn strict_equals(&self, cx: &Context, other: &Value) -> bool {
+		let mut equal = false;
+		unsafe { JS_StrictlyEqual(cx.as_ptr(), self.handle().into(), other.handle().into(), &mut equal) && equal }
+	}
+
+	/// Compares two values for equality using the [IsLooselyEqual algorithm](https://tc39.es/ecma262/multipage/abstract-operations.html#sec-islooselyequal), as with `==`.
+	/// Returns [Err] if an exception occurs while converting an object to a primitive.
+	pub fn loose_equals(&self, cx: &Context, other: &Value) -> Result<bool> {
+		let mut equal = false;
+		if unsafe { JS_LooselyEqual(cx.as_ptr(), self.handle().into(), other.handle().into(), &mut equal) } {
+			Ok(equal)
+		} else {
+			Err(Error::none())
+		}
+	}
+
+	/// Checks if the [Value] is callable, such as a function or a callable proxy.
+	pub fn is_callable(&self) -> bool {
+		self.handle().is_object() && unsafe { IsCallable(self.handle().to_object()) }
+	}
+
+	/// Checks if the [Value] can be constructed with the `new` operator.
+	pub fn is_constructor(&self) -> bool {
+		self.handle().is_object() && unsafe { IsConstructor(self.handle().to_object()) }
+	}
+
 	pub fn to_source<'cx>(&self, cx: &'cx Context) -> crate::String<'cx> {
 		crate::String::from(cx.root(unsafe { JS_ValueToSource(cx.as_ptr(), self.handle().into()) }))
 	}
@@ -0,0 +1,34 @@
+use ion::conversions::{FromValue, ToValue};
+use ion::utils::test::TestRuntime;
+use ion::BigInt;
+
+#[test]
+fn bigint() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let large = i128::MIN + 1;
+	let bigint = BigInt::from_i128(cx, large);
+	assert!(bigint.is_negative());
+	assert_eq!(bigint.to_i128(cx), Some(large));
+	assert_eq!(bigint.to_u128(cx), None);
+	assert_eq!(BigInt::from_u128(cx, u128::MAX).to_u128(cx), Some(u128::MAX));
+
+	let (negative, digits) = BigInt::from_i64(cx, -0x1_0203).to_digits(cx);
+	assert!(negative);
+	assert_eq!(digits, vec![0x01, 0x02, 0x03]);
+	let bigint = BigInt::from_digits(cx, true, &[0x01, 0x02, 0x03]);
+	assert_eq!(bigint.to_i64(), Some(-0x1_0203));
+	assert_eq!(BigInt::from_digits(cx, false, &[]).to_digits(cx), (false, Vec::new()));
+
+	let value = u128::MAX.as_value(cx);
+	assert!(value.handle().is_bigint());
+	assert_eq!(u128::from_value(cx, &value, true, ()).unwrap(), u128::MAX);
+	assert!(i128::from_value(cx, &value, true, ()).is_err());
+	assert!(BigInt::from_value(cx, &1.5f64.as_value(cx), false, ()).is_err());
+	assert!(BigInt::from_value(cx, &"12".as_value(cx), true, ()).is_err());
+	assert_eq!(
+		BigInt::from_value(cx, &"12".as_value(cx), false, ()).unwrap().to_i64(),
+		Some(12)
+	);
+}
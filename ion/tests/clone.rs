@@ -0,0 +1,38 @@
+use std::path::Path;
+
+use ion::clone::{read, write};
+use ion::script::Script;
+use ion::utils::test::TestRuntime;
+use ion::{Object, Value};
+
+#[test]
+fn clone() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let script = r#"({ buffer: new ArrayBuffer(8), map: new Map([["key", [1, 2]]]), date: new Date(0) })"#;
+	let value = Script::compile_and_evaluate(cx, Path::new("clone.js"), script).unwrap();
+	let object = value.to_object(cx);
+	let buffer: Object = object.get_as(cx, "buffer", true, ()).unwrap().unwrap();
+
+	let bytes = write(cx, &value, Some(vec![Object::from(cx.root(buffer.handle().get()))])).unwrap();
+	assert!(!bytes.is_empty());
+
+	let global = Object::global(cx);
+	assert!(global.set(cx, "original", &value));
+	assert!(global.set(cx, "cloned", &read(cx, &bytes).unwrap()));
+
+	let script = r#"
+		original.buffer.byteLength === 0
+			&& cloned !== original
+			&& cloned.buffer.byteLength === 8
+			&& cloned.map.get("key")[1] === 2
+			&& cloned.date.getTime() === 0
+	"#;
+	let result = Script::compile_and_evaluate(cx, Path::new("clone.js"), script).unwrap();
+	assert!(result.handle().to_boolean());
+
+	let function = Script::compile_and_evaluate(cx, Path::new("function.js"), "() => {}").unwrap();
+	assert!(write(cx, &function, None).is_err());
+	assert!(write(cx, &Value::i32(cx, 1), None).is_ok());
+}
@@ -0,0 +1,100 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use ion::conversions::{ConversionBehavior, Either, FromValue, Settled, ToValue};
+use ion::utils::test::TestRuntime;
+use ion::{Map, Object, Promise, Set, Value};
+
+#[test]
+fn map() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let hashmap = HashMap::from([(String::from("a"), 1), (String::from("b"), 2)]);
+	let value = hashmap.as_value(cx);
+	let map = Map::from_value(cx, &value, true, ()).unwrap();
+	assert_eq!(map.size(cx), 2);
+	assert!(map.has(cx, &Value::string(cx, "a")));
+	assert_eq!(map.to_vec(cx).unwrap().len(), 2);
+
+	assert!(map.set(cx, &Value::string(cx, "c"), &Value::i32(cx, 3)));
+	assert!(map.delete(cx, &Value::string(cx, "a")));
+	let converted =
+		HashMap::<String, i32>::from_value(cx, &map.as_value(cx), true, ((), ConversionBehavior::Default)).unwrap();
+	assert_eq!(
+		converted,
+		HashMap::from([(String::from("b"), 2), (String::from("c"), 3)])
+	);
+}
+
+#[test]
+fn set() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let hashset = HashSet::from([1, 2, 3]);
+	let value = hashset.as_value(cx);
+	let set = Set::from_value(cx, &value, true, ()).unwrap();
+	assert_eq!(set.size(cx), 3);
+	assert!(set.has(cx, &Value::i32(cx, 2)));
+	assert!(Map::from_value(cx, &value, true, ()).is_err());
+
+	let converted = HashSet::<i32>::from_value(cx, &value, true, ConversionBehavior::Default).unwrap();
+	assert_eq!(converted, hashset);
+}
+
+#[test]
+fn combinators() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let btreemap = BTreeMap::from([(1, String::from("a")), (2, String::from("b"))]);
+	let value = btreemap.as_value(cx);
+	assert!(Map::from_value(cx, &value, true, ()).is_ok());
+	let converted = BTreeMap::<i32, String>::from_value(cx, &value, true, (ConversionBehavior::Default, ())).unwrap();
+	assert_eq!(converted, btreemap);
+
+	let object = Object::new(cx);
+	object.set_as(cx, "x", &1);
+	let object = object.as_value(cx);
+	assert!(HashMap::<String, i32>::from_value(cx, &object, true, ((), ConversionBehavior::Default)).is_err());
+	let converted = HashMap::<String, i32>::from_value(cx, &object, false, ((), ConversionBehavior::Default)).unwrap();
+	assert_eq!(converted, HashMap::from([(String::from("x"), 1)]));
+
+	let btreeset = BTreeSet::from([3, 1, 2]);
+	let converted = BTreeSet::<i32>::from_value(cx, &btreeset.as_value(cx), true, ConversionBehavior::Default).unwrap();
+	assert_eq!(converted, btreeset);
+
+	let tuple = (1, String::from("b"), true);
+	let value = tuple.as_value(cx);
+	let config = (ConversionBehavior::Default, (), ());
+	assert_eq!(
+		<(i32, String, bool)>::from_value(cx, &value, true, config).unwrap(),
+		tuple
+	);
+	assert!(<(i32, String)>::from_value(cx, &value, true, (ConversionBehavior::Default, ())).is_err());
+
+	let config = (ConversionBehavior::Default, ());
+	let number = Either::<i32, String>::from_value(cx, &Value::i32(cx, 1), true, config).unwrap();
+	assert_eq!(number, Either::Left(1));
+	let string = Either::<i32, String>::from_value(cx, &Value::string(cx, "a"), true, config).unwrap();
+	assert_eq!(string, Either::Right(String::from("a")));
+
+	let cow = Cow::<str>::from_value(cx, &Cow::Borrowed("c").as_value(cx), true, ()).unwrap();
+	assert_eq!(cow, "c");
+
+	let config = (ConversionBehavior::Default, ());
+	let ok = Settled::<i32, String>(Ok(1)).as_value(cx);
+	assert_eq!(
+		Settled::<i32, String>::from_value(cx, &ok, true, config).unwrap(),
+		Settled(Ok(1))
+	);
+	let err = Settled::<i32, String>(Err(String::from("e"))).as_value(cx);
+	assert_eq!(
+		Settled::<i32, String>::from_value(cx, &err, true, config).unwrap().into_result(),
+		Err(String::from("e"))
+	);
+	let pending = Promise::new(cx).as_value(cx);
+	assert!(Settled::<i32, String>::from_value(cx, &pending, true, config).is_err());
+	Promise::from_value(cx, &err, true, ()).unwrap().set_handled(cx);
+}
@@ -0,0 +1,49 @@
+use std::path::Path;
+
+use ion::script::Script;
+use ion::utils::test::TestRuntime;
+use ion::{Error, ErrorKind, Exception, Object, ThrowException};
+
+#[test]
+fn error_class() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let global = Object::global(cx);
+	assert!(Error::define_class(cx, &global, "NetworkError", ErrorKind::Type).is_some());
+	assert!(Error::define_class(cx, &global, "HttpError", ErrorKind::Custom("NetworkError".into())).is_some());
+	assert!(Error::define_class(cx, &global, "UnknownError", ErrorKind::Custom("Unknown".into())).is_none());
+
+	let script = r#"
+		const error = new HttpError("Not Found");
+		[
+			error instanceof HttpError,
+			error instanceof NetworkError,
+			error instanceof TypeError,
+			error.name === "HttpError",
+			error.message === "Not Found",
+			Object.getPrototypeOf(HttpError) === NetworkError,
+		].every(Boolean)
+	"#;
+	let result = Script::compile_and_evaluate(cx, Path::new("error.js"), script).unwrap();
+	assert!(result.handle().to_boolean());
+
+	let error = Error::new("Service Unavailable", ErrorKind::Custom("HttpError".into()));
+	let object = error.to_object(cx).unwrap();
+	match Exception::from_object(cx, &object).unwrap() {
+		Exception::Error(error) => {
+			assert_eq!(error.kind, ErrorKind::Custom("HttpError".into()));
+			assert_eq!(error.format(), "HttpError - Service Unavailable");
+		}
+		Exception::Other(_) => panic!("Expected Error"),
+	}
+
+	Error::new("Unregistered", ErrorKind::Custom("UnregisteredError".into())).throw(cx);
+	match Exception::new(cx).unwrap() {
+		Some(Exception::Error(error)) => {
+			assert_eq!(error.kind, ErrorKind::Normal);
+			assert_eq!(error.message, "Unregistered");
+		}
+		_ => panic!("Expected Error"),
+	}
+}
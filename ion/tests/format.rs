@@ -0,0 +1,26 @@
+use std::path::Path;
+
+use ion::format::{format_value, ColourConfig, Config};
+use ion::script::Script;
+use ion::utils::test::TestRuntime;
+
+#[test]
+fn format() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let format = |source: &str, cfg: Config| {
+		let value = Script::compile_and_evaluate(cx, Path::new("format.js"), source).unwrap();
+		format_value(cx, cfg, &value).to_string()
+	};
+	let uncoloured = Config::default().colours(ColourConfig::none());
+
+	let formatted = format(r#"({ a: [1, "b", null, undefined, true, Symbol("c")] })"#, uncoloured);
+	assert!(!formatted.contains('\x1b'), "{formatted:?}");
+
+	assert!(!format("({ a: { a: { a: { a: 1 } } } })", uncoloured).contains("[Object]"));
+	assert!(format("({ a: { a: { a: { a: { a: 1 } } } } })", uncoloured).contains("[Object]"));
+	assert!(!format("[[[[[1]]]]]", uncoloured).contains("[Array]"));
+	assert!(format("[[[[[[1]]]]]]", uncoloured).contains("[Array]"));
+	assert!(!format("[[[[[[1]]]]]]", uncoloured.max_depth(5)).contains("[Array]"));
+}
@@ -0,0 +1,71 @@
+use std::path::Path;
+use std::ptr;
+
+use mozjs::jsapi::{JSContext, JSFunctionSpec, JSNativeWrapper};
+use mozjs::jsval::JSVal;
+
+use ion::conversions::{FromValue, ToValue};
+use ion::flags::PropertyFlags;
+use ion::script::Script;
+use ion::spec::create_function_spec;
+use ion::utils::test::TestRuntime;
+use ion::{Function, Object, Value};
+
+#[test]
+fn function() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let path = Path::new("function.js");
+	let source = "(function Point(x, y) { if (this) { this.x = x; this.y = y; } return this === undefined; })";
+	let point = Script::compile_and_evaluate(cx, path, source).unwrap();
+	assert!(point.is_callable() && point.is_constructor());
+	assert!(!Value::i32(cx, 1).is_callable());
+
+	let point = Function::from_object(cx, &point.to_object(cx)).unwrap();
+	assert_eq!(point.name(cx).unwrap(), "Point");
+	assert_eq!(point.nargs(), 2);
+
+	let result = point.apply(cx, &Value::undefined(cx), &[]).unwrap();
+	assert!(bool::from_value(cx, &result, true, ()).unwrap());
+
+	let object = point.construct(cx, &[Value::i32(cx, 1), Value::i32(cx, 2)], None).unwrap();
+	let y = object.get(cx, "y").unwrap().unwrap();
+	assert_eq!(y.to_source(cx).to_owned(cx).unwrap(), "2");
+
+	let target = Script::compile_and_evaluate(cx, path, "function Target() {}; Target")
+		.unwrap()
+		.to_object(cx);
+	let object = point.construct(cx, &[], Some(&target)).unwrap();
+	assert!(Object::global(cx).set_as(cx, "constructed", &object));
+	let result = Script::compile_and_evaluate(cx, path, "constructed instanceof Target").unwrap();
+	assert!(bool::from_value(cx, &result, true, ()).unwrap());
+
+	let bound = point.bind(cx, &Object::new(cx).as_value(cx), &[Value::i32(cx, 3)]).unwrap();
+	let result = bound.apply(cx, &Value::undefined(cx), &[Value::i32(cx, 4)]).unwrap();
+	assert!(!bool::from_value(cx, &result, true, ()).unwrap());
+}
+
+unsafe extern "C" fn native(_: *mut JSContext, _: u32, _: *mut JSVal) -> bool {
+	true
+}
+
+#[test]
+fn unstable_methods() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let wrapper = JSNativeWrapper { op: Some(native), info: ptr::null_mut() };
+	let methods = [
+		create_function_spec("native\0", wrapper, 0, PropertyFlags::CONSTANT_ENUMERATED),
+		JSFunctionSpec::ZERO,
+	];
+
+	let object = Object::new(cx);
+	assert!(unsafe { object.define_unstable_methods(cx, &methods) });
+	assert!(!object.has_own(cx, "native"));
+
+	unsafe { (*cx.get_inner_data().as_ptr()).unstable = true };
+	assert!(unsafe { object.define_unstable_methods(cx, &methods) });
+	assert!(object.has_own(cx, "native"));
+}
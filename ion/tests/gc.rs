@@ -0,0 +1,78 @@
+use std::cell::RefCell;
+use std::env::temp_dir;
+use std::fs;
+use std::path::Path;
+use std::rc::Rc;
+
+use ion::conversions::ConversionBehavior;
+use ion::gc::{
+	collect, heap_statistics, set_gc_callback, write_heap_dump, write_heap_snapshot, zone_heap_bytes, GCStatus,
+};
+use ion::json::parse;
+use ion::utils::test::TestRuntime;
+use ion::{Object, Script};
+
+#[test]
+fn gc() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let statuses = Rc::new(RefCell::new(Vec::new()));
+	let recorded = Rc::clone(&statuses);
+	set_gc_callback(cx, Some(Box::new(move |status| recorded.borrow_mut().push(status))));
+
+	for _ in 0..100 {
+		Object::new(cx);
+	}
+	let before = heap_statistics(cx);
+	assert!(before.bytes > 0);
+	let zone = zone_heap_bytes(&Object::global(cx));
+	assert!(zone > 0 && zone <= before.bytes);
+
+	collect(cx);
+	let after = heap_statistics(cx);
+	assert!(after.major_gc_count > before.major_gc_count);
+	assert!(after.gc_count > before.gc_count);
+	assert_eq!(statuses.borrow().first(), Some(&GCStatus::Begin));
+	assert_eq!(statuses.borrow().last(), Some(&GCStatus::End));
+
+	set_gc_callback(cx, None);
+	let count = statuses.borrow().len();
+	collect(cx);
+	assert_eq!(statuses.borrow().len(), count);
+
+	let path = temp_dir().join("ion-heap-dump.txt");
+	write_heap_dump(cx, &path).unwrap();
+	assert!(!fs::read_to_string(&path).unwrap().is_empty());
+	fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn heap_snapshot() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let script = "globalThis.retained = function retainedFunction() {};";
+	Script::compile_and_evaluate(cx, Path::new("snapshot.js"), script).unwrap();
+
+	let path = temp_dir().join("ion-heap-snapshot.heapsnapshot");
+	write_heap_snapshot(cx, &path).unwrap();
+	let json = fs::read_to_string(&path).unwrap();
+	fs::remove_file(&path).unwrap();
+
+	let snapshot = parse(cx, &json).unwrap().to_object(cx);
+	let meta: Object = snapshot.get_as(cx, "snapshot", true, ()).unwrap().unwrap();
+	let node_count: u32 = meta.get_as(cx, "node_count", true, ConversionBehavior::Default).unwrap().unwrap();
+	let edge_count: u32 = meta.get_as(cx, "edge_count", true, ConversionBehavior::Default).unwrap().unwrap();
+	assert!(node_count > 1 && edge_count > 0);
+
+	let nodes: Vec<u32> = snapshot.get_as(cx, "nodes", true, ConversionBehavior::Default).unwrap().unwrap();
+	let edges: Vec<u32> = snapshot.get_as(cx, "edges", true, ConversionBehavior::Default).unwrap().unwrap();
+	assert_eq!(nodes.len(), node_count as usize * 7);
+	assert_eq!(edges.len(), edge_count as usize * 3);
+	assert!(edges.chunks(3).all(|edge| edge[2] < nodes.len() as u32 && edge[2] % 7 == 0));
+
+	let strings: Vec<String> = snapshot.get_as(cx, "strings", true, ()).unwrap().unwrap();
+	assert_eq!(strings[0], "(GC roots)");
+	assert!(strings.iter().any(|string| string == "retainedFunction"));
+}
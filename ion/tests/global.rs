@@ -0,0 +1,32 @@
+use std::path::Path;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use ion::object::define_lazy_global;
+use ion::script::Script;
+use ion::utils::test::TestRuntime;
+use ion::{Context, Object};
+
+static INITIALISED: AtomicU32 = AtomicU32::new(0);
+
+fn define(cx: &Context, global: &Object) -> bool {
+	INITIALISED.fetch_add(1, Ordering::SeqCst);
+	global.set_as(cx, "first", &1i32) && global.set_as(cx, "second", &2i32)
+}
+
+#[test]
+fn lazy_global() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	define_lazy_global(cx, &["first", "second"], define);
+	assert_eq!(INITIALISED.load(Ordering::SeqCst), 0);
+
+	let path = Path::new("global.js");
+	let sum = Script::compile_and_evaluate(cx, path, "first + second").unwrap();
+	assert_eq!(sum.handle().to_int32(), 3);
+	assert_eq!(INITIALISED.load(Ordering::SeqCst), 1);
+
+	let missing = Script::compile_and_evaluate(cx, path, "typeof third").unwrap();
+	assert!(missing.handle().is_string());
+	assert_eq!(INITIALISED.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,91 @@
+use std::path::Path;
+
+use ion::conversions::{ConversionBehavior, FromValue};
+use ion::json::{parse, parse_with_reviver, stringify, stringify_into};
+use ion::script::Script;
+use ion::utils::test::TestRuntime;
+use ion::{Array, Function, Value};
+
+#[test]
+fn json() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let value = parse(cx, r#"{"a": [1, 2], "b": "text"}"#).unwrap();
+	let object = value.to_object(cx);
+	assert_eq!(
+		object.get_as::<_, String>(cx, "b", true, ()).unwrap().as_deref(),
+		Some("text")
+	);
+	assert!(parse(cx, "{").is_err());
+
+	let json = stringify(cx, &value, None, None).unwrap();
+	assert_eq!(json.as_deref(), Some(r#"{"a":[1,2],"b":"text"}"#));
+
+	let json = stringify(cx, &value, None, Some("  ")).unwrap().unwrap();
+	assert_eq!(json, "{\n  \"a\": [\n    1,\n    2\n  ],\n  \"b\": \"text\"\n}");
+
+	let keys = Array::from_slice(cx, &[Value::string(cx, "b").get()]);
+	let json = stringify(cx, &value, Some(&keys), None).unwrap();
+	assert_eq!(json.as_deref(), Some(r#"{"b":"text"}"#));
+	assert_eq!(stringify(cx, &Value::undefined(cx), None, None).unwrap(), None);
+	assert_eq!(
+		stringify(cx, &Value::null(cx), None, None).unwrap().as_deref(),
+		Some("null")
+	);
+
+	let mut buffer = Vec::new();
+	assert!(stringify_into(cx, &value, None, None, &mut buffer).unwrap());
+	assert_eq!(buffer, br#"{"a":[1,2],"b":"text"}"#);
+
+	let mut buffer = Vec::new();
+	assert!(!stringify_into(cx, &Value::undefined(cx), None, None, &mut buffer).unwrap());
+	assert!(buffer.is_empty());
+
+	let value = Script::compile_and_evaluate(
+		cx,
+		Path::new("stringify.js"),
+		r#"({
+			a: [1, "two", undefined, () => {}, { b: null }],
+			c: { d: new Date(0), e: new Number(5), f: undefined },
+			g: [],
+			h: {},
+			"\u2028": "\ud800",
+			1: true,
+		})"#,
+	)
+	.unwrap();
+	let replacer = Script::compile_and_evaluate(
+		cx,
+		Path::new("replacer.js"),
+		"(key, value) => typeof value === 'number' ? value + 1 : value",
+	)
+	.unwrap()
+	.to_object(cx);
+	let keys = Array::from_slice(cx, &[Value::string(cx, "a").get(), Value::i32(cx, 1).get()]);
+	for (replacer, indent) in [
+		(None, None),
+		(None, Some("\t")),
+		(Some(&replacer), Some("  ")),
+		(Some(keys.as_object()), None),
+	] {
+		let mut buffer = Vec::new();
+		assert!(stringify_into(cx, &value, replacer, indent, &mut buffer).unwrap());
+		let json = stringify(cx, &value, replacer, indent).unwrap().unwrap();
+		assert_eq!(String::from_utf8(buffer).unwrap(), json);
+	}
+
+	let cyclic = Script::compile_and_evaluate(cx, Path::new("cyclic.js"), "const a = []; a.push({ a }); a").unwrap();
+	assert!(stringify_into(cx, &cyclic, None, None, &mut Vec::new()).is_err());
+
+	let reviver = Script::compile_and_evaluate(
+		cx,
+		Path::new("reviver.js"),
+		"(key, value) => typeof value === 'number' ? value * 2 : value",
+	)
+	.unwrap();
+	let reviver = Function::from_value(cx, &reviver, true, ()).unwrap();
+	let value = parse_with_reviver(cx, "[1, 2]", &reviver).unwrap();
+	let doubled = Vec::<i32>::from_value(cx, &value, true, ConversionBehavior::Default).unwrap();
+	assert_eq!(doubled, [2, 4]);
+}
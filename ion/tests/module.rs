@@ -0,0 +1,49 @@
+use std::path::Path;
+
+use futures::executor::block_on;
+use ion::conversions::ConversionBehavior;
+use ion::module::Module;
+use ion::script::off_thread_statistics;
+use ion::utils::test::TestRuntime;
+
+#[test]
+fn module() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let path = Path::new("module.js");
+	let (module, _) = Module::compile_and_evaluate(cx, "module.js", Some(path), "export const answer = 42;").unwrap();
+	assert_eq!(module.data(cx).unwrap().path.as_deref(), Some("module.js"));
+
+	let namespace = module.namespace(cx).unwrap();
+	let answer: i32 = namespace.get_as(cx, "answer", true, ConversionBehavior::Default).unwrap().unwrap();
+	assert_eq!(answer, 42);
+
+	let source = "import { a } from './a.js';\nimport * as b from 'b';\nexport { a };";
+	let module = Module::compile(cx, "imports.js", None, source).unwrap();
+	assert_eq!(module.requested_modules(cx).unwrap(), ["./a.js", "b"]);
+
+	let path = Path::new("off-thread.js");
+	let source = String::from("export default 1 + 2;");
+	let (module, _) = block_on(Module::compile_and_evaluate_off_thread(
+		cx,
+		"off-thread.js",
+		Some(path),
+		source,
+	))
+	.unwrap();
+	assert_eq!(module.data(cx).unwrap().path.as_deref(), Some("off-thread.js"));
+
+	let namespace = module.namespace(cx).unwrap();
+	let sum: i32 = namespace.get_as(cx, "default", true, ConversionBehavior::Default).unwrap().unwrap();
+	assert_eq!(sum, 3);
+	assert_eq!(off_thread_statistics(cx).compilations, 1);
+
+	let error = block_on(Module::compile_off_thread(
+		cx,
+		"error.js",
+		None,
+		String::from("export +"),
+	));
+	assert!(error.is_err());
+}
@@ -0,0 +1,81 @@
+use std::path::Path;
+
+use ion::conversions::{ConversionBehavior, FromValue};
+use ion::gc::collect;
+use ion::script::Script;
+use ion::utils::test::TestRuntime;
+use ion::{Context, Object, Persistent};
+use mozjs::jsapi::{ClearKeptObjects, JSObject};
+
+struct Listener {
+	callback: Persistent<*mut JSObject>,
+}
+
+fn create_listener(cx: &Context) -> Listener {
+	let object = Object::new(cx);
+	assert!(object.set_as(cx, "value", &7i32));
+	Listener {
+		callback: Persistent::from_local(&object),
+	}
+}
+
+#[test]
+fn persistent() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let listener = create_listener(cx);
+	let cloned = listener.callback.clone();
+	collect(cx);
+
+	let object = Object::from(listener.callback.to_local());
+	let value: Option<i32> = object.get_as(cx, "value", true, ConversionBehavior::Default).unwrap();
+	assert_eq!(value, Some(7));
+	assert_eq!(cloned.get(), listener.callback.get());
+
+	drop(listener);
+	collect(cx);
+	assert!(!cloned.get().is_null());
+}
+
+const CREATE: &str = r#"(() => {
+	const rooted = { value: 7 };
+	globalThis.refs = [new WeakRef(rooted), new WeakRef({})];
+	return rooted;
+})()"#;
+
+fn is_alive(cx: &Context, index: u32) -> bool {
+	let source = format!("refs[{index}].deref() !== undefined");
+	let result = Script::compile_and_evaluate(cx, Path::new("persistent.js"), &source).unwrap();
+	unsafe { ClearKeptObjects(cx.as_ptr()) };
+	bool::from_value(cx, &result, true, ()).unwrap()
+}
+
+#[test]
+fn persistent_gc() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	// Locals are rooted until their context is dropped, so the object is created with a temporary context.
+	let persistent = {
+		let cx = unsafe { Context::new_unchecked(cx.as_ptr()) };
+		let rooted = Script::compile_and_evaluate(&cx, Path::new("persistent.js"), CREATE).unwrap();
+		Persistent::new(rooted.handle().to_object())
+	};
+	unsafe { ClearKeptObjects(cx.as_ptr()) };
+
+	collect(cx);
+	assert!(is_alive(cx, 0));
+	assert!(!is_alive(cx, 1));
+
+	{
+		let cx = unsafe { Context::new_unchecked(cx.as_ptr()) };
+		let object = Object::from(persistent.to_local());
+		let value: Option<i32> = object.get_as(&cx, "value", true, ConversionBehavior::Default).unwrap();
+		assert_eq!(value, Some(7));
+	}
+
+	drop(persistent);
+	collect(cx);
+	assert!(!is_alive(cx, 0));
+}
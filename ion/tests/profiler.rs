@@ -0,0 +1,74 @@
+use std::path::Path;
+use std::time::Duration;
+
+use ion::profiler::{is_profiling, start_heap_profiling, start_profiling, stop_profiling, Weight};
+use ion::script::Script;
+use ion::utils::test::TestRuntime;
+
+const SCRIPT: &str = r#"
+function busy() {
+	const end = Date.now() + 50;
+	while (Date.now() < end) {}
+}
+busy();
+"#;
+
+const ALLOCATING_SCRIPT: &str = r#"
+function allocate() {
+	const arrays = [];
+	for (let i = 0; i < 1000; i++) {
+		arrays.push([i]);
+	}
+	return arrays;
+}
+globalThis.retained = allocate();
+"#;
+
+#[test]
+fn profiler() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	assert!(start_profiling(cx, Duration::from_millis(1)));
+	assert!(!start_profiling(cx, Duration::from_millis(1)));
+	assert!(is_profiling(cx));
+
+	Script::compile_and_evaluate(cx, Path::new("profiler.js"), SCRIPT).unwrap();
+
+	let profile = stop_profiling(cx).unwrap();
+	assert!(!is_profiling(cx));
+	assert!(stop_profiling(cx).is_none());
+
+	assert!(!profile.samples.is_empty());
+	let busy = profile.frames.iter().position(|frame| frame.function.as_deref() == Some("busy")).unwrap();
+	assert!(profile.samples.iter().any(|sample| sample.stack.last() == Some(&busy)));
+	assert!(profile.to_folded(Weight::Time).contains("busy (profiler.js:"));
+	assert!(profile.to_speedscope("profiler", Weight::Time).starts_with('{'));
+	assert_eq!(profile.weights(Weight::HeapGrowth).len(), profile.samples.len());
+}
+
+#[test]
+fn heap_profiler() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	assert!(start_heap_profiling(cx, Duration::from_millis(1)));
+	assert!(!start_heap_profiling(cx, Duration::from_millis(1)));
+
+	Script::compile_and_evaluate(cx, Path::new("heap-profiler.js"), ALLOCATING_SCRIPT).unwrap();
+
+	let profile = stop_profiling(cx).unwrap();
+	assert!(!is_profiling(cx));
+
+	let allocate = profile
+		.frames
+		.iter()
+		.position(|frame| frame.function.as_deref() == Some("allocate"))
+		.unwrap();
+	let allocations = profile.allocations.iter().filter(|allocation| allocation.stack.last() == Some(&allocate));
+	assert!(allocations.filter(|allocation| allocation.class == "Array").count() >= 1000);
+	assert!(!profile.object_counts.is_empty());
+	assert!(profile.object_counts.iter().all(|count| count.objects > 0 && count.bytes > 0));
+	assert_eq!(profile.weights(Weight::Allocations).len(), profile.allocations.len());
+	assert!(profile.to_folded(Weight::Allocations).contains("allocate (heap-profiler.js:"));
+}
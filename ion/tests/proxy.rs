@@ -0,0 +1,34 @@
+use std::path::Path;
+
+use ion::conversions::ToValue;
+use ion::script::Script;
+use ion::utils::test::TestRuntime;
+use ion::{Object, OwnedKey, PropertyKey, Proxy, Value};
+
+#[test]
+fn proxy() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let target = Object::new(cx);
+	let proxy = Proxy::builder(cx, &target)
+		.get(|cx, _, key, _| match key.to_owned_key(cx)? {
+			OwnedKey::String(key) => Ok(Value::string(cx, &key.to_uppercase())),
+			_ => Ok(Value::undefined(cx)),
+		})
+		.has(|cx, _, key| Ok(matches!(key.to_owned_key(cx)?, OwnedKey::String(key) if key.starts_with('a'))))
+		.own_keys(|cx, _| Ok(vec![PropertyKey::with_string(cx, "virtual").unwrap()]))
+		.build()
+		.unwrap();
+
+	let global = Object::global(cx);
+	assert!(global.set_as(cx, "proxy", &Object::from(proxy.into_local()).as_value(cx)));
+
+	let path = Path::new("proxy.js");
+	let result = Script::compile_and_evaluate(cx, path, "proxy.hello").unwrap();
+	assert_eq!(result.to_source(cx).to_owned(cx).unwrap(), "\"HELLO\"");
+	let result = Script::compile_and_evaluate(cx, path, "'abc' in proxy && !('xyz' in proxy)").unwrap();
+	assert!(result.handle().to_boolean());
+	let result = Script::compile_and_evaluate(cx, path, "Reflect.ownKeys(proxy).join()").unwrap();
+	assert_eq!(result.to_source(cx).to_owned(cx).unwrap(), "\"virtual\"");
+}
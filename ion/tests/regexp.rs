@@ -0,0 +1,37 @@
+use ion::flags::RegExpFlags;
+use ion::utils::test::TestRuntime;
+use ion::RegExp;
+
+#[test]
+fn regexp() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let flags: RegExpFlags = "gi".parse().unwrap();
+	assert_eq!(flags, RegExpFlags::GLOBAL | RegExpFlags::IGNORE_CASE);
+	assert!("gg".parse::<RegExpFlags>().is_err());
+	assert!("x".parse::<RegExpFlags>().is_err());
+
+	let regexp = RegExp::new(cx, r"(?<year>\d{4})-(\d{2})(-(\d{2}))?", flags).unwrap();
+	assert_eq!(
+		regexp.source(cx).to_owned(cx).unwrap(),
+		r"(?<year>\d{4})-(\d{2})(-(\d{2}))?"
+	);
+	assert_eq!(regexp.flags(cx), flags);
+	assert!(regexp.test(cx, "on 2024-05"));
+	assert!(!regexp.test(cx, "none"));
+
+	let mut index = 0;
+	let matched = regexp.exec(cx, "from 2024-05 to 2025-06-07", &mut index).unwrap().unwrap();
+	assert_eq!(matched.index, 5);
+	assert_eq!(matched.matched(), "2024-05");
+	assert_eq!(matched.get(2), Some("05"));
+	assert_eq!(matched.get(3), None);
+	assert_eq!(matched.name("year"), Some("2024"));
+	assert_eq!(index, 12);
+
+	let matched = regexp.exec(cx, "from 2024-05 to 2025-06-07", &mut index).unwrap().unwrap();
+	assert_eq!(matched.matched(), "2025-06-07");
+	assert_eq!(matched.get(4), Some("07"));
+	assert!(regexp.exec(cx, "from 2024-05 to 2025-06-07", &mut index).unwrap().is_none());
+}
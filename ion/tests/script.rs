@@ -0,0 +1,30 @@
+use futures::executor::block_on;
+use ion::conversions::{ConversionBehavior, FromValue};
+use ion::script::{CompileOptions, Script};
+use ion::utils::test::TestRuntime;
+
+#[test]
+fn script() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let options = CompileOptions::new("script.js").line(10).lazy(false);
+	let script = Script::compile_with_options(cx, "new Error().lineNumber", &options).unwrap();
+	let line = script.evaluate(cx).unwrap();
+	assert_eq!(
+		i32::from_value(cx, &line, true, ConversionBehavior::Default).unwrap(),
+		10
+	);
+
+	let script = block_on(Script::compile_off_thread(
+		cx,
+		String::from("1 + 2"),
+		CompileOptions::default(),
+	))
+	.unwrap();
+	let result = script.evaluate(cx).unwrap();
+	assert_eq!(result.handle().to_int32(), 3);
+
+	let error = block_on(Script::compile_off_thread(cx, String::from("1 +"), options));
+	assert!(error.is_err());
+}
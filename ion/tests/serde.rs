@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use ion::conversions::serde::{from_value, to_value};
+use ion::utils::test::TestRuntime;
+use ion::{Object, Value};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+struct Record {
+	name: String,
+	id: u64,
+	ratio: f64,
+	tags: Vec<String>,
+	parent: Option<Box<Record>>,
+	kind: Kind,
+	attributes: HashMap<String, i32>,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+enum Kind {
+	Empty,
+	Single(u8),
+	Pair(i32, i32),
+	Named { value: bool },
+}
+
+#[test]
+fn serde() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let record = Record {
+		name: String::from("child"),
+		id: u64::MAX,
+		ratio: 0.5,
+		tags: vec![String::from("a"), String::from("b")],
+		parent: Some(Box::new(Record {
+			name: String::from("parent"),
+			id: 1 << 40,
+			ratio: -1.0,
+			tags: Vec::new(),
+			parent: None,
+			kind: Kind::Named { value: true },
+			attributes: HashMap::new(),
+		})),
+		kind: Kind::Pair(1, -2),
+		attributes: HashMap::from([(String::from("x"), 3)]),
+	};
+
+	let value = to_value(cx, &record).unwrap();
+	assert!(value.handle().is_object());
+	let object = value.to_object(cx);
+	assert!(object.get(cx, "id").unwrap().unwrap().handle().is_bigint());
+	assert!(object.get(cx, "parent").unwrap().unwrap().handle().is_object());
+
+	let deserialized: Record = from_value(cx, &value).unwrap();
+	assert_eq!(deserialized, record);
+
+	let kind = to_value(cx, &Kind::Empty).unwrap();
+	assert!(kind.handle().is_string());
+	assert_eq!(from_value::<Kind>(cx, &kind).unwrap(), Kind::Empty);
+	assert_eq!(
+		from_value::<Kind>(cx, &to_value(cx, &Kind::Single(7)).unwrap()).unwrap(),
+		Kind::Single(7)
+	);
+
+	let object = Object::new(cx);
+	object.set_as(cx, "value", &1.5f64);
+	assert!(from_value::<Kind>(cx, &Value::object(cx, &object)).is_err());
+	assert!(from_value::<u8>(cx, &Value::i32(cx, 256)).is_err());
+}
@@ -0,0 +1,36 @@
+use std::path::Path;
+
+use ion::script::Script;
+use ion::utils::test::TestRuntime;
+use ion::Stack;
+
+const SCRIPT: &str = r#"
+function inner() {
+	return [1].map(() => new Error("inner"))[0];
+}
+
+function outer() {
+	return inner();
+}
+
+outer();
+"#;
+
+#[test]
+fn stack() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let error = Script::compile_and_evaluate(cx, Path::new("stack.js"), SCRIPT).unwrap();
+	let mut stack = Stack::from_exception(cx, &error).unwrap();
+	assert!(stack.iter().any(|record| record.is_internal()));
+
+	stack.filter_internal();
+	let functions: Vec<_> = stack.iter().filter_map(|record| record.function.as_deref()).collect();
+	assert_eq!(functions, ["inner/<", "inner", "outer"]);
+	assert!(stack.iter().all(|record| record.location.file.ends_with("stack.js")));
+	assert_eq!(stack.records[0].location.lineno, 3);
+
+	let number = Script::compile_and_evaluate(cx, Path::new("number.js"), "1").unwrap();
+	assert!(Stack::from_exception(cx, &number).is_none());
+}
@@ -0,0 +1,44 @@
+use std::path::Path;
+
+use ion::conversions::FromValue;
+use ion::script::Script;
+use ion::utils::test::TestRuntime;
+use ion::String;
+
+#[test]
+fn string() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let ascii = String::copy_from_str(cx, "spiderfire").unwrap();
+	assert!(ascii.is_latin1());
+	assert_eq!(ascii.len(), 10);
+	assert_eq!(ascii.char_at(cx, 6), u16::from(b'f'));
+	assert_eq!(ascii.to_owned(cx).unwrap(), "spiderfire");
+
+	let unicode = String::copy_from_str(cx, "fire 🔥").unwrap();
+	assert!(unicode.is_utf16());
+	assert_eq!(unicode.len(), 7);
+	assert_eq!(unicode.to_wtf16(cx), "fire 🔥".encode_utf16().collect::<Vec<_>>());
+	assert_eq!(unicode.to_owned(cx).unwrap(), "fire 🔥");
+
+	let latin1 = String::copy_from_str(cx, "café").unwrap();
+	assert!(latin1.is_latin1());
+	assert_eq!(latin1.len(), 4);
+	assert_eq!(latin1.to_owned(cx).unwrap(), "café");
+
+	let long = "🔥".repeat(100);
+	let long_unicode = String::copy_from_str(cx, &long).unwrap();
+	assert_eq!(long_unicode.len(), 200);
+	assert_eq!(long_unicode.to_owned(cx).unwrap(), long);
+
+	let concat = ascii.concat(cx, &unicode);
+	assert_eq!(concat.len(), 17);
+	assert!(String::new(cx).is_empty());
+
+	let value = Script::compile_and_evaluate(cx, Path::new("string.js"), r#""a\uD800b""#).unwrap();
+	let surrogate = String::from_value(cx, &value, true, ()).unwrap();
+	assert!(surrogate.to_owned(cx).is_err());
+	assert_eq!(surrogate.to_wtf16(cx), [0x61, 0xD800, 0x62]);
+	assert_eq!(surrogate.to_owned_lossy(cx), "a\u{FFFD}b");
+}
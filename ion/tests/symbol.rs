@@ -0,0 +1,40 @@
+use ion::conversions::ConversionBehavior;
+use ion::symbol::{SymbolCode, WellKnownSymbolCode};
+use ion::utils::test::TestRuntime;
+use ion::{Object, Symbol, Value};
+
+#[test]
+fn symbol() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let registered = Symbol::for_key(cx, "ion");
+	assert_eq!(registered, Symbol::for_key(cx, "ion"));
+	assert_eq!(registered.code(), SymbolCode::InSymbolRegistry);
+	assert_eq!(registered.key_for(cx).as_deref(), Some("ion"));
+
+	let unique = Symbol::new(cx, "ion");
+	assert_ne!(unique, registered);
+	assert_eq!(unique.code(), SymbolCode::UniqueSymbol);
+	assert_eq!(unique.description(cx).as_deref(), Some("ion"));
+	assert_eq!(unique.key_for(cx), None);
+	assert_eq!(Symbol::new_unique(cx).description(cx), None);
+
+	let code = WellKnownSymbolCode::from_identifier("iterator").unwrap();
+	assert_eq!(code, WellKnownSymbolCode::Iterator);
+	assert_eq!(Symbol::well_known(cx, code).code(), SymbolCode::WellKnown(code));
+	assert_eq!(WellKnownSymbolCode::from_identifier("unknown"), None);
+
+	let object = Object::new(cx);
+	assert!(object.set(cx, &unique, &Value::i32(cx, 1)));
+	assert!(object.set_as(cx, &registered, &2i32));
+	assert!(!object.has(cx, "ion"));
+	assert_eq!(
+		object.get_as::<_, i32>(cx, &unique, true, ConversionBehavior::Default).unwrap(),
+		Some(1)
+	);
+	assert_eq!(
+		object.get_as::<_, i32>(cx, &registered, true, ConversionBehavior::Default).unwrap(),
+		Some(2)
+	);
+}
@@ -0,0 +1,49 @@
+use bytes::Bytes;
+use ion::conversions::{FromValue, ToValue};
+use ion::typedarray::{ArrayBuffer, ArrayBufferView, Float64Array, Uint16Array, Uint8Array, Uint8ArrayWrapper};
+use ion::utils::test::TestRuntime;
+use ion::{Object, Value};
+use mozjs::jsapi::{JS_NewDataView, Type};
+
+#[test]
+fn typedarray() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let array = Uint8Array::copy_from_bytes(cx, &[1, 2, 3, 4]).unwrap();
+	assert_eq!(array.len(), 4);
+	assert_eq!(array.to_vec(), vec![1, 2, 3, 4]);
+
+	let value = array.as_value(cx);
+	let wrapper = Uint8ArrayWrapper::from_value(cx, &value, true, ()).unwrap();
+	assert_eq!(&**wrapper, &[1, 2, 3, 4]);
+	assert!(Float64Array::from_value(cx, &value, true, ()).is_err());
+
+	let buffer = array.buffer(cx);
+	assert!(!array.is_detached(cx));
+	assert!(buffer.detach(cx));
+	assert!(array.is_detached(cx));
+	assert!(unsafe { array.as_slice_checked(cx) }.is_err());
+	assert!(array.to_vec().is_empty());
+
+	let array = Uint16Array::copy_from_bytes(cx, &[0x0102, 0x0304]).unwrap();
+	let view = array.into_view();
+	assert_eq!(view.view_type(), Type::Uint16);
+	assert_eq!((view.element_size(), view.element_len(), view.byte_length()), (2, 2, 4));
+	assert_eq!(view.len(), 4);
+
+	let buffer = ArrayBuffer::from_vec(cx, vec![5, 6, 7, 8]).unwrap();
+	let data_view = Object::from(cx.root(unsafe { JS_NewDataView(cx.as_ptr(), buffer.handle().into(), 1, 2) }));
+	let view = ArrayBufferView::from_value(cx, &Value::object(cx, &data_view), true, ()).unwrap();
+	assert!(view.is_data_view());
+	assert_eq!((view.offset(), view.element_len()), (1, 2));
+	assert_eq!(unsafe { view.as_slice() }, &[6, 7]);
+
+	let buffer = ArrayBuffer::from_vec(cx, vec![5, 6, 7]).unwrap();
+	assert_eq!(buffer.to_vec(), vec![5, 6, 7]);
+	assert!(unsafe { buffer.as_slice_checked() }.is_ok());
+
+	let bytes = Bytes::from(vec![8, 9]);
+	let buffer = ArrayBuffer::from_bytes(cx, bytes.clone()).unwrap();
+	assert_eq!(buffer.to_bytes(), bytes);
+}
@@ -0,0 +1,41 @@
+use std::path::Path;
+
+use ion::script::Script;
+use ion::utils::test::TestRuntime;
+use ion::{Exception, Object, Value, ValueType};
+
+#[test]
+fn value() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let path = Path::new("value.js");
+	let function = Script::compile_and_evaluate(cx, path, "(function() {})").unwrap();
+	assert_eq!(Value::undefined(cx).value_type(), ValueType::Undefined);
+	assert_eq!(Value::null(cx).value_type(), ValueType::Null);
+	assert_eq!(Value::f64(cx, 0.5).value_type(), ValueType::Number);
+	assert_eq!(Value::string(cx, "").value_type(), ValueType::String);
+	assert_eq!(Value::object(cx, &Object::new(cx)).value_type(), ValueType::Object);
+	assert_eq!(function.value_type(), ValueType::Function);
+	assert_eq!(Value::null(cx).value_type().to_string(), "object");
+
+	let nan = Value::f64(cx, f64::NAN);
+	assert!(nan.same_value(cx, &Value::f64(cx, f64::NAN)));
+	assert!(!nan.strict_equals(cx, &Value::f64(cx, f64::NAN)));
+	assert!(Value::f64(cx, 0.0).strict_equals(cx, &Value::f64(cx, -0.0)));
+	assert!(!Value::f64(cx, 0.0).same_value(cx, &Value::f64(cx, -0.0)));
+	#[expect(deprecated)]
+	{
+		assert!(nan.is_same(cx, &Value::f64(cx, f64::NAN)));
+		assert!(!Value::f64(cx, 0.0).is_same(cx, &Value::f64(cx, -0.0)));
+	}
+
+	let one = Value::i32(cx, 1);
+	assert!(!one.strict_equals(cx, &Value::string(cx, "1")));
+	assert!(one.loose_equals(cx, &Value::string(cx, "1")).unwrap());
+	assert!(Value::null(cx).loose_equals(cx, &Value::undefined(cx)).unwrap());
+
+	let throwing = Script::compile_and_evaluate(cx, path, "({ valueOf() { throw new Error(); } })").unwrap();
+	assert!(throwing.loose_equals(cx, &one).is_err());
+	Exception::clear(cx);
+}
@@ -0,0 +1,67 @@
+use ion::conversions::FromValue;
+use ion::gc::collect;
+use ion::utils::test::TestRuntime;
+use ion::{Context, Object, TracedHeap, Value, WeakMap, WeakRef, WeakSet};
+use mozjs::jsapi::ClearKeptObjects;
+
+#[test]
+fn weak_map() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let map = WeakMap::new(cx);
+	let key = Object::new(cx);
+	assert!(!map.has(cx, &key));
+	assert!(map.set(cx, &key, &Value::i32(cx, 1)));
+	assert!(map.has(cx, &key));
+	assert_eq!(map.get(cx, &key).unwrap().handle().to_int32(), 1);
+	assert!(map.delete(cx, &key));
+	assert!(map.get(cx, &key).is_none());
+	assert!(!map.delete(cx, &key));
+
+	assert!(map.set(cx, &key, &Value::i32(cx, 2)));
+	assert!(map.set(cx, &key, &Value::string(cx, "value")));
+	let value = map.get(cx, &key).unwrap();
+	assert_eq!(String::from_value(cx, &value, true, ()).unwrap(), "value");
+
+	let other = Object::new(cx);
+	assert!(map.get(cx, &other).is_none());
+	assert!(map.set(cx, &other, &Value::undefined(cx)));
+	assert!(map.get(cx, &other).unwrap().handle().is_undefined());
+	assert!(WeakSet::from(cx.root(map.handle().get())).is_none());
+}
+
+#[test]
+fn weak_set() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let set = WeakSet::new(cx).unwrap();
+	let object = Object::new(cx);
+	assert!(set.add(cx, &object));
+	assert!(set.has(cx, &object));
+	assert!(set.delete(cx, &object));
+	assert!(!set.has(cx, &object));
+	assert!(WeakMap::from(cx.root(set.get())).is_none());
+}
+
+#[test]
+fn weak_ref() {
+	let rt = TestRuntime::new();
+	let cx = &rt.cx;
+
+	let target = Object::new(cx);
+	let weak_ref = WeakRef::new(cx, &target).unwrap();
+	assert!(WeakRef::is_weak_ref(weak_ref.get()));
+	assert!(!WeakRef::is_weak_ref(target.handle().get()));
+	assert_eq!(weak_ref.target(cx).unwrap().handle().get(), target.handle().get());
+
+	// Locals are rooted until their context is dropped, so the target is created with a temporary context.
+	let weak_ref = {
+		let cx = unsafe { Context::new_unchecked(cx.as_ptr()) };
+		TracedHeap::new(WeakRef::new(&cx, &Object::new(&cx)).unwrap().get())
+	};
+	unsafe { ClearKeptObjects(cx.as_ptr()) };
+	collect(cx);
+	assert!(WeakRef::from(weak_ref.to_local()).unwrap().target(cx).is_none());
+}
@@ -4,9 +4,304 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
+const {fail} = ______assertInternal______;
+
+function isDeepStrictEqual(actual, expected, seen) {
+	if (Object.is(actual, expected)) {
+		return true;
+	}
+	if (typeof actual !== "object" || typeof expected !== "object" || actual === null || expected === null) {
+		return false;
+	}
+	if (Object.getPrototypeOf(actual) !== Object.getPrototypeOf(expected)) {
+		return false;
+	}
+	if (seen.get(actual) === expected) {
+		return true;
+	}
+
+	// Pairs are only marked as seen while they are being compared, so a failed comparison is not remembered as equal.
+	const hadPrevious = seen.has(actual);
+	const previous = seen.get(actual);
+	seen.set(actual, expected);
+	try {
+		return isObjectEqual(actual, expected, seen);
+	} finally {
+		if (hadPrevious) {
+			seen.set(actual, previous);
+		} else {
+			seen.delete(actual);
+		}
+	}
+}
+
+function isObjectEqual(actual, expected, seen) {
+	if (Array.isArray(actual) && actual.length !== expected.length) {
+		return false;
+	}
+	if (actual instanceof Date) {
+		if (!Object.is(actual.getTime(), expected.getTime())) {
+			return false;
+		}
+	} else if (actual instanceof RegExp) {
+		if (actual.source !== expected.source || actual.flags !== expected.flags || actual.lastIndex !== expected.lastIndex) {
+			return false;
+		}
+	} else if (actual instanceof Error) {
+		if (actual.name !== expected.name || actual.message !== expected.message) {
+			return false;
+		}
+	} else if (actual instanceof Number || actual instanceof String || actual instanceof Boolean) {
+		if (!Object.is(actual.valueOf(), expected.valueOf())) {
+			return false;
+		}
+	} else if (ArrayBuffer.isView(actual) || actual instanceof ArrayBuffer) {
+		if (!isBufferEqual(actual, expected)) {
+			return false;
+		}
+		if (ArrayBuffer.isView(actual) && !(actual instanceof DataView)) {
+			return isKeysEqual(actual, expected, seen, key => !isIndex(key));
+		}
+	} else if (actual instanceof Map) {
+		if (!isMapEqual(actual, expected, seen)) {
+			return false;
+		}
+	} else if (actual instanceof Set) {
+		if (!isSetEqual(actual, expected, seen)) {
+			return false;
+		}
+	}
+
+	return isKeysEqual(actual, expected, seen, () => true);
+}
+
+function isIndex(key) {
+	return typeof key === "string" && String(Number(key)) === key && Number.isInteger(Number(key));
+}
+
+function isBufferEqual(actual, expected) {
+	if (actual.byteLength !== expected.byteLength) {
+		return false;
+	}
+	const actualBytes = ArrayBuffer.isView(actual) ? new Uint8Array(actual.buffer, actual.byteOffset, actual.byteLength) : new Uint8Array(actual);
+	const expectedBytes = ArrayBuffer.isView(expected) ? new Uint8Array(expected.buffer, expected.byteOffset, expected.byteLength) : new Uint8Array(expected);
+	for (let i = 0; i < actualBytes.length; i++) {
+		if (actualBytes[i] !== expectedBytes[i]) {
+			return false;
+		}
+	}
+	return true;
+}
+
+function isKeysEqual(actual, expected, seen, filter) {
+	const enumerable = object => Reflect.ownKeys(object).filter(key => Object.prototype.propertyIsEnumerable.call(object, key) && filter(key));
+	const actualKeys = enumerable(actual);
+	const expectedKeys = enumerable(expected);
+	if (actualKeys.length !== expectedKeys.length) {
+		return false;
+	}
+	for (const key of actualKeys) {
+		if (!Object.prototype.propertyIsEnumerable.call(expected, key)) {
+			return false;
+		}
+		if (!isDeepStrictEqual(actual[key], expected[key], seen)) {
+			return false;
+		}
+	}
+	return true;
+}
+
+function isMapEqual(actual, expected, seen) {
+	if (actual.size !== expected.size) {
+		return false;
+	}
+	const unmatched = [];
+	for (const [key, value] of actual) {
+		if (typeof key !== "object" || key === null) {
+			if (!expected.has(key) || !isDeepStrictEqual(value, expected.get(key), seen)) {
+				return false;
+			}
+		} else {
+			unmatched.push([key, value]);
+		}
+	}
+	const candidates = [...expected].filter(([key]) => typeof key === "object" && key !== null);
+	return unmatched.every(([key, value]) => {
+		const index = candidates.findIndex(([k, v]) => isDeepStrictEqual(key, k, seen) && isDeepStrictEqual(value, v, seen));
+		return index !== -1 && candidates.splice(index, 1);
+	});
+}
+
+function isSetEqual(actual, expected, seen) {
+	if (actual.size !== expected.size) {
+		return false;
+	}
+	const unmatched = [];
+	for (const value of actual) {
+		if (typeof value !== "object" || value === null) {
+			if (!expected.has(value)) {
+				return false;
+			}
+		} else {
+			unmatched.push(value);
+		}
+	}
+	const candidates = [...expected].filter(value => typeof value === "object" && value !== null);
+	return unmatched.every(value => {
+		const index = candidates.findIndex(candidate => isDeepStrictEqual(value, candidate, seen));
+		return index !== -1 && candidates.splice(index, 1);
+	});
+}
+
+function deepStrictEqual(actual, expected, message) {
+	if (!isDeepStrictEqual(actual, expected, new Map())) {
+		fail(message);
+	}
+}
+
+function notDeepStrictEqual(actual, expected, message) {
+	if (isDeepStrictEqual(actual, expected, new Map())) {
+		fail(message);
+	}
+}
+
+function isExpectedError(error, expected) {
+	if (expected === undefined) {
+		return true;
+	}
+	if (expected instanceof RegExp) {
+		return expected.test(String(error));
+	}
+	if (typeof expected === "function") {
+		return error instanceof expected;
+	}
+	if (typeof expected === "object" && expected !== null && typeof error === "object" && error !== null) {
+		return Reflect.ownKeys(expected).every(key => {
+			const value = expected[key];
+			return value instanceof RegExp && typeof error[key] === "string" ? value.test(error[key]) : isDeepStrictEqual(error[key], value, new Map());
+		});
+	}
+	return false;
+}
+
+function throws(func, expected, message) {
+	if (typeof expected === "string" && message === undefined) {
+		[expected, message] = [undefined, expected];
+	}
+	try {
+		func();
+	} catch (error) {
+		if (!isExpectedError(error, expected)) {
+			fail(message);
+		}
+		return;
+	}
+	fail(message);
+}
+
+async function rejects(promise, expected, message) {
+	if (typeof expected === "string" && message === undefined) {
+		[expected, message] = [undefined, expected];
+	}
+	try {
+		await (typeof promise === "function" ? promise() : promise);
+	} catch (error) {
+		if (!isExpectedError(error, expected)) {
+			fail(message);
+		}
+		return;
+	}
+	fail(message);
+}
+
+function match(string, regexp, message) {
+	if (typeof string !== "string" || !regexp.test(string)) {
+		fail(message);
+	}
+}
+
+function serialize(value, indent, seen) {
+	const inner = `${indent}\t`;
+	switch (typeof value) {
+		case "string":
+			return JSON.stringify(value);
+		case "bigint":
+			return `${value}n`;
+		case "symbol":
+		case "undefined":
+		case "boolean":
+			return String(value);
+		case "number":
+			return Object.is(value, -0) ? "-0" : String(value);
+		case "function":
+			return `[Function ${value.name || "(anonymous)"}]`;
+	}
+	if (value === null) {
+		return "null";
+	}
+	if (seen.has(value)) {
+		return "[Circular]";
+	}
+	seen.add(value);
+
+	const entries = items => (items.length === 0 ? "" : `\n${items.map(item => `${inner}${item},\n`).join("")}${indent}`);
+	let result;
+	if (value instanceof Date) {
+		result = `Date(${value.toISOString()})`;
+	} else if (value instanceof RegExp) {
+		result = String(value);
+	} else if (value instanceof Error) {
+		result = `${value.name}(${JSON.stringify(value.message)})`;
+	} else if (Array.isArray(value)) {
+		result = `[${entries(value.map(item => serialize(item, inner, seen)))}]`;
+	} else if (ArrayBuffer.isView(value) && !(value instanceof DataView)) {
+		result = `${value.constructor.name} [${Array.from(value, String).join(", ")}]`;
+	} else if (value instanceof Map) {
+		const items = [...value].map(([key, item]) => `${serialize(key, inner, seen)} => ${serialize(item, inner, seen)}`);
+		result = `Map {${entries(items)}}`;
+	} else if (value instanceof Set) {
+		result = `Set {${entries([...value].map(item => serialize(item, inner, seen)))}}`;
+	} else {
+		const keys = Object.keys(value).sort();
+		const name = value.constructor?.name && value.constructor !== Object ? `${value.constructor.name} ` : "";
+		result = `${name}{${entries(keys.map(key => `${JSON.stringify(key)}: ${serialize(value[key], inner, seen)}`))}}`;
+	}
+	seen.delete(value);
+	return result;
+}
+
+const snapshotFiles = new Map();
+
+function matchSnapshot(actual, name, message) {
+	const {path, contents, update} = ______assertInternal______.loadSnapshots();
+	let snapshots = snapshotFiles.get(path);
+	if (snapshots === undefined) {
+		snapshots = contents === null ? {} : JSON.parse(contents);
+		snapshotFiles.set(path, snapshots);
+	}
+
+	const serialized = serialize(actual, "", new Set());
+	if (update || !Object.prototype.hasOwnProperty.call(snapshots, name)) {
+		snapshots[name] = serialized;
+		const sorted = Object.fromEntries(Object.entries(snapshots).sort(([a], [b]) => (a < b ? -1 : a > b ? 1 : 0)));
+		______assertInternal______.saveSnapshots(path, `${JSON.stringify(sorted, null, "\t")}\n`);
+	} else if (snapshots[name] !== serialized) {
+		fail(message ?? `Snapshot ${JSON.stringify(name)} does not match\nExpected: ${snapshots[name]}\nReceived: ${serialized}`);
+	}
+}
+
+Object.defineProperties(______assertInternal______, {
+	deepStrictEqual: {value: deepStrictEqual, enumerable: true},
+	notDeepStrictEqual: {value: notDeepStrictEqual, enumerable: true},
+	throws: {value: throws, enumerable: true},
+	rejects: {value: rejects, enumerable: true},
+	match: {value: match, enumerable: true},
+	matchSnapshot: {value: matchSnapshot, enumerable: true},
+});
+
 export const ok = ______assertInternal______.ok;
 export const equals = ______assertInternal______.equals;
-export const throws = ______assertInternal______.throws;
-export const fail = ______assertInternal______.fail;
+export {deepStrictEqual, notDeepStrictEqual, throws, rejects, match, matchSnapshot};
+export {fail};
 
 export default Object.freeze(______assertInternal______);
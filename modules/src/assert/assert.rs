@@ -4,8 +4,11 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
 use ion::function::Opt;
-use ion::{Context, Error, Function, Object, Result, Value};
+use ion::{Context, Error, Object, Result, Stack, Value};
 use mozjs::jsapi::JSFunctionSpec;
 use runtime::module::NativeModule;
 
@@ -28,7 +31,7 @@ fn ok(Opt(assertion): Opt<bool>, Opt(message): Opt<String>) -> Result<()> {
 
 #[js_fn]
 fn equals(cx: &Context, actual: Value, expected: Value, Opt(message): Opt<String>) -> Result<()> {
-	if actual.is_same(cx, &expected) {
+	if actual.same_value(cx, &expected) {
 		Ok(())
 	} else {
 		assert_internal(message)
@@ -36,24 +39,62 @@ fn equals(cx: &Context, actual: Value, expected: Value, Opt(message): Opt<String
 }
 
 #[js_fn]
-fn throws(cx: &Context, func: Function, Opt(message): Opt<String>) -> Result<()> {
-	if func.call(cx, &Object::global(cx), &[]).is_err() {
-		assert_internal(message)
-	} else {
-		Ok(())
-	}
+fn fail(Opt(message): Opt<String>) -> Result<()> {
+	assert_internal(message)
+}
+
+/// Returns the path of the snapshot file for the script calling into the assert module.
+/// Snapshots for `dir/script.js` are stored in `dir/__snapshots__/script.js.snap`.
+fn snapshot_path(cx: &Context) -> Result<PathBuf> {
+	let stack = Stack::from_capture(cx).ok_or_else(|| Error::new("Could not capture stack for snapshot", None))?;
+	let mut files = stack.records.iter().map(|record| record.location.file.as_str());
+	let internal = files.next();
+	let file = files
+		.find(|file| Some(*file) != internal)
+		.ok_or_else(|| Error::new("Could not determine script for snapshot", None))?;
+
+	let file = Path::new(file.strip_prefix("file://").unwrap_or(file));
+	let name = file
+		.file_name()
+		.ok_or_else(|| Error::new("Could not determine script for snapshot", None))?;
+	let mut snapshot = file.with_file_name("__snapshots__");
+	snapshot.push(format!("{}.snap", name.to_string_lossy()));
+	Ok(snapshot)
 }
 
 #[js_fn]
-fn fail(Opt(message): Opt<String>) -> Result<()> {
-	assert_internal(message)
+fn load_snapshots(cx: &Context) -> Result<Object> {
+	let path = snapshot_path(cx)?;
+	let contents = match fs::read_to_string(&path) {
+		Ok(contents) => Some(contents),
+		Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+		Err(err) => return Err(err.into()),
+	};
+	let update = env::var_os("SPIDERFIRE_UPDATE_SNAPSHOTS").is_some_and(|update| !update.is_empty());
+
+	let snapshots = Object::new(cx);
+	snapshots.set_as(cx, "path", &path.to_string_lossy().into_owned());
+	snapshots.set_as(cx, "contents", &contents);
+	snapshots.set_as(cx, "update", &update);
+	Ok(snapshots)
+}
+
+#[js_fn]
+fn save_snapshots(path: String, contents: String) -> Result<()> {
+	let path = Path::new(&path);
+	if let Some(parent) = path.parent() {
+		fs::create_dir_all(parent)?;
+	}
+	fs::write(path, contents)?;
+	Ok(())
 }
 
 const FUNCTIONS: &[JSFunctionSpec] = &[
 	function_spec!(ok, 0),
 	function_spec!(equals, 2),
-	function_spec!(throws, 1),
 	function_spec!(fail, 0),
+	function_spec!(load_snapshots, "loadSnapshots", 0),
+	function_spec!(save_snapshots, "saveSnapshots", 2),
 	JSFunctionSpec::ZERO,
 ];
 
@@ -0,0 +1,240 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+const {encode, decode, byteLength, isEncoding} = ______bufferInternal______;
+
+function checkOffset(buffer, offset, size) {
+	if (!Number.isInteger(offset) || offset < 0 || offset + size > buffer.length) {
+		throw new RangeError(`Offset ${offset} is out of range for buffer of length ${buffer.length}`);
+	}
+}
+
+function view(buffer) {
+	return new DataView(buffer.buffer, buffer.byteOffset, buffer.byteLength);
+}
+
+function toBytes(value, encoding) {
+	if (typeof value === "string") {
+		return encode(value, encoding);
+	}
+	if (typeof value === "number") {
+		return Uint8Array.of(value & 0xFF);
+	}
+	if (ArrayBuffer.isView(value)) {
+		return new Uint8Array(value.buffer, value.byteOffset, value.byteLength);
+	}
+	throw new TypeError("Expected a string, number, Buffer or Uint8Array");
+}
+
+function compareBytes(a, b) {
+	const length = Math.min(a.length, b.length);
+	for (let i = 0; i < length; i++) {
+		if (a[i] !== b[i]) {
+			return a[i] < b[i] ? -1 : 1;
+		}
+	}
+	return a.length === b.length ? 0 : a.length < b.length ? -1 : 1;
+}
+
+class Buffer extends Uint8Array {
+	static from(value, encodingOrOffset, length) {
+		if (typeof value === "string") {
+			const bytes = encode(value, encodingOrOffset);
+			return new Buffer(bytes.buffer, bytes.byteOffset, bytes.byteLength);
+		}
+		if (value instanceof ArrayBuffer || value instanceof SharedArrayBuffer) {
+			const offset = encodingOrOffset ?? 0;
+			return new Buffer(value, offset, length ?? value.byteLength - offset);
+		}
+		if (ArrayBuffer.isView(value)) {
+			const buffer = Buffer.allocUnsafe(value.byteLength);
+			buffer.set(new Uint8Array(value.buffer, value.byteOffset, value.byteLength));
+			return buffer;
+		}
+		if (value !== null && typeof value === "object") {
+			if (value.type === "Buffer" && Array.isArray(value.data)) {
+				return Buffer.from(value.data);
+			}
+			return super.from(value, byte => byte & 0xFF);
+		}
+		throw new TypeError("Expected a string, Array, ArrayBuffer or Buffer");
+	}
+
+	static alloc(size, fill, encoding) {
+		const buffer = new Buffer(size);
+		if (fill !== undefined && fill !== 0) {
+			buffer.fill(fill, 0, size, encoding);
+		}
+		return buffer;
+	}
+
+	static allocUnsafe(size) {
+		return new Buffer(size);
+	}
+
+	static concat(list, totalLength) {
+		totalLength ??= list.reduce((length, buffer) => length + buffer.length, 0);
+		const result = Buffer.alloc(totalLength);
+		let offset = 0;
+		for (const buffer of list) {
+			if (offset >= totalLength) {
+				break;
+			}
+			const bytes = buffer.subarray(0, totalLength - offset);
+			result.set(bytes, offset);
+			offset += bytes.length;
+		}
+		return result;
+	}
+
+	static isBuffer(value) {
+		return value instanceof Buffer;
+	}
+
+	static isEncoding(encoding) {
+		return typeof encoding === "string" && isEncoding(encoding);
+	}
+
+	static byteLength(value, encoding) {
+		if (typeof value === "string") {
+			return byteLength(value, encoding);
+		}
+		return value.byteLength;
+	}
+
+	static compare(a, b) {
+		return compareBytes(a, b);
+	}
+
+	toString(encoding, start = 0, end = this.length) {
+		start = Math.max(0, start);
+		end = Math.min(this.length, end);
+		if (end <= start) {
+			return "";
+		}
+		return decode(this.subarray(start, end), encoding);
+	}
+
+	toJSON() {
+		return {type: "Buffer", data: Array.from(this)};
+	}
+
+	equals(other) {
+		return compareBytes(this, other) === 0;
+	}
+
+	compare(target, targetStart = 0, targetEnd = target.length, sourceStart = 0, sourceEnd = this.length) {
+		return compareBytes(this.subarray(sourceStart, sourceEnd), target.subarray(targetStart, targetEnd));
+	}
+
+	indexOf(value, byteOffset = 0, encoding) {
+		if (typeof byteOffset === "string") {
+			[byteOffset, encoding] = [0, byteOffset];
+		}
+		if (byteOffset < 0) {
+			byteOffset = Math.max(0, this.length + byteOffset);
+		}
+		if (typeof value === "number") {
+			return super.indexOf(value & 0xFF, byteOffset);
+		}
+
+		const needle = toBytes(value, encoding);
+		if (needle.length === 0) {
+			return Math.min(byteOffset, this.length);
+		}
+		outer: for (let i = byteOffset; i <= this.length - needle.length; i++) {
+			for (let j = 0; j < needle.length; j++) {
+				if (this[i + j] !== needle[j]) {
+					continue outer;
+				}
+			}
+			return i;
+		}
+		return -1;
+	}
+
+	includes(value, byteOffset, encoding) {
+		return this.indexOf(value, byteOffset, encoding) !== -1;
+	}
+
+	fill(value, offset = 0, end = this.length, encoding) {
+		if (typeof offset === "string") {
+			[offset, end, encoding] = [0, this.length, offset];
+		} else if (typeof end === "string") {
+			[end, encoding] = [this.length, end];
+		}
+		if (typeof value === "number") {
+			return super.fill(value & 0xFF, offset, end);
+		}
+		const bytes = toBytes(value, encoding);
+		if (bytes.length === 0) {
+			return super.fill(0, offset, end);
+		}
+		for (let i = offset; i < end; i++) {
+			this[i] = bytes[(i - offset) % bytes.length];
+		}
+		return this;
+	}
+
+	write(string, offset = 0, length = this.length - offset, encoding = "utf8") {
+		if (typeof offset === "string") {
+			[offset, length, encoding] = [0, this.length, offset];
+		} else if (typeof length === "string") {
+			[length, encoding] = [this.length - offset, length];
+		}
+		const bytes = encode(string, encoding).subarray(0, Math.min(length, this.length - offset));
+		this.set(bytes, offset);
+		return bytes.length;
+	}
+
+	slice(start, end) {
+		return this.subarray(start, end);
+	}
+}
+
+const accessors = [
+	["Int8", 1, "Int8"],
+	["UInt8", 1, "Uint8"],
+	["Int16", 2, "Int16"],
+	["UInt16", 2, "Uint16"],
+	["Int32", 4, "Int32"],
+	["UInt32", 4, "Uint32"],
+	["Float", 4, "Float32"],
+	["Double", 8, "Float64"],
+	["BigInt64", 8, "BigInt64"],
+	["BigUInt64", 8, "BigUint64"],
+];
+
+for (const [name, size, method] of accessors) {
+	const endians = size === 1 ? [["", false]] : [["LE", true], ["BE", false]];
+	for (const [suffix, littleEndian] of endians) {
+		Object.defineProperty(Buffer.prototype, `read${name}${suffix}`, {
+			value(offset = 0) {
+				checkOffset(this, offset, size);
+				return view(this)[`get${method}`](offset, littleEndian);
+			},
+			configurable: true,
+			writable: true,
+		});
+		Object.defineProperty(Buffer.prototype, `write${name}${suffix}`, {
+			value(value, offset = 0) {
+				checkOffset(this, offset, size);
+				view(this)[`set${method}`](offset, value, littleEndian);
+				return offset + size;
+			},
+			configurable: true,
+			writable: true,
+		});
+	}
+}
+
+Object.defineProperty(______bufferInternal______, "Buffer", {
+	value: Buffer,
+	enumerable: true,
+});
+
+export {Buffer};
+export default Object.freeze(______bufferInternal______);
@@ -0,0 +1,172 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use base64::alphabet::{STANDARD, URL_SAFE};
+use base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig};
+use base64::engine::DecodePaddingMode;
+use base64::Engine;
+use ion::function::Opt;
+use ion::typedarray::Uint8ArrayWrapper;
+use ion::{Context, Error, ErrorKind, Object, Result};
+use mozjs::jsapi::JSFunctionSpec;
+use runtime::globals::file::BufferSource;
+use runtime::module::NativeModule;
+
+const BASE64_CONFIG: GeneralPurposeConfig = GeneralPurposeConfig::new()
+	.with_encode_padding(true)
+	.with_decode_padding_mode(DecodePaddingMode::Indifferent)
+	.with_decode_allow_trailing_bits(true);
+const BASE64: GeneralPurpose = GeneralPurpose::new(&STANDARD, BASE64_CONFIG);
+const BASE64_URL: GeneralPurpose = GeneralPurpose::new(&URL_SAFE, BASE64_CONFIG.with_encode_padding(false));
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Encoding {
+	Utf8,
+	Utf16Le,
+	Latin1,
+	Ascii,
+	Base64,
+	Base64Url,
+	Hex,
+}
+
+impl Encoding {
+	fn parse(encoding: Option<&str>) -> Result<Encoding> {
+		let Some(encoding) = encoding else {
+			return Ok(Encoding::Utf8);
+		};
+		match encoding.to_ascii_lowercase().as_str() {
+			"utf8" | "utf-8" => Ok(Encoding::Utf8),
+			"utf16le" | "utf-16le" | "ucs2" | "ucs-2" => Ok(Encoding::Utf16Le),
+			"latin1" | "binary" => Ok(Encoding::Latin1),
+			"ascii" => Ok(Encoding::Ascii),
+			"base64" => Ok(Encoding::Base64),
+			"base64url" => Ok(Encoding::Base64Url),
+			"hex" => Ok(Encoding::Hex),
+			_ => Err(Error::new(format!("Unknown encoding: {encoding}"), ErrorKind::Type)),
+		}
+	}
+
+	fn encode(self, string: &str) -> Vec<u8> {
+		match self {
+			Encoding::Utf8 => string.as_bytes().to_vec(),
+			Encoding::Utf16Le => string.encode_utf16().flat_map(u16::to_le_bytes).collect(),
+			Encoding::Latin1 | Encoding::Ascii => string.encode_utf16().map(|unit| unit as u8).collect(),
+			Encoding::Base64 | Encoding::Base64Url => decode_base64(string),
+			Encoding::Hex => decode_hex(string),
+		}
+	}
+
+	fn decode(self, bytes: &[u8]) -> String {
+		match self {
+			Encoding::Utf8 => String::from_utf8_lossy(bytes).into_owned(),
+			Encoding::Utf16Le => {
+				let units: Vec<u16> =
+					bytes.chunks_exact(2).map(|unit| u16::from_le_bytes([unit[0], unit[1]])).collect();
+				String::from_utf16_lossy(&units)
+			}
+			Encoding::Latin1 => bytes.iter().map(|&byte| char::from(byte)).collect(),
+			Encoding::Ascii => bytes.iter().map(|&byte| char::from(byte & 0x7F)).collect(),
+			Encoding::Base64 => BASE64.encode(bytes),
+			Encoding::Base64Url => BASE64_URL.encode(bytes),
+			Encoding::Hex => {
+				const HEX: &[u8; 16] = b"0123456789abcdef";
+				let mut hex = String::with_capacity(bytes.len() * 2);
+				for byte in bytes {
+					hex.push(char::from(HEX[usize::from(byte >> 4)]));
+					hex.push(char::from(HEX[usize::from(byte & 0xF)]));
+				}
+				hex
+			}
+		}
+	}
+}
+
+/// Decodes base64 leniently, accepting both alphabets and ignoring whitespace and characters after padding.
+fn decode_base64(string: &str) -> Vec<u8> {
+	let string: String = string
+		.chars()
+		.take_while(|&char| char != '=')
+		.filter(|char| !char.is_ascii_whitespace())
+		.map(|char| match char {
+			'-' => '+',
+			'_' => '/',
+			char => char,
+		})
+		.collect();
+	let valid = string.find(|char: char| !(char.is_ascii_alphanumeric() || char == '+' || char == '/'));
+	let string = &string[..valid.unwrap_or(string.len())];
+	let string = if string.len() % 4 == 1 {
+		&string[..string.len() - 1]
+	} else {
+		string
+	};
+	BASE64.decode(string).unwrap_or_default()
+}
+
+/// Decodes hexadecimal pairs until the first invalid pair.
+fn decode_hex(string: &str) -> Vec<u8> {
+	string
+		.as_bytes()
+		.chunks_exact(2)
+		.map_while(|pair| {
+			let high = char::from(pair[0]).to_digit(16)?;
+			let low = char::from(pair[1]).to_digit(16)?;
+			Some((high * 16 + low) as u8)
+		})
+		.collect()
+}
+
+#[js_fn]
+fn encode(string: String, Opt(encoding): Opt<String>) -> Result<Uint8ArrayWrapper> {
+	let encoding = Encoding::parse(encoding.as_deref())?;
+	Ok(Uint8ArrayWrapper::from(encoding.encode(&string)))
+}
+
+#[js_fn]
+fn decode(#[ion(convert = true)] bytes: BufferSource, Opt(encoding): Opt<String>) -> Result<String> {
+	let encoding = Encoding::parse(encoding.as_deref())?;
+	Ok(encoding.decode(&bytes.to_vec()))
+}
+
+#[js_fn]
+fn byte_length(string: String, Opt(encoding): Opt<String>) -> Result<u64> {
+	let encoding = Encoding::parse(encoding.as_deref())?;
+	let length = match encoding {
+		Encoding::Utf8 => string.len(),
+		Encoding::Utf16Le => string.encode_utf16().count() * 2,
+		Encoding::Latin1 | Encoding::Ascii => string.encode_utf16().count(),
+		_ => encoding.encode(&string).len(),
+	};
+	Ok(length as u64)
+}
+
+#[js_fn]
+fn is_encoding(encoding: String) -> bool {
+	Encoding::parse(Some(&encoding)).is_ok()
+}
+
+const FUNCTIONS: &[JSFunctionSpec] = &[
+	function_spec!(encode, 1),
+	function_spec!(decode, 1),
+	function_spec!(byte_length, "byteLength", 1),
+	function_spec!(is_encoding, "isEncoding", 1),
+	JSFunctionSpec::ZERO,
+];
+
+#[derive(Default)]
+pub struct BufferM;
+
+impl NativeModule for BufferM {
+	const NAME: &'static str = "buffer";
+	const VARIABLE_NAME: &'static str = "buffer";
+	const SOURCE: &'static str = include_str!("buffer.js");
+
+	fn module(cx: &Context) -> Option<Object> {
+		let buffer = Object::new(cx);
+		unsafe { buffer.define_methods(cx, FUNCTIONS).then_some(buffer) }
+	}
+}
@@ -0,0 +1,15 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+export const lookup = ______dnsInternal______.lookup;
+export const resolve4 = ______dnsInternal______.resolve4;
+export const resolve6 = ______dnsInternal______.resolve6;
+export const resolveMx = ______dnsInternal______.resolveMx;
+export const resolveTxt = ______dnsInternal______.resolveTxt;
+export const resolveSrv = ______dnsInternal______.resolveSrv;
+export const reverse = ______dnsInternal______.reverse;
+
+export default Object.freeze(______dnsInternal______);
@@ -0,0 +1,261 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashSet;
+use std::net::{IpAddr, SocketAddr};
+
+use hickory_resolver::config::{NameServerConfigGroup, ResolverConfig, ResolverOpts};
+use hickory_resolver::error::ResolveError;
+use hickory_resolver::TokioAsyncResolver;
+use ion::conversions::{ConversionBehavior, ToValue};
+use ion::function::Opt;
+use ion::{Context, Error, ErrorKind, Object, Promise, Result, Value};
+use mozjs::jsapi::JSFunctionSpec;
+use runtime::module::NativeModule;
+use runtime::promise::future_to_promise;
+use tokio::net::lookup_host;
+
+const DNS_PORT: u16 = 53;
+
+fn resolve_error(hostname: &str, err: ResolveError) -> Error {
+	Error::new(format!("Could not resolve {hostname}\n{err}"), None)
+}
+
+#[derive(Default, FromValue)]
+pub struct ResolveOptions {
+	nameserver: Option<String>,
+}
+
+impl ResolveOptions {
+	fn nameserver(&self) -> Result<Option<SocketAddr>> {
+		let Some(nameserver) = &self.nameserver else {
+			return Ok(None);
+		};
+		if let Ok(address) = nameserver.parse() {
+			Ok(Some(address))
+		} else if let Ok(ip) = nameserver.parse::<IpAddr>() {
+			Ok(Some(SocketAddr::new(ip, DNS_PORT)))
+		} else {
+			Err(Error::new(format!("Invalid nameserver: {nameserver}"), ErrorKind::Type))
+		}
+	}
+
+	/// Creates a resolver using the given nameserver, or the system configuration if none was specified.
+	fn resolver(&self) -> Result<TokioAsyncResolver> {
+		match self.nameserver()? {
+			Some(nameserver) => {
+				let group = NameServerConfigGroup::from_ips_clear(&[nameserver.ip()], nameserver.port(), true);
+				let config = ResolverConfig::from_parts(None, Vec::new(), group);
+				Ok(TokioAsyncResolver::tokio(config, ResolverOpts::default()))
+			}
+			None => TokioAsyncResolver::tokio_from_system_conf()
+				.map_err(|err| Error::new(format!("Could not read system DNS configuration\n{err}"), None)),
+		}
+	}
+}
+
+#[derive(Default, FromValue)]
+pub struct LookupOptions {
+	#[ion(convert = ConversionBehavior::EnforceRange)]
+	family: Option<u8>,
+	nameserver: Option<String>,
+}
+
+pub struct MxRecord {
+	exchange: String,
+	priority: u16,
+}
+
+impl<'cx> ToValue<'cx> for MxRecord {
+	fn to_value(&self, cx: &'cx Context, value: &mut Value) {
+		let object = Object::new(cx);
+		object.set_as(cx, "exchange", &self.exchange);
+		object.set_as(cx, "priority", &self.priority);
+		object.to_value(cx, value);
+	}
+}
+
+pub struct SrvRecord {
+	name: String,
+	port: u16,
+	priority: u16,
+	weight: u16,
+}
+
+impl<'cx> ToValue<'cx> for SrvRecord {
+	fn to_value(&self, cx: &'cx Context, value: &mut Value) {
+		let object = Object::new(cx);
+		object.set_as(cx, "name", &self.name);
+		object.set_as(cx, "port", &self.port);
+		object.set_as(cx, "priority", &self.priority);
+		object.set_as(cx, "weight", &self.weight);
+		object.to_value(cx, value);
+	}
+}
+
+fn to_domain(name: &hickory_resolver::Name) -> String {
+	let name = name.to_utf8();
+	name.strip_suffix('.').map(String::from).unwrap_or(name)
+}
+
+/// Resolves a hostname to its IP addresses, optionally restricted to IPv4 (`4`) or IPv6 (`6`).
+/// Uses the operating system's resolver unless a nameserver is specified.
+#[js_fn]
+fn lookup<'cx>(cx: &'cx Context, hostname: String, Opt(options): Opt<LookupOptions>) -> Result<Option<Promise<'cx>>> {
+	let options = options.unwrap_or_default();
+	let family = options.family;
+	if !matches!(family, None | Some(4 | 6)) {
+		return Err(Error::new("Expected 4 or 6 for family", ErrorKind::Type));
+	}
+	let resolver = match options.nameserver {
+		Some(nameserver) => Some(ResolveOptions { nameserver: Some(nameserver) }.resolver()?),
+		None => None,
+	};
+
+	Ok(future_to_promise::<_, _, Error>(cx, async move {
+		let addresses: Vec<IpAddr> = match resolver {
+			Some(resolver) => {
+				let lookup = resolver.lookup_ip(hostname.as_str()).await;
+				lookup.map_err(|err| resolve_error(&hostname, err))?.iter().collect()
+			}
+			None => {
+				let lookup = lookup_host((hostname.as_str(), 0)).await;
+				let lookup = lookup.map_err(|err| Error::new(format!("Could not resolve {hostname}\n{err}"), None))?;
+				lookup.map(|address| address.ip()).collect()
+			}
+		};
+
+		// The resolver may return an address once for each socket type, so duplicates are removed, keeping its order.
+		let mut unique = HashSet::new();
+		let addresses: Vec<String> = addresses
+			.into_iter()
+			.filter(|address| match family {
+				Some(4) => address.is_ipv4(),
+				Some(6) => address.is_ipv6(),
+				_ => true,
+			})
+			.filter(|address| unique.insert(*address))
+			.map(|address| address.to_string())
+			.collect();
+		Ok(addresses)
+	}))
+}
+
+#[js_fn]
+fn resolve4<'cx>(
+	cx: &'cx Context, hostname: String, Opt(options): Opt<ResolveOptions>,
+) -> Result<Option<Promise<'cx>>> {
+	let resolver = options.unwrap_or_default().resolver()?;
+	Ok(future_to_promise::<_, _, Error>(cx, async move {
+		let lookup = resolver.ipv4_lookup(hostname.as_str()).await;
+		let lookup = lookup.map_err(|err| resolve_error(&hostname, err))?;
+		Ok(lookup.iter().map(|address| address.to_string()).collect::<Vec<_>>())
+	}))
+}
+
+#[js_fn]
+fn resolve6<'cx>(
+	cx: &'cx Context, hostname: String, Opt(options): Opt<ResolveOptions>,
+) -> Result<Option<Promise<'cx>>> {
+	let resolver = options.unwrap_or_default().resolver()?;
+	Ok(future_to_promise::<_, _, Error>(cx, async move {
+		let lookup = resolver.ipv6_lookup(hostname.as_str()).await;
+		let lookup = lookup.map_err(|err| resolve_error(&hostname, err))?;
+		Ok(lookup.iter().map(|address| address.to_string()).collect::<Vec<_>>())
+	}))
+}
+
+#[js_fn]
+fn resolve_mx<'cx>(
+	cx: &'cx Context, hostname: String, Opt(options): Opt<ResolveOptions>,
+) -> Result<Option<Promise<'cx>>> {
+	let resolver = options.unwrap_or_default().resolver()?;
+	Ok(future_to_promise::<_, _, Error>(cx, async move {
+		let lookup = resolver.mx_lookup(hostname.as_str()).await;
+		let lookup = lookup.map_err(|err| resolve_error(&hostname, err))?;
+		let records = lookup.iter().map(|mx| MxRecord {
+			exchange: to_domain(mx.exchange()),
+			priority: mx.preference(),
+		});
+		Ok(records.collect::<Vec<_>>())
+	}))
+}
+
+#[js_fn]
+fn resolve_txt<'cx>(
+	cx: &'cx Context, hostname: String, Opt(options): Opt<ResolveOptions>,
+) -> Result<Option<Promise<'cx>>> {
+	let resolver = options.unwrap_or_default().resolver()?;
+	Ok(future_to_promise::<_, _, Error>(cx, async move {
+		let lookup = resolver.txt_lookup(hostname.as_str()).await;
+		let lookup = lookup.map_err(|err| resolve_error(&hostname, err))?;
+		let records = lookup.iter().map(|txt| {
+			txt.txt_data()
+				.iter()
+				.map(|data| String::from_utf8_lossy(data).into_owned())
+				.collect::<Vec<_>>()
+		});
+		Ok(records.collect::<Vec<_>>())
+	}))
+}
+
+#[js_fn]
+fn resolve_srv<'cx>(
+	cx: &'cx Context, hostname: String, Opt(options): Opt<ResolveOptions>,
+) -> Result<Option<Promise<'cx>>> {
+	let resolver = options.unwrap_or_default().resolver()?;
+	Ok(future_to_promise::<_, _, Error>(cx, async move {
+		let lookup = resolver.srv_lookup(hostname.as_str()).await;
+		let lookup = lookup.map_err(|err| resolve_error(&hostname, err))?;
+		let records = lookup.iter().map(|srv| SrvRecord {
+			name: to_domain(srv.target()),
+			port: srv.port(),
+			priority: srv.priority(),
+			weight: srv.weight(),
+		});
+		Ok(records.collect::<Vec<_>>())
+	}))
+}
+
+#[js_fn]
+fn reverse<'cx>(cx: &'cx Context, ip: String, Opt(options): Opt<ResolveOptions>) -> Result<Option<Promise<'cx>>> {
+	let address: IpAddr = ip.parse().map_err(|_| Error::new(format!("Invalid IP address: {ip}"), ErrorKind::Type))?;
+	let resolver = options.unwrap_or_default().resolver()?;
+	Ok(future_to_promise::<_, _, Error>(cx, async move {
+		let lookup = resolver.reverse_lookup(address).await;
+		let lookup = lookup.map_err(|err| resolve_error(&ip, err))?;
+		Ok(lookup.iter().map(|ptr| to_domain(&ptr.0)).collect::<Vec<_>>())
+	}))
+}
+
+const FUNCTIONS: &[JSFunctionSpec] = &[
+	function_spec!(lookup, 1),
+	function_spec!(resolve4, 1),
+	function_spec!(resolve6, 1),
+	function_spec!(resolve_mx, "resolveMx", 1),
+	function_spec!(resolve_txt, "resolveTxt", 1),
+	function_spec!(resolve_srv, "resolveSrv", 1),
+	function_spec!(reverse, 1),
+	JSFunctionSpec::ZERO,
+];
+
+#[derive(Default)]
+pub struct Dns;
+
+impl NativeModule for Dns {
+	const NAME: &'static str = "dns";
+	const VARIABLE_NAME: &'static str = "dns";
+	const SOURCE: &'static str = include_str!("dns.js");
+
+	fn module(cx: &Context) -> Option<Object> {
+		let dns = Object::new(cx);
+		if unsafe { dns.define_methods(cx, FUNCTIONS) } {
+			Some(dns)
+		} else {
+			None
+		}
+	}
+}
@@ -27,15 +27,13 @@ fn read_dir_error(path: &str, err: io::Error) -> Error {
 }
 
 #[js_fn]
-fn read_binary(cx: &Context, path_str: String) -> Option<Promise> {
-	future_to_promise(cx, async move {
-		let path = Path::new(&path_str);
+async fn read_binary(path_str: String) -> Result<Uint8ArrayWrapper> {
+	let path = Path::new(&path_str);
 
-		match tokio::fs::read(path).await {
-			Ok(bytes) => Ok(Uint8ArrayWrapper::from(bytes)),
-			Err(err) => Err(read_file_error(&path_str, err)),
-		}
-	})
+	match tokio::fs::read(path).await {
+		Ok(bytes) => Ok(Uint8ArrayWrapper::from(bytes)),
+		Err(err) => Err(read_file_error(&path_str, err)),
+	}
 }
 
 #[js_fn]
@@ -49,12 +47,10 @@ fn read_binary_sync(path_str: String) -> Result<Uint8ArrayWrapper> {
 }
 
 #[js_fn]
-fn read_string(cx: &Context, path_str: String) -> Option<Promise> {
-	future_to_promise(cx, async move {
-		let path = Path::new(&path_str);
+async fn read_string(path_str: String) -> Result<String> {
+	let path = Path::new(&path_str);
 
-		tokio::fs::read_to_string(path).await.map_err(|err| read_file_error(&path_str, err))
-	})
+	tokio::fs::read_to_string(path).await.map_err(|err| read_file_error(&path_str, err))
 }
 
 #[js_fn]
@@ -65,24 +61,22 @@ fn read_string_sync(path_str: String) -> Result<String> {
 }
 
 #[js_fn]
-fn read_dir(cx: &Context, path_str: String) -> Option<Promise> {
-	future_to_promise::<_, _, Error>(cx, async move {
-		let path = Path::new(&path_str);
+async fn read_dir(path_str: String) -> Result<Vec<String>> {
+	let path = Path::new(&path_str);
 
-		match tokio::fs::read_dir(path).await {
-			Ok(dir) => {
-				let mut entries: Vec<_> = ReadDirStream::new(dir)
-					.filter_map(|entry| async move { entry.ok() })
-					.map(|entry| entry.file_name().into_string().unwrap())
-					.collect()
-					.await;
-				entries.sort();
+	match tokio::fs::read_dir(path).await {
+		Ok(dir) => {
+			let mut entries: Vec<_> = ReadDirStream::new(dir)
+				.filter_map(|entry| async move { entry.ok() })
+				.map(|entry| entry.file_name().into_string().unwrap())
+				.collect()
+				.await;
+			entries.sort();
 
-				Ok(entries)
-			}
-			Err(err) => Err(read_dir_error(&path_str, err)),
+			Ok(entries)
 		}
-	})
+		Err(err) => Err(read_dir_error(&path_str, err)),
+	}
 }
 
 #[js_fn]
@@ -123,12 +117,10 @@ fn write_sync(path_str: String, #[ion(convert = false)] contents: BufferSource)
 }
 
 #[js_fn]
-fn create_dir(cx: &Context, path_str: String) -> Option<Promise> {
-	future_to_promise::<_, _, Error>(cx, async move {
-		let path = Path::new(&path_str);
+async fn create_dir(path_str: String) -> bool {
+	let path = Path::new(&path_str);
 
-		Ok(tokio::fs::create_dir(path).await.is_ok())
-	})
+	tokio::fs::create_dir(path).await.is_ok()
 }
 
 #[js_fn]
@@ -139,12 +131,10 @@ fn create_dir_sync(path_str: String) -> bool {
 }
 
 #[js_fn]
-fn create_dir_recursive(cx: &Context, path_str: String) -> Option<Promise> {
-	future_to_promise::<_, _, Error>(cx, async move {
-		let path = Path::new(&path_str);
+async fn create_dir_recursive(path_str: String) -> bool {
+	let path = Path::new(&path_str);
 
-		Ok(tokio::fs::create_dir_all(path).await.is_ok())
-	})
+	tokio::fs::create_dir_all(path).await.is_ok()
 }
 
 #[js_fn]
@@ -155,11 +145,9 @@ fn create_dir_recursive_sync(path_str: String) -> bool {
 }
 
 #[js_fn]
-fn remove_file(cx: &Context, path_str: String) -> Option<Promise> {
-	future_to_promise::<_, _, Error>(cx, async move {
-		let path = Path::new(&path_str);
-		Ok(tokio::fs::remove_file(path).await.is_ok())
-	})
+async fn remove_file(path_str: String) -> bool {
+	let path = Path::new(&path_str);
+	tokio::fs::remove_file(path).await.is_ok()
 }
 
 #[js_fn]
@@ -169,11 +157,9 @@ fn remove_file_sync(path_str: String) -> bool {
 }
 
 #[js_fn]
-fn remove_dir(cx: &Context, path_str: String) -> Option<Promise> {
-	future_to_promise::<_, _, Error>(cx, async move {
-		let path = Path::new(&path_str);
-		Ok(tokio::fs::remove_dir(path).await.is_ok())
-	})
+async fn remove_dir(path_str: String) -> bool {
+	let path = Path::new(&path_str);
+	tokio::fs::remove_dir(path).await.is_ok()
 }
 
 #[js_fn]
@@ -183,11 +169,9 @@ fn remove_dir_sync(path_str: String) -> bool {
 }
 
 #[js_fn]
-fn remove_dir_recursive(cx: &Context, path_str: String) -> Option<Promise> {
-	future_to_promise::<_, _, Error>(cx, async move {
-		let path = Path::new(&path_str);
-		Ok(tokio::fs::remove_dir_all(path).await.is_ok())
-	})
+async fn remove_dir_recursive(path_str: String) -> bool {
+	let path = Path::new(&path_str);
+	tokio::fs::remove_dir_all(path).await.is_ok()
 }
 
 #[js_fn]
@@ -197,13 +181,11 @@ fn remove_dir_recursive_sync(path_str: String) -> bool {
 }
 
 #[js_fn]
-fn copy(cx: &Context, from_str: String, to_str: String) -> Option<Promise> {
-	future_to_promise::<_, _, Error>(cx, async move {
-		let from = Path::new(&from_str);
-		let to = Path::new(&to_str);
+async fn copy(from_str: String, to_str: String) -> bool {
+	let from = Path::new(&from_str);
+	let to = Path::new(&to_str);
 
-		Ok(tokio::fs::copy(from, to).await.is_ok())
-	})
+	tokio::fs::copy(from, to).await.is_ok()
 }
 
 #[js_fn]
@@ -215,13 +197,11 @@ fn copy_sync(from_str: String, to_str: String) -> bool {
 }
 
 #[js_fn]
-fn rename(cx: &Context, from_str: String, to_str: String) -> Option<Promise> {
-	future_to_promise::<_, _, Error>(cx, async move {
-		let from = Path::new(&from_str);
-		let to = Path::new(&to_str);
+async fn rename(from_str: String, to_str: String) -> bool {
+	let from = Path::new(&from_str);
+	let to = Path::new(&to_str);
 
-		Ok(tokio::fs::rename(from, to).await.is_ok())
-	})
+	tokio::fs::rename(from, to).await.is_ok()
 }
 
 #[js_fn]
@@ -233,26 +213,24 @@ fn rename_sync(from_str: String, to_str: String) -> bool {
 }
 
 #[js_fn]
-fn soft_link(cx: &Context, original_str: String, link_str: String) -> Option<Promise> {
-	future_to_promise::<_, _, Error>(cx, async move {
-		let original = Path::new(&original_str);
-		let link = Path::new(&link_str);
+async fn soft_link(original_str: String, link_str: String) -> bool {
+	let original = Path::new(&original_str);
+	let link = Path::new(&link_str);
 
-		#[cfg(target_family = "unix")]
-		{
-			Ok(tokio::fs::symlink(original, link).await.is_ok())
-		}
-		#[cfg(target_family = "windows")]
-		{
-			if original.is_file() {
-				Ok(tokio::fs::symlink_file(original, link).await.is_ok())
-			} else if original.is_dir() {
-				Ok(tokio::fs::symlink_dir(original, link).await.is_ok())
-			} else {
-				Ok(false)
-			}
+	#[cfg(target_family = "unix")]
+	{
+		tokio::fs::symlink(original, link).await.is_ok()
+	}
+	#[cfg(target_family = "windows")]
+	{
+		if original.is_file() {
+			tokio::fs::symlink_file(original, link).await.is_ok()
+		} else if original.is_dir() {
+			tokio::fs::symlink_dir(original, link).await.is_ok()
+		} else {
+			false
 		}
-	})
+	}
 }
 
 #[js_fn]
@@ -277,13 +255,11 @@ fn soft_link_sync(original_str: String, link_str: String) -> bool {
 }
 
 #[js_fn]
-fn hard_link(cx: &Context, original_str: String, link_str: String) -> Option<Promise> {
-	future_to_promise::<_, _, Error>(cx, async move {
-		let original = Path::new(&original_str);
-		let link = Path::new(&link_str);
+async fn hard_link(original_str: String, link_str: String) -> bool {
+	let original = Path::new(&original_str);
+	let link = Path::new(&link_str);
 
-		Ok(tokio::fs::hard_link(original, link).await.is_ok())
-	})
+	tokio::fs::hard_link(original, link).await.is_ok()
 }
 
 #[js_fn]
@@ -10,32 +10,73 @@
 extern crate ion;
 
 use ion::{Context, Object};
-use runtime::module::{init_global_module, init_module, StandardModules};
+use runtime::module::{define_lazy_global_module, init_module, StandardModules};
 
 pub use crate::assert::Assert;
+pub use crate::buffer::BufferM;
+pub use crate::dns::Dns;
 pub use crate::fs::FileSystem;
+pub use crate::mime::Mime;
 pub use crate::path::PathM;
+pub use crate::profiler::Profiler;
+pub use crate::signals::Signals;
+pub use crate::sqlite::Sqlite;
+pub use crate::stream::StreamM;
+pub use crate::subprocess::SubprocessM;
+pub use crate::tty::Tty;
 pub use crate::url::UrlM;
+pub use crate::util::Util;
 
 mod assert;
+mod buffer;
+mod dns;
 mod fs;
+mod mime;
 mod path;
+mod profiler;
+mod signals;
+mod sqlite;
+mod stream;
+mod subprocess;
+mod tty;
 mod url;
+mod util;
 
 pub struct Modules;
 
 impl StandardModules for Modules {
 	fn init(self, cx: &Context, global: &Object) -> bool {
 		init_module::<Assert>(cx, global)
+			&& init_module::<BufferM>(cx, global)
+			&& init_module::<Dns>(cx, global)
 			&& init_module::<FileSystem>(cx, global)
+			&& init_module::<Mime>(cx, global)
 			&& init_module::<PathM>(cx, global)
+			&& init_module::<Profiler>(cx, global)
+			&& init_module::<Signals>(cx, global)
+			&& init_module::<Sqlite>(cx, global)
+			&& init_module::<StreamM>(cx, global)
+			&& init_module::<SubprocessM>(cx, global)
+			&& init_module::<Tty>(cx, global)
 			&& init_module::<UrlM>(cx, global)
+			&& init_module::<Util>(cx, global)
 	}
 
-	fn init_globals(self, cx: &Context, global: &Object) -> bool {
-		init_global_module::<Assert>(cx, global)
-			&& init_global_module::<FileSystem>(cx, global)
-			&& init_global_module::<PathM>(cx, global)
-			&& init_global_module::<UrlM>(cx, global)
+	fn init_globals(self, cx: &Context, _: &Object) -> bool {
+		define_lazy_global_module::<Assert>(cx);
+		define_lazy_global_module::<BufferM>(cx);
+		define_lazy_global_module::<Dns>(cx);
+		define_lazy_global_module::<FileSystem>(cx);
+		define_lazy_global_module::<Mime>(cx);
+		define_lazy_global_module::<PathM>(cx);
+		define_lazy_global_module::<Profiler>(cx);
+		define_lazy_global_module::<Signals>(cx);
+		define_lazy_global_module::<Sqlite>(cx);
+		define_lazy_global_module::<StreamM>(cx);
+		define_lazy_global_module::<SubprocessM>(cx);
+		define_lazy_global_module::<Tty>(cx);
+		define_lazy_global_module::<UrlM>(cx);
+		define_lazy_global_module::<Util>(cx);
+		true
 	}
 }
@@ -0,0 +1,14 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+export const lookup = ______mimeInternal______.lookup;
+export const extension = ______mimeInternal______.extension;
+export const parse = ______mimeInternal______.parse;
+export const format = ______mimeInternal______.format;
+export const essence = ______mimeInternal______.essence;
+export const isSameEssence = ______mimeInternal______.isSameEssence;
+
+export default Object.freeze(______mimeInternal______);
@@ -0,0 +1,82 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::path::Path;
+
+use ion::{Context, Object};
+use mozjs::jsapi::JSFunctionSpec;
+use runtime::mime::MediaType;
+use runtime::module::NativeModule;
+
+#[js_fn]
+fn lookup(path: String) -> Option<String> {
+	let extension = Path::new(&path).extension().and_then(|ext| ext.to_str()).unwrap_or(&path);
+	MediaType::from_extension(extension).map(|media_type| media_type.to_string())
+}
+
+#[js_fn]
+fn extension(kind: String) -> Option<String> {
+	MediaType::parse(&kind).and_then(|media_type| media_type.extension().map(String::from))
+}
+
+#[js_fn]
+fn parse(cx: &Context, kind: String) -> Option<Object> {
+	let media_type = MediaType::parse(&kind)?;
+
+	let parameters = Object::new(cx);
+	for (name, value) in &media_type.parameters {
+		parameters.set_as(cx, name.as_str(), value);
+	}
+
+	let object = Object::new(cx);
+	object.set_as(cx, "type", &media_type.kind);
+	object.set_as(cx, "subtype", &media_type.subtype);
+	object.set_as(cx, "essence", &media_type.essence());
+	object.set_as(cx, "parameters", &parameters);
+	Some(object)
+}
+
+#[js_fn]
+fn format(kind: String) -> Option<String> {
+	MediaType::parse(&kind).map(|media_type| media_type.to_string())
+}
+
+#[js_fn]
+fn essence(kind: String) -> Option<String> {
+	MediaType::parse(&kind).map(|media_type| media_type.essence())
+}
+
+#[js_fn]
+fn is_same_essence(first: String, second: String) -> bool {
+	match (MediaType::parse(&first), MediaType::parse(&second)) {
+		(Some(first), Some(second)) => first.is_same_essence(&second),
+		_ => false,
+	}
+}
+
+const FUNCTIONS: &[JSFunctionSpec] = &[
+	function_spec!(lookup, 1),
+	function_spec!(extension, 1),
+	function_spec!(parse, 1),
+	function_spec!(format, 1),
+	function_spec!(essence, 1),
+	function_spec!(is_same_essence, "isSameEssence", 2),
+	JSFunctionSpec::ZERO,
+];
+
+#[derive(Default)]
+pub struct Mime;
+
+impl NativeModule for Mime {
+	const NAME: &'static str = "mime";
+	const VARIABLE_NAME: &'static str = "mime";
+	const SOURCE: &'static str = include_str!("mime.js");
+
+	fn module(cx: &Context) -> Option<Object> {
+		let mime = Object::new(cx);
+		unsafe { mime.define_methods(cx, FUNCTIONS).then_some(mime) }
+	}
+}
@@ -0,0 +1,97 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::path::Path;
+use std::time::Duration;
+
+use ion::function::Opt;
+use ion::profiler::{self, Weight, DEFAULT_INTERVAL};
+use ion::{Context, Error, ErrorKind, Object, Result};
+use mozjs::jsapi::JSFunctionSpec;
+use runtime::module::NativeModule;
+
+/// Starts sampling the JavaScript stack every `interval` milliseconds, 1 by default.
+/// If `allocations` is `true`, the allocations of the script and its live objects are also tracked.
+/// Returns `false` if the profiler is already running.
+#[js_fn]
+fn start(cx: &Context, Opt(interval): Opt<f64>, Opt(allocations): Opt<bool>) -> Result<bool> {
+	let interval = match interval {
+		Some(interval) if interval.is_finite() && interval > 0.0 => Duration::from_secs_f64(interval / 1000.0),
+		Some(_) => {
+			return Err(Error::new(
+				"Sampling interval must be a positive number",
+				ErrorKind::Range,
+			))
+		}
+		None => DEFAULT_INTERVAL,
+	};
+	if allocations.unwrap_or_default() {
+		Ok(profiler::start_heap_profiling(cx, interval))
+	} else {
+		Ok(profiler::start_profiling(cx, interval))
+	}
+}
+
+/// Stops the profiler and writes the profile to the file at `path`, as folded stacks if its extension is `.folded` and
+/// as speedscope JSON otherwise. Without a path, the profile is returned as speedscope JSON instead.
+///
+/// Samples are weighted by `"time"` by default, or by the growth of the heap since the previous sample for
+/// `"heap-growth"`. For `"allocations"`, the allocations tracked since [start] are weighted by their size instead.
+#[js_fn]
+fn stop(cx: &Context, Opt(path): Opt<String>, Opt(weight): Opt<String>) -> Result<Option<String>> {
+	let weight = match weight.as_deref() {
+		None | Some("time") => Weight::Time,
+		Some("heap-growth") => Weight::HeapGrowth,
+		Some("allocations") => Weight::Allocations,
+		Some(weight) => {
+			return Err(Error::new(
+				format!("Expected \"time\", \"heap-growth\" or \"allocations\" for weight, received \"{weight}\""),
+				ErrorKind::Type,
+			));
+		}
+	};
+
+	let profile = profiler::stop_profiling(cx).ok_or_else(|| Error::new("Profiler is not running", None))?;
+	match path {
+		Some(path) => {
+			profile
+				.write(Path::new(&path), weight)
+				.map_err(|err| Error::new(format!("Could not write profile: {path}\n{err}"), None))?;
+			Ok(None)
+		}
+		None => Ok(Some(profile.to_speedscope("profile", weight))),
+	}
+}
+
+#[js_fn]
+fn is_profiling(cx: &Context) -> bool {
+	profiler::is_profiling(cx)
+}
+
+const FUNCTIONS: &[JSFunctionSpec] = &[
+	function_spec!(start, 0),
+	function_spec!(stop, 0),
+	function_spec!(is_profiling, "isProfiling", 0),
+	JSFunctionSpec::ZERO,
+];
+
+#[derive(Default)]
+pub struct Profiler;
+
+impl NativeModule for Profiler {
+	const NAME: &'static str = "profiler";
+	const VARIABLE_NAME: &'static str = "profiler";
+	const SOURCE: &'static str = include_str!("profiler.js");
+
+	fn module(cx: &Context) -> Option<Object> {
+		let profiler = Object::new(cx);
+		if unsafe { profiler.define_methods(cx, FUNCTIONS) } {
+			Some(profiler)
+		} else {
+			None
+		}
+	}
+}
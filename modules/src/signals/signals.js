@@ -0,0 +1,66 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+const listeners = new Map();
+
+Object.defineProperty(______signalsInternal______.SignalListener.prototype, Symbol.asyncIterator, {
+	async* value() {
+		try {
+			while (await this.recv()) {
+				yield this.signal;
+			}
+		} finally {
+			this.close();
+		}
+	},
+	configurable: true,
+	writable: true,
+});
+
+function addSignalListener(signal, callback) {
+	let callbacks = listeners.get(signal);
+	if (callbacks === undefined) {
+		callbacks = new Map();
+		listeners.set(signal, callbacks);
+	}
+	if (callbacks.has(callback)) {
+		return;
+	}
+
+	const listener = ______signalsInternal______.listen(signal);
+	callbacks.set(callback, listener);
+	(async () => {
+		for await (const received of listener) {
+			queueMicrotask(() => callback(received));
+		}
+	})();
+}
+
+function removeSignalListener(signal, callback) {
+	const callbacks = listeners.get(signal);
+	const listener = callbacks?.get(callback);
+	if (listener !== undefined) {
+		listener.close();
+		callbacks.delete(callback);
+	}
+}
+
+Object.defineProperties(______signalsInternal______, {
+	addSignalListener: {
+		value: addSignalListener,
+		enumerable: true,
+	},
+	removeSignalListener: {
+		value: removeSignalListener,
+		enumerable: true,
+	},
+});
+
+export const listen = ______signalsInternal______.listen;
+export const SignalListener = ______signalsInternal______.SignalListener;
+export {addSignalListener, removeSignalListener};
+
+export default Object.freeze(______signalsInternal______);
@@ -0,0 +1,178 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::Cell;
+use std::pin::pin;
+use std::rc::Rc;
+
+use futures::future::{select, Either};
+use ion::class::{NativeObject, Reflector};
+use ion::{ClassDefinition, ConstEnum, Context, Error, ErrorKind, Object, Promise, Result};
+use mozjs::jsapi::{JSFunctionSpec, JSObject};
+use runtime::module::NativeModule;
+use runtime::promise::future_to_promise;
+use tokio::sync::{Mutex, Notify};
+
+#[cfg(unix)]
+type SignalStream = tokio::signal::unix::Signal;
+#[cfg(windows)]
+type SignalStream = tokio::signal::windows::CtrlC;
+
+/// Numbers of the signals that can be listened for, exposed as `signals.Signal`.
+#[cfg(unix)]
+#[derive(ConstEnum)]
+#[ion(rename_all = "UPPERCASE")]
+#[repr(i32)]
+enum Signal {
+	Hup = libc::SIGHUP,
+	Int = libc::SIGINT,
+	Quit = libc::SIGQUIT,
+	Term = libc::SIGTERM,
+	Usr1 = libc::SIGUSR1,
+	Usr2 = libc::SIGUSR2,
+	Alrm = libc::SIGALRM,
+	Chld = libc::SIGCHLD,
+	Pipe = libc::SIGPIPE,
+	Winch = libc::SIGWINCH,
+}
+
+#[cfg(unix)]
+fn signal_stream(signal: &str) -> Result<SignalStream> {
+	use tokio::signal::unix::{signal as listen, SignalKind};
+
+	let kind = match signal.strip_prefix("SIG").unwrap_or(signal) {
+		"HUP" => SignalKind::hangup(),
+		"INT" => SignalKind::interrupt(),
+		"QUIT" => SignalKind::quit(),
+		"TERM" => SignalKind::terminate(),
+		"USR1" => SignalKind::user_defined1(),
+		"USR2" => SignalKind::user_defined2(),
+		"ALRM" => SignalKind::alarm(),
+		"CHLD" => SignalKind::child(),
+		"PIPE" => SignalKind::pipe(),
+		"WINCH" => SignalKind::window_change(),
+		_ => {
+			return Err(Error::new(
+				format!("Cannot listen for signal: {signal}"),
+				ErrorKind::Type,
+			))
+		}
+	};
+	listen(kind).map_err(|err| Error::new(format!("Could not listen for {signal}\n{err}"), None))
+}
+
+#[cfg(windows)]
+fn signal_stream(signal: &str) -> Result<SignalStream> {
+	match signal.strip_prefix("SIG").unwrap_or(signal) {
+		"INT" => tokio::signal::windows::ctrl_c()
+			.map_err(|err| Error::new(format!("Could not listen for {signal}\n{err}"), None)),
+		_ => Err(Error::new(
+			format!("Cannot listen for signal: {signal}"),
+			ErrorKind::Type,
+		)),
+	}
+}
+
+#[js_class]
+pub struct SignalListener {
+	reflector: Reflector,
+	#[trace(no_trace)]
+	signal: String,
+	#[trace(no_trace)]
+	stream: Rc<Mutex<SignalStream>>,
+	#[trace(no_trace)]
+	closed: Rc<Cell<bool>>,
+	#[trace(no_trace)]
+	close: Rc<Notify>,
+}
+
+#[js_class]
+impl SignalListener {
+	#[ion(get)]
+	pub fn get_signal(&self) -> String {
+		self.signal.clone()
+	}
+
+	#[ion(get)]
+	pub fn get_closed(&self) -> bool {
+		self.closed.get()
+	}
+
+	/// Waits for the next signal, resolving with `true` when it is received, or `false` once the listener is closed.
+	/// A pending call keeps the event loop alive.
+	pub fn recv<'cx>(&self, cx: &'cx Context) -> Option<Promise<'cx>> {
+		let stream = Rc::clone(&self.stream);
+		let closed = Rc::clone(&self.closed);
+		let close = Rc::clone(&self.close);
+		future_to_promise::<_, _, Error>(cx, async move {
+			// The notification is enabled before checking if the listener is closed, as `notify_waiters` does not
+			// notify futures created after it is called.
+			let mut notified = pin!(close.notified());
+			notified.as_mut().enable();
+			if closed.get() {
+				return Ok(false);
+			}
+
+			let mut stream = match select(pin!(stream.lock()), notified.as_mut()).await {
+				Either::Left((stream, _)) => stream,
+				Either::Right(_) => return Ok(false),
+			};
+			let received = match select(pin!(stream.recv()), notified).await {
+				Either::Left((received, _)) => received.is_some(),
+				Either::Right(_) => false,
+			};
+			Ok(received && !closed.get())
+		})
+	}
+
+	/// Stops listening, resolving any pending [recv](SignalListener::recv) with `false`.
+	pub fn close(&self) {
+		self.closed.set(true);
+		self.close.notify_waiters();
+	}
+}
+
+#[js_fn]
+fn listen(cx: &Context, signal: String) -> Result<*mut JSObject> {
+	let stream = signal_stream(&signal)?;
+	let listener = SignalListener {
+		reflector: Reflector::default(),
+		signal,
+		stream: Rc::new(Mutex::new(stream)),
+		closed: Rc::new(Cell::new(false)),
+		close: Rc::new(Notify::new()),
+	};
+	Ok(SignalListener::new_object(cx, Box::new(listener)))
+}
+
+const FUNCTIONS: &[JSFunctionSpec] = &[function_spec!(listen, 1), JSFunctionSpec::ZERO];
+
+#[derive(Default)]
+pub struct Signals;
+
+impl NativeModule for Signals {
+	const NAME: &'static str = "signals";
+	const VARIABLE_NAME: &'static str = "signals";
+	#[cfg(unix)]
+	const SOURCE: &'static str = concat!(
+		include_str!("signals.js"),
+		"export const Signal = ______signalsInternal______.Signal;\n"
+	);
+	#[cfg(windows)]
+	const SOURCE: &'static str = include_str!("signals.js");
+
+	fn module(cx: &Context) -> Option<Object> {
+		let signals = Object::new(cx);
+		if !unsafe { signals.define_methods(cx, FUNCTIONS) } || !SignalListener::init_class(cx, &signals).0 {
+			return None;
+		}
+		#[cfg(unix)]
+		if !Signal::define_namespace(cx, &signals) {
+			return None;
+		}
+		Some(signals)
+	}
+}
@@ -0,0 +1,36 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+const transactions = new WeakMap();
+
+// Transactions on the same connection are queued, so that each one begins after the previous one has finished.
+// The callback must not start another transaction on the same connection, as it would wait for the callback.
+Object.defineProperty(______sqliteInternal______.Database.prototype, "transaction", {
+	value(callback) {
+		const previous = transactions.get(this) ?? Promise.resolve();
+		const transaction = previous.then(async () => {
+			await this.exec("BEGIN");
+			try {
+				const result = await callback(this);
+				await this.exec("COMMIT");
+				return result;
+			} catch (error) {
+				await this.exec("ROLLBACK");
+				throw error;
+			}
+		});
+		transactions.set(this, transaction.catch(() => {}));
+		return transaction;
+	},
+	configurable: true,
+	writable: true,
+});
+
+export const open = ______sqliteInternal______.open;
+export const Database = ______sqliteInternal______.Database;
+export const Statement = ______sqliteInternal______.Statement;
+
+export default Object.freeze(______sqliteInternal______);
@@ -0,0 +1,417 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use ion::class::{NativeObject, Reflector};
+use ion::conversions::{ConversionBehavior, FromValue, IntoValue, ToValue};
+use ion::function::Opt;
+use ion::typedarray::Uint8ArrayWrapper;
+use ion::{Array, BigInt, ClassDefinition, Context, Error, ErrorKind, Object, OwnedKey, Promise, Result, Value};
+use mozjs::jsapi::{JSFunctionSpec, JSObject};
+use runtime::globals::file::BufferSource;
+use runtime::module::NativeModule;
+use runtime::promise::future_to_promise;
+use rusqlite::types::Value as SqlValue;
+use rusqlite::{Connection, OpenFlags, Statement as SqlStatement};
+use tokio::task::spawn_blocking;
+
+const MAX_SAFE_INTEGER: i64 = (1 << 53) - 1;
+
+type SharedConnection = Arc<Mutex<Option<Connection>>>;
+
+fn sqlite_error(err: rusqlite::Error) -> Error {
+	Error::new(format!("SQLite Error: {err}"), None)
+}
+
+fn closed_error() -> Error {
+	Error::new("Database is closed", None)
+}
+
+/// Parameters bound to a statement, either by position or by name.
+pub enum Params {
+	Positional(Vec<SqlValue>),
+	Named(Vec<(String, SqlValue)>),
+}
+
+impl Params {
+	fn from_value(cx: &Context, value: Option<Value>) -> Result<Params> {
+		let Some(value) = value.filter(|value| !value.handle().is_null_or_undefined()) else {
+			return Ok(Params::Positional(Vec::new()));
+		};
+		if !value.handle().is_object() {
+			return Err(Error::new("Expected Array or Object for parameters", ErrorKind::Type));
+		}
+
+		let object = value.to_object(cx);
+		if let Some(array) = Array::from(cx, object.into_local()) {
+			let params = array.iter(cx, None).map(|(_, value)| to_sql_value(cx, &value?)).collect::<Result<_>>()?;
+			Ok(Params::Positional(params))
+		} else {
+			let object = value.to_object(cx);
+			let mut params = Vec::new();
+			for (key, value) in object.iter(cx, None) {
+				if let OwnedKey::String(key) = key.to_owned_key(cx)? {
+					let key = if key.starts_with([':', '@', '$']) {
+						key
+					} else {
+						format!(":{key}")
+					};
+					params.push((key, to_sql_value(cx, &value?)?));
+				}
+			}
+			Ok(Params::Named(params))
+		}
+	}
+
+	fn bind(&self, statement: &mut SqlStatement) -> rusqlite::Result<()> {
+		match self {
+			Params::Positional(params) => {
+				for (index, param) in params.iter().enumerate() {
+					statement.raw_bind_parameter(index + 1, param)?;
+				}
+			}
+			Params::Named(params) => {
+				for (name, param) in params {
+					let index = statement
+						.parameter_index(name)?
+						.ok_or_else(|| rusqlite::Error::InvalidParameterName(name.clone()))?;
+					statement.raw_bind_parameter(index, param)?;
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+fn to_sql_value(cx: &Context, value: &Value) -> Result<SqlValue> {
+	let handle = value.handle();
+	if handle.is_null_or_undefined() {
+		Ok(SqlValue::Null)
+	} else if handle.is_boolean() {
+		Ok(SqlValue::Integer(i64::from(handle.to_boolean())))
+	} else if handle.is_int32() {
+		Ok(SqlValue::Integer(i64::from(handle.to_int32())))
+	} else if handle.is_double() {
+		Ok(SqlValue::Real(handle.to_double()))
+	} else if handle.is_string() {
+		Ok(SqlValue::Text(String::from_value(cx, value, true, ())?))
+	} else if handle.is_bigint() {
+		let bigint = BigInt::from(cx.root(handle.to_bigint()));
+		let integer = bigint
+			.to_i64()
+			.ok_or_else(|| Error::new("BigInt is too large to be stored in SQLite", ErrorKind::Range))?;
+		Ok(SqlValue::Integer(integer))
+	} else if handle.is_object() {
+		let buffer = BufferSource::from_value(cx, value, true, false)
+			.map_err(|_| Error::new("Unsupported parameter type for SQLite", ErrorKind::Type))?;
+		Ok(SqlValue::Blob(buffer.to_vec()))
+	} else {
+		Err(Error::new("Unsupported parameter type for SQLite", ErrorKind::Type))
+	}
+}
+
+fn sql_value_into_value(cx: &Context, sql: SqlValue, value: &mut Value) {
+	match sql {
+		SqlValue::Null => value.handle_mut().set(mozjs::jsval::NullValue()),
+		SqlValue::Integer(integer) if (-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&integer) => {
+			(integer as f64).to_value(cx, value)
+		}
+		SqlValue::Integer(integer) => {
+			let bigint = Value::bigint(cx, &BigInt::from_i64(cx, integer));
+			value.handle_mut().set(bigint.get());
+		}
+		SqlValue::Real(real) => real.to_value(cx, value),
+		SqlValue::Text(text) => text.to_value(cx, value),
+		SqlValue::Blob(blob) => Box::new(Uint8ArrayWrapper::from(blob)).into_value(cx, value),
+	}
+}
+
+fn row_to_object<'cx>(cx: &'cx Context, columns: &[String], row: Vec<SqlValue>) -> Object<'cx> {
+	let object = Object::new(cx);
+	for (column, sql) in columns.iter().zip(row) {
+		let mut value = Value::undefined(cx);
+		sql_value_into_value(cx, sql, &mut value);
+		object.set(cx, column.as_str(), &value);
+	}
+	object
+}
+
+/// Rows returned by a query, converted to objects keyed by column name.
+pub struct Rows {
+	columns: Vec<String>,
+	rows: Vec<Vec<SqlValue>>,
+}
+
+impl<'cx> IntoValue<'cx> for Rows {
+	fn into_value(self: Box<Self>, cx: &'cx Context, value: &mut Value) {
+		let array = Array::new(cx);
+		for (index, row) in self.rows.into_iter().enumerate() {
+			array.set_as(cx, index as u32, &row_to_object(cx, &self.columns, row));
+		}
+		array.to_value(cx, value);
+	}
+}
+
+/// First row returned by a query, or `null` if there were no rows.
+pub struct Row(Option<Rows>);
+
+impl<'cx> IntoValue<'cx> for Row {
+	fn into_value(self: Box<Self>, cx: &'cx Context, value: &mut Value) {
+		match self.0.and_then(|mut rows| rows.rows.pop().map(|row| (rows.columns, row))) {
+			Some((columns, row)) => row_to_object(cx, &columns, row).to_value(cx, value),
+			None => value.handle_mut().set(mozjs::jsval::NullValue()),
+		}
+	}
+}
+
+pub struct RunResult {
+	changes: usize,
+	last_insert_rowid: i64,
+}
+
+impl<'cx> ToValue<'cx> for RunResult {
+	fn to_value(&self, cx: &'cx Context, value: &mut Value) {
+		let object = Object::new(cx);
+		object.set_as(cx, "changes", &(self.changes as f64));
+		let mut rowid = Value::undefined(cx);
+		sql_value_into_value(cx, SqlValue::Integer(self.last_insert_rowid), &mut rowid);
+		object.set(cx, "lastInsertRowid", &rowid);
+		object.to_value(cx, value);
+	}
+}
+
+fn query(connection: &Connection, sql: &str, params: &Params, limit: Option<usize>) -> rusqlite::Result<Rows> {
+	let mut statement = connection.prepare_cached(sql)?;
+	params.bind(&mut statement)?;
+	let columns: Vec<String> = statement.column_names().into_iter().map(String::from).collect();
+
+	let mut rows = Vec::new();
+	let mut raw = statement.raw_query();
+	while limit.map_or(true, |limit| rows.len() < limit) {
+		let Some(row) = raw.next()? else {
+			break;
+		};
+		let row: Vec<SqlValue> = (0..columns.len()).map(|index| row.get(index)).collect::<rusqlite::Result<_>>()?;
+		rows.push(row);
+	}
+	Ok(Rows { columns, rows })
+}
+
+fn run(connection: &Connection, sql: &str, params: &Params) -> rusqlite::Result<RunResult> {
+	let mut statement = connection.prepare_cached(sql)?;
+	params.bind(&mut statement)?;
+	let changes = statement.raw_execute()?;
+	Ok(RunResult {
+		changes,
+		last_insert_rowid: connection.last_insert_rowid(),
+	})
+}
+
+/// Runs `f` with the connection on the blocking thread pool, resolving the returned promise with its result.
+fn blocking<'cx, T, F>(cx: &'cx Context, connection: &SharedConnection, f: F) -> Option<Promise<'cx>>
+where
+	T: for<'cx2> IntoValue<'cx2> + Send + 'static,
+	F: FnOnce(&Connection) -> rusqlite::Result<T> + Send + 'static,
+{
+	let connection = Arc::clone(connection);
+	future_to_promise::<_, _, Error>(cx, async move {
+		let result = spawn_blocking(move || {
+			let connection = connection.lock().unwrap_or_else(|err| err.into_inner());
+			connection.as_ref().map(f)
+		})
+		.await
+		.map_err(|err| Error::new(err.to_string(), None))?;
+		result.ok_or_else(closed_error)?.map_err(sqlite_error)
+	})
+}
+
+#[derive(Default, FromValue)]
+pub struct OpenOptions {
+	readonly: Option<bool>,
+	create: Option<bool>,
+	wal: Option<bool>,
+	#[ion(convert = ConversionBehavior::EnforceRange)]
+	timeout: Option<u64>,
+}
+
+#[js_class]
+pub struct Database {
+	reflector: Reflector,
+	#[trace(no_trace)]
+	path: String,
+	#[trace(no_trace)]
+	connection: SharedConnection,
+}
+
+#[js_class]
+impl Database {
+	#[ion(constructor)]
+	pub fn constructor(path: String, Opt(options): Opt<OpenOptions>) -> Result<Database> {
+		let options = options.unwrap_or_default();
+		let mut flags = OpenFlags::SQLITE_OPEN_URI | OpenFlags::SQLITE_OPEN_NO_MUTEX;
+		if options.readonly.unwrap_or(false) {
+			flags |= OpenFlags::SQLITE_OPEN_READ_ONLY;
+		} else {
+			flags |= OpenFlags::SQLITE_OPEN_READ_WRITE;
+			if options.create.unwrap_or(true) {
+				flags |= OpenFlags::SQLITE_OPEN_CREATE;
+			}
+		}
+
+		let connection = Connection::open_with_flags(&path, flags).map_err(sqlite_error)?;
+		if let Some(timeout) = options.timeout {
+			connection.busy_timeout(Duration::from_millis(timeout)).map_err(sqlite_error)?;
+		}
+		if options.wal.unwrap_or(false) {
+			connection.pragma_update(None, "journal_mode", "WAL").map_err(sqlite_error)?;
+		}
+
+		Ok(Database {
+			reflector: Reflector::default(),
+			path,
+			connection: Arc::new(Mutex::new(Some(connection))),
+		})
+	}
+
+	#[ion(get)]
+	pub fn get_path(&self) -> String {
+		self.path.clone()
+	}
+
+	/// Executes one or more statements without parameters.
+	pub fn exec<'cx>(&self, cx: &'cx Context, sql: String) -> Option<Promise<'cx>> {
+		blocking(cx, &self.connection, move |connection| connection.execute_batch(&sql))
+	}
+
+	/// Executes a statement and resolves with the number of changed rows and the last inserted row id.
+	pub fn run<'cx>(&self, cx: &'cx Context, sql: String, Opt(params): Opt<Value>) -> Result<Option<Promise<'cx>>> {
+		let params = Params::from_value(cx, params)?;
+		Ok(blocking(cx, &self.connection, move |connection| {
+			run(connection, &sql, &params)
+		}))
+	}
+
+	/// Executes a query and resolves with all resulting rows.
+	pub fn all<'cx>(&self, cx: &'cx Context, sql: String, Opt(params): Opt<Value>) -> Result<Option<Promise<'cx>>> {
+		let params = Params::from_value(cx, params)?;
+		Ok(blocking(cx, &self.connection, move |connection| {
+			query(connection, &sql, &params, None)
+		}))
+	}
+
+	/// Executes a query and resolves with the first resulting row, or `null`.
+	pub fn get<'cx>(&self, cx: &'cx Context, sql: String, Opt(params): Opt<Value>) -> Result<Option<Promise<'cx>>> {
+		let params = Params::from_value(cx, params)?;
+		Ok(blocking(cx, &self.connection, move |connection| {
+			query(connection, &sql, &params, Some(1)).map(|rows| Row(Some(rows)))
+		}))
+	}
+
+	/// Prepares a statement, which is compiled once and cached by the connection.
+	pub fn prepare(&self, cx: &Context, sql: String) -> Result<*mut JSObject> {
+		{
+			let connection = self.connection.lock().unwrap_or_else(|err| err.into_inner());
+			let connection = connection.as_ref().ok_or_else(closed_error)?;
+			connection.prepare_cached(&sql).map_err(sqlite_error)?;
+		}
+
+		let statement = Statement {
+			reflector: Reflector::default(),
+			connection: Arc::clone(&self.connection),
+			sql,
+		};
+		Ok(Statement::new_object(cx, Box::new(statement)))
+	}
+
+	/// Closes the connection once all pending queries have completed.
+	pub fn close<'cx>(&self, cx: &'cx Context) -> Option<Promise<'cx>> {
+		let connection = Arc::clone(&self.connection);
+		future_to_promise::<_, _, Error>(cx, async move {
+			let result = spawn_blocking(move || {
+				let mut connection = connection.lock().unwrap_or_else(|err| err.into_inner());
+				match connection.take() {
+					Some(connection) => connection.close().map_err(|(_, err)| err),
+					None => Ok(()),
+				}
+			})
+			.await
+			.map_err(|err| Error::new(err.to_string(), None))?;
+			result.map_err(sqlite_error)
+		})
+	}
+}
+
+#[js_class]
+pub struct Statement {
+	reflector: Reflector,
+	#[trace(no_trace)]
+	connection: SharedConnection,
+	#[trace(no_trace)]
+	sql: String,
+}
+
+#[js_class]
+impl Statement {
+	#[ion(get)]
+	pub fn get_source(&self) -> String {
+		self.sql.clone()
+	}
+
+	pub fn run<'cx>(&self, cx: &'cx Context, Opt(params): Opt<Value>) -> Result<Option<Promise<'cx>>> {
+		let params = Params::from_value(cx, params)?;
+		let sql = self.sql.clone();
+		Ok(blocking(cx, &self.connection, move |connection| {
+			run(connection, &sql, &params)
+		}))
+	}
+
+	pub fn all<'cx>(&self, cx: &'cx Context, Opt(params): Opt<Value>) -> Result<Option<Promise<'cx>>> {
+		let params = Params::from_value(cx, params)?;
+		let sql = self.sql.clone();
+		Ok(blocking(cx, &self.connection, move |connection| {
+			query(connection, &sql, &params, None)
+		}))
+	}
+
+	pub fn get<'cx>(&self, cx: &'cx Context, Opt(params): Opt<Value>) -> Result<Option<Promise<'cx>>> {
+		let params = Params::from_value(cx, params)?;
+		let sql = self.sql.clone();
+		Ok(blocking(cx, &self.connection, move |connection| {
+			query(connection, &sql, &params, Some(1)).map(|rows| Row(Some(rows)))
+		}))
+	}
+}
+
+#[js_fn]
+fn open(cx: &Context, path: String, Opt(options): Opt<OpenOptions>) -> Result<*mut JSObject> {
+	let database = Database::constructor(path, Opt(options))?;
+	Ok(Database::new_object(cx, Box::new(database)))
+}
+
+const FUNCTIONS: &[JSFunctionSpec] = &[function_spec!(open, 1), JSFunctionSpec::ZERO];
+
+#[derive(Default)]
+pub struct Sqlite;
+
+impl NativeModule for Sqlite {
+	const NAME: &'static str = "sqlite";
+	const VARIABLE_NAME: &'static str = "sqlite";
+	const SOURCE: &'static str = include_str!("sqlite.js");
+
+	fn module(cx: &Context) -> Option<Object> {
+		let sqlite = Object::new(cx);
+		if unsafe { sqlite.define_methods(cx, FUNCTIONS) }
+			&& Database::init_class(cx, &sqlite).0
+			&& Statement::init_class(cx, &sqlite).0
+		{
+			Some(sqlite)
+		} else {
+			None
+		}
+	}
+}
@@ -0,0 +1,916 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import {Buffer} from "buffer";
+
+const kEvents = Symbol("events");
+const kDestroyed = Symbol("destroyed");
+const kReadable = Symbol("readable");
+const kWritable = Symbol("writable");
+
+const DEFAULT_HIGH_WATER_MARK = 16 * 1024;
+const DEFAULT_OBJECT_HIGH_WATER_MARK = 16;
+
+class EventEmitter {
+	constructor() {
+		this[kEvents] = new Map();
+	}
+
+	on(event, listener) {
+		const listeners = this[kEvents].get(event);
+		if (listeners === undefined) {
+			this[kEvents].set(event, [listener]);
+		} else {
+			listeners.push(listener);
+		}
+		return this;
+	}
+
+	addListener(event, listener) {
+		return this.on(event, listener);
+	}
+
+	once(event, listener) {
+		const wrapper = (...args) => {
+			this.off(event, wrapper);
+			listener.apply(this, args);
+		};
+		wrapper.listener = listener;
+		return this.on(event, wrapper);
+	}
+
+	off(event, listener) {
+		const listeners = this[kEvents].get(event);
+		if (listeners !== undefined) {
+			const index = listeners.findIndex(l => l === listener || l.listener === listener);
+			if (index !== -1) {
+				listeners.splice(index, 1);
+			}
+		}
+		return this;
+	}
+
+	removeListener(event, listener) {
+		return this.off(event, listener);
+	}
+
+	removeAllListeners(event) {
+		if (event === undefined) {
+			this[kEvents].clear();
+		} else {
+			this[kEvents].delete(event);
+		}
+		return this;
+	}
+
+	emit(event, ...args) {
+		const listeners = this[kEvents].get(event);
+		if (listeners === undefined || listeners.length === 0) {
+			if (event === "error") {
+				throw args[0];
+			}
+			return false;
+		}
+		for (const listener of [...listeners]) {
+			listener.apply(this, args);
+		}
+		return true;
+	}
+
+	listenerCount(event) {
+		return this[kEvents].get(event)?.length ?? 0;
+	}
+}
+
+class Stream extends EventEmitter {
+	constructor(options = {}) {
+		super();
+		this[kDestroyed] = false;
+		if (typeof options.destroy === "function") {
+			this._destroy = options.destroy;
+		}
+	}
+
+	get destroyed() {
+		return this[kDestroyed];
+	}
+
+	destroy(error, callback) {
+		if (this[kDestroyed]) {
+			return this;
+		}
+		this[kDestroyed] = true;
+		this._destroy(error ?? null, err => {
+			queueMicrotask(() => {
+				callback?.(err);
+				if (err) {
+					this.emit("error", err);
+				}
+				this.emit("close");
+			});
+		});
+		return this;
+	}
+
+	_destroy(error, callback) {
+		callback(error);
+	}
+}
+
+function toChunk(chunk, encoding) {
+	if (typeof chunk === "string") {
+		return Buffer.from(chunk, encoding);
+	}
+	if (chunk instanceof Buffer) {
+		return chunk;
+	}
+	if (ArrayBuffer.isView(chunk)) {
+		return Buffer.from(chunk.buffer, chunk.byteOffset, chunk.byteLength);
+	}
+	throw new TypeError("Expected chunk to be a string, Buffer or Uint8Array");
+}
+
+function scheduleFlow(stream) {
+	const state = stream[kReadable];
+	if (!state.flowScheduled) {
+		state.flowScheduled = true;
+		queueMicrotask(() => {
+			state.flowScheduled = false;
+			flow(stream);
+		});
+	}
+}
+
+function flow(stream) {
+	const state = stream[kReadable];
+	if (stream[kDestroyed]) {
+		return;
+	}
+	if (state.flowing) {
+		while (state.flowing && state.buffer.length > 0) {
+			stream.emit("data", stream.read());
+		}
+	} else if (state.buffer.length > 0) {
+		stream.emit("readable");
+	}
+
+	if (state.ended && state.buffer.length === 0) {
+		if (!state.endEmitted) {
+			state.endEmitted = true;
+			if (!state.flowing) {
+				stream.emit("readable");
+			}
+			stream.emit("end");
+			if (state.autoDestroy && (stream[kWritable] === undefined || stream[kWritable].finished)) {
+				stream.destroy();
+			}
+		}
+	} else if (state.flowing !== null) {
+		maybeRead(stream);
+	}
+}
+
+function maybeRead(stream) {
+	const state = stream[kReadable];
+	if (!state.ended && !state.reading && !stream[kDestroyed] && state.length < state.highWaterMark) {
+		state.reading = true;
+		queueMicrotask(() => {
+			try {
+				stream._read(state.highWaterMark);
+			} catch (error) {
+				stream.destroy(error);
+			}
+		});
+	}
+}
+
+class Readable extends Stream {
+	constructor(options = {}) {
+		super(options);
+		const objectMode = Boolean(options.objectMode ?? options.readableObjectMode);
+		this[kReadable] = {
+			buffer: [],
+			length: 0,
+			flowing: null,
+			ended: false,
+			endEmitted: false,
+			reading: false,
+			flowScheduled: false,
+			objectMode,
+			highWaterMark: options.readableHighWaterMark ?? options.highWaterMark ?? (objectMode ? DEFAULT_OBJECT_HIGH_WATER_MARK : DEFAULT_HIGH_WATER_MARK),
+			decoder: null,
+			autoDestroy: options.autoDestroy ?? true,
+			pipes: [],
+		};
+		if (typeof options.read === "function") {
+			this._read = options.read;
+		}
+		if (options.encoding) {
+			this.setEncoding(options.encoding);
+		}
+	}
+
+	get readable() {
+		return !this[kDestroyed] && !this[kReadable].endEmitted;
+	}
+
+	get readableEnded() {
+		return this[kReadable].endEmitted;
+	}
+
+	get readableFlowing() {
+		return this[kReadable].flowing;
+	}
+
+	get readableLength() {
+		return this[kReadable].length;
+	}
+
+	get readableHighWaterMark() {
+		return this[kReadable].highWaterMark;
+	}
+
+	get readableObjectMode() {
+		return this[kReadable].objectMode;
+	}
+
+	_read() {
+		throw new Error("The _read() method is not implemented");
+	}
+
+	push(chunk, encoding) {
+		const state = this[kReadable];
+		state.reading = false;
+		if (chunk === null) {
+			state.ended = true;
+			const tail = state.decoder?.decode();
+			if (tail) {
+				state.buffer.push(tail);
+				state.length += tail.length;
+			}
+			scheduleFlow(this);
+			return false;
+		}
+		if (state.ended) {
+			this.destroy(new Error("stream.push() after EOF"));
+			return false;
+		}
+
+		if (!state.objectMode) {
+			chunk = toChunk(chunk, encoding);
+			if (state.decoder !== null) {
+				chunk = state.decoder.decode(chunk, {stream: true});
+			}
+			if (chunk.length === 0) {
+				return state.length < state.highWaterMark;
+			}
+		}
+		state.buffer.push(chunk);
+		state.length += state.objectMode ? 1 : chunk.length;
+		scheduleFlow(this);
+		return state.length < state.highWaterMark;
+	}
+
+	unshift(chunk, encoding) {
+		const state = this[kReadable];
+		if (!state.objectMode) {
+			chunk = toChunk(chunk, encoding);
+			if (state.decoder !== null) {
+				chunk = new TextDecoder(state.decoder.encoding).decode(chunk);
+			}
+		}
+		state.buffer.unshift(chunk);
+		state.length += state.objectMode ? 1 : chunk.length;
+		scheduleFlow(this);
+	}
+
+	/**
+	 * Returns the next buffered chunk, or `null` if none are buffered.
+	 * Unlike Node, chunks are not merged or split to match `size`.
+	 */
+	read() {
+		const state = this[kReadable];
+		let chunk = null;
+		if (state.buffer.length > 0) {
+			chunk = state.buffer.shift();
+			state.length -= state.objectMode ? 1 : chunk.length;
+		}
+		if (state.ended && state.buffer.length === 0) {
+			scheduleFlow(this);
+		} else {
+			maybeRead(this);
+		}
+		return chunk;
+	}
+
+	setEncoding(encoding) {
+		this[kReadable].decoder = new TextDecoder(encoding ?? "utf-8");
+		return this;
+	}
+
+	on(event, listener) {
+		super.on(event, listener);
+		const state = this[kReadable];
+		if (event === "data" && state.flowing !== false) {
+			this.resume();
+		} else if (event === "readable") {
+			state.flowing = false;
+			scheduleFlow(this);
+			maybeRead(this);
+		}
+		return this;
+	}
+
+	pause() {
+		this[kReadable].flowing = false;
+		return this;
+	}
+
+	resume() {
+		this[kReadable].flowing = true;
+		scheduleFlow(this);
+		maybeRead(this);
+		return this;
+	}
+
+	isPaused() {
+		return this[kReadable].flowing === false;
+	}
+
+	pipe(destination, {end = true} = {}) {
+		let waiting = false;
+		const ondata = chunk => {
+			if (destination.write(chunk) === false && !waiting) {
+				waiting = true;
+				this.pause();
+				destination.once("drain", () => {
+					waiting = false;
+					this.resume();
+				});
+			}
+		};
+		const onend = () => destination.end();
+
+		this[kReadable].pipes.push({destination, ondata, onend});
+		this.on("data", ondata);
+		if (end) {
+			this.once("end", onend);
+		}
+		destination.emit("pipe", this);
+		return destination;
+	}
+
+	unpipe(destination) {
+		const pipes = this[kReadable].pipes;
+		for (const pipe of [...pipes]) {
+			if (destination === undefined || pipe.destination === destination) {
+				this.off("data", pipe.ondata);
+				this.off("end", pipe.onend);
+				pipes.splice(pipes.indexOf(pipe), 1);
+				pipe.destination.emit("unpipe", this);
+			}
+		}
+		if (pipes.length === 0) {
+			this.pause();
+		}
+		return this;
+	}
+
+	async* [Symbol.asyncIterator]() {
+		const state = this[kReadable];
+		let error = null;
+		let wake = null;
+		const notify = () => wake?.();
+		const onerror = err => {
+			error = err;
+			notify();
+		};
+		this.on("readable", notify);
+		this.on("end", notify);
+		this.on("close", notify);
+		this.on("error", onerror);
+
+		try {
+			while (true) {
+				const chunk = this.read();
+				if (chunk !== null) {
+					yield chunk;
+				} else if (error !== null) {
+					throw error;
+				} else if (state.endEmitted || this[kDestroyed]) {
+					break;
+				} else {
+					await new Promise(resolve => (wake = resolve));
+					wake = null;
+				}
+			}
+		} finally {
+			this.off("readable", notify);
+			this.off("end", notify);
+			this.off("close", notify);
+			this.off("error", onerror);
+			if (!state.endEmitted) {
+				this.destroy();
+			}
+		}
+	}
+
+	static from(iterable, options) {
+		const iterator = iterable[Symbol.asyncIterator]?.() ?? iterable[Symbol.iterator]();
+		return new Readable({
+			objectMode: true,
+			...options,
+			read() {
+				Promise.resolve(iterator.next()).then(
+					({done, value}) => this.push(done ? null : value),
+					error => this.destroy(error),
+				);
+			},
+			destroy(error, callback) {
+				Promise.resolve(iterator.return?.()).then(() => callback(error), callback);
+			},
+		});
+	}
+
+	static fromWeb(readableStream, options) {
+		const reader = readableStream.getReader();
+		return new Readable({
+			...options,
+			read() {
+				reader.read().then(
+					({done, value}) => this.push(done ? null : value),
+					error => this.destroy(error),
+				);
+			},
+			destroy(error, callback) {
+				reader.cancel(error ?? undefined).then(() => callback(error), callback);
+			},
+		});
+	}
+
+	static toWeb(readable) {
+		return new ReadableStream({
+			start(controller) {
+				readable.on("data", chunk => {
+					controller.enqueue(typeof chunk === "string" ? Buffer.from(chunk) : chunk);
+					if (controller.desiredSize <= 0) {
+						readable.pause();
+					}
+				});
+				readable.once("end", () => controller.close());
+				readable.once("error", error => controller.error(error));
+			},
+			pull() {
+				readable.resume();
+			},
+			cancel(reason) {
+				readable.destroy(reason);
+			},
+		});
+	}
+}
+
+function initWritable(stream, options) {
+	const objectMode = Boolean(options.objectMode ?? options.writableObjectMode);
+	stream[kWritable] = {
+		queue: [],
+		length: 0,
+		writing: false,
+		corked: 0,
+		ending: false,
+		finishing: false,
+		finished: false,
+		needDrain: false,
+		objectMode,
+		highWaterMark: options.writableHighWaterMark ?? options.highWaterMark ?? (objectMode ? DEFAULT_OBJECT_HIGH_WATER_MARK : DEFAULT_HIGH_WATER_MARK),
+		decodeStrings: options.decodeStrings !== false,
+		defaultEncoding: options.defaultEncoding ?? "utf8",
+		autoDestroy: options.autoDestroy ?? true,
+	};
+	if (typeof options.write === "function") {
+		stream._write = options.write;
+	}
+	if (typeof options.final === "function") {
+		stream._final = options.final;
+	}
+}
+
+function writeNext(stream) {
+	const state = stream[kWritable];
+	if (state.writing || state.corked > 0 || stream[kDestroyed]) {
+		return;
+	}
+
+	const entry = state.queue.shift();
+	if (entry === undefined) {
+		if (state.needDrain) {
+			state.needDrain = false;
+			stream.emit("drain");
+		}
+		if (state.ending) {
+			finishWritable(stream);
+		}
+		return;
+	}
+
+	state.writing = true;
+	stream._write(entry.chunk, entry.encoding, error => {
+		state.writing = false;
+		state.length -= entry.size;
+		queueMicrotask(() => {
+			entry.callback?.(error ?? null);
+			if (error) {
+				stream.destroy(error);
+			} else {
+				writeNext(stream);
+			}
+		});
+	});
+}
+
+function finishWritable(stream) {
+	const state = stream[kWritable];
+	if (state.finishing) {
+		return;
+	}
+	state.finishing = true;
+	stream._final(error => {
+		queueMicrotask(() => {
+			if (error) {
+				stream.destroy(error);
+				return;
+			}
+			state.finished = true;
+			stream.emit("finish");
+			const readable = stream[kReadable];
+			if (state.autoDestroy && (readable === undefined || readable.endEmitted)) {
+				stream.destroy();
+			}
+		});
+	});
+}
+
+const WritableMethods = {
+	get writable() {
+		const state = this[kWritable];
+		return !this[kDestroyed] && !state.ending;
+	},
+
+	get writableEnded() {
+		return this[kWritable].ending;
+	},
+
+	get writableFinished() {
+		return this[kWritable].finished;
+	},
+
+	get writableLength() {
+		return this[kWritable].length;
+	},
+
+	get writableHighWaterMark() {
+		return this[kWritable].highWaterMark;
+	},
+
+	get writableObjectMode() {
+		return this[kWritable].objectMode;
+	},
+
+	get writableNeedDrain() {
+		return this[kWritable].needDrain;
+	},
+
+	get writableCorked() {
+		return this[kWritable].corked;
+	},
+
+	_write() {
+		throw new Error("The _write() method is not implemented");
+	},
+
+	_final(callback) {
+		callback();
+	},
+
+	write(chunk, encoding, callback) {
+		const state = this[kWritable];
+		if (typeof encoding === "function") {
+			[encoding, callback] = [undefined, encoding];
+		}
+		encoding ??= state.defaultEncoding;
+
+		if (state.ending || this[kDestroyed]) {
+			const error = new Error(state.ending ? "write after end" : "Cannot call write after a stream was destroyed");
+			queueMicrotask(() => {
+				callback?.(error);
+				this.emit("error", error);
+			});
+			return false;
+		}
+
+		if (!state.objectMode && (typeof chunk !== "string" || state.decodeStrings)) {
+			chunk = toChunk(chunk, encoding);
+			encoding = "buffer";
+		}
+		const size = state.objectMode ? 1 : chunk.length;
+		state.length += size;
+		const ok = state.length < state.highWaterMark;
+		if (!ok) {
+			state.needDrain = true;
+		}
+
+		state.queue.push({chunk, encoding, callback, size});
+		writeNext(this);
+		return ok;
+	},
+
+	end(chunk, encoding, callback) {
+		const state = this[kWritable];
+		if (typeof chunk === "function") {
+			[chunk, encoding, callback] = [undefined, undefined, chunk];
+		} else if (typeof encoding === "function") {
+			[encoding, callback] = [undefined, encoding];
+		}
+		if (chunk !== undefined && chunk !== null) {
+			this.write(chunk, encoding);
+		}
+		if (callback !== undefined) {
+			if (state.finished) {
+				queueMicrotask(callback);
+			} else {
+				this.once("finish", callback);
+			}
+		}
+		if (!state.ending) {
+			state.ending = true;
+			state.corked = 0;
+			writeNext(this);
+		}
+		return this;
+	},
+
+	cork() {
+		this[kWritable].corked++;
+	},
+
+	uncork() {
+		const state = this[kWritable];
+		if (state.corked > 0) {
+			state.corked--;
+			writeNext(this);
+		}
+	},
+
+	setDefaultEncoding(encoding) {
+		this[kWritable].defaultEncoding = encoding;
+		return this;
+	},
+};
+
+class Writable extends Stream {
+	constructor(options = {}) {
+		super(options);
+		initWritable(this, options);
+	}
+
+	static [Symbol.hasInstance](object) {
+		return Function.prototype[Symbol.hasInstance].call(this, object) || (this === Writable && object?.[kWritable] !== undefined);
+	}
+
+	static fromWeb(writableStream, options) {
+		const writer = writableStream.getWriter();
+		return new Writable({
+			...options,
+			write(chunk, _, callback) {
+				writer.write(chunk).then(() => callback(), callback);
+			},
+			final(callback) {
+				writer.close().then(() => callback(), callback);
+			},
+			destroy(error, callback) {
+				writer.abort(error ?? undefined).then(() => callback(error), () => callback(error));
+			},
+		});
+	}
+
+	static toWeb(writable) {
+		if (typeof WritableStream === "undefined") {
+			throw new TypeError("WritableStream is not supported");
+		}
+		return new WritableStream({
+			write(chunk) {
+				return new Promise((resolve, reject) => writable.write(chunk, error => (error ? reject(error) : resolve())));
+			},
+			close() {
+				return new Promise(resolve => writable.end(resolve));
+			},
+			abort(reason) {
+				writable.destroy(reason);
+			},
+		});
+	}
+}
+
+Object.defineProperties(Writable.prototype, Object.getOwnPropertyDescriptors(WritableMethods));
+
+class Duplex extends Readable {
+	constructor(options = {}) {
+		super(options);
+		initWritable(this, options);
+		if (options.allowHalfOpen === false) {
+			this.once("end", () => this.end());
+		}
+	}
+
+	static from(value) {
+		if (value instanceof Duplex) {
+			return value;
+		}
+		if (value?.readable !== undefined && value?.writable !== undefined) {
+			return Duplex.fromWeb(value);
+		}
+		throw new TypeError("Expected a Duplex or an object with readable and writable streams");
+	}
+
+	static fromWeb({readable, writable}, options) {
+		const reader = readable.getReader();
+		const writer = writable.getWriter();
+		return new Duplex({
+			...options,
+			read() {
+				reader.read().then(
+					({done, value}) => this.push(done ? null : value),
+					error => this.destroy(error),
+				);
+			},
+			write(chunk, _, callback) {
+				writer.write(chunk).then(() => callback(), callback);
+			},
+			final(callback) {
+				writer.close().then(() => callback(), callback);
+			},
+			destroy(error, callback) {
+				Promise.all([reader.cancel(error ?? undefined), writer.abort(error ?? undefined)]).then(() => callback(error), () => callback(error));
+			},
+		});
+	}
+
+	static toWeb(duplex) {
+		return {
+			readable: Readable.toWeb(duplex),
+			writable: Writable.toWeb(duplex),
+		};
+	}
+}
+
+Object.defineProperties(Duplex.prototype, Object.getOwnPropertyDescriptors(WritableMethods));
+
+class Transform extends Duplex {
+	constructor(options = {}) {
+		super(options);
+		if (typeof options.transform === "function") {
+			this._transform = options.transform;
+		}
+		if (typeof options.flush === "function") {
+			this._flush = options.flush;
+		}
+	}
+
+	_read() {}
+
+	_transform() {
+		throw new Error("The _transform() method is not implemented");
+	}
+
+	_write(chunk, encoding, callback) {
+		this._transform(chunk, encoding, (error, data) => {
+			if (error) {
+				callback(error);
+				return;
+			}
+			if (data !== undefined && data !== null) {
+				this.push(data);
+			}
+			callback();
+		});
+	}
+
+	_final(callback) {
+		const done = (error, data) => {
+			if (error) {
+				callback(error);
+				return;
+			}
+			if (data !== undefined && data !== null) {
+				this.push(data);
+			}
+			this.push(null);
+			callback();
+		};
+		if (typeof this._flush === "function") {
+			this._flush(done);
+		} else {
+			done();
+		}
+	}
+}
+
+class PassThrough extends Transform {
+	_transform(chunk, _, callback) {
+		callback(null, chunk);
+	}
+}
+
+function finished(stream, callback) {
+	let called = false;
+	const done = error => {
+		if (!called) {
+			called = true;
+			cleanup();
+			callback(error ?? undefined);
+		}
+	};
+	const onend = () => {
+		if (stream[kWritable] === undefined || stream[kWritable].finished) {
+			done();
+		}
+	};
+	const onfinish = () => {
+		if (stream[kReadable] === undefined || stream[kReadable].endEmitted) {
+			done();
+		}
+	};
+	const onclose = () => done(stream[kReadable]?.endEmitted || stream[kWritable]?.finished ? undefined : new Error("Premature close"));
+	const cleanup = () => {
+		stream.off("end", onend);
+		stream.off("finish", onfinish);
+		stream.off("error", done);
+		stream.off("close", onclose);
+	};
+
+	stream.on("end", onend);
+	stream.on("finish", onfinish);
+	stream.on("error", done);
+	stream.on("close", onclose);
+	return cleanup;
+}
+
+function pipeline(...streams) {
+	const callback = typeof streams.at(-1) === "function" ? streams.pop() : () => {};
+	let error = null;
+	let remaining = streams.length;
+	const destroyAll = err => {
+		error ??= err;
+		for (const stream of streams) {
+			stream.destroy(err);
+		}
+	};
+
+	for (let i = 0; i < streams.length; i++) {
+		if (i < streams.length - 1) {
+			streams[i].pipe(streams[i + 1]);
+		}
+		finished(streams[i], err => {
+			if (remaining === 0) {
+				return;
+			}
+			if (err) {
+				destroyAll(err);
+			}
+			if (--remaining === 0 || error !== null) {
+				// The callback is only called once, even though the other streams finish with errors after being destroyed.
+				remaining = 0;
+				callback(error ?? undefined);
+			}
+		});
+	}
+	return streams.at(-1);
+}
+
+const promises = Object.freeze({
+	finished(stream) {
+		return new Promise((resolve, reject) => finished(stream, error => (error ? reject(error) : resolve())));
+	},
+	pipeline(...streams) {
+		return new Promise((resolve, reject) => pipeline(...streams, error => (error ? reject(error) : resolve())));
+	},
+});
+
+Object.defineProperties(______streamInternal______, {
+	Stream: {value: Stream, enumerable: true},
+	Readable: {value: Readable, enumerable: true},
+	Writable: {value: Writable, enumerable: true},
+	Duplex: {value: Duplex, enumerable: true},
+	Transform: {value: Transform, enumerable: true},
+	PassThrough: {value: PassThrough, enumerable: true},
+	finished: {value: finished, enumerable: true},
+	pipeline: {value: pipeline, enumerable: true},
+	promises: {value: promises, enumerable: true},
+});
+
+export {Stream, Readable, Writable, Duplex, Transform, PassThrough, finished, pipeline, promises};
+
+export default Object.freeze(______streamInternal______);
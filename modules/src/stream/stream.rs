@@ -0,0 +1,21 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use ion::{Context, Object};
+use runtime::module::NativeModule;
+
+#[derive(Default)]
+pub struct StreamM;
+
+impl NativeModule for StreamM {
+	const NAME: &'static str = "stream";
+	const VARIABLE_NAME: &'static str = "stream";
+	const SOURCE: &'static str = include_str!("stream.js");
+
+	fn module(cx: &Context) -> Option<Object> {
+		Some(Object::new(cx))
+	}
+}
@@ -0,0 +1,47 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+const streams = new WeakMap();
+
+function readable(read) {
+	return new ReadableStream({
+		async pull(controller) {
+			const chunk = await read();
+			if (chunk === null) {
+				controller.close();
+			} else {
+				controller.enqueue(chunk);
+			}
+		},
+	});
+}
+
+function defineStream(name, read) {
+	Object.defineProperty(______subprocessInternal______.Subprocess.prototype, name, {
+		get() {
+			let cache = streams.get(this);
+			if (cache === undefined) {
+				cache = {};
+				streams.set(this, cache);
+			}
+			if (cache[name] === undefined) {
+				cache[name] = readable(() => read.call(this));
+			}
+			return cache[name];
+		},
+		configurable: true,
+		enumerable: true,
+	});
+}
+
+defineStream("stdout", ______subprocessInternal______.Subprocess.prototype.readStdout);
+defineStream("stderr", ______subprocessInternal______.Subprocess.prototype.readStderr);
+
+export const spawn = ______subprocessInternal______.spawn;
+export const output = ______subprocessInternal______.output;
+export const Subprocess = ______subprocessInternal______.Subprocess;
+
+export default Object.freeze(______subprocessInternal______);
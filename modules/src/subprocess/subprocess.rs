@@ -0,0 +1,366 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::Cell;
+use std::io;
+use std::process::{ExitStatus, Stdio};
+use std::rc::Rc;
+
+use ion::class::{NativeObject, Reflector};
+use ion::conversions::{FromValue, IntoValue, ToValue};
+use ion::function::Opt;
+use ion::typedarray::Uint8ArrayWrapper;
+use ion::{ClassDefinition, Context, Error, ErrorKind, Object, OwnedKey, Promise, Result, Value};
+use mozjs::jsapi::{JSFunctionSpec, JSObject};
+use runtime::globals::file::BufferSource;
+use runtime::module::NativeModule;
+use runtime::promise::future_to_promise;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::process::{Child, ChildStderr, ChildStdin, ChildStdout, Command};
+use tokio::sync::Mutex;
+
+const CHUNK_SIZE: usize = 64 * 1024;
+
+fn spawn_error(command: &str, err: io::Error) -> Error {
+	Error::new(format!("Could not spawn process: {command}\n{err}"), None)
+}
+
+fn stdio_error(stream: &str) -> Error {
+	Error::new(format!("Subprocess {stream} is not piped"), ErrorKind::Type)
+}
+
+fn exited_error() -> Error {
+	Error::new("Subprocess has already exited", None)
+}
+
+fn parse_stdio(stdio: Option<&str>, default: fn() -> Stdio) -> Result<Stdio> {
+	match stdio {
+		None => Ok(default()),
+		Some("inherit") => Ok(Stdio::inherit()),
+		Some("piped") => Ok(Stdio::piped()),
+		Some("null") => Ok(Stdio::null()),
+		Some(stdio) => Err(Error::new(
+			format!("Expected 'inherit', 'piped' or 'null' for stdio, received '{stdio}'"),
+			ErrorKind::Type,
+		)),
+	}
+}
+
+#[cfg(unix)]
+fn parse_signal(signal: &str) -> Result<i32> {
+	let signal = match signal.strip_prefix("SIG").unwrap_or(signal) {
+		"HUP" => libc::SIGHUP,
+		"INT" => libc::SIGINT,
+		"QUIT" => libc::SIGQUIT,
+		"KILL" => libc::SIGKILL,
+		"TERM" => libc::SIGTERM,
+		"USR1" => libc::SIGUSR1,
+		"USR2" => libc::SIGUSR2,
+		"STOP" => libc::SIGSTOP,
+		"CONT" => libc::SIGCONT,
+		_ => return Err(Error::new(format!("Unknown signal: {signal}"), ErrorKind::Type)),
+	};
+	Ok(signal)
+}
+
+#[cfg(unix)]
+fn send_signal(pid: Option<u32>, signal: Option<&str>) -> Result<()> {
+	let pid = pid.ok_or_else(exited_error)?;
+	let signal = parse_signal(signal.unwrap_or("SIGTERM"))?;
+	if unsafe { libc::kill(pid as libc::pid_t, signal) } == -1 {
+		return Err(Error::new(io::Error::last_os_error().to_string(), None));
+	}
+	Ok(())
+}
+
+#[derive(Default, FromValue)]
+pub struct SpawnOptions<'cx> {
+	args: Option<Vec<String>>,
+	cwd: Option<String>,
+	env: Option<Object<'cx>>,
+	clear_env: Option<bool>,
+	stdin: Option<String>,
+	stdout: Option<String>,
+	stderr: Option<String>,
+}
+
+impl SpawnOptions<'_> {
+	fn to_command(&self, cx: &Context, program: &str, default: fn() -> Stdio) -> Result<Command> {
+		let mut command = Command::new(program);
+		if let Some(args) = &self.args {
+			command.args(args);
+		}
+		if let Some(cwd) = &self.cwd {
+			command.current_dir(cwd);
+		}
+		if self.clear_env.unwrap_or(false) {
+			command.env_clear();
+		}
+		if let Some(env) = &self.env {
+			for (key, value) in env.iter(cx, None) {
+				if let OwnedKey::String(key) = key.to_owned_key(cx)? {
+					let value = String::from_value(cx, &value?, false, ())?;
+					command.env(key, value);
+				}
+			}
+		}
+
+		command.stdin(parse_stdio(self.stdin.as_deref(), default)?);
+		command.stdout(parse_stdio(self.stdout.as_deref(), default)?);
+		command.stderr(parse_stdio(self.stderr.as_deref(), default)?);
+		Ok(command)
+	}
+}
+
+pub struct ProcessStatus {
+	success: bool,
+	code: Option<i32>,
+	signal: Option<i32>,
+}
+
+impl From<ExitStatus> for ProcessStatus {
+	fn from(status: ExitStatus) -> ProcessStatus {
+		#[cfg(unix)]
+		let signal = std::os::unix::process::ExitStatusExt::signal(&status);
+		#[cfg(not(unix))]
+		let signal = None;
+
+		ProcessStatus {
+			success: status.success(),
+			code: status.code(),
+			signal,
+		}
+	}
+}
+
+impl<'cx> ToValue<'cx> for ProcessStatus {
+	fn to_value(&self, cx: &'cx Context, value: &mut Value) {
+		let object = Object::new(cx);
+		object.set_as(cx, "success", &self.success);
+		object.set_as(cx, "code", &self.code);
+		object.set_as(cx, "signal", &self.signal);
+		object.to_value(cx, value);
+	}
+}
+
+pub struct ProcessOutput {
+	status: ProcessStatus,
+	stdout: Vec<u8>,
+	stderr: Vec<u8>,
+}
+
+impl<'cx> IntoValue<'cx> for ProcessOutput {
+	fn into_value(self: Box<Self>, cx: &'cx Context, value: &mut Value) {
+		let object = Object::new(cx);
+		object.set_as(cx, "status", &self.status);
+
+		let mut stdout = Value::undefined(cx);
+		Box::new(Uint8ArrayWrapper::from(self.stdout)).into_value(cx, &mut stdout);
+		object.set(cx, "stdout", &stdout);
+
+		let mut stderr = Value::undefined(cx);
+		Box::new(Uint8ArrayWrapper::from(self.stderr)).into_value(cx, &mut stderr);
+		object.set(cx, "stderr", &stderr);
+
+		object.to_value(cx, value);
+	}
+}
+
+/// Chunk read from a piped stream, or `null` once the stream has ended.
+pub struct Chunk(Option<Vec<u8>>);
+
+impl<'cx> IntoValue<'cx> for Chunk {
+	fn into_value(self: Box<Self>, cx: &'cx Context, value: &mut Value) {
+		match self.0 {
+			Some(chunk) => Box::new(Uint8ArrayWrapper::from(chunk)).into_value(cx, value),
+			None => value.handle_mut().set(mozjs::jsval::NullValue()),
+		}
+	}
+}
+
+#[js_class]
+pub struct Subprocess {
+	reflector: Reflector,
+	#[trace(no_trace)]
+	pid: Option<u32>,
+	#[trace(no_trace)]
+	child: Rc<Mutex<Child>>,
+	#[trace(no_trace)]
+	exited: Rc<Cell<bool>>,
+	#[trace(no_trace)]
+	stdin: Rc<Mutex<Option<ChildStdin>>>,
+	#[trace(no_trace)]
+	stdout: Rc<Mutex<Option<ChildStdout>>>,
+	#[trace(no_trace)]
+	stderr: Rc<Mutex<Option<ChildStderr>>>,
+}
+
+#[js_class]
+impl Subprocess {
+	#[ion(get)]
+	pub fn get_pid(&self) -> Option<u32> {
+		self.pid
+	}
+
+	/// Waits for the process to exit and resolves with its status.
+	pub fn status<'cx>(&self, cx: &'cx Context) -> Option<Promise<'cx>> {
+		let child = Rc::clone(&self.child);
+		let stdin = Rc::clone(&self.stdin);
+		let exited = Rc::clone(&self.exited);
+		future_to_promise::<_, _, Error>(cx, async move {
+			stdin.lock().await.take();
+			let status = child.lock().await.wait().await;
+			exited.set(status.is_ok());
+			status.map(ProcessStatus::from).map_err(|err| Error::new(err.to_string(), None))
+		})
+	}
+
+	/// Sends a signal to the process, defaulting to `SIGTERM`.
+	/// Throws once the process has exited and been reaped, as its PID may have been reused by another process.
+	pub fn kill(&self, Opt(signal): Opt<String>) -> Result<()> {
+		if self.exited.get() {
+			return Err(exited_error());
+		}
+
+		match self.child.try_lock() {
+			Ok(mut child) => {
+				if child.try_wait().map_err(|err| Error::new(err.to_string(), None))?.is_some() {
+					self.exited.set(true);
+					return Err(exited_error());
+				}
+				#[cfg(unix)]
+				{
+					send_signal(child.id(), signal.as_deref())
+				}
+				#[cfg(not(unix))]
+				{
+					let _ = signal;
+					child.start_kill().map_err(|err| Error::new(err.to_string(), None))
+				}
+			}
+			// While the status is awaited, the process is only reaped by that wait, which marks it as exited before
+			// any script can run.
+			#[cfg(unix)]
+			Err(_) => send_signal(self.pid, signal.as_deref()),
+			#[cfg(not(unix))]
+			Err(_) => Err(Error::new("Subprocess cannot be killed while awaiting status", None)),
+		}
+	}
+
+	#[ion(name = "readStdout")]
+	pub fn read_stdout<'cx>(&self, cx: &'cx Context) -> Option<Promise<'cx>> {
+		let stdout = Rc::clone(&self.stdout);
+		future_to_promise::<_, _, Error>(cx, async move {
+			let mut stdout = stdout.lock().await;
+			let stdout = stdout.as_mut().ok_or_else(|| stdio_error("stdout"))?;
+			read_chunk(stdout).await
+		})
+	}
+
+	#[ion(name = "readStderr")]
+	pub fn read_stderr<'cx>(&self, cx: &'cx Context) -> Option<Promise<'cx>> {
+		let stderr = Rc::clone(&self.stderr);
+		future_to_promise::<_, _, Error>(cx, async move {
+			let mut stderr = stderr.lock().await;
+			let stderr = stderr.as_mut().ok_or_else(|| stdio_error("stderr"))?;
+			read_chunk(stderr).await
+		})
+	}
+
+	#[ion(name = "writeStdin")]
+	pub fn write_stdin<'cx>(
+		&self, cx: &'cx Context, #[ion(convert = false)] data: BufferSource<'cx>,
+	) -> Option<Promise<'cx>> {
+		let stdin = Rc::clone(&self.stdin);
+		let data = data.to_vec();
+		future_to_promise::<_, _, Error>(cx, async move {
+			let mut stdin = stdin.lock().await;
+			let stdin = stdin.as_mut().ok_or_else(|| stdio_error("stdin"))?;
+			stdin.write_all(&data).await.map_err(|err| Error::new(err.to_string(), None))
+		})
+	}
+
+	#[ion(name = "closeStdin")]
+	pub fn close_stdin<'cx>(&self, cx: &'cx Context) -> Option<Promise<'cx>> {
+		let stdin = Rc::clone(&self.stdin);
+		future_to_promise::<_, _, Error>(cx, async move {
+			stdin.lock().await.take();
+			Ok(())
+		})
+	}
+}
+
+async fn read_chunk<R: AsyncReadExt + Unpin>(reader: &mut R) -> Result<Chunk> {
+	let mut buf = vec![0; CHUNK_SIZE];
+	match reader.read(&mut buf).await {
+		Ok(0) => Ok(Chunk(None)),
+		Ok(read) => {
+			buf.truncate(read);
+			Ok(Chunk(Some(buf)))
+		}
+		Err(err) => Err(Error::new(err.to_string(), None)),
+	}
+}
+
+#[js_fn]
+fn spawn(cx: &Context, program: String, Opt(options): Opt<SpawnOptions>) -> Result<*mut JSObject> {
+	let options = options.unwrap_or_default();
+	let mut command = options.to_command(cx, &program, Stdio::inherit)?;
+	let mut child = command.spawn().map_err(|err| spawn_error(&program, err))?;
+
+	let subprocess = Subprocess {
+		reflector: Reflector::default(),
+		pid: child.id(),
+		stdin: Rc::new(Mutex::new(child.stdin.take())),
+		stdout: Rc::new(Mutex::new(child.stdout.take())),
+		stderr: Rc::new(Mutex::new(child.stderr.take())),
+		child: Rc::new(Mutex::new(child)),
+		exited: Rc::new(Cell::new(false)),
+	};
+	Ok(Subprocess::new_object(cx, Box::new(subprocess)))
+}
+
+#[js_fn]
+fn output<'cx>(cx: &'cx Context, program: String, Opt(options): Opt<SpawnOptions>) -> Result<Option<Promise<'cx>>> {
+	let options = options.unwrap_or_default();
+	let mut command = options.to_command(cx, &program, Stdio::piped)?;
+	if options.stdin.is_none() {
+		command.stdin(Stdio::null());
+	}
+
+	Ok(future_to_promise::<_, _, Error>(cx, async move {
+		let output = command.output().await.map_err(|err| spawn_error(&program, err))?;
+		Ok(ProcessOutput {
+			status: ProcessStatus::from(output.status),
+			stdout: output.stdout,
+			stderr: output.stderr,
+		})
+	}))
+}
+
+const FUNCTIONS: &[JSFunctionSpec] = &[
+	function_spec!(spawn, 1),
+	function_spec!(output, 1),
+	JSFunctionSpec::ZERO,
+];
+
+#[derive(Default)]
+pub struct SubprocessM;
+
+impl NativeModule for SubprocessM {
+	const NAME: &'static str = "subprocess";
+	const VARIABLE_NAME: &'static str = "subprocess";
+	const SOURCE: &'static str = include_str!("subprocess.js");
+
+	fn module(cx: &Context) -> Option<Object> {
+		let subprocess = Object::new(cx);
+		if unsafe { subprocess.define_methods(cx, FUNCTIONS) } && Subprocess::init_class(cx, &subprocess).0 {
+			Some(subprocess)
+		} else {
+			None
+		}
+	}
+}
@@ -0,0 +1,35 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+async function* keys() {
+	const raw = ______ttyInternal______.isRawMode();
+	______ttyInternal______.setRawMode(true);
+	try {
+		while (true) {
+			yield await ______ttyInternal______.readKey();
+		}
+	} finally {
+		______ttyInternal______.setRawMode(raw);
+	}
+}
+
+Object.defineProperty(______ttyInternal______, "keys", {
+	value: keys,
+	enumerable: true,
+});
+
+export const prompt = ______ttyInternal______.prompt;
+export const confirm = ______ttyInternal______.confirm;
+export const password = ______ttyInternal______.password;
+export const readKey = ______ttyInternal______.readKey;
+export const setRawMode = ______ttyInternal______.setRawMode;
+export const isRawMode = ______ttyInternal______.isRawMode;
+export {keys};
+export const size = ______ttyInternal______.size;
+export const isatty = ______ttyInternal______.isatty;
+export const supportsColor = ______ttyInternal______.supportsColor;
+
+export default Object.freeze(______ttyInternal______);
@@ -0,0 +1,258 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::env;
+use std::io::{self, BufRead, IsTerminal, Write};
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::terminal;
+use ion::conversions::{ConversionBehavior, ToValue};
+use ion::function::Opt;
+use ion::{Context, Error, ErrorKind, Object, Promise, Result, Value};
+use mozjs::jsapi::JSFunctionSpec;
+use runtime::module::NativeModule;
+use runtime::promise::future_to_promise;
+use tokio::task::spawn_blocking;
+
+fn io_error(err: io::Error) -> Error {
+	Error::new(err.to_string(), None)
+}
+
+async fn blocking<T: Send + 'static>(f: impl FnOnce() -> io::Result<T> + Send + 'static) -> Result<T> {
+	let result = spawn_blocking(f).await.map_err(|err| Error::new(err.to_string(), None))?;
+	result.map_err(io_error)
+}
+
+fn write_message(message: &str) -> io::Result<()> {
+	let mut stdout = io::stdout().lock();
+	stdout.write_all(message.as_bytes())?;
+	stdout.flush()
+}
+
+/// Reads a line from standard input, without the trailing newline.
+/// Returns [None] once standard input has ended.
+fn read_line() -> io::Result<Option<String>> {
+	let mut line = String::new();
+	if io::stdin().lock().read_line(&mut line)? == 0 {
+		return Ok(None);
+	}
+	let len = line.trim_end_matches(['\r', '\n']).len();
+	line.truncate(len);
+	Ok(Some(line))
+}
+
+/// Reads a line from standard input in raw mode, so the input is not echoed.
+fn read_password() -> io::Result<String> {
+	let mut password = String::new();
+	terminal::enable_raw_mode()?;
+	let result = loop {
+		match event::read() {
+			Ok(Event::Key(key)) if key.kind != KeyEventKind::Release => match key.code {
+				KeyCode::Enter => break Ok(()),
+				KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+					break Err(io::Error::new(io::ErrorKind::Interrupted, "Input was interrupted"));
+				}
+				KeyCode::Char(char) => password.push(char),
+				KeyCode::Backspace => {
+					password.pop();
+				}
+				_ => {}
+			},
+			Ok(_) => {}
+			Err(err) => break Err(err),
+		}
+	};
+	terminal::disable_raw_mode()?;
+	write_message("\n")?;
+	result.map(|_| password)
+}
+
+pub struct KeyEvent {
+	key: String,
+	ctrl: bool,
+	alt: bool,
+	shift: bool,
+	meta: bool,
+}
+
+impl From<event::KeyEvent> for KeyEvent {
+	fn from(event: event::KeyEvent) -> KeyEvent {
+		let key = match event.code {
+			KeyCode::Char(char) => String::from(char),
+			KeyCode::F(number) => format!("F{number}"),
+			code => String::from(match code {
+				KeyCode::Backspace => "Backspace",
+				KeyCode::Enter => "Enter",
+				KeyCode::Left => "ArrowLeft",
+				KeyCode::Right => "ArrowRight",
+				KeyCode::Up => "ArrowUp",
+				KeyCode::Down => "ArrowDown",
+				KeyCode::Home => "Home",
+				KeyCode::End => "End",
+				KeyCode::PageUp => "PageUp",
+				KeyCode::PageDown => "PageDown",
+				KeyCode::Tab | KeyCode::BackTab => "Tab",
+				KeyCode::Delete => "Delete",
+				KeyCode::Insert => "Insert",
+				KeyCode::Esc => "Escape",
+				_ => "Unidentified",
+			}),
+		};
+		let modifiers = event.modifiers;
+		KeyEvent {
+			key,
+			ctrl: modifiers.contains(KeyModifiers::CONTROL),
+			alt: modifiers.contains(KeyModifiers::ALT),
+			shift: modifiers.contains(KeyModifiers::SHIFT) || event.code == KeyCode::BackTab,
+			meta: modifiers.intersects(KeyModifiers::META | KeyModifiers::SUPER),
+		}
+	}
+}
+
+impl<'cx> ToValue<'cx> for KeyEvent {
+	fn to_value(&self, cx: &'cx Context, value: &mut Value) {
+		let object = Object::new(cx);
+		object.set_as(cx, "key", &self.key);
+		object.set_as(cx, "ctrl", &self.ctrl);
+		object.set_as(cx, "alt", &self.alt);
+		object.set_as(cx, "shift", &self.shift);
+		object.set_as(cx, "meta", &self.meta);
+		object.to_value(cx, value);
+	}
+}
+
+/// Prints the message and resolves with the line entered, the default if it was empty, or `null` at the end of input.
+#[js_fn]
+fn prompt<'cx>(cx: &'cx Context, Opt(message): Opt<String>, Opt(default): Opt<String>) -> Option<Promise<'cx>> {
+	future_to_promise::<_, _, Error>(cx, async move {
+		let line = blocking(move || {
+			write_message(message.as_deref().unwrap_or(""))?;
+			read_line()
+		})
+		.await?;
+		Ok(match line {
+			Some(line) if line.is_empty() => default.or(Some(line)),
+			line => line,
+		})
+	})
+}
+
+/// Prints the message followed by `[y/N]` and resolves with whether the answer was affirmative.
+#[js_fn]
+fn confirm<'cx>(cx: &'cx Context, Opt(message): Opt<String>) -> Option<Promise<'cx>> {
+	future_to_promise::<_, _, Error>(cx, async move {
+		let line = blocking(move || {
+			write_message(&format!("{} [y/N] ", message.as_deref().unwrap_or("")))?;
+			read_line()
+		})
+		.await?;
+		Ok(line.is_some_and(|line| matches!(line.trim().to_ascii_lowercase().as_str(), "y" | "yes")))
+	})
+}
+
+/// Prints the message and resolves with the line entered, without echoing it to the terminal.
+#[js_fn]
+fn password<'cx>(cx: &'cx Context, Opt(message): Opt<String>) -> Option<Promise<'cx>> {
+	future_to_promise::<_, _, Error>(cx, async move {
+		blocking(move || {
+			write_message(message.as_deref().unwrap_or(""))?;
+			if io::stdin().is_terminal() {
+				read_password()
+			} else {
+				read_line().map(Option::unwrap_or_default)
+			}
+		})
+		.await
+	})
+}
+
+/// Resolves with the next key pressed. Raw mode must be enabled to receive keys before a newline.
+#[js_fn]
+fn read_key<'cx>(cx: &'cx Context) -> Option<Promise<'cx>> {
+	future_to_promise::<_, _, Error>(cx, async move {
+		blocking(|| loop {
+			if let Event::Key(key) = event::read()? {
+				if key.kind != KeyEventKind::Release {
+					return Ok(KeyEvent::from(key));
+				}
+			}
+		})
+		.await
+	})
+}
+
+#[js_fn]
+fn set_raw_mode(enabled: bool) -> Result<()> {
+	if enabled {
+		terminal::enable_raw_mode().map_err(io_error)
+	} else {
+		terminal::disable_raw_mode().map_err(io_error)
+	}
+}
+
+#[js_fn]
+fn is_raw_mode() -> Result<bool> {
+	terminal::is_raw_mode_enabled().map_err(io_error)
+}
+
+#[js_fn]
+fn size(cx: &Context) -> Result<Object> {
+	let (columns, rows) = terminal::size().map_err(io_error)?;
+	let size = Object::new(cx);
+	size.set_as(cx, "columns", &columns);
+	size.set_as(cx, "rows", &rows);
+	Ok(size)
+}
+
+/// Checks if the given file descriptor (`0`, `1` or `2`) refers to a terminal.
+#[js_fn]
+fn isatty(#[ion(convert = ConversionBehavior::EnforceRange)] fd: u8) -> Result<bool> {
+	match fd {
+		0 => Ok(io::stdin().is_terminal()),
+		1 => Ok(io::stdout().is_terminal()),
+		2 => Ok(io::stderr().is_terminal()),
+		_ => Err(Error::new("Expected 0, 1 or 2 for file descriptor", ErrorKind::Range)),
+	}
+}
+
+/// Checks if standard output is a terminal that accepts ANSI escape codes, respecting `NO_COLOR`.
+#[js_fn]
+fn supports_color() -> bool {
+	io::stdout().is_terminal()
+		&& env::var_os("NO_COLOR").map_or(true, |value| value.is_empty())
+		&& env::var("TERM").map_or(cfg!(windows), |term| term != "dumb")
+}
+
+const FUNCTIONS: &[JSFunctionSpec] = &[
+	function_spec!(prompt, 0),
+	function_spec!(confirm, 0),
+	function_spec!(password, 0),
+	function_spec!(read_key, "readKey", 0),
+	function_spec!(set_raw_mode, "setRawMode", 1),
+	function_spec!(is_raw_mode, "isRawMode", 0),
+	function_spec!(size, 0),
+	function_spec!(isatty, 1),
+	function_spec!(supports_color, "supportsColor", 0),
+	JSFunctionSpec::ZERO,
+];
+
+#[derive(Default)]
+pub struct Tty;
+
+impl NativeModule for Tty {
+	const NAME: &'static str = "tty";
+	const VARIABLE_NAME: &'static str = "tty";
+	const SOURCE: &'static str = include_str!("tty.js");
+
+	fn module(cx: &Context) -> Option<Object> {
+		let tty = Object::new(cx);
+		if unsafe { tty.define_methods(cx, FUNCTIONS) } {
+			Some(tty)
+		} else {
+			None
+		}
+	}
+}
@@ -0,0 +1,107 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+const {types} = ______utilInternal______;
+
+const AsyncFunction = Object.getPrototypeOf(async function() {}).constructor;
+const GeneratorFunction = Object.getPrototypeOf(function*() {}).constructor;
+const AsyncGeneratorFunction = Object.getPrototypeOf(async function*() {}).constructor;
+const TypedArray = Object.getPrototypeOf(Uint8Array);
+
+function promisify(original) {
+	if (typeof original !== "function") {
+		throw new TypeError("Expected a function to promisify");
+	}
+	return function(...args) {
+		return new Promise((resolve, reject) => {
+			original.call(this, ...args, (error, value) => {
+				if (error) {
+					reject(error);
+				} else {
+					resolve(value);
+				}
+			});
+		});
+	};
+}
+
+function callbackify(original) {
+	if (typeof original !== "function") {
+		throw new TypeError("Expected a function to callbackify");
+	}
+	return function(...args) {
+		const callback = args.pop();
+		if (typeof callback !== "function") {
+			throw new TypeError("Expected the last argument to be a callback");
+		}
+		Promise.resolve(original.apply(this, args)).then(
+			value => queueMicrotask(() => callback(null, value)),
+			error => queueMicrotask(() => callback(error ?? new Error("Promise was rejected with a falsy value"))),
+		);
+	};
+}
+
+function isBrand(method, value) {
+	try {
+		method.call(value, {});
+		return true;
+	} catch {
+		return false;
+	}
+}
+
+Object.defineProperties(types, {
+	isAsyncFunction: {
+		value: value => value instanceof AsyncFunction || value instanceof AsyncGeneratorFunction,
+		enumerable: true,
+	},
+	isGeneratorFunction: {
+		value: value => value instanceof GeneratorFunction || value instanceof AsyncGeneratorFunction,
+		enumerable: true,
+	},
+	isGeneratorObject: {
+		value: value => ["[object Generator]", "[object AsyncGenerator]"].includes(Object.prototype.toString.call(value)),
+		enumerable: true,
+	},
+	isWeakMap: {
+		value: value => isBrand(WeakMap.prototype.has, value),
+		enumerable: true,
+	},
+	isWeakSet: {
+		value: value => isBrand(WeakSet.prototype.has, value),
+		enumerable: true,
+	},
+	isTypedArray: {
+		value: value => value instanceof TypedArray,
+		enumerable: true,
+	},
+	isUint8Array: {
+		value: value => value instanceof Uint8Array,
+		enumerable: true,
+	},
+	isDataView: {
+		value: value => value instanceof DataView,
+		enumerable: true,
+	},
+});
+Object.freeze(types);
+
+Object.defineProperties(______utilInternal______, {
+	promisify: {
+		value: promisify,
+		enumerable: true,
+	},
+	callbackify: {
+		value: callbackify,
+		enumerable: true,
+	},
+});
+
+export const inspect = ______utilInternal______.inspect;
+export const format = ______utilInternal______.format;
+export {promisify, callbackify, types};
+
+export default Object.freeze(______utilInternal______);
@@ -0,0 +1,176 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use ion::conversions::ConversionBehavior;
+use ion::flags::PropertyFlags;
+use ion::format::{format_value, ColourConfig, Config as FormatConfig};
+use ion::function::{Opt, Rest};
+use ion::typedarray::{ArrayBuffer, ArrayBufferView};
+use ion::{Context, Object, Value};
+use mozjs::jsapi::{ESClass, IsProxy, JSFunctionSpec};
+use runtime::globals::console::format_message;
+use runtime::module::NativeModule;
+
+#[derive(Default, FromValue)]
+pub struct InspectOptions {
+	#[ion(convert = ConversionBehavior::Clamp)]
+	depth: Option<u16>,
+	#[ion(convert = ConversionBehavior::Clamp)]
+	indentation: Option<u16>,
+	colors: Option<bool>,
+	multiline: Option<bool>,
+}
+
+/// Formats a value in the same way as the console.
+#[js_fn]
+fn inspect<'cx>(cx: &'cx Context, value: Value<'cx>, Opt(options): Opt<InspectOptions>) -> String {
+	let options = options.unwrap_or_default();
+	let mut config = FormatConfig::default().quoted(true);
+	if let Some(depth) = options.depth {
+		config = config.depth(depth);
+	}
+	if let Some(indentation) = options.indentation {
+		config = config.indentation(indentation);
+	}
+	if let Some(multiline) = options.multiline {
+		config = config.multiline(multiline);
+	}
+	if !options.colors.unwrap_or(false) {
+		config = config.colours(ColourConfig::none());
+	}
+	format_value(cx, config, &value).to_string()
+}
+
+/// Formats the arguments in the same way as the console, applying `printf`-style format specifiers.
+#[js_fn]
+fn format(cx: &Context, Rest(values): Rest<Value>) -> String {
+	format_message(cx, &values)
+}
+
+fn is_class(cx: &Context, value: &Value, class: ESClass) -> bool {
+	value.handle().is_object() && value.to_object(cx).get_builtin_class(cx) == class
+}
+
+#[js_fn]
+fn is_promise(cx: &Context, value: Value) -> bool {
+	is_class(cx, &value, ESClass::Promise)
+}
+
+#[js_fn]
+fn is_map(cx: &Context, value: Value) -> bool {
+	is_class(cx, &value, ESClass::Map)
+}
+
+#[js_fn]
+fn is_set(cx: &Context, value: Value) -> bool {
+	is_class(cx, &value, ESClass::Set)
+}
+
+#[js_fn]
+fn is_map_iterator(cx: &Context, value: Value) -> bool {
+	is_class(cx, &value, ESClass::MapIterator)
+}
+
+#[js_fn]
+fn is_set_iterator(cx: &Context, value: Value) -> bool {
+	is_class(cx, &value, ESClass::SetIterator)
+}
+
+#[js_fn]
+fn is_date(cx: &Context, value: Value) -> bool {
+	is_class(cx, &value, ESClass::Date)
+}
+
+#[js_fn]
+fn is_reg_exp(cx: &Context, value: Value) -> bool {
+	is_class(cx, &value, ESClass::RegExp)
+}
+
+#[js_fn]
+fn is_native_error(cx: &Context, value: Value) -> bool {
+	is_class(cx, &value, ESClass::Error)
+}
+
+#[js_fn]
+fn is_arguments_object(cx: &Context, value: Value) -> bool {
+	is_class(cx, &value, ESClass::Arguments)
+}
+
+#[js_fn]
+fn is_array_buffer(cx: &Context, value: Value) -> bool {
+	is_class(cx, &value, ESClass::ArrayBuffer)
+}
+
+#[js_fn]
+fn is_shared_array_buffer(cx: &Context, value: Value) -> bool {
+	is_class(cx, &value, ESClass::SharedArrayBuffer)
+}
+
+#[js_fn]
+fn is_any_array_buffer(value: Value) -> bool {
+	value.handle().is_object() && ArrayBuffer::is_array_buffer(value.handle().to_object())
+}
+
+#[js_fn]
+fn is_array_buffer_view(value: Value) -> bool {
+	value.handle().is_object() && ArrayBufferView::is_array_buffer_view(value.handle().to_object())
+}
+
+#[js_fn]
+fn is_boxed_primitive(cx: &Context, value: Value) -> bool {
+	value.handle().is_object() && value.to_object(cx).is_boxed_primitive(cx).is_some()
+}
+
+#[js_fn]
+fn is_proxy(value: Value) -> bool {
+	value.handle().is_object() && unsafe { IsProxy(value.handle().to_object()) }
+}
+
+const FUNCTIONS: &[JSFunctionSpec] = &[
+	function_spec!(inspect, 1),
+	function_spec!(format, 0),
+	JSFunctionSpec::ZERO,
+];
+
+const TYPES: &[JSFunctionSpec] = &[
+	function_spec!(is_promise, "isPromise", 1),
+	function_spec!(is_map, "isMap", 1),
+	function_spec!(is_set, "isSet", 1),
+	function_spec!(is_map_iterator, "isMapIterator", 1),
+	function_spec!(is_set_iterator, "isSetIterator", 1),
+	function_spec!(is_date, "isDate", 1),
+	function_spec!(is_reg_exp, "isRegExp", 1),
+	function_spec!(is_native_error, "isNativeError", 1),
+	function_spec!(is_arguments_object, "isArgumentsObject", 1),
+	function_spec!(is_array_buffer, "isArrayBuffer", 1),
+	function_spec!(is_shared_array_buffer, "isSharedArrayBuffer", 1),
+	function_spec!(is_any_array_buffer, "isAnyArrayBuffer", 1),
+	function_spec!(is_array_buffer_view, "isArrayBufferView", 1),
+	function_spec!(is_boxed_primitive, "isBoxedPrimitive", 1),
+	function_spec!(is_proxy, "isProxy", 1),
+	JSFunctionSpec::ZERO,
+];
+
+#[derive(Default)]
+pub struct Util;
+
+impl NativeModule for Util {
+	const NAME: &'static str = "util";
+	const VARIABLE_NAME: &'static str = "util";
+	const SOURCE: &'static str = include_str!("util.js");
+
+	fn module(cx: &Context) -> Option<Object> {
+		let util = Object::new(cx);
+		let types = Object::new(cx);
+		if unsafe { util.define_methods(cx, FUNCTIONS) && types.define_methods(cx, TYPES) }
+			&& util.define_as(cx, "types", &types, PropertyFlags::CONSTANT_ENUMERATED)
+		{
+			Some(util)
+		} else {
+			None
+		}
+	}
+}
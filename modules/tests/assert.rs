@@ -19,6 +19,9 @@ const OK: (&str, &str) = ("ok", include_str!("scripts/assert/ok.js"));
 const EQUALS: (&str, &str) = ("equals", include_str!("scripts/assert/equals.js"));
 const THROWS: (&str, &str) = ("throws", include_str!("scripts/assert/throws.js"));
 const FAIL: (&str, &str) = ("fail", include_str!("scripts/assert/fail.js"));
+const DEEP_STRICT_EQUAL: (&str, &str) = ("deepStrictEqual", include_str!("scripts/assert/deepStrictEqual.js"));
+const REJECTS: (&str, &str) = ("rejects", include_str!("scripts/assert/rejects.js"));
+const MATCH: (&str, &str) = ("match", include_str!("scripts/assert/match.js"));
 
 const EXCEPTION_STRING: &str = "_spidermonkey_exception_";
 
@@ -40,6 +43,9 @@ async fn assert() {
 	eval_module(&rt, rt.cx(), EQUALS).await;
 	eval_module(&rt, rt.cx(), THROWS).await;
 	eval_module(&rt, rt.cx(), FAIL).await;
+	eval_module(&rt, rt.cx(), DEEP_STRICT_EQUAL).await;
+	eval_module(&rt, rt.cx(), REJECTS).await;
+	eval_module(&rt, rt.cx(), MATCH).await;
 }
 
 pub async fn eval_module(rt: &Runtime<'_>, cx: &Context, test: (&str, &str)) {
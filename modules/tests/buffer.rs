@@ -0,0 +1,14 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+mod common;
+
+const SCRIPT: &str = include_str!("scripts/buffer.js");
+
+#[tokio::test]
+async fn buffer() {
+	common::eval_module("buffer", SCRIPT).await;
+}
@@ -0,0 +1,55 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::path::Path;
+
+use ion::module::Module;
+use ion::{Context, Exception};
+use modules::Modules;
+use mozjs::jsapi::PromiseState;
+use mozjs::rust::{JSEngine, Runtime as RustRuntime};
+use runtime::config::{Config, CONFIG};
+use runtime::module::Loader;
+use runtime::RuntimeBuilder;
+
+/// Evaluates the script of a test as a module with the standard modules, running the event loop until it completes.
+/// Panics if the module throws, or its evaluation promise is rejected.
+pub async fn eval_module(test: &str, script: &str) {
+	CONFIG.set(Config::default()).unwrap();
+
+	let engine = JSEngine::init().unwrap();
+	let rt = RustRuntime::new(engine.handle());
+
+	let cx = &mut Context::from_runtime(&rt);
+	let rt = RuntimeBuilder::new()
+		.modules(Loader::default())
+		.standard_modules(Modules)
+		.microtask_queue()
+		.macrotask_queue()
+		.build(cx);
+
+	let filename = format!("{test}.js");
+	let path = format!("./tests/scripts/{test}.js");
+
+	let result = Module::compile_and_evaluate(rt.cx(), &filename, Some(Path::new(&path)), script);
+	let promise = match result {
+		Ok((_, promise)) => promise.unwrap(),
+		Err(error) => panic!("{filename}: {}", error.report.format(rt.cx())),
+	};
+
+	if let Err(Some(report)) = rt.run_event_loop().await {
+		panic!("{filename}: {}", report.format(rt.cx()));
+	}
+
+	match promise.state() {
+		PromiseState::Fulfilled => {}
+		PromiseState::Rejected => {
+			let exception = Exception::from_value(rt.cx(), &promise.result(rt.cx())).unwrap();
+			panic!("{filename}: {}", exception.format(rt.cx()));
+		}
+		PromiseState::Pending => panic!("{filename}: Module did not finish evaluating"),
+	}
+}
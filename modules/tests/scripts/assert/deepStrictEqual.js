@@ -0,0 +1,27 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import {deepStrictEqual, throws} from "assert";
+
+deepStrictEqual({a: [1, 2], b: new Map([["c", new Set([3])]])}, {a: [1, 2], b: new Map([["c", new Set([3])]])});
+deepStrictEqual(new Uint8Array([1, 2, 3]), new Uint8Array([1, 2, 3]));
+
+const cyclic = {};
+cyclic.self = cyclic;
+const other = {};
+other.self = other;
+deepStrictEqual(cyclic, other);
+
+// Arrays of different lengths are not equal, even if they have the same elements.
+throws(() => deepStrictEqual(new Array(3), []));
+throws(() => deepStrictEqual([1, 2, , ], [1, 2]));
+
+// A failed comparison of p and q while matching set entries must not be remembered as equal.
+const p = {v: 1};
+const q = {v: 2};
+throws(() => deepStrictEqual([new Set([{a: p}, {a: {v: 2}}]), {b: p}], [new Set([{a: q}, {a: p}]), {b: q}]));
+
+deepStrictEqual({a: 1}, {a: "1"}, "assert.deepStrictEqual");
@@ -11,3 +11,17 @@ function thrower() {
 }
 
 throws(thrower, "assert.throws");
+
+function typeThrower() {
+	throw new TypeError("Invalid Type");
+}
+
+throws(typeThrower, TypeError);
+throws(typeThrower, /Invalid Type/);
+throws(typeThrower, {name: "TypeError", message: /Invalid/});
+
+function returner() {
+	return 1;
+}
+
+throws(returner, "assert.throws");
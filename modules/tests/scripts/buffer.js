@@ -0,0 +1,65 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import {deepStrictEqual, throws} from "assert";
+import {Buffer} from "buffer";
+
+const hello = Buffer.from("hello");
+deepStrictEqual(Buffer.isBuffer(hello), true);
+deepStrictEqual(hello.toString(), "hello");
+deepStrictEqual(hello.toString("hex"), "68656c6c6f");
+deepStrictEqual(hello.toString("base64"), "aGVsbG8=");
+deepStrictEqual(hello.toString("utf8", 1, 3), "el");
+deepStrictEqual(Buffer.from("aGVsbG8", "base64").toString(), "hello");
+deepStrictEqual(Buffer.from("aGVsbG8", "base64url").toString(), "hello");
+deepStrictEqual(Buffer.from("68656c6c6fzz", "hex").toString(), "hello");
+deepStrictEqual(Array.from(Buffer.from("hi", "utf16le")), [104, 0, 105, 0]);
+deepStrictEqual(Buffer.byteLength("héllo"), 6);
+deepStrictEqual(Buffer.byteLength("héllo", "latin1"), 5);
+deepStrictEqual(Buffer.isEncoding("UTF-8"), true);
+deepStrictEqual(Buffer.isEncoding("utf32"), false);
+throws(() => Buffer.from("hello", "utf32"), TypeError);
+
+const json = Buffer.from([1, 2, 3]).toJSON();
+deepStrictEqual(json, {type: "Buffer", data: [1, 2, 3]});
+deepStrictEqual(Array.from(Buffer.from(json)), [1, 2, 3]);
+
+deepStrictEqual(Buffer.concat([Buffer.from("ab"), Buffer.from("cd")]).toString(), "abcd");
+deepStrictEqual(Buffer.concat([Buffer.from("ab"), Buffer.from("cd")], 3).toString(), "abc");
+deepStrictEqual(Buffer.compare(Buffer.from("a"), Buffer.from("b")), -1);
+deepStrictEqual(Buffer.from("ab").compare(Buffer.from("a")), 1);
+deepStrictEqual(Buffer.from("ab").equals(Buffer.from("ab")), true);
+
+deepStrictEqual(hello.indexOf("ll"), 2);
+deepStrictEqual(hello.indexOf(0x6f), 4);
+deepStrictEqual(hello.indexOf("6c6c", "hex"), 2);
+deepStrictEqual(hello.indexOf("l", -2), 3);
+deepStrictEqual(hello.includes("z"), false);
+
+const filled = Buffer.alloc(5);
+deepStrictEqual(Array.from(filled.fill("ab", 1, "latin1")), [0, 97, 98, 97, 98]);
+deepStrictEqual(filled.fill("6869", "hex").toString(), "hihih");
+deepStrictEqual(Array.from(filled.fill("ff", 0, 2, "hex")), [255, 255, 104, 105, 104]);
+deepStrictEqual(Array.from(filled.fill(0x101)), [1, 1, 1, 1, 1]);
+deepStrictEqual(Buffer.alloc(4, "ab").toString(), "abab");
+deepStrictEqual(Buffer.alloc(4, "6869", "hex").toString(), "hihi");
+
+const written = Buffer.alloc(4);
+deepStrictEqual(written.write("hi", 1), 2);
+deepStrictEqual(Array.from(written), [0, 104, 105, 0]);
+deepStrictEqual(written.write("ffff", "hex"), 2);
+deepStrictEqual(written.write("abcdef", 2), 2);
+deepStrictEqual(written.toString("latin1"), "ÿÿab");
+
+const numbers = Buffer.alloc(8);
+deepStrictEqual(numbers.writeUInt16LE(0x0102, 0), 2);
+deepStrictEqual(numbers.readUInt16BE(0), 0x0201);
+deepStrictEqual(numbers.writeInt32BE(-2, 2), 6);
+deepStrictEqual(numbers.readInt32BE(2), -2);
+deepStrictEqual(numbers.readUInt8(2), 0xFF);
+deepStrictEqual(numbers.writeDoubleLE(1.5, 0), 8);
+deepStrictEqual(numbers.readDoubleLE(0), 1.5);
+throws(() => numbers.readUInt32LE(6), RangeError);
@@ -0,0 +1,22 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import {deepStrictEqual, throws} from "assert";
+import {lookup} from "dns";
+
+deepStrictEqual(await lookup("127.0.0.1"), ["127.0.0.1"]);
+deepStrictEqual(await lookup("::1", {family: 6}), ["::1"]);
+deepStrictEqual(await lookup("::1", {family: 4}), []);
+
+const addresses = await lookup("localhost");
+deepStrictEqual(addresses, [...new Set(addresses)]);
+
+const ipv4 = await lookup("localhost", {family: 4});
+deepStrictEqual(ipv4.includes("127.0.0.1"), true);
+deepStrictEqual(ipv4.every(address => !address.includes(":")), true);
+deepStrictEqual(ipv4, addresses.filter(address => !address.includes(":")));
+
+throws(() => lookup("localhost", {family: 5}), TypeError);
@@ -0,0 +1,50 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import {equals, ok, throws} from "assert";
+import {addSignalListener, listen, removeSignalListener, Signal} from "signals";
+import {spawn} from "subprocess";
+
+async function raise(signal) {
+	const status = await spawn("sh", {args: ["-c", `kill -${signal} $PPID`]}).status();
+	equals(status.code, 0);
+}
+
+equals(Signal.INT, 2);
+equals(Signal.TERM, 15);
+throws(() => listen("SIGFOO"), TypeError);
+
+const listener = listen("SIGUSR1");
+equals(listener.signal, "SIGUSR1");
+ok(!listener.closed);
+
+const received = listener.recv();
+await raise("USR1");
+equals(await received, true);
+
+const pending = listener.recv();
+listener.close();
+ok(listener.closed);
+equals(await pending, false);
+equals(await listener.recv(), false);
+
+const iterated = (async () => {
+	for await (const signal of listen("USR2")) {
+		return signal;
+	}
+})();
+await raise("USR2");
+equals(await iterated, "USR2");
+
+const callbackReceived = new Promise(resolve => {
+	const callback = signal => {
+		removeSignalListener("SIGUSR1", callback);
+		resolve(signal);
+	};
+	addSignalListener("SIGUSR1", callback);
+});
+await raise("USR1");
+equals(await callbackReceived, "SIGUSR1");
@@ -0,0 +1,46 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import {deepStrictEqual, rejects} from "assert";
+import {open} from "sqlite";
+
+const db = open(":memory:");
+await db.exec("CREATE TABLE entries (name TEXT, value INTEGER)");
+
+function sleep(ms) {
+	return new Promise(resolve => setTimeout(resolve, ms));
+}
+
+// Transactions started together run one after another, instead of failing to begin while another is open.
+const first = db.transaction(async db => {
+	await db.run("INSERT INTO entries VALUES (:name, :value)", {name: "first", value: 1});
+	await sleep(10);
+	await db.run("INSERT INTO entries VALUES (:name, :value)", {name: "first", value: 2});
+});
+const failed = db.transaction(async db => {
+	await db.run("INSERT INTO entries VALUES (?, ?)", ["failed", 3]);
+	throw new RangeError();
+});
+const second = db.transaction(async db => {
+	await db.run("INSERT INTO entries VALUES ($name, $value)", {$name: "second", $value: 4});
+	return "second";
+});
+
+await first;
+await rejects(failed, RangeError);
+deepStrictEqual(await second, "second");
+
+const rows = await db.all("SELECT name, value FROM entries ORDER BY value");
+deepStrictEqual(rows, [
+	{name: "first", value: 1},
+	{name: "first", value: 2},
+	{name: "second", value: 4},
+]);
+
+await rejects(db.run("INSERT INTO entries VALUES (:name, :value)", {name: "unknown", other: 5}), Error);
+await rejects(db.prepare("SELECT * FROM entries WHERE name = :name").all({value: 1}), Error);
+
+await db.close();
@@ -0,0 +1,103 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import {deepStrictEqual, rejects} from "assert";
+import {Buffer} from "buffer";
+import {PassThrough, Readable, Transform, Writable, finished, pipeline, promises} from "stream";
+
+function collector(options) {
+	const chunks = [];
+	const writable = new Writable({
+		...options,
+		write(chunk, _, callback) {
+			chunks.push(chunk);
+			callback();
+		},
+	});
+	return {writable, chunks};
+}
+
+function uppercase() {
+	return new Transform({
+		transform(chunk, _, callback) {
+			callback(null, chunk.toString().toUpperCase());
+		},
+	});
+}
+
+const objects = [];
+for await (const object of Readable.from([{a: 1}, {b: 2}])) {
+	objects.push(object);
+}
+deepStrictEqual(objects, [{a: 1}, {b: 2}]);
+
+const readable = new Readable({encoding: "utf8", read() {}});
+readable.push("hello ");
+readable.push(Buffer.from("world"));
+readable.push(null);
+const text = await new Promise((resolve, reject) => {
+	let text = "";
+	readable.on("data", chunk => (text += chunk));
+	readable.on("end", () => resolve(text));
+	readable.on("error", reject);
+});
+deepStrictEqual(text, "hello world");
+deepStrictEqual(readable.readableEnded, true);
+
+const {writable, chunks} = collector();
+await promises.pipeline(Readable.from(["a", "b"], {objectMode: false}), uppercase(), writable);
+deepStrictEqual(Buffer.concat(chunks).toString(), "AB");
+deepStrictEqual(writable.writableFinished, true);
+
+const failing = new Writable({
+	write(_, __, callback) {
+		callback(new RangeError());
+	},
+});
+let calls = 0;
+await rejects(
+	new Promise((resolve, reject) => {
+		pipeline(Readable.from(["a"], {objectMode: false}), failing, error => {
+			calls++;
+			error ? reject(error) : resolve();
+		});
+	}),
+	RangeError,
+);
+await new Promise(resolve => setTimeout(resolve, 10));
+deepStrictEqual(calls, 1);
+
+const slow = new Writable({
+	highWaterMark: 2,
+	write(_, __, callback) {
+		setTimeout(callback, 1);
+	},
+});
+deepStrictEqual(slow.write("abc"), false);
+deepStrictEqual(slow.writableNeedDrain, true);
+await new Promise(resolve => slow.once("drain", resolve));
+deepStrictEqual(slow.writableLength, 0);
+
+const passThrough = new PassThrough({objectMode: true});
+passThrough.write({a: 1});
+passThrough.end({b: 2});
+const passed = [];
+for await (const object of passThrough) {
+	passed.push(object);
+}
+deepStrictEqual(passed, [{a: 1}, {b: 2}]);
+
+const corked = collector({objectMode: true});
+corked.writable.cork();
+corked.writable.write(1);
+corked.writable.write(2);
+deepStrictEqual(corked.chunks, []);
+corked.writable.uncork();
+await new Promise((resolve, reject) => {
+	finished(corked.writable, error => (error ? reject(error) : resolve()));
+	corked.writable.end(3);
+});
+deepStrictEqual(corked.chunks, [1, 2, 3]);
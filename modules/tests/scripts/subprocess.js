@@ -0,0 +1,27 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+import {equals, ok, throws} from "assert";
+import {spawn} from "subprocess";
+
+const sleeping = spawn("sleep", {args: ["10"]});
+const status = sleeping.status();
+sleeping.kill();
+
+const killed = await status;
+ok(!killed.success);
+equals(killed.signal, 15);
+
+// The process has been reaped, so its PID may have been reused by another process.
+throws(() => sleeping.kill(), Error);
+
+const exited = spawn("true");
+equals((await exited.status()).code, 0);
+throws(() => exited.kill("SIGKILL"), Error);
+
+const interrupted = spawn("sleep", {args: ["10"]});
+interrupted.kill("SIGINT");
+equals((await interrupted.status()).signal, 2);
@@ -4,10 +4,16 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
+use std::path::PathBuf;
 use std::sync::OnceLock;
 
+use ion::{gc, Context};
+use mozjs::jsapi::{JSGCParamKey, JSJitCompilerOption, JS_SetGlobalJitCompilerOption};
+
 pub static CONFIG: OnceLock<Config> = OnceLock::new();
 
+const MEGABYTE: u64 = 1024 * 1024;
+
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub enum LogLevel {
 	None = 0,
@@ -28,13 +34,53 @@ impl LogLevel {
 	pub fn is_stderr(&self) -> bool {
 		!self.is_stdout()
 	}
+
+	/// Returns the name of the log level, as used in structured log records.
+	pub fn name(&self) -> &'static str {
+		match self {
+			LogLevel::None => "none",
+			LogLevel::Info => "info",
+			LogLevel::Warn => "warn",
+			LogLevel::Error => "error",
+			LogLevel::Debug => "debug",
+		}
+	}
+}
+
+/// Format of the output of the console.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LogFormat {
+	Text,
+	/// Newline-delimited JSON records, for log aggregation.
+	Json,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct Config {
 	pub log_level: LogLevel,
+	pub log_format: LogFormat,
 	pub script: bool,
 	pub typescript: bool,
+	pub args: Vec<String>,
+	pub allow_env: bool,
+	pub unstable: bool,
+	pub inspect_depth: u16,
+	pub inspect_array_length: u32,
+	/// Default locale of `Intl`, overriding the locale of the system.
+	pub locale: Option<String>,
+	/// Maximum number of microtasks run in each microtask checkpoint, or 0 for no limit.
+	pub microtask_budget: usize,
+	/// Whether entry scripts and modules are compiled on a separate thread.
+	/// Modules imported by the entry module are still compiled synchronously.
+	pub off_thread_compile: bool,
+	/// Dotenv files loaded into the environment before the entry script runs, in order of increasing precedence.
+	pub env_files: Vec<PathBuf>,
+	/// Whether variables from [env_files](Config::env_files) override those already set in the environment.
+	pub env_override: bool,
+	/// File the CPU profile of the entry script is written to, if it is profiled.
+	pub profile: Option<PathBuf>,
+	/// File the heap growth profile of the entry script is written to, if it is profiled.
+	pub heap_profile: Option<PathBuf>,
 }
 
 impl Config {
@@ -42,6 +88,10 @@ impl Config {
 		Config { log_level, ..self }
 	}
 
+	pub fn log_format(self, log_format: LogFormat) -> Config {
+		Config { log_format, ..self }
+	}
+
 	pub fn script(self, script: bool) -> Config {
 		Config { script, ..self }
 	}
@@ -50,8 +100,57 @@ impl Config {
 		Config { typescript, ..self }
 	}
 
+	pub fn args(self, args: Vec<String>) -> Config {
+		Config { args, ..self }
+	}
+
+	pub fn allow_env(self, allow_env: bool) -> Config {
+		Config { allow_env, ..self }
+	}
+
+	pub fn unstable(self, unstable: bool) -> Config {
+		Config { unstable, ..self }
+	}
+
+	pub fn inspect_depth(self, inspect_depth: u16) -> Config {
+		Config { inspect_depth, ..self }
+	}
+
+	pub fn inspect_array_length(self, inspect_array_length: u32) -> Config {
+		Config { inspect_array_length, ..self }
+	}
+
+	pub fn locale(self, locale: Option<String>) -> Config {
+		Config { locale, ..self }
+	}
+
+	pub fn microtask_budget(self, microtask_budget: usize) -> Config {
+		Config { microtask_budget, ..self }
+	}
+
+	pub fn off_thread_compile(self, off_thread_compile: bool) -> Config {
+		Config { off_thread_compile, ..self }
+	}
+
+	pub fn env_files(self, env_files: Vec<PathBuf>) -> Config {
+		Config { env_files, ..self }
+	}
+
+	pub fn env_override(self, env_override: bool) -> Config {
+		Config { env_override, ..self }
+	}
+
+	pub fn profile(self, profile: Option<PathBuf>) -> Config {
+		Config { profile, ..self }
+	}
+
+	pub fn heap_profile(self, heap_profile: Option<PathBuf>) -> Config {
+		Config { heap_profile, ..self }
+	}
+
+	/// Returns the global configuration, which is initialised to the default configuration if it has not been set.
 	pub fn global() -> &'static Config {
-		CONFIG.get().expect("Configuration not initialised")
+		CONFIG.get_or_init(Config::default)
 	}
 }
 
@@ -59,8 +158,140 @@ impl Default for Config {
 	fn default() -> Config {
 		Config {
 			log_level: LogLevel::Error,
+			log_format: LogFormat::Text,
 			script: false,
 			typescript: true,
+			args: Vec::new(),
+			allow_env: false,
+			unstable: false,
+			inspect_depth: 4,
+			inspect_array_length: 100,
+			locale: None,
+			microtask_budget: 100_000,
+			off_thread_compile: false,
+			env_files: Vec::new(),
+			env_override: false,
+			profile: None,
+			heap_profile: None,
+		}
+	}
+}
+
+/// Tuning of the garbage collector and JIT compilers, applied when a runtime is built.
+///
+/// Parameters which are [None] keep the defaults of SpiderMonkey.
+/// Lowering the heap limits suits memory-constrained environments, while disabling compacting and lowering the
+/// slice budget reduces pauses in latency-sensitive workloads.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct RuntimeConfig {
+	/// Size of the heap, in bytes, at which the first major garbage collection is triggered.
+	/// SpiderMonkey sets this in whole megabytes, so it is rounded up to the next megabyte.
+	pub initial_heap_bytes: Option<u64>,
+	/// Maximum size of the heap, in bytes.
+	/// SpiderMonkey limits this to [u32::MAX] bytes, which larger values are clamped to, and leaves the heap unbounded
+	/// at that limit.
+	pub max_heap_bytes: Option<u64>,
+	/// Whether garbage collections are split into incremental slices.
+	pub incremental_gc: Option<bool>,
+	/// Maximum duration of each incremental garbage collection slice, in milliseconds.
+	pub slice_budget_ms: Option<u32>,
+	/// Whether major garbage collections compact the heap.
+	pub compacting_gc: Option<bool>,
+	/// Whether the baseline interpreter is enabled.
+	pub baseline_interpreter: Option<bool>,
+	/// Whether the baseline JIT compiler is enabled.
+	pub baseline_jit: Option<bool>,
+	/// Whether the optimising (Ion) JIT compiler is enabled.
+	pub ion_jit: Option<bool>,
+}
+
+impl RuntimeConfig {
+	pub fn initial_heap_bytes(self, initial_heap_bytes: u64) -> RuntimeConfig {
+		RuntimeConfig {
+			initial_heap_bytes: Some(initial_heap_bytes),
+			..self
+		}
+	}
+
+	pub fn max_heap_bytes(self, max_heap_bytes: u64) -> RuntimeConfig {
+		RuntimeConfig {
+			max_heap_bytes: Some(max_heap_bytes),
+			..self
+		}
+	}
+
+	pub fn incremental_gc(self, incremental_gc: bool) -> RuntimeConfig {
+		RuntimeConfig {
+			incremental_gc: Some(incremental_gc),
+			..self
+		}
+	}
+
+	pub fn slice_budget_ms(self, slice_budget_ms: u32) -> RuntimeConfig {
+		RuntimeConfig {
+			slice_budget_ms: Some(slice_budget_ms),
+			..self
+		}
+	}
+
+	pub fn compacting_gc(self, compacting_gc: bool) -> RuntimeConfig {
+		RuntimeConfig {
+			compacting_gc: Some(compacting_gc),
+			..self
+		}
+	}
+
+	pub fn baseline_interpreter(self, baseline_interpreter: bool) -> RuntimeConfig {
+		RuntimeConfig {
+			baseline_interpreter: Some(baseline_interpreter),
+			..self
+		}
+	}
+
+	pub fn baseline_jit(self, baseline_jit: bool) -> RuntimeConfig {
+		RuntimeConfig { baseline_jit: Some(baseline_jit), ..self }
+	}
+
+	pub fn ion_jit(self, ion_jit: bool) -> RuntimeConfig {
+		RuntimeConfig { ion_jit: Some(ion_jit), ..self }
+	}
+
+	/// Applies the configuration to the runtime of the context.
+	///
+	/// JIT options are shared by all runtimes in the process.
+	pub(crate) fn apply(&self, cx: &Context) {
+		let clamp = |value: u64| u32::try_from(value).unwrap_or(u32::MAX);
+		let parameters = [
+			(
+				JSGCParamKey::JSGC_ALLOCATION_THRESHOLD,
+				self.initial_heap_bytes.map(|bytes| clamp(bytes.div_ceil(MEGABYTE))),
+			),
+			(JSGCParamKey::JSGC_MAX_BYTES, self.max_heap_bytes.map(clamp)),
+			(
+				JSGCParamKey::JSGC_INCREMENTAL_GC_ENABLED,
+				self.incremental_gc.map(u32::from),
+			),
+			(JSGCParamKey::JSGC_SLICE_TIME_BUDGET_MS, self.slice_budget_ms),
+			(JSGCParamKey::JSGC_COMPACTING_ENABLED, self.compacting_gc.map(u32::from)),
+		];
+		for (key, value) in parameters {
+			if let Some(value) = value {
+				gc::set_parameter(cx, key, value);
+			}
+		}
+
+		let options = [
+			(
+				JSJitCompilerOption::JSJITCOMPILER_BASELINE_INTERPRETER_ENABLE,
+				self.baseline_interpreter,
+			),
+			(JSJitCompilerOption::JSJITCOMPILER_BASELINE_ENABLE, self.baseline_jit),
+			(JSJitCompilerOption::JSJITCOMPILER_ION_ENABLE, self.ion_jit),
+		];
+		for (option, enabled) in options {
+			if let Some(enabled) = enabled {
+				unsafe { JS_SetGlobalJitCompilerOption(cx.as_ptr(), option, u32::from(enabled)) }
+			}
 		}
 	}
 }
@@ -0,0 +1,251 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::iter::Peekable;
+use std::path::{Path, PathBuf};
+use std::str::Chars;
+use std::{env, fmt, fs, io};
+
+/// Loads the variables of the given dotenv files into the environment of the process.
+///
+/// Variables in later files override those in earlier files. Variables which are already set in the environment are
+/// only overridden if `override_existing` is `true`.
+pub fn load_env_files<P: AsRef<Path>>(paths: &[P], override_existing: bool) -> Result<(), Error> {
+	let mut variables = HashMap::new();
+	for path in paths {
+		let path = path.as_ref();
+		let source = fs::read_to_string(path).map_err(|err| Error::Io(path.to_path_buf(), err))?;
+		parse_env(&source, &mut variables, override_existing).map_err(|(line, message)| Error::Syntax {
+			path: path.to_path_buf(),
+			line,
+			message,
+		})?;
+	}
+
+	for (name, value) in variables {
+		if override_existing || env::var_os(&name).is_none() {
+			env::set_var(name, value);
+		}
+	}
+	Ok(())
+}
+
+/// Parses the variables of a dotenv file into `variables`, replacing any with the same name.
+///
+/// Each line is of the form `NAME=value`, optionally prefixed with `export`. Values may be unquoted, single-quoted or
+/// double-quoted, and quoted values may span multiple lines. Comments start with `#`, except within quoted values.
+///
+/// References to variables, as `$NAME`, `${NAME}` or `${NAME:-default}`, are expanded in unquoted and double-quoted
+/// values. They are resolved from the environment of the process first, and then from `variables`, unless
+/// `override_existing` is `true`, in which case `variables` takes precedence, as it does when they are loaded.
+///
+/// Returns the line number and a description of the first syntax error.
+pub fn parse_env(
+	source: &str, variables: &mut HashMap<String, String>, override_existing: bool,
+) -> Result<(), (usize, &'static str)> {
+	let mut parser = Parser {
+		chars: source.chars().peekable(),
+		line: 1,
+		override_existing,
+	};
+	while let Some((name, value)) = parser.entry(variables).map_err(|message| (parser.line, message))? {
+		variables.insert(name, value);
+	}
+	Ok(())
+}
+
+#[derive(Debug)]
+pub enum Error {
+	Io(PathBuf, io::Error),
+	Syntax { path: PathBuf, line: usize, message: &'static str },
+}
+
+impl Display for Error {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self {
+			Error::Io(path, err) => write!(f, "Could not read environment file {}: {err}", path.display()),
+			Error::Syntax { path, line, message } => write!(f, "{message} at {}:{line}", path.display()),
+		}
+	}
+}
+
+struct Parser<'s> {
+	chars: Peekable<Chars<'s>>,
+	line: usize,
+	override_existing: bool,
+}
+
+impl Parser<'_> {
+	fn next(&mut self) -> Option<char> {
+		let char = self.chars.next();
+		if char == Some('\n') {
+			self.line += 1;
+		}
+		char
+	}
+
+	fn next_if(&mut self, predicate: impl FnOnce(&char) -> bool) -> Option<char> {
+		let char = self.chars.next_if(predicate);
+		if char == Some('\n') {
+			self.line += 1;
+		}
+		char
+	}
+
+	fn skip_blank(&mut self) {
+		while self.next_if(|c| matches!(c, ' ' | '\t')).is_some() {}
+	}
+
+	fn skip_line(&mut self) {
+		while self.next_if(|c| *c != '\n').is_some() {}
+	}
+
+	fn entry(&mut self, variables: &HashMap<String, String>) -> Result<Option<(String, String)>, &'static str> {
+		loop {
+			match self.chars.peek() {
+				None => return Ok(None),
+				Some(' ' | '\t' | '\r' | '\n') => {
+					self.next();
+				}
+				Some('#') => self.skip_line(),
+				Some(_) => break,
+			}
+		}
+
+		let mut name = self.name();
+		if name == "export" && self.chars.peek().is_some_and(|c| matches!(c, ' ' | '\t')) {
+			self.skip_blank();
+			name = self.name();
+		}
+		if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+			return Err("Expected variable name");
+		}
+
+		self.skip_blank();
+		if self.next_if(|c| *c == '=').is_none() {
+			return Err("Expected '=' after variable name");
+		}
+		self.skip_blank();
+
+		let value = match self.chars.peek() {
+			Some('\'') => {
+				self.next();
+				let mut value = String::new();
+				loop {
+					match self.next() {
+						Some('\'') => break,
+						Some(char) => value.push(char),
+						None => return Err("Unterminated single-quoted value"),
+					}
+				}
+				self.end_of_line()?;
+				value
+			}
+			Some('"') => {
+				self.next();
+				let mut value = String::new();
+				loop {
+					match self.next() {
+						Some('"') => break,
+						Some('\\') => match self.next() {
+							Some('n') => value.push('\n'),
+							Some('r') => value.push('\r'),
+							Some('t') => value.push('\t'),
+							Some(char @ ('"' | '\\' | '$')) => value.push(char),
+							Some(char) => {
+								value.push('\\');
+								value.push(char);
+							}
+							None => return Err("Unterminated double-quoted value"),
+						},
+						Some('$') => self.expand(&mut value, variables)?,
+						Some(char) => value.push(char),
+						None => return Err("Unterminated double-quoted value"),
+					}
+				}
+				self.end_of_line()?;
+				value
+			}
+			_ => {
+				let mut value = String::new();
+				while let Some(char) = self.next_if(|c| *c != '\n') {
+					match char {
+						'#' if value.is_empty() || value.ends_with([' ', '\t']) => {
+							self.skip_line();
+							break;
+						}
+						'$' => self.expand(&mut value, variables)?,
+						char => value.push(char),
+					}
+				}
+				value.truncate(value.trim_end().len());
+				value
+			}
+		};
+
+		Ok(Some((name, value)))
+	}
+
+	fn name(&mut self) -> String {
+		let mut name = String::new();
+		while let Some(char) = self.next_if(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.')) {
+			name.push(char);
+		}
+		name
+	}
+
+	fn end_of_line(&mut self) -> Result<(), &'static str> {
+		self.skip_blank();
+		match self.chars.peek() {
+			None | Some('\r' | '\n') => Ok(()),
+			Some('#') => {
+				self.skip_line();
+				Ok(())
+			}
+			Some(_) => Err("Unexpected characters after quoted value"),
+		}
+	}
+
+	fn expand(&mut self, value: &mut String, variables: &HashMap<String, String>) -> Result<(), &'static str> {
+		let braced = self.next_if(|c| *c == '{').is_some();
+		let mut name = String::new();
+		while let Some(char) = self.next_if(|c| c.is_ascii_alphanumeric() || *c == '_') {
+			name.push(char);
+		}
+
+		let mut default = None;
+		if braced {
+			if self.next_if(|c| *c == ':').is_some() {
+				if self.next_if(|c| *c == '-').is_none() {
+					return Err("Expected '-' in variable default");
+				}
+				let mut fallback = String::new();
+				while let Some(char) = self.next_if(|c| *c != '}' && *c != '\n') {
+					fallback.push(char);
+				}
+				default = Some(fallback);
+			}
+			if self.next_if(|c| *c == '}').is_none() {
+				return Err("Unterminated variable reference");
+			}
+		} else if name.is_empty() {
+			value.push('$');
+			return Ok(());
+		}
+
+		let resolved = if self.override_existing {
+			variables.get(&name).cloned().or_else(|| env::var(&name).ok())
+		} else {
+			env::var(&name).ok().or_else(|| variables.get(&name).cloned())
+		};
+		if let Some(resolved) = resolved.filter(|resolved| !resolved.is_empty()).or(default) {
+			value.push_str(&resolved);
+		}
+		Ok(())
+	}
+}
@@ -10,10 +10,12 @@ use std::task::Poll;
 use futures::stream::FuturesUnordered;
 use futures::StreamExt;
 use ion::conversions::BoxedIntoValue;
-use ion::{Context, Error, ErrorKind, ErrorReport, Promise, ThrowException, Value};
+use ion::{Context, Error, ErrorKind, ErrorReport, Exception, Promise, ThrowException, Value};
 use mozjs::jsapi::JSObject;
 use tokio::task::JoinHandle;
 
+use crate::event_loop::microtasks::MicrotaskQueue;
+
 type FutureOutput = (Result<BoxedIntoValue, BoxedIntoValue>, *mut JSObject);
 
 #[derive(Default)]
@@ -22,7 +24,11 @@ pub struct FutureQueue {
 }
 
 impl FutureQueue {
-	pub fn run_futures(&mut self, cx: &Context, wcx: &mut task::Context) -> Result<(), Option<ErrorReport>> {
+	/// Settles the promises of completed futures, performing a microtask checkpoint after each one.
+	/// Returns `true` if any futures completed.
+	pub fn run_futures(
+		&mut self, cx: &Context, wcx: &mut task::Context, mut microtasks: Option<&mut MicrotaskQueue>,
+	) -> Result<bool, Option<ErrorReport>> {
 		let mut results = Vec::new();
 
 		while let Poll::Ready(Some(item)) = self.queue.poll_next_unpin(wcx) {
@@ -35,6 +41,7 @@ impl FutureQueue {
 			}
 		}
 
+		let completed = !results.is_empty();
 		for (result, promise) in results {
 			let mut value = Value::undefined(cx);
 			let promise = Promise::from(cx.root(promise)).unwrap();
@@ -51,11 +58,15 @@ impl FutureQueue {
 			};
 
 			if !result {
-				return Err(ErrorReport::new_with_exception_stack(cx).unwrap());
+				let report = ErrorReport::new_with_exception_stack(cx);
+				return Err(report.unwrap_or_else(|error| Some(ErrorReport::from(Exception::Error(error), None))));
+			}
+			if let Some(microtasks) = &mut microtasks {
+				microtasks.run_jobs(cx)?;
 			}
 		}
 
-		Ok(())
+		Ok(completed)
 	}
 
 	pub fn enqueue(&self, handle: JoinHandle<FutureOutput>) {
@@ -4,21 +4,23 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
-use std::collections::HashMap;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
 use std::fmt;
 use std::fmt::{Debug, Formatter};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
-use chrono::{DateTime, Duration, Utc};
+use chrono::Duration;
 use ion::{Context, ErrorReport, Function, Object, Value};
 use mozjs::jsapi::JSFunction;
 use mozjs::jsval::JSVal;
+use tokio::time::Instant;
 
 pub struct SignalMacrotask {
 	callback: Option<Box<dyn FnOnce()>>,
 	terminate: Arc<AtomicBool>,
-	scheduled: DateTime<Utc>,
+	scheduled: Instant,
 }
 
 impl SignalMacrotask {
@@ -26,7 +28,7 @@ impl SignalMacrotask {
 		SignalMacrotask {
 			callback: Some(callback),
 			terminate,
-			scheduled: Utc::now() + duration,
+			scheduled: Instant::now() + duration.to_std().unwrap_or_default(),
 		}
 	}
 }
@@ -45,9 +47,11 @@ pub struct TimerMacrotask {
 	callback: *mut JSFunction,
 	arguments: Box<[JSVal]>,
 	repeat: bool,
-	scheduled: DateTime<Utc>,
+	scheduled: Instant,
 	duration: Duration,
 	nesting: u8,
+	/// Whether the timer keeps the event loop alive, which can be changed with `ref()` and `unref()`.
+	pub(crate) referenced: bool,
 }
 
 impl TimerMacrotask {
@@ -57,14 +61,15 @@ impl TimerMacrotask {
 			arguments,
 			repeat,
 			duration,
-			scheduled: Utc::now(),
+			scheduled: Instant::now(),
 			nesting: 0,
+			referenced: true,
 		}
 	}
 
 	pub fn reset(&mut self) -> bool {
 		if self.repeat {
-			self.scheduled = Utc::now();
+			self.scheduled = Instant::now();
 		}
 		self.repeat
 	}
@@ -73,30 +78,59 @@ impl TimerMacrotask {
 #[derive(Debug)]
 pub struct UserMacrotask {
 	callback: *mut JSFunction,
-	scheduled: DateTime<Utc>,
+	scheduled: Instant,
 }
 
 impl UserMacrotask {
 	pub fn new(callback: Function) -> UserMacrotask {
 		UserMacrotask {
 			callback: callback.get(),
-			scheduled: Utc::now(),
+			scheduled: Instant::now(),
 		}
 	}
 }
 
+/// Callback of a [NativeMacrotask], which may run scripts. Errors it returns are reported by the event loop.
+pub type NativeCallback = dyn FnOnce(&Context) -> Result<(), Option<ErrorReport>>;
+
+pub struct NativeMacrotask {
+	callback: Option<Box<NativeCallback>>,
+	scheduled: Instant,
+}
+
+impl NativeMacrotask {
+	pub fn new(callback: Box<NativeCallback>) -> NativeMacrotask {
+		NativeMacrotask {
+			callback: Some(callback),
+			scheduled: Instant::now(),
+		}
+	}
+}
+
+impl Debug for NativeMacrotask {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct("NativeMacrotask").field("scheduled", &self.scheduled).finish()
+	}
+}
+
 #[derive(Debug)]
 pub enum Macrotask {
 	Signal(SignalMacrotask),
 	Timer(TimerMacrotask),
 	User(UserMacrotask),
+	Native(NativeMacrotask),
 }
 
+/// Queue of macrotasks, ordered by their deadlines.
+///
+/// Macrotasks are stored by their ID, and a min-heap of deadlines determines the order they are run in.
+/// Entries in the heap are removed lazily, so they are skipped if their macrotask has been removed or rescheduled.
 #[derive(Debug, Default)]
 pub struct MacrotaskQueue {
 	pub(crate) map: HashMap<u32, Macrotask>,
 	pub(crate) nesting: u8,
-	next: Option<u32>,
+	deadlines: BinaryHeap<Reverse<(Instant, u64, u32)>>,
+	sequence: u64,
 	latest: Option<u32>,
 }
 
@@ -108,6 +142,9 @@ impl Macrotask {
 			}
 			return Ok(());
 		}
+		if let Macrotask::Native(native) = self {
+			return native.callback.take().map_or(Ok(()), |callback| callback(cx));
+		}
 
 		let (callback, args) = match self {
 			Macrotask::Timer(timer) => (timer.callback, timer.arguments.clone()),
@@ -129,6 +166,14 @@ impl Macrotask {
 		}
 	}
 
+	/// Checks if the macrotask keeps the event loop alive. Only timers can be unreferenced.
+	fn is_referenced(&self) -> bool {
+		match self {
+			Macrotask::Timer(timer) => timer.referenced,
+			_ => true,
+		}
+	}
+
 	fn terminate(&self) -> bool {
 		match self {
 			Macrotask::Signal(signal) => signal.terminate.load(Ordering::SeqCst),
@@ -136,55 +181,54 @@ impl Macrotask {
 		}
 	}
 
-	fn remaining(&self) -> Duration {
+	fn deadline(&self) -> Instant {
 		match self {
-			Macrotask::Signal(signal) => signal.scheduled - Utc::now(),
-			Macrotask::Timer(timer) => timer.scheduled + timer.duration - Utc::now(),
-			Macrotask::User(user) => user.scheduled - Utc::now(),
+			Macrotask::Signal(signal) => signal.scheduled,
+			Macrotask::Timer(timer) => timer.scheduled + timer.duration.to_std().unwrap_or_default(),
+			Macrotask::User(user) => user.scheduled,
+			Macrotask::Native(native) => native.scheduled,
 		}
 	}
 }
 
 impl MacrotaskQueue {
-	pub fn run_job(&mut self, cx: &Context) -> Result<(), Option<ErrorReport>> {
-		self.find_next();
-		if let Some(next) = self.next {
-			{
-				let macrotask = self.map.get_mut(&next);
-				if let Some(macrotask) = macrotask {
-					macrotask.run(cx)?;
-				}
-			}
+	/// Runs the earliest macrotask if its deadline has passed.
+	/// Returns `true` if a macrotask was run.
+	pub fn run_job(&mut self, cx: &Context) -> Result<bool, Option<ErrorReport>> {
+		self.map.retain(|_, macrotask| !macrotask.terminate());
 
-			// The previous reference may be invalidated by running the macrotask.
-			let macrotask = self.map.get_mut(&next);
-			if let Some(macrotask) = macrotask {
-				if macrotask.remove() {
-					self.map.remove(&next);
-				}
+		let next = match self.next_due() {
+			Some((deadline, next)) if deadline <= Instant::now() => next,
+			_ => return Ok(false),
+		};
+		self.deadlines.pop();
+
+		if let Some(macrotask) = self.map.get_mut(&next) {
+			macrotask.run(cx)?;
+		}
+
+		// The previous reference may be invalidated by running the macrotask.
+		if let Some(macrotask) = self.map.get_mut(&next) {
+			if macrotask.remove() {
+				self.map.remove(&next);
+			} else {
+				let deadline = macrotask.deadline();
+				self.push_deadline(deadline, next);
 			}
 		}
 
-		Ok(())
+		Ok(true)
 	}
 
 	pub fn enqueue(&mut self, mut macrotask: Macrotask, id: Option<u32>) -> u32 {
 		let index = id.unwrap_or_else(|| self.latest.map(|l| l + 1).unwrap_or(0));
 
-		let next = self.next.and_then(|next| self.map.get(&next));
-		if let Some(next) = next {
-			if macrotask.remaining() < next.remaining() {
-				self.set_next(index, &macrotask);
-			}
-		} else {
-			self.set_next(index, &macrotask);
-		}
-
 		if let Macrotask::Timer(timer) = &mut macrotask {
 			self.nesting += 1;
 			timer.nesting = self.nesting;
 		}
 
+		self.push_deadline(macrotask.deadline(), index);
 		self.latest = Some(index);
 		self.map.insert(index, macrotask);
 
@@ -192,45 +236,58 @@ impl MacrotaskQueue {
 	}
 
 	pub fn remove(&mut self, id: u32) {
-		if self.map.remove(&id).is_some() {
-			if let Some(next) = self.next {
-				if next == id {
-					self.next = None;
-				}
-			}
+		self.map.remove(&id);
+	}
+
+	/// Returns the timer with the given ID, if it has not finished or been cleared.
+	pub fn timer(&mut self, id: u32) -> Option<&mut TimerMacrotask> {
+		match self.map.get_mut(&id) {
+			Some(Macrotask::Timer(timer)) => Some(timer),
+			_ => None,
 		}
 	}
 
-	pub fn find_next(&mut self) {
-		let mut next: Option<(u32, &Macrotask)> = None;
-		let mut to_remove = Vec::new();
-		for (id, macrotask) in &self.map {
-			if macrotask.terminate() {
-				to_remove.push(*id);
-				continue;
-			}
-			if let Some((_, next_macrotask)) = next {
-				if macrotask.remaining() < next_macrotask.remaining() {
-					next = Some((*id, macrotask));
+	/// Restarts the timer with the given ID, so it is scheduled after its duration from now.
+	/// Returns `false` if the timer has finished or been cleared.
+	pub fn refresh(&mut self, id: u32) -> bool {
+		let Some(timer) = self.timer(id) else {
+			return false;
+		};
+		timer.scheduled = Instant::now();
+		let deadline = timer.scheduled + timer.duration.to_std().unwrap_or_default();
+		self.push_deadline(deadline, id);
+		true
+	}
+
+	/// Returns the deadline of the earliest macrotask.
+	pub fn next_deadline(&mut self) -> Option<Instant> {
+		self.next_due().map(|(deadline, _)| deadline)
+	}
+
+	fn next_due(&mut self) -> Option<(Instant, u32)> {
+		while let Some(Reverse((deadline, _, id))) = self.deadlines.peek().copied() {
+			match self.map.get(&id) {
+				Some(macrotask) if macrotask.deadline() == deadline => return Some((deadline, id)),
+				_ => {
+					self.deadlines.pop();
 				}
-			} else if macrotask.remaining() <= Duration::zero() {
-				next = Some((*id, macrotask));
 			}
 		}
-		let next = next.map(|(id, _)| id);
-		for id in to_remove.iter_mut() {
-			self.map.remove(id);
-		}
-		self.next = next;
+		None
 	}
 
-	pub fn set_next(&mut self, index: u32, macrotask: &Macrotask) {
-		if macrotask.remaining() < Duration::zero() {
-			self.next = Some(index);
-		}
+	fn push_deadline(&mut self, deadline: Instant, id: u32) {
+		// The sequence number runs macrotasks with the same deadline in the order they were scheduled.
+		self.deadlines.push(Reverse((deadline, self.sequence, id)));
+		self.sequence += 1;
 	}
 
 	pub fn is_empty(&self) -> bool {
 		self.map.is_empty()
 	}
+
+	/// Checks if any macrotask keeps the event loop alive.
+	pub fn has_referenced(&self) -> bool {
+		self.map.values().any(Macrotask::is_referenced)
+	}
 }
@@ -8,37 +8,41 @@ use std::collections::vec_deque::VecDeque;
 use std::ffi::c_void;
 
 use ion::{Context, ErrorReport, Function, Object};
+use mozjs::gc::Traceable;
 use mozjs::glue::JobQueueTraps;
 use mozjs::jsapi::{
-	CurrentGlobalOrNull, Handle, JSContext, JSFunction, JSObject, JobQueueIsEmpty, JobQueueMayNotBeEmpty,
+	ClearKeptObjects, CurrentGlobalOrNull, Handle, Heap, JSContext, JSFunction, JSObject, JSTracer, JobQueueIsEmpty,
+	JobQueueMayNotBeEmpty,
 };
 
 use crate::ContextExt;
 
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 pub enum Microtask {
-	Promise(*mut JSObject),
-	User(*mut JSFunction),
+	Promise(Box<Heap<*mut JSObject>>),
+	User(Box<Heap<*mut JSFunction>>),
 	None,
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Debug, Default)]
 pub struct MicrotaskQueue {
 	queue: VecDeque<Microtask>,
 	draining: bool,
+	budget: usize,
+	warned: bool,
 }
 
 impl Microtask {
 	pub fn run(&self, cx: &Context) -> Result<(), Option<ErrorReport>> {
 		match self {
 			Microtask::Promise(job) => {
-				let object = cx.root(*job);
+				let object = cx.root(job.get());
 				let function = Function::from_object(cx, &object).unwrap();
 
 				function.call(cx, &Object::null(cx), &[]).map(|_| ())
 			}
 			Microtask::User(callback) => {
-				let callback = Function::from(cx.root(*callback));
+				let callback = Function::from(cx.root(callback.get()));
 				callback.call(cx, &Object::global(cx), &[]).map(|_| ())
 			}
 			Microtask::None => Ok(()),
@@ -52,6 +56,17 @@ impl MicrotaskQueue {
 		unsafe { JobQueueMayNotBeEmpty(cx.as_ptr()) }
 	}
 
+	/// Creates a [MicrotaskQueue] which runs at most `budget` microtasks in each checkpoint.
+	/// A budget of 0 is unlimited.
+	pub fn new(budget: usize) -> MicrotaskQueue {
+		MicrotaskQueue { budget, ..MicrotaskQueue::default() }
+	}
+
+	/// Performs a microtask checkpoint, running microtasks until the queue is empty or the budget is exhausted.
+	/// Microtasks left over after the budget is exhausted are run in the next checkpoint, so that the event loop can
+	/// make progress on other tasks.
+	///
+	/// Checkpoints are not reentrant, so this does nothing if called while the queue is being drained.
 	pub fn run_jobs(&mut self, cx: &Context) -> Result<(), Option<ErrorReport>> {
 		if self.draining {
 			return Ok(());
@@ -59,12 +74,36 @@ impl MicrotaskQueue {
 
 		self.draining = true;
 
+		let mut count = 0;
 		while let Some(microtask) = self.queue.pop_front() {
-			microtask.run(cx)?;
+			if self.budget != 0 && count == self.budget {
+				self.queue.push_front(microtask);
+				if !self.warned {
+					self.warned = true;
+					eprintln!(
+						"Warning: Microtask checkpoint exceeded the budget of {} microtasks, deferring the remaining \
+						 microtasks. This may be caused by an unbounded chain of promises.",
+						self.budget
+					);
+				}
+				break;
+			}
+
+			count += 1;
+			if let Err(error) = microtask.run(cx) {
+				self.draining = false;
+				return Err(error);
+			}
 		}
 
 		self.draining = false;
-		unsafe { JobQueueIsEmpty(cx.as_ptr()) };
+		unsafe {
+			if self.queue.is_empty() {
+				JobQueueIsEmpty(cx.as_ptr());
+			}
+			// Targets of `WeakRef.prototype.deref` are only kept alive until the end of the microtask checkpoint.
+			ClearKeptObjects(cx.as_ptr());
+		}
 
 		Ok(())
 	}
@@ -72,6 +111,25 @@ impl MicrotaskQueue {
 	pub fn is_empty(&self) -> bool {
 		self.queue.is_empty()
 	}
+
+	/// Checks if any checkpoint has exceeded the budget, which is when the warning is printed.
+	pub fn exceeded_budget(&self) -> bool {
+		self.warned
+	}
+}
+
+unsafe impl Traceable for MicrotaskQueue {
+	unsafe fn trace(&self, trc: *mut JSTracer) {
+		for microtask in &self.queue {
+			unsafe {
+				match microtask {
+					Microtask::Promise(job) => job.trace(trc),
+					Microtask::User(callback) => callback.trace(trc),
+					Microtask::None => {}
+				}
+			}
+		}
+	}
 }
 
 unsafe extern "C" fn get_incumbent_global(_: *const c_void, cx: *mut JSContext) -> *mut JSObject {
@@ -86,13 +144,27 @@ unsafe extern "C" fn enqueue_promise_job(
 	let event_loop = unsafe { &mut cx.get_private().event_loop };
 	let microtasks = event_loop.microtasks.as_mut().unwrap();
 	if !job.is_null() {
-		microtasks.enqueue(cx, Microtask::Promise(job.get()))
+		microtasks.enqueue(cx, Microtask::Promise(Heap::boxed(job.get())))
 	} else {
 		microtasks.enqueue(cx, Microtask::None)
 	};
 	true
 }
 
+/// Schedules the cleanup of a `FinalizationRegistry` after its targets have been collected.
+///
+/// This is called during garbage collection, so the cleanup function is queued as a job instead of being called.
+/// The queue is traced by the runtime, which keeps the cleanup function alive until the job runs.
+pub(crate) unsafe extern "C" fn enqueue_finalization_registry_cleanup(
+	do_cleanup: *mut JSFunction, _: *mut JSObject, data: *mut c_void,
+) {
+	let cx = unsafe { &Context::new_unchecked(data.cast()) };
+	let event_loop = unsafe { &mut cx.get_private().event_loop };
+	if let Some(microtasks) = &mut event_loop.microtasks {
+		microtasks.enqueue(cx, Microtask::User(Heap::boxed(do_cleanup)));
+	}
+}
+
 unsafe extern "C" fn empty(extra: *const c_void) -> bool {
 	let queue = unsafe { &*extra.cast::<MicrotaskQueue>() };
 	queue.queue.is_empty()
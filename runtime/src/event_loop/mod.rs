@@ -6,16 +6,19 @@
 
 use std::collections::VecDeque;
 use std::ffi::c_void;
+use std::future::Future;
+use std::pin::Pin;
 use std::task;
-use std::task::Poll;
+use std::task::{Poll, Waker};
 
 use futures::future::poll_fn;
 use ion::format::{format_value, Config};
 use ion::{Context, ErrorReport, Local, Promise};
 use mozjs::jsapi::{Handle, Heap, JSContext, JSObject, PromiseRejectionHandlingState};
+use tokio::time::{sleep_until, Sleep};
 
 use crate::event_loop::future::FutureQueue;
-use crate::event_loop::macrotasks::MacrotaskQueue;
+use crate::event_loop::macrotasks::{Macrotask, MacrotaskQueue, NativeMacrotask};
 use crate::event_loop::microtasks::MicrotaskQueue;
 use crate::ContextExt;
 
@@ -29,32 +32,46 @@ pub struct EventLoop {
 	pub(crate) microtasks: Option<MicrotaskQueue>,
 	pub(crate) macrotasks: Option<MacrotaskQueue>,
 	pub(crate) unhandled_rejections: VecDeque<Box<Heap<*mut JSObject>>>,
+	pub(crate) exit_code: Option<i32>,
+	sleep: Option<Pin<Box<Sleep>>>,
+	waker: Option<Waker>,
 }
 
 impl EventLoop {
 	pub async fn run_event_loop(&mut self, cx: &Context) -> Result<(), Option<ErrorReport>> {
 		let mut complete = false;
-		poll_fn(|wcx| self.poll_event_loop(cx, wcx, &mut complete)).await
+		let result = poll_fn(|wcx| self.poll_event_loop(cx, wcx, &mut complete)).await;
+		if self.exit_code.is_some() {
+			Ok(())
+		} else {
+			result
+		}
 	}
 
 	fn poll_event_loop(
 		&mut self, cx: &Context, wcx: &mut task::Context, complete: &mut bool,
 	) -> Poll<Result<(), Option<ErrorReport>>> {
+		if self.exit_code.is_some() {
+			return Poll::Ready(Ok(()));
+		}
+		if !self.waker.as_ref().is_some_and(|waker| waker.will_wake(wcx.waker())) {
+			self.waker = Some(wcx.waker().clone());
+		}
+
+		let mut progressed = false;
+
 		if let Some(futures) = &mut self.futures {
 			if !futures.is_empty() {
-				futures.run_futures(cx, wcx)?;
+				progressed |= futures.run_futures(cx, wcx, self.microtasks.as_mut())?;
 			}
 		}
 
-		if let Some(microtasks) = &mut self.microtasks {
-			if !microtasks.is_empty() {
-				microtasks.run_jobs(cx)?;
-			}
-		}
+		progressed |= self.checkpoint(cx)?;
 
 		if let Some(macrotasks) = &mut self.macrotasks {
-			if !macrotasks.is_empty() {
-				macrotasks.run_job(cx)?;
+			if !macrotasks.is_empty() && macrotasks.run_job(cx)? {
+				progressed = true;
+				self.checkpoint(cx)?;
 			}
 		}
 
@@ -68,19 +85,56 @@ impl EventLoop {
 		}
 
 		let empty = self.is_empty();
-		if empty && *complete {
-			Poll::Ready(Ok(()))
-		} else {
+		if self.exit_code.is_some() || (empty && *complete) {
+			return Poll::Ready(Ok(()));
+		}
+		*complete = empty;
+
+		// Running jobs may have queued more jobs or futures, which are handled in the next iteration.
+		// Otherwise, the event loop is woken when a future completes or the earliest macrotask is due.
+		if progressed || empty {
 			wcx.waker().wake_by_ref();
-			*complete = empty;
-			Poll::Pending
+		} else if let Some(deadline) = self.macrotasks.as_mut().and_then(MacrotaskQueue::next_deadline) {
+			let sleep = self.sleep.get_or_insert_with(|| Box::pin(sleep_until(deadline)));
+			if sleep.deadline() != deadline {
+				sleep.as_mut().reset(deadline);
+			}
+			if sleep.as_mut().poll(wcx).is_ready() {
+				wcx.waker().wake_by_ref();
+			}
+		}
+		Poll::Pending
+	}
+
+	/// Queues a [NativeMacrotask] from outside of the event loop, such as from a spawned task, and wakes the event loop
+	/// to run it. Returns `false` if no macrotask queue has been initialised.
+	pub(crate) fn enqueue_native(&mut self, macrotask: NativeMacrotask) -> bool {
+		let Some(macrotasks) = &mut self.macrotasks else {
+			return false;
+		};
+		macrotasks.enqueue(Macrotask::Native(macrotask), None);
+		if let Some(waker) = &self.waker {
+			waker.wake_by_ref();
+		}
+		true
+	}
+
+	/// Performs a microtask checkpoint, which is done after each macrotask and after each future completes.
+	/// Returns `true` if any microtasks were run.
+	pub(crate) fn checkpoint(&mut self, cx: &Context) -> Result<bool, Option<ErrorReport>> {
+		match &mut self.microtasks {
+			Some(microtasks) if !microtasks.is_empty() => {
+				microtasks.run_jobs(cx)?;
+				Ok(true)
+			}
+			_ => Ok(false),
 		}
 	}
 
 	fn is_empty(&self) -> bool {
 		self.microtasks.as_ref().map(|m| m.is_empty()).unwrap_or(true)
 			&& self.futures.as_ref().map(|f| f.is_empty()).unwrap_or(true)
-			&& self.macrotasks.as_ref().map(|m| m.is_empty()).unwrap_or(true)
+			&& self.macrotasks.as_ref().map(|m| !m.has_referenced()).unwrap_or(true)
 	}
 }
 
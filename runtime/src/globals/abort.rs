@@ -5,7 +5,7 @@
  */
 
 use std::future::Future;
-use std::pin::{pin, Pin};
+use std::pin::Pin;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::task::Poll;
@@ -34,34 +34,43 @@ pub enum Signal {
 
 impl Signal {
 	pub fn poll(&self) -> SignalFuture {
-		SignalFuture { inner: self.clone() }
+		let changed = match self {
+			Signal::Receiver(receiver) | Signal::Timeout(receiver, _) => Some(wait_for_abort(receiver.clone())),
+			_ => None,
+		};
+		SignalFuture { inner: self.clone(), changed }
 	}
 }
 
+fn wait_for_abort(mut receiver: Receiver<Option<JSVal>>) -> Pin<Box<dyn Future<Output = Option<JSVal>>>> {
+	Box::pin(async move { receiver.wait_for(Option::is_some).await.ok().and_then(|abort| *abort) })
+}
+
 pub struct SignalFuture {
 	inner: Signal,
+	changed: Option<Pin<Box<dyn Future<Output = Option<JSVal>>>>>,
 }
 
 impl Future for SignalFuture {
 	type Output = JSVal;
 
 	fn poll(mut self: Pin<&mut SignalFuture>, cx: &mut task::Context) -> Poll<JSVal> {
-		match &mut self.inner {
-			Signal::None => Poll::Pending,
-			Signal::Abort(abort) => Poll::Ready(*abort),
-			Signal::Receiver(receiver) | Signal::Timeout(receiver, _) => {
-				if let Some(abort) = *receiver.borrow() {
-					return Poll::Ready(abort);
-				}
-				let changed = { pin!(receiver.changed()).poll(cx) };
-				match changed {
-					Poll::Ready(_) => match *receiver.borrow() {
-						Some(abort) => Poll::Ready(abort),
-						None => Poll::Pending,
-					},
-					Poll::Pending => Poll::Pending,
-				}
+		if let Signal::Abort(abort) = &self.inner {
+			return Poll::Ready(*abort);
+		}
+
+		// The task is woken by the watch channel when the signal is aborted.
+		let Some(changed) = &mut self.changed else {
+			return Poll::Pending;
+		};
+		match changed.as_mut().poll(cx) {
+			Poll::Ready(Some(abort)) => Poll::Ready(abort),
+			Poll::Ready(None) => {
+				// The sender was dropped, so the signal can no longer be aborted.
+				self.changed = None;
+				Poll::Pending
 			}
+			Poll::Pending => Poll::Pending,
 		}
 	}
 }
@@ -147,6 +156,7 @@ impl AbortSignal {
 		}
 	}
 
+	#[ion(static)]
 	pub fn abort<'cx>(cx: &'cx Context, Opt(reason): Opt<Value<'cx>>) -> *mut JSObject {
 		let reason = reason.unwrap_or_else(|| Error::new("AbortError", None).as_value(cx));
 		AbortSignal::new_object(
@@ -158,6 +168,7 @@ impl AbortSignal {
 		)
 	}
 
+	#[ion(static)]
 	pub fn timeout(cx: &Context, Enforce(time): Enforce<u64>) -> *mut JSObject {
 		let (sender, receiver) = channel(None);
 		let terminate = Arc::new(AtomicBool::new(false));
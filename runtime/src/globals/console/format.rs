@@ -8,8 +8,8 @@ use std::fmt;
 use std::fmt::{Display, Formatter, Write};
 
 use ion::conversions::FromValue;
-use ion::format::{format_value, Config as FormatConfig, ValueDisplay};
-use ion::{BigInt, Context, Local, Result, Value};
+use ion::format::{format_value, ColourConfig, Config as FormatConfig, ValueDisplay};
+use ion::{json, BigInt, Context, Local, Result, Value};
 use mozjs::conversions::ConversionBehavior;
 
 use crate::config::{Config, LogLevel};
@@ -38,16 +38,18 @@ impl Display for FormatArg<'_> {
 	}
 }
 
-pub(crate) fn format_args<'cx>(cx: &'cx Context, args: &'cx [Value<'cx>]) -> Vec<FormatArg<'cx>> {
-	fn inner<'cx>(cx: &'cx Context, args: &'cx [Value<'cx>]) -> Result<Vec<FormatArg<'cx>>> {
+pub(crate) fn format_args<'cx>(
+	cx: &'cx Context, args: &'cx [Value<'cx>], colours: ColourConfig,
+) -> Vec<FormatArg<'cx>> {
+	fn inner<'cx>(cx: &'cx Context, args: &'cx [Value<'cx>], colours: ColourConfig) -> Result<Vec<FormatArg<'cx>>> {
 		if args.len() <= 1 || !args[0].get().is_string() {
-			return Ok(format_value_args(cx, args.iter()).collect());
+			return Ok(format_value_args(cx, args.iter(), colours).collect());
 		}
 
 		let format = String::from_value(cx, &args[0], true, ())?;
 
 		if format.is_empty() {
-			return Ok(format_value_args(cx, args.iter()).collect());
+			return Ok(format_value_args(cx, args.iter(), colours).collect());
 		}
 
 		let mut outputs = Vec::new();
@@ -89,10 +91,20 @@ pub(crate) fn format_args<'cx>(cx: &'cx Context, args: &'cx [Value<'cx>]) -> Vec
 					output = String::with_capacity(format.len() - index);
 
 					outputs.push(FormatArg::Value {
-						value: format_value(cx, FormatConfig::default().indentation(INDENTS.get()), arg),
+						value: format_value(cx, format_config(colours), arg),
 						spaced: false,
 					});
 				}
+				Some(b'j') => {
+					let arg = args.next().unwrap();
+					index += 1;
+
+					match json::stringify(cx, arg, None, None) {
+						Ok(Some(json)) => output.push_str(&json),
+						Ok(None) => output.push_str("undefined"),
+						Err(_) => output.push_str("[Circular]"),
+					}
+				}
 				Some(b'c') => {
 					index += 1;
 				}
@@ -110,11 +122,11 @@ pub(crate) fn format_args<'cx>(cx: &'cx Context, args: &'cx [Value<'cx>]) -> Vec
 
 		output.push_str(&format[index..]);
 		outputs.push(FormatArg::String(output));
-		outputs.extend(format_value_args(cx, args));
+		outputs.extend(format_value_args(cx, args, colours));
 		Ok(outputs)
 	}
 
-	inner(cx, args).unwrap_or_else(|error| {
+	inner(cx, args, colours).unwrap_or_else(|error| {
 		if Config::global().log_level >= LogLevel::Warn {
 			eprintln!("{}", error.format());
 		}
@@ -122,15 +134,53 @@ pub(crate) fn format_args<'cx>(cx: &'cx Context, args: &'cx [Value<'cx>]) -> Vec
 	})
 }
 
+/// Formats the arguments as the console logs them, applying format specifiers from the first argument.
+/// The message is not coloured.
+pub fn format_message(cx: &Context, args: &[Value]) -> String {
+	let colours = ColourConfig::none();
+	if args.len() == 1 {
+		join_args(format_value_args(cx, args.iter(), colours))
+	} else {
+		join_args(format_args(cx, args, colours).into_iter())
+	}
+}
+
+pub(crate) fn join_args<'cx>(args: impl Iterator<Item = FormatArg<'cx>>) -> String {
+	let mut output = String::new();
+	let mut first = true;
+
+	let mut prev_spaced = false;
+	for arg in args {
+		let spaced = arg.spaced();
+		if !first && (prev_spaced || spaced) {
+			output.push(' ');
+		}
+		output.push_str(&arg.to_string());
+		first = false;
+		prev_spaced = spaced;
+	}
+	output
+}
+
 pub(crate) fn format_value_args<'cx>(
-	cx: &'cx Context, args: impl Iterator<Item = &'cx Value<'cx>>,
+	cx: &'cx Context, args: impl Iterator<Item = &'cx Value<'cx>>, colours: ColourConfig,
 ) -> impl Iterator<Item = FormatArg<'cx>> {
-	args.map(|arg| FormatArg::Value {
-		value: format_value(cx, FormatConfig::default().indentation(INDENTS.get()), arg),
+	args.map(move |arg| FormatArg::Value {
+		value: format_value(cx, format_config(colours), arg),
 		spaced: true,
 	})
 }
 
+/// Returns the configuration used to format values in the console, with the colours and current group indentation.
+pub(crate) fn format_config(colours: ColourConfig) -> FormatConfig {
+	let config = Config::global();
+	FormatConfig::default()
+		.colours(colours)
+		.indentation(INDENTS.get())
+		.max_depth(config.inspect_depth)
+		.max_array_length(config.inspect_array_length)
+}
+
 pub(crate) fn format_number_arg<'cx>(
 	cx: &'cx Context, arg: &Value<'cx>, format: &str, index: &mut usize, output: &mut String,
 ) -> Result<()> {
@@ -5,19 +5,22 @@
  */
 
 mod format;
+mod record;
+
+pub use format::format_message;
+pub use record::format_record;
 
 use std::cell::{Cell, RefCell};
 use std::collections::hash_map::{Entry, HashMap};
+use std::io::{stderr, stdout, IsTerminal};
+use std::time::Instant;
 
-use chrono::offset::Utc;
-use chrono::DateTime;
 use indent::indent_all_by;
 use indexmap::IndexSet;
-use ion::conversions::FromValue;
-use ion::flags::PropertyFlags;
+use ion::conversions::{FromValue, ToValue};
+use ion::flags::{IteratorFlags, PropertyFlags};
 use ion::format::key::format_key;
-use ion::format::primitive::format_primitive;
-use ion::format::{format_value, indent_str, Config as FormatConfig};
+use ion::format::{format_value, indent_str, ColourConfig, Config as FormatConfig};
 use ion::function::{Opt, Rest};
 use ion::{Context, Object, OwnedKey, Result, Stack, Value};
 use mozjs::jsapi::JSFunctionSpec;
@@ -26,8 +29,9 @@ use term_table::table_cell::{Alignment, TableCell};
 use term_table::{Table, TableStyle};
 
 use crate::cache::map::find_sourcemap;
-use crate::config::{Config, LogLevel};
-use crate::globals::console::format::{format_args, format_value_args, FormatArg};
+use crate::config::{Config, LogFormat, LogLevel};
+use crate::globals::console::format::{format_args, format_config, format_value_args, join_args, FormatArg};
+use crate::globals::console::record::print_record;
 
 const ANSI_CLEAR: &str = "\x1b[1;1H";
 const ANSI_CLEAR_SCREEN_DOWN: &str = "\x1b[0J";
@@ -36,7 +40,7 @@ const DEFAULT_LABEL: &str = "default";
 
 thread_local! {
 	static COUNT_MAP: RefCell<HashMap<String, u32>> = RefCell::new(HashMap::new());
-	static TIMER_MAP: RefCell<HashMap<String, DateTime<Utc>>> = RefCell::new(HashMap::new());
+	static TIMER_MAP: RefCell<HashMap<String, Instant>> = RefCell::new(HashMap::new());
 
 	static INDENTS: Cell<u16> = const { Cell::new(0) };
 }
@@ -47,40 +51,32 @@ fn log_args(cx: &Context, args: &[Value], log_level: LogLevel) {
 	}
 
 	if args.len() == 1 {
-		print_args(format_value_args(cx, args.iter()), log_level);
+		print_args(format_value_args(cx, args.iter(), colours(log_level)), log_level);
 	} else {
-		print_args(format_args(cx, args).into_iter(), log_level);
+		print_args(format_args(cx, args, colours(log_level)).into_iter(), log_level);
 	}
 }
 
 fn print_args<'cx>(args: impl Iterator<Item = FormatArg<'cx>>, log_level: LogLevel) {
-	if log_level == LogLevel::None {
-		return;
+	match log_level {
+		LogLevel::Info | LogLevel::Debug => print!("{}", join_args(args)),
+		LogLevel::Warn | LogLevel::Error => eprint!("{}", join_args(args)),
+		LogLevel::None => {}
 	}
+}
 
-	let mut first = true;
-
-	let mut prev_spaced = false;
-	for arg in args {
-		let spaced = arg.spaced();
-		let to_space = !first && (prev_spaced || spaced);
-		match log_level {
-			LogLevel::Info | LogLevel::Debug => {
-				if to_space {
-					print!(" ");
-				}
-				print!("{arg}");
-			}
-			LogLevel::Warn | LogLevel::Error => {
-				if to_space {
-					eprint!(" ");
-				}
-				eprint!("{arg}");
-			}
-			LogLevel::None => unreachable!(),
-		};
-		first = false;
-		prev_spaced = spaced;
+/// Returns the colours used to format values at the log level, which are disabled if the stream the log level is
+/// printed to is not a terminal.
+fn colours(log_level: LogLevel) -> ColourConfig {
+	let is_terminal = if log_level.is_stdout() {
+		stdout().is_terminal()
+	} else {
+		stderr().is_terminal()
+	};
+	if is_terminal {
+		ColourConfig::default()
+	} else {
+		ColourConfig::none()
 	}
 }
 
@@ -93,6 +89,23 @@ fn print_indent(log_level: LogLevel) {
 	}
 }
 
+/// Prints a message at the log level, as text or as a JSON record.
+fn print_message(cx: &Context, log_level: LogLevel, message: &str) {
+	if !print_record(cx, log_level, message, &[], None) {
+		print_indent(log_level);
+		match log_level {
+			LogLevel::Info | LogLevel::Debug => println!("{message}"),
+			LogLevel::Warn | LogLevel::Error => eprintln!("{message}"),
+			LogLevel::None => {}
+		}
+	}
+}
+
+/// Formats the time elapsed since the start of a timer in milliseconds, with microsecond precision.
+fn format_elapsed(start: Instant) -> String {
+	format!("{:.3}ms", start.elapsed().as_secs_f64() * 1000.0)
+}
+
 // TODO: Convert to Undefinable<String> as null is a valid label
 fn get_label(label: Option<String>) -> String {
 	if let Some(label) = label {
@@ -104,7 +117,7 @@ fn get_label(label: Option<String>) -> String {
 
 #[js_fn]
 fn log(cx: &Context, Rest(values): Rest<Value>) {
-	if Config::global().log_level >= LogLevel::Info {
+	if Config::global().log_level >= LogLevel::Info && !print_record(cx, LogLevel::Info, "", &values, None) {
 		print_indent(LogLevel::Info);
 		log_args(cx, &values, LogLevel::Info);
 		println!();
@@ -113,7 +126,7 @@ fn log(cx: &Context, Rest(values): Rest<Value>) {
 
 #[js_fn]
 fn warn(cx: &Context, Rest(values): Rest<Value>) {
-	if Config::global().log_level >= LogLevel::Warn {
+	if Config::global().log_level >= LogLevel::Warn && !print_record(cx, LogLevel::Warn, "", &values, None) {
 		print_indent(LogLevel::Warn);
 		log_args(cx, &values, LogLevel::Warn);
 		println!();
@@ -122,7 +135,7 @@ fn warn(cx: &Context, Rest(values): Rest<Value>) {
 
 #[js_fn]
 fn error(cx: &Context, Rest(values): Rest<Value>) {
-	if Config::global().log_level >= LogLevel::Error {
+	if Config::global().log_level >= LogLevel::Error && !print_record(cx, LogLevel::Error, "", &values, None) {
 		print_indent(LogLevel::Error);
 		log_args(cx, &values, LogLevel::Error);
 		println!();
@@ -131,7 +144,7 @@ fn error(cx: &Context, Rest(values): Rest<Value>) {
 
 #[js_fn]
 fn debug(cx: &Context, Rest(values): Rest<Value>) {
-	if Config::global().log_level == LogLevel::Debug {
+	if Config::global().log_level == LogLevel::Debug && !print_record(cx, LogLevel::Debug, "", &values, None) {
 		print_indent(LogLevel::Debug);
 		log_args(cx, &values, LogLevel::Debug);
 		println!();
@@ -139,67 +152,96 @@ fn debug(cx: &Context, Rest(values): Rest<Value>) {
 }
 
 #[js_fn]
-fn assert(cx: &Context, Opt(assertion): Opt<bool>, Rest(values): Rest<Value>) {
-	if Config::global().log_level >= LogLevel::Error {
-		if let Some(assertion) = assertion {
-			if assertion {
-				return;
-			}
+fn assert(cx: &Context, Opt(condition): Opt<bool>, Rest(values): Rest<Value>) {
+	const MESSAGE: &str = "Assertion failed";
 
-			if values.is_empty() {
-				print_indent(LogLevel::Error);
-				eprintln!("Assertion Failed");
-				return;
-			}
-
-			if values[0].handle().is_string() {
-				print_indent(LogLevel::Error);
-				eprint!(
-					"Assertion Failed: {} ",
-					format_primitive(cx, FormatConfig::default(), &values[0])
-				);
-				log_args(cx, &values[2..], LogLevel::Error);
-				eprintln!();
-				return;
-			}
+	if condition.unwrap_or(false) || Config::global().log_level < LogLevel::Error {
+		return;
+	}
 
-			print_indent(LogLevel::Error);
-			eprint!("Assertion Failed: ");
-			log_args(cx, &values, LogLevel::Error);
-			println!();
-		} else {
-			eprintln!("Assertion Failed:");
+	let mut values = values.into_vec();
+	match values.first() {
+		Some(first) if first.handle().is_string() => {
+			let first = String::from_value(cx, first, true, ()).unwrap();
+			values[0] = format!("{MESSAGE}: {first}").as_value(cx);
 		}
+		_ => values.insert(0, MESSAGE.as_value(cx)),
+	}
+
+	if !print_record(cx, LogLevel::Error, "", &values, None) {
+		print_indent(LogLevel::Error);
+		log_args(cx, &values, LogLevel::Error);
+		eprintln!();
+	}
+}
+
+#[derive(Default, FromValue)]
+struct DirOptions {
+	#[ion(default)]
+	depth: Option<f64>,
+	#[ion(default)]
+	show_hidden: bool,
+}
+
+#[js_fn]
+fn dir(cx: &Context, Opt(value): Opt<Value>, Opt(options): Opt<DirOptions>) {
+	if Config::global().log_level < LogLevel::Info {
+		return;
+	}
+
+	let value = value.unwrap_or_else(|| Value::undefined(cx));
+	if print_record(cx, LogLevel::Info, "", std::slice::from_ref(&value), None) {
+		return;
+	}
+
+	let options = options.unwrap_or_default();
+	let mut config = format_config(colours(LogLevel::Info));
+	if let Some(depth) = options.depth {
+		config = config.max_depth(depth.clamp(0.0, f64::from(u16::MAX)) as u16);
 	}
+	if options.show_hidden {
+		config = config.iteration(config.iteration | IteratorFlags::HIDDEN);
+	}
+
+	print_indent(LogLevel::Info);
+	println!("{}", format_value(cx, config, &value));
 }
 
 #[js_fn]
 fn clear() {
 	INDENTS.set(0);
 
-	println!("{ANSI_CLEAR}");
-	println!("{ANSI_CLEAR_SCREEN_DOWN}");
+	if Config::global().log_format == LogFormat::Text {
+		println!("{ANSI_CLEAR}");
+		println!("{ANSI_CLEAR_SCREEN_DOWN}");
+	}
 }
 
 #[js_fn]
 fn trace(cx: &Context, Rest(values): Rest<Value>) {
 	if Config::global().log_level == LogLevel::Debug {
-		print_indent(LogLevel::Debug);
-		print!("Trace: ");
-		log_args(cx, &values, LogLevel::Debug);
-		println!();
-
 		let mut stack = Stack::from_capture(cx);
-		let indents = ((INDENTS.get() + 1) * 2) as usize;
-
 		if let Some(stack) = &mut stack {
 			for record in &mut stack.records {
 				if let Some(sourcemap) = find_sourcemap(&record.location.file) {
 					record.transform_with_sourcemap(&sourcemap);
 				}
 			}
+		}
 
-			println!("{}", &indent_all_by(indents, stack.format()));
+		let formatted = stack.as_ref().map(Stack::format);
+		if print_record(cx, LogLevel::Debug, "Trace: ", &values, formatted.as_deref()) {
+			return;
+		}
+
+		print_indent(LogLevel::Debug);
+		print!("Trace: ");
+		log_args(cx, &values, LogLevel::Debug);
+		println!();
+
+		let indents = ((INDENTS.get() + 1) * 2) as usize;
+		if let Some(stack) = formatted {
+			println!("{}", &indent_all_by(indents, stack));
 		} else {
 			eprintln!("Current Stack could not be captured.");
 		}
@@ -208,12 +250,16 @@ fn trace(cx: &Context, Rest(values): Rest<Value>) {
 
 #[js_fn]
 fn group(cx: &Context, Rest(values): Rest<Value>) {
-	INDENTS.set(INDENTS.get().min(u16::MAX - 1) + 1);
-
-	if Config::global().log_level >= LogLevel::Info {
+	if Config::global().log_level >= LogLevel::Info
+		&& !values.is_empty()
+		&& !print_record(cx, LogLevel::Info, "", &values, None)
+	{
+		print_indent(LogLevel::Info);
 		log_args(cx, &values, LogLevel::Info);
 		println!();
 	}
+
+	INDENTS.set(INDENTS.get().min(u16::MAX - 1) + 1);
 }
 
 #[js_fn]
@@ -222,7 +268,7 @@ fn group_end() {
 }
 
 #[js_fn]
-fn count(Opt(label): Opt<String>) {
+fn count(cx: &Context, Opt(label): Opt<String>) {
 	let label = get_label(label);
 	COUNT_MAP.with_borrow_mut(|counts| {
 		let count = match counts.entry(label.clone()) {
@@ -230,14 +276,13 @@ fn count(Opt(label): Opt<String>) {
 			Entry::Occupied(mut o) => o.insert(o.get() + 1),
 		};
 		if Config::global().log_level >= LogLevel::Info {
-			print_indent(LogLevel::Info);
-			println!("{label}: {count}");
+			print_message(cx, LogLevel::Info, &format!("{label}: {count}"));
 		}
 	});
 }
 
 #[js_fn]
-fn count_reset(Opt(label): Opt<String>) {
+fn count_reset(cx: &Context, Opt(label): Opt<String>) {
 	let label = get_label(label);
 	COUNT_MAP.with_borrow_mut(|counts| match counts.get_mut(&label) {
 		Some(count) => {
@@ -245,24 +290,22 @@ fn count_reset(Opt(label): Opt<String>) {
 		}
 		None => {
 			if Config::global().log_level >= LogLevel::Warn {
-				print_indent(LogLevel::Warn);
-				eprintln!("Count for {label} does not exist");
+				print_message(cx, LogLevel::Warn, &format!("Count for {label} does not exist"));
 			}
 		}
 	});
 }
 
 #[js_fn]
-fn time(Opt(label): Opt<String>) {
+fn time(cx: &Context, Opt(label): Opt<String>) {
 	let label = get_label(label);
 	TIMER_MAP.with_borrow_mut(|timers| match timers.entry(label.clone()) {
 		Entry::Vacant(v) => {
-			v.insert(Utc::now());
+			v.insert(Instant::now());
 		}
 		Entry::Occupied(_) => {
 			if Config::global().log_level >= LogLevel::Warn {
-				print_indent(LogLevel::Warn);
-				eprintln!("Timer {label} already exists");
+				print_message(cx, LogLevel::Warn, &format!("Timer {label} already exists"));
 			}
 		}
 	});
@@ -273,39 +316,36 @@ fn time_log(cx: &Context, Opt(label): Opt<String>, Rest(values): Rest<Value>) {
 	let label = get_label(label);
 	TIMER_MAP.with_borrow(|timers| match timers.get(&label) {
 		Some(start) => {
-			if Config::global().log_level >= LogLevel::Info {
-				let duration = Utc::now().timestamp_millis() - start.timestamp_millis();
+			let prefix = format!("{label}: {} ", format_elapsed(*start));
+			if Config::global().log_level >= LogLevel::Info && !print_record(cx, LogLevel::Info, &prefix, &values, None)
+			{
 				print_indent(LogLevel::Info);
-				print!("{label}: {duration}ms ");
+				print!("{prefix}");
 				log_args(cx, &values, LogLevel::Info);
 				println!();
 			}
 		}
 		None => {
 			if Config::global().log_level >= LogLevel::Warn {
-				print_indent(LogLevel::Warn);
-				eprintln!("Timer {label} does not exist");
+				print_message(cx, LogLevel::Warn, &format!("Timer {label} does not exist"));
 			}
 		}
 	});
 }
 
 #[js_fn]
-fn time_end(Opt(label): Opt<String>) {
+fn time_end(cx: &Context, Opt(label): Opt<String>) {
 	let label = get_label(label);
 	TIMER_MAP.with_borrow_mut(|timers| match timers.remove(&label) {
 		Some(start_time) => {
 			if Config::global().log_level >= LogLevel::Info {
-				let duration = Utc::now().timestamp_millis() - start_time.timestamp_millis();
-				print_indent(LogLevel::Info);
-				print!("{label}: {duration}ms - Timer Ended");
-				println!();
+				let message = format!("{label}: {} - Timer Ended", format_elapsed(start_time));
+				print_message(cx, LogLevel::Info, &message);
 			}
 		}
 		None => {
 			if Config::global().log_level >= LogLevel::Warn {
-				print_indent(LogLevel::Warn);
-				eprintln!("Timer {label} does not exist");
+				print_message(cx, LogLevel::Warn, &format!("Timer {label} does not exist"));
 			}
 		}
 	});
@@ -340,6 +380,13 @@ fn table(cx: &Context, data: Value, Opt(columns): Opt<Vec<String>>) -> Result<()
 		keys
 	}
 
+	if Config::global().log_level < LogLevel::Info
+		|| print_record(cx, LogLevel::Info, "", std::slice::from_ref(&data), None)
+	{
+		return Ok(());
+	}
+
+	let colours = colours(LogLevel::Info);
 	let indents = INDENTS.get();
 	if let Ok(object) = Object::from_value(cx, &data, true, ()) {
 		let rows = object.keys(cx, None).map(|key| key.to_owned_key(cx));
@@ -380,7 +427,7 @@ fn table(cx: &Context, data: Value, Opt(columns): Opt<Vec<String>>) -> Result<()
 		let mut headers = Vec::with_capacity(1 + columns.len() + 1);
 		headers.push(TableCell::builder("Indices").alignment(Alignment::Center).build());
 		for column in &columns {
-			let key = format_key(cx, FormatConfig::default(), column);
+			let key = format_key(cx, FormatConfig::default().colours(colours), column);
 			headers.push(TableCell::builder(key).alignment(Alignment::Center).build());
 		}
 		if has_values {
@@ -391,7 +438,7 @@ fn table(cx: &Context, data: Value, Opt(columns): Opt<Vec<String>>) -> Result<()
 
 		for row in &rows {
 			let value = object.get(cx, row)?.unwrap();
-			let key = format_key(cx, FormatConfig::default(), row);
+			let key = format_key(cx, FormatConfig::default().colours(colours), row);
 
 			let mut cells = Vec::with_capacity(1 + columns.len() + 1);
 			cells.push(TableCell::builder(key).alignment(Alignment::Center).build());
@@ -399,7 +446,11 @@ fn table(cx: &Context, data: Value, Opt(columns): Opt<Vec<String>>) -> Result<()
 			if let Ok(object) = Object::from_value(cx, &value, true, ()) {
 				for column in &columns {
 					if let Some(value) = object.get(cx, column)? {
-						let value = format_value(cx, FormatConfig::default().multiline(false).quoted(true), &value);
+						let value = format_value(
+							cx,
+							FormatConfig::default().colours(colours).multiline(false).quoted(true),
+							&value,
+						);
 						cells.push(TableCell::builder(value).alignment(Alignment::Center).build());
 					} else {
 						cells.push(TableCell::new(""));
@@ -411,7 +462,11 @@ fn table(cx: &Context, data: Value, Opt(columns): Opt<Vec<String>>) -> Result<()
 			} else {
 				cells.extend((0..columns.len()).map(|_| TableCell::new("")));
 				if has_values {
-					let value = format_value(cx, FormatConfig::default().multiline(false).quoted(true), &value);
+					let value = format_value(
+						cx,
+						FormatConfig::default().colours(colours).multiline(false).quoted(true),
+						&value,
+					);
 					cells.push(TableCell::builder(value).alignment(Alignment::Center).build());
 				}
 			}
@@ -422,10 +477,7 @@ fn table(cx: &Context, data: Value, Opt(columns): Opt<Vec<String>>) -> Result<()
 		println!("{}", indent_all_by((indents * 2) as usize, table.render()))
 	} else if Config::global().log_level >= LogLevel::Info {
 		print_indent(LogLevel::Info);
-		println!(
-			"{}",
-			format_value(cx, FormatConfig::default().indentation(indents), &data)
-		);
+		println!("{}", format_value(cx, format_config(colours), &data));
 	}
 
 	Ok(())
@@ -434,7 +486,7 @@ fn table(cx: &Context, data: Value, Opt(columns): Opt<Vec<String>>) -> Result<()
 const METHODS: &[JSFunctionSpec] = &[
 	function_spec!(log, 0),
 	function_spec!(log, "info", 0),
-	function_spec!(log, "dir", 0),
+	function_spec!(dir, 0),
 	function_spec!(log, "dirxml", 0),
 	function_spec!(warn, 0),
 	function_spec!(error, 0),
@@ -0,0 +1,93 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use chrono::{SecondsFormat, Utc};
+use ion::conversions::ToValue;
+use ion::{json, Array, Context, Object, Value};
+use mozjs::jsapi::ESClass;
+
+use crate::config::{Config, LogFormat, LogLevel};
+use crate::globals::console::format::format_message;
+
+/// Prints a console call as a newline-delimited JSON record, if structured logging is enabled.
+/// Returns `false` if the call should be printed as text instead.
+pub(crate) fn print_record(
+	cx: &Context, log_level: LogLevel, prefix: &str, values: &[Value], stack: Option<&str>,
+) -> bool {
+	if Config::global().log_format != LogFormat::Json || log_level == LogLevel::None {
+		return false;
+	}
+
+	let json = format_record(cx, log_level, prefix, values, stack);
+	if log_level.is_stdout() {
+		println!("{json}");
+	} else {
+		eprintln!("{json}");
+	}
+	true
+}
+
+/// Formats a console call as a JSON record.
+///
+/// The record has the `timestamp` of the call, its `level`, the formatted `message` and the `args` as JSON values.
+/// It also has the `stack` of the trace, or of the first error in the arguments, if there is one.
+pub fn format_record(cx: &Context, log_level: LogLevel, prefix: &str, values: &[Value], stack: Option<&str>) -> String {
+	let message = format!("{prefix}{}", format_message(cx, values));
+
+	let record = Object::new(cx);
+	record.set_as(
+		cx,
+		"timestamp",
+		&Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+	);
+	record.set_as(cx, "level", log_level.name());
+	record.set_as(cx, "message", &message);
+
+	let args = Array::new_with_length(cx, values.len());
+	let mut error_stack = None;
+	for (index, value) in values.iter().enumerate() {
+		let arg = structured_arg(cx, value, &mut error_stack);
+		args.set(cx, index as u32, &arg);
+	}
+	record.set_as(cx, "args", &args);
+
+	if let Some(stack) = stack.map(String::from).or(error_stack) {
+		record.set_as(cx, "stack", &stack);
+	}
+
+	let json = json::stringify(cx, &record.as_value(cx), None, None).ok().flatten();
+	json.unwrap_or_else(|| {
+		let record = Object::new(cx);
+		record.set_as(cx, "level", log_level.name());
+		record.set_as(cx, "message", &message);
+		json::stringify(cx, &record.as_value(cx), None, None).ok().flatten().unwrap_or_default()
+	})
+}
+
+/// Converts an argument into a value that can be represented in JSON.
+/// Errors are converted to their name, message and stack, and other values that cannot be represented are formatted.
+fn structured_arg<'cx>(cx: &'cx Context, value: &Value<'cx>, error_stack: &mut Option<String>) -> Value<'cx> {
+	if value.handle().is_object() {
+		let object = value.to_object(cx);
+		if object.get_builtin_class(cx) == ESClass::Error {
+			let error = Object::new(cx);
+			for key in ["name", "message", "stack"] {
+				if let Ok(Some(property)) = object.get_as::<_, String>(cx, key, false, ()) {
+					if key == "stack" && error_stack.is_none() {
+						*error_stack = Some(property.clone());
+					}
+					error.set_as(cx, key, &property);
+				}
+			}
+			return error.as_value(cx);
+		}
+	}
+
+	match json::stringify(cx, value, None, None) {
+		Ok(Some(_)) => Value::from(cx.root(value.get())),
+		_ => format_message(cx, std::slice::from_ref(value)).as_value(cx),
+	}
+}
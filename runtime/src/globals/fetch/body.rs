@@ -6,23 +6,31 @@
 
 use std::fmt::{Display, Formatter};
 use std::pin::Pin;
-use std::task::Poll;
+use std::task::{ready, Poll};
 use std::{fmt, task};
 
-use bytes::Bytes;
+use bytes::{Bytes, BytesMut};
 use form_urlencoded::Serializer;
 use http::header::CONTENT_TYPE;
 use http::{HeaderMap, HeaderValue};
-use http_body_util::Full;
+use http_body_util::{BodyExt, Full};
 use hyper::body::{Frame, Incoming, SizeHint};
+use ion::class::ClassObjectWrapper;
 use ion::conversions::FromValue;
-use ion::{Context, Error, ErrorKind, Value};
-use mozjs::jsapi::Heap;
+use ion::typedarray::ArrayBufferWrapper;
+use ion::{json, ClassDefinition, Context, Error, ErrorKind, Exception, Local, Object, Promise, Value};
+use mozjs::jsapi::{Heap, JSObject};
 use mozjs::jsval::JSVal;
 use pin_project::pin_project;
+use tokio::sync::watch;
 
+use crate::globals::fetch::form_data::FormData;
+use crate::globals::fetch::progress::Progress;
+use crate::globals::fetch::Headers;
 use crate::globals::file::{Blob, BufferSource};
 use crate::globals::url::URLSearchParams;
+use crate::mime::MediaType;
+use crate::promise::future_to_promise;
 
 #[derive(Debug, Clone, Traceable)]
 #[non_exhaustive]
@@ -75,6 +83,13 @@ impl FetchBody {
 		}
 	}
 
+	pub(crate) fn bytes(&self) -> Option<&Bytes> {
+		match &self.body {
+			FetchBodyInner::None => None,
+			FetchBodyInner::Bytes(bytes) => Some(bytes),
+		}
+	}
+
 	pub fn is_stream(&self) -> bool {
 		!matches!(&self.body, FetchBodyInner::None | FetchBodyInner::Bytes(_))
 	}
@@ -115,6 +130,16 @@ impl Default for FetchBody {
 	}
 }
 
+impl From<Bytes> for FetchBody {
+	fn from(bytes: Bytes) -> FetchBody {
+		FetchBody {
+			body: FetchBodyInner::Bytes(bytes),
+			source: None,
+			kind: None,
+		}
+	}
+}
+
 impl<'cx> FromValue<'cx> for FetchBody {
 	type Config = ();
 	fn from_value(cx: &'cx Context, value: &Value, strict: bool, _: ()) -> ion::Result<FetchBody> {
@@ -135,7 +160,7 @@ impl<'cx> FromValue<'cx> for FetchBody {
 				return Ok(FetchBody {
 					body: FetchBodyInner::Bytes(blob.bytes.clone()),
 					source: Some(Heap::boxed(value.get())),
-					kind: blob.kind.clone().map(FetchBodyKind::Blob),
+					kind: blob.kind.clone().filter(|kind| !kind.is_empty()).map(FetchBodyKind::Blob),
 				});
 			} else if let Ok(search_params) = <&URLSearchParams>::from_value(cx, value, strict, ()) {
 				return Ok(FetchBody {
@@ -157,7 +182,11 @@ pub enum Body {
 	#[default]
 	Empty,
 	Once(#[pin] Full<Bytes>),
-	Incoming(#[pin] Incoming),
+	Incoming(#[pin] Incoming, Option<watch::Sender<Option<HeaderMap>>>),
+	/// Body which reports the number of bytes read from it.
+	Progress(#[pin] Box<Body>, watch::Sender<Progress>),
+	/// Body which yields bytes that have already been read from the inner body, before the rest of it.
+	Prefixed(Option<Bytes>, #[pin] Box<Body>),
 }
 
 impl hyper::body::Body for Body {
@@ -170,7 +199,38 @@ impl hyper::body::Body for Body {
 		match self.project() {
 			BodyProject::Empty => Poll::Ready(None),
 			BodyProject::Once(full) => full.poll_frame(cx).map_err(|e| match e {}),
-			BodyProject::Incoming(incoming) => incoming.poll_frame(cx),
+			BodyProject::Incoming(incoming, trailers) => {
+				let frame = ready!(incoming.poll_frame(cx));
+				if let Some(trailers) = trailers {
+					match &frame {
+						Some(Ok(frame)) => {
+							if let Some(map) = frame.trailers_ref() {
+								trailers.send_replace(Some(map.clone()));
+							}
+						}
+						None => {
+							trailers.send_if_modified(|trailers| {
+								let modified = trailers.is_none();
+								trailers.get_or_insert_with(HeaderMap::new);
+								modified
+							});
+						}
+						Some(Err(_)) => {}
+					}
+				}
+				Poll::Ready(frame)
+			}
+			BodyProject::Progress(body, progress) => {
+				let frame = ready!(body.poll_frame(cx));
+				if let Some(data) = frame.as_ref().and_then(|frame| frame.as_ref().ok()?.data_ref()) {
+					progress.send_modify(|progress| progress.loaded += data.len() as u64);
+				}
+				Poll::Ready(frame)
+			}
+			BodyProject::Prefixed(prefix, body) => match prefix.take().filter(|prefix| !prefix.is_empty()) {
+				Some(prefix) => Poll::Ready(Some(Ok(Frame::data(prefix)))),
+				None => body.poll_frame(cx),
+			},
 		}
 	}
 
@@ -178,7 +238,9 @@ impl hyper::body::Body for Body {
 		match self {
 			Body::Empty => true,
 			Body::Once(full) => full.is_end_stream(),
-			Body::Incoming(incoming) => incoming.is_end_stream(),
+			Body::Incoming(incoming, _) => incoming.is_end_stream(),
+			Body::Progress(body, _) => body.is_end_stream(),
+			Body::Prefixed(prefix, body) => prefix.as_ref().map_or(true, Bytes::is_empty) && body.is_end_stream(),
 		}
 	}
 
@@ -186,7 +248,17 @@ impl hyper::body::Body for Body {
 		match self {
 			Body::Empty => SizeHint::with_exact(0),
 			Body::Once(full) => full.size_hint(),
-			Body::Incoming(incoming) => incoming.size_hint(),
+			Body::Incoming(incoming, _) => incoming.size_hint(),
+			Body::Progress(body, _) => body.size_hint(),
+			Body::Prefixed(prefix, body) => {
+				let length = prefix.as_ref().map_or(0, |prefix| prefix.len() as u64);
+				let mut hint = body.size_hint();
+				hint.set_lower(hint.lower() + length);
+				if let Some(upper) = hint.upper() {
+					hint.set_upper(upper + length);
+				}
+				hint
+			}
 		}
 	}
 }
@@ -196,3 +268,84 @@ impl From<Bytes> for Body {
 		Body::Once(Full::new(bytes))
 	}
 }
+
+pub async fn read_to_bytes(body: Body) -> ion::Result<Bytes> {
+	Ok(body.collect().await?.to_bytes())
+}
+
+/// Reads the body into [Bytes], like [read_to_bytes].
+///
+/// If reading fails, the error is returned alongside a body which yields the bytes read so far before the rest of the
+/// body, so that the body can be restored.
+pub(crate) async fn try_read_to_bytes(mut body: Body) -> Result<Bytes, (ion::Error, Body)> {
+	let mut bytes = BytesMut::new();
+	while let Some(frame) = body.frame().await {
+		match frame {
+			Ok(frame) => {
+				if let Some(data) = frame.data_ref() {
+					bytes.extend_from_slice(data);
+				}
+			}
+			Err(error) => return Err((error.into(), Body::Prefixed(Some(bytes.freeze()), Box::new(body)))),
+		}
+	}
+	Ok(bytes.freeze())
+}
+
+/// Implements the methods of the body mixin, which are shared by [Request](super::Request) and
+/// [Response](super::Response).
+pub(crate) trait BodyMixin {
+	/// Returns the headers object, which is used to read the `Content-Type` of the body.
+	fn headers_object(&self) -> &Heap<*mut JSObject>;
+
+	/// Takes the body so that it can be read, and marks it as used.
+	///
+	/// Returns an error if the body has already been used.
+	fn take_body(&mut self, cx: &Context) -> ion::Result<Body>;
+
+	/// Extracts the MIME type of the body from the `Content-Type` headers.
+	fn content_type(&self, cx: &Context) -> Option<MediaType> {
+		let headers = Object::from(unsafe { Local::from_heap(self.headers_object()) });
+		let headers = Headers::get_private(cx, &headers).ok()?;
+		let values = headers.headers.get_all(CONTENT_TYPE).into_iter().filter_map(|value| value.to_str().ok());
+		MediaType::extract(values)
+	}
+
+	fn read_array_buffer<'cx>(&mut self, cx: &'cx Context) -> Option<Promise<'cx>> {
+		let body = self.take_body(cx);
+		future_to_promise::<_, _, Error>(cx, async move {
+			let bytes = read_to_bytes(body?).await?;
+			Ok(ArrayBufferWrapper::from(bytes))
+		})
+	}
+
+	fn read_text<'cx>(&mut self, cx: &'cx Context) -> Option<Promise<'cx>> {
+		let body = self.take_body(cx);
+		future_to_promise::<_, _, Error>(cx, async move {
+			let bytes = read_to_bytes(body?).await?;
+			String::from_utf8(Vec::from(bytes)).map_err(|e| Error::new(format!("Invalid UTF-8 sequence: {e}"), None))
+		})
+	}
+
+	fn read_json<'cx>(&mut self, cx: &'cx Context) -> Option<Promise<'cx>> {
+		let body = self.take_body(cx);
+		let cx2 = unsafe { Context::new_unchecked(cx.as_ptr()) };
+		future_to_promise::<_, _, Exception>(cx, async move {
+			let bytes = read_to_bytes(body?).await?;
+			let text = String::from_utf8(Vec::from(bytes))
+				.map_err(|e| Error::new(format!("Invalid UTF-8 sequence: {e}"), None))?;
+			Ok(json::parse(&cx2, &text)?.get())
+		})
+	}
+
+	fn read_form_data<'cx>(&mut self, cx: &'cx Context) -> Option<Promise<'cx>> {
+		let body = self.take_body(cx);
+		let content_type = self.content_type(cx);
+		let cx2 = unsafe { Context::new_unchecked(cx.as_ptr()) };
+		future_to_promise::<_, _, Error>(cx, async move {
+			let bytes = read_to_bytes(body?).await?;
+			let form_data = FormData::parse(&cx2, &bytes, content_type.as_ref())?;
+			Ok(ClassObjectWrapper(Box::new(form_data)))
+		})
+	}
+}
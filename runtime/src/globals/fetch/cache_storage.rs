@@ -0,0 +1,438 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::io::ErrorKind as IoErrorKind;
+use std::path::{Path, PathBuf};
+
+use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+use base64::Engine;
+use dirs::home_dir;
+use headers::{HeaderMapExt, Vary};
+use http::{Method, StatusCode};
+use ion::class::{ClassObjectWrapper, Reflector};
+use ion::conversions::{IntoValue, ToValue};
+use ion::flags::PropertyFlags;
+use ion::function::Opt;
+use ion::{Array, ClassDefinition, Context, Error, ErrorKind, Local, Object, Promise, Result, Value};
+use sha3::{Digest, Sha3_256};
+use tokio::fs;
+use url::{Position, Url};
+
+use crate::globals::fetch::body::{read_to_bytes, BodyMixin};
+use crate::globals::fetch::serialize::{Reader, REQUEST_MAGIC};
+use crate::globals::fetch::{Headers, Request, RequestInfo, Response};
+use crate::promise::future_to_promise;
+
+const NAME_FILE: &str = "name";
+const REQUEST_EXTENSION: &str = "request";
+const RESPONSE_EXTENSION: &str = "response";
+const TEMPORARY_EXTENSION: &str = "tmp";
+
+#[derive(Default, FromValue)]
+pub struct CacheQueryOptions {
+	#[ion(default)]
+	ignore_search: bool,
+	#[ion(default)]
+	ignore_method: bool,
+}
+
+#[derive(Default, FromValue)]
+pub struct MultiCacheQueryOptions {
+	#[ion(default)]
+	ignore_search: bool,
+	#[ion(default)]
+	ignore_method: bool,
+	#[ion(default)]
+	cache_name: Option<String>,
+}
+
+/// Stores named [caches](Cache) on disk, in `~/.spiderfire/caches`, so that they persist between runs.
+#[js_class]
+pub struct CacheStorage {
+	reflector: Reflector,
+	#[trace(no_trace)]
+	dir: Option<PathBuf>,
+}
+
+impl CacheStorage {
+	fn dir(&self) -> Result<PathBuf> {
+		self.dir.clone().ok_or_else(|| {
+			Error::new(
+				"Cache storage is unavailable without a home directory.",
+				ErrorKind::Normal,
+			)
+		})
+	}
+}
+
+#[js_class]
+impl CacheStorage {
+	#[ion(name = "match")]
+	pub fn match_<'cx>(
+		&self, cx: &'cx Context, request: RequestInfo, Opt(options): Opt<MultiCacheQueryOptions>,
+	) -> Option<Promise<'cx>> {
+		let options = options.unwrap_or_default();
+		let query = self.dir().and_then(|dir| {
+			let query = Query::new(cx, request, options.ignore_search, options.ignore_method)?;
+			Ok((dir, query))
+		});
+
+		let cx2 = unsafe { Context::new_unchecked(cx.as_ptr()) };
+		future_to_promise::<_, _, Error>(cx, async move {
+			let (dir, query) = query?;
+			for (name, dir) in caches(&dir).await? {
+				if options.cache_name.as_ref().is_some_and(|cache_name| *cache_name != name) {
+					continue;
+				}
+				if let Some(stem) = find_entries(&dir, &query).await?.into_iter().next() {
+					let response = read_response(&cx2, &stem).await?;
+					return Ok(Entry(Some(Box::new(response))));
+				}
+			}
+			Ok(Entry(None))
+		})
+	}
+
+	pub fn has<'cx>(&self, cx: &'cx Context, name: String) -> Option<Promise<'cx>> {
+		let dir = self.dir();
+		future_to_promise::<_, _, Error>(cx, async move {
+			let name_file = cache_dir(&dir?, &name).join(NAME_FILE);
+			Ok(fs::try_exists(name_file).await?)
+		})
+	}
+
+	pub fn open<'cx>(&self, cx: &'cx Context, name: String) -> Option<Promise<'cx>> {
+		let dir = self.dir();
+		future_to_promise::<_, _, Error>(cx, async move {
+			let dir = cache_dir(&dir?, &name);
+			fs::create_dir_all(&dir).await?;
+			fs::write(dir.join(NAME_FILE), &name).await?;
+			Ok(ClassObjectWrapper(Box::new(Cache {
+				reflector: Reflector::default(),
+				dir,
+			})))
+		})
+	}
+
+	pub fn delete<'cx>(&self, cx: &'cx Context, name: String) -> Option<Promise<'cx>> {
+		let dir = self.dir();
+		future_to_promise::<_, _, Error>(cx, async move {
+			match fs::remove_dir_all(cache_dir(&dir?, &name)).await {
+				Ok(()) => Ok(true),
+				Err(error) if error.kind() == IoErrorKind::NotFound => Ok(false),
+				Err(error) => Err(error.into()),
+			}
+		})
+	}
+
+	pub fn keys<'cx>(&self, cx: &'cx Context) -> Option<Promise<'cx>> {
+		let dir = self.dir();
+		future_to_promise::<_, _, Error>(cx, async move {
+			let caches = caches(&dir?).await?;
+			Ok(caches.into_iter().map(|(name, _)| name).collect::<Vec<_>>())
+		})
+	}
+}
+
+/// Stores pairs of [requests](Request) and [responses](Response), keyed by the URL of the request.
+///
+/// Only `GET` requests are stored, and the `Vary` header of responses is not taken into account when matching.
+#[js_class]
+pub struct Cache {
+	reflector: Reflector,
+	#[trace(no_trace)]
+	dir: PathBuf,
+}
+
+#[js_class]
+impl Cache {
+	#[ion(name = "match")]
+	pub fn match_<'cx>(
+		&self, cx: &'cx Context, request: RequestInfo, Opt(options): Opt<CacheQueryOptions>,
+	) -> Option<Promise<'cx>> {
+		let options = options.unwrap_or_default();
+		let query = Query::new(cx, request, options.ignore_search, options.ignore_method);
+		let dir = self.dir.clone();
+
+		let cx2 = unsafe { Context::new_unchecked(cx.as_ptr()) };
+		future_to_promise::<_, _, Error>(cx, async move {
+			match find_entries(&dir, &query?).await?.into_iter().next() {
+				Some(stem) => Ok(Entry(Some(Box::new(read_response(&cx2, &stem).await?)))),
+				None => Ok(Entry(None)),
+			}
+		})
+	}
+
+	#[ion(name = "matchAll")]
+	pub fn match_all<'cx>(
+		&self, cx: &'cx Context, Opt(request): Opt<RequestInfo>, Opt(options): Opt<CacheQueryOptions>,
+	) -> Option<Promise<'cx>> {
+		let options = options.unwrap_or_default();
+		let query = request
+			.map(|request| Query::new(cx, request, options.ignore_search, options.ignore_method))
+			.transpose();
+		let dir = self.dir.clone();
+
+		let cx2 = unsafe { Context::new_unchecked(cx.as_ptr()) };
+		future_to_promise::<_, _, Error>(cx, async move {
+			let entries = entries(&dir, query?.as_ref()).await?;
+			let mut responses = Vec::with_capacity(entries.len());
+			for stem in entries {
+				responses.push(Box::new(read_response(&cx2, &stem).await?));
+			}
+			Ok(Entries(responses))
+		})
+	}
+
+	/// Stores the response for the request, replacing any response stored for the same URL.
+	/// The body of the response is consumed.
+	///
+	/// The response is written before the request, and each file is replaced atomically, so that interrupted writes
+	/// never leave an entry without its response.
+	pub fn put<'cx>(&self, cx: &'cx Context, request: RequestInfo, response: Object) -> Option<Promise<'cx>> {
+		let entry = to_request(cx, request).and_then(|request| {
+			if request.url.scheme() != "http" && request.url.scheme() != "https" {
+				return Err(Error::new("Cache requests must use HTTP or HTTPS.", ErrorKind::Type));
+			}
+			if request.method != Method::GET {
+				return Err(Error::new("Cache requests must use the GET method.", ErrorKind::Type));
+			}
+
+			let response = Response::get_mut_private(cx, &response)?;
+			if response.status == Some(StatusCode::PARTIAL_CONTENT) {
+				return Err(Error::new("Partial responses cannot be cached.", ErrorKind::Type));
+			}
+			let headers = Object::from(unsafe { Local::from_heap(&response.headers) });
+			let vary = Headers::get_private(cx, &headers)?.headers.typed_get::<Vary>();
+			if vary.is_some_and(|vary| vary.is_any()) {
+				return Err(Error::new(
+					"Responses which vary on '*' cannot be cached.",
+					ErrorKind::Type,
+				));
+			}
+			if response.get_body_used(cx) {
+				return Err(Error::new("Response body has already been used.", ErrorKind::Type));
+			}
+
+			let writer = response.write_head(cx)?;
+			let body = response.take_body(cx)?;
+			Ok((entry_stem(&self.dir, &request.url), request.to_bytes(cx)?, writer, body))
+		});
+
+		future_to_promise::<_, _, Error>(cx, async move {
+			let (stem, request, mut writer, body) = entry?;
+			let bytes = read_to_bytes(body).await?;
+			writer.write_body(Some(&bytes));
+
+			write_atomic(&stem.with_extension(RESPONSE_EXTENSION), &writer.finish()).await?;
+			write_atomic(&stem.with_extension(REQUEST_EXTENSION), &request).await?;
+			Ok(())
+		})
+	}
+
+	pub fn delete<'cx>(
+		&self, cx: &'cx Context, request: RequestInfo, Opt(options): Opt<CacheQueryOptions>,
+	) -> Option<Promise<'cx>> {
+		let options = options.unwrap_or_default();
+		let query = Query::new(cx, request, options.ignore_search, options.ignore_method);
+		let dir = self.dir.clone();
+
+		future_to_promise::<_, _, Error>(cx, async move {
+			let entries = find_entries(&dir, &query?).await?;
+			for stem in &entries {
+				fs::remove_file(stem.with_extension(REQUEST_EXTENSION)).await?;
+				fs::remove_file(stem.with_extension(RESPONSE_EXTENSION)).await?;
+			}
+			Ok(!entries.is_empty())
+		})
+	}
+
+	pub fn keys<'cx>(
+		&self, cx: &'cx Context, Opt(request): Opt<RequestInfo>, Opt(options): Opt<CacheQueryOptions>,
+	) -> Option<Promise<'cx>> {
+		let options = options.unwrap_or_default();
+		let query = request
+			.map(|request| Query::new(cx, request, options.ignore_search, options.ignore_method))
+			.transpose();
+		let dir = self.dir.clone();
+
+		let cx2 = unsafe { Context::new_unchecked(cx.as_ptr()) };
+		future_to_promise::<_, _, Error>(cx, async move {
+			let entries = entries(&dir, query?.as_ref()).await?;
+			let mut requests = Vec::with_capacity(entries.len());
+			for stem in entries {
+				let bytes = fs::read(stem.with_extension(REQUEST_EXTENSION)).await?;
+				requests.push(Box::new(Request::from_bytes(&cx2, &bytes)?));
+			}
+			Ok(Entries(requests))
+		})
+	}
+}
+
+/// Request to match against the entries of a [Cache].
+struct Query {
+	method: Method,
+	url: Url,
+	ignore_search: bool,
+	ignore_method: bool,
+}
+
+impl Query {
+	fn new(cx: &Context, request: RequestInfo, ignore_search: bool, ignore_method: bool) -> Result<Query> {
+		let request = to_request(cx, request)?;
+		Ok(Query {
+			method: request.method,
+			url: request.url,
+			ignore_search,
+			ignore_method,
+		})
+	}
+}
+
+/// [Request] or [Response] read from a [Cache], which is converted into an object when the promise settles.
+/// Converts to `undefined` if there is no entry.
+struct Entry<T: ClassDefinition>(Option<Box<T>>);
+
+impl<T: ClassDefinition> IntoValue<'_> for Entry<T> {
+	fn into_value(self: Box<Self>, cx: &Context, value: &mut Value) {
+		match self.0 {
+			Some(entry) => T::new_object(cx, entry).to_value(cx, value),
+			None => value.handle_mut().set(mozjs::jsval::UndefinedValue()),
+		}
+	}
+}
+
+/// [Requests](Request) or [responses](Response) read from a [Cache], which are converted into an array of objects when
+/// the promise settles.
+struct Entries<T: ClassDefinition>(Vec<Box<T>>);
+
+impl<T: ClassDefinition> IntoValue<'_> for Entries<T> {
+	fn into_value(self: Box<Self>, cx: &Context, value: &mut Value) {
+		let array = Array::new(cx);
+		for (index, entry) in self.0.into_iter().enumerate() {
+			array.set_as(cx, index as u32, &T::new_object(cx, entry));
+		}
+		array.to_value(cx, value);
+	}
+}
+
+fn to_request(cx: &Context, request: RequestInfo) -> Result<Request> {
+	match request {
+		RequestInfo::Request(request) => {
+			let request = request.to_bytes(cx)?;
+			Request::from_bytes(cx, &request)
+		}
+		info @ RequestInfo::String(_) => Request::constructor(cx, info, Opt(None)),
+	}
+}
+
+async fn read_response(cx: &Context, stem: &Path) -> Result<Response> {
+	let bytes = fs::read(stem.with_extension(RESPONSE_EXTENSION)).await?;
+	Response::from_bytes(cx, &bytes)
+}
+
+/// Writes the file by replacing it with a temporary file, so that it is never partially written.
+async fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+	let mut temporary = path.as_os_str().to_owned();
+	temporary.push(".");
+	temporary.push(TEMPORARY_EXTENSION);
+
+	fs::write(&temporary, contents).await?;
+	fs::rename(&temporary, path).await?;
+	Ok(())
+}
+
+/// Returns the names and directories of the caches in the storage directory, sorted by name.
+async fn caches(dir: &Path) -> Result<Vec<(String, PathBuf)>> {
+	let mut caches = Vec::new();
+	let mut entries = match fs::read_dir(dir).await {
+		Ok(entries) => entries,
+		Err(error) if error.kind() == IoErrorKind::NotFound => return Ok(caches),
+		Err(error) => return Err(error.into()),
+	};
+	while let Some(entry) = entries.next_entry().await? {
+		let path = entry.path();
+		if let Ok(name) = fs::read_to_string(path.join(NAME_FILE)).await {
+			caches.push((name, path));
+		}
+	}
+	caches.sort();
+	Ok(caches)
+}
+
+/// Returns the paths, without extensions, of the entries which match the query, or every entry without a query.
+async fn entries(dir: &Path, query: Option<&Query>) -> Result<Vec<PathBuf>> {
+	if let Some(query) = query {
+		return find_entries(dir, query).await;
+	}
+
+	let mut entries = Vec::new();
+	let mut dir = fs::read_dir(dir).await?;
+	while let Some(entry) = dir.next_entry().await? {
+		let path = entry.path();
+		if path.extension().is_some_and(|extension| extension == REQUEST_EXTENSION) {
+			entries.push(path.with_extension(""));
+		}
+	}
+	entries.sort();
+	Ok(entries)
+}
+
+/// Returns the paths, without extensions, of the entries which match the query.
+async fn find_entries(dir: &Path, query: &Query) -> Result<Vec<PathBuf>> {
+	if !query.ignore_method && query.method != Method::GET {
+		return Ok(Vec::new());
+	}
+
+	if !query.ignore_search {
+		let stem = entry_stem(dir, &query.url);
+		let exists = fs::try_exists(stem.with_extension(REQUEST_EXTENSION)).await?;
+		return Ok(if exists { vec![stem] } else { Vec::new() });
+	}
+
+	let mut entries = Vec::new();
+	let mut dir = fs::read_dir(dir).await?;
+	while let Some(entry) = dir.next_entry().await? {
+		let path = entry.path();
+		if path.extension().is_some_and(|extension| extension == REQUEST_EXTENSION) {
+			let bytes = fs::read(&path).await?;
+			let mut reader = Reader::new(&bytes, REQUEST_MAGIC)?;
+			let _: Method = reader.read_parsed()?;
+			let url: Url = reader.read_parsed()?;
+			if url[..Position::AfterPath] == query.url[..Position::AfterPath] {
+				entries.push(path.with_extension(""));
+			}
+		}
+	}
+	entries.sort();
+	Ok(entries)
+}
+
+fn cache_dir(dir: &Path, name: &str) -> PathBuf {
+	dir.join(hash(name))
+}
+
+fn entry_stem(dir: &Path, url: &Url) -> PathBuf {
+	dir.join(hash(&url[..Position::AfterQuery]))
+}
+
+fn hash(string: &str) -> String {
+	BASE64_URL_SAFE_NO_PAD.encode(Sha3_256::new().chain_update(string).finalize())
+}
+
+pub fn define(cx: &Context, global: &Object) -> bool {
+	let dir = home_dir().map(|mut path| {
+		path.extend([".spiderfire", "caches"]);
+		path
+	});
+	if !(CacheStorage::init_class(cx, global).0 && Cache::init_class(cx, global).0) {
+		return false;
+	}
+
+	let caches = CacheStorage { reflector: Reflector::default(), dir };
+	let caches = Object::from(cx.root(CacheStorage::new_object(cx, Box::new(caches))));
+	global.define_as(cx, "caches", &caches, PropertyFlags::CONSTANT_ENUMERATED)
+}
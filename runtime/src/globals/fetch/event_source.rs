@@ -0,0 +1,508 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::mem;
+use std::pin::pin;
+use std::rc::Rc;
+use std::time::Duration;
+
+use futures::future::{select, Either};
+use http::header::ACCEPT;
+use http::{HeaderValue, StatusCode};
+use http_body_util::BodyExt;
+use ion::class::Reflector;
+use ion::function::{Callback, Opt};
+use ion::{ClassDefinition, Context, Error, Function, Local, Object, Result, TracedHeap};
+use mozjs::jsapi::JSObject;
+use tokio::sync::{oneshot, watch};
+use tokio::time::sleep;
+use url::Url;
+
+use crate::event_loop::macrotasks::NativeMacrotask;
+use crate::globals::fetch::body::BodyMixin;
+use crate::globals::fetch::request::{RequestCache, RequestCredentials, RequestMode};
+use crate::globals::fetch::response::ResponseKind;
+use crate::globals::fetch::{main_fetch, Headers, Request, RequestInfo, GLOBAL_CLIENT};
+use crate::promise::future_to_promise;
+use crate::ContextExt;
+
+/// Time waited before reconnecting, until the server sends a `retry` field.
+const DEFAULT_RETRY: Duration = Duration::from_secs(3);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Traceable)]
+#[repr(u8)]
+pub enum ReadyState {
+	#[default]
+	Connecting = 0,
+	Open = 1,
+	Closed = 2,
+}
+
+#[derive(Default, FromValue)]
+pub struct EventSourceInit {
+	#[ion(default)]
+	with_credentials: bool,
+}
+
+#[derive(Traceable)]
+struct Listener {
+	event: String,
+	callback: Callback<(*mut JSObject,)>,
+}
+
+/// Client for server-sent events, which receives events from a `text/event-stream` response.
+///
+/// There is no `EventTarget` in the runtime, so listeners are stored by the [EventSource] itself, and are called with
+/// plain event objects.
+#[js_class]
+pub struct EventSource {
+	reflector: Reflector,
+	#[trace(no_trace)]
+	url: Url,
+	with_credentials: bool,
+	ready_state: ReadyState,
+
+	on_open: Option<Callback<(*mut JSObject,)>>,
+	on_message: Option<Callback<(*mut JSObject,)>>,
+	on_error: Option<Callback<(*mut JSObject,)>>,
+	listeners: Vec<Listener>,
+
+	#[trace(no_trace)]
+	closed: watch::Sender<bool>,
+}
+
+#[js_class]
+impl EventSource {
+	#[ion(constant)]
+	pub const CONNECTING: i32 = ReadyState::Connecting as u8 as i32;
+	#[ion(constant)]
+	pub const OPEN: i32 = ReadyState::Open as u8 as i32;
+	#[ion(constant)]
+	pub const CLOSED: i32 = ReadyState::Closed as u8 as i32;
+
+	#[ion(constructor)]
+	pub fn constructor(
+		cx: &Context, #[ion(this)] this: &Object, url: String, Opt(init): Opt<EventSourceInit>,
+	) -> Result<EventSource> {
+		let init = init.unwrap_or_default();
+		let request = Request::constructor(cx, RequestInfo::String(url), Opt(None))?;
+		let url = request.url.clone();
+
+		let (closed, mut receiver) = watch::channel(false);
+		let this = TracedHeap::new(this.handle().get());
+		let cx2 = unsafe { Context::new_unchecked(cx.as_ptr()) };
+		let connection_url = url.clone();
+		let credentials = if init.with_credentials {
+			RequestCredentials::Include
+		} else {
+			RequestCredentials::SameOrigin
+		};
+
+		future_to_promise::<_, _, Error>(cx, async move {
+			let closed = pin!(receiver.wait_for(|closed| *closed));
+			let connection = pin!(connect(&cx2, &this, connection_url, credentials));
+			// The connection is dropped once the event source is closed.
+			match select(closed, connection).await {
+				Either::Left(_) => Ok(()),
+				Either::Right((result, _)) => result,
+			}
+		});
+
+		Ok(EventSource {
+			reflector: Reflector::default(),
+			url,
+			with_credentials: init.with_credentials,
+			ready_state: ReadyState::Connecting,
+
+			on_open: None,
+			on_message: None,
+			on_error: None,
+			listeners: Vec::new(),
+
+			closed,
+		})
+	}
+
+	#[ion(get)]
+	pub fn get_url(&self) -> String {
+		self.url.to_string()
+	}
+
+	#[ion(get)]
+	pub fn get_with_credentials(&self) -> bool {
+		self.with_credentials
+	}
+
+	#[ion(get)]
+	pub fn get_ready_state(&self) -> u8 {
+		self.ready_state as u8
+	}
+
+	#[ion(get)]
+	pub fn get_onopen<'cx>(&self, cx: &'cx Context) -> Option<Function<'cx>> {
+		self.on_open.as_ref().map(|callback| callback.function(cx))
+	}
+
+	#[ion(set)]
+	pub fn set_onopen(&mut self, cx: &Context, handler: Option<Function>) {
+		self.on_open = handler.map(|handler| Callback::new(cx, &handler));
+	}
+
+	#[ion(get)]
+	pub fn get_onmessage<'cx>(&self, cx: &'cx Context) -> Option<Function<'cx>> {
+		self.on_message.as_ref().map(|callback| callback.function(cx))
+	}
+
+	#[ion(set)]
+	pub fn set_onmessage(&mut self, cx: &Context, handler: Option<Function>) {
+		self.on_message = handler.map(|handler| Callback::new(cx, &handler));
+	}
+
+	#[ion(get)]
+	pub fn get_onerror<'cx>(&self, cx: &'cx Context) -> Option<Function<'cx>> {
+		self.on_error.as_ref().map(|callback| callback.function(cx))
+	}
+
+	#[ion(set)]
+	pub fn set_onerror(&mut self, cx: &Context, handler: Option<Function>) {
+		self.on_error = handler.map(|handler| Callback::new(cx, &handler));
+	}
+
+	#[ion(name = "addEventListener")]
+	pub fn add_event_listener(&mut self, cx: &Context, event: String, callback: Option<Function>) {
+		if let Some(callback) = callback {
+			let exists = self
+				.listeners
+				.iter()
+				.any(|listener| listener.event == event && listener.callback.function(cx).get() == callback.get());
+			if !exists {
+				let callback = Callback::new(cx, &callback);
+				self.listeners.push(Listener { event, callback });
+			}
+		}
+	}
+
+	#[ion(name = "removeEventListener")]
+	pub fn remove_event_listener(&mut self, cx: &Context, event: String, callback: Option<Function>) {
+		if let Some(callback) = callback {
+			self.listeners
+				.retain(|listener| listener.event != event || listener.callback.function(cx).get() != callback.get());
+		}
+	}
+
+	pub fn close(&mut self) {
+		self.ready_state = ReadyState::Closed;
+		self.closed.send_replace(true);
+	}
+}
+
+/// Connects to the URL of the event source, and reconnects whenever the connection is lost, until the event source
+/// is closed or the server responds with an unexpected status or content type.
+async fn connect(
+	cx: &Context, this: &TracedHeap<*mut JSObject>, url: Url, credentials: RequestCredentials,
+) -> Result<()> {
+	let mut parser = Parser::default();
+	let mut retry = DEFAULT_RETRY;
+
+	loop {
+		let mut request = Request::constructor(cx, RequestInfo::String(url.to_string()), Opt(None))?;
+		request.mode = RequestMode::Cors;
+		request.credentials = credentials;
+		request.cache = RequestCache::NoStore;
+
+		let headers = Object::from(unsafe { Local::from_heap(&request.headers) });
+		let headers = &mut Headers::get_mut_private(cx, &headers)?.headers;
+		headers.insert(ACCEPT, HeaderValue::from_static("text/event-stream"));
+		if !parser.last_event_id.is_empty() {
+			if let Ok(id) = HeaderValue::from_str(&parser.last_event_id) {
+				headers.insert("last-event-id", id);
+			}
+		}
+
+		let mut response = main_fetch(cx, &mut request, GLOBAL_CLIENT.get().unwrap().clone(), 0).await;
+		if response.kind != ResponseKind::Error {
+			let essence = response.content_type(cx).map(|mime| mime.essence());
+			if response.status != Some(StatusCode::OK) || essence.as_deref() != Some("text/event-stream") {
+				// The connection fails, and is not reestablished.
+				set_ready_state(cx, this, ReadyState::Closed)?;
+				dispatch(cx, this, "error", None).await?;
+				return Ok(());
+			}
+
+			if !set_ready_state(cx, this, ReadyState::Open)? || !dispatch(cx, this, "open", None).await? {
+				return Ok(());
+			}
+
+			let mut body = response.take_body(cx)?;
+			parser.reset();
+			while let Some(Ok(frame)) = body.frame().await {
+				let Ok(data) = frame.into_data() else {
+					continue;
+				};
+				for event in parser.feed(&data) {
+					if !dispatch(cx, this, &event.kind, Some(&event)).await? {
+						return Ok(());
+					}
+				}
+				if let Some(milliseconds) = parser.retry.take() {
+					retry = Duration::from_millis(milliseconds);
+				}
+			}
+		}
+
+		if !set_ready_state(cx, this, ReadyState::Connecting)? || !dispatch(cx, this, "error", None).await? {
+			return Ok(());
+		}
+		sleep(retry).await;
+	}
+}
+
+/// Sets the ready state of the event source, unless it has been closed.
+/// Returns `false` if the event source has been closed.
+fn set_ready_state(cx: &Context, this: &TracedHeap<*mut JSObject>, state: ReadyState) -> Result<bool> {
+	let source = Object::from(this.to_local());
+	let source = EventSource::get_mut_private(cx, &source)?;
+	if source.ready_state == ReadyState::Closed {
+		return Ok(false);
+	}
+	source.ready_state = state;
+	Ok(true)
+}
+
+/// Calls the event handler and the listeners for the event, each in a macrotask, so exceptions they throw are reported
+/// by the event loop, as with timers.
+/// Returns `false` if the event source is closed after the listeners are called, or if they could not be called.
+async fn dispatch(cx: &Context, this: &TracedHeap<*mut JSObject>, kind: &str, message: Option<&Event>) -> Result<bool> {
+	let target = Object::from(this.to_local());
+	let source = EventSource::get_private(cx, &target)?;
+
+	let event = Object::new(cx);
+	event.set_as(cx, "type", kind);
+	event.set_as(cx, "target", &target);
+	if let Some(message) = message {
+		event.set_as(cx, "data", &message.data);
+		event.set_as(cx, "origin", &source.url.origin().ascii_serialization());
+		event.set_as(cx, "lastEventId", &message.last_event_id);
+	}
+
+	let handler = match kind {
+		"open" => source.on_open.as_ref(),
+		"message" => source.on_message.as_ref(),
+		"error" => source.on_error.as_ref(),
+		_ => None,
+	};
+	let listeners = source.listeners.iter().filter(|listener| listener.event == kind);
+	// Callbacks are only traced by the event source, so the functions are rooted until their macrotasks have run.
+	let callbacks: Vec<TracedHeap<*mut JSObject>> = handler
+		.into_iter()
+		.chain(listeners.map(|listener| &listener.callback))
+		.map(|callback| TracedHeap::new(callback.function(cx).to_object(cx).handle().get()))
+		.collect();
+
+	let target = Rc::new(TracedHeap::new(this.get()));
+	let event = Rc::new(TracedHeap::new(event.handle().get()));
+	let event_loop = unsafe { &mut cx.get_private().event_loop };
+	let mut called = None;
+	for callback in callbacks {
+		let (sender, receiver) = oneshot::channel();
+		let target = Rc::clone(&target);
+		let event = Rc::clone(&event);
+		let macrotask = NativeMacrotask::new(Box::new(move |cx: &Context| {
+			let callback =
+				Callback::<(*mut JSObject,)>::new(cx, &Function::from_object(cx, &callback.to_local()).unwrap());
+			let result = callback.call(cx, &Object::from(target.to_local()), (event.get(),));
+			let _ = sender.send(());
+			result
+		}));
+		if !event_loop.enqueue_native(macrotask) {
+			return Ok(false);
+		}
+		called = Some(receiver);
+	}
+
+	// Macrotasks are run in order, so the listeners have all been called once the last one has.
+	// The remaining listeners are dropped without running if the event loop ends, such as when a listener throws.
+	if let Some(called) = called {
+		if called.await.is_err() {
+			return Ok(false);
+		}
+	}
+	Ok(EventSource::get_private(cx, &Object::from(this.to_local()))?.ready_state != ReadyState::Closed)
+}
+
+struct Event {
+	kind: String,
+	data: String,
+	last_event_id: String,
+}
+
+/// Incremental parser of `text/event-stream` bodies, which can be fed chunks that split lines at any point.
+#[derive(Default)]
+struct Parser {
+	line: Vec<u8>,
+	after_cr: bool,
+	started: bool,
+	kind: String,
+	data: String,
+	id: String,
+	last_event_id: String,
+	retry: Option<u64>,
+}
+
+impl Parser {
+	/// Discards the partially received line and event, keeping the ID of the last dispatched event.
+	fn reset(&mut self) {
+		self.line.clear();
+		self.after_cr = false;
+		self.started = false;
+		self.kind.clear();
+		self.data.clear();
+		self.id.clone_from(&self.last_event_id);
+	}
+
+	/// Parses a chunk of the stream, and returns the events which were completed by it.
+	fn feed(&mut self, chunk: &[u8]) -> Vec<Event> {
+		let mut events = Vec::new();
+		for &byte in chunk {
+			if mem::take(&mut self.after_cr) && byte == b'\n' {
+				continue;
+			}
+			match byte {
+				b'\r' => {
+					self.after_cr = true;
+					self.process_line(&mut events);
+				}
+				b'\n' => self.process_line(&mut events),
+				byte => self.line.push(byte),
+			}
+		}
+		events
+	}
+
+	fn process_line(&mut self, events: &mut Vec<Event>) {
+		let line = mem::take(&mut self.line);
+		let mut line = String::from_utf8_lossy(&line).into_owned();
+		if !mem::replace(&mut self.started, true) && line.starts_with('\u{FEFF}') {
+			line.remove(0);
+		}
+
+		if line.is_empty() {
+			// The ID of an event only takes effect once it is dispatched, even if it has no data.
+			self.last_event_id.clone_from(&self.id);
+			let kind = mem::take(&mut self.kind);
+			let mut data = mem::take(&mut self.data);
+			if !data.is_empty() {
+				data.pop();
+				events.push(Event {
+					kind: if kind.is_empty() { String::from("message") } else { kind },
+					data,
+					last_event_id: self.last_event_id.clone(),
+				});
+			}
+			return;
+		}
+
+		let (field, value) = match line.split_once(':') {
+			Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+			None => (line.as_str(), ""),
+		};
+		match field {
+			"" => {}
+			"event" => self.kind = String::from(value),
+			"data" => {
+				self.data.push_str(value);
+				self.data.push('\n');
+			}
+			"id" if !value.contains('\0') => self.id = String::from(value),
+			"retry" if !value.is_empty() && value.bytes().all(|byte| byte.is_ascii_digit()) => {
+				self.retry = value.parse().ok();
+			}
+			_ => {}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::globals::fetch::event_source::Parser;
+
+	fn parse(parser: &mut Parser, chunks: &[&[u8]]) -> Vec<(String, String, String)> {
+		chunks
+			.iter()
+			.flat_map(|chunk| parser.feed(chunk))
+			.map(|event| (event.kind, event.data, event.last_event_id))
+			.collect()
+	}
+
+	fn message(data: &str, last_event_id: &str) -> (String, String, String) {
+		(String::from("message"), String::from(data), String::from(last_event_id))
+	}
+
+	#[test]
+	fn line_endings() {
+		let cases: [&[&[u8]]; 6] = [
+			&[b"data: a\n\ndata: b\n\n"],
+			&[b"data: a\r\rdata: b\r\r"],
+			&[b"data: a\r\n\r\ndata: b\r\n\r\n"],
+			&[b"data: a\r\r\ndata: b\n\r\n"],
+			&[b"data: a\r", b"\n\r", b"\ndata: b\r\n", b"\r\n"],
+			&[b"da", b"ta: a\n", b"\n", b"data: b", b"\r", b"\r"],
+		];
+		for chunks in cases {
+			let events = parse(&mut Parser::default(), chunks);
+			assert_eq!(events, [message("a", ""), message("b", "")], "{chunks:?}");
+		}
+	}
+
+	#[test]
+	fn byte_order_mark() {
+		let chunks: &[&[u8]] = &[b"\xEF\xBB", b"\xBFdata: a\n\n\xEF\xBB\xBFdata: b\n\n"];
+		let events = parse(&mut Parser::default(), chunks);
+		assert_eq!(events, [message("a", "")]);
+	}
+
+	#[test]
+	fn fields() {
+		let chunks: &[&[u8]] = &[
+			b": comment\ndata:a\ndata\ndata:  b\nunknown: c\n\n",
+			b"event: ping\ndata: d\n\nevent: ping\n\ndata: e\n\n",
+		];
+		let events = parse(&mut Parser::default(), chunks);
+		let ping = (String::from("ping"), String::from("d"), String::new());
+		assert_eq!(events, [message("a\n\n b", ""), ping, message("e", "")]);
+	}
+
+	#[test]
+	fn ids() {
+		let mut parser = Parser::default();
+		let chunks: &[&[u8]] = &[b"id: 1\ndata: a\n\nid: 2\0\ndata: b\n\nid\ndata: c\n\nid: 3\n\n"];
+		let events = parse(&mut parser, chunks);
+		assert_eq!(events, [message("a", "1"), message("b", "1"), message("c", "")]);
+		assert_eq!(parser.last_event_id, "3");
+
+		parser.feed(b"id: 4\ndata: d");
+		assert_eq!(parser.last_event_id, "3");
+		parser.reset();
+		assert!(parser.feed(b"\n\n").is_empty());
+		assert_eq!(parser.last_event_id, "3");
+
+		let events = parse(&mut parser, &[b"data: e\n\n"]);
+		assert_eq!(events, [message("e", "3")]);
+	}
+
+	#[test]
+	fn retry() {
+		let mut parser = Parser::default();
+		parser.feed(b"retry: 100\n");
+		assert_eq!(parser.retry.take(), Some(100));
+
+		let lines: [&[u8]; 4] = [b"retry: 1a\n", b"retry: -1\n", b"retry:\n", b"retry: 1 \n"];
+		for line in lines {
+			parser.feed(line);
+			assert_eq!(parser.retry, None, "{line:?}");
+		}
+	}
+}
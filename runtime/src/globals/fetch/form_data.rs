@@ -0,0 +1,253 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::str;
+
+use bytes::Bytes;
+use form_urlencoded::parse;
+use ion::class::{NativeObject, Reflector};
+use ion::conversions::ToValue;
+use ion::function::Opt;
+use ion::{Array, ClassDefinition, Context, Error, ErrorKind, JSIterator, Result, Value};
+use mozjs::jsapi::{Heap, JSObject};
+
+use crate::globals::file::{Blob, File};
+use crate::mime::MediaType;
+
+#[derive(FromValue)]
+pub enum FormDataValue<'cx> {
+	File(&'cx File),
+	Blob(&'cx Blob),
+	String(String),
+}
+
+#[derive(Traceable)]
+pub enum FormDataEntryValue {
+	String(String),
+	File(Box<Heap<*mut JSObject>>),
+}
+
+impl FormDataEntryValue {
+	fn new(cx: &Context, value: FormDataValue, filename: Option<String>) -> FormDataEntryValue {
+		let file = match (value, filename) {
+			(FormDataValue::String(string), _) => return FormDataEntryValue::String(string),
+			(FormDataValue::File(file), None) => return FormDataEntryValue::File(Heap::boxed(file.reflector().get())),
+			(FormDataValue::File(file), Some(filename)) => {
+				File::new(file.blob.bytes.clone(), file.blob.kind.clone(), filename)
+			}
+			(FormDataValue::Blob(blob), filename) => File::new(
+				blob.bytes.clone(),
+				blob.kind.clone(),
+				filename.unwrap_or_else(|| String::from("blob")),
+			),
+		};
+		FormDataEntryValue::File(Heap::boxed(File::new_object(cx, Box::new(file))))
+	}
+}
+
+impl Clone for FormDataEntryValue {
+	fn clone(&self) -> FormDataEntryValue {
+		match self {
+			FormDataEntryValue::String(string) => FormDataEntryValue::String(string.clone()),
+			FormDataEntryValue::File(file) => FormDataEntryValue::File(Heap::boxed(file.get())),
+		}
+	}
+}
+
+impl ToValue<'_> for FormDataEntryValue {
+	fn to_value(&self, cx: &Context, value: &mut Value) {
+		match self {
+			FormDataEntryValue::String(string) => string.to_value(cx, value),
+			FormDataEntryValue::File(file) => file.get().to_value(cx, value),
+		}
+	}
+}
+
+#[derive(Traceable)]
+pub struct FormDataEntry {
+	name: String,
+	value: FormDataEntryValue,
+}
+
+#[js_class]
+#[derive(Default)]
+pub struct FormData {
+	reflector: Reflector,
+	pub(crate) entries: Vec<FormDataEntry>,
+}
+
+impl FormData {
+	/// Parses a body with the given MIME type into [FormData].
+	///
+	/// Supports `multipart/form-data` and `application/x-www-form-urlencoded` bodies.
+	pub(crate) fn parse(cx: &Context, bytes: &[u8], mime: Option<&MediaType>) -> Result<FormData> {
+		let entries = match mime.map(|mime| (mime.essence(), mime.parameter("boundary"))) {
+			Some((essence, Some(boundary))) if essence == "multipart/form-data" => parse_multipart(cx, bytes, boundary)
+				.ok_or_else(|| Error::new("Invalid multipart body", ErrorKind::Type))?,
+			Some((essence, _)) if essence == "application/x-www-form-urlencoded" => parse(bytes)
+				.into_owned()
+				.map(|(name, value)| FormDataEntry {
+					name,
+					value: FormDataEntryValue::String(value),
+				})
+				.collect(),
+			_ => return Err(Error::new("Body cannot be parsed as form data", ErrorKind::Type)),
+		};
+		Ok(FormData { reflector: Reflector::default(), entries })
+	}
+}
+
+#[js_class]
+impl FormData {
+	#[ion(constructor)]
+	pub fn constructor() -> FormData {
+		FormData::default()
+	}
+
+	pub fn append(&mut self, cx: &Context, name: String, value: FormDataValue, Opt(filename): Opt<String>) {
+		let value = FormDataEntryValue::new(cx, value, filename);
+		self.entries.push(FormDataEntry { name, value });
+	}
+
+	pub fn delete(&mut self, name: String) {
+		self.entries.retain(|entry| entry.name != name);
+	}
+
+	pub fn get(&self, name: String) -> Option<FormDataEntryValue> {
+		self.entries.iter().find(|entry| entry.name == name).map(|entry| entry.value.clone())
+	}
+
+	#[ion(name = "getAll")]
+	pub fn get_all(&self, name: String) -> Vec<FormDataEntryValue> {
+		self.entries
+			.iter()
+			.filter(|entry| entry.name == name)
+			.map(|entry| entry.value.clone())
+			.collect()
+	}
+
+	pub fn has(&self, name: String) -> bool {
+		self.entries.iter().any(|entry| entry.name == name)
+	}
+
+	pub fn set(&mut self, cx: &Context, name: String, value: FormDataValue, Opt(filename): Opt<String>) {
+		let value = FormDataEntryValue::new(cx, value, filename);
+		match self.entries.iter().position(|entry| entry.name == name) {
+			Some(index) => {
+				self.entries[index].value = value;
+				let mut i = 0;
+				self.entries.retain(|entry| {
+					i += 1;
+					i <= index + 1 || entry.name != name
+				});
+			}
+			None => self.entries.push(FormDataEntry { name, value }),
+		}
+	}
+
+	#[ion(iterable)]
+	pub fn iterator(&self) -> FormDataIterator {
+		FormDataIterator::default()
+	}
+}
+
+#[derive(Default)]
+pub struct FormDataIterator(usize);
+
+impl JSIterator for FormDataIterator {
+	fn next_value<'cx>(&mut self, cx: &'cx Context, private: &Value<'cx>) -> Option<Value<'cx>> {
+		let object = private.to_object(cx);
+		let form_data = FormData::get_private(cx, &object).unwrap();
+		let entry = form_data.entries.get(self.0);
+		entry.map(move |entry| {
+			self.0 += 1;
+			let array = Array::new(cx);
+			array.set_as(cx, 0, &entry.name);
+			array.set_as(cx, 1, &entry.value);
+			array.as_value(cx)
+		})
+	}
+}
+
+fn parse_multipart(cx: &Context, body: &[u8], boundary: &str) -> Option<Vec<FormDataEntry>> {
+	let delimiter = format!("\r\n--{boundary}");
+	let mut entries = Vec::new();
+	let mut rest = body.strip_prefix(&delimiter.as_bytes()[2..])?;
+
+	loop {
+		if rest.starts_with(b"--") {
+			return Some(entries);
+		}
+		rest = rest.strip_prefix(b"\r\n")?;
+
+		let end = find(rest, b"\r\n\r\n")?;
+		let headers = str::from_utf8(&rest[..end]).ok()?;
+		rest = &rest[end + 4..];
+
+		let end = find(rest, delimiter.as_bytes())?;
+		let content = &rest[..end];
+		rest = &rest[end + delimiter.len()..];
+
+		let mut name = None;
+		let mut filename = None;
+		let mut content_type = None;
+		for header in headers.split("\r\n") {
+			let (header, value) = header.split_once(':')?;
+			let value = value.trim();
+			if header.eq_ignore_ascii_case("content-disposition") {
+				for (parameter, value) in parse_disposition(value)? {
+					match parameter.as_str() {
+						"name" => name = Some(value),
+						"filename" => filename = Some(value),
+						_ => {}
+					}
+				}
+			} else if header.eq_ignore_ascii_case("content-type") {
+				content_type = Some(value.to_ascii_lowercase());
+			}
+		}
+
+		let value = match filename {
+			Some(filename) => {
+				let content_type = content_type.unwrap_or_else(|| String::from("text/plain"));
+				let file = File::new(Bytes::copy_from_slice(content), Some(content_type), filename);
+				FormDataEntryValue::File(Heap::boxed(File::new_object(cx, Box::new(file))))
+			}
+			None => FormDataEntryValue::String(String::from_utf8_lossy(content).into_owned()),
+		};
+		entries.push(FormDataEntry { name: name?, value });
+	}
+}
+
+fn parse_disposition(value: &str) -> Option<Vec<(String, String)>> {
+	let mut parameters = Vec::new();
+	let mut rest = value.strip_prefix("form-data")?;
+
+	loop {
+		rest = rest.trim_start();
+		if rest.is_empty() {
+			return Some(parameters);
+		}
+		let (parameter, after) = rest.strip_prefix(';')?.split_once('=')?;
+		let (value, after) = match after.strip_prefix('"') {
+			Some(quoted) => {
+				let end = quoted.find('"')?;
+				(&quoted[..end], &quoted[end + 1..])
+			}
+			None => {
+				let end = after.find(';').unwrap_or(after.len());
+				(after[..end].trim_end(), &after[end..])
+			}
+		};
+		let value = value.replace("%22", "\"").replace("%0D", "\r").replace("%0A", "\n");
+		parameters.push((parameter.trim().to_ascii_lowercase(), value));
+		rest = after;
+	}
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+	haystack.windows(needle.len()).position(|window| window == needle)
+}
@@ -13,23 +13,21 @@ use std::{fmt, str, vec};
 
 use http::header::{
 	Entry, HeaderMap, HeaderName, HeaderValue, ACCEPT, ACCEPT_CHARSET, ACCEPT_ENCODING, ACCEPT_LANGUAGE,
-	ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_ALLOW_METHODS, CONNECTION, CONTENT_LANGUAGE, CONTENT_LENGTH,
-	CONTENT_TYPE, COOKIE, DATE, DNT, EXPECT, HOST, ORIGIN, RANGE, REFERER, SET_COOKIE, TE, TRAILER, TRANSFER_ENCODING,
-	UPGRADE, VIA,
+	ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_ALLOW_METHODS, ACCESS_CONTROL_EXPOSE_HEADERS, CACHE_CONTROL,
+	CONNECTION, CONTENT_LANGUAGE, CONTENT_LENGTH, CONTENT_TYPE, COOKIE, DATE, DNT, EXPECT, EXPIRES, HOST,
+	LAST_MODIFIED, ORIGIN, PRAGMA, RANGE, REFERER, SET_COOKIE, TE, TRAILER, TRANSFER_ENCODING, UPGRADE, VIA,
 };
 use ion::class::Reflector;
 use ion::conversions::{FromValue, ToValue};
 use ion::function::Opt;
 use ion::string::byte::{ByteString, VisibleAscii};
-use ion::symbol::WellKnownSymbolCode;
 use ion::{Array, ClassDefinition, Context, Error, ErrorKind, JSIterator, Object, OwnedKey, Result, Value};
-use mime::{Mime, APPLICATION, FORM_DATA, MULTIPART, PLAIN, TEXT, WWW_FORM_URLENCODED};
+
+use crate::mime::MediaType;
 
 #[derive(FromValue)]
 pub enum Header {
-	#[ion(inherit)]
 	Multiple(Vec<String>),
-	#[ion(inherit)]
 	Single(String),
 }
 
@@ -88,11 +86,8 @@ impl<'cx> FromValue<'cx> for HeadersObject {
 
 #[derive(Default, FromValue)]
 pub enum HeadersInit<'cx> {
-	#[ion(inherit)]
 	Existing(&'cx Headers),
-	#[ion(inherit)]
 	Array(Vec<HeaderEntry>),
-	#[ion(inherit)]
 	Object(HeadersObject),
 	#[default]
 	#[ion(skip)]
@@ -102,29 +97,9 @@ pub enum HeadersInit<'cx> {
 impl HeadersInit<'_> {
 	pub(crate) fn into_headers(self, mut headers: HeaderMap, kind: HeadersKind) -> Result<Headers> {
 		match self {
-			HeadersInit::Existing(existing) => {
-				headers.extend(existing.headers.iter().map(|(name, value)| (name.clone(), value.clone())));
-				Ok(Headers {
-					reflector: Reflector::default(),
-					headers,
-					kind,
-				})
-			}
+			HeadersInit::Existing(existing) => Headers::from_map(existing.headers.clone(), headers, kind),
 			HeadersInit::Array(vec) => Headers::from_array(vec, headers, kind),
-			HeadersInit::Object(object) => {
-				let mut name = None;
-				for (nm, value) in object.0 {
-					if let nm @ Some(_) = nm {
-						name = nm;
-					}
-					append_header(&mut headers, name.clone().unwrap(), value, kind)?;
-				}
-				Ok(Headers {
-					reflector: Reflector::default(),
-					headers,
-					kind,
-				})
-			}
+			HeadersInit::Object(object) => Headers::from_map(object.0, headers, kind),
 			HeadersInit::Empty => Ok(Headers {
 				reflector: Reflector::default(),
 				headers,
@@ -171,6 +146,21 @@ impl Headers {
 			kind,
 		})
 	}
+
+	pub fn from_map(map: HeaderMap, mut headers: HeaderMap, kind: HeadersKind) -> Result<Headers> {
+		let mut name = None;
+		for (nm, value) in map {
+			if let nm @ Some(_) = nm {
+				name = nm;
+			}
+			append_header(&mut headers, name.clone().unwrap(), value, kind)?;
+		}
+		Ok(Headers {
+			reflector: Reflector::default(),
+			headers,
+			kind,
+		})
+	}
 }
 
 #[js_class]
@@ -181,18 +171,13 @@ impl Headers {
 	}
 
 	pub fn append(&mut self, name: ByteString<VisibleAscii>, value: ByteString<VisibleAscii>) -> Result<()> {
-		if self.kind != HeadersKind::Immutable {
-			let name = HeaderName::from_bytes(&name)?;
-			let value = HeaderValue::from_bytes(&value)?;
-			self.headers.append(name, value);
-			Ok(())
-		} else {
-			Err(Error::new("Cannot Modify Readonly Headers", None))
-		}
+		let name = parse_header_name(&name)?;
+		let value = parse_header_value(&value)?;
+		append_header(&mut self.headers, name, value, self.kind)
 	}
 
 	pub fn delete(&mut self, name: ByteString<VisibleAscii>) -> Result<()> {
-		let name = HeaderName::from_bytes(&name)?;
+		let name = parse_header_name(&name)?;
 		if !validate_header(&name, &HeaderValue::from_static(""), self.kind)? {
 			return Ok(());
 		}
@@ -210,7 +195,7 @@ impl Headers {
 	}
 
 	pub fn get(&self, name: ByteString<VisibleAscii>) -> Result<Option<Header>> {
-		let name = HeaderName::from_bytes(&name)?;
+		let name = parse_header_name(&name)?;
 		Ok(get_header(&self.headers, &name))
 	}
 
@@ -223,18 +208,18 @@ impl Headers {
 	}
 
 	pub fn has(&self, name: ByteString<VisibleAscii>) -> Result<bool> {
-		let name = HeaderName::from_bytes(&name)?;
+		let name = parse_header_name(&name)?;
 		Ok(self.headers.contains_key(name))
 	}
 
 	pub fn set(&mut self, name: ByteString<VisibleAscii>, value: ByteString<VisibleAscii>) -> Result<()> {
-		let name = HeaderName::from_bytes(&name)?;
-		let value = HeaderValue::from_bytes(&value)?;
-		if !validate_header(&name, &HeaderValue::from_static(""), self.kind)? {
+		let name = parse_header_name(&name)?;
+		let value = parse_header_value(&value)?;
+		if !validate_header(&name, &value, self.kind)? {
 			return Ok(());
 		}
 		if self.kind == HeadersKind::RequestNoCors
-			&& !validate_no_cors_safelisted_request_header(&mut self.headers, &name, &value)
+			&& !validate_no_cors_safelisted_request_header(&mut HeaderMap::new(), &name, &value)
 		{
 			return Ok(());
 		}
@@ -243,8 +228,8 @@ impl Headers {
 		Ok(())
 	}
 
-	#[ion(name = WellKnownSymbolCode::Iterator)]
-	pub fn iterator(&self, cx: &Context) -> ion::Iterator {
+	#[ion(iterable)]
+	pub fn iterator(&self) -> HeadersIterator {
 		let cookies: Vec<_> = self.headers.get_all(&SET_COOKIE).iter().map(HeaderValue::clone).collect();
 
 		let mut keys: Vec<_> = self.headers.keys().map(|name| name.as_str().to_ascii_lowercase()).collect();
@@ -254,14 +239,10 @@ impl Headers {
 		}
 		keys.sort();
 
-		let this = self.reflector.get().as_value(cx);
-		ion::Iterator::new(
-			HeadersIterator {
-				keys: keys.into_iter(),
-				cookies: cookies.into_iter(),
-			},
-			&this,
-		)
+		HeadersIterator {
+			keys: keys.into_iter(),
+			cookies: cookies.into_iter(),
+		}
 	}
 }
 
@@ -322,30 +303,51 @@ static FORBIDDEN_REQUEST_HEADER_METHODS: [HeaderName; 3] = [X_HTTP_METHOD, X_HTT
 pub(crate) static FORBIDDEN_RESPONSE_HEADERS: [HeaderName; 2] = [SET_COOKIE, SET_COOKIE2];
 
 static NO_CORS_SAFELISTED_REQUEST_HEADERS: [HeaderName; 4] = [ACCEPT, ACCEPT_LANGUAGE, CONTENT_LANGUAGE, CONTENT_TYPE];
+static CORS_SAFELISTED_RESPONSE_HEADERS: [HeaderName; 7] = [
+	CACHE_CONTROL,
+	CONTENT_LANGUAGE,
+	CONTENT_LENGTH,
+	CONTENT_TYPE,
+	EXPIRES,
+	LAST_MODIFIED,
+	PRAGMA,
+];
+
+fn parse_header_name(name: &[u8]) -> Result<HeaderName> {
+	HeaderName::from_bytes(name).map_err(|_| Error::new("Received invalid header name", ErrorKind::Type))
+}
+
+fn parse_header_value(value: &[u8]) -> Result<HeaderValue> {
+	let value = value.trim_ascii();
+	HeaderValue::from_bytes(value).map_err(|_| Error::new("Received invalid header value", ErrorKind::Type))
+}
 
 fn validate_header(name: &HeaderName, value: &HeaderValue, kind: HeadersKind) -> Result<bool> {
-	if kind == HeadersKind::Immutable {
-		return Err(Error::new("Headers cannot be modified", ErrorKind::Type));
+	match kind {
+		HeadersKind::Immutable => Err(Error::new("Headers cannot be modified", ErrorKind::Type)),
+		HeadersKind::Request | HeadersKind::RequestNoCors => Ok(!is_forbidden_request_header(name, value)),
+		HeadersKind::Response => Ok(!FORBIDDEN_RESPONSE_HEADERS.contains(name)),
+		HeadersKind::None => Ok(true),
 	}
+}
 
+fn is_forbidden_request_header(name: &HeaderName, value: &HeaderValue) -> bool {
 	if FORBIDDEN_REQUEST_HEADERS.contains(name) {
-		return Ok(false);
+		return true;
 	}
 	if name.as_str().starts_with("proxy-") || name.as_str().starts_with("sec-") {
-		return Ok(false);
+		return true;
 	}
 	if FORBIDDEN_REQUEST_HEADER_METHODS.contains(name) {
 		let value = split_value(value);
-		if value.iter().any(|v| v == "CONNECT" || v == "TRACE" || v == "TRACK") {
-			return Ok(false);
-		}
-	}
-
-	if FORBIDDEN_RESPONSE_HEADERS.contains(name) {
-		return Ok(false);
-	}
-
-	Ok(true)
+		return value.iter().any(|method| {
+			let method = method.trim();
+			["CONNECT", "TRACE", "TRACK"]
+				.iter()
+				.any(|forbidden| method.eq_ignore_ascii_case(forbidden))
+		});
+	}
+	false
 }
 
 fn validate_no_cors_safelisted_request_header(headers: &mut HeaderMap, name: &HeaderName, value: &HeaderValue) -> bool {
@@ -385,17 +387,16 @@ fn validate_no_cors_safelisted_request_header(headers: &mut HeaderMap, name: &He
 		if unsafe_header_byte {
 			return false;
 		}
-		let mime = Mime::from_str(str);
-		match mime {
-			Ok(mime) => {
-				if !(mime.type_() == APPLICATION && mime.subtype() == WWW_FORM_URLENCODED
-					|| mime.type_() == MULTIPART && mime.subtype() == FORM_DATA
-					|| mime.type_() == TEXT && mime.subtype() == PLAIN)
-				{
+		match MediaType::parse(str) {
+			Some(mime) => {
+				if !matches!(
+					mime.essence().as_str(),
+					"application/x-www-form-urlencoded" | "multipart/form-data" | "text/plain"
+				) {
 					return false;
 				}
 			}
-			Err(_) => return false,
+			None => return false,
 		}
 	} else if name == RANGE {
 		if !str.starts_with("bytes=") {
@@ -477,6 +478,27 @@ fn append_to_headers(cx: &Context, headers: &mut HeaderMap, obj: Object) -> Resu
 	Ok(())
 }
 
+pub(crate) fn filter_cors_response_headers(headers: &mut HeaderMap, include_credentials: bool) {
+	let exposed: Vec<_> = headers.get_all(ACCESS_CONTROL_EXPOSE_HEADERS).iter().flat_map(split_value).collect();
+	let exposes_all = !include_credentials && exposed.iter().any(|name| name.trim() == "*");
+
+	let removed: Vec<_> = headers
+		.keys()
+		.filter(|name| {
+			if FORBIDDEN_RESPONSE_HEADERS.contains(name) {
+				return true;
+			}
+			let exposed =
+				exposes_all || exposed.iter().any(|exposed| exposed.trim().eq_ignore_ascii_case(name.as_str()));
+			!exposed && !CORS_SAFELISTED_RESPONSE_HEADERS.contains(name)
+		})
+		.cloned()
+		.collect();
+	for name in removed {
+		remove_all_header_entries(headers, &name);
+	}
+}
+
 pub(crate) fn remove_all_header_entries(headers: &mut HeaderMap, name: &HeaderName) {
 	match headers.entry(name) {
 		Entry::Occupied(o) => {
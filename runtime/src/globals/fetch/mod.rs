@@ -13,53 +13,74 @@ use arrayvec::ArrayVec;
 use async_recursion::async_recursion;
 use body::FetchBody;
 use bytes::Bytes;
+pub use cache_storage::{Cache, CacheStorage};
 use client::Client;
 pub use client::{default_client, GLOBAL_CLIENT};
 use const_format::concatcp;
 use data_url::DataUrl;
+pub use event_source::EventSource;
+pub use form_data::FormData;
 use futures::future::{select, Either};
 pub use header::Headers;
-use header::{remove_all_header_entries, HeadersKind, FORBIDDEN_RESPONSE_HEADERS};
+use header::{filter_cors_response_headers, remove_all_header_entries, HeadersKind, FORBIDDEN_RESPONSE_HEADERS};
 use headers::{HeaderMapExt, Range};
 use http::header::{
-	ACCEPT, ACCEPT_ENCODING, ACCEPT_LANGUAGE, ACCESS_CONTROL_ALLOW_HEADERS, CACHE_CONTROL, CONTENT_ENCODING,
-	CONTENT_LANGUAGE, CONTENT_LENGTH, CONTENT_LOCATION, CONTENT_RANGE, CONTENT_TYPE, HOST, IF_MATCH, IF_MODIFIED_SINCE,
-	IF_NONE_MATCH, IF_RANGE, IF_UNMODIFIED_SINCE, LOCATION, PRAGMA, RANGE, REFERER, REFERRER_POLICY, USER_AGENT,
+	ACCEPT, ACCEPT_ENCODING, ACCEPT_LANGUAGE, CACHE_CONTROL, CONTENT_ENCODING, CONTENT_LANGUAGE, CONTENT_LENGTH,
+	CONTENT_LOCATION, CONTENT_RANGE, CONTENT_TYPE, HOST, IF_MATCH, IF_MODIFIED_SINCE, IF_NONE_MATCH, IF_RANGE,
+	IF_UNMODIFIED_SINCE, LOCATION, PRAGMA, RANGE, REFERER, REFERRER_POLICY, USER_AGENT,
 };
 use http::{HeaderMap, HeaderValue, Method, StatusCode};
+use hyper::body::Body as _;
 use ion::class::{ClassObjectWrapper, Reflector};
 use ion::conversions::ToValue;
 use ion::flags::PropertyFlags;
 use ion::function::Opt;
-use ion::{ClassDefinition, Context, Error, ErrorKind, Exception, Local, Object, Promise, ResultExc, TracedHeap};
+use ion::{
+	ClassDefinition, Context, Error, ErrorKind, Exception, Function, Local, Object, Promise, ResultExc, TracedHeap,
+};
 use request::{Referrer, ReferrerPolicy, RequestCache, RequestCredentials, RequestMode, RequestRedirect};
 pub use request::{Request, RequestInfo, RequestInit};
 pub use response::Response;
 use response::{network_error, ResponseKind, ResponseTaint};
 use sys_locale::get_locales;
 use tokio::fs::read;
+use tokio::sync::watch;
 use uri_url::url_to_uri;
 use url::Url;
 
 use crate::globals::abort::AbortSignal;
 use crate::globals::fetch::body::Body;
+use crate::globals::fetch::progress::report_progress;
+use crate::globals::fetch::response::body::ResponseBody;
 use crate::globals::file::Blob;
 use crate::globals::url::parse_uuid_from_url_path;
+use crate::mime::MediaType;
 use crate::promise::future_to_promise;
 use crate::{ContextExt, VERSION};
 
-mod body;
+pub(crate) mod body;
+mod cache_storage;
 mod client;
+mod event_source;
+mod form_data;
 mod header;
+mod progress;
 mod request;
 mod response;
+mod serialize;
 
 const DEFAULT_USER_AGENT: &str = concatcp!("Spiderfire/", VERSION);
 
 #[js_fn]
-fn fetch<'cx>(cx: &'cx Context, resource: RequestInfo, init: Opt<RequestInit>) -> Option<Promise<'cx>> {
+fn fetch<'cx>(cx: &'cx Context, resource: RequestInfo, mut init: Opt<RequestInit>) -> Option<Promise<'cx>> {
 	let promise = Promise::new(cx);
 
+	let (upload_progress, download_progress) = match &mut init.0 {
+		Some(init) => (init.on_upload_progress.take(), init.on_download_progress.take()),
+		None => (None, None),
+	};
+	let download_progress = download_progress.map(|callback| TracedHeap::new(callback.to_object(cx).handle().get()));
+
 	let request = match Request::constructor(cx, resource, init) {
 		Ok(request) => request,
 		Err(error) => {
@@ -93,11 +114,30 @@ fn fetch<'cx>(cx: &'cx Context, resource: RequestInfo, init: Opt<RequestInit>) -
 		headers.headers.append(ACCEPT_LANGUAGE, HeaderValue::from_str(&locale_string).unwrap());
 	}
 
+	if let Some(callback) = upload_progress {
+		let total = request.body.len().map(|len| len as u64);
+		let sender = report_progress(cx, &callback, total);
+		request.upload_progress.set(Some(sender));
+	}
+
 	let request = TracedHeap::new(Request::new_object(cx, Box::new(request)));
 	let cx2 = unsafe { Context::new_unchecked(cx.as_ptr()) };
-	future_to_promise(cx, async move {
+	future_to_promise::<_, _, Exception>(cx, async move {
 		let request = Object::from(request.to_local());
-		fetch_internal(&cx2, &request, GLOBAL_CLIENT.get().unwrap().clone()).await
+		let response = fetch_internal(&cx2, &request, GLOBAL_CLIENT.get().unwrap().clone()).await;
+		// Upload progress is no longer reported once the request has completed, even if the body was never sent.
+		Request::get_private(&cx2, &request)?.upload_progress.take();
+
+		let mut response = response?;
+		if let Some(callback) = download_progress {
+			if let Some(ResponseBody::Hyper(body)) = response.0.body.take() {
+				let total = body.size_hint().exact();
+				let callback = Function::from_object(&cx2, &callback.to_local()).unwrap();
+				let sender = report_progress(&cx2, &callback, total);
+				response.0.body = Some(ResponseBody::Hyper(Body::Progress(Box::new(body), sender)));
+			}
+		}
+		Ok(response)
 	})
 }
 
@@ -232,6 +272,7 @@ async fn main_fetch(cx: &Context, request: &mut Request, client: Client, redirec
 				if request.redirect != RequestRedirect::Follow {
 					return network_error();
 				}
+				taint = ResponseTaint::Opaque;
 			} else {
 				taint = ResponseTaint::Cors;
 			}
@@ -243,9 +284,7 @@ async fn main_fetch(cx: &Context, request: &mut Request, client: Client, redirec
 		}
 	};
 
-	let redirected = redirections > 0;
-	if redirected || response.kind == ResponseKind::Error {
-		response.redirected = redirected;
+	if redirections > 0 || response.kind == ResponseKind::Error {
 		return response;
 	}
 
@@ -285,6 +324,7 @@ async fn main_fetch(cx: &Context, request: &mut Request, client: Client, redirec
 		response.status = None;
 		response.status_text = None;
 		response.body = None;
+		response.trailers = None;
 
 		headers.headers.clear();
 	} else {
@@ -298,40 +338,7 @@ async fn main_fetch(cx: &Context, request: &mut Request, client: Client, redirec
 			}
 			ResponseTaint::Cors => {
 				response.kind = ResponseKind::Cors;
-
-				let mut allows_all = false;
-				let allowed: Vec<_> = headers
-					.headers
-					.get_all(ACCESS_CONTROL_ALLOW_HEADERS)
-					.into_iter()
-					.map(|v| {
-						if v == "*" {
-							allows_all = true
-						}
-						v.clone()
-					})
-					.collect();
-				let mut to_remove = Vec::new();
-				if request.credentials != RequestCredentials::Include && allows_all {
-					for name in headers.headers.keys() {
-						if headers.headers.get_all(name).into_iter().size_hint().1.is_none() {
-							to_remove.push(name.clone());
-						}
-					}
-				} else {
-					for name in headers.headers.keys() {
-						let allowed = allowed.iter().any(|allowed| allowed.as_bytes() == name.as_str().as_bytes());
-						if allowed {
-							to_remove.push(name.clone());
-						}
-					}
-				}
-				for name in to_remove {
-					remove_all_header_entries(&mut headers.headers, &name);
-				}
-				for name in &FORBIDDEN_RESPONSE_HEADERS {
-					remove_all_header_entries(&mut headers.headers, name);
-				}
+				filter_cors_response_headers(&mut headers.headers, request.credentials == RequestCredentials::Include);
 			}
 			ResponseTaint::Opaque => {
 				response.kind = ResponseKind::Opaque;
@@ -339,11 +346,13 @@ async fn main_fetch(cx: &Context, request: &mut Request, client: Client, redirec
 				response.status = None;
 				response.status_text = None;
 				response.body = None;
+				response.trailers = None;
 
 				headers.headers.clear();
 			}
 		}
 	}
+	response.redirected = response.url.is_some() && request.locations.len() > 1;
 
 	response
 }
@@ -446,8 +455,7 @@ async fn scheme_fetch(cx: &Context, scheme: &str, request: &Request, url: Url) -
 				Ok(decoded) => decoded,
 				Err(_) => return network_error(),
 			};
-			let mime = data_url.mime_type();
-			let mime = format!("{}/{}", mime.type_, mime.subtype);
+			let mime = data_url.mime_type().to_string();
 
 			let response = Response::new_from_bytes(Bytes::from(body), url);
 			let headers = Headers {
@@ -464,10 +472,16 @@ async fn scheme_fetch(cx: &Context, scheme: &str, request: &Request, url: Url) -
 			}
 
 			match url.to_file_path() {
-				Ok(path) => match read(path).await {
+				Ok(path) => match read(&path).await {
 					Ok(bytes) => {
+						let mut headers = Headers::new(HeadersKind::Immutable);
+						// The MIME type of a file is determined from its extension, as the bytes are not sniffed.
+						let mime = path.extension().and_then(|ext| MediaType::from_extension(&ext.to_string_lossy()));
+						if let Some(mime) = mime {
+							headers.headers.insert(CONTENT_TYPE, HeaderValue::from_str(&mime.to_string()).unwrap());
+						}
+
 						let response = Response::new_from_bytes(Bytes::from(bytes), url);
-						let headers = Headers::new(HeadersKind::Immutable);
 						response.headers.set(Headers::new_object(cx, Box::new(headers)));
 						response
 					}
@@ -571,12 +585,18 @@ async fn http_network_fetch(cx: &Context, request: &Request, client: Client, is_
 	let uri = url_to_uri(&request.url).unwrap();
 	let mut builder = hyper::Request::builder().method(request.method.clone()).uri(uri);
 	*builder.headers_mut().unwrap() = headers;
-	let req = builder.body(request.body.to_http_body()).unwrap();
+	let mut body = request.body.to_http_body();
+	if let Some(progress) = request.upload_progress.take() {
+		body = Body::Progress(Box::new(body), progress);
+	}
+	let req = builder.body(body).unwrap();
 
 	let mut response = match client.request(req).await {
 		Ok(response) => {
-			let response = response.map(Body::Incoming);
-			let (headers, response) = Response::from_hyper(response, request.url.clone());
+			let (trailers, receiver) = watch::channel(None);
+			let response = response.map(|body| Body::Incoming(body, Some(trailers)));
+			let (headers, mut response) = Response::from_hyper(response, request.url.clone());
+			response.trailers = Some(receiver);
 
 			let headers = Headers {
 				reflector: Reflector::default(),
@@ -675,5 +695,10 @@ async fn http_redirect_fetch(
 pub fn define(cx: &Context, global: &Object) -> bool {
 	let _ = GLOBAL_CLIENT.set(default_client());
 	global.define_method(cx, "fetch", fetch, 1, PropertyFlags::CONSTANT_ENUMERATED);
-	Headers::init_class(cx, global).0 && Request::init_class(cx, global).0 && Response::init_class(cx, global).0
+	Headers::init_class(cx, global).0
+		&& Request::init_class(cx, global).0
+		&& Response::init_class(cx, global).0
+		&& FormData::init_class(cx, global).0
+		&& EventSource::init_class(cx, global).0
+		&& cache_storage::define(cx, global)
 }
@@ -0,0 +1,67 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::rc::Rc;
+
+use ion::conversions::ToValue;
+use ion::{Context, Function, Object, TracedHeap, Value};
+use tokio::sync::{oneshot, watch};
+use tokio::task::spawn_local;
+
+use crate::event_loop::macrotasks::NativeMacrotask;
+use crate::ContextExt;
+
+/// Progress of the transfer of a body, which is passed to the `onUploadProgress` and `onDownloadProgress` callbacks of
+/// `fetch` as `{ loaded, total }`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Progress {
+	/// Number of bytes of the body transferred so far.
+	pub loaded: u64,
+	/// Length of the body, if it is known, which is `null` otherwise.
+	pub total: Option<u64>,
+}
+
+impl ToValue<'_> for Progress {
+	fn to_value(&self, cx: &Context, value: &mut Value) {
+		let object = Object::new(cx);
+		object.set_as(cx, "loaded", &self.loaded);
+		object.set_as(cx, "total", &self.total);
+		object.to_value(cx, value);
+	}
+}
+
+/// Calls the callback with the [Progress] sent through the returned sender, in a macrotask.
+///
+/// Updates which are sent before the callback runs are coalesced, so the callback only receives the latest progress.
+/// Reporting stops once the sender is dropped, which happens when the body has been transferred or discarded.
+/// Waiting for progress does not keep the event loop alive, and exceptions thrown by the callback are reported by the
+/// event loop, as with timers.
+pub(crate) fn report_progress(cx: &Context, callback: &Function, total: Option<u64>) -> watch::Sender<Progress> {
+	let (sender, mut receiver) = watch::channel(Progress { loaded: 0, total });
+	// The callback is rooted until reporting stops.
+	let callback = Rc::new(TracedHeap::new(callback.to_object(cx).handle().get()));
+	let cx = unsafe { Context::new_unchecked(cx.as_ptr()) };
+	spawn_local(async move {
+		while receiver.changed().await.is_ok() {
+			let progress = *receiver.borrow_and_update();
+			let (reported, done) = oneshot::channel();
+			let callback = Rc::clone(&callback);
+			let macrotask = NativeMacrotask::new(Box::new(move |cx: &Context| {
+				let _ = reported.send(());
+				let progress = progress.as_value(cx);
+				let callback = Function::from_object(cx, &callback.to_local()).unwrap();
+				callback.call(cx, &Object::global(cx), &[progress]).map(|_| ())
+			}));
+
+			let event_loop = unsafe { &mut cx.get_private().event_loop };
+			// Progress is not reported if the macrotask is dropped without running, such as when the event loop ends.
+			if !event_loop.enqueue_native(macrotask) || done.await.is_err() {
+				break;
+			}
+		}
+	});
+	sender
+}
@@ -4,28 +4,34 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
+use std::cell::Cell;
 use std::str::FromStr;
 
+use bytes::Bytes;
 use http::{HeaderMap, Method};
 use ion::class::Reflector;
 use ion::function::Opt;
-use ion::{ClassDefinition, Context, Error, ErrorKind, Result};
+use ion::typedarray::ArrayBufferWrapper;
+use ion::{ClassDefinition, Context, Error, ErrorKind, Local, Object, Promise, Result};
 use mozjs::jsapi::{Heap, JSObject};
 pub use options::*;
+use tokio::sync::watch;
 use url::Url;
 
 use crate::globals::abort::AbortSignal;
-use crate::globals::fetch::body::FetchBody;
+use crate::globals::fetch::body::{Body, BodyMixin, FetchBody};
 use crate::globals::fetch::header::HeadersKind;
+use crate::globals::fetch::progress::Progress;
+use crate::globals::fetch::serialize::{Reader, Writer, REQUEST_MAGIC};
 use crate::globals::fetch::Headers;
+use crate::globals::file::BufferSource;
+use crate::promise::future_to_promise;
 
 mod options;
 
 #[derive(FromValue)]
 pub enum RequestInfo<'cx> {
-	#[ion(inherit)]
 	Request(&'cx Request),
-	#[ion(inherit)]
 	String(String),
 }
 
@@ -45,20 +51,31 @@ pub struct Request {
 	pub(crate) locations: Vec<Url>,
 
 	pub(crate) referrer: Referrer,
+	#[ion(accessor, readonly)]
 	pub(crate) referrer_policy: ReferrerPolicy,
 
+	#[ion(accessor, readonly)]
 	pub(crate) mode: RequestMode,
+	#[ion(accessor, readonly)]
 	pub(crate) credentials: RequestCredentials,
+	#[ion(accessor, readonly)]
 	pub(crate) cache: RequestCache,
+	#[ion(accessor, readonly)]
 	pub(crate) redirect: RequestRedirect,
 
+	#[ion(accessor, readonly)]
 	pub(crate) integrity: String,
 
 	pub(crate) unsafe_request: bool,
+	#[ion(accessor, readonly)]
 	pub(crate) keepalive: bool,
 
 	pub(crate) client_window: bool,
 	pub(crate) signal_object: Box<Heap<*mut JSObject>>,
+
+	/// Sender for the upload progress of `fetch`, which is taken when the body is sent.
+	#[trace(no_trace)]
+	pub(crate) upload_progress: Cell<Option<watch::Sender<Progress>>>,
 }
 
 #[js_class]
@@ -66,9 +83,16 @@ impl Request {
 	#[ion(constructor)]
 	pub fn constructor(cx: &Context, info: RequestInfo, Opt(init): Opt<RequestInit>) -> Result<Request> {
 		let mut fallback_cors = false;
+		let mut input_headers = None;
+		let mut input_used = false;
 
 		let mut request = match info {
-			RequestInfo::Request(request) => request.clone(),
+			RequestInfo::Request(request) => {
+				let headers = Object::from(unsafe { Local::from_heap(&request.headers) });
+				input_headers = Some(Headers::get_private(cx, &headers)?.headers.clone());
+				input_used = request.body_used;
+				request.clone()
+			}
 			RequestInfo::String(url) => {
 				let url = Url::from_str(&url)?;
 				if url.username() != "" || url.password().is_some() {
@@ -103,6 +127,7 @@ impl Request {
 
 					client_window: true,
 					signal_object: Heap::boxed(AbortSignal::new_object(cx, Box::default())),
+					upload_progress: Cell::default(),
 				}
 			}
 		};
@@ -158,13 +183,8 @@ impl Request {
 				request.signal_object.set(signal_object);
 			}
 
-			if let Some(mut method) = init.method {
-				method.make_ascii_uppercase();
-				let method = Method::from_str(&method)?;
-				if method == Method::CONNECT || method == Method::TRACE {
-					return Err(Error::new("Received invalid request method", ErrorKind::Type));
-				}
-				request.method = method;
+			if let Some(method) = init.method {
+				request.method = normalise_method(&method)?;
 			}
 
 			headers = init.headers;
@@ -191,16 +211,23 @@ impl Request {
 		let mut headers = if let Some(headers) = headers {
 			headers.into_headers(HeaderMap::new(), kind)?
 		} else {
-			Headers {
-				reflector: Reflector::default(),
-				headers: HeaderMap::new(),
-				kind,
-			}
+			Headers::from_map(input_headers.unwrap_or_default(), HeaderMap::new(), kind)?
 		};
 
+		let has_body = body.as_ref().is_some_and(|body| !body.is_none()) || !request.body.is_none();
+		if has_body && matches!(request.method, Method::GET | Method::HEAD) {
+			return Err(Error::new(
+				"Request with GET or HEAD method cannot have a body",
+				ErrorKind::Type,
+			));
+		}
+
 		if let Some(body) = body {
 			body.add_content_type_header(&mut headers.headers);
 			request.body = body;
+			request.body_used = false;
+		} else if input_used {
+			return Err(Error::new("Request body has already been used.", ErrorKind::Type));
 		}
 		request.headers.set(Headers::new_object(cx, Box::new(headers)));
 
@@ -233,58 +260,186 @@ impl Request {
 	}
 
 	#[ion(get)]
-	pub fn get_referrer_policy(&self) -> String {
-		self.referrer.to_string()
+	pub fn get_is_reload_navigation(&self) -> bool {
+		false
 	}
 
 	#[ion(get)]
-	pub fn get_mode(&self) -> String {
-		self.mode.to_string()
+	pub fn get_is_history_navigation(&self) -> bool {
+		false
 	}
 
 	#[ion(get)]
-	pub fn get_credentials(&self) -> String {
-		self.credentials.to_string()
+	pub fn get_signal(&self) -> *mut JSObject {
+		self.signal_object.get()
 	}
 
 	#[ion(get)]
-	pub fn get_cache(&self) -> String {
-		self.cache.to_string()
+	pub fn get_duplex(&self) -> String {
+		String::from("half")
 	}
 
 	#[ion(get)]
-	pub fn get_redirect(&self) -> String {
-		self.redirect.to_string()
+	pub fn get_body_used(&self) -> bool {
+		self.body_used
 	}
 
-	#[ion(get)]
-	pub fn get_integrity(&self) -> String {
-		self.integrity.clone()
+	#[ion(name = "arrayBuffer")]
+	pub fn array_buffer<'cx>(&mut self, cx: &'cx Context) -> Option<Promise<'cx>> {
+		self.read_array_buffer(cx)
 	}
 
-	#[ion(get)]
-	pub fn get_keepalive(&self) -> bool {
-		self.keepalive
+	#[ion(name = "formData")]
+	pub fn form_data<'cx>(&mut self, cx: &'cx Context) -> Option<Promise<'cx>> {
+		self.read_form_data(cx)
 	}
 
-	#[ion(get)]
-	pub fn get_is_reload_navigation(&self) -> bool {
-		false
+	pub fn text<'cx>(&mut self, cx: &'cx Context) -> Option<Promise<'cx>> {
+		self.read_text(cx)
 	}
 
-	#[ion(get)]
-	pub fn get_is_history_navigation(&self) -> bool {
-		false
+	pub fn json<'cx>(&mut self, cx: &'cx Context) -> Option<Promise<'cx>> {
+		self.read_json(cx)
 	}
 
-	#[ion(get)]
-	pub fn get_signal(&self) -> *mut JSObject {
-		self.signal_object.get()
+	pub fn serialize<'cx>(&self, cx: &'cx Context) -> Option<Promise<'cx>> {
+		let bytes = self.to_bytes(cx);
+		future_to_promise::<_, _, Error>(cx, async move { Ok(ArrayBufferWrapper::from(bytes?)) })
 	}
 
-	#[ion(get)]
-	pub fn get_duplex(&self) -> String {
-		String::from("half")
+	#[ion(static)]
+	pub fn deserialize(cx: &Context, #[ion(convert = false)] data: BufferSource) -> Result<*mut JSObject> {
+		let request = Request::from_bytes(cx, &data.to_vec())?;
+		Ok(Request::new_object(cx, Box::new(request)))
+	}
+}
+
+impl Request {
+	/// Reads a [Request] from the binary form written by [Request::to_bytes].
+	pub(crate) fn from_bytes(cx: &Context, bytes: &[u8]) -> Result<Request> {
+		let mut reader = Reader::new(bytes, REQUEST_MAGIC)?;
+
+		let method = reader.read_parsed()?;
+		let url: Url = reader.read_parsed()?;
+		let headers = reader.read_headers()?;
+		let body = reader.read_body()?;
+
+		let referrer = reader.read_parsed()?;
+		let referrer_policy = reader.read_parsed()?;
+		let mode = reader.read_parsed()?;
+		let credentials = reader.read_parsed()?;
+		let cache = reader.read_parsed()?;
+		let redirect = reader.read_parsed()?;
+		let integrity = reader.read_string()?;
+		let keepalive = reader.read_bool()?;
+		reader.finish()?;
+
+		let request = Request {
+			reflector: Reflector::default(),
+
+			headers: Box::default(),
+			body: body.map(FetchBody::from).unwrap_or_default(),
+			body_used: false,
+
+			method,
+			url: url.clone(),
+			locations: vec![url],
+
+			referrer,
+			referrer_policy,
+
+			mode,
+			credentials,
+			cache,
+			redirect,
+
+			integrity,
+
+			unsafe_request: false,
+			keepalive,
+
+			client_window: true,
+			signal_object: Heap::boxed(AbortSignal::new_object(cx, Box::default())),
+			upload_progress: Cell::default(),
+		};
+
+		let kind = if request.mode == RequestMode::NoCors {
+			HeadersKind::RequestNoCors
+		} else {
+			HeadersKind::Request
+		};
+		let headers = Headers {
+			reflector: Reflector::default(),
+			headers,
+			kind,
+		};
+		request.headers.set(Headers::new_object(cx, Box::new(headers)));
+
+		Ok(request)
+	}
+
+	pub(crate) fn to_bytes(&self, cx: &Context) -> Result<Bytes> {
+		if self.body_used {
+			return Err(Error::new("Request body has already been used.", ErrorKind::Type));
+		}
+		let headers = Object::from(unsafe { Local::from_heap(&self.headers) });
+		let headers = Headers::get_private(cx, &headers)?;
+
+		let mut writer = Writer::new(REQUEST_MAGIC);
+		writer.write_str(self.method.as_str());
+		writer.write_str(self.url.as_str());
+		writer.write_headers(&headers.headers);
+		writer.write_body(self.body.bytes().map(|bytes| &bytes[..]));
+
+		let referrer = match &self.referrer {
+			Referrer::NoReferrer => String::new(),
+			referrer => referrer.to_string(),
+		};
+		writer.write_str(&referrer);
+		writer.write_str(&self.referrer_policy.to_string());
+		writer.write_str(&self.mode.to_string());
+		writer.write_str(&self.credentials.to_string());
+		writer.write_str(&self.cache.to_string());
+		writer.write_str(&self.redirect.to_string());
+		writer.write_str(&self.integrity);
+		writer.write_bool(self.keepalive);
+		Ok(writer.finish())
+	}
+}
+
+impl BodyMixin for Request {
+	fn headers_object(&self) -> &Heap<*mut JSObject> {
+		&self.headers
+	}
+
+	fn take_body(&mut self, _: &Context) -> Result<Body> {
+		if self.body_used {
+			return Err(Error::new("Request body has already been used.", ErrorKind::Type));
+		}
+		self.body_used = !self.body.is_none();
+		Ok(self.body.to_http_body())
+	}
+}
+
+fn normalise_method(method: &str) -> Result<Method> {
+	const FORBIDDEN: [&str; 3] = ["CONNECT", "TRACE", "TRACK"];
+	const NORMALISED: [Method; 6] = [
+		Method::DELETE,
+		Method::GET,
+		Method::HEAD,
+		Method::OPTIONS,
+		Method::POST,
+		Method::PUT,
+	];
+
+	if FORBIDDEN.iter().any(|forbidden| method.eq_ignore_ascii_case(forbidden)) {
+		return Err(Error::new("Received forbidden request method", ErrorKind::Type));
+	}
+	let normalised = NORMALISED.into_iter().find(|normalised| method.eq_ignore_ascii_case(normalised.as_str()));
+	match normalised {
+		Some(normalised) => Ok(normalised),
+		None => Method::from_bytes(method.as_bytes())
+			.map_err(|_| Error::new("Received invalid request method", ErrorKind::Type)),
 	}
 }
 
@@ -318,6 +473,7 @@ impl Clone for Request {
 
 			client_window: self.client_window,
 			signal_object: Heap::boxed(self.signal_object.get()),
+			upload_progress: Cell::default(),
 		}
 	}
 }
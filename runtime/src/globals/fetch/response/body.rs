@@ -4,24 +4,53 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
-use http_body_util::BodyExt;
-use ion::Result;
+use std::mem;
+
+use ion::{ClassDefinition, Context, Local, Object, Result};
+use mozjs::jsapi::{Heap, JSObject};
 
 use crate::globals::fetch::body::{Body, FetchBody};
+use crate::globals::streams::readable::ReadableStream;
 
 #[derive(Traceable)]
 pub enum ResponseBody {
 	Fetch(FetchBody),
 	Hyper(#[trace(no_trace)] Body),
+	Stream(Box<Heap<*mut JSObject>>),
 }
 
 impl ResponseBody {
-	pub async fn read_to_bytes(self) -> Result<Vec<u8>> {
-		let body = match self {
-			ResponseBody::Fetch(body) => body.to_http_body(),
-			ResponseBody::Hyper(body) => body,
-		};
+	/// Converts the body into a [ReadableStream], which reads the body as it is received.
+	pub fn into_stream(self, cx: &Context) -> Box<Heap<*mut JSObject>> {
+		match self {
+			ResponseBody::Fetch(body) => Heap::boxed(ReadableStream::from_body(cx, body.to_http_body())),
+			ResponseBody::Hyper(body) => Heap::boxed(ReadableStream::from_body(cx, body)),
+			ResponseBody::Stream(stream) => stream,
+		}
+	}
+
+	/// Checks if the body has been read from, through its stream or otherwise.
+	pub fn is_disturbed(&self, cx: &Context) -> bool {
+		match self {
+			ResponseBody::Stream(stream) => {
+				let stream = Object::from(unsafe { Local::from_heap(stream) });
+				ReadableStream::get_private(cx, &stream).is_ok_and(|stream| stream.disturbed)
+			}
+			_ => false,
+		}
+	}
 
-		Ok(body.collect().await?.to_bytes().to_vec())
+	/// Takes the unread body, so that it can be read without a [ReadableStream].
+	///
+	/// Returns [None] if the body is a stream that is locked or has been read from.
+	pub fn take_http_body(&mut self, cx: &Context) -> Result<Option<Body>> {
+		match self {
+			ResponseBody::Fetch(body) => Ok(Some(body.to_http_body())),
+			ResponseBody::Hyper(body) => Ok(Some(mem::take(body))),
+			ResponseBody::Stream(stream) => {
+				let stream = Object::from(unsafe { Local::from_heap(stream) });
+				ReadableStream::get_mut_private(cx, &stream)?.take_body(cx)
+			}
+		}
 	}
 }
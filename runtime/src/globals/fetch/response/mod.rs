@@ -8,20 +8,27 @@ use bytes::Bytes;
 use http::{HeaderMap, StatusCode};
 use hyper::ext::ReasonPhrase;
 use ion::class::{NativeObject, Reflector};
+use ion::conversions::ToValue;
 use ion::function::Opt;
 use ion::typedarray::ArrayBufferWrapper;
-use ion::{ClassDefinition, Context, Error, ErrorKind, Object, Promise, Result, TracedHeap};
+use ion::{ClassDefinition, Context, Error, ErrorKind, Local, Object, Promise, Result, TracedHeap};
 use mozjs::jsapi::{Heap, JSObject};
 pub use options::*;
-use url::Url;
+use tokio::sync::watch;
+use tokio::task::spawn_local;
+use url::{Position, Url};
 
-use crate::globals::fetch::body::{Body, FetchBody};
+use crate::event_loop::macrotasks::NativeMacrotask;
+use crate::globals::fetch::body::{try_read_to_bytes, Body, BodyMixin, FetchBody};
 use crate::globals::fetch::header::HeadersKind;
 use crate::globals::fetch::response::body::ResponseBody;
+use crate::globals::fetch::serialize::{Reader, Writer, RESPONSE_MAGIC};
 use crate::globals::fetch::Headers;
+use crate::globals::file::BufferSource;
 use crate::promise::future_to_promise;
+use crate::ContextExt;
 
-mod body;
+pub(crate) mod body;
 mod options;
 
 #[js_class]
@@ -30,6 +37,7 @@ pub struct Response {
 
 	pub(crate) headers: Box<Heap<*mut JSObject>>,
 	pub(crate) body: Option<ResponseBody>,
+	pub(crate) body_used: bool,
 
 	pub(crate) kind: ResponseKind,
 	#[trace(no_trace)]
@@ -41,6 +49,9 @@ pub struct Response {
 	pub(crate) status_text: Option<String>,
 
 	pub(crate) range_requested: bool,
+	#[trace(no_trace)]
+	pub(crate) trailers: Option<watch::Receiver<Option<HeaderMap>>>,
+	trailer: Box<Heap<*mut JSObject>>,
 }
 
 impl Response {
@@ -58,6 +69,7 @@ impl Response {
 
 			headers: Box::default(),
 			body: Some(ResponseBody::Hyper(body)),
+			body_used: false,
 
 			kind: ResponseKind::default(),
 			url: Some(url),
@@ -67,6 +79,8 @@ impl Response {
 			status_text,
 
 			range_requested: false,
+			trailers: None,
+			trailer: Box::default(),
 		};
 
 		(parts.headers, response)
@@ -78,6 +92,7 @@ impl Response {
 
 			headers: Box::default(),
 			body: Some(ResponseBody::Hyper(Body::from(bytes))),
+			body_used: false,
 
 			kind: ResponseKind::Basic,
 			url: Some(url),
@@ -87,6 +102,8 @@ impl Response {
 			status_text: Some(String::from("OK")),
 
 			range_requested: false,
+			trailers: None,
+			trailer: Box::default(),
 		}
 	}
 }
@@ -102,6 +119,7 @@ impl Response {
 
 			headers: Box::default(),
 			body: Some(ResponseBody::Hyper(Body::Empty)),
+			body_used: false,
 
 			kind: ResponseKind::default(),
 			url: None,
@@ -111,6 +129,8 @@ impl Response {
 			status_text: init.status_text,
 
 			range_requested: false,
+			trailers: None,
+			trailer: Box::default(),
 		};
 
 		let mut headers = init.headers.into_headers(HeaderMap::new(), HeadersKind::Response)?;
@@ -142,7 +162,10 @@ impl Response {
 
 	#[ion(get)]
 	pub fn get_url(&self) -> String {
-		self.url.as_ref().map(Url::to_string).unwrap_or_default()
+		self.url
+			.as_ref()
+			.map(|url| String::from(&url[..Position::AfterQuery]))
+			.unwrap_or_default()
 	}
 
 	#[ion(get)]
@@ -171,39 +194,217 @@ impl Response {
 	}
 
 	#[ion(get)]
-	pub fn get_body_used(&self) -> bool {
-		self.body.is_none()
+	pub fn get_body(&mut self, cx: &Context) -> Option<*mut JSObject> {
+		let stream = self.body.take()?.into_stream(cx);
+		let object = stream.get();
+		self.body = Some(ResponseBody::Stream(stream));
+		Some(object)
+	}
+
+	#[ion(get)]
+	pub fn get_body_used(&self, cx: &Context) -> bool {
+		self.body_used || self.body.as_ref().is_some_and(|body| body.is_disturbed(cx))
 	}
 
-	async fn read_to_bytes(&mut self) -> Result<Vec<u8>> {
-		if self.body.is_none() {
-			return Err(Error::new("Response body has already been used.", None));
+	/// Returns a promise which resolves with the trailers once the body has been read to the end.
+	/// The promise is created once, and waiting for the trailers does not keep the event loop alive.
+	#[ion(get)]
+	pub fn get_trailer<'cx>(&mut self, cx: &'cx Context) -> Promise<'cx> {
+		if !self.trailer.get().is_null() {
+			return Promise::from(cx.root(self.trailer.get())).unwrap();
 		}
-		self.body.take().unwrap().read_to_bytes().await
+
+		let promise = Promise::new(cx);
+		self.trailer.set(promise.get());
+
+		let Some(mut trailers) = self.trailers.clone() else {
+			promise.resolve(cx, &trailer_headers(cx, HeaderMap::new()).as_value(cx));
+			return promise;
+		};
+
+		// The promise is rooted until the trailers are received or the body is dropped.
+		let root = TracedHeap::new(promise.get());
+		let cx = unsafe { Context::new_unchecked(cx.as_ptr()) };
+		spawn_local(async move {
+			let trailers = trailers
+				.wait_for(Option::is_some)
+				.await
+				.map(|trailers| trailers.clone().unwrap_or_default());
+			let macrotask = NativeMacrotask::new(Box::new(move |cx: &Context| {
+				let promise = Promise::from(root.to_local()).unwrap();
+				match trailers {
+					Ok(trailers) => promise.resolve(cx, &trailer_headers(cx, trailers).as_value(cx)),
+					Err(_) => {
+						promise.reject_with_error(cx, &Error::new("Response body was not fully read.", ErrorKind::Type))
+					}
+				};
+				Ok(())
+			}));
+			let event_loop = unsafe { &mut cx.get_private().event_loop };
+			event_loop.enqueue_native(macrotask);
+		});
+		promise
 	}
 
 	#[ion(name = "arrayBuffer")]
 	pub fn array_buffer<'cx>(&mut self, cx: &'cx Context) -> Option<Promise<'cx>> {
-		let this = TracedHeap::new(self.reflector().get());
-		let cx2 = unsafe { Context::new_unchecked(cx.as_ptr()) };
-		future_to_promise::<_, _, Error>(cx, async move {
-			let response = Object::from(this.to_local());
-			let response = Response::get_mut_private(&cx2, &response)?;
-			let bytes = response.read_to_bytes().await?;
-			Ok(ArrayBufferWrapper::from(bytes))
-		})
+		self.read_array_buffer(cx)
+	}
+
+	#[ion(name = "formData")]
+	pub fn form_data<'cx>(&mut self, cx: &'cx Context) -> Option<Promise<'cx>> {
+		self.read_form_data(cx)
 	}
 
 	pub fn text<'cx>(&mut self, cx: &'cx Context) -> Option<Promise<'cx>> {
+		self.read_text(cx)
+	}
+
+	pub fn json<'cx>(&mut self, cx: &'cx Context) -> Option<Promise<'cx>> {
+		self.read_json(cx)
+	}
+
+	pub fn serialize<'cx>(&mut self, cx: &'cx Context) -> Option<Promise<'cx>> {
+		let head = if self.get_body_used(cx) {
+			Err(Error::new("Response body has already been used.", ErrorKind::Type))
+		} else {
+			self.write_head(cx)
+		};
+		let body = match head {
+			Ok(_) if self.body.is_some() => Some(self.take_body(cx)),
+			_ => None,
+		};
+
 		let this = TracedHeap::new(self.reflector().get());
-		let cx2 = unsafe { Context::new_unchecked(cx.as_ptr()) };
+		let cx = unsafe { Context::new_unchecked(cx.as_ptr()) };
 		future_to_promise::<_, _, Error>(cx, async move {
+			let mut writer = head?;
+			let Some(body) = body else {
+				writer.write_body(None);
+				return Ok(ArrayBufferWrapper::from(writer.finish()));
+			};
+
+			// The body is restored so that serialising does not consume the response, even if reading it fails.
+			let (bytes, restored) = match try_read_to_bytes(body?).await {
+				Ok(bytes) => (Ok(bytes.clone()), Body::from(bytes)),
+				Err((error, body)) => (Err(error), body),
+			};
 			let response = Object::from(this.to_local());
 			let response = Response::get_mut_private(&cx2, &response)?;
-			let bytes = response.read_to_bytes().await?;
-			String::from_utf8(bytes).map_err(|e| Error::new(format!("Invalid UTF-8 sequence: {e}"), None))
+			response.body = Some(ResponseBody::Hyper(restored));
+			response.body_used = false;
+
+			writer.write_body(Some(&bytes?));
+			Ok(ArrayBufferWrapper::from(writer.finish()))
 		})
 	}
+
+	#[ion(static)]
+	pub fn deserialize(cx: &Context, #[ion(convert = false)] data: BufferSource) -> Result<*mut JSObject> {
+		let response = Response::from_bytes(cx, &data.to_vec())?;
+		Ok(Response::new_object(cx, Box::new(response)))
+	}
+}
+
+impl Response {
+	/// Reads a [Response] from the binary form written by [Response::serialize].
+	pub(crate) fn from_bytes(cx: &Context, bytes: &[u8]) -> Result<Response> {
+		let mut reader = Reader::new(bytes, RESPONSE_MAGIC)?;
+
+		let kind = reader.read_parsed()?;
+		let url = reader.read_string()?;
+		let url = if url.is_empty() { None } else { Some(Url::parse(&url)?) };
+		let redirected = reader.read_bool()?;
+		let status = reader.read_u16()?;
+		let status = if status == 0 {
+			None
+		} else {
+			Some(StatusCode::from_u16(status)?)
+		};
+		let status_text = reader.read_string()?;
+		let headers = reader.read_headers()?;
+		let body = reader.read_body()?;
+		reader.finish()?;
+
+		let response = Response {
+			reflector: Reflector::default(),
+
+			headers: Box::default(),
+			body: body.map(|body| ResponseBody::Hyper(Body::from(body))),
+			body_used: false,
+
+			kind,
+			url,
+			redirected,
+
+			status,
+			status_text: Some(status_text),
+
+			range_requested: false,
+			trailers: None,
+			trailer: Box::default(),
+		};
+
+		let kind = if response.kind == ResponseKind::Default {
+			HeadersKind::Response
+		} else {
+			HeadersKind::Immutable
+		};
+		let headers = Headers {
+			reflector: Reflector::default(),
+			headers,
+			kind,
+		};
+		response.headers.set(Headers::new_object(cx, Box::new(headers)));
+
+		Ok(response)
+	}
+
+	pub(crate) fn write_head(&self, cx: &Context) -> Result<Writer> {
+		let headers = Object::from(unsafe { Local::from_heap(&self.headers) });
+		let headers = Headers::get_private(cx, &headers)?;
+
+		let mut writer = Writer::new(RESPONSE_MAGIC);
+		writer.write_str(&self.kind.to_string());
+		writer.write_str(self.url.as_ref().map_or("", Url::as_str));
+		writer.write_bool(self.redirected);
+		writer.write_u16(self.status.as_ref().map(StatusCode::as_u16).unwrap_or_default());
+		writer.write_str(self.status_text.as_deref().unwrap_or_default());
+		writer.write_headers(&headers.headers);
+		Ok(writer)
+	}
+}
+
+impl BodyMixin for Response {
+	fn headers_object(&self) -> &Heap<*mut JSObject> {
+		&self.headers
+	}
+
+	fn take_body(&mut self, cx: &Context) -> Result<Body> {
+		if self.body_used {
+			return Err(Error::new("Response body has already been used.", ErrorKind::Type));
+		}
+		let stream = matches!(self.body, Some(ResponseBody::Stream(_)));
+		let body = match &mut self.body {
+			Some(body) => body.take_http_body(cx)?,
+			None => Some(Body::Empty),
+		};
+		if !stream {
+			self.body_used = self.body.take().is_some();
+		}
+		body.ok_or_else(|| Error::new("Response body has already been used.", ErrorKind::Type))
+	}
+}
+
+fn trailer_headers(cx: &Context, headers: HeaderMap) -> *mut JSObject {
+	Headers::new_object(
+		cx,
+		Box::new(Headers {
+			reflector: Reflector::default(),
+			headers,
+			kind: HeadersKind::Immutable,
+		}),
+	)
 }
 
 pub fn network_error() -> Response {
@@ -212,6 +413,7 @@ pub fn network_error() -> Response {
 
 		headers: Box::default(),
 		body: None,
+		body_used: false,
 
 		kind: ResponseKind::Error,
 		url: None,
@@ -221,5 +423,7 @@ pub fn network_error() -> Response {
 		status_text: None,
 
 		range_requested: false,
+		trailers: None,
+		trailer: Box::default(),
 	}
 }
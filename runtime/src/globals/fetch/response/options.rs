@@ -4,9 +4,6 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
-use std::fmt;
-use std::fmt::{Display, Formatter};
-
 use http::StatusCode;
 use ion::conversions::FromValue;
 use ion::{Context, Error, ErrorKind, Result, Value};
@@ -33,7 +30,7 @@ fn parse_status(cx: &Context, status: Value) -> Result<StatusCode> {
 	}
 }
 
-#[derive(Clone, Copy, Debug, Default, PartialEq, Traceable)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, StringEnum, Traceable)]
 pub enum ResponseKind {
 	Basic,
 	Cors,
@@ -41,22 +38,10 @@ pub enum ResponseKind {
 	Default,
 	Error,
 	Opaque,
+	#[ion(name = "opaqueredirect")]
 	OpaqueRedirect,
 }
 
-impl Display for ResponseKind {
-	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-		match self {
-			ResponseKind::Basic => f.write_str("basic"),
-			ResponseKind::Cors => f.write_str("cors"),
-			ResponseKind::Default => f.write_str("default"),
-			ResponseKind::Error => f.write_str("error"),
-			ResponseKind::Opaque => f.write_str("opaque"),
-			ResponseKind::OpaqueRedirect => f.write_str("opaqueredirect"),
-		}
-	}
-}
-
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub enum ResponseTaint {
 	#[default]
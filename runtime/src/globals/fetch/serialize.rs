@@ -0,0 +1,151 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::str;
+use std::str::FromStr;
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use http::{HeaderMap, HeaderName, HeaderValue};
+use ion::{Error, ErrorKind, Result};
+
+const VERSION: u8 = 1;
+
+pub(crate) const REQUEST_MAGIC: &[u8; 4] = b"SFRQ";
+pub(crate) const RESPONSE_MAGIC: &[u8; 4] = b"SFRS";
+
+/// Writes the binary form of a [Request](super::Request) or [Response](super::Response).
+///
+/// Strings and header values are prefixed with their length as a 32-bit integer, and bodies with their length as a
+/// 64-bit integer. All integers are little-endian.
+pub(crate) struct Writer(BytesMut);
+
+impl Writer {
+	pub(crate) fn new(magic: &[u8; 4]) -> Writer {
+		let mut buffer = BytesMut::new();
+		buffer.put_slice(magic);
+		buffer.put_u8(VERSION);
+		Writer(buffer)
+	}
+
+	pub(crate) fn write_bool(&mut self, boolean: bool) {
+		self.0.put_u8(u8::from(boolean));
+	}
+
+	pub(crate) fn write_u16(&mut self, integer: u16) {
+		self.0.put_u16_le(integer);
+	}
+
+	pub(crate) fn write_bytes(&mut self, bytes: &[u8]) {
+		self.0.put_u32_le(bytes.len() as u32);
+		self.0.put_slice(bytes);
+	}
+
+	pub(crate) fn write_str(&mut self, string: &str) {
+		self.write_bytes(string.as_bytes());
+	}
+
+	pub(crate) fn write_headers(&mut self, headers: &HeaderMap) {
+		self.0.put_u32_le(headers.len() as u32);
+		for (name, value) in headers {
+			self.write_str(name.as_str());
+			self.write_bytes(value.as_bytes());
+		}
+	}
+
+	pub(crate) fn write_body(&mut self, body: Option<&[u8]>) {
+		self.write_bool(body.is_some());
+		if let Some(body) = body {
+			self.0.put_u64_le(body.len() as u64);
+			self.0.put_slice(body);
+		}
+	}
+
+	pub(crate) fn finish(self) -> Bytes {
+		self.0.freeze()
+	}
+}
+
+/// Reads the binary form written by [Writer].
+pub(crate) struct Reader<'b>(&'b [u8]);
+
+impl<'b> Reader<'b> {
+	pub(crate) fn new(bytes: &'b [u8], magic: &[u8; 4]) -> Result<Reader<'b>> {
+		let mut reader = Reader(bytes);
+		if reader.read_slice(magic.len())? != magic || reader.read_slice(1)?[0] != VERSION {
+			return Err(invalid_data());
+		}
+		Ok(reader)
+	}
+
+	fn read_slice(&mut self, len: usize) -> Result<&'b [u8]> {
+		if self.0.remaining() < len {
+			return Err(invalid_data());
+		}
+		let (slice, rest) = self.0.split_at(len);
+		self.0 = rest;
+		Ok(slice)
+	}
+
+	pub(crate) fn read_bool(&mut self) -> Result<bool> {
+		match self.read_slice(1)?[0] {
+			0 => Ok(false),
+			1 => Ok(true),
+			_ => Err(invalid_data()),
+		}
+	}
+
+	pub(crate) fn read_u16(&mut self) -> Result<u16> {
+		Ok(self.read_slice(2)?.get_u16_le())
+	}
+
+	fn read_u32(&mut self) -> Result<usize> {
+		Ok(self.read_slice(4)?.get_u32_le() as usize)
+	}
+
+	pub(crate) fn read_bytes(&mut self) -> Result<&'b [u8]> {
+		let len = self.read_u32()?;
+		self.read_slice(len)
+	}
+
+	pub(crate) fn read_string(&mut self) -> Result<String> {
+		str::from_utf8(self.read_bytes()?).map(String::from).map_err(|_| invalid_data())
+	}
+
+	pub(crate) fn read_parsed<T: FromStr>(&mut self) -> Result<T> {
+		T::from_str(&self.read_string()?).map_err(|_| invalid_data())
+	}
+
+	pub(crate) fn read_headers(&mut self) -> Result<HeaderMap> {
+		let len = self.read_u32()?;
+		let mut headers = HeaderMap::new();
+		for _ in 0..len {
+			let name = HeaderName::from_bytes(self.read_bytes()?).map_err(|_| invalid_data())?;
+			let value = HeaderValue::from_bytes(self.read_bytes()?).map_err(|_| invalid_data())?;
+			headers.append(name, value);
+		}
+		Ok(headers)
+	}
+
+	pub(crate) fn read_body(&mut self) -> Result<Option<Bytes>> {
+		if !self.read_bool()? {
+			return Ok(None);
+		}
+		let len = usize::try_from(self.read_slice(8)?.get_u64_le()).map_err(|_| invalid_data())?;
+		Ok(Some(Bytes::copy_from_slice(self.read_slice(len)?)))
+	}
+
+	pub(crate) fn finish(self) -> Result<()> {
+		if self.0.is_empty() {
+			Ok(())
+		} else {
+			Err(invalid_data())
+		}
+	}
+}
+
+fn invalid_data() -> Error {
+	Error::new("Received invalid serialized data", ErrorKind::Type)
+}
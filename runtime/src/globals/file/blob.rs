@@ -4,8 +4,6 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
-use std::str::FromStr;
-
 use bytes::{BufMut, Bytes, BytesMut};
 use encoding_rs::UTF_8;
 use ion::class::Reflector;
@@ -78,15 +76,12 @@ impl<'cx> FromValue<'cx> for BufferSource<'cx> {
 
 #[derive(Debug, FromValue)]
 pub enum BlobPart<'cx> {
-	#[ion(inherit)]
 	String(String),
-	#[ion(inherit)]
 	BufferSource(#[ion(convert = false)] BufferSource<'cx>),
-	#[ion(inherit)]
 	Blob(&'cx Blob),
 }
 
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, StringEnum)]
 pub enum Endings {
 	#[default]
 	Transparent,
@@ -118,27 +113,6 @@ impl Endings {
 	}
 }
 
-impl FromStr for Endings {
-	type Err = Error;
-
-	fn from_str(endings: &str) -> Result<Endings> {
-		match endings {
-			"transparent" => Ok(Endings::Transparent),
-			"native" => Ok(Endings::Native),
-			_ => Err(Error::new("Invalid ending type for Blob", ErrorKind::Type)),
-		}
-	}
-}
-
-impl<'cx> FromValue<'cx> for Endings {
-	type Config = ();
-
-	fn from_value(cx: &'cx Context, value: &Value, strict: bool, _: ()) -> Result<Endings> {
-		let endings = String::from_value(cx, value, strict, ())?;
-		Endings::from_str(&endings)
-	}
-}
-
 #[derive(Debug, Default, FromValue)]
 pub struct BlobOptions {
 	#[ion(name = "type")]
@@ -235,11 +209,11 @@ impl Blob {
 	#[ion(name = "arrayBuffer")]
 	pub fn array_buffer<'cx>(&self, cx: &'cx Context) -> Option<Promise<'cx>> {
 		let bytes = self.bytes.clone();
-		future_to_promise(cx, async move { Ok::<_, ()>(ArrayBufferWrapper::from(bytes.to_vec())) })
+		future_to_promise(cx, async move { Ok::<_, ()>(ArrayBufferWrapper::from(bytes)) })
 	}
 
 	pub fn bytes<'cx>(&self, cx: &'cx Context) -> Option<Promise<'cx>> {
 		let bytes = self.bytes.clone();
-		future_to_promise(cx, async move { Ok::<_, ()>(Uint8ArrayWrapper::from(bytes.to_vec())) })
+		future_to_promise(cx, async move { Ok::<_, ()>(Uint8ArrayWrapper::from(bytes)) })
 	}
 }
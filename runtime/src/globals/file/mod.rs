@@ -5,7 +5,9 @@
  */
 
 pub use blob::{Blob, BufferSource};
+use bytes::Bytes;
 use chrono::{DateTime, TimeZone, Utc};
+use ion::class::Reflector;
 use ion::function::{Opt, Wrap};
 use ion::{ClassDefinition, Context, Object};
 
@@ -23,13 +25,25 @@ pub struct FileOptions {
 }
 
 #[js_class]
+#[ion(extends = Blob)]
 pub struct File {
-	blob: Blob,
-	name: String,
+	pub(crate) blob: Blob,
+	pub(crate) name: String,
 	#[trace(no_trace)]
 	modified: DateTime<Utc>,
 }
 
+impl File {
+	pub fn new(bytes: Bytes, kind: Option<String>, name: String) -> File {
+		let blob = Blob {
+			reflector: Reflector::default(),
+			bytes,
+			kind,
+		};
+		File { blob, name, modified: Utc::now() }
+	}
+}
+
 #[js_class]
 impl File {
 	#[ion(constructor)]
@@ -5,7 +5,6 @@
  */
 
 use std::cell::UnsafeCell;
-use std::str::FromStr;
 
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
@@ -16,20 +15,18 @@ use ion::function::Opt;
 use ion::string::byte::{ByteString, Latin1};
 use ion::typedarray::ArrayBufferWrapper;
 use ion::{ClassDefinition, Context, Error, ErrorKind, Object, Result, TracedHeap};
-use mime::Mime;
 use mozjs::jsapi::{Heap, JSObject};
 use mozjs::jsval::{JSVal, NullValue};
 
 use crate::globals::file::Blob;
+use crate::mime::MediaType;
 use crate::promise::future_to_promise;
 
 fn encoding_from_string_mime(encoding: Option<&str>, mime: Option<&str>) -> &'static Encoding {
 	encoding
 		.and_then(|e| match Encoding::for_label_no_replacement(e.as_bytes()) {
-			None if mime.is_some() => Mime::from_str(mime.unwrap()).ok().and_then(|mime| {
-				Encoding::for_label_no_replacement(
-					mime.get_param("charset").map(|p| p.as_str().as_bytes()).unwrap_or(b""),
-				)
+			None if mime.is_some() => MediaType::parse(mime.unwrap()).and_then(|mime| {
+				Encoding::for_label_no_replacement(mime.parameter("charset").unwrap_or("").as_bytes())
 			}),
 			e => e,
 		})
@@ -66,8 +63,11 @@ pub struct FileReader {
 
 #[js_class]
 impl FileReader {
+	#[ion(constant)]
 	pub const EMPTY: i32 = FileReaderState::Empty as u8 as i32;
+	#[ion(constant)]
 	pub const LOADING: i32 = FileReaderState::Loading as u8 as i32;
+	#[ion(constant)]
 	pub const DONE: i32 = FileReaderState::Done as u8 as i32;
 
 	#[ion(constructor)]
@@ -101,7 +101,7 @@ impl FileReader {
 		future_to_promise::<_, _, Error>(cx, async move {
 			let reader = Object::from(this.to_local());
 			let reader = FileReader::get_private(&cx2, &reader)?;
-			let array_buffer = ArrayBufferWrapper::from(bytes.to_vec());
+			let array_buffer = ArrayBufferWrapper::from(bytes);
 			reader.result.set(array_buffer.as_value(&cx2).get());
 			Ok(())
 		});
@@ -6,7 +6,7 @@
 
 use ion::flags::PropertyFlags;
 use ion::{Context, Error, Function, Object, Result};
-use mozjs::jsapi::JSFunctionSpec;
+use mozjs::jsapi::{Heap, JSFunctionSpec};
 
 use crate::event_loop::microtasks::Microtask;
 use crate::ContextExt;
@@ -15,7 +15,7 @@ use crate::ContextExt;
 fn queue_microtask(cx: &Context, callback: Function) -> Result<()> {
 	let event_loop = unsafe { &mut cx.get_private().event_loop };
 	if let Some(queue) = &mut event_loop.microtasks {
-		queue.enqueue(cx, Microtask::User(callback.get()));
+		queue.enqueue(cx, Microtask::User(Heap::boxed(callback.get())));
 		Ok(())
 	} else {
 		Err(Error::new("Microtask Queue has not been initialised.", None))
@@ -4,7 +4,8 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
-use ion::{ClassDefinition, Context, Iterator, Object};
+use ion::object::define_lazy_global;
+use ion::{AsyncIterator, ClassDefinition, Context, Iterator, Object};
 
 pub mod abort;
 pub mod base64;
@@ -15,28 +16,52 @@ pub mod encoding;
 pub mod fetch;
 pub mod file;
 pub mod microtasks;
+pub mod process;
+pub mod runtime;
 pub mod streams;
 pub mod timers;
 pub mod url;
 
 pub fn init_globals(cx: &Context, global: &Object) -> bool {
-	let result = base64::define(cx, global)
+	define_lazy_global(cx, &["TextDecoder", "TextEncoder"], encoding::define);
+	define_lazy_global(cx, &["Blob", "File", "FileReader", "FileReaderSync"], file::define);
+	define_lazy_global(
+		cx,
+		&[
+			"ReadableStream",
+			"ReadableByteStreamController",
+			"ReadableStreamDefaultController",
+			"ReadableStreamBYOBRequest",
+			"ReadableStreamDefaultReader",
+			"ReadableStreamBYOBReader",
+		],
+		streams::define,
+	);
+	define_lazy_global(cx, &["URL", "URLSearchParams"], url::define);
+	#[cfg(feature = "fetch")]
+	define_lazy_global(
+		cx,
+		&[
+			"fetch",
+			"caches",
+			"Cache",
+			"CacheStorage",
+			"EventSource",
+			"FormData",
+			"Headers",
+			"Request",
+			"Response",
+		],
+		fetch::define,
+	);
+
+	base64::define(cx, global)
 		&& clone::define(cx, global)
 		&& console::define(cx, global)
-		&& encoding::define(cx, global)
-		&& file::define(cx, global)
-		&& streams::define(cx, global)
-		&& url::define(cx, global)
-		&& Iterator::init_class(cx, global).0;
-
-	#[cfg(feature = "fetch")]
-	{
-		result && fetch::define(cx, global)
-	}
-	#[cfg(not(feature = "fetch"))]
-	{
-		result
-	}
+		&& process::define(cx, global)
+		&& runtime::define(cx, global)
+		&& Iterator::init_class(cx, global).0
+		&& AsyncIterator::init_class(cx, global).0
 }
 
 pub fn init_timers(cx: &Context, global: &Object) -> bool {
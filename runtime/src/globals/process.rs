@@ -0,0 +1,134 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::env;
+
+use ion::conversions::{ConversionBehavior, FromValue};
+use ion::flags::PropertyFlags;
+use ion::{Arguments, Context, Error, Object, Result, ThrowException};
+use mozjs::jsapi::{JSContext, JSFunctionSpec};
+use mozjs::jsval::JSVal;
+
+use crate::config::Config;
+use crate::ContextExt;
+
+fn check_env_permission() -> Result<()> {
+	if Config::global().allow_env {
+		Ok(())
+	} else {
+		Err(Error::new(
+			"Access to environment variables requires the --allow-env flag",
+			None,
+		))
+	}
+}
+
+#[js_fn]
+fn get(key: String) -> Result<Option<String>> {
+	check_env_permission()?;
+	Ok(env::var(key).ok())
+}
+
+#[js_fn]
+fn set(key: String, value: String) -> Result<()> {
+	check_env_permission()?;
+	if key.is_empty() || key.contains(['=', '\0']) || value.contains('\0') {
+		return Err(Error::new(format!("Invalid environment variable: {key}"), None));
+	}
+	env::set_var(key, value);
+	Ok(())
+}
+
+#[js_fn]
+fn delete(key: String) -> Result<()> {
+	check_env_permission()?;
+	if key.is_empty() || key.contains(['=', '\0']) {
+		return Err(Error::new(format!("Invalid environment variable: {key}"), None));
+	}
+	env::remove_var(key);
+	Ok(())
+}
+
+#[js_fn]
+fn to_object(cx: &Context) -> Result<Object> {
+	check_env_permission()?;
+	let object = Object::new(cx);
+	for (key, value) in env::vars() {
+		object.set_as(cx, key.as_str(), &value);
+	}
+	Ok(object)
+}
+
+#[js_fn]
+fn cwd() -> Result<String> {
+	let cwd = env::current_dir()?;
+	Ok(cwd.to_string_lossy().into_owned())
+}
+
+#[js_fn]
+fn chdir(path: String) -> Result<()> {
+	env::set_current_dir(&path).map_err(|err| Error::new(format!("Could not change directory to {path}\n{err}"), None))
+}
+
+/// Terminates execution without a pending exception, so it cannot be caught by scripts.
+/// The event loop stops at the next opportunity and the runtime reports the exit code.
+unsafe extern "C" fn exit(cx: *mut JSContext, argc: u32, vp: *mut JSVal) -> bool {
+	let cx = unsafe { &Context::new_unchecked(cx) };
+	let args = unsafe { Arguments::new(cx, argc, vp) };
+
+	let code = match args.value(0) {
+		Some(code) if !code.handle().is_undefined() => {
+			match i32::from_value(cx, &code, false, ConversionBehavior::Default) {
+				Ok(code) => code,
+				Err(error) => {
+					error.throw(cx);
+					return false;
+				}
+			}
+		}
+		_ => exit_code(cx, &Object::global(cx)),
+	};
+
+	let event_loop = unsafe { &mut cx.get_private().event_loop };
+	event_loop.exit_code = Some(code);
+	false
+}
+
+const ENV_FUNCTIONS: &[JSFunctionSpec] = &[
+	function_spec!(get, 1),
+	function_spec!(set, 2),
+	function_spec!(delete, 1),
+	function_spec!(to_object, "toObject", 0),
+	JSFunctionSpec::ZERO,
+];
+
+const FUNCTIONS: &[JSFunctionSpec] = &[
+	function_spec!(cwd, 0),
+	function_spec!(chdir, 1),
+	function_spec!(exit, 0),
+	JSFunctionSpec::ZERO,
+];
+
+/// Returns the value of `process.exitCode`, or `0` if it is absent or not an integer.
+pub fn exit_code(cx: &Context, global: &Object) -> i32 {
+	let process: Option<Object> = global.get_as(cx, "process", true, ()).ok().flatten();
+	process
+		.and_then(|process| process.get_as(cx, "exitCode", true, ConversionBehavior::Default).ok().flatten())
+		.unwrap_or(0)
+}
+
+pub fn define(cx: &Context, global: &Object) -> bool {
+	let process = Object::new(cx);
+	let env = Object::new(cx);
+	let args = &Config::global().args;
+
+	let methods = unsafe { process.define_methods(cx, FUNCTIONS) && env.define_methods(cx, ENV_FUNCTIONS) };
+	methods
+		&& process.define_as(cx, "args", args, PropertyFlags::CONSTANT_ENUMERATED)
+		&& process.define_as(cx, "env", &env, PropertyFlags::CONSTANT_ENUMERATED)
+		&& process.set_as(cx, "exitCode", &0)
+		&& global.define_as(cx, "process", &process, PropertyFlags::CONSTANT)
+}
@@ -0,0 +1,54 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::path::Path;
+
+use ion::flags::PropertyFlags;
+use ion::gc::{heap_statistics, write_heap_dump, write_heap_snapshot, zone_heap_bytes};
+use ion::{Context, Object, Result};
+use mozjs::jsapi::JSFunctionSpec;
+
+#[js_fn]
+fn memory_usage(cx: &Context) -> Object {
+	let statistics = heap_statistics(cx);
+	let usage = Object::new(cx);
+	usage.set_as(cx, "heapUsed", &statistics.bytes);
+	usage.set_as(cx, "heapLimit", &statistics.max_bytes);
+	usage.set_as(cx, "gcCount", &statistics.gc_count);
+	usage.set_as(cx, "majorGCCount", &statistics.major_gc_count);
+	usage.set_as(cx, "minorGCCount", &statistics.minor_gc_count);
+
+	// Scripts and modules all run in the realm of the global, so it is the only realm reported.
+	let realm = Object::new(cx);
+	realm.set_as(cx, "heapUsed", &zone_heap_bytes(&Object::global(cx)));
+	usage.set_as(cx, "realms", &vec![realm]);
+	usage
+}
+
+#[js_fn]
+fn heap_dump(cx: &Context, path: String) -> Result<()> {
+	write_heap_dump(cx, Path::new(&path))?;
+	Ok(())
+}
+
+#[js_fn]
+fn heap_snapshot(cx: &Context, path: String) -> Result<()> {
+	write_heap_snapshot(cx, Path::new(&path))?;
+	Ok(())
+}
+
+const FUNCTIONS: &[JSFunctionSpec] = &[
+	function_spec!(memory_usage, "memoryUsage", 0),
+	function_spec!(heap_dump, "heapDump", 1),
+	function_spec!(heap_snapshot, "heapSnapshot", 1),
+	JSFunctionSpec::ZERO,
+];
+
+pub fn define(cx: &Context, global: &Object) -> bool {
+	let runtime = Object::new(cx);
+	(unsafe { runtime.define_methods(cx, FUNCTIONS) })
+		&& global.define_as(cx, "runtime", &runtime, PropertyFlags::CONSTANT)
+}
@@ -12,7 +12,7 @@ use readable::{
 
 pub mod readable;
 
-pub fn define<'cx>(cx: &'cx Context, global: &'cx Object) -> bool {
+pub fn define(cx: &Context, global: &Object) -> bool {
 	let dummy = Object::new(cx);
 	ReadableStream::init_class(cx, global).0
 		&& CommonController::init_class(cx, &dummy).0
@@ -5,6 +5,8 @@
  */
 
 use std::collections::VecDeque;
+#[cfg(feature = "fetch")]
+use std::mem;
 use std::rc::Rc;
 
 pub use controller::{ByobRequest, ByteStreamController, CommonController, DefaultController};
@@ -20,6 +22,9 @@ use reader::{Reader, ReaderKind};
 pub use source::StreamSource;
 use source::{forward_reader_error, TeeBytesState, TeeDefaultState};
 
+#[cfg(feature = "fetch")]
+use crate::globals::fetch::body::Body;
+
 mod controller;
 mod reader;
 mod source;
@@ -245,6 +250,54 @@ impl ReadableStream {
 		}
 	}
 
+	/// Creates a readable byte stream which reads the chunks of the body as they are received.
+	#[cfg(feature = "fetch")]
+	pub(crate) fn from_body(cx: &Context, body: Body) -> *mut JSObject {
+		let stream = Object::from(cx.root(ReadableStream::new_raw_object(cx)));
+		let controller = ByteStreamController {
+			common: CommonController::new(&stream, StreamSource::Body(Some(body)), 0.0),
+			auto_allocate_chunk_size: 0,
+			byob_request: None,
+			pending_descriptors: VecDeque::default(),
+			queue: VecDeque::default(),
+		};
+		let controller = Heap::boxed(ByteStreamController::new_object(cx, Box::new(controller)));
+
+		unsafe {
+			let controller = Object::from(Local::from_heap(&controller));
+			ByteStreamController::get_mut_private_unchecked(&controller).start(cx, None).unwrap();
+		}
+
+		let readable = ReadableStream::new(ControllerKind::ByteStream, controller);
+		unsafe {
+			ReadableStream::set_private(stream.handle().get(), Box::new(readable));
+		}
+		stream.handle().get()
+	}
+
+	/// Takes the unread body of a stream created with [ReadableStream::from_body], so that it can be read directly.
+	/// The stream is disturbed and closed.
+	///
+	/// Returns [None] if the stream is locked or has been read from.
+	#[cfg(feature = "fetch")]
+	pub(crate) fn take_body(&mut self, cx: &Context) -> Result<Option<Body>> {
+		if self.get_locked() || self.disturbed {
+			return Ok(None);
+		}
+
+		self.disturbed = true;
+		let mut controller = self.native_controller(cx)?;
+		let source = mem::replace(&mut controller.common_mut().source, StreamSource::None);
+		if self.state == State::Readable {
+			let _ = self.close(cx);
+		}
+
+		match source {
+			StreamSource::Body(Some(body)) => Ok(Some(body)),
+			_ => Ok(Some(Body::Empty)),
+		}
+	}
+
 	pub(crate) fn tee_internal<'cx>(&mut self, cx: &'cx Context, clone_branch_2: bool) -> [Object<'cx>; 2] {
 		match self.controller_kind {
 			ControllerKind::Default => {
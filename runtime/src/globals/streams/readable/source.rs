@@ -8,6 +8,8 @@ use std::cell::Cell;
 use std::rc::Rc;
 
 use bytes::{Buf, Bytes};
+#[cfg(feature = "fetch")]
+use http_body_util::BodyExt;
 use ion::class::NativeObject;
 use ion::clone::StructuredCloneBuffer;
 use ion::conversions::{FromValue, ToValue};
@@ -22,9 +24,13 @@ use mozjs::jsapi::{CloneDataPolicy, Heap, JSFunction, JSObject, StructuredCloneS
 use mozjs::jsval::{JSVal, UndefinedValue};
 
 use crate::globals::clone::{StructuredCloneDataHolder, STRUCTURED_CLONE_CALLBACKS};
+#[cfg(feature = "fetch")]
+use crate::globals::fetch::body::Body;
 use crate::globals::streams::readable::controller::ControllerInternals;
 use crate::globals::streams::readable::reader::{ReaderKind, Request};
 use crate::globals::streams::readable::{ByobRequest, ByteStreamController, ReadableStream, ReaderOptions};
+#[cfg(feature = "fetch")]
+use crate::promise::future_to_promise;
 
 #[derive(Traceable)]
 pub enum StreamSource {
@@ -36,6 +42,8 @@ pub enum StreamSource {
 	},
 	Bytes(#[trace(no_trace)] Option<Bytes>),
 	BytesBuf(#[trace(no_trace)] Option<Box<dyn Buf>>),
+	#[cfg(feature = "fetch")]
+	Body(#[trace(no_trace)] Option<Body>),
 	Iterator(#[trace(no_trace)] Box<dyn JSIterator>, Option<Box<Heap<JSVal>>>),
 	TeeDefault(Rc<TeeDefaultState>, bool),
 	TeeBytes(Rc<TeeBytesState>, bool),
@@ -76,6 +84,44 @@ impl StreamSource {
 				buf.advance(chunk.len());
 				Ok(Some(Promise::resolved(cx, &buffer.as_value(cx))))
 			}
+			#[cfg(feature = "fetch")]
+			StreamSource::Body(body) => {
+				let Some(mut body) = body.take() else {
+					return Ok(None);
+				};
+
+				let controller = TracedHeap::new(controller);
+				let cx2 = unsafe { Context::new_unchecked(cx.as_ptr()) };
+				Ok(future_to_promise::<_, _, Exception>(cx, async move {
+					let chunk = loop {
+						match body.frame().await {
+							Some(frame) => {
+								if let Ok(data) = frame?.into_data() {
+									if !data.is_empty() {
+										break Some(data);
+									}
+								}
+							}
+							None => break None,
+						}
+					};
+
+					let controller = ByteStreamController::from_traced_heap(&cx2, &controller)?;
+					match chunk {
+						Some(chunk) => {
+							// The body is only returned to the source if the stream was not cancelled while reading.
+							if let StreamSource::Body(source) = &mut controller.common.source {
+								*source = Some(body);
+							}
+
+							let buffer = ArrayBuffer::copy_from_bytes(&cx2, &chunk).unwrap();
+							let array = Uint8Array::with_array_buffer(&cx2, &buffer, 0, buffer.len()).unwrap();
+							controller.enqueue(&cx2, ArrayBufferView::from(array.into_local()).unwrap())
+						}
+						None => controller.close(&cx2),
+					}
+				}))
+			}
 			StreamSource::Iterator(iterator, Some(data)) => {
 				let data = Value::from(unsafe { Local::from_heap(data) });
 				Ok(iterator.next_value(cx, &data).map(|value| Promise::resolved(cx, &value)))
@@ -469,6 +515,10 @@ impl StreamSource {
 			StreamSource::BytesBuf(buf) => {
 				*buf = None;
 			}
+			#[cfg(feature = "fetch")]
+			StreamSource::Body(_) => {
+				*self = StreamSource::None;
+			}
 			StreamSource::Iterator(_, data) => {
 				*data = None;
 			}
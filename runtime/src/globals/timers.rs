@@ -5,83 +5,137 @@
  */
 
 use chrono::Duration;
+use ion::class::{NativeObject, Reflector};
 use ion::function::{Clamp, Enforce, Opt, Rest};
-use ion::{Context, Error, Function, Object, Result};
-use mozjs::jsapi::JSFunctionSpec;
+use ion::symbol::WellKnownSymbolCode;
+use ion::{ClassDefinition, Context, Error, Function, Object, Result};
+use mozjs::jsapi::{JSFunctionSpec, JSObject};
 use mozjs::jsval::JSVal;
 
-use crate::event_loop::macrotasks::{Macrotask, TimerMacrotask, UserMacrotask};
+use crate::event_loop::macrotasks::{Macrotask, MacrotaskQueue, TimerMacrotask, UserMacrotask};
 use crate::ContextExt;
 
 const MINIMUM_DELAY: i32 = 1;
 const MINIMUM_DELAY_NESTED: i32 = 4;
 
+/// Handle of a timer returned by `setTimeout` and `setInterval`.
+///
+/// Converts to the ID of the timer, so it can also be passed to `clearTimeout` and `clearInterval` as a number.
+#[js_class]
+pub struct Timeout {
+	reflector: Reflector,
+	id: u32,
+}
+
+#[js_class]
+impl Timeout {
+	/// Makes the timer keep the event loop alive, which is the default.
+	#[ion(name = "ref")]
+	pub fn reference(&self, cx: &Context) -> Result<*mut JSObject> {
+		if let Some(timer) = macrotask_queue(cx)?.timer(self.id) {
+			timer.referenced = true;
+		}
+		Ok(self.reflector().get())
+	}
+
+	/// Stops the timer from keeping the event loop alive, so it can exit before the timer fires.
+	#[ion(name = "unref")]
+	pub fn unreference(&self, cx: &Context) -> Result<*mut JSObject> {
+		if let Some(timer) = macrotask_queue(cx)?.timer(self.id) {
+			timer.referenced = false;
+		}
+		Ok(self.reflector().get())
+	}
+
+	/// Checks if the timer keeps the event loop alive. Returns `false` once it has finished or been cleared.
+	#[ion(name = "hasRef")]
+	pub fn has_ref(&self, cx: &Context) -> Result<bool> {
+		Ok(macrotask_queue(cx)?.timer(self.id).is_some_and(|timer| timer.referenced))
+	}
+
+	/// Restarts the timer, so it fires after its duration from now.
+	pub fn refresh(&self, cx: &Context) -> Result<*mut JSObject> {
+		macrotask_queue(cx)?.refresh(self.id);
+		Ok(self.reflector().get())
+	}
+
+	#[ion(name = WellKnownSymbolCode::ToPrimitive)]
+	pub fn to_primitive(&self) -> u32 {
+		self.id
+	}
+}
+
+#[derive(FromValue)]
+pub enum TimerId<'cx> {
+	Timeout(&'cx Timeout),
+	Id(Enforce<u32>),
+}
+
+fn macrotask_queue(cx: &Context) -> Result<&mut MacrotaskQueue> {
+	let event_loop = unsafe { &mut cx.get_private().event_loop };
+	event_loop
+		.macrotasks
+		.as_mut()
+		.ok_or_else(|| Error::new("Macrotask Queue has not been initialised.", None))
+}
+
 fn set_timer(
 	cx: &Context, callback: Function, duration: Option<Clamp<i32>>, arguments: Box<[JSVal]>, repeat: bool,
-) -> Result<u32> {
-	let event_loop = unsafe { &mut cx.get_private().event_loop };
-	if let Some(queue) = &mut event_loop.macrotasks {
-		let minimum = if queue.nesting > 5 {
-			MINIMUM_DELAY_NESTED
-		} else {
-			MINIMUM_DELAY
-		};
-
-		let duration = duration.map(|t| t.0.max(minimum)).unwrap_or(minimum);
-		let timer = TimerMacrotask::new(callback, arguments, repeat, Duration::milliseconds(duration.into()));
-		Ok(queue.enqueue(Macrotask::Timer(timer), None))
+) -> Result<*mut JSObject> {
+	let queue = macrotask_queue(cx)?;
+	let minimum = if queue.nesting > 5 {
+		MINIMUM_DELAY_NESTED
 	} else {
-		Err(Error::new("Macrotask Queue has not been initialised.", None))
-	}
+		MINIMUM_DELAY
+	};
+
+	let duration = duration.map(|t| t.0.max(minimum)).unwrap_or(minimum);
+	let timer = TimerMacrotask::new(callback, arguments, repeat, Duration::milliseconds(duration.into()));
+	let id = queue.enqueue(Macrotask::Timer(timer), None);
+	Ok(Timeout::new_object(
+		cx,
+		Box::new(Timeout { reflector: Reflector::default(), id }),
+	))
 }
 
-fn clear_timer(cx: &Context, id: Option<Enforce<u32>>) -> Result<()> {
-	if let Some(id) = id {
-		let event_loop = unsafe { &mut cx.get_private().event_loop };
-		if let Some(queue) = &mut event_loop.macrotasks {
-			queue.remove(id.0);
-			Ok(())
-		} else {
-			Err(Error::new("Macrotask Queue has not been initialised.", None))
-		}
-	} else {
-		Ok(())
-	}
+fn clear_timer(cx: &Context, id: Option<TimerId>) -> Result<()> {
+	let id = match id {
+		Some(TimerId::Timeout(timeout)) => timeout.id,
+		Some(TimerId::Id(id)) => id.0,
+		None => return Ok(()),
+	};
+	macrotask_queue(cx)?.remove(id);
+	Ok(())
 }
 
 #[js_fn]
 fn set_timeout(
 	cx: &Context, callback: Function, Opt(duration): Opt<Clamp<i32>>, Rest(arguments): Rest<JSVal>,
-) -> Result<u32> {
+) -> Result<*mut JSObject> {
 	set_timer(cx, callback, duration, arguments, false)
 }
 
 #[js_fn]
 fn set_interval(
 	cx: &Context, callback: Function, Opt(duration): Opt<Clamp<i32>>, Rest(arguments): Rest<JSVal>,
-) -> Result<u32> {
+) -> Result<*mut JSObject> {
 	set_timer(cx, callback, duration, arguments, true)
 }
 
 #[js_fn]
-fn clear_timeout(cx: &Context, Opt(id): Opt<Enforce<u32>>) -> Result<()> {
+fn clear_timeout(cx: &Context, Opt(id): Opt<TimerId>) -> Result<()> {
 	clear_timer(cx, id)
 }
 
 #[js_fn]
-fn clear_interval(cx: &Context, Opt(id): Opt<Enforce<u32>>) -> Result<()> {
+fn clear_interval(cx: &Context, Opt(id): Opt<TimerId>) -> Result<()> {
 	clear_timer(cx, id)
 }
 
 #[js_fn]
 fn queue_macrotask(cx: &Context, callback: Function) -> Result<()> {
-	let event_loop = unsafe { &mut cx.get_private().event_loop };
-	if let Some(queue) = &mut event_loop.macrotasks {
-		queue.enqueue(Macrotask::User(UserMacrotask::new(callback)), None);
-		Ok(())
-	} else {
-		Err(Error::new("Macrotask Queue has not been initialised.", None))
-	}
+	macrotask_queue(cx)?.enqueue(Macrotask::User(UserMacrotask::new(callback)), None);
+	Ok(())
 }
 
 const FUNCTIONS: &[JSFunctionSpec] = &[
@@ -94,5 +148,5 @@ const FUNCTIONS: &[JSFunctionSpec] = &[
 ];
 
 pub fn define(cx: &Context, global: &Object) -> bool {
-	unsafe { global.define_methods(cx, FUNCTIONS) }
+	(unsafe { global.define_methods(cx, FUNCTIONS) }) && Timeout::init_class(cx, global).0
 }
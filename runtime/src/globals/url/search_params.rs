@@ -8,7 +8,6 @@ use form_urlencoded::{parse, Serializer};
 use ion::class::Reflector;
 use ion::conversions::{FromValue, ToValue};
 use ion::function::Opt;
-use ion::symbol::WellKnownSymbolCode;
 use ion::{ClassDefinition, Context, Error, ErrorKind, JSIterator, Local, Object, OwnedKey, Result, Value};
 use mozjs::jsapi::{Heap, JSObject};
 use url::Url;
@@ -184,10 +183,9 @@ impl URLSearchParams {
 		}
 	}
 
-	#[ion(name = WellKnownSymbolCode::Iterator)]
-	pub fn iterator(cx: &Context, #[ion(this)] this: &Object) -> ion::Iterator {
-		let thisv = this.as_value(cx);
-		ion::Iterator::new(SearchParamsIterator::default(), &thisv)
+	#[ion(iterable)]
+	pub fn iterator(&self) -> SearchParamsIterator {
+		SearchParamsIterator::default()
 	}
 }
 
@@ -13,8 +13,10 @@ pub use crate::runtime::*;
 
 pub mod cache;
 pub mod config;
+pub mod env;
 pub mod event_loop;
 pub mod globals;
+pub mod mime;
 pub mod module;
 #[cfg(feature = "tokio-promise")]
 pub mod promise;
@@ -0,0 +1,297 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::fmt::{Display, Formatter, Write};
+use std::str::FromStr;
+
+use ion::{Error, ErrorKind};
+
+const EXTENSIONS: &[(&str, &str)] = &[
+	("aac", "audio/aac"),
+	("apng", "image/apng"),
+	("avif", "image/avif"),
+	("bin", "application/octet-stream"),
+	("bmp", "image/bmp"),
+	("cjs", "text/javascript"),
+	("css", "text/css"),
+	("csv", "text/csv"),
+	("gif", "image/gif"),
+	("gz", "application/gzip"),
+	("htm", "text/html"),
+	("html", "text/html"),
+	("ico", "image/vnd.microsoft.icon"),
+	("ics", "text/calendar"),
+	("jpeg", "image/jpeg"),
+	("jpg", "image/jpeg"),
+	("js", "text/javascript"),
+	("json", "application/json"),
+	("jsonld", "application/ld+json"),
+	("jsx", "text/javascript"),
+	("map", "application/json"),
+	("md", "text/markdown"),
+	("mjs", "text/javascript"),
+	("mp3", "audio/mpeg"),
+	("mp4", "video/mp4"),
+	("mpeg", "video/mpeg"),
+	("oga", "audio/ogg"),
+	("ogg", "audio/ogg"),
+	("ogv", "video/ogg"),
+	("opus", "audio/opus"),
+	("otf", "font/otf"),
+	("pdf", "application/pdf"),
+	("png", "image/png"),
+	("rtf", "application/rtf"),
+	("svg", "image/svg+xml"),
+	("tar", "application/x-tar"),
+	("tif", "image/tiff"),
+	("tiff", "image/tiff"),
+	("toml", "application/toml"),
+	("ts", "text/typescript"),
+	("tsx", "text/typescript"),
+	("ttf", "font/ttf"),
+	("txt", "text/plain"),
+	("wasm", "application/wasm"),
+	("wav", "audio/wav"),
+	("weba", "audio/webm"),
+	("webm", "video/webm"),
+	("webmanifest", "application/manifest+json"),
+	("webp", "image/webp"),
+	("woff", "font/woff"),
+	("woff2", "font/woff2"),
+	("xhtml", "application/xhtml+xml"),
+	("xml", "application/xml"),
+	("yaml", "application/yaml"),
+	("yml", "application/yaml"),
+	("zip", "application/zip"),
+];
+
+/// Media type as defined by the [MIME Sniffing Standard](https://mimesniff.spec.whatwg.org/#mime-type-representation).
+/// Type, subtype and parameter names are always ASCII lowercase.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MediaType {
+	pub kind: String,
+	pub subtype: String,
+	pub parameters: Vec<(String, String)>,
+}
+
+impl MediaType {
+	/// Parses a MIME type according to <https://mimesniff.spec.whatwg.org/#parse-a-mime-type>.
+	pub fn parse(input: &str) -> Option<MediaType> {
+		let input = input.trim_matches(is_http_whitespace);
+
+		let (kind, rest) = input.split_once('/')?;
+		if kind.is_empty() || !kind.chars().all(is_token) {
+			return None;
+		}
+
+		let (subtype, mut rest) = rest.split_once(';').map(|(s, r)| (s, Some(r))).unwrap_or((rest, None));
+		let subtype = subtype.trim_end_matches(is_http_whitespace);
+		if subtype.is_empty() || !subtype.chars().all(is_token) {
+			return None;
+		}
+
+		let mut media_type = MediaType {
+			kind: kind.to_ascii_lowercase(),
+			subtype: subtype.to_ascii_lowercase(),
+			parameters: Vec::new(),
+		};
+
+		while let Some(parameter) = rest {
+			let parameter = parameter.trim_start_matches(is_http_whitespace);
+			let end = parameter.find([';', '=']).unwrap_or(parameter.len());
+			let name = parameter[..end].to_ascii_lowercase();
+			let parameter = &parameter[end..];
+
+			if parameter.is_empty() {
+				break;
+			} else if let Some(next) = parameter.strip_prefix(';') {
+				rest = Some(next);
+				continue;
+			}
+			let parameter = &parameter[1..];
+			if parameter.is_empty() {
+				break;
+			}
+
+			let value = if parameter.starts_with('"') {
+				let (value, remaining) = collect_quoted_string(parameter);
+				rest = remaining.split_once(';').map(|(_, r)| r);
+				value
+			} else {
+				let (value, remaining) =
+					parameter.split_once(';').map(|(v, r)| (v, Some(r))).unwrap_or((parameter, None));
+				rest = remaining;
+				let value = value.trim_end_matches(is_http_whitespace);
+				if value.is_empty() {
+					continue;
+				}
+				String::from(value)
+			};
+
+			if !name.is_empty()
+				&& name.chars().all(is_token)
+				&& value.chars().all(is_quoted_string_token)
+				&& media_type.parameter(&name).is_none()
+			{
+				media_type.parameters.push((name, value));
+			}
+		}
+
+		Some(media_type)
+	}
+
+	/// Extracts a MIME type from the values of a `Content-Type` header, according to
+	/// <https://fetch.spec.whatwg.org/#concept-header-extract-mime-type>.
+	///
+	/// Values are split on commas outside of quoted strings, and the last valid MIME type is returned. Its `charset`
+	/// parameter is taken from an earlier value with the same essence if it does not have one.
+	pub fn extract<'a, I: IntoIterator<Item = &'a str>>(values: I) -> Option<MediaType> {
+		let mut charset = None;
+		let mut essence = None;
+		let mut media_type: Option<MediaType> = None;
+
+		for value in values.into_iter().flat_map(split_header_value) {
+			let Some(mut current) = MediaType::parse(value) else {
+				continue;
+			};
+			if current.kind == "*" && current.subtype == "*" {
+				continue;
+			}
+
+			let current_essence = current.essence();
+			if essence.as_ref() != Some(&current_essence) {
+				charset = current.parameter("charset").map(String::from);
+				essence = Some(current_essence);
+			} else if let Some(charset) = &charset {
+				if current.parameter("charset").is_none() {
+					current.parameters.push((String::from("charset"), charset.clone()));
+				}
+			}
+			media_type = Some(current);
+		}
+
+		media_type
+	}
+
+	/// Returns the type and subtype, without any parameters.
+	pub fn essence(&self) -> String {
+		format!("{}/{}", self.kind, self.subtype)
+	}
+
+	pub fn parameter(&self, name: &str) -> Option<&str> {
+		self.parameters.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str())
+	}
+
+	pub fn is_same_essence(&self, other: &MediaType) -> bool {
+		self.kind == other.kind && self.subtype == other.subtype
+	}
+
+	/// Returns the media type for a file extension, with or without the leading dot.
+	pub fn from_extension(extension: &str) -> Option<MediaType> {
+		let extension = extension.strip_prefix('.').unwrap_or(extension).to_ascii_lowercase();
+		EXTENSIONS
+			.iter()
+			.find(|(ext, _)| *ext == extension)
+			.and_then(|(_, kind)| MediaType::parse(kind))
+	}
+
+	/// Returns the preferred file extension for the essence of this media type.
+	pub fn extension(&self) -> Option<&'static str> {
+		let essence = self.essence();
+		EXTENSIONS.iter().find(|(_, kind)| *kind == essence).map(|(ext, _)| *ext)
+	}
+}
+
+impl Display for MediaType {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "{}/{}", self.kind, self.subtype)?;
+		for (name, value) in &self.parameters {
+			write!(f, ";{name}=")?;
+			if !value.is_empty() && value.chars().all(is_token) {
+				f.write_str(value)?;
+			} else {
+				f.write_char('"')?;
+				for char in value.chars() {
+					if char == '"' || char == '\\' {
+						f.write_char('\\')?;
+					}
+					f.write_char(char)?;
+				}
+				f.write_char('"')?;
+			}
+		}
+		Ok(())
+	}
+}
+
+impl FromStr for MediaType {
+	type Err = Error;
+
+	fn from_str(input: &str) -> Result<MediaType, Error> {
+		MediaType::parse(input).ok_or_else(|| Error::new(format!("Invalid MIME type: {input}"), ErrorKind::Type))
+	}
+}
+
+/// Splits a header value on commas which are not within quoted strings, according to
+/// <https://fetch.spec.whatwg.org/#header-value-get-decode-and-split>.
+fn split_header_value(value: &str) -> impl Iterator<Item = &str> {
+	let mut quoted = false;
+	let mut escaped = false;
+	let mut start = 0;
+	let mut values = Vec::new();
+	for (index, char) in value.char_indices() {
+		match char {
+			_ if escaped => escaped = false,
+			'\\' if quoted => escaped = true,
+			'"' => quoted = !quoted,
+			',' if !quoted => {
+				values.push(&value[start..index]);
+				start = index + 1;
+			}
+			_ => {}
+		}
+	}
+	values.push(&value[start..]);
+	values.into_iter().map(|value| value.trim_matches(is_http_whitespace))
+}
+
+/// Collects an [HTTP quoted string](https://fetch.spec.whatwg.org/#collect-an-http-quoted-string),
+/// returning the unescaped value and the remaining input.
+fn collect_quoted_string(input: &str) -> (String, &str) {
+	let mut value = String::new();
+	let mut chars = input[1..].char_indices();
+	while let Some((index, char)) = chars.next() {
+		match char {
+			'"' => return (value, &input[index + 2..]),
+			'\\' => match chars.next() {
+				Some((_, escaped)) => value.push(escaped),
+				None => {
+					value.push('\\');
+					break;
+				}
+			},
+			char => value.push(char),
+		}
+	}
+	(value, "")
+}
+
+fn is_http_whitespace(char: char) -> bool {
+	matches!(char, '\t' | '\n' | '\r' | ' ')
+}
+
+fn is_token(char: char) -> bool {
+	char.is_ascii_alphanumeric()
+		|| matches!(
+			char,
+			'!' | '#' | '$' | '%' | '&' | '\'' | '*' | '+' | '-' | '.' | '^' | '_' | '`' | '|' | '~'
+		)
+}
+
+fn is_quoted_string_token(char: char) -> bool {
+	matches!(char, '\t' | ' '..='~' | '\u{80}'..='\u{FF}')
+}
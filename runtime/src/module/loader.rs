@@ -5,14 +5,18 @@
  */
 
 use std::collections::hash_map::{Entry, HashMap};
+use std::collections::VecDeque;
 use std::ffi::OsStr;
 use std::fs::read_to_string;
-use std::path::Path;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::thread::available_parallelism;
 
 use dunce::canonicalize;
-use ion::module::{Module, ModuleData, ModuleLoader, ModuleRequest};
+use ion::module::{Module, ModuleData, ModuleLoader, ModuleRequest, PendingModule};
 use ion::{Context, Error, Local, Object, Result, Value};
 use mozjs::jsapi::JSObject;
+use sourcemap::SourceMap;
 use url::Url;
 
 use crate::cache::locate_in_cache;
@@ -22,6 +26,24 @@ use crate::config::Config;
 #[derive(Default)]
 pub struct Loader {
 	registry: HashMap<String, *mut JSObject>,
+	pending: HashMap<PathBuf, PendingModule<Option<SourceMap>>>,
+	queue: VecDeque<PathBuf>,
+}
+
+impl Loader {
+	/// Starts loading queued modules on separate threads, while fewer than the available parallelism are pending.
+	fn load_queued(&mut self, cx: &Context) {
+		let limit = available_parallelism().map_or(1, NonZeroUsize::get);
+		while self.pending.len() < limit {
+			let Some(path) = self.queue.pop_front() else {
+				break;
+			};
+			let source_path = path.clone();
+			let filename = String::from(path.to_str().unwrap());
+			let pending = Module::load_off_thread(cx, &filename, Some(&path), move || load_module(&source_path));
+			self.pending.insert(path, pending);
+		}
+	}
 }
 
 impl ModuleLoader for Loader {
@@ -29,40 +51,53 @@ impl ModuleLoader for Loader {
 		let specifier = request.specifier(cx).to_owned(cx).unwrap();
 		let data = ModuleData::from_private(cx, private);
 
-		let path = if specifier.starts_with("./") || specifier.starts_with("../") {
-			Path::new(data.as_ref().and_then(|d| d.path.as_ref()).unwrap())
-				.parent()
-				.unwrap()
-				.join(&specifier)
-		} else {
-			Path::new(&specifier).to_path_buf()
-		};
+		if let Some(module) = specifier.strip_prefix("node:").and_then(|name| self.registry.get(name)) {
+			return Ok(Module(Object::from(unsafe { Local::from_marked(module) })));
+		}
+
+		let path = resolve_path(data.as_ref().and_then(|d| d.path.as_deref()), &specifier)
+			.ok_or_else(|| Error::new(format!("Unable to resolve module: {specifier}"), None))?;
 
 		let specifier = String::from(path.to_str().unwrap());
 		if let Some(module) = self.registry.get(&specifier) {
-			Ok(Module(Object::from(unsafe { Local::from_marked(module) })))
-		} else if let Ok(script) = read_to_string(&path) {
-			let is_typescript = Config::global().typescript && path.extension() == Some(OsStr::new("ts"));
-			let (script, sourcemap) = is_typescript
-				.then(|| locate_in_cache(&path, &script))
-				.flatten()
-				.map(|(s, sm)| (s, Some(sm)))
-				.unwrap_or_else(|| (script, None));
-			if let Some(sourcemap) = sourcemap {
-				save_sourcemap(&path, sourcemap);
+			return Ok(Module(Object::from(unsafe { Local::from_marked(module) })));
+		}
+
+		let prefetched = match self.pending.remove(&path) {
+			Some(pending) => {
+				let module = pending.finish(cx);
+				self.load_queued(cx);
+				module
 			}
+			None => {
+				self.queue.retain(|queued| queued != &path);
+				None
+			}
+		};
 
-			let module = Module::compile_and_evaluate(cx, &specifier, Some(path.as_path()), &script);
+		let compiled = match prefetched {
+			Some(module) => module,
+			None => {
+				let (script, sourcemap) = load_module(&path)
+					.ok_or_else(|| Error::new(format!("Unable to read module: {specifier}"), None))?;
+				Module::compile(cx, &specifier, Some(path.as_path()), &script).map(|module| (module, sourcemap))
+			}
+		};
 
-			if let Ok((module, _)) = module {
-				let request = ModuleRequest::new(cx, path.to_str().unwrap());
-				self.register(cx, module.0.handle().get(), &request)?;
-				Ok(module)
-			} else {
-				Err(Error::new(format!("Unable to compile module: {specifier}"), None))
+		let module = compiled.and_then(|(module, sourcemap)| {
+			if let Some(sourcemap) = sourcemap {
+				save_sourcemap(&path, sourcemap);
 			}
+			self.prefetch(cx, &module);
+			module.link_and_evaluate(cx)
+		});
+
+		if let Ok((module, _)) = module {
+			let request = ModuleRequest::new(cx, path.to_str().unwrap());
+			self.register(cx, module.0.handle().get(), &request)?;
+			Ok(module)
 		} else {
-			Err(Error::new(format!("Unable to read module: {specifier}"), None))
+			Err(Error::new(format!("Unable to compile module: {specifier}"), None))
 		}
 	}
 
@@ -77,6 +112,27 @@ impl ModuleLoader for Loader {
 		}
 	}
 
+	fn prefetch(&mut self, cx: &Context, module: &Module) {
+		let Ok(specifiers) = module.requested_modules(cx) else {
+			return;
+		};
+		let base = module.data(cx).and_then(|data| data.path);
+
+		for specifier in specifiers {
+			if specifier.starts_with("node:") {
+				continue;
+			}
+			let Some(path) = resolve_path(base.as_deref(), &specifier) else {
+				continue;
+			};
+			let loaded = path.to_str().is_some_and(|path| self.registry.contains_key(path));
+			if !loaded && !self.pending.contains_key(&path) && !self.queue.contains(&path) {
+				self.queue.push_back(path);
+			}
+		}
+		self.load_queued(cx);
+	}
+
 	fn metadata(&self, cx: &Context, private: &Value, meta: &Object) -> Result<()> {
 		let data = ModuleData::from_private(cx, private);
 
@@ -91,3 +147,36 @@ impl ModuleLoader for Loader {
 		Ok(())
 	}
 }
+
+impl Drop for Loader {
+	/// Cancels modules which are still being compiled, so their stencils are released once compilation finishes.
+	fn drop(&mut self) {
+		self.queue.clear();
+		for (_, pending) in self.pending.drain() {
+			pending.cancel();
+		}
+	}
+}
+
+/// Resolves the specifier of a module relative to the path of the module which requested it.
+fn resolve_path(base: Option<&str>, specifier: &str) -> Option<PathBuf> {
+	if specifier.starts_with("./") || specifier.starts_with("../") {
+		Some(Path::new(base?).parent()?.join(specifier))
+	} else {
+		Some(PathBuf::from(specifier))
+	}
+}
+
+/// Reads the source of a module, compiling it to JavaScript if it is written in TypeScript.
+/// This may be called on any thread, so the source map is returned rather than saved.
+fn load_module(path: &Path) -> Option<(String, Option<SourceMap>)> {
+	let script = read_to_string(path).ok()?;
+	let is_typescript = Config::global().typescript && path.extension() == Some(OsStr::new("ts"));
+	Some(
+		is_typescript
+			.then(|| locate_in_cache(path, &script))
+			.flatten()
+			.map(|(s, sm)| (s, Some(sm)))
+			.unwrap_or_else(|| (script, None)),
+	)
+}
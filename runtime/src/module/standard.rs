@@ -6,6 +6,7 @@
 
 use ion::flags::PropertyFlags;
 use ion::module::{Module, ModuleRequest};
+use ion::object::define_lazy_global;
 use ion::{Context, Object};
 
 pub trait StandardModules {
@@ -37,8 +38,9 @@ impl<M: NativeModule> StandardModules for M {
 		init_module::<M>(cx, global)
 	}
 
-	fn init_globals(self, cx: &Context, global: &Object) -> bool {
-		init_global_module::<M>(cx, global)
+	fn init_globals(self, cx: &Context, _: &Object) -> bool {
+		define_lazy_global_module::<M>(cx);
+		true
 	}
 }
 
@@ -68,3 +70,8 @@ pub fn init_global_module<M: NativeModule>(cx: &Context, global: &Object) -> boo
 		.map(|module| global.define_as(cx, M::NAME, &module, PropertyFlags::CONSTANT_ENUMERATED))
 		.unwrap_or_default()
 }
+
+/// Defines the module as a global, which is initialised when it is first accessed.
+pub fn define_lazy_global_module<M: NativeModule>(cx: &Context) {
+	define_lazy_global(cx, &[M::NAME], init_global_module::<M>);
+}
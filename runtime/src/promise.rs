@@ -6,12 +6,27 @@
 
 use std::future::Future;
 
-use ion::conversions::{BoxedIntoValue, IntoValue};
-use ion::{Context, Promise};
+use ion::conversions::IntoValue;
+use ion::{Context, FutureSpawner, Promise, PromiseFuture, SpawnedFuture};
 use tokio::task::spawn_local;
 
 use crate::ContextExt;
 
+/// Spawns futures onto the future queue of the event loop.
+pub(crate) struct EventLoopSpawner;
+
+impl FutureSpawner for EventLoopSpawner {
+	fn spawn(&self, cx: &Context, promise: &Promise, future: SpawnedFuture) -> bool {
+		let object = promise.handle().get();
+		let event_loop = unsafe { &cx.get_private().event_loop };
+		event_loop
+			.futures
+			.as_ref()
+			.map(|futures| futures.enqueue(spawn_local(async move { (future.await, object) })))
+			.is_some()
+	}
+}
+
 /// Returns None if no future queue has been initialised.
 pub fn future_to_promise<'cx, F, O, E>(cx: &'cx Context, future: F) -> Option<Promise<'cx>>
 where
@@ -19,20 +34,11 @@ where
 	O: for<'cx2> IntoValue<'cx2> + 'static,
 	E: for<'cx2> IntoValue<'cx2> + 'static,
 {
-	let promise = Promise::new(cx);
-	let object = promise.handle().get();
-
-	let handle = spawn_local(async move {
-		let result: Result<BoxedIntoValue, BoxedIntoValue> = match future.await {
-			Ok(o) => Ok(Box::new(o)),
-			Err(e) => Err(Box::new(e)),
-		};
-		(result, object)
-	});
+	Promise::spawn_future(cx, future)
+}
 
-	let event_loop = unsafe { &cx.get_private().event_loop };
-	event_loop.futures.as_ref().map(|futures| {
-		futures.enqueue(handle);
-		promise
-	})
+/// Converts a [Promise] into a [Future], which resolves to the settled value once the job queue settles the promise.
+/// `Ok` is returned if the promise is fulfilled, and `Err` if it is rejected.
+pub fn promise_to_future(cx: &Context, promise: &Promise) -> PromiseFuture {
+	promise.to_future(cx)
 }
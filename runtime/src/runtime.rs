@@ -5,24 +5,29 @@
  */
 
 use std::collections::HashMap;
+use std::ffi::CString;
 use std::ptr;
 
+use ion::conversions::{FromValue, ToValue};
 use ion::module::{init_module_loader, ModuleLoader};
 use ion::object::default_new_global;
-use ion::{Context, ContextInner, ErrorReport, Object};
+use ion::{init_future_spawner, Context, ContextInner, ErrorReport, Function, Object};
 use mozjs::gc::Traceable;
 use mozjs::glue::CreateJobQueue;
 use mozjs::jsapi::{
-	ContextOptionsRef, Heap, JSAutoRealm, JSObject, JSTracer, SetJobQueue, SetPromiseRejectionTrackerCallback,
+	ContextOptionsRef, Heap, JSAutoRealm, JSObject, JSTracer, JS_GetRuntime, JS_SetDefaultLocale,
+	SetHostCleanupFinalizationRegistryCallback, SetJobQueue, SetPromiseRejectionTrackerCallback,
 };
 use uuid::Uuid;
 
+use crate::config::{Config, RuntimeConfig, CONFIG};
 use crate::event_loop::future::FutureQueue;
 use crate::event_loop::macrotasks::MacrotaskQueue;
-use crate::event_loop::microtasks::{MicrotaskQueue, JOB_QUEUE_TRAPS};
+use crate::event_loop::microtasks::{enqueue_finalization_registry_cleanup, MicrotaskQueue, JOB_QUEUE_TRAPS};
 use crate::event_loop::{promise_rejection_tracker_callback, EventLoop};
-use crate::globals::{init_globals, init_microtasks, init_timers};
+use crate::globals::{init_globals, init_microtasks, init_timers, process};
 use crate::module::StandardModules;
+use crate::promise::EventLoopSpawner;
 
 #[derive(Default)]
 pub struct ContextPrivate {
@@ -37,6 +42,11 @@ unsafe impl Traceable for ContextPrivate {
 				blob.trace(trc);
 			}
 		}
+		if let Some(microtasks) = &self.event_loop.microtasks {
+			unsafe {
+				microtasks.trace(trc);
+			}
+		}
 	}
 }
 
@@ -75,6 +85,24 @@ impl<'cx> Runtime<'cx> {
 		let event_loop = unsafe { &mut self.cx.get_private().event_loop };
 		event_loop.run_event_loop(self.cx).await
 	}
+
+	/// Returns true if the script has called `process.exit()`.
+	pub fn is_exiting(&self) -> bool {
+		let event_loop = unsafe { &self.cx.get_private().event_loop };
+		event_loop.exit_code.is_some()
+	}
+
+	/// Returns true if a microtask checkpoint has exceeded the microtask budget, deferring the remaining microtasks.
+	pub fn exceeded_microtask_budget(&self) -> bool {
+		let event_loop = unsafe { &self.cx.get_private().event_loop };
+		event_loop.microtasks.as_ref().is_some_and(MicrotaskQueue::exceeded_budget)
+	}
+
+	/// Returns the code passed to `process.exit()`, or the value of `process.exitCode`.
+	pub fn exit_code(&self) -> i32 {
+		let event_loop = unsafe { &self.cx.get_private().event_loop };
+		event_loop.exit_code.unwrap_or_else(|| process::exit_code(self.cx, &self.global))
+	}
 }
 
 impl Drop for Runtime<'_> {
@@ -87,12 +115,31 @@ impl Drop for Runtime<'_> {
 	}
 }
 
+/// Sets the default locale used by `Intl` and locale-sensitive methods, such as `Number.prototype.toLocaleString`.
+/// The locale is validated and canonicalised with `Intl.getCanonicalLocales`, so a realm must be entered.
+/// Returns `false` if the locale is invalid or could not be set.
+pub fn set_default_locale(cx: &Context, locale: &str) -> bool {
+	match canonicalise_locale(cx, locale).and_then(|locale| CString::new(locale).ok()) {
+		Some(locale) => unsafe { JS_SetDefaultLocale(JS_GetRuntime(cx.as_ptr()), locale.as_ptr()) },
+		None => false,
+	}
+}
+
+fn canonicalise_locale(cx: &Context, locale: &str) -> Option<String> {
+	let intl = Object::global(cx).get_as::<_, Object>(cx, "Intl", true, ()).ok()??;
+	let canonicalise = intl.get_as::<_, Function>(cx, "getCanonicalLocales", true, ()).ok()??;
+	let locales = canonicalise.call(cx, &intl, &[locale.as_value(cx)]).ok()?;
+	let mut locales = Vec::<String>::from_value(cx, &locales, true, ()).ok()?;
+	(locales.len() == 1).then(|| locales.remove(0))
+}
+
 #[derive(Copy, Clone, Debug)]
 pub struct RuntimeBuilder<ML: ModuleLoader + 'static = (), Std: StandardModules + 'static = ()> {
 	microtask_queue: bool,
 	macrotask_queue: bool,
 	modules: Option<ML>,
 	standard_modules: Option<Std>,
+	config: RuntimeConfig,
 }
 
 impl<ML: ModuleLoader + 'static, Std: StandardModules + 'static> RuntimeBuilder<ML, Std> {
@@ -120,10 +167,27 @@ impl<ML: ModuleLoader + 'static, Std: StandardModules + 'static> RuntimeBuilder<
 		self
 	}
 
+	/// Sets the [RuntimeConfig] applied to the garbage collector and JIT compilers when the runtime is built.
+	pub fn config(mut self, config: RuntimeConfig) -> RuntimeBuilder<ML, Std> {
+		self.config = config;
+		self
+	}
+
 	pub fn build(self, cx: &mut Context) -> Runtime {
+		self.config.apply(cx);
+		unsafe {
+			(*cx.get_inner_data().as_ptr()).unstable = CONFIG.get().is_some_and(|config| config.unstable);
+		}
+
 		let global = default_new_global(cx);
 		let realm = JSAutoRealm::new(cx.as_ptr(), global.handle().get());
 
+		if let Some(locale) = CONFIG.get().and_then(|config| config.locale.as_deref()) {
+			if !set_default_locale(cx, locale) {
+				eprintln!("Invalid Locale: {locale}");
+			}
+		}
+
 		let global_obj = global.handle().get();
 		global.set_as(cx, "global", &global_obj);
 		init_globals(cx, &global);
@@ -131,9 +195,11 @@ impl<ML: ModuleLoader + 'static, Std: StandardModules + 'static> RuntimeBuilder<
 		let mut private = Box::<ContextPrivate>::default();
 
 		if self.microtask_queue {
-			private.event_loop.microtasks = Some(MicrotaskQueue::default());
+			let budget = CONFIG.get().map_or(Config::default().microtask_budget, |config| config.microtask_budget);
+			private.event_loop.microtasks = Some(MicrotaskQueue::new(budget));
 			init_microtasks(cx, &global);
 			private.event_loop.futures = Some(FutureQueue::default());
+			init_future_spawner(cx, EventLoopSpawner);
 
 			unsafe {
 				SetJobQueue(
@@ -148,6 +214,11 @@ impl<ML: ModuleLoader + 'static, Std: StandardModules + 'static> RuntimeBuilder<
 					Some(promise_rejection_tracker_callback),
 					ptr::null_mut(),
 				);
+				SetHostCleanupFinalizationRegistryCallback(
+					cx.as_ptr(),
+					Some(enqueue_finalization_registry_cleanup),
+					cx.as_ptr().cast(),
+				);
 			}
 		}
 		if self.macrotask_queue {
@@ -183,6 +254,7 @@ impl<ML: ModuleLoader + 'static, Std: StandardModules + 'static> Default for Run
 			macrotask_queue: false,
 			modules: None,
 			standard_modules: None,
+			config: RuntimeConfig::default(),
 		}
 	}
 }
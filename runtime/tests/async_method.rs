@@ -0,0 +1,75 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::path::Path;
+
+use ion::class::Reflector;
+use ion::conversions::FromValue;
+use ion::gc::collect;
+use ion::script::Script;
+use ion::{js_class, ClassDefinition, Context};
+use mozjs::rust::{JSEngine, Runtime};
+use runtime::config::{Config, CONFIG};
+use runtime::RuntimeBuilder;
+use tokio::runtime::Builder;
+use tokio::task::{yield_now, LocalSet};
+
+const FILE_NAME: &str = "async-method.js";
+const SCRIPT: &str = r#"
+globalThis.greetings = [];
+(async () => {
+	greetings.push(await new Greeter("Hello").greet("World"));
+	greetings.push(await new Greeter("Goodbye").greet("Moon"));
+})();
+"#;
+
+#[js_class]
+pub struct Greeter {
+	reflector: Reflector,
+	greeting: String,
+}
+
+#[js_class]
+impl Greeter {
+	#[ion(constructor)]
+	pub fn constructor(greeting: String) -> Greeter {
+		Greeter {
+			reflector: Reflector::default(),
+			greeting,
+		}
+	}
+
+	pub async fn greet(&self, name: String) -> String {
+		yield_now().await;
+		format!("{}, {}!", self.greeting, name)
+	}
+}
+
+#[test]
+fn async_method() {
+	CONFIG.set(Config::default().script(true)).unwrap();
+
+	let engine = JSEngine::init().unwrap();
+	let rt = Runtime::new(engine.handle());
+
+	let cx = &mut Context::from_runtime(&rt);
+	let rt = RuntimeBuilder::<()>::new().microtask_queue().macrotask_queue().build(cx);
+	assert!(Greeter::init_class(rt.cx(), rt.global()).0);
+
+	let result = Script::compile_and_evaluate(rt.cx(), Path::new(FILE_NAME), SCRIPT);
+	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+
+	// The greeter is only referenced by the pending future, which roots it.
+	collect(rt.cx());
+
+	let tokio = Builder::new_current_thread().enable_all().build().unwrap();
+	let result = LocalSet::new().block_on(&tokio, rt.run_event_loop());
+	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+
+	let greetings = Script::compile_and_evaluate(rt.cx(), Path::new(FILE_NAME), "greetings.join()").unwrap();
+	let greetings = String::from_value(rt.cx(), &greetings, true, ()).unwrap();
+	assert_eq!(greetings, "Hello, World!,Goodbye, Moon!");
+}
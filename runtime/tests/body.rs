@@ -0,0 +1,45 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "fetch")]
+
+use std::path::Path;
+
+use ion::script::Script;
+use ion::Context;
+use mozjs::rust::{JSEngine, Runtime};
+use runtime::config::{Config, CONFIG};
+use runtime::RuntimeBuilder;
+use tokio::runtime::Builder;
+use tokio::task::LocalSet;
+
+const FILE_NAME: &str = "body.js";
+const SCRIPT: &str = include_str!("scripts/body.js");
+
+#[test]
+fn body() {
+	CONFIG.set(Config::default().script(true)).unwrap();
+
+	let engine = JSEngine::init().unwrap();
+	let rt = Runtime::new(engine.handle());
+
+	let cx = &mut Context::from_runtime(&rt);
+	let rt = RuntimeBuilder::<()>::new().microtask_queue().macrotask_queue().build(cx);
+
+	let tokio = Builder::new_current_thread().enable_all().build().unwrap();
+	let local = LocalSet::new();
+
+	let result = local.block_on(&tokio, async {
+		Script::compile_and_evaluate(rt.cx(), Path::new(FILE_NAME), SCRIPT)
+	});
+	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+
+	let result = local.block_on(&tokio, rt.run_event_loop());
+	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+
+	let result = Script::compile_and_evaluate(rt.cx(), Path::new(FILE_NAME), "check()");
+	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+}
@@ -0,0 +1,49 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "fetch")]
+
+use std::env;
+use std::fs::remove_dir_all;
+use std::path::Path;
+
+use ion::conversions::FromValue;
+use ion::script::Script;
+use ion::Context;
+use mozjs::rust::{JSEngine, Runtime};
+use runtime::config::{Config, CONFIG};
+use runtime::RuntimeBuilder;
+use tokio::runtime::Builder;
+use tokio::task::LocalSet;
+
+const FILE_NAME: &str = "cache.js";
+const SCRIPT: &str = include_str!("scripts/cache.js");
+
+#[test]
+fn cache() {
+	// Caches are stored in the home directory, which is replaced so that the test does not modify it.
+	let home = env::temp_dir().join(format!("spiderfire-cache-{}", std::process::id()));
+	env::set_var("HOME", &home);
+	CONFIG.set(Config::default().script(true)).unwrap();
+
+	let engine = JSEngine::init().unwrap();
+	let rt = Runtime::new(engine.handle());
+
+	let cx = &mut Context::from_runtime(&rt);
+	let rt = RuntimeBuilder::<()>::new().microtask_queue().macrotask_queue().build(cx);
+
+	let result = Script::compile_and_evaluate(rt.cx(), Path::new(FILE_NAME), SCRIPT);
+	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+
+	let tokio = Builder::new_current_thread().enable_all().build().unwrap();
+	let result = LocalSet::new().block_on(&tokio, rt.run_event_loop());
+	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+
+	let result = Script::compile_and_evaluate(rt.cx(), Path::new(FILE_NAME), "check()");
+	let _ = remove_dir_all(&home);
+	let result = result.unwrap_or_else(|error| panic!("Error: {}", error.format(rt.cx())));
+	assert!(bool::from_value(rt.cx(), &result, true, ()).unwrap());
+}
@@ -0,0 +1,60 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener};
+use std::thread;
+
+/// Request received by the server started with [serve].
+pub struct Request {
+	pub method: String,
+	pub path: String,
+	pub body: Vec<u8>,
+}
+
+/// Starts an HTTP/1.1 server on a separate thread, which responds to each request with the raw response returned by
+/// the handler, then closes the connection. Request bodies must have a `Content-Length`.
+pub fn serve(handler: fn(&Request) -> Vec<u8>) -> SocketAddr {
+	let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+	let address = listener.local_addr().unwrap();
+
+	thread::spawn(move || {
+		for stream in listener.incoming() {
+			let Ok(mut stream) = stream else {
+				continue;
+			};
+			let mut reader = BufReader::new(&mut stream);
+
+			let mut line = String::new();
+			reader.read_line(&mut line).unwrap();
+			let mut parts = line.split_whitespace();
+			let method = String::from(parts.next().unwrap_or_default());
+			let path = String::from(parts.next().unwrap_or_default());
+
+			let mut length = 0;
+			loop {
+				let mut header = String::new();
+				reader.read_line(&mut header).unwrap();
+				if header.trim_end().is_empty() {
+					break;
+				}
+				if let Some((name, value)) = header.split_once(':') {
+					if name.eq_ignore_ascii_case("content-length") {
+						length = value.trim().parse().unwrap();
+					}
+				}
+			}
+
+			let mut body = vec![0; length];
+			reader.read_exact(&mut body).unwrap();
+
+			let response = handler(&Request { method, path, body });
+			let _ = stream.write_all(&response);
+		}
+	});
+
+	address
+}
@@ -0,0 +1,35 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::path::Path;
+
+use ion::script::Script;
+use ion::Context;
+use mozjs::rust::{JSEngine, Runtime};
+use runtime::config::{Config, LogLevel, CONFIG};
+use runtime::RuntimeBuilder;
+
+const FILE_NAME: &str = "config.js";
+const SCRIPT: &str = include_str!("scripts/config.js");
+
+#[test]
+fn config() {
+	// Runtimes can be built without setting the configuration, which then uses the default configuration.
+	let engine = JSEngine::init().unwrap();
+	let rt = Runtime::new(engine.handle());
+
+	let cx = &mut Context::from_runtime(&rt);
+	let rt = RuntimeBuilder::<()>::new().build(cx);
+
+	let result = Script::compile_and_evaluate(rt.cx(), Path::new(FILE_NAME), SCRIPT);
+	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+
+	let config = Config::global();
+	assert_eq!(config.log_level, LogLevel::Error);
+	assert!(!config.allow_env);
+	assert!(config.args.is_empty());
+	assert!(CONFIG.set(Config::default().allow_env(true)).is_err());
+}
@@ -0,0 +1,50 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::path::Path;
+
+use ion::conversions::FromValue;
+use ion::script::Script;
+use ion::{Context, Value};
+use mozjs::rust::{JSEngine, Runtime};
+use runtime::config::{Config, LogFormat, LogLevel, CONFIG};
+use runtime::globals::console::format_record;
+use runtime::RuntimeBuilder;
+
+const FILE_NAME: &str = "console-record.js";
+const SCRIPT: &str = include_str!("scripts/console-record.js");
+
+#[test]
+fn console_record() {
+	CONFIG
+		.set(Config::default().log_level(LogLevel::Debug).log_format(LogFormat::Json).script(true))
+		.unwrap();
+
+	let engine = JSEngine::init().unwrap();
+	let rt = Runtime::new(engine.handle());
+
+	let cx = &mut Context::from_runtime(&rt);
+	let rt = RuntimeBuilder::<()>::new().build(cx);
+
+	let values = Script::compile_and_evaluate(rt.cx(), Path::new(FILE_NAME), SCRIPT).unwrap();
+	let values = Vec::<Value>::from_value(rt.cx(), &values, true, ()).unwrap();
+
+	let record = format_record(rt.cx(), LogLevel::Warn, "Prefix: ", &values, None);
+	rt.global().set_as(rt.cx(), "record", &record);
+	let result = Script::compile_and_evaluate(rt.cx(), Path::new(FILE_NAME), "check(record)");
+	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+
+	let record = format_record(
+		rt.cx(),
+		LogLevel::Debug,
+		"Trace: ",
+		&values[..2],
+		Some("trace@console-record.js:1:1"),
+	);
+	rt.global().set_as(rt.cx(), "record", &record);
+	let result = Script::compile_and_evaluate(rt.cx(), Path::new(FILE_NAME), "checkTrace(record)");
+	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+}
@@ -0,0 +1,76 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+use std::{env, fs};
+
+use runtime::env::{load_env_files, parse_env};
+
+const SOURCE: &str = r#"# Comment
+export FIRST=1 # Inline Comment
+SECOND='$FIRST'
+THIRD="${FIRST}\n$SECOND ${SPIDERFIRE_MISSING:-default} \$FIRST"
+FOURTH=a#b
+FIFTH="multiple
+lines"
+"#;
+
+#[test]
+fn env() {
+	let mut variables = HashMap::new();
+	parse_env(SOURCE, &mut variables, false).unwrap();
+
+	assert_eq!(variables["FIRST"], "1");
+	assert_eq!(variables["SECOND"], "$FIRST");
+	assert_eq!(variables["THIRD"], "1\n$FIRST default $FIRST");
+	assert_eq!(variables["FOURTH"], "a#b");
+	assert_eq!(variables["FIFTH"], "multiple\nlines");
+
+	assert_eq!(
+		parse_env("FIRST=\"1\" 2", &mut variables, false),
+		Err((1, "Unexpected characters after quoted value"))
+	);
+	assert_eq!(
+		parse_env("\n1FIRST=1", &mut variables, false),
+		Err((2, "Expected variable name"))
+	);
+}
+
+#[test]
+fn precedence() {
+	env::set_var("SPIDERFIRE_ENV_PRECEDENCE", "environment");
+	let source = "SPIDERFIRE_ENV_PRECEDENCE=file\nREFERENCE=$SPIDERFIRE_ENV_PRECEDENCE";
+
+	let mut variables = HashMap::new();
+	parse_env(source, &mut variables, false).unwrap();
+	assert_eq!(variables["REFERENCE"], "environment");
+
+	parse_env(source, &mut variables, true).unwrap();
+	assert_eq!(variables["REFERENCE"], "file");
+}
+
+#[test]
+fn load() {
+	let directory = env::temp_dir().join(format!("spiderfire-env-{}", std::process::id()));
+	fs::create_dir_all(&directory).unwrap();
+	let first = directory.join("first.env");
+	let second = directory.join("second.env");
+	fs::write(&first, "SPIDERFIRE_ENV_KEPT=first\nSPIDERFIRE_ENV_LATER=first\n").unwrap();
+	fs::write(&second, "SPIDERFIRE_ENV_LATER=second\nSPIDERFIRE_ENV_EXISTING=file\n").unwrap();
+
+	env::set_var("SPIDERFIRE_ENV_EXISTING", "environment");
+	load_env_files(&[&first, &second], false).unwrap();
+	assert_eq!(env::var("SPIDERFIRE_ENV_KEPT").unwrap(), "first");
+	assert_eq!(env::var("SPIDERFIRE_ENV_LATER").unwrap(), "second");
+	assert_eq!(env::var("SPIDERFIRE_ENV_EXISTING").unwrap(), "environment");
+
+	load_env_files(&[&second], true).unwrap();
+	assert_eq!(env::var("SPIDERFIRE_ENV_EXISTING").unwrap(), "file");
+
+	let missing = directory.join("missing.env");
+	assert!(load_env_files(&[&missing], false).is_err());
+	fs::remove_dir_all(&directory).unwrap();
+}
@@ -0,0 +1,48 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::path::Path;
+
+use ion::conversions::FromValue;
+use ion::gc::collect;
+use ion::script::Script;
+use ion::Context;
+use mozjs::rust::{JSEngine, Runtime};
+use runtime::config::{Config, CONFIG};
+use runtime::RuntimeBuilder;
+use tokio::runtime::Builder;
+
+const FILE_NAME: &str = "finalization.js";
+const SCRIPT: &str = include_str!("scripts/finalization.js");
+
+#[test]
+fn finalization_registry() {
+	CONFIG.set(Config::default().script(true)).unwrap();
+
+	let engine = JSEngine::init().unwrap();
+	let rt = Runtime::new(engine.handle());
+
+	let cx = &mut Context::from_runtime(&rt);
+	let rt = RuntimeBuilder::<()>::new().microtask_queue().build(cx);
+
+	let result = Script::compile_and_evaluate(rt.cx(), Path::new(FILE_NAME), SCRIPT);
+	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+
+	// Collects the target, which queues the cleanup of the registry.
+	collect(rt.cx());
+
+	// The queued cleanup job is the only reference left to the registry, so it must survive this collection.
+	Script::compile_and_evaluate(rt.cx(), Path::new(FILE_NAME), "registry = null").unwrap();
+	collect(rt.cx());
+
+	let tokio = Builder::new_current_thread().enable_time().build().unwrap();
+	let result = tokio.block_on(rt.run_event_loop());
+	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+
+	let cleaned = Script::compile_and_evaluate(rt.cx(), Path::new(FILE_NAME), "cleaned.join()").unwrap();
+	let cleaned = String::from_value(rt.cx(), &cleaned, true, ()).unwrap();
+	assert_eq!(cleaned, "held");
+}
@@ -0,0 +1,45 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::path::Path;
+
+use ion::conversions::FromValue;
+use ion::script::Script;
+use ion::Context;
+use mozjs::rust::{JSEngine, Runtime};
+use runtime::config::{Config, CONFIG};
+use runtime::{set_default_locale, RuntimeBuilder};
+
+const FILE_NAME: &str = "locale.js";
+
+fn evaluate(cx: &Context, source: &str) -> String {
+	let result = Script::compile_and_evaluate(cx, Path::new(FILE_NAME), source).unwrap();
+	String::from_value(cx, &result, true, ()).unwrap()
+}
+
+#[test]
+fn locale() {
+	CONFIG.set(Config::default().locale(Some(String::from("de-de")))).unwrap();
+
+	let engine = JSEngine::init().unwrap();
+	let rt = Runtime::new(engine.handle());
+
+	let cx = &mut Context::from_runtime(&rt);
+	let rt = RuntimeBuilder::<()>::new().build(cx);
+	let cx = rt.cx();
+
+	let locale = "new Intl.NumberFormat().resolvedOptions().locale";
+	assert_eq!(evaluate(cx, locale), "de-DE");
+	assert_eq!(evaluate(cx, "(1234.5).toLocaleString()"), "1.234,5");
+
+	assert!(!set_default_locale(cx, ""));
+	assert!(!set_default_locale(cx, "not a locale"));
+	assert!(!set_default_locale(cx, "en\0GB"));
+	assert_eq!(evaluate(cx, locale), "de-DE");
+
+	assert!(set_default_locale(cx, "EN-gb"));
+	assert_eq!(evaluate(cx, locale), "en-GB");
+}
@@ -0,0 +1,50 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::path::Path;
+
+use ion::conversions::FromValue;
+use ion::script::Script;
+use ion::Context;
+use mozjs::rust::{JSEngine, Runtime};
+use runtime::config::{Config, CONFIG};
+use runtime::RuntimeBuilder;
+use tokio::runtime::Builder;
+
+const FILE_NAME: &str = "microtask-budget.js";
+const SCRIPT: &str = r#"
+globalThis.count = 0;
+function chain() {
+	if (++count < 100) {
+		Promise.resolve().then(chain);
+	}
+}
+chain();
+"#;
+
+#[test]
+fn microtask_budget() {
+	CONFIG.set(Config::default().script(true).microtask_budget(10)).unwrap();
+
+	let engine = JSEngine::init().unwrap();
+	let rt = Runtime::new(engine.handle());
+
+	let cx = &mut Context::from_runtime(&rt);
+	let rt = RuntimeBuilder::<()>::new().microtask_queue().macrotask_queue().build(cx);
+
+	let result = Script::compile_and_evaluate(rt.cx(), Path::new(FILE_NAME), SCRIPT);
+	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+	assert!(!rt.exceeded_microtask_budget());
+
+	let tokio = Builder::new_current_thread().enable_time().build().unwrap();
+	let result = tokio.block_on(rt.run_event_loop());
+	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+
+	// The chain of promises is deferred across checkpoints, but still runs to completion.
+	assert!(rt.exceeded_microtask_budget());
+	let count = Script::compile_and_evaluate(rt.cx(), Path::new(FILE_NAME), "count").unwrap();
+	assert_eq!(f64::from_value(rt.cx(), &count, true, ()).unwrap(), 100.0);
+}
@@ -0,0 +1,118 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use runtime::mime::MediaType;
+
+fn parse(input: &str) -> MediaType {
+	MediaType::parse(input).unwrap_or_else(|| panic!("Failed to parse {input:?}"))
+}
+
+#[test]
+fn parameters() {
+	let mime = parse(" Text/HTML; Charset=UTF-8 ;level=1");
+	assert_eq!(mime.kind, "text");
+	assert_eq!(mime.subtype, "html");
+	assert_eq!(mime.essence(), "text/html");
+	assert_eq!(mime.parameter("charset"), Some("UTF-8"));
+	assert_eq!(mime.parameter("level"), Some("1"));
+	assert_eq!(mime.to_string(), "text/html;charset=UTF-8;level=1");
+
+	assert_eq!(parse("text/plain;charset=a;charset=b").parameter("charset"), Some("a"));
+	assert_eq!(
+		parse("text/plain;flag;a=b").parameters,
+		[(String::from("a"), String::from("b"))]
+	);
+	assert_eq!(
+		parse("text/plain;a=;b=c").parameters,
+		[(String::from("b"), String::from("c"))]
+	);
+	assert_eq!(
+		parse("text/plain;a b=c;d=e").parameters,
+		[(String::from("d"), String::from("e"))]
+	);
+	assert!(parse("text/plain;").parameters.is_empty());
+}
+
+#[test]
+fn quoting() {
+	let mime = parse(r#"text/plain;name="a\"b\\c";empty="""#);
+	assert_eq!(mime.parameter("name"), Some(r#"a"b\c"#));
+	assert_eq!(mime.parameter("empty"), Some(""));
+	assert_eq!(mime.to_string(), r#"text/plain;name="a\"b\\c";empty="""#);
+
+	let mime = parse(r#"text/plain;a="b" ignored;c=d"#);
+	assert_eq!(mime.parameter("a"), Some("b"));
+	assert_eq!(mime.parameter("c"), Some("d"));
+
+	assert_eq!(parse(r#"text/plain;a="b;c""#).parameter("a"), Some("b;c"));
+	assert_eq!(
+		parse(r#"text/plain;a="unterminated"#).parameter("a"),
+		Some("unterminated")
+	);
+	assert_eq!(parse(r#"text/plain;a="b c""#).to_string(), r#"text/plain;a="b c""#);
+}
+
+#[test]
+fn invalid() {
+	for input in [
+		"",
+		"text",
+		"/plain",
+		"text/",
+		"te xt/plain",
+		"text/pl ain",
+		"text/plain/html",
+		"text/plain\u{0}",
+		"tèxt/plain",
+	] {
+		assert!(MediaType::parse(input).is_none(), "Parsed {input:?}");
+	}
+
+	assert!("text".parse::<MediaType>().is_err());
+	assert_eq!(parse(r#"text/plain;a="é""#).parameter("a"), Some("é"));
+	assert!(parse("text/plain;a=\u{100}").parameters.is_empty());
+}
+
+#[test]
+fn extract() {
+	let extract = |values: &[&str]| MediaType::extract(values.iter().copied()).map(|mime| mime.to_string());
+
+	assert_eq!(extract(&[]), None);
+	assert_eq!(extract(&["invalid"]), None);
+	assert_eq!(extract(&["*/*"]), None);
+	assert_eq!(extract(&["text/html, */*"]).as_deref(), Some("text/html"));
+	assert_eq!(extract(&["text/html", "invalid"]).as_deref(), Some("text/html"));
+	assert_eq!(
+		extract(&["text/plain;charset=gbk", "text/plain"]).as_deref(),
+		Some("text/plain;charset=gbk")
+	);
+	assert_eq!(
+		extract(&["text/plain;charset=gbk, text/plain;charset=utf-8"]).as_deref(),
+		Some("text/plain;charset=utf-8")
+	);
+	assert_eq!(
+		extract(&["text/plain;charset=gbk, text/html"]).as_deref(),
+		Some("text/html")
+	);
+	assert_eq!(
+		extract(&[r#"text/plain;charset="a,b""#]).as_deref(),
+		Some(r#"text/plain;charset="a,b""#)
+	);
+}
+
+#[test]
+fn extensions() {
+	assert_eq!(
+		MediaType::from_extension("JSON").map(|mime| mime.essence()).as_deref(),
+		Some("application/json")
+	);
+	assert_eq!(
+		MediaType::from_extension(".html").map(|mime| mime.essence()).as_deref(),
+		Some("text/html")
+	);
+	assert!(MediaType::from_extension("unknown").is_none());
+	assert_eq!(parse("image/jpeg;quality=1").extension(), Some("jpeg"));
+}
@@ -29,4 +29,7 @@ fn modules() {
 	let path = format!("./tests/scripts/{FILE_NAME}");
 	let result = Module::compile(rt.cx(), FILE_NAME, Some(Path::new(&path)), SCRIPT);
 	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+
+	let result = result.unwrap().link_and_evaluate(rt.cx());
+	assert!(result.is_ok(), "Error: {:?}", result.err());
 }
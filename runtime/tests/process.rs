@@ -0,0 +1,36 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::env;
+use std::path::Path;
+
+use ion::script::Script;
+use ion::Context;
+use mozjs::rust::{JSEngine, Runtime};
+use runtime::config::{Config, CONFIG};
+use runtime::RuntimeBuilder;
+
+const FILE_NAME: &str = "process.js";
+const SCRIPT: &str = include_str!("scripts/process.js");
+
+#[test]
+fn process() {
+	let args = vec![String::from("first"), String::from("second")];
+	CONFIG.set(Config::default().script(true).allow_env(true).args(args)).unwrap();
+	env::set_var("SPIDERFIRE_PROCESS_EXISTING", "existing");
+
+	let engine = JSEngine::init().unwrap();
+	let rt = Runtime::new(engine.handle());
+
+	let cx = &mut Context::from_runtime(&rt);
+	let rt = RuntimeBuilder::<()>::new().build(cx);
+
+	let result = Script::compile_and_evaluate(rt.cx(), Path::new(FILE_NAME), SCRIPT);
+	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+
+	assert_eq!(env::var("SPIDERFIRE_PROCESS_SET").unwrap(), "set");
+	assert!(env::var_os("SPIDERFIRE_PROCESS_EXISTING").is_none());
+}
@@ -0,0 +1,66 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "fetch")]
+
+use std::path::Path;
+
+use ion::script::Script;
+use ion::Context;
+use mozjs::rust::{JSEngine, Runtime};
+use runtime::config::{Config, CONFIG};
+use runtime::RuntimeBuilder;
+use tokio::runtime::Builder;
+use tokio::task::LocalSet;
+
+mod common;
+
+const FILE_NAME: &str = "response-type.js";
+const SCRIPT: &str = include_str!("scripts/response-type.js");
+
+fn respond(request: &common::Request) -> Vec<u8> {
+	let path = request.path.split('#').next().unwrap();
+	let response = match path {
+		"/redirect" => "HTTP/1.1 302 Found\r\nLocation: /headers\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+		"/wildcard" => concat!(
+			"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nX-Secret: secret\r\n",
+			"Access-Control-Expose-Headers: *\r\nConnection: close\r\n\r\n"
+		),
+		_ => concat!(
+			"HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 5\r\n",
+			"X-Exposed: exposed\r\nX-Secret: secret\r\nSet-Cookie: key=value\r\n",
+			"Access-Control-Expose-Headers: X-Exposed\r\nConnection: close\r\n\r\nhello"
+		),
+	};
+	response.as_bytes().to_vec()
+}
+
+#[test]
+fn response_type() {
+	CONFIG.set(Config::default().script(true)).unwrap();
+	let address = common::serve(respond);
+
+	let engine = JSEngine::init().unwrap();
+	let rt = Runtime::new(engine.handle());
+
+	let cx = &mut Context::from_runtime(&rt);
+	let rt = RuntimeBuilder::<()>::new().microtask_queue().macrotask_queue().build(cx);
+	rt.global().set_as(rt.cx(), "url", &format!("http://{address}"));
+
+	let tokio = Builder::new_current_thread().enable_all().build().unwrap();
+	let local = LocalSet::new();
+
+	let result = local.block_on(&tokio, async {
+		Script::compile_and_evaluate(rt.cx(), Path::new(FILE_NAME), SCRIPT)
+	});
+	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+
+	let result = local.block_on(&tokio, rt.run_event_loop());
+	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+
+	let result = Script::compile_and_evaluate(rt.cx(), Path::new(FILE_NAME), "check()");
+	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+}
@@ -0,0 +1,38 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use ion::gc::parameter;
+use ion::Context;
+use mozjs::jsapi::JSGCParamKey;
+use mozjs::rust::{JSEngine, Runtime};
+use runtime::config::{Config, RuntimeConfig, CONFIG};
+use runtime::RuntimeBuilder;
+
+const MEGABYTE: u64 = 1024 * 1024;
+
+/// Builds a runtime with the configuration, and returns its allocation threshold and maximum heap size.
+fn heap_parameters(engine: &JSEngine, config: RuntimeConfig) -> (u32, u32) {
+	let rt = Runtime::new(engine.handle());
+	let cx = &mut Context::from_runtime(&rt);
+	let rt = RuntimeBuilder::<()>::new().config(config).build(cx);
+	(
+		parameter(rt.cx(), JSGCParamKey::JSGC_ALLOCATION_THRESHOLD),
+		parameter(rt.cx(), JSGCParamKey::JSGC_MAX_BYTES),
+	)
+}
+
+#[test]
+fn heap_limits() {
+	CONFIG.set(Config::default()).unwrap();
+	let engine = JSEngine::init().unwrap();
+
+	let config = RuntimeConfig::default().initial_heap_bytes(32 * MEGABYTE).max_heap_bytes(256 * MEGABYTE);
+	assert_eq!(heap_parameters(&engine, config), (32, 256 << 20));
+
+	// The initial heap is rounded up to whole megabytes, and the maximum is clamped to what SpiderMonkey accepts.
+	let config = RuntimeConfig::default().initial_heap_bytes(16 * MEGABYTE + 1).max_heap_bytes(8 << 30);
+	assert_eq!(heap_parameters(&engine, config), (17, u32::MAX));
+}
@@ -0,0 +1,102 @@
+function assert(condition, message) {
+	if (!condition) {
+		throw new Error(message);
+	}
+}
+
+async function assertRejects(promise, message) {
+	try {
+		await promise;
+	} catch (error) {
+		assert(error instanceof TypeError, `${message}: Expected TypeError, Received ${error}`);
+		return;
+	}
+	throw new Error(`${message}: Expected TypeError`);
+}
+
+const url = "https://example.com/";
+const completed = [];
+
+function test(name, callback) {
+	callback().then(() => completed.push(name));
+}
+
+test("multipart", async () => {
+	const body = [
+		"--boundary",
+		'Content-Disposition: form-data; name="field"',
+		"",
+		"value",
+		"--boundary",
+		'Content-Disposition: form-data; name="quoted%22name"; filename="file.txt"',
+		"Content-Type: Text/Plain",
+		"",
+		"line 1\r\nline 2",
+		"--boundary",
+		"content-disposition: form-data; name=unquoted",
+		"",
+		"",
+		"--boundary--",
+		"",
+	].join("\r\n");
+	const headers = { "Content-Type": "multipart/form-data; boundary=boundary" };
+
+	for (const message of [new Request(url, { method: "POST", body, headers }), new Response(body, { headers })]) {
+		const formData = await message.formData();
+		assert(formData.get("field") === "value", "Multipart field did not match");
+		assert(formData.get("unquoted") === "", "Unquoted multipart field did not match");
+
+		const file = formData.get('quoted"name');
+		assert(file instanceof File, "Multipart file was not parsed as a file");
+		assert(file.name === "file.txt", "Multipart file name did not match");
+		assert(file.type === "text/plain", "Multipart file type was not lowercased");
+		assert((await file.text()) === "line 1\r\nline 2", "Multipart file contents did not match");
+	}
+
+	const invalid = "--boundary\r\nContent-Disposition: form-data\r\n\r\nvalue\r\n--boundary--";
+	await assertRejects(new Response(invalid, { headers }).formData(), "Multipart field without a name");
+	await assertRejects(new Response("value", { headers }).formData(), "Multipart body without a delimiter");
+});
+
+test("urlencoded", async () => {
+	const headers = { "Content-Type": "application/x-www-form-urlencoded" };
+	const body = "a=1&b=two+words&a=%26";
+	const formData = await new Request(url, { method: "POST", body, headers }).formData();
+	assert(formData.getAll("a").join() === "1,&", "URL-encoded fields did not match");
+	assert(formData.get("b") === "two words", "URL-encoded spaces were not decoded");
+
+	const params = await new Response(new URLSearchParams({ key: "value" })).formData();
+	assert(params.get("key") === "value", "URLSearchParams body did not match");
+
+	await assertRejects(new Response("a=1", { headers: { "Content-Type": "text/plain" } }).formData(), "Plain text body");
+});
+
+test("request", async () => {
+	const text = new Request(url, { method: "POST", body: "body" });
+	assert(!text.bodyUsed, "Unread request body was used");
+	assert((await text.text()) === "body", "Request text did not match");
+	assert(text.bodyUsed, "Read request body was not used");
+	await assertRejects(text.text(), "Reading a used request body");
+
+	const json = new Request(url, { method: "POST", body: '{"key":[1,2]}' });
+	const value = await json.json();
+	assert(value.key.join() === "1,2", "Request JSON did not match");
+	await assertRejects(json.arrayBuffer(), "Reading a used request body as an ArrayBuffer");
+
+	const empty = new Request(url);
+	assert((await empty.text()) === "", "Request without a body was not empty");
+	assert(!empty.bodyUsed, "Request without a body was used");
+
+	try {
+		await new Request(url, { method: "POST", body: "[" }).json();
+		throw new Error("Invalid JSON was parsed");
+	} catch (error) {
+		assert(error instanceof SyntaxError, `Invalid JSON: Expected SyntaxError, Received ${error}`);
+	}
+});
+
+function check() {
+	for (const name of ["multipart", "urlencoded", "request"]) {
+		assert(completed.includes(name), `Test ${name} did not complete`);
+	}
+}
@@ -0,0 +1,74 @@
+function assert(condition, message) {
+	if (!condition) {
+		throw new Error(message);
+	}
+}
+
+async function assertRejects(promise, type, message) {
+	try {
+		await promise;
+	} catch (error) {
+		assert(error instanceof type, `${message}: Expected ${type.name}, Received ${error}`);
+		return;
+	}
+	throw new Error(`${message}: Expected ${type.name}`);
+}
+
+const url = "https://example.com/resource?query";
+let completed = false;
+let failure = null;
+
+async function test() {
+	const cache = await caches.open("test");
+	assert(await caches.has("test"), "Opened cache did not exist");
+	assert(!(await caches.has("missing")), "Missing cache existed");
+
+	await cache.put(url, new Response("cached", { headers: { "Content-Type": "text/plain" } }));
+	const response = await cache.match(url);
+	assert(response.headers.get("Content-Type") === "text/plain", "Cached headers did not match");
+	assert((await response.text()) === "cached", "Cached body did not match");
+
+	// Replacing an entry keeps a single entry for the URL.
+	await cache.put(new Request(url), new Response("replaced"));
+	assert((await (await cache.match(url)).text()) === "replaced", "Cached body was not replaced");
+
+	assert((await cache.match("https://example.com/resource")) === undefined, "Search was not matched");
+	const ignored = await cache.match("https://example.com/resource", { ignoreSearch: true });
+	assert(ignored !== undefined, "Search was not ignored");
+	assert((await cache.match(url, { ignoreMethod: false })) !== undefined, "GET request was not matched");
+	const head = new Request(url, { method: "HEAD" });
+	assert((await cache.match(head)) === undefined, "HEAD request was matched");
+	assert((await cache.match(head, { ignoreMethod: true })) !== undefined, "HEAD request ignoring method was not matched");
+
+	const keys = await cache.keys();
+	assert(keys.length === 1 && keys[0].url === url, "Cached requests did not match");
+	assert((await cache.matchAll()).length === 1, "Cached responses did not match");
+	assert((await caches.match(url, { cacheName: "test" })) !== undefined, "Cache storage did not match");
+
+	const used = new Response("used");
+	await used.text();
+	await assertRejects(cache.put(url, used), TypeError, "Used body was cached");
+	await assertRejects(cache.put(url, new Response("partial", { status: 206 })), TypeError, "Partial response was cached");
+	await assertRejects(cache.put(new Request(url, { method: "POST" }), new Response()), TypeError, "POST request was cached");
+
+	assert(await cache.delete(url), "Entry was not deleted");
+	assert(!(await cache.delete(url)), "Deleted entry was deleted again");
+	assert((await cache.keys()).length === 0, "Deleted entry remained");
+
+	assert((await caches.keys()).includes("test"), "Cache name was not listed");
+	assert(await caches.delete("test"), "Cache was not deleted");
+	assert(!(await caches.has("test")), "Deleted cache existed");
+
+	completed = true;
+}
+
+test().catch(error => {
+	failure = error;
+});
+
+function check() {
+	if (failure !== null) {
+		throw failure;
+	}
+	return completed;
+}
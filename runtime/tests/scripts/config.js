@@ -0,0 +1,15 @@
+function assert(condition, message) {
+	if (!condition) {
+		throw new Error(message);
+	}
+}
+
+assert(Array.isArray(process.args) && process.args.length === 0, "Arguments were not empty");
+assert(process.exitCode === 0, "Exit code was not 0");
+
+try {
+	process.env.get("PATH");
+	assert(false, "Environment was accessible without permission");
+} catch (error) {
+	assert(error.message.includes("--allow-env"), `Unexpected error: ${error.message}`);
+}
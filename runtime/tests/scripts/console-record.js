@@ -0,0 +1,37 @@
+function assert(condition, message) {
+	if (!condition) {
+		throw new Error(message);
+	}
+}
+
+const error = new Error("failure");
+
+function check(json) {
+	const record = JSON.parse(json);
+	const keys = Object.keys(record).join();
+	assert(keys === "timestamp,level,message,args,stack", `Record had keys ${keys}`);
+
+	assert(/^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}\.\d{3}Z$/.test(record.timestamp), "Timestamp was not in RFC 3339");
+	assert(record.level === "warn", `Level was ${record.level}`);
+	assert(record.message.startsWith("Prefix: text 1 "), `Message was ${record.message}`);
+
+	const [text, number, object, exception, symbol, missing] = record.args;
+	assert(record.args.length === 6, "Arguments were not all recorded");
+	assert(text === "text" && number === 1, "Primitives were not recorded as JSON values");
+	assert(JSON.stringify(object) === '{"a":[true,null]}', "Object was not recorded as a JSON value");
+	assert(exception.name === "Error" && exception.message === "failure", "Error was not recorded");
+	assert(exception.stack === error.stack, "Stack of the error was not recorded");
+	assert(symbol === "Symbol(symbol)", `Symbol was recorded as ${symbol}`);
+	assert(missing === "undefined", `Undefined was recorded as ${missing}`);
+
+	assert(record.stack === error.stack, "Stack of the record was not that of the error");
+}
+
+function checkTrace(json) {
+	const record = JSON.parse(json);
+	assert(record.level === "debug", `Level was ${record.level}`);
+	assert(record.message === "Trace: text 1", `Message was ${record.message}`);
+	assert(record.stack === "trace@console-record.js:1:1", "Stack of the trace was not recorded");
+}
+
+["text", 1, { a: [true, null] }, error, Symbol("symbol"), undefined];
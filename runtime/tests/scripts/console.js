@@ -2,12 +2,17 @@ console.log("Log", true, false);
 console.info("Info", "Information", 0, -4e8, 2 ** 16);
 console.warn("Warn", "Warning", undefined, [], [3, false, "String"]);
 console.error("Error", null, {}, {"key": "value"}, /^\d{8}$/gi);
+console.log("%s is %i years old and %.2f metres tall: %j %o", "Bob", 42, 1.8, {"key": "value"}, [1, 2]);
 console.debug("Debug", {"key": {"obj": "Object", "date": new Date()}}, function debug() {});
 
 console.assert();
 console.assert(true);
 console.assert(false, "Assertion:", true, "Time -", new Date());
 
+console.assert(0, {"key": "value"});
+
+console.dir({"a": {"b": {"c": {"d": {}}}}}, {depth: 2, showHidden: true});
+
 console.clear();
 
 function trace() {
@@ -0,0 +1,34 @@
+function assert(condition, message) {
+	if (!condition) {
+		throw new Error(message);
+	}
+}
+
+const states = [];
+const messages = [];
+const ids = [];
+
+const source = new EventSource(`${url}/events`);
+source.onopen = () => states.push(source.readyState);
+source.onmessage = event => messages.push(event.data);
+source.addEventListener("message", event => {
+	ids.push(event.lastEventId);
+	if (ids.length === 2) {
+		source.close();
+	}
+});
+
+function check() {
+	assert(states.length === 1 && states[0] === EventSource.OPEN, "Event source was not opened once");
+	assert(messages.join() === "a,b", `Received messages ${messages.join()}`);
+	assert(ids.join() === ",1", `Received event IDs ${ids.join()}`);
+	assert(source.readyState === EventSource.CLOSED, "Event source was not closed");
+}
+
+function throwing() {
+	const source = new EventSource(`${url}/events`);
+	source.onmessage = () => {
+		source.close();
+		throw new Error("Event Listener Error");
+	};
+}
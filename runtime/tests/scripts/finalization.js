@@ -0,0 +1,4 @@
+const cleaned = [];
+
+let registry = new FinalizationRegistry(held => cleaned.push(held));
+registry.register({}, "held");
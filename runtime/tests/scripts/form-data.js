@@ -0,0 +1,30 @@
+function assert(condition, message) {
+	if (!condition) {
+		throw new Error(message);
+	}
+}
+
+const formData = new FormData();
+formData.append("name", "first");
+formData.append("name", "second");
+formData.append("blob", new Blob(["blob"], { type: "text/plain" }));
+formData.append("file", new File(["file"], "file.txt"), "renamed.txt");
+
+assert(formData.get("name") === "first", "FormData did not get first entry");
+assert(formData.getAll("name").join() === "first,second", "FormData did not get all entries");
+assert(formData.get("missing") === null, "FormData got missing entry");
+
+const blob = formData.get("blob");
+assert(blob instanceof File, "FormData did not convert Blob to File");
+assert(blob.name === "blob", "FormData did not name Blob entry");
+assert(blob.type === "text/plain", "FormData did not keep Blob type");
+assert(formData.get("file").name === "renamed.txt", "FormData did not rename File entry");
+
+formData.set("name", "third");
+assert(formData.getAll("name").join() === "third", "FormData did not replace entries");
+
+formData.delete("blob");
+assert(!formData.has("blob"), "FormData did not delete entry");
+
+const names = [...formData].map(([name]) => name);
+assert(names.join() === "name,file", "FormData did not iterate entries in order");
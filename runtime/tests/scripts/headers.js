@@ -0,0 +1,55 @@
+function assert(condition, message) {
+	if (!condition) {
+		throw new Error(message);
+	}
+}
+
+function assertThrows(callback, message) {
+	try {
+		callback();
+	} catch (error) {
+		assert(error instanceof TypeError, `${message}: Expected TypeError, Received ${error}`);
+		return;
+	}
+	throw new Error(`${message}: Expected TypeError`);
+}
+
+const headers = new Headers({ "Host": "example.com" });
+headers.append("Set-Cookie", "cookie=value");
+headers.set("X-HTTP-Method-Override", "trace");
+assert(headers.get("host") === "example.com", "Headers removed forbidden request header");
+assert(headers.get("set-cookie") === "cookie=value", "Headers removed forbidden response header");
+assert(headers.get("x-http-method-override") === "trace", "Headers removed method override header");
+
+assertThrows(() => headers.append("Invalid Name", "value"), "Invalid header name");
+assertThrows(() => headers.get("Invalid Name"), "Invalid header name");
+
+headers.append("X-Padded", "  value  ");
+assert(headers.get("x-padded") === "value", "Headers did not normalise header value");
+
+const request = new Request("https://example.com/");
+request.headers.append("Host", "example.org");
+request.headers.set("Cookie", "cookie=value");
+request.headers.append("Sec-Custom", "value");
+request.headers.append("X-Method-Override", "connect");
+request.headers.append("X-Custom", "value");
+assert(!request.headers.has("host"), "Request headers appended forbidden header");
+assert(!request.headers.has("cookie"), "Request headers set forbidden header");
+assert(!request.headers.has("sec-custom"), "Request headers appended forbidden prefix");
+assert(!request.headers.has("x-method-override"), "Request headers appended forbidden method override");
+assert(request.headers.get("x-custom") === "value", "Request headers did not append header");
+
+const noCors = new Request("https://example.com/", { mode: "no-cors" });
+noCors.headers.append("Accept", "text/html");
+noCors.headers.append("X-Custom", "value");
+noCors.headers.set("Content-Type", "application/json");
+assert(noCors.headers.get("accept") === "text/html", "No-CORS request headers did not append safelisted header");
+assert(!noCors.headers.has("x-custom"), "No-CORS request headers appended non-safelisted header");
+assert(!noCors.headers.has("content-type"), "No-CORS request headers set non-safelisted content type");
+
+const response = new Response("", { headers: { "Set-Cookie": "cookie=value" } });
+response.headers.append("Set-Cookie2", "cookie=value");
+response.headers.set("Host", "example.com");
+assert(!response.headers.has("set-cookie"), "Response headers kept forbidden header");
+assert(!response.headers.has("set-cookie2"), "Response headers appended forbidden header");
+assert(response.headers.get("host") === "example.com", "Response headers removed request header");
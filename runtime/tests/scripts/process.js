@@ -0,0 +1,25 @@
+function assert(condition, message) {
+	if (!condition) {
+		throw new Error(message);
+	}
+}
+
+assert(process.args.join() === "first,second", `Arguments were ${process.args.join()}`);
+
+assert(process.env.get("SPIDERFIRE_PROCESS_EXISTING") === "existing", "Existing variable was not read");
+assert(process.env.get("SPIDERFIRE_PROCESS_MISSING") === undefined, "Missing variable was not undefined");
+
+process.env.set("SPIDERFIRE_PROCESS_SET", "set");
+assert(process.env.toObject().SPIDERFIRE_PROCESS_SET === "set", "Set variable was not in the environment");
+
+process.env.delete("SPIDERFIRE_PROCESS_EXISTING");
+assert(!("SPIDERFIRE_PROCESS_EXISTING" in process.env.toObject()), "Deleted variable was still in the environment");
+
+for (const key of ["", "A=B", "A\0B"]) {
+	try {
+		process.env.set(key, "value");
+		assert(false, `Invalid variable ${JSON.stringify(key)} was set`);
+	} catch (error) {
+		assert(error.message.startsWith("Invalid environment variable"), `Unexpected error: ${error.message}`);
+	}
+}
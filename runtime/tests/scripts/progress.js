@@ -0,0 +1,52 @@
+function assert(condition, message) {
+	if (!condition) {
+		throw new Error(message);
+	}
+}
+
+const uploads = [];
+const downloads = [];
+let text = null;
+
+fetch(`${url}/echo`, {
+	method: "POST",
+	body: "a".repeat(4096),
+	onUploadProgress: progress => uploads.push(progress),
+	onDownloadProgress: progress => downloads.push(progress),
+})
+	.then(response => response.text())
+	.then(body => {
+		text = body;
+	});
+
+let unread = null;
+fetch(`${url}/unread`, { onDownloadProgress: () => {} }).then(response => {
+	unread = response;
+});
+
+function check() {
+	assert(text === "a".repeat(4096), "Echoed body did not match");
+	assert(unread !== null, "Unread response was not received");
+
+	assert(uploads.length > 0, "Upload progress was not reported");
+	assert(uploads.at(-1).loaded === 4096, `Upload progress was ${uploads.at(-1).loaded} bytes`);
+	assert(uploads.at(-1).total === 4096, "Upload total did not match the length of the body");
+
+	assert(downloads.length > 0, "Download progress was not reported");
+	assert(downloads.at(-1).loaded === 4096, `Download progress was ${downloads.at(-1).loaded} bytes`);
+	assert(downloads.at(-1).total === 4096, "Download total did not match the Content-Length");
+
+	for (let i = 1; i < downloads.length; i++) {
+		assert(downloads[i].loaded > downloads[i - 1].loaded, "Download progress did not increase");
+	}
+}
+
+function throwing() {
+	fetch(`${url}/echo`, {
+		method: "POST",
+		body: "a",
+		onDownloadProgress: () => {
+			throw new Error("Progress Callback Error");
+		},
+	}).then(response => response.text());
+}
@@ -0,0 +1,60 @@
+function assert(condition, message) {
+	if (!condition) {
+		throw new Error(message);
+	}
+}
+
+const completed = [];
+
+function test(name, callback) {
+	callback().then(() => completed.push(name));
+}
+
+test("cors", async () => {
+	const response = await fetch(`${url}/headers#fragment`);
+	assert(response.type === "cors", `CORS response had type ${response.type}`);
+	assert(response.url === `${url}/headers`, `CORS response URL ${response.url} kept the fragment`);
+	assert(!response.redirected, "Response without redirects was redirected");
+	assert(response.headers.get("content-type") === "text/plain", "Safelisted header was removed");
+	assert(response.headers.get("x-exposed") === "exposed", "Exposed header was removed");
+	assert(!response.headers.has("x-secret"), "Unexposed header was kept");
+	assert(!response.headers.has("set-cookie"), "Forbidden header was kept");
+	assert((await response.text()) === "hello", "CORS response body did not match");
+});
+
+test("wildcard", async () => {
+	const response = await fetch(`${url}/wildcard`);
+	assert(response.headers.get("x-secret") === "secret", "Wildcard did not expose header");
+
+	const credentialed = await fetch(`${url}/wildcard`, { credentials: "include" });
+	assert(!credentialed.headers.has("x-secret"), "Wildcard exposed header with credentials");
+});
+
+test("opaque", async () => {
+	const response = await fetch(`${url}/headers`, { mode: "no-cors" });
+	assert(response.type === "opaque", `No-CORS response had type ${response.type}`);
+	assert(response.url === "", "Opaque response exposed its URL");
+	assert(response.status === 0, "Opaque response exposed its status");
+	assert([...response.headers].length === 0, "Opaque response exposed its headers");
+	assert((await response.text()) === "", "Opaque response exposed its body");
+});
+
+test("basic", async () => {
+	const response = await fetch("data:text/plain,hello");
+	assert(response.type === "basic", `Data URL response had type ${response.type}`);
+	assert(!response.redirected, "Data URL response was redirected");
+});
+
+test("redirect", async () => {
+	const response = await fetch(`${url}/redirect#fragment`);
+	assert(response.redirected, "Response after a redirect was not redirected");
+	assert(response.url === `${url}/headers`, `Redirected response URL was ${response.url}`);
+	assert(response.headers.get("x-exposed") === "exposed", "Redirected response was not filtered");
+	assert(!response.headers.has("x-secret"), "Redirected response was not filtered");
+});
+
+function check() {
+	for (const name of ["cors", "wildcard", "opaque", "basic", "redirect"]) {
+		assert(completed.includes(name), `Test ${name} did not complete`);
+	}
+}
@@ -0,0 +1,114 @@
+function assert(condition, message) {
+	if (!condition) {
+		throw new Error(message);
+	}
+}
+
+function assertThrows(callback, message) {
+	try {
+		callback();
+	} catch (error) {
+		assert(error instanceof TypeError, `${message}: Expected TypeError, Received ${error}`);
+		return;
+	}
+	throw new Error(`${message}: Expected TypeError`);
+}
+
+async function assertRejects(promise, message) {
+	try {
+		await promise;
+	} catch (error) {
+		assert(error instanceof TypeError, `${message}: Expected TypeError, Received ${error}`);
+		return;
+	}
+	throw new Error(`${message}: Expected TypeError`);
+}
+
+const url = "https://example.com/resource?query";
+let completed = false;
+let failure = null;
+
+async function testRequest() {
+	const request = new Request(url, {
+		method: "POST",
+		headers: { "Content-Type": "text/plain", "X-Custom": "value" },
+		body: "request body",
+	});
+	const data = await request.serialize();
+	assert(data instanceof ArrayBuffer, "Serialised request was not an ArrayBuffer");
+
+	const deserialised = Request.deserialize(data);
+	assert(deserialised.method === "POST", "Request method did not round-trip");
+	assert(deserialised.url === url, "Request URL did not round-trip");
+	assert(deserialised.headers.get("X-Custom") === "value", "Request headers did not round-trip");
+	assert((await deserialised.text()) === "request body", "Request body did not round-trip");
+
+	await request.text();
+	await assertRejects(request.serialize(), "Used request was serialised");
+}
+
+async function testResponse() {
+	const response = new Response("response body", {
+		status: 201,
+		statusText: "Created",
+		headers: { "Content-Type": "text/plain", "X-Custom": "value" },
+	});
+	const data = await response.serialize();
+	assert(data instanceof ArrayBuffer, "Serialised response was not an ArrayBuffer");
+	assert(!response.bodyUsed, "Serialising used the response body");
+
+	const deserialised = Response.deserialize(data);
+	assert(deserialised.status === 201, "Response status did not round-trip");
+	assert(deserialised.statusText === "Created", "Response status text did not round-trip");
+	assert(deserialised.headers.get("X-Custom") === "value", "Response headers did not round-trip");
+	assert((await deserialised.text()) === "response body", "Response body did not round-trip");
+
+	// Serialising restores the body, so it can be serialised and read again.
+	assert((await response.serialize()).byteLength === data.byteLength, "Serialised response changed");
+	assert((await response.text()) === "response body", "Serialised response body was not restored");
+	await assertRejects(response.serialize(), "Used response was serialised");
+
+	const empty = Response.deserialize(await new Response(undefined, { status: 204 }).serialize());
+	assert(empty.status === 204, "Empty response status did not round-trip");
+	assert((await empty.text()) === "", "Empty response body was not empty");
+}
+
+async function testInvalid() {
+	const request = new Uint8Array(await new Request(url).serialize());
+	const response = new Uint8Array(await new Response("body").serialize());
+
+	for (let length = 0; length < request.length; length++) {
+		assertThrows(() => Request.deserialize(request.slice(0, length)), `Truncated request of length ${length}`);
+	}
+	for (let length = 0; length < response.length; length++) {
+		assertThrows(() => Response.deserialize(response.slice(0, length)), `Truncated response of length ${length}`);
+	}
+
+	const trailing = new Uint8Array(response.length + 1);
+	trailing.set(response);
+	assertThrows(() => Response.deserialize(trailing), "Response with trailing data");
+
+	const magic = response.slice();
+	magic[0] ^= 0xff;
+	assertThrows(() => Response.deserialize(magic), "Response with bad magic");
+	assertThrows(() => Request.deserialize(response), "Response as request");
+	assertThrows(() => Response.deserialize(request), "Request as response");
+}
+
+async function test() {
+	await testRequest();
+	await testResponse();
+	await testInvalid();
+	completed = true;
+}
+
+test().catch(error => {
+	failure = error;
+});
+
+function check() {
+	if (failure !== null) {
+		throw failure;
+	}
+	return completed;
+}
@@ -0,0 +1,26 @@
+function assert(condition, message) {
+	if (!condition) {
+		throw new Error(message);
+	}
+}
+
+const order = [];
+
+const unreferenced = setTimeout(() => order.push("unreferenced"), 1000);
+assert(unreferenced.unref() === unreferenced, "unref() did not return the timer");
+assert(!unreferenced.hasRef(), "Timer was referenced after unref()");
+
+const timeout = setTimeout((a, b) => order.push(a + b), 1, 1, 2);
+assert(timeout.hasRef(), "Timer was not referenced by default");
+assert(typeof +timeout === "number", "Timer did not convert to its ID");
+
+const cleared = setTimeout(() => order.push("cleared"), 1);
+clearTimeout(cleared);
+assert(!cleared.hasRef(), "Cleared timer was referenced");
+
+const interval = setInterval(() => {
+	order.push("interval");
+	clearInterval(+interval);
+}, 5);
+
+globalThis.order = order;
@@ -0,0 +1,66 @@
+function assert(condition, message) {
+	if (!condition) {
+		throw new Error(message);
+	}
+}
+
+const results = {};
+
+fetch(`${url}/trailers`).then(async response => {
+	const trailer = response.trailer;
+	results.cached = trailer === response.trailer;
+	results.text = await response.text();
+	results.checksum = (await trailer).get("x-checksum");
+});
+
+fetch(`${url}/trailers`).then(async response => {
+	const reader = response.body.getReader();
+	let length = 0;
+	while (true) {
+		const { done, value } = await reader.read();
+		if (done) {
+			break;
+		}
+		length += value.length;
+	}
+	results.streamed = length;
+	results.streamedChecksum = (await response.trailer).get("x-checksum");
+});
+
+fetch(`${url}/trailers`).then(async response => {
+	const trailer = response.trailer;
+	await response.body.cancel();
+	try {
+		await trailer;
+		results.unread = "resolved";
+	} catch (error) {
+		results.unread = error instanceof TypeError ? error.message : String(error);
+	}
+});
+
+fetch(`${url}/plain`).then(async response => {
+	await response.text();
+	results.plain = [...(await response.trailer)].length;
+});
+
+// Waiting for the trailers of a body which is never read does not keep the event loop alive.
+fetch(`${url}/trailers`).then(response => {
+	results.pending = response;
+	response.trailer.then(() => (results.pendingSettled = true));
+});
+
+new Response("body").trailer.then(headers => {
+	results.constructed = [...headers].length;
+});
+
+function check() {
+	assert(results.cached, "Trailer promise was not cached");
+	assert(results.text === "hello", "Chunked body did not match");
+	assert(results.checksum === "abc", "Trailer was not received after reading the body");
+	assert(results.streamed === 5, "Streamed body length did not match");
+	assert(results.streamedChecksum === "abc", "Trailer was not received after streaming the body");
+	assert(results.unread === "Response body was not fully read.", `Unread trailer settled with ${results.unread}`);
+	assert(results.plain === 0, "Response without trailers resolved with headers");
+	assert(results.constructed === 0, "Constructed response resolved with headers");
+	assert(results.pending && !results.pendingSettled, "Trailer of an unread body settled");
+}
@@ -0,0 +1,41 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+use std::path::Path;
+
+use ion::conversions::FromValue;
+use ion::script::Script;
+use ion::Context;
+use mozjs::rust::{JSEngine, Runtime};
+use runtime::config::{Config, CONFIG};
+use runtime::RuntimeBuilder;
+use tokio::runtime::Builder;
+
+const FILE_NAME: &str = "timers.js";
+const SCRIPT: &str = include_str!("scripts/timers.js");
+
+#[test]
+fn timers() {
+	CONFIG.set(Config::default().script(true)).unwrap();
+
+	let engine = JSEngine::init().unwrap();
+	let rt = Runtime::new(engine.handle());
+
+	let cx = &mut Context::from_runtime(&rt);
+	let rt = RuntimeBuilder::<()>::new().macrotask_queue().build(cx);
+
+	let result = Script::compile_and_evaluate(rt.cx(), Path::new(FILE_NAME), SCRIPT);
+	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+
+	let tokio = Builder::new_current_thread().enable_time().build().unwrap();
+	let result = tokio.block_on(rt.run_event_loop());
+	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+
+	// The event loop completes without waiting for the unreferenced timer.
+	let order = Script::compile_and_evaluate(rt.cx(), Path::new(FILE_NAME), "order.join()").unwrap();
+	let order = String::from_value(rt.cx(), &order, true, ()).unwrap();
+	assert_eq!(order, "3,interval");
+}
@@ -0,0 +1,58 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "fetch")]
+
+use std::path::Path;
+
+use ion::script::Script;
+use ion::Context;
+use mozjs::rust::{JSEngine, Runtime};
+use runtime::config::{Config, CONFIG};
+use runtime::RuntimeBuilder;
+use tokio::runtime::Builder;
+use tokio::task::LocalSet;
+
+mod common;
+
+const FILE_NAME: &str = "trailers.js";
+const SCRIPT: &str = include_str!("scripts/trailers.js");
+
+fn respond(request: &common::Request) -> Vec<u8> {
+	let response = match request.path.as_str() {
+		"/plain" => "HTTP/1.1 200 OK\r\nContent-Length: 5\r\nConnection: close\r\n\r\nhello",
+		_ => concat!(
+			"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\nTrailer: X-Checksum\r\nConnection: close\r\n\r\n",
+			"5\r\nhello\r\n0\r\nX-Checksum: abc\r\n\r\n"
+		),
+	};
+	response.as_bytes().to_vec()
+}
+
+#[test]
+fn trailers() {
+	CONFIG.set(Config::default().script(true)).unwrap();
+	let address = common::serve(respond);
+
+	let engine = JSEngine::init().unwrap();
+	let rt = Runtime::new(engine.handle());
+
+	let cx = &mut Context::from_runtime(&rt);
+	let rt = RuntimeBuilder::<()>::new().microtask_queue().macrotask_queue().build(cx);
+	rt.global().set_as(rt.cx(), "url", &format!("http://{address}"));
+
+	let tokio = Builder::new_current_thread().enable_all().build().unwrap();
+	let local = LocalSet::new();
+
+	let result = Script::compile_and_evaluate(rt.cx(), Path::new(FILE_NAME), SCRIPT);
+	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+
+	let result = local.block_on(&tokio, rt.run_event_loop());
+	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+
+	let result = Script::compile_and_evaluate(rt.cx(), Path::new(FILE_NAME), "check()");
+	assert!(result.is_ok(), "Error: {:?}", result.unwrap_err());
+}